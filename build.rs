@@ -7,13 +7,15 @@ fn main() {
     let profile = env::var("PROFILE").unwrap();
     println!("cargo::rustc-env=PROFILE={profile}");
 
-    println!("cargo::rustc-check-cfg=cfg(backend, values(\"winit\", \"android\"))");
+    println!("cargo::rustc-check-cfg=cfg(backend, values(\"winit\", \"android\", \"kms\"))");
 
     let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
     let target_family = env::var("CARGO_CFG_TARGET_FAMILY").unwrap();
 
     if target_os == "android" {
         println!("cargo::rustc-cfg=backend=\"android\"");
+    } else if cfg!(feature = "kms") && target_os == "linux" {
+        println!("cargo::rustc-cfg=backend=\"kms\"");
     } else if cfg!(feature = "winit")
         && (target_family == "unix" || target_os == "macos" || target_os == "windows")
     {
@@ -1,5 +1,6 @@
 mod init;
 mod run;
+mod test;
 
 use std::process::ExitCode;
 
@@ -39,6 +40,7 @@ impl Args {
         match self.command {
             Command::Init(init) => init.run(),
             Command::Run(run) => run.run(),
+            Command::Test(test) => test.run(),
         }
     }
 }
@@ -51,4 +53,8 @@ enum Command {
     /// Run a project.
     #[clap(visible_alias = "r")]
     Run(run::Command),
+
+    /// Test a project, comparing snapshots against their golden images.
+    #[clap(visible_alias = "t")]
+    Test(test::Command),
 }
@@ -0,0 +1,140 @@
+use std::{
+    ffi::OsString,
+    fs,
+    path::Path,
+    process::{self, Stdio},
+};
+
+use cargo_metadata::MetadataCommand;
+use clap::Parser;
+use eyre::OptionExt;
+
+/// The environment variable `ike_skia::Snapshots` reads whether to update golden images from.
+const UPDATE_SNAPSHOTS: &str = "IKE_UPDATE_SNAPSHOTS";
+
+#[derive(Parser)]
+pub struct Command {
+    /// Report the snapshots that didn't match their golden images.
+    #[clap(long)]
+    snapshots: bool,
+
+    /// Overwrite the golden images with the rendered snapshots.
+    #[clap(long)]
+    update_snapshots: bool,
+
+    /// Arguments passed to `cargo test`.
+    #[clap(last = true)]
+    args: Vec<OsString>,
+}
+
+impl Command {
+    pub fn run(self) -> eyre::Result<()> {
+        let metadata = MetadataCommand::new().exec()?;
+
+        let root_package = metadata
+            .root_package()
+            .ok_or_eyre("root package could not be found")?;
+
+        let snapshots_dir = root_package
+            .manifest_path
+            .parent()
+            .expect("files always have a parent directory")
+            .join("tests")
+            .join("snapshots");
+
+        let mut cargo = process::Command::new("cargo");
+        cargo.arg("test").args(&self.args);
+
+        if self.update_snapshots {
+            cargo.env(UPDATE_SNAPSHOTS, "1");
+        }
+
+        let status = cargo
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()?;
+
+        if self.snapshots || self.update_snapshots {
+            report_snapshots(
+                snapshots_dir.as_std_path(),
+                self.update_snapshots,
+            )?;
+        }
+
+        if !status.success() {
+            eyre::bail!("tests failed");
+        }
+
+        Ok(())
+    }
+}
+
+fn report_snapshots(dir: &Path, updated: bool) -> eyre::Result<()> {
+    if !dir.exists() {
+        println!("no snapshots in `{}`", dir.display());
+        return Ok(());
+    }
+
+    let mut goldens = 0;
+    let mut failed = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        if let Some(name) = name.strip_suffix(".new.png") {
+            failed.push((name.to_owned(), path.clone()));
+        } else if name.ends_with(".png") && !name.ends_with(".diff.png") {
+            goldens += 1;
+        }
+    }
+
+    failed.sort();
+
+    if updated {
+        println!(
+            "updated {goldens} snapshots in `{}`",
+            dir.display()
+        );
+        return Ok(());
+    }
+
+    let mut changed = 0;
+
+    for (name, path) in &failed {
+        let golden = path.with_file_name(format!("{name}.png"));
+        let diff = path.with_file_name(format!("{name}.diff.png"));
+
+        if !golden.exists() {
+            println!(
+                "snapshot `{name}` is new: `{}`",
+                path.display()
+            );
+            continue;
+        }
+
+        changed += 1;
+
+        match diff.exists() {
+            true => println!(
+                "snapshot `{name}` changed: `{}`, `{}`",
+                path.display(),
+                diff.display(),
+            ),
+            false => println!(
+                "snapshot `{name}` changed size: `{}`",
+                path.display()
+            ),
+        }
+    }
+
+    println!(
+        "{} of {goldens} snapshots matched",
+        goldens - changed,
+    );
+
+    Ok(())
+}
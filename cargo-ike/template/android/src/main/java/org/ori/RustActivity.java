@@ -1,12 +1,26 @@
 package org.ori;
 
 import android.app.NativeActivity;
+import android.content.ClipData;
+import android.content.ClipboardManager;
+import android.content.Context;
+import android.content.Intent;
+import android.os.Build;
 import android.os.Bundle;
+import android.view.View;
 import android.view.WindowInsets;
+import android.view.WindowInsetsAnimation;
+import android.webkit.MimeTypeMap;
 import android.graphics.Insets;
+import android.window.BackEvent;
+import android.window.OnBackAnimationCallback;
+import android.window.OnBackInvokedDispatcher;
+
+import java.util.List;
 
 public final class RustActivity extends NativeActivity {
     RustView rustView;
+    boolean animatingIme;
 
     @Override
     protected void onCreate(Bundle savedInstanceData) {
@@ -14,6 +28,8 @@ public final class RustActivity extends NativeActivity {
 
         rustView = new RustView(this);
         setContentView(rustView);
+
+        registerBackCallback();
     }
 
     @Override
@@ -26,26 +42,190 @@ public final class RustActivity extends NativeActivity {
     private void attachInsetListener() {
         getWindow().setDecorFitsSystemWindows(false);
 
-        getWindow().getDecorView().setOnApplyWindowInsetsListener((v, insets) -> {
-            Insets systemBars = insets.getInsets(WindowInsets.Type.systemBars());
-            Insets ime = insets.getInsets(WindowInsets.Type.ime());
-            Insets cutout = insets.getInsets(WindowInsets.Type.displayCutout());
-
-            rustView.onApplyWindowInsetsNative(
-                    systemBars.left,
-                    systemBars.top,
-                    systemBars.right,
-                    systemBars.bottom,
-                    ime.left,
-                    ime.top,
-                    ime.right,
-                    ime.bottom,
-                    cutout.left,
-                    cutout.top,
-                    cutout.right,
-                    cutout.bottom);
+        View decorView = getWindow().getDecorView();
+
+        decorView.setOnApplyWindowInsetsListener((v, insets) -> {
+            // while the keyboard is animated the insets are sent for every frame of the animation
+            // instead, the insets applied here are already the ones at the end of it
+            if (!animatingIme) {
+                applyInsets(insets);
+            }
 
             return insets;
         });
+
+        int dispatchMode = WindowInsetsAnimation.Callback.DISPATCH_MODE_CONTINUE_ON_SUBTREE;
+        decorView.setWindowInsetsAnimationCallback(new WindowInsetsAnimation.Callback(dispatchMode) {
+            @Override
+            public void onPrepare(WindowInsetsAnimation animation) {
+                if ((animation.getTypeMask() & WindowInsets.Type.ime()) != 0) {
+                    animatingIme = true;
+                }
+            }
+
+            @Override
+            public WindowInsets onProgress(WindowInsets insets, List<WindowInsetsAnimation> animations) {
+                if (animatingIme) {
+                    applyInsets(insets);
+                }
+
+                return insets;
+            }
+
+            @Override
+            public void onEnd(WindowInsetsAnimation animation) {
+                if ((animation.getTypeMask() & WindowInsets.Type.ime()) == 0) {
+                    return;
+                }
+
+                animatingIme = false;
+
+                WindowInsets insets = decorView.getRootWindowInsets();
+
+                if (insets != null) {
+                    applyInsets(insets);
+                }
+            }
+        });
+    }
+
+    private void applyInsets(WindowInsets insets) {
+        Insets systemBars = insets.getInsets(WindowInsets.Type.systemBars());
+        Insets ime = insets.getInsets(WindowInsets.Type.ime());
+        Insets cutout = insets.getInsets(WindowInsets.Type.displayCutout());
+
+        rustView.onApplyWindowInsetsNative(
+                systemBars.left,
+                systemBars.top,
+                systemBars.right,
+                systemBars.bottom,
+                ime.left,
+                ime.top,
+                ime.right,
+                ime.bottom,
+                cutout.left,
+                cutout.top,
+                cutout.right,
+                cutout.bottom);
+    }
+
+    // the callback is always registered, the activity is finished from rust when going back isn't
+    // handled, on older versions the back key is sent through the input queue instead
+    private void registerBackCallback() {
+        int priority = OnBackInvokedDispatcher.PRIORITY_DEFAULT;
+
+        if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.UPSIDE_DOWN_CAKE) {
+            getOnBackInvokedDispatcher().registerOnBackInvokedCallback(priority, new OnBackAnimationCallback() {
+                @Override
+                public void onBackStarted(BackEvent event) {
+                    rustView.onBackStartedNative(
+                            event.getProgress(),
+                            event.getSwipeEdge(),
+                            event.getTouchX(),
+                            event.getTouchY());
+                }
+
+                @Override
+                public void onBackProgressed(BackEvent event) {
+                    rustView.onBackProgressedNative(
+                            event.getProgress(),
+                            event.getSwipeEdge(),
+                            event.getTouchX(),
+                            event.getTouchY());
+                }
+
+                @Override
+                public void onBackCancelled() {
+                    rustView.onBackCancelledNative();
+                }
+
+                @Override
+                public void onBackInvoked() {
+                    rustView.onBackInvokedNative();
+                }
+            });
+        } else if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.TIRAMISU) {
+            getOnBackInvokedDispatcher().registerOnBackInvokedCallback(priority, rustView::onBackInvokedNative);
+        }
+    }
+
+    @Override
+    public void onTrimMemory(int level) {
+        super.onTrimMemory(level);
+
+        rustView.onTrimMemoryNative(level);
+    }
+
+    void setClipboardText(String text) {
+        ClipboardManager clipboard = (ClipboardManager) getSystemService(Context.CLIPBOARD_SERVICE);
+        clipboard.setPrimaryClip(ClipData.newPlainText(null, text));
+    }
+
+    String getClipboardText() {
+        ClipboardManager clipboard = (ClipboardManager) getSystemService(Context.CLIPBOARD_SERVICE);
+        ClipData clip = clipboard.getPrimaryClip();
+
+        if (clip == null || clip.getItemCount() == 0) {
+            return null;
+        }
+
+        return clip.getItemAt(0).coerceToText(this).toString();
+    }
+
+    void openFileDialog(int request, int kind, String[] extensions, String name) {
+        Intent intent;
+
+        if (kind == 2) {
+            intent = new Intent(Intent.ACTION_OPEN_DOCUMENT_TREE);
+        } else {
+            intent = new Intent(kind == 3 ? Intent.ACTION_CREATE_DOCUMENT : Intent.ACTION_OPEN_DOCUMENT);
+            intent.addCategory(Intent.CATEGORY_OPENABLE);
+            intent.setType("*/*");
+            intent.putExtra(Intent.EXTRA_ALLOW_MULTIPLE, kind == 1);
+
+            if (name != null) {
+                intent.putExtra(Intent.EXTRA_TITLE, name);
+            }
+
+            String[] mimeTypes = new String[extensions.length];
+            int count = 0;
+
+            for (String extension : extensions) {
+                String mimeType = MimeTypeMap.getSingleton().getMimeTypeFromExtension(extension);
+
+                if (mimeType != null) {
+                    mimeTypes[count++] = mimeType;
+                }
+            }
+
+            if (count > 0) {
+                intent.putExtra(Intent.EXTRA_MIME_TYPES, java.util.Arrays.copyOf(mimeTypes, count));
+            }
+        }
+
+        runOnUiThread(() -> startActivityForResult(intent, request));
+    }
+
+    @Override
+    protected void onActivityResult(int requestCode, int resultCode, Intent data) {
+        super.onActivityResult(requestCode, resultCode, data);
+
+        String[] uris = new String[0];
+
+        if (resultCode == RESULT_OK && data != null) {
+            ClipData clip = data.getClipData();
+
+            if (clip != null) {
+                uris = new String[clip.getItemCount()];
+
+                for (int i = 0; i < uris.length; i++) {
+                    uris[i] = clip.getItemAt(i).getUri().toString();
+                }
+            } else if (data.getData() != null) {
+                uris = new String[] { data.getData().toString() };
+            }
+        }
+
+        rustView.onFileDialogResultNative(requestCode, uris);
     }
 }
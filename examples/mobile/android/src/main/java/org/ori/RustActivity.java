@@ -1,9 +1,16 @@
 package org.ori;
 
 import android.app.NativeActivity;
+import android.content.ClipData;
+import android.content.Intent;
+import android.os.Build;
 import android.os.Bundle;
 import android.view.WindowInsets;
+import android.webkit.MimeTypeMap;
 import android.graphics.Insets;
+import android.window.BackEvent;
+import android.window.OnBackAnimationCallback;
+import android.window.OnBackInvokedDispatcher;
 
 public final class RustActivity extends NativeActivity {
     RustView rustView;
@@ -14,6 +21,8 @@ public final class RustActivity extends NativeActivity {
 
         rustView = new RustView(this);
         setContentView(rustView);
+
+        registerBackCallback();
     }
 
     @Override
@@ -48,4 +57,108 @@ public final class RustActivity extends NativeActivity {
             return insets;
         });
     }
+
+    // the callback is always registered, the activity is finished from rust when going back isn't
+    // handled, on older versions the back key is sent through the input queue instead
+    private void registerBackCallback() {
+        int priority = OnBackInvokedDispatcher.PRIORITY_DEFAULT;
+
+        if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.UPSIDE_DOWN_CAKE) {
+            getOnBackInvokedDispatcher().registerOnBackInvokedCallback(priority, new OnBackAnimationCallback() {
+                @Override
+                public void onBackStarted(BackEvent event) {
+                    rustView.onBackStartedNative(
+                            event.getProgress(),
+                            event.getSwipeEdge(),
+                            event.getTouchX(),
+                            event.getTouchY());
+                }
+
+                @Override
+                public void onBackProgressed(BackEvent event) {
+                    rustView.onBackProgressedNative(
+                            event.getProgress(),
+                            event.getSwipeEdge(),
+                            event.getTouchX(),
+                            event.getTouchY());
+                }
+
+                @Override
+                public void onBackCancelled() {
+                    rustView.onBackCancelledNative();
+                }
+
+                @Override
+                public void onBackInvoked() {
+                    rustView.onBackInvokedNative();
+                }
+            });
+        } else if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.TIRAMISU) {
+            getOnBackInvokedDispatcher().registerOnBackInvokedCallback(priority, rustView::onBackInvokedNative);
+        }
+    }
+
+    @Override
+    public void onTrimMemory(int level) {
+        super.onTrimMemory(level);
+
+        rustView.onTrimMemoryNative(level);
+    }
+
+    void openFileDialog(int request, int kind, String[] extensions, String name) {
+        Intent intent;
+
+        if (kind == 2) {
+            intent = new Intent(Intent.ACTION_OPEN_DOCUMENT_TREE);
+        } else {
+            intent = new Intent(kind == 3 ? Intent.ACTION_CREATE_DOCUMENT : Intent.ACTION_OPEN_DOCUMENT);
+            intent.addCategory(Intent.CATEGORY_OPENABLE);
+            intent.setType("*/*");
+            intent.putExtra(Intent.EXTRA_ALLOW_MULTIPLE, kind == 1);
+
+            if (name != null) {
+                intent.putExtra(Intent.EXTRA_TITLE, name);
+            }
+
+            String[] mimeTypes = new String[extensions.length];
+            int count = 0;
+
+            for (String extension : extensions) {
+                String mimeType = MimeTypeMap.getSingleton().getMimeTypeFromExtension(extension);
+
+                if (mimeType != null) {
+                    mimeTypes[count++] = mimeType;
+                }
+            }
+
+            if (count > 0) {
+                intent.putExtra(Intent.EXTRA_MIME_TYPES, java.util.Arrays.copyOf(mimeTypes, count));
+            }
+        }
+
+        runOnUiThread(() -> startActivityForResult(intent, request));
+    }
+
+    @Override
+    protected void onActivityResult(int requestCode, int resultCode, Intent data) {
+        super.onActivityResult(requestCode, resultCode, data);
+
+        String[] uris = new String[0];
+
+        if (resultCode == RESULT_OK && data != null) {
+            ClipData clip = data.getClipData();
+
+            if (clip != null) {
+                uris = new String[clip.getItemCount()];
+
+                for (int i = 0; i < uris.length; i++) {
+                    uris[i] = clip.getItemAt(i).getUri().toString();
+                }
+            } else if (data.getData() != null) {
+                uris = new String[] { data.getData().toString() };
+            }
+        }
+
+        rustView.onFileDialogResultNative(requestCode, uris);
+    }
 }
@@ -10,6 +10,7 @@ import android.view.KeyEvent;
 
 public final class RustView extends View {
     final InputMethodManager inputMethodManager;
+    int inputType = InputType.TYPE_CLASS_TEXT;
 
     public RustView(Context context) {
         super(context);
@@ -22,11 +23,15 @@ public final class RustView extends View {
 
     @Override
     public InputConnection onCreateInputConnection(EditorInfo outAttrs) {
-        outAttrs.inputType = InputType.TYPE_CLASS_TEXT;
+        outAttrs.inputType = inputType;
 
         return new RustInputConnection(this);
     }
 
+    void setInputType(int inputType) {
+        this.inputType = inputType;
+    }
+
     public native void onApplyWindowInsetsNative(int systemBarsLeft, int systemBarsTop, int systemBarsRight,
             int systemBarsBottom, int imeLeft, int imeTop, int imeRight, int imeBottom, int cutoutLeft, int cutoutTop,
             int cutoutRight, int cutoutBottom);
@@ -48,4 +53,16 @@ public final class RustView extends View {
     public native boolean sendKeyEventNative(KeyEvent event);
 
     public native boolean setSelectionNative(int start, int end);
+
+    public native void onFileDialogResultNative(int request, String[] uris);
+
+    public native void onTrimMemoryNative(int level);
+
+    public native void onBackStartedNative(float progress, int edge, float x, float y);
+
+    public native void onBackProgressedNative(float progress, int edge, float x, float y);
+
+    public native void onBackCancelledNative();
+
+    public native void onBackInvokedNative();
 }
@@ -0,0 +1,101 @@
+use ike_core::{BackEdge, BackEvent, BackGesture, Point};
+use jni::{JNIEnv, objects::JObject};
+
+use crate::{Event, EventLoop, WindowState, send_event};
+
+// edges of `android.window.BackEvent`
+const EDGE_LEFT: i32 = 0;
+const EDGE_RIGHT: i32 = 1;
+
+impl<'a, T> EventLoop<'a, T> {
+    /// Send `event` to the window, the activity is finished when going back isn't handled.
+    pub fn handle_back_event(&mut self, event: BackEvent) {
+        // the touch position is reported in pixels
+        let event = match event {
+            BackEvent::Started(gesture) => BackEvent::Started(self.scale_gesture(gesture)),
+            BackEvent::Progressed(gesture) => BackEvent::Progressed(self.scale_gesture(gesture)),
+            event => event,
+        };
+
+        let is_invoked = event == BackEvent::Invoked;
+
+        let handled = match self.window {
+            WindowState::Open(ref window) => match window.id {
+                Some(id) => self.shell.world().back(id, event),
+                None => false,
+            },
+
+            WindowState::Pending { .. } => false,
+        };
+
+        if is_invoked && !handled {
+            tracing::debug!("back not handled, finishing activity");
+
+            unsafe { ndk_sys::ANativeActivity_finish(self.native_activity.as_ptr()) };
+        }
+    }
+
+    fn scale_gesture(&self, gesture: BackGesture) -> BackGesture {
+        BackGesture {
+            position: Point::new(
+                gesture.position.x / self.scale_factor,
+                gesture.position.y / self.scale_factor,
+            ),
+            ..gesture
+        }
+    }
+}
+
+fn back_gesture(progress: f32, edge: i32, x: f32, y: f32) -> BackGesture {
+    let edge = match edge {
+        EDGE_LEFT => BackEdge::Left,
+        EDGE_RIGHT => BackEdge::Right,
+        _ => BackEdge::None,
+    };
+
+    BackGesture {
+        progress,
+        edge,
+        position: Point::new(x, y),
+    }
+}
+
+pub unsafe extern "C" fn on_back_started<'local>(
+    _env: JNIEnv<'local>,
+    _rust_view: JObject<'local>,
+    progress: f32,
+    edge: i32,
+    x: f32,
+    y: f32,
+) {
+    let gesture = back_gesture(progress, edge, x, y);
+    send_event(Event::Back(BackEvent::Started(gesture)));
+}
+
+pub unsafe extern "C" fn on_back_progressed<'local>(
+    _env: JNIEnv<'local>,
+    _rust_view: JObject<'local>,
+    progress: f32,
+    edge: i32,
+    x: f32,
+    y: f32,
+) {
+    let gesture = back_gesture(progress, edge, x, y);
+    send_event(Event::Back(BackEvent::Progressed(
+        gesture,
+    )));
+}
+
+pub unsafe extern "C" fn on_back_cancelled<'local>(
+    _env: JNIEnv<'local>,
+    _rust_view: JObject<'local>,
+) {
+    send_event(Event::Back(BackEvent::Cancelled));
+}
+
+pub unsafe extern "C" fn on_back_invoked<'local>(
+    _env: JNIEnv<'local>,
+    _rust_view: JObject<'local>,
+) {
+    send_event(Event::Back(BackEvent::Invoked));
+}
@@ -1,9 +1,16 @@
-use crate::{Event, InputQueueEvent, WindowEvent, send_event};
+use ike_core::{Lifecycle, MemoryPressure};
+
+use crate::{Event, InputQueueEvent, WindowEvent, lifecycle, send_event};
 
 pub fn register_callbacks(activity: &mut ndk_sys::ANativeActivity) {
     let callbacks = unsafe { &mut *activity.callbacks };
 
+    callbacks.onStart = Some(on_start);
     callbacks.onResume = Some(on_resume);
+    callbacks.onPause = Some(on_pause);
+    callbacks.onStop = Some(on_stop);
+    callbacks.onSaveInstanceState = Some(lifecycle::on_save_instance_state);
+    callbacks.onConfigurationChanged = Some(on_configuration_changed);
     callbacks.onNativeWindowCreated = Some(on_window_created);
     callbacks.onNativeWindowDestroyed = Some(on_window_destroyed);
     callbacks.onNativeWindowRedrawNeeded = Some(on_window_redraw_needed);
@@ -11,10 +18,28 @@ pub fn register_callbacks(activity: &mut ndk_sys::ANativeActivity) {
     callbacks.onWindowFocusChanged = Some(on_window_focus_changed);
     callbacks.onInputQueueCreated = Some(on_input_queue_created);
     callbacks.onInputQueueDestroyed = Some(on_input_queue_destroyed);
+    callbacks.onLowMemory = Some(on_low_memory);
+}
+
+// started is visible, but not in the foreground yet
+unsafe extern "C" fn on_start(_activity: *mut ndk_sys::ANativeActivity) {
+    send_event(Event::Lifecycle(Lifecycle::Paused));
 }
 
 unsafe extern "C" fn on_resume(_activity: *mut ndk_sys::ANativeActivity) {
-    send_event(Event::Resumed);
+    send_event(Event::Lifecycle(Lifecycle::Resumed));
+}
+
+unsafe extern "C" fn on_pause(_activity: *mut ndk_sys::ANativeActivity) {
+    send_event(Event::Lifecycle(Lifecycle::Paused));
+}
+
+unsafe extern "C" fn on_stop(_activity: *mut ndk_sys::ANativeActivity) {
+    send_event(Event::Lifecycle(Lifecycle::Stopped));
+}
+
+unsafe extern "C" fn on_configuration_changed(_activity: *mut ndk_sys::ANativeActivity) {
+    send_event(Event::ConfigurationChanged);
 }
 
 unsafe extern "C" fn on_window_created(
@@ -73,3 +98,9 @@ unsafe extern "C" fn on_input_queue_destroyed(
         InputQueueEvent::Destroyed(queue),
     ));
 }
+
+unsafe extern "C" fn on_low_memory(_activity: *mut ndk_sys::ANativeActivity) {
+    send_event(Event::MemoryPressure(
+        MemoryPressure::Critical,
+    ));
+}
@@ -0,0 +1,69 @@
+use ike_core::WindowId;
+use jni::{JNIEnv, objects::JString};
+
+use crate::{EventLoop, native};
+
+impl<'a, T> EventLoop<'a, T> {
+    /// Set the primary clip of the system clipboard to `text`.
+    pub fn set_clipboard(&mut self, text: String) {
+        let Ok(mut env) = self.jvm.attach_current_thread() else {
+            return;
+        };
+
+        if let Err(err) = self.set_clipboard_text(&mut env, &text) {
+            tracing::warn!(?err, "setting clipboard failed");
+        }
+    }
+
+    /// Read the primary clip of the system clipboard as text, and paste it into `window`.
+    pub fn paste_clipboard(&mut self, window: WindowId) {
+        let Ok(mut env) = self.jvm.attach_current_thread() else {
+            return;
+        };
+
+        match self.get_clipboard_text(&mut env) {
+            Ok(Some(text)) => {
+                self.shell.world().text_pasted(window, text);
+            }
+
+            Ok(None) => {}
+
+            Err(err) => {
+                tracing::warn!(?err, "reading clipboard failed");
+            }
+        }
+    }
+
+    fn set_clipboard_text(&self, env: &mut JNIEnv<'_>, text: &str) -> jni::errors::Result<()> {
+        let activity = unsafe { native::native_activity(self.native_activity) };
+        let text = env.new_string(text)?;
+
+        env.call_method(
+            activity,
+            "setClipboardText",
+            "(Ljava/lang/String;)V",
+            &[(&text).into()],
+        )?
+        .v()
+    }
+
+    fn get_clipboard_text(&self, env: &mut JNIEnv<'_>) -> jni::errors::Result<Option<String>> {
+        let activity = unsafe { native::native_activity(self.native_activity) };
+
+        let text = env
+            .call_method(
+                activity,
+                "getClipboardText",
+                "()Ljava/lang/String;",
+                &[],
+            )?
+            .l()?;
+
+        if text.is_null() {
+            return Ok(None);
+        }
+
+        let text = env.get_string(&JString::from(text))?;
+        Ok(Some(text.into()))
+    }
+}
@@ -1,7 +1,4 @@
-use std::{
-    pin::Pin,
-    sync::{Arc, mpsc::Sender},
-};
+use std::sync::mpsc::Sender;
 
 use crate::Event;
 
@@ -28,21 +25,3 @@ impl Proxy {
         unsafe { ndk_sys::ALooper_wake(self.looper) };
     }
 }
-
-impl ori::Proxy for Proxy {
-    fn cloned(&self) -> Arc<dyn ori::Proxy> {
-        Arc::new(self.clone())
-    }
-
-    fn rebuild(&self) {
-        self.send(Event::Rebuild);
-    }
-
-    fn event(&self, event: ori::Event) {
-        self.send(Event::Event(event))
-    }
-
-    fn spawn_boxed(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
-        self.send(Event::Future(future));
-    }
-}
@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+
+use ike_core::{FileDialog, FileDialogKind, FileDialogResponder};
+use jni::{
+    JNIEnv,
+    objects::{JObject, JObjectArray, JString},
+};
+
+use crate::{Event, EventLoop, native, send_event};
+
+impl<'a, T> EventLoop<'a, T> {
+    /// Start a Storage Access Framework activity for `dialog`, `respond` is called when
+    /// [`on_file_dialog_result`] is received for it.
+    pub fn show_file_dialog(&mut self, dialog: FileDialog, respond: FileDialogResponder) {
+        // activity request codes are limited to the lower 16 bits
+        let request = self.next_file_dialog;
+        self.next_file_dialog = (self.next_file_dialog + 1) % 0x10000;
+
+        let Ok(mut env) = self.jvm.attach_current_thread() else {
+            respond(Vec::new());
+            return;
+        };
+
+        if let Err(err) = self.open_file_dialog(&mut env, request, &dialog) {
+            tracing::warn!(?err, "opening file dialog failed");
+            respond(Vec::new());
+            return;
+        }
+
+        self.file_dialogs.insert(request, respond);
+    }
+
+    pub fn handle_file_dialog_result(&mut self, request: i32, uris: Vec<String>) {
+        let Some(respond) = self.file_dialogs.remove(&request) else {
+            return;
+        };
+
+        respond(uris.into_iter().map(PathBuf::from).collect());
+    }
+
+    fn open_file_dialog(
+        &self,
+        env: &mut JNIEnv<'_>,
+        request: i32,
+        dialog: &FileDialog,
+    ) -> jni::errors::Result<()> {
+        let activity = unsafe { native::native_activity(self.native_activity) };
+
+        let (kind, name) = match dialog.kind {
+            FileDialogKind::Open => (0, None),
+            FileDialogKind::OpenMany => (1, None),
+            FileDialogKind::Folder => (2, None),
+            FileDialogKind::Save { ref name } => (3, name.as_deref()),
+        };
+
+        let extensions = dialog
+            .filters
+            .iter()
+            .flat_map(|filter| &filter.extensions)
+            .collect::<Vec<_>>();
+
+        let string_class = env.find_class("java/lang/String")?;
+        let array = env.new_object_array(
+            extensions.len() as i32,
+            string_class,
+            JObject::null(),
+        )?;
+
+        for (i, extension) in extensions.into_iter().enumerate() {
+            let extension = env.new_string(extension)?;
+            env.set_object_array_element(&array, i as i32, extension)?;
+        }
+
+        let name = match name {
+            Some(name) => JObject::from(env.new_string(name)?),
+            None => JObject::null(),
+        };
+
+        env.call_method(
+            activity,
+            "openFileDialog",
+            "(II[Ljava/lang/String;Ljava/lang/String;)V",
+            &[
+                request.into(),
+                kind.into(),
+                (&*array).into(),
+                (&name).into(),
+            ],
+        )?
+        .v()
+    }
+}
+
+pub unsafe extern "C" fn on_file_dialog_result<'local>(
+    mut env: JNIEnv<'local>,
+    _rust_view: JObject<'local>,
+    request: i32,
+    uris: JObjectArray<'local>,
+) {
+    let len = env.get_array_length(&uris).unwrap_or(0);
+    let mut paths = Vec::new();
+
+    for i in 0..len {
+        let Ok(uri) = env.get_object_array_element(&uris, i) else {
+            continue;
+        };
+
+        if let Ok(uri) = env.get_string(&JString::from(uri)) {
+            paths.push(uri.to_string_lossy().to_string());
+        }
+    }
+
+    send_event(Event::FileDialog(request, paths));
+}
@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use ike_core::{ImeSignal, Key, NamedKey};
+use ike_core::{ImePurpose, ImeSignal, Key, NamedKey};
 use jni::{
     JNIEnv,
     objects::{JObject, JString},
@@ -13,6 +13,7 @@ use crate::{Event, EventLoop, GLOBAL_STATE, WindowState, native, send_event};
 pub(super) enum ImeEvent {
     CommitText(String, usize),
     DeleteSurrounding(usize, usize),
+    SetComposingText(String, i32),
     SendKeyEvent { key: Key, pressed: bool },
     SetSelection(usize, usize),
 }
@@ -108,7 +109,7 @@ impl<'a, T> EventLoop<'a, T> {
                         return;
                     };
 
-                    self.context.world.ime_commit_text(id, text);
+                    self.shell.context.world.ime_commit_text(id, text);
                 }
             }
 
@@ -123,24 +124,47 @@ impl<'a, T> EventLoop<'a, T> {
                     let selection = self.ime().selection();
 
                     if selection.start != selection.end {
-                        self.context.world.ime_commit_text(id, String::new());
+                        self.shell.context.world.ime_commit_text(id, String::new());
                         return;
                     }
 
                     if before != 0 {
                         let start = self.ime().index_n_chars_before(before);
-                        self.context.world.ime_select(id, start..selection.start);
-                        self.context.world.ime_commit_text(id, String::new());
+                        (self.shell.context.world).ime_select(id, start..selection.start);
+                        self.shell.context.world.ime_commit_text(id, String::new());
                     }
 
                     if after != 0 {
                         let end = self.ime().index_n_chars_after(after);
-                        self.context.world.ime_select(id, selection.end..end);
-                        self.context.world.ime_commit_text(id, String::new());
+                        self.shell.context.world.ime_select(id, selection.end..end);
+                        self.shell.context.world.ime_commit_text(id, String::new());
                     }
                 }
             }
 
+            ImeEvent::SetComposingText(text, new_cursor_position) => {
+                tracing::trace!(
+                    text,
+                    new_cursor_position,
+                    "ime set composing text",
+                );
+
+                if let WindowState::Open(ref window) = self.window {
+                    let Some(id) = window.id else {
+                        return;
+                    };
+
+                    // positive positions are relative to the end of the composing text, others
+                    // to the start, we don't support moving the cursor outside of it
+                    let cursor = match new_cursor_position > 0 {
+                        true => text.len(),
+                        false => 0,
+                    };
+
+                    (self.shell.context.world).ime_preedit(id, text, Some(cursor..cursor));
+                }
+            }
+
             ImeEvent::SendKeyEvent { key, pressed } => {
                 tracing::trace!(?key, pressed, "ime send key event");
 
@@ -149,7 +173,7 @@ impl<'a, T> EventLoop<'a, T> {
                         return;
                     };
 
-                    (self.context.world).key_pressed(id, key, false, None, pressed);
+                    (self.shell.context.world).key_pressed(id, key, false, None, pressed);
                 }
             }
 
@@ -162,7 +186,7 @@ impl<'a, T> EventLoop<'a, T> {
                     };
 
                     self.ime().set_selection(start..end);
-                    self.context.world.ime_select(id, start..end);
+                    self.shell.context.world.ime_select(id, start..end);
                 }
             }
         }
@@ -207,6 +231,19 @@ impl<'a, T> EventLoop<'a, T> {
 
             ImeSignal::Area(..) => {}
 
+            ImeSignal::Purpose(purpose) => {
+                if let Ok(mut env) = self.jvm.attach_current_thread() {
+                    // the input type is only read when the input is restarted
+                    if self.set_input_type(&mut env, purpose).is_ok()
+                        && self.restart_input(&mut env).is_ok()
+                    {
+                        tracing::trace!(?purpose, "set input type");
+                    } else {
+                        tracing::warn!(?purpose, "set input type failed");
+                    }
+                }
+            }
+
             ImeSignal::Text(text) => {
                 self.ime().set_text(text);
 
@@ -293,6 +330,28 @@ impl<'a, T> EventLoop<'a, T> {
         .z()
     }
 
+    fn set_input_type(&self, env: &mut JNIEnv<'_>, purpose: ImePurpose) -> jni::errors::Result<()> {
+        // see `android.text.InputType`
+        const TYPE_CLASS_TEXT: i32 = 0x01;
+        const TYPE_TEXT_VARIATION_PASSWORD: i32 = 0x80;
+
+        let input_type = match purpose {
+            ImePurpose::Normal => TYPE_CLASS_TEXT,
+            ImePurpose::Password => TYPE_CLASS_TEXT | TYPE_TEXT_VARIATION_PASSWORD,
+        };
+
+        let activity = unsafe { native::native_activity(self.native_activity) };
+        let view = native::rust_view(env, &activity)?;
+
+        env.call_method(
+            view,
+            "setInputType",
+            "(I)V",
+            &[input_type.into()],
+        )?
+        .v()
+    }
+
     fn restart_input(&self, env: &mut JNIEnv<'_>) -> jni::errors::Result<()> {
         let activity = unsafe { native::native_activity(self.native_activity) };
         let view = native::rust_view(env, &activity)?;
@@ -464,14 +523,21 @@ pub unsafe extern "C" fn delete_surrounding_in_code_points_text<'local>(
 }
 
 pub unsafe extern "C" fn set_composing_text<'local>(
-    _env: JNIEnv<'local>,
+    mut env: JNIEnv<'local>,
     _rust_view: JObject<'local>,
-    _text: JString<'local>,
-    _new_cursor_position: i32,
+    text: JString<'local>,
+    new_cursor_position: i32,
 ) -> bool {
-    tracing::info!("set composing text");
+    if let Ok(text) = env.get_string(&text) {
+        send_event(Event::Ime(ImeEvent::SetComposingText(
+            text.to_string_lossy().to_string(),
+            new_cursor_position,
+        )));
 
-    false
+        true
+    } else {
+        false
+    }
 }
 
 pub unsafe extern "C" fn send_key_event<'local>(
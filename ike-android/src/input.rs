@@ -1,6 +1,6 @@
 use std::ptr;
 
-use ike_core::{Point, TouchId, WindowId};
+use ike_core::{Key, NamedKey, Point, Tool, ToolKind, TouchId, WindowId};
 
 use crate::{EventLoop, WindowState};
 
@@ -71,6 +71,39 @@ impl<'a, T> EventLoop<'a, T> {
 
         match kind as u32 {
             ndk_sys::AINPUT_EVENT_TYPE_MOTION => self.handle_motion_event(id, event),
+            ndk_sys::AINPUT_EVENT_TYPE_KEY => self.handle_key_event(id, event),
+            _ => false,
+        }
+    }
+
+    fn handle_key_event(&mut self, window_id: WindowId, event: *mut ndk_sys::AInputEvent) -> bool {
+        let keycode = unsafe { ndk_sys::AKeyEvent_getKeyCode(event) as u32 };
+        let action = unsafe { ndk_sys::AKeyEvent_getAction(event) as u32 };
+
+        // other keys arrive through the input connection of the ime
+        if keycode != ndk_sys::AKEYCODE_BACK {
+            return false;
+        }
+
+        let key = Key::Named(NamedKey::GoBack);
+
+        match action {
+            ndk_sys::AKEY_EVENT_ACTION_DOWN => {
+                let repeat = unsafe { ndk_sys::AKeyEvent_getRepeatCount(event) } > 0;
+
+                if !repeat {
+                    self.back_handled =
+                        (self.shell.context.world).key_pressed(window_id, key, false, None, true);
+                }
+
+                self.back_handled
+            }
+
+            ndk_sys::AKEY_EVENT_ACTION_UP => {
+                (self.shell.context.world).key_pressed(window_id, key, false, None, false);
+                self.back_handled
+            }
+
             _ => false,
         }
     }
@@ -96,8 +129,9 @@ impl<'a, T> EventLoop<'a, T> {
 
                 let id = unsafe { ndk_sys::AMotionEvent_getPointerId(event, index) };
                 let touch_id = TouchId::from_u64(id as u64);
+                let tool = unsafe { motion_tool(event, index) };
 
-                self.context.world.touch_down(window_id, touch_id, point)
+                (self.shell.context.world).touch_down(window_id, touch_id, point, tool)
             }
 
             ndk_sys::AMOTION_EVENT_ACTION_UP => {
@@ -110,8 +144,9 @@ impl<'a, T> EventLoop<'a, T> {
 
                 let id = unsafe { ndk_sys::AMotionEvent_getPointerId(event, index) };
                 let touch_id = TouchId::from_u64(id as u64);
+                let tool = unsafe { motion_tool(event, index) };
 
-                self.context.world.touch_up(window_id, touch_id, point)
+                (self.shell.context.world).touch_up(window_id, touch_id, point, tool)
             }
 
             ndk_sys::AMOTION_EVENT_ACTION_MOVE => {
@@ -127,12 +162,16 @@ impl<'a, T> EventLoop<'a, T> {
                     );
 
                     let id = unsafe { ndk_sys::AMotionEvent_getPointerId(event, i) };
-                    let tool = unsafe { ndk_sys::AMotionEvent_getToolType(event, i) };
+                    let tool = unsafe { motion_tool(event, i) };
                     let touch_id = TouchId::from_u64(id as u64);
 
-                    tracing::trace!(index, ?point, tool, "move event");
+                    tracing::trace!(index, ?point, ?tool, "move event");
 
-                    handled |= self.context.world.touch_move(window_id, touch_id, point);
+                    handled |= self
+                        .shell
+                        .context
+                        .world
+                        .touch_move(window_id, touch_id, point, tool);
                 }
 
                 handled
@@ -142,3 +181,27 @@ impl<'a, T> EventLoop<'a, T> {
         }
     }
 }
+
+/// The tool of the pointer at `index` of a motion `event`.
+///
+/// Only styluses report pressure and tilt, for fingers the axes describe the contact area.
+unsafe fn motion_tool(event: *const ndk_sys::AInputEvent, index: usize) -> Tool {
+    let kind = unsafe { ndk_sys::AMotionEvent_getToolType(event, index) };
+
+    let kind = match kind as u32 {
+        ndk_sys::AMOTION_EVENT_TOOL_TYPE_FINGER => return Tool::FINGER,
+        ndk_sys::AMOTION_EVENT_TOOL_TYPE_MOUSE => return Tool::MOUSE,
+        ndk_sys::AMOTION_EVENT_TOOL_TYPE_STYLUS => ToolKind::Stylus,
+        ndk_sys::AMOTION_EVENT_TOOL_TYPE_ERASER => ToolKind::Eraser,
+        _ => return Tool::default(),
+    };
+
+    let axis = |axis: u32| unsafe { ndk_sys::AMotionEvent_getAxisValue(event, axis as i32, index) };
+
+    Tool {
+        kind,
+        pressure: axis(ndk_sys::AMOTION_EVENT_AXIS_PRESSURE).clamp(0.0, 1.0),
+        tilt: axis(ndk_sys::AMOTION_EVENT_AXIS_TILT),
+        azimuth: axis(ndk_sys::AMOTION_EVENT_AXIS_ORIENTATION),
+    }
+}
@@ -1,30 +1,40 @@
 #![warn(clippy::unwrap_used)]
 
 use std::{
+    collections::HashMap,
     ffi::{self, CString},
     fmt, io,
-    pin::Pin,
     ptr::{self, NonNull},
     sync::{
-        Arc, OnceLock,
+        OnceLock,
         mpsc::{Receiver, Sender, channel},
     },
-    time::Instant,
 };
 
+mod back;
 mod callbacks;
+mod clipboard;
 mod context;
+mod dialog;
 mod ime;
 mod input;
+mod lifecycle;
 mod log;
+mod memory;
+mod motion;
 mod native;
 mod window;
 
 use jni::JavaVM;
+pub use lifecycle::saved_state;
 pub use log::MakeAndroidWriter;
 
-use ike_core::{Padding, Signal, Size, WindowId, WindowUpdate, World};
-use ori::{AnyState, AnyView, Proxied, View};
+use ike_core::{
+    BackEvent, ColorScheme, FileDialog, FileDialogResponder, ImeSignal, LayoutDirection, Lifecycle,
+    MemoryPressure, Padding, Size, WindowId, WindowUpdate,
+};
+use ike_shell::{Driver, Shell, ShellEvent, ShellProxy};
+use ori::Proxy as _;
 use parking_lot::Mutex;
 use raw_window_handle::DisplayHandle;
 
@@ -43,6 +53,10 @@ struct ActivityState {
     sender:   Sender<Event>,
     waker:    Mutex<Option<Box<dyn Fn() + Send>>>,
 
+    /// The state restored when the activity was created, replaced by
+    /// [`Builder::save_state`](ike_core::Builder::save_state).
+    saved_state: Mutex<Option<Vec<u8>>>,
+
     ime: Ime,
 }
 
@@ -71,8 +85,12 @@ pub enum Error {
 }
 
 #[doc(hidden)]
-pub fn android_main<T>(native_activity: *mut ffi::c_void, main: fn() -> T)
-where
+pub fn android_main<T>(
+    native_activity: *mut ffi::c_void,
+    saved_state: *mut ffi::c_void,
+    saved_state_size: usize,
+    main: fn() -> T,
+) where
     T: std::process::Termination + 'static,
 {
     std::panic::set_hook(Box::new(|info| {
@@ -96,12 +114,26 @@ where
 
     let (sender, receiver) = channel();
 
+    // the saved state is only valid during this call
+    let saved_state = match saved_state.is_null() || saved_state_size == 0 {
+        true => None,
+        false => Some(unsafe {
+            std::slice::from_raw_parts(
+                saved_state.cast::<u8>(),
+                saved_state_size,
+            )
+            .to_vec()
+        }),
+    };
+
     let state = ActivityState {
         activity: native_activity,
         receiver,
         sender,
         waker: Default::default(),
 
+        saved_state: Mutex::new(saved_state),
+
         ime: Ime::new(),
     };
 
@@ -129,7 +161,7 @@ where
 
 pub fn run<T>(
     data: &mut T,
-    mut build: ike_ori::UiBuilder<T>,
+    build: ike_ori::UiBuilder<T>,
     settings: ike_core::Settings,
 ) -> Result<(), Error> {
     let global_state = GLOBAL_STATE
@@ -169,35 +201,40 @@ pub fn run<T>(
         unsafe { ndk_sys::ALooper_prepare(ndk_sys::ALOOPER_PREPARE_ALLOW_NON_CALLBACKS as i32) };
 
     let proxy = Proxy::new(global_state.sender.clone(), looper);
-    let signaller = Box::new({
+    let shell_proxy = ShellProxy::new({
         let proxy = proxy.clone();
-
-        move |signal| proxy.send(Event::Signal(signal))
+        move |event| proxy.send(Event::Shell(event))
     });
 
-    let mut context = ike_ori::Context {
-        world:     World::new(signaller, settings),
-        proxy:     Arc::new(proxy.clone()),
-        resources: ike_ori::Resources::new(),
-    };
+    let mut shell = Shell::new(
+        data,
+        build,
+        settings,
+        painter,
+        shell_proxy,
+        runtime,
+    );
+
+    let color_scheme = unsafe { color_scheme(global_state.activity) };
+    shell.world().color_scheme_changed(color_scheme);
+
+    let direction = unsafe { layout_direction(global_state.activity) };
+    shell.world().layout_direction_changed(direction);
+
+    let locale = unsafe { locale(global_state.activity) };
+    shell.locales_changed(&Vec::from_iter(locale));
 
     *global_state.waker.lock() = Some(Box::new({
         let proxy = proxy.clone();
         move || proxy.wake()
     }));
 
-    let view = build(data);
-    let (_, state) = view.build(&mut context, data);
+    shell.build();
 
     let jvm = unsafe { JavaVM::from_raw(global_state.activity.as_ref().vm)? };
 
     let mut event_loop = EventLoop {
-        data,
-        build,
-        state,
-
-        runtime,
-        context,
+        shell,
         proxy,
 
         global_state,
@@ -209,32 +246,31 @@ pub fn run<T>(
         is_rendering: false,
         wants_render: false,
         vulkan: vulkan_context,
-        painter,
 
         scale_factor,
 
         input_queue: None,
+        back_handled: false,
+        paused_window: None,
+
+        file_dialogs: HashMap::new(),
+        next_file_dialog: 0,
 
-        animate: None,
         window: WindowState::Pending {
             id:      None,
             updates: Vec::new(),
         },
     };
 
+    event_loop.update_animation_settings();
     event_loop.run();
 
     Ok(())
 }
 
 struct EventLoop<'a, T> {
-    data:  &'a mut T,
-    build: ike_ori::UiBuilder<T>,
-    state: AnyState<ike_ori::Context, T, ori::NoElement>,
-
-    runtime: tokio::runtime::Handle,
-    context: ike_ori::Context,
-    proxy:   Proxy,
+    shell: Shell<'a, T>,
+    proxy: Proxy,
 
     global_state:    &'static ActivityState,
     native_activity: NonNull<ndk_sys::ANativeActivity>,
@@ -245,13 +281,21 @@ struct EventLoop<'a, T> {
     is_rendering:  bool,
     wants_render:  bool,
     vulkan:        ike_skia::vulkan::Context,
-    painter:       ike_skia::SkiaPainter,
     scale_factor:  f32,
 
     input_queue: Option<*mut ndk_sys::AInputQueue>,
 
-    animate: Option<Instant>,
-    window:  WindowState,
+    /// Whether the last press of the back key was handled, its release must be reported the
+    /// same way, as the activity goes back when the key is released.
+    back_handled: bool,
+
+    /// The native window kept while paused, its surface is recreated when resumed.
+    paused_window: Option<*mut ndk_sys::ANativeWindow>,
+
+    file_dialogs:     HashMap<i32, FileDialogResponder>,
+    next_file_dialog: i32,
+
+    window: WindowState,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -265,38 +309,105 @@ enum WindowState {
 }
 
 struct Window {
-    id:      Option<WindowId>,
-    android: *mut ndk_sys::ANativeWindow,
-    surface: ike_skia::vulkan::Surface,
-    focused: bool,
-    width:   u32,
-    height:  u32,
-    insets:  Padding,
+    id:       Option<WindowId>,
+    android:  *mut ndk_sys::ANativeWindow,
+    surface:  ike_skia::vulkan::Surface,
+    focused:  bool,
+    width:    u32,
+    height:   u32,
+    insets:   Padding,
+    keyboard: f32,
+}
+
+/// Read the night mode of the current configuration of `activity`.
+unsafe fn color_scheme(activity: NonNull<ndk_sys::ANativeActivity>) -> Option<ColorScheme> {
+    unsafe {
+        let config = ndk_sys::AConfiguration_new();
+        ndk_sys::AConfiguration_fromAssetManager(config, activity.as_ref().assetManager);
+
+        let night = ndk_sys::AConfiguration_getUiModeNight(config) as u32;
+        ndk_sys::AConfiguration_delete(config);
+
+        match night {
+            ndk_sys::ACONFIGURATION_UI_MODE_NIGHT_YES => Some(ColorScheme::Dark),
+            ndk_sys::ACONFIGURATION_UI_MODE_NIGHT_NO => Some(ColorScheme::Light),
+            _ => None,
+        }
+    }
+}
+
+/// Read the layout direction of the locale of the current configuration of `activity`.
+unsafe fn layout_direction(activity: NonNull<ndk_sys::ANativeActivity>) -> LayoutDirection {
+    unsafe {
+        let config = ndk_sys::AConfiguration_new();
+        ndk_sys::AConfiguration_fromAssetManager(config, activity.as_ref().assetManager);
+
+        let direction = ndk_sys::AConfiguration_getLayoutDirection(config) as u32;
+        ndk_sys::AConfiguration_delete(config);
+
+        match direction {
+            ndk_sys::ACONFIGURATION_LAYOUTDIR_RTL => LayoutDirection::Rtl,
+            _ => LayoutDirection::Ltr,
+        }
+    }
+}
+
+/// The locale of the configuration, e.g. `pt-BR`, Android only exposes the most preferred one.
+unsafe fn locale(activity: NonNull<ndk_sys::ANativeActivity>) -> Option<String> {
+    unsafe {
+        let config = ndk_sys::AConfiguration_new();
+        ndk_sys::AConfiguration_fromAssetManager(config, activity.as_ref().assetManager);
+
+        let mut language = [0u8; 2];
+        let mut country = [0u8; 2];
+        ndk_sys::AConfiguration_getLanguage(config, language.as_mut_ptr().cast());
+        ndk_sys::AConfiguration_getCountry(config, country.as_mut_ptr().cast());
+        ndk_sys::AConfiguration_delete(config);
+
+        if language[0] == 0 {
+            return None;
+        }
+
+        let language = String::from_utf8_lossy(&language);
+
+        Some(match country[0] {
+            0 => language.into_owned(),
+            _ => format!(
+                "{}-{}",
+                language,
+                String::from_utf8_lossy(&country)
+            ),
+        })
+    }
 }
 
 enum Event {
-    Resumed,
+    Lifecycle(Lifecycle),
+    ConfigurationChanged,
+    MemoryPressure(MemoryPressure),
 
     InputQueue(InputQueueEvent),
     Window(WindowEvent),
+    Back(BackEvent),
     Ime(ImeEvent),
-    Signal(Signal),
-    Rebuild,
-    Event(ori::Event),
-    Future(Pin<Box<dyn Future<Output = ()> + Send>>),
+    FileDialog(i32, Vec<String>),
+    Shell(ShellEvent),
 }
 
 impl fmt::Debug for Event {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Resumed => write!(f, "Resumed"),
+            Self::Lifecycle(arg0) => f.debug_tuple("Lifecycle").field(arg0).finish(),
+            Self::ConfigurationChanged => write!(f, "ConfigurationChanged"),
+            Self::MemoryPressure(arg0) => f.debug_tuple("MemoryPressure").field(arg0).finish(),
             Self::InputQueue(arg0) => f.debug_tuple("InputQueue").field(arg0).finish(),
             Self::Window(arg0) => f.debug_tuple("Window").field(arg0).finish(),
+            Self::Back(arg0) => f.debug_tuple("Back").field(arg0).finish(),
             Self::Ime(arg0) => f.debug_tuple("Ime").field(arg0).finish(),
-            Self::Signal(arg0) => f.debug_tuple("Signal").field(arg0).finish(),
-            Self::Rebuild => write!(f, "Rebuild"),
-            Self::Event(arg0) => f.debug_tuple("Event").field(arg0).finish(),
-            Self::Future(_) => f.debug_tuple("Future").finish(),
+            Self::FileDialog(arg0, arg1) => {
+                f.debug_tuple("FileDialog").field(arg0).field(arg1).finish()
+            }
+            Self::Shell(arg0) => f.debug_tuple("Shell").field(arg0).finish(),
         }
     }
 }
@@ -342,92 +453,103 @@ impl<'a, T> EventLoop<'a, T> {
         tracing::trace!(?event, "android event");
 
         match event {
-            Event::Resumed => {}
+            Event::Lifecycle(lifecycle) => self.handle_lifecycle(lifecycle),
+
+            Event::ConfigurationChanged => {
+                let color_scheme = unsafe { color_scheme(self.native_activity) };
+
+                if self.shell.world().color_scheme_changed(color_scheme) {
+                    self.shell.context.proxy.rebuild();
+                }
+
+                let direction = unsafe { layout_direction(self.native_activity) };
+                self.shell.world().layout_direction_changed(direction);
+
+                let locale = unsafe { locale(self.native_activity) };
+                self.shell.locales_changed(&Vec::from_iter(locale));
+            }
 
             Event::InputQueue(event) => self.handle_input_queue_event(event),
             Event::Window(event) => self.handle_window_event(event),
+            Event::Back(event) => self.handle_back_event(event),
             Event::Ime(event) => self.handle_ime_event(event),
-            Event::Signal(signal) => self.handle_signal(signal),
-
-            Event::Rebuild => {
-                let view = (self.build)(self.data);
-                view.rebuild(
-                    (),
-                    &mut self.state,
-                    &mut self.context,
-                    self.data,
-                );
+            Event::FileDialog(request, uris) => self.handle_file_dialog_result(request, uris),
+            Event::MemoryPressure(pressure) => self.handle_memory_pressure(pressure),
+
+            Event::Shell(event) => {
+                if let Err(error) = Driver::handle_event(self, event) {
+                    tracing::error!("{error}");
+                }
             }
+        }
+    }
+}
 
-            Event::Event(mut event) => {
-                let action = Box::<dyn AnyView<_, _, _>>::event(
-                    (),
-                    &mut self.state,
-                    &mut self.context,
-                    self.data,
-                    &mut event,
-                );
+impl<'a, T> Driver<'a, T> for EventLoop<'a, T> {
+    type Error = Error;
 
-                self.context.send_action(action);
-            }
+    fn shell(&mut self) -> &mut Shell<'a, T> {
+        &mut self.shell
+    }
 
-            Event::Future(future) => {
-                self.runtime.spawn(future);
-            }
-        }
+    fn request_redraw(&mut self, _window: WindowId) {
+        self.proxy.send(Event::Window(WindowEvent::Redraw));
     }
 
-    fn handle_signal(&mut self, signal: Signal) {
-        match signal {
-            Signal::RequestRedraw { .. } => {
-                self.proxy.send(Event::Window(WindowEvent::Redraw));
+    fn create_window(&mut self, window_id: WindowId) -> Result<(), Error> {
+        match self.window {
+            WindowState::Pending { ref mut id, .. } if id.is_none() => {
+                *id = Some(window_id);
             }
 
-            Signal::RequestAnimate { start, .. } => {
-                if self.animate.is_none() {
-                    self.animate = Some(start);
-                    self.proxy.send(Event::Window(WindowEvent::Redraw));
-                }
+            WindowState::Open(ref mut window) if window.id.is_none() => {
+                let size = Size::new(
+                    window.width as f32 / self.scale_factor,
+                    window.height as f32 / self.scale_factor,
+                );
+
+                let world = self.shell.world();
+                window.id = Some(window_id);
+                world.window_scaled(window_id, size, self.scale_factor);
+                world.window_focused(window_id, window.focused);
+                world.window_inset(window_id, window.insets);
+                world.window_keyboard_resized(window_id, window.keyboard);
             }
 
-            Signal::Mutate(f) => {
-                f(&mut self.context.world);
+            _ => {
+                tracing::error!("android only supportes one window!");
             }
+        }
 
-            Signal::ClipboardSet(..) => {}
+        Ok(())
+    }
 
-            Signal::CreateWindow(window_id) => match self.window {
-                WindowState::Pending { ref mut id, .. } if id.is_none() => {
-                    *id = Some(window_id);
-                }
+    fn remove_window(&mut self, _window: WindowId) {}
 
-                WindowState::Open(ref mut window) if window.id.is_none() => {
-                    let size = Size::new(
-                        window.width as f32 / self.scale_factor,
-                        window.height as f32 / self.scale_factor,
-                    );
+    fn update_window(&mut self, _window: WindowId, update: WindowUpdate) {
+        if let WindowState::Open(ref mut window) = self.window {
+            window.handle_update(update);
+        }
+    }
 
-                    window.id = Some(window_id);
-                    (self.context.world).window_scaled(window_id, size, self.scale_factor);
-                    (self.context.world).window_focused(window_id, window.focused);
-                    (self.context.world).window_inset(window_id, window.insets);
-                }
+    fn set_clipboard(&mut self, text: String) {
+        EventLoop::set_clipboard(self, text);
+    }
 
-                _ => {
-                    tracing::error!("android only supportes one window!");
-                }
-            },
+    fn paste_clipboard(&mut self, window: WindowId) {
+        EventLoop::paste_clipboard(self, window);
+    }
 
-            Signal::RemoveWindow(..) => {}
+    fn ime_signal(&mut self, ime: ImeSignal) {
+        self.handle_ime_signal(ime);
+    }
 
-            Signal::UpdateWindow(_, update) => {
-                if let WindowState::Open(ref mut window) = self.window {
-                    window.handle_update(update);
-                }
-            }
+    fn show_file_dialog(&mut self, dialog: FileDialog, respond: FileDialogResponder) {
+        EventLoop::show_file_dialog(self, dialog, respond);
+    }
 
-            Signal::Ime(ime) => self.handle_ime_signal(ime),
-        }
+    fn save_state(&mut self, state: Vec<u8>) {
+        EventLoop::save_state(self, state);
     }
 }
 
@@ -438,7 +560,25 @@ impl Window {
             WindowUpdate::Sizing(..) => {}
             WindowUpdate::Visible(..) => {}
             WindowUpdate::Decorated(..) => {}
+            WindowUpdate::Level(..) => {}
+            WindowUpdate::SkipTaskbar(..) => {}
+            WindowUpdate::Blur(..) => {}
             WindowUpdate::Cursor(..) => {}
+            WindowUpdate::CursorGrab(..) => {}
+            WindowUpdate::CursorPosition(..) => {}
+            WindowUpdate::Drag => {}
+            WindowUpdate::DragResize(..) => {}
+            WindowUpdate::Maximized(..) => {}
+            WindowUpdate::Minimized(..) => {}
+            WindowUpdate::Position(..) => {}
+            WindowUpdate::Focus => {}
+            WindowUpdate::Popup(..) => {}
+
+            WindowUpdate::Presentation(presentation) => {
+                if let Err(err) = self.surface.set_presentation(presentation) {
+                    tracing::error!("failed setting presentation: {err}");
+                }
+            }
         }
     }
 }
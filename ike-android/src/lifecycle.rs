@@ -0,0 +1,94 @@
+use std::{ffi, ptr};
+
+use ike_core::{Lifecycle, MemoryPressure};
+
+use crate::{EventLoop, GLOBAL_STATE, WindowState};
+
+unsafe extern "C" {
+    fn malloc(size: usize) -> *mut ffi::c_void;
+}
+
+impl<'a, T> EventLoop<'a, T> {
+    /// Release the surface while the activity isn't resumed, and recreate it once it is.
+    pub fn handle_lifecycle(&mut self, lifecycle: Lifecycle) {
+        tracing::debug!(?lifecycle, "activity lifecycle");
+
+        match lifecycle {
+            Lifecycle::Resumed => {
+                // the scale may have been changed in the system settings while paused
+                self.update_animation_settings();
+
+                // trim requests are mostly made while in the background, there is no event for
+                // when memory is available again
+                if self.shell.world().memory_pressure() != MemoryPressure::None {
+                    self.handle_memory_pressure(MemoryPressure::None);
+                }
+
+                if let Some(android) = self.paused_window.take() {
+                    self.open_window(android);
+
+                    unsafe { ndk_sys::ANativeWindow_release(android) };
+                }
+            }
+
+            Lifecycle::Paused | Lifecycle::Stopped => {
+                // keep the native window alive, so the surface can be recreated on resume,
+                // unless it's destroyed in the meantime
+                if let WindowState::Open(ref window) = self.window {
+                    let android = window.android;
+
+                    unsafe { ndk_sys::ANativeWindow_acquire(android) };
+
+                    self.close_window();
+                    self.paused_window = Some(android);
+                }
+            }
+        }
+
+        self.shell.lifecycle_changed(lifecycle);
+    }
+
+    /// Keep `state` for [`on_save_instance_state`], which is called from another thread.
+    pub fn save_state(&mut self, state: Vec<u8>) {
+        *self.global_state.saved_state.lock() = Some(state);
+    }
+}
+
+/// Get the state saved with [`Builder::save_state`](ike_core::Builder::save_state) before the
+/// application was last killed, if any.
+pub fn saved_state() -> Option<Vec<u8>> {
+    let global_state = GLOBAL_STATE.get()?;
+    global_state.saved_state.lock().clone()
+}
+
+pub unsafe extern "C" fn on_save_instance_state(
+    _activity: *mut ndk_sys::ANativeActivity,
+    out_size: *mut usize,
+) -> *mut ffi::c_void {
+    let global_state = GLOBAL_STATE.get().expect("GLOBAL_STATE has not been set");
+    let saved_state = global_state.saved_state.lock();
+
+    unsafe { *out_size = 0 };
+
+    let Some(ref state) = *saved_state else {
+        return ptr::null_mut();
+    };
+
+    if state.is_empty() {
+        return ptr::null_mut();
+    }
+
+    // the activity frees the state with `free`
+    let data = unsafe { malloc(state.len()) };
+
+    if data.is_null() {
+        return ptr::null_mut();
+    }
+
+    unsafe {
+        ptr::copy_nonoverlapping(state.as_ptr(), data.cast(), state.len());
+        *out_size = state.len();
+    }
+
+    data
+}
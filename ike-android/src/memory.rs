@@ -0,0 +1,44 @@
+use ike_core::MemoryPressure;
+use jni::{JNIEnv, objects::JObject};
+
+use crate::{Event, EventLoop, WindowState, send_event};
+
+// levels of `ComponentCallbacks2.onTrimMemory`
+const TRIM_MEMORY_RUNNING_MODERATE: i32 = 5;
+const TRIM_MEMORY_RUNNING_CRITICAL: i32 = 15;
+const TRIM_MEMORY_UI_HIDDEN: i32 = 20;
+const TRIM_MEMORY_BACKGROUND: i32 = 40;
+
+fn pressure_from_trim_level(level: i32) -> MemoryPressure {
+    match level {
+        // in the background the process is next in line to be killed
+        TRIM_MEMORY_BACKGROUND.. => MemoryPressure::Critical,
+        TRIM_MEMORY_UI_HIDDEN.. => MemoryPressure::Moderate,
+        TRIM_MEMORY_RUNNING_CRITICAL.. => MemoryPressure::Critical,
+        TRIM_MEMORY_RUNNING_MODERATE.. => MemoryPressure::Moderate,
+        _ => MemoryPressure::None,
+    }
+}
+
+impl<'a, T> EventLoop<'a, T> {
+    /// Release memory held by the shell and the surface according to `pressure`.
+    pub fn handle_memory_pressure(&mut self, pressure: MemoryPressure) {
+        tracing::debug!(?pressure, "memory pressure");
+
+        self.shell.memory_pressure_changed(pressure);
+
+        if let WindowState::Open(ref mut window) = self.window {
+            window.surface.set_memory_pressure(pressure);
+        }
+    }
+}
+
+pub unsafe extern "C" fn on_trim_memory<'local>(
+    _env: JNIEnv<'local>,
+    _rust_view: JObject<'local>,
+    level: i32,
+) {
+    send_event(Event::MemoryPressure(
+        pressure_from_trim_level(level),
+    ));
+}
@@ -0,0 +1,62 @@
+use ike_core::AnimationSettings;
+use jni::{JNIEnv, objects::JValue};
+
+use crate::{EventLoop, native};
+
+impl<'a, T> EventLoop<'a, T> {
+    /// Apply the animator duration scale from the developer and accessibility settings, unless
+    /// the app overrides [`AnimationSettings`].
+    pub fn update_animation_settings(&mut self) {
+        if let Some(animation) = self.shell.context.world.settings().animation {
+            AnimationSettings::set(animation);
+            return;
+        }
+
+        let Ok(mut env) = self.jvm.attach_current_thread() else {
+            return;
+        };
+
+        match self.animator_duration_scale(&mut env) {
+            Ok(scale) => AnimationSettings::set(AnimationSettings {
+                scale,
+                // "remove animations" sets the scale to zero
+                reduce_motion: scale == 0.0,
+            }),
+
+            Err(err) => {
+                let _ = env.exception_clear();
+                tracing::warn!(
+                    ?err,
+                    "reading animator duration scale failed"
+                );
+            }
+        }
+    }
+
+    fn animator_duration_scale(&self, env: &mut JNIEnv<'_>) -> jni::errors::Result<f32> {
+        let activity = unsafe { native::native_activity(self.native_activity) };
+
+        let resolver = env
+            .call_method(
+                &activity,
+                "getContentResolver",
+                "()Landroid/content/ContentResolver;",
+                &[],
+            )?
+            .l()?;
+
+        let name = env.new_string("animator_duration_scale")?;
+
+        env.call_static_method(
+            "android/provider/Settings$Global",
+            "getFloat",
+            "(Landroid/content/ContentResolver;Ljava/lang/String;F)F",
+            &[
+                JValue::Object(&resolver),
+                JValue::Object(&name),
+                JValue::Float(1.0),
+            ],
+        )?
+        .f()
+    }
+}
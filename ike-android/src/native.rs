@@ -5,7 +5,7 @@ use jni::{
     objects::{JClass, JObject},
 };
 
-use crate::{ime, window};
+use crate::{back, dialog, ime, memory, window};
 
 pub unsafe fn init(
     jvm: &JavaVM,
@@ -87,6 +87,36 @@ pub unsafe fn init(
                 sig:    "(II)Z".into(),
                 fn_ptr: ime::set_selection as *mut ffi::c_void,
             },
+            jni::NativeMethod {
+                name:   "onFileDialogResultNative".into(),
+                sig:    "(I[Ljava/lang/String;)V".into(),
+                fn_ptr: dialog::on_file_dialog_result as *mut ffi::c_void,
+            },
+            jni::NativeMethod {
+                name:   "onTrimMemoryNative".into(),
+                sig:    "(I)V".into(),
+                fn_ptr: memory::on_trim_memory as *mut ffi::c_void,
+            },
+            jni::NativeMethod {
+                name:   "onBackStartedNative".into(),
+                sig:    "(FIFF)V".into(),
+                fn_ptr: back::on_back_started as *mut ffi::c_void,
+            },
+            jni::NativeMethod {
+                name:   "onBackProgressedNative".into(),
+                sig:    "(FIFF)V".into(),
+                fn_ptr: back::on_back_progressed as *mut ffi::c_void,
+            },
+            jni::NativeMethod {
+                name:   "onBackCancelledNative".into(),
+                sig:    "()V".into(),
+                fn_ptr: back::on_back_cancelled as *mut ffi::c_void,
+            },
+            jni::NativeMethod {
+                name:   "onBackInvokedNative".into(),
+                sig:    "()V".into(),
+                fn_ptr: back::on_back_invoked as *mut ffi::c_void,
+            },
         ],
     )?;
 
@@ -26,93 +26,15 @@ unsafe impl Send for WindowEvent {}
 impl<'a, T> EventLoop<'a, T> {
     pub fn handle_window_event(&mut self, event: WindowEvent) {
         match event {
-            WindowEvent::Created(android) => {
-                let WindowState::Pending {
-                    id,
-                    ref mut updates,
-                } = self.window
-                else {
-                    tracing::error!("android only supports one window");
-                    return;
-                };
-
-                let window_handle = unsafe {
-                    let Some(window) = ptr::NonNull::new(android) else {
-                        tracing::error!("android window was null");
-                        return;
-                    };
-
-                    WindowHandle::borrow_raw(RawWindowHandle::AndroidNdk(
-                        AndroidNdkWindowHandle::new(window.cast()),
-                    ))
-                };
-
-                let width = unsafe { ndk_sys::ANativeWindow_getWidth(android) };
-                let height = unsafe { ndk_sys::ANativeWindow_getHeight(android) };
-
-                tracing::debug!(width, height, "window created");
-
-                let surface = unsafe {
-                    ike_skia::vulkan::Surface::new(
-                        &mut self.vulkan,
-                        DisplayHandle::android(),
-                        window_handle,
-                        width as u32,
-                        height as u32,
-                        false,
-                    )
-                };
-
-                let surface = match surface {
-                    Ok(surface) => surface,
-                    Err(err) => {
-                        tracing::error!("failed creating vulkan context: {err}");
-                        return;
-                    }
-                };
-
-                let mut window = Window {
-                    id,
-                    android,
-                    surface,
-                    focused: false,
-                    width: width as u32,
-                    height: height as u32,
-                    insets: Padding::all(0.0),
-                };
-
-                for update in updates.drain(..) {
-                    window.handle_update(update);
-                }
-
-                self.window = WindowState::Open(window);
-
-                let size = Size::new(
-                    width as f32 / self.scale_factor,
-                    height as f32 / self.scale_factor,
-                );
-
-                if let Some(id) = id {
-                    (self.context.world).window_scaled(id, size, self.scale_factor);
-                }
-            }
+            WindowEvent::Created(android) => self.open_window(android),
 
             WindowEvent::Destroyed => {
-                match self.window {
-                    WindowState::Open(ref window) => {
-                        self.window = WindowState::Pending {
-                            id:      window.id,
-                            updates: Vec::new(),
-                        };
-
-                        // apparently, when the surface is recreated, all the recorded images become
-                        // invalidated. for this reason, we clear the recorder to ensure all widgets are
-                        // redraw. what a funny quirk!
-                        self.context.world.recorder_mut().clear();
-                    }
+                // the window kept while paused is gone as well
+                if let Some(android) = self.paused_window.take() {
+                    unsafe { ndk_sys::ANativeWindow_release(android) };
+                }
 
-                    WindowState::Pending { .. } => {}
-                };
+                self.close_window();
             }
 
             WindowEvent::Redraw => {
@@ -126,12 +48,9 @@ impl<'a, T> EventLoop<'a, T> {
                         return;
                     };
 
-                    if let Some(animate) = self.animate.take() {
-                        let delta_time = animate.elapsed();
-                        self.context.world.animate(id, delta_time);
-                    }
+                    self.shell.animate(id);
 
-                    let Some(win) = self.context.world.get_window(id) else {
+                    let Some(win) = self.shell.context.world.get_window(id) else {
                         tracing::error!("window not registered with ike");
                         return;
                     };
@@ -149,18 +68,18 @@ impl<'a, T> EventLoop<'a, T> {
                     self.is_rendering = true;
 
                     let result = window.surface.draw(
-                        &mut self.painter,
-                        win.color(),
+                        &mut self.shell.painter,
+                        win.color().fade(win.opacity()),
                         self.scale_factor,
                         || {},
-                        |canvas| self.context.world.draw(id, canvas),
+                        |canvas| self.shell.context.world.draw(id, canvas),
                     );
 
                     if let Err(err) = result {
                         tracing::error!("draw failed: {err}");
                     }
 
-                    self.painter.cleanup();
+                    self.shell.painter.cleanup();
                 }
             }
 
@@ -188,7 +107,7 @@ impl<'a, T> EventLoop<'a, T> {
                         height as f32 / self.scale_factor,
                     );
 
-                    self.context.world.window_resized(id, size);
+                    self.shell.context.world.window_resized(id, size);
                 }
             }
 
@@ -207,20 +126,27 @@ impl<'a, T> EventLoop<'a, T> {
                 if let WindowState::Open(ref mut window) = self.window {
                     let mut insets = Padding::all(0.0);
 
-                    insets.left += system_bars.left + ime.left + cutout.left;
-                    insets.top += system_bars.top + ime.top + cutout.top;
-                    insets.right += system_bars.right + ime.right + cutout.right;
-                    insets.bottom += system_bars.bottom.max(ime.bottom) + cutout.bottom;
+                    insets.left += system_bars.left + cutout.left;
+                    insets.top += system_bars.top + cutout.top;
+                    insets.right += system_bars.right + cutout.right;
+                    insets.bottom += system_bars.bottom + cutout.bottom;
 
                     insets.left /= self.scale_factor;
                     insets.top /= self.scale_factor;
                     insets.right /= self.scale_factor;
                     insets.bottom /= self.scale_factor;
 
+                    // the keyboard always slides in from the bottom
+                    let keyboard = ime.bottom / self.scale_factor;
+
                     window.insets = insets;
+                    window.keyboard = keyboard;
 
                     if let Some(id) = window.id {
-                        (self.context.world).window_inset(id, insets);
+                        let world = &mut self.shell.context.world;
+
+                        world.window_inset(id, insets);
+                        world.window_keyboard_resized(id, keyboard);
                     }
                 }
             }
@@ -235,7 +161,7 @@ impl<'a, T> EventLoop<'a, T> {
                         return;
                     };
 
-                    self.context.world.window_focused(id, focused);
+                    self.shell.context.world.window_focused(id, focused);
                 }
             }
 
@@ -251,6 +177,116 @@ impl<'a, T> EventLoop<'a, T> {
             }
         }
     }
+
+    /// Create the surface of `android` and show the window on it.
+    pub fn open_window(&mut self, android: *mut ndk_sys::ANativeWindow) {
+        let WindowState::Pending {
+            id,
+            ref mut updates,
+        } = self.window
+        else {
+            tracing::error!("android only supports one window");
+            return;
+        };
+
+        let window_handle = unsafe {
+            let Some(window) = ptr::NonNull::new(android) else {
+                tracing::error!("android window was null");
+                return;
+            };
+
+            WindowHandle::borrow_raw(RawWindowHandle::AndroidNdk(
+                AndroidNdkWindowHandle::new(window.cast()),
+            ))
+        };
+
+        let width = unsafe { ndk_sys::ANativeWindow_getWidth(android) };
+        let height = unsafe { ndk_sys::ANativeWindow_getHeight(android) };
+
+        tracing::debug!(width, height, "window created");
+
+        let surface = unsafe {
+            ike_skia::vulkan::Surface::new(
+                &mut self.vulkan,
+                DisplayHandle::android(),
+                window_handle,
+                width as u32,
+                height as u32,
+                false,
+            )
+        };
+
+        let mut surface = match surface {
+            Ok(surface) => surface,
+            Err(err) => {
+                tracing::error!("failed creating vulkan context: {err}");
+                return;
+            }
+        };
+
+        // the surface is recreated when the app is resumed, keep the presentation
+        if let Some(id) = id
+            && let Some(desc) = self.shell.context.world.get_window(id)
+            && let Err(err) = surface.set_presentation(desc.presentation())
+        {
+            tracing::error!("failed setting presentation: {err}");
+        }
+
+        surface.set_memory_pressure(self.shell.context.world.memory_pressure());
+
+        let mut window = Window {
+            id,
+            android,
+            surface,
+            focused: false,
+            width: width as u32,
+            height: height as u32,
+            insets: Padding::all(0.0),
+            keyboard: 0.0,
+        };
+
+        for update in updates.drain(..) {
+            window.handle_update(update);
+        }
+
+        self.window = WindowState::Open(window);
+
+        let size = Size::new(
+            width as f32 / self.scale_factor,
+            height as f32 / self.scale_factor,
+        );
+
+        if let Some(id) = id {
+            (self.shell.context.world).window_scaled(id, size, self.scale_factor);
+            (self.shell.context.world).window_occluded(id, false);
+        }
+    }
+
+    /// Release the surface of the window, it's kept pending until a new one is opened.
+    pub fn close_window(&mut self) {
+        match self.window {
+            WindowState::Open(ref window) => {
+                let id = window.id;
+
+                self.window = WindowState::Pending {
+                    id,
+                    updates: Vec::new(),
+                };
+
+                // apparently, when the surface is recreated, all the recorded images become
+                // invalidated. for this reason, we clear the recorder to ensure all widgets are
+                // redraw. what a funny quirk!
+                self.shell.context.world.recorder_mut().clear();
+
+                // without a surface nothing is shown, so don't keep animating
+                if let Some(id) = id {
+                    self.shell.context.world.window_occluded(id, true);
+                }
+            }
+
+            WindowState::Pending { .. } => {}
+        };
+    }
 }
 
 unsafe extern "C" fn frame_callback(_frame_time_nanos: i64, data: *mut ffi::c_void) {
@@ -0,0 +1,205 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    ops::{Add, AddAssign, Sub},
+    time::Instant,
+};
+
+use crate::FrameTimings;
+
+thread_local! {
+    static THREAD_ALLOCATIONS: Cell<Allocations> = const { Cell::new(Allocations::ZERO) };
+}
+
+/// A number of allocations, and the total number of bytes allocated by them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+}
+
+impl Allocations {
+    pub const ZERO: Self = Self { count: 0, bytes: 0 };
+
+    /// The allocations made by the current thread so far.
+    ///
+    /// Always zero unless [`CountingAllocator`] is the global allocator.
+    pub fn current_thread() -> Self {
+        THREAD_ALLOCATIONS.try_with(Cell::get).unwrap_or_default()
+    }
+
+    fn record(bytes: usize) {
+        // the thread local may already be destroyed while the thread is exiting
+        let _ = THREAD_ALLOCATIONS.try_with(|allocations| {
+            let mut current = allocations.get();
+            current.count += 1;
+            current.bytes += bytes as u64;
+            allocations.set(current);
+        });
+    }
+}
+
+impl Add for Allocations {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            count: self.count + rhs.count,
+            bytes: self.bytes + rhs.bytes,
+        }
+    }
+}
+
+impl AddAssign for Allocations {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Allocations {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            count: self.count.saturating_sub(rhs.count),
+            bytes: self.bytes.saturating_sub(rhs.bytes),
+        }
+    }
+}
+
+/// A global allocator counting the allocations of each thread, reported per [`FramePhase`] by
+/// [`World::frame_allocations`](crate::World::frame_allocations).
+///
+/// Counting is opt-in, usually only in debug builds:
+///
+/// ```ignore
+/// #[cfg(debug_assertions)]
+/// #[global_allocator]
+/// static ALLOCATOR: ike::CountingAllocator = ike::CountingAllocator::new();
+/// ```
+pub struct CountingAllocator<A = System> {
+    inner: A,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self { inner: System }
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> CountingAllocator<A> {
+    /// Count the allocations made with `inner`.
+    pub const fn wrap(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Allocations::record(layout.size());
+        unsafe { self.inner.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Allocations::record(layout.size());
+        unsafe { self.inner.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Allocations::record(new_size);
+        unsafe { self.inner.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.inner.dealloc(ptr, layout) }
+    }
+}
+
+/// A phase of producing a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FramePhase {
+    Animate,
+    Layout,
+    Compose,
+    Hover,
+    Record,
+    Draw,
+    Sample,
+}
+
+impl FramePhase {
+    pub const ALL: [Self; 7] = [
+        Self::Animate,
+        Self::Layout,
+        Self::Compose,
+        Self::Hover,
+        Self::Record,
+        Self::Draw,
+        Self::Sample,
+    ];
+}
+
+/// The allocations made in each [`FramePhase`] of a frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameAllocations {
+    phases: [Allocations; FramePhase::ALL.len()],
+}
+
+impl FrameAllocations {
+    pub fn get(&self, phase: FramePhase) -> Allocations {
+        self.phases[phase as usize]
+    }
+
+    /// The allocations made in every phase of the frame.
+    pub fn total(&self) -> Allocations {
+        self.phases.iter().fold(Allocations::ZERO, |total, &phase| {
+            total + phase
+        })
+    }
+
+    pub(crate) fn add(&mut self, phase: FramePhase, allocations: Allocations) {
+        self.phases[phase as usize] += allocations;
+    }
+}
+
+/// Attributes the allocations made on the current thread, and the time taken, to consecutive
+/// [`FramePhase`]s.
+pub(crate) struct PhaseMeter {
+    mark:    Allocations,
+    instant: Instant,
+    frame:   FrameAllocations,
+    timings: FrameTimings,
+}
+
+impl PhaseMeter {
+    pub(crate) fn start() -> Self {
+        Self {
+            mark:    Allocations::current_thread(),
+            instant: Instant::now(),
+            frame:   FrameAllocations::default(),
+            timings: FrameTimings::default(),
+        }
+    }
+
+    /// End `phase`, attributing the allocations made and the time taken since the previous
+    /// phase ended to it.
+    pub(crate) fn end(&mut self, phase: FramePhase) {
+        let now = Allocations::current_thread();
+        self.frame.add(phase, now - self.mark);
+        self.mark = now;
+
+        let instant = Instant::now();
+        self.timings.add(phase, instant - self.instant);
+        self.instant = instant;
+    }
+
+    pub(crate) fn finish(self) -> (FrameAllocations, FrameTimings) {
+        (self.frame, self.timings)
+    }
+}
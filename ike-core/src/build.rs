@@ -1,6 +1,9 @@
+use std::path::PathBuf;
+
 use crate::{
-    AnyWidget, AnyWidgetId, Color, GetError, Update, Widget, WidgetId, WidgetMut, WidgetRef,
-    WindowId, WindowSizing, World, passes,
+    AnyWidget, AnyWidgetId, Color, FileDialog, GetError, PageSetup, Point, PopupAnchor,
+    Presentation, Signal, Transition, Update, Widget, WidgetId, WidgetMut, WidgetRef, WindowId,
+    WindowLevel, WindowSizing, World, passes,
 };
 
 pub trait Builder {
@@ -25,6 +28,9 @@ pub trait Builder {
         world.widgets.get_mut(&mut world.state, id)
     }
 
+    /// Add `widget` to the world, in debug builds the caller is recorded, see
+    /// [`World::widget_location`].
+    #[track_caller]
     fn build_widget<T>(&mut self, widget: T) -> WidgetBuilder<'_, T>
     where
         Self: Sized,
@@ -157,6 +163,32 @@ pub trait Builder {
             .is_ok_and(|child| child.cx.parent() == Some(parent))
     }
 
+    /// Batch the invalidations of the mutations made in `f`.
+    ///
+    /// Requests for layout and draw made inside `f` are propagated to the ancestors of each
+    /// mutated widget once, when the outermost transaction ends, instead of on every call.
+    fn transaction<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R
+    where
+        Self: Sized,
+    {
+        self.world().state.begin_transaction();
+        let output = f(self);
+
+        let world = self.world_mut();
+
+        if let Some(transaction) = world.state.end_transaction() {
+            for widget in transaction.widgets {
+                passes::hierarchy::propagate_down(&world.widgets, widget);
+            }
+
+            for window in transaction.windows {
+                world.state.request_redraw(window);
+            }
+        }
+
+        output
+    }
+
     fn set_window_base_layer(&mut self, window: WindowId, contents: impl AnyWidgetId)
     where
         Self: Sized,
@@ -184,10 +216,156 @@ pub trait Builder {
         state.set_window_decorated(window, decorated);
     }
 
+    /// Set the window owning `window`, see [`Window::parent`](crate::Window::parent).
+    ///
+    /// The platform only places windows over their parent when the parent is set before the
+    /// window is shown, i.e. in the same build as the window is created.
+    fn set_window_parent(&mut self, window: WindowId, parent: Option<WindowId>) {
+        let state = &mut self.world_mut().state;
+        state.set_window_parent(window, parent);
+    }
+
+    /// Set whether `window` blocks input to its parent while it's visible.
+    fn set_window_modal(&mut self, window: WindowId, modal: bool) {
+        let state = &mut self.world_mut().state;
+        state.set_window_modal(window, modal);
+    }
+
+    /// Make `window` a popup placed next to `anchor` in its parent, e.g. a menu or tooltip,
+    /// see [`Window::popup`](crate::Window::popup).
+    ///
+    /// Like parents, popups must be set in the same build as the window is created.
+    fn set_window_popup(&mut self, window: WindowId, anchor: Option<PopupAnchor>) {
+        let state = &mut self.world_mut().state;
+        state.set_window_popup(window, anchor);
+    }
+
+    /// Set the opacity of `window`, applied to both its color and its contents.
+    fn set_window_opacity(&mut self, window: WindowId, opacity: f32) {
+        let state = &mut self.world_mut().state;
+        state.set_window_opacity(window, opacity);
+    }
+
+    /// Set where `window` is stacked relative to the windows of other applications.
+    fn set_window_level(&mut self, window: WindowId, level: WindowLevel) {
+        let state = &mut self.world_mut().state;
+        state.set_window_level(window, level);
+    }
+
+    fn set_window_skip_taskbar(&mut self, window: WindowId, skip_taskbar: bool) {
+        let state = &mut self.world_mut().state;
+        state.set_window_skip_taskbar(window, skip_taskbar);
+    }
+
+    /// Blur what is behind `window`, where the platform supports it.
+    fn set_window_blurred(&mut self, window: WindowId, blurred: bool) {
+        let state = &mut self.world_mut().state;
+        state.set_window_blurred(window, blurred);
+    }
+
+    /// Replace content marked with [`Redact`](crate::widgets::Redact) by placeholders in every
+    /// window, see [`World::set_redacted`].
+    fn set_redacted(&mut self, redacted: bool) {
+        self.world_mut().set_redacted(redacted);
+    }
+
+    /// Replace content marked with [`Redact`](crate::widgets::Redact) by placeholders in
+    /// `window`, see [`World::set_redacted`].
+    fn set_window_redacted(&mut self, window: WindowId, redacted: bool) {
+        (self.world_mut()).set_window_redacted(window, redacted);
+    }
+
+    /// Draw `window` with `scale` pixels per unit instead of the scale factor of the platform,
+    /// see [`World::set_window_scale`].
+    fn set_window_scale(&mut self, window: WindowId, scale: Option<f32>) {
+        (self.world_mut()).set_window_scale(window, scale);
+    }
+
+    fn set_window_maximized(&mut self, window: WindowId, maximized: bool) {
+        let state = &mut self.world_mut().state;
+        state.set_window_maximized(window, maximized);
+    }
+
+    /// Move `window` to `position` on the desktop, where the platform supports it.
+    fn set_window_position(&mut self, window: WindowId, position: Point) {
+        let state = &mut self.world_mut().state;
+        state.set_window_position(window, position);
+    }
+
     fn set_window_color(&mut self, window: WindowId, color: Color) {
         let state = &mut self.world_mut().state;
         state.set_window_color(window, color);
     }
+
+    /// Set the transition used when the color of `window` changes.
+    fn set_window_color_transition(&mut self, window: WindowId, transition: Transition) {
+        let state = &mut self.world_mut().state;
+        state.set_window_color_transition(window, transition);
+    }
+
+    /// Set how the frames of `window` are buffered, see [`Presentation`].
+    fn set_window_presentation(&mut self, window: WindowId, presentation: Presentation) {
+        let state = &mut self.world_mut().state;
+        state.set_window_presentation(window, presentation);
+    }
+
+    /// Set the text contents of the clipboard.
+    fn set_clipboard(&mut self, contents: String) {
+        let state = &mut self.world_mut().state;
+        state.emit_signal(Signal::ClipboardSet(contents));
+    }
+
+    /// Set the state saved by the platform, for when the application is killed in the
+    /// background and later restarted, replacing the state saved before.
+    ///
+    /// This is only supported on android, where the state is restored with
+    /// `ike_android::saved_state`, and should be kept small.
+    fn save_state(&mut self, state: Vec<u8>) {
+        let world_state = &mut self.world_mut().state;
+        world_state.emit_signal(Signal::SaveState(state));
+    }
+
+    /// Request that `widget` is printed, see [`World::print`].
+    fn print(&mut self, widget: impl AnyWidgetId, setup: PageSetup)
+    where
+        Self: Sized,
+    {
+        let state = &mut self.world_mut().state;
+        state.print(widget.upcast(), setup);
+    }
+
+    /// Show the platform file dialog, `respond` is called with the picked paths once it's
+    /// closed, or with none if it was cancelled.
+    fn show_file_dialog(
+        &mut self,
+        dialog: FileDialog,
+        respond: impl FnOnce(Vec<PathBuf>) + Send + 'static,
+    ) where
+        Self: Sized,
+    {
+        let state = &mut self.world_mut().state;
+        state.show_file_dialog(dialog, Box::new(respond));
+    }
+
+    /// Render `widget` as an SVG document, at the size it was last laid out with, `respond`
+    /// is called with the document once the backend has rendered it.
+    fn render_svg(
+        &mut self,
+        widget: impl AnyWidgetId,
+        respond: impl FnOnce(String) + Send + 'static,
+    ) where
+        Self: Sized,
+    {
+        let state = &mut self.world_mut().state;
+        state.render_svg(widget.upcast(), Box::new(respond));
+    }
+
+    /// Load a font, as the family `alias` if set, text is laid out again once the backend has
+    /// loaded it.
+    fn load_font(&mut self, bytes: Vec<u8>, alias: Option<String>) {
+        let state = &mut self.world_mut().state;
+        state.emit_signal(Signal::LoadFont { bytes, alias });
+    }
 }
 
 impl<T> Builder for &mut T
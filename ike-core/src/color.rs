@@ -656,6 +656,23 @@ impl Color {
         l
     }
 
+    /// Get the relative luminance, as defined by WCAG, ignoring alpha.
+    pub fn relative_luminance(self) -> f32 {
+        let r = Self::to_linear(self.r);
+        let g = Self::to_linear(self.g);
+        let b = Self::to_linear(self.b);
+
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Get the contrast ratio between two colors, as defined by WCAG, between `1.0` and `21.0`.
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
     /// Linearly interpolate between two colors.
     ///
     /// This uses a fractor `t` between `0.0` and `1.0`.
@@ -4,9 +4,12 @@ use std::{
 };
 
 use crate::{
-    Affine, AnyWidget, AnyWidgetId, Clip, CursorIcon, GetError, ImeSignal, Painter, Paragraph,
-    Point, Rect, Settings, Signal, Size, Space, Svg, TextLayoutLine, WidgetId, WidgetMut,
-    WidgetRef, Window, WindowId, World, passes,
+    Affine, AnyWidget, AnyWidgetId, Canvas, Clip, CornerRadius, Cursor, CursorGrab, Curve,
+    GetError, ImePurpose, ImeSignal, LayoutDirection, Painter, Paragraph, Point, Rect, ResizeEdge,
+    Settings, Signal, Size, Space, Svg, TextLayoutLine, WidgetId, WidgetMut, WidgetRef, Window,
+    WindowId, World,
+    hero::HeroCoordinator,
+    passes,
     widget::{WidgetHierarchy, WidgetState},
     world::{Widgets, WorldState},
 };
@@ -19,6 +22,12 @@ pub(crate) enum FocusUpdate {
     Previous,
 }
 
+pub(crate) enum GrabUpdate {
+    None,
+    Grab(WidgetId),
+    Release(WidgetId),
+}
+
 pub struct RefCx<'a> {
     pub(crate) widgets:   &'a Widgets,
     pub(crate) world:     &'a WorldState,
@@ -39,6 +48,7 @@ pub struct EventCx<'a> {
     pub(crate) state:     &'a mut WidgetState,
     pub(crate) hierarchy: &'a WidgetHierarchy,
     pub(crate) focus:     &'a mut FocusUpdate,
+    pub(crate) grab:      &'a mut GrabUpdate,
 }
 
 pub struct UpdateCx<'a> {
@@ -75,7 +85,7 @@ pub struct DrawCx<'a> {
 impl MutCx<'_> {
     pub fn request_compose(&mut self) {
         self.hierarchy.request_compose();
-        passes::hierarchy::propagate_down(self.widgets, self.id());
+        self.propagate_request();
 
         if let Some(window) = self.hierarchy.window {
             self.world.request_redraw(window);
@@ -84,7 +94,7 @@ impl MutCx<'_> {
 
     pub fn request_animate(&mut self) {
         self.hierarchy.request_animate();
-        passes::hierarchy::propagate_down(self.widgets, self.id());
+        self.propagate_request();
 
         if let Some(window) = self.hierarchy.window {
             self.world.request_animate(window);
@@ -93,7 +103,7 @@ impl MutCx<'_> {
 
     pub fn request_layout(&mut self) {
         self.hierarchy.request_layout();
-        passes::hierarchy::propagate_down(self.widgets, self.id());
+        self.propagate_request();
 
         if let Some(window) = self.hierarchy.window {
             self.world.request_redraw(window);
@@ -102,13 +112,20 @@ impl MutCx<'_> {
 
     pub fn request_draw(&mut self) {
         self.hierarchy.request_draw();
-        passes::hierarchy::propagate_down(self.widgets, self.id());
+        self.propagate_request();
 
         if let Some(window) = self.hierarchy.window {
             self.world.request_redraw(window);
         }
     }
 
+    fn propagate_request(&mut self) {
+        // inside a transaction the request is propagated once, when it ends
+        if !self.world.defer_propagation(self.id()) {
+            passes::hierarchy::propagate_down(self.widgets, self.id());
+        }
+    }
+
     pub fn set_subpixel(&mut self, subpixel: bool) {
         if self.is_subpixel() != subpixel {
             self.state.is_subpixel = subpixel;
@@ -116,8 +133,28 @@ impl MutCx<'_> {
         }
     }
 
-    pub fn set_cursor(&mut self, cursor: CursorIcon) {
-        self.state.cursor = cursor;
+    /// Rasterize the widget and its descendants once, and draw the image until one of them
+    /// requests a draw, see [`Cache`](crate::widgets::Cache).
+    ///
+    /// The image is still released when it goes unused, or the recorder runs out of memory,
+    /// see [`RecordSettings`](crate::RecordSettings).
+    pub fn set_cached(&mut self, is_cached: bool) {
+        if self.is_cached() != is_cached {
+            self.state.is_cached = is_cached;
+            self.request_draw();
+        }
+    }
+
+    /// Set whether the widget accepts focus, overriding [`Widget::accepts_focus`] for widgets
+    /// only focusable with some content.
+    ///
+    /// [`Widget::accepts_focus`]: crate::Widget::accepts_focus
+    pub fn set_accepts_focus(&mut self, accepts_focus: bool) {
+        self.hierarchy.set_accepts_focus(accepts_focus);
+    }
+
+    pub fn set_cursor(&mut self, cursor: impl Into<Cursor>) {
+        self.state.cursor = cursor.into();
     }
 
     pub(crate) fn as_update_cx(&mut self) -> UpdateCx<'_> {
@@ -189,9 +226,85 @@ impl EventCx<'_> {
         *self.focus = FocusUpdate::Previous;
     }
 
+    /// Route all pointer, touch and key input in the window to this widget and its descendants,
+    /// regardless of what is under the pointer or focused.
+    ///
+    /// The grab is released with [`EventCx::release_input`], when escape is pressed and not
+    /// handled, or when the widget is removed. [`Update::Grabbed`](crate::Update::Grabbed) is
+    /// sent when the grab is gained or lost.
+    pub fn grab_input(&mut self) {
+        *self.grab = GrabUpdate::Grab(self.id());
+    }
+
+    /// Release the input grab, if held by this widget.
+    pub fn release_input(&mut self) {
+        *self.grab = GrabUpdate::Release(self.id());
+    }
+
     pub fn set_clipboard(&mut self, contents: String) {
         self.world.emit_signal(Signal::ClipboardSet(contents));
     }
+
+    /// Hold the cursor in the window, e.g. [`CursorGrab::Locked`] while dragging a slider.
+    ///
+    /// The grab is kept until set to [`CursorGrab::None`] again.
+    pub fn set_cursor_grab(&mut self, grab: CursorGrab) {
+        if let Some(window) = self.window() {
+            self.world.set_window_cursor_grab(window, grab);
+        }
+    }
+
+    /// Move the cursor to `position` in window coordinates.
+    pub fn set_cursor_position(&mut self, position: Point) {
+        if let Some(window) = self.window() {
+            self.world.set_window_cursor_position(window, position);
+        }
+    }
+
+    /// Move the window with the pointer, e.g. when a custom titlebar is pressed.
+    ///
+    /// Must be called while the primary button is pressed.
+    pub fn begin_window_drag(&mut self) {
+        if let Some(window) = self.window() {
+            self.world.begin_window_drag(window);
+        }
+    }
+
+    /// Resize the window from `edge` with the pointer, e.g. for windows without decorations.
+    ///
+    /// Must be called while the primary button is pressed.
+    pub fn begin_window_resize(&mut self, edge: ResizeEdge) {
+        if let Some(window) = self.window() {
+            self.world.begin_window_resize(window, edge);
+        }
+    }
+
+    pub fn set_window_maximized(&mut self, maximized: bool) {
+        if let Some(window) = self.window() {
+            self.world.set_window_maximized(window, maximized);
+        }
+    }
+
+    pub fn minimize_window(&mut self) {
+        if let Some(window) = self.window() {
+            self.world.set_window_minimized(window, true);
+        }
+    }
+
+    /// Close the window, as if its close button was pressed.
+    pub fn close_window(&mut self) {
+        if let Some(window) = self.window() {
+            self.world.close_window(window);
+        }
+    }
+
+    /// Paste the contents of the clipboard into the focused widget, they arrive as a
+    /// [`TextEvent::Paste`](crate::TextEvent::Paste) once the platform has read them.
+    pub fn request_paste(&mut self) {
+        if let Some(window) = self.window() {
+            self.world.emit_signal(Signal::ClipboardPaste(window));
+        }
+    }
 }
 
 impl LayoutCx<'_> {
@@ -269,6 +382,21 @@ impl LayoutCx<'_> {
     pub fn set_clip(&mut self, rect: impl Into<Option<Clip>>) {
         self.state.clip = rect.into();
     }
+
+    /// Clip the widget, and its children, to `rect` with rounded corners.
+    pub fn set_clip_rrect(&mut self, rect: Rect, radius: CornerRadius) {
+        self.set_clip(Clip::Rect(rect, radius));
+    }
+
+    /// Clip the widget, and its children, to the inside of `curve`.
+    pub fn set_clip_curve(&mut self, curve: Curve) {
+        self.set_clip(Clip::Curve(curve));
+    }
+
+    /// Set how far past its size the widget draws, e.g. to cast a shadow.
+    pub fn set_overflow(&mut self, overflow: f32) {
+        self.state.overflow = overflow;
+    }
 }
 
 impl LayoutCx<'_> {
@@ -286,6 +414,14 @@ impl ComposeCx<'_> {
         self.scale
     }
 
+    pub fn request_animate(&mut self) {
+        self.hierarchy.request_animate();
+
+        if let Some(window) = self.hierarchy.window {
+            self.world.request_animate(window);
+        }
+    }
+
     pub fn place_child(&mut self, child: impl AnyWidgetId, transform: impl Into<Affine>) {
         passes::compose::place_child(self, child.upcast(), transform.into());
     }
@@ -303,6 +439,29 @@ impl ComposeCx<'_> {
     pub fn request_draw(&mut self) {
         self.hierarchy.request_draw();
     }
+
+    /// Set the opacity the widget, and its descendants, are drawn with.
+    pub fn set_opacity(&mut self, opacity: f32) {
+        let opacity = opacity.clamp(0.0, 1.0);
+
+        if self.state.opacity != opacity {
+            self.state.opacity = opacity;
+            self.request_draw();
+        }
+    }
+}
+
+impl DrawCx<'_> {
+    /// Draw `widget`, and its descendants, in its own coordinate space.
+    ///
+    /// Recordings are not used, and the draw state of `widget` is left untouched, which makes
+    /// this suitable for drawing previews of widgets elsewhere in the tree. `widget` must not be
+    /// an ancestor of the widget being drawn.
+    pub fn draw_widget(&mut self, widget: impl AnyWidgetId, canvas: &mut dyn Canvas) {
+        if let Ok(mut widget) = self.widgets.get_mut(self.world, widget.upcast()) {
+            passes::draw::export_widget(&mut widget, canvas);
+        }
+    }
 }
 
 macro_rules! impl_contexts {
@@ -321,6 +480,16 @@ macro_rules! impl_contexts {
     }
 }
 
+impl_contexts! {
+    MutCx<'_>,
+    UpdateCx<'_>,
+    ComposeCx<'_> {
+        pub(crate) fn heroes(&mut self) -> &mut HeroCoordinator {
+            &mut self.world.heroes
+        }
+    }
+}
+
 impl_contexts! {
     MutCx<'_>,
     EventCx<'_>,
@@ -374,8 +543,23 @@ impl_contexts! {
             }
         }
 
-        pub fn set_cursor(&mut self, cursor: CursorIcon) {
-            self.state.cursor = cursor;
+        pub fn set_cursor(&mut self, cursor: impl Into<Cursor>) {
+            self.state.cursor = cursor.into();
+        }
+
+        /// Request the color of the pixel at `point` in window coordinates, as it is drawn next
+        /// frame, delivered with [`Update::Sampled`](crate::Update::Sampled).
+        pub fn request_sample(&mut self, point: Point) {
+            let id = self.id();
+
+            if let Some(window) = self.hierarchy.window
+                && let Some(window) = self.world.window_mut(window)
+            {
+                window.samples.push((id, point));
+
+                let window = window.id;
+                self.world.request_redraw(window);
+            }
         }
     }
 }
@@ -447,6 +631,15 @@ impl_contexts! {
                 composing,
             }));
         }
+
+        pub fn set_ime_purpose(&mut self, purpose: ImePurpose) {
+            if !self.is_focused() || !self.hierarchy.accepts_text() {
+                tracing::warn!("`set_ime_purpose` can only be called on a focused view that accepts text");
+                return;
+            }
+
+            self.world.emit_signal(Signal::Ime(ImeSignal::Purpose(purpose)));
+        }
     }
 }
 
@@ -536,6 +729,18 @@ impl_contexts! {
             self.get_window().is_some_and(|window| window.is_focused())
         }
 
+        /// Whether content marked with [`Redact`](crate::widgets::Redact) should be replaced by
+        /// placeholders, either in every window or in this one.
+        pub fn is_redacted(&self) -> bool {
+            self.world.is_redacted || self.get_window().is_some_and(|window| window.is_redacted())
+        }
+
+        /// The direction the widget lays out its contents in horizontally, inherited from the
+        /// closest ancestor that sets one, or the direction of the language of the platform.
+        pub fn layout_direction(&self) -> LayoutDirection {
+            passes::hierarchy::layout_direction(self.widgets, self.world, self.hierarchy)
+        }
+
         pub fn settings(&self) -> &Settings {
             &self.world.settings
         }
@@ -544,6 +749,10 @@ impl_contexts! {
             self.state.is_subpixel
         }
 
+        pub fn is_cached(&self) -> bool {
+            self.state.is_cached
+        }
+
         pub fn is_hovered(&self) -> bool {
             self.hierarchy.is_hovered()
         }
@@ -580,8 +789,12 @@ impl_contexts! {
             self.state.clip.as_ref()
         }
 
-        pub fn cursor(&self) -> CursorIcon{
-            self.state.cursor
+        pub fn cursor(&self) -> Cursor {
+            self.state.cursor.clone()
+        }
+
+        pub fn test_id(&self) -> Option<&str> {
+            self.state.test_id.as_deref()
         }
     }
 }
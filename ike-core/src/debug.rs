@@ -13,14 +13,100 @@ pub struct DebugSettings {
     pub trace_widgets:    bool,
     pub bounds_overlay:   bool,
     pub recorder_overlay: bool,
+
+    /// Paint the layout of every widget over the frame, its rect, the insets around its
+    /// children, its clip, the space given to the flexible children of stacks and the baselines
+    /// of text.
+    ///
+    /// In debug builds this is toggled with `F6`.
+    pub layout_overlay: bool,
+
+    /// Show the allocations made in each phase of the last frame, see
+    /// [`CountingAllocator`](crate::CountingAllocator).
+    pub allocation_overlay: bool,
+
+    /// Show the time taken by each phase of the last frame, a graph of the recent frame times,
+    /// and how many widgets exist.
+    ///
+    /// In debug builds this is toggled with `F5`.
+    pub performance_overlay: bool,
+
+    /// Draw windows as they're seen with a color blindness.
+    pub color_blindness: Option<ColorBlindness>,
+
+    /// Flag text with a contrast ratio to its background below WCAG AA, `4.5` for regular text,
+    /// and `3.0` for large text.
+    ///
+    /// The background is read back from the canvas, so this does nothing when it can't be.
+    pub contrast_overlay: bool,
+
+    /// Show the widget under the pointer with its type, bounds, padding and state, and route
+    /// input to the inspector instead of the widgets.
+    ///
+    /// In debug builds this is toggled with `Ctrl+Shift+I`. While inspecting, the arrow keys
+    /// walk to the parent, first child and siblings of the widget, `Enter` or a click logs its
+    /// ancestors and `Escape` closes the inspector.
+    pub inspector: bool,
 }
 
 impl Default for DebugSettings {
     fn default() -> Self {
         Self {
-            trace_widgets:    cfg!(debug_assertions),
-            bounds_overlay:   false,
-            recorder_overlay: false,
+            trace_widgets:       cfg!(debug_assertions),
+            bounds_overlay:      false,
+            recorder_overlay:    false,
+            layout_overlay:      false,
+            allocation_overlay:  false,
+            performance_overlay: false,
+            color_blindness:     None,
+            contrast_overlay:    false,
+            inspector:           false,
+        }
+    }
+}
+
+/// A color blindness to simulate, see [`DebugSettings::color_blindness`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorBlindness {
+    /// No red cones.
+    Protanopia,
+
+    /// No green cones.
+    Deuteranopia,
+
+    /// No blue cones.
+    Tritanopia,
+}
+
+impl ColorBlindness {
+    /// The matrix simulating the color blindness, see
+    /// [`LayerOptions::color_matrix`](crate::LayerOptions::color_matrix).
+    ///
+    /// These are the matrices of Machado et al. at full severity. They're made for linear
+    /// colors, but are applied to sRGB, which is close enough to audit a palette.
+    #[rustfmt::skip]
+    pub const fn color_matrix(self) -> [f32; 20] {
+        match self {
+            ColorBlindness::Protanopia => [
+                 0.152_286,  1.052_583, -0.204_868, 0.0, 0.0,
+                 0.114_503,  0.786_281,  0.099_216, 0.0, 0.0,
+                -0.003_882, -0.048_116,  1.051_998, 0.0, 0.0,
+                 0.0,        0.0,        0.0,       1.0, 0.0,
+            ],
+
+            ColorBlindness::Deuteranopia => [
+                 0.367_322,  0.860_646, -0.227_968, 0.0, 0.0,
+                 0.280_085,  0.672_501,  0.047_413, 0.0, 0.0,
+                -0.011_820,  0.042_940,  0.968_881, 0.0, 0.0,
+                 0.0,        0.0,        0.0,       1.0, 0.0,
+            ],
+
+            ColorBlindness::Tritanopia => [
+                 1.255_528, -0.076_749, -0.178_779, 0.0, 0.0,
+                -0.078_411,  0.930_809,  0.147_602, 0.0, 0.0,
+                 0.004_733,  0.691_367,  0.303_900, 0.0, 0.0,
+                 0.0,        0.0,        0.0,       1.0, 0.0,
+            ],
         }
     }
 }
@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use crate::WindowId;
+
+/// What a [`FileDialog`] asks the user to pick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileDialogKind {
+    /// Pick a single existing file.
+    Open,
+
+    /// Pick any number of existing files.
+    OpenMany,
+
+    /// Pick a single folder.
+    Folder,
+
+    /// Pick a path to save to, suggesting `name` as the file name.
+    Save { name: Option<String> },
+}
+
+/// A named group of file extensions shown by a [`FileDialog`], e.g. `Images` for `png` and `jpg`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileFilter {
+    pub name:       String,
+    pub extensions: Vec<String>,
+}
+
+/// A request for the platform file dialog, see [`Builder::show_file_dialog`](crate::Builder::show_file_dialog).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileDialog {
+    pub kind:      FileDialogKind,
+    pub title:     Option<String>,
+    pub filters:   Vec<FileFilter>,
+    pub directory: Option<PathBuf>,
+    pub parent:    Option<WindowId>,
+}
+
+impl FileDialog {
+    pub fn new(kind: FileDialogKind) -> Self {
+        Self {
+            kind,
+            title: None,
+            filters: Vec::new(),
+            directory: None,
+            parent: None,
+        }
+    }
+
+    pub fn open() -> Self {
+        Self::new(FileDialogKind::Open)
+    }
+
+    pub fn open_many() -> Self {
+        Self::new(FileDialogKind::OpenMany)
+    }
+
+    pub fn folder() -> Self {
+        Self::new(FileDialogKind::Folder)
+    }
+
+    pub fn save(name: impl Into<String>) -> Self {
+        Self::new(FileDialogKind::Save {
+            name: Some(name.into()),
+        })
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Add a filter, `extensions` are given without the leading dot.
+    pub fn filter<S>(
+        mut self,
+        name: impl Into<String>,
+        extensions: impl IntoIterator<Item = S>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.filters.push(FileFilter {
+            name:       name.into(),
+            extensions: extensions.into_iter().map(Into::into).collect(),
+        });
+
+        self
+    }
+
+    /// Set the directory the dialog starts in.
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Set the window the dialog is modal to.
+    pub fn parent(mut self, window: WindowId) -> Self {
+        self.parent = Some(window);
+        self
+    }
+}
+
+/// Called with the paths picked in a [`FileDialog`], which are empty if it was cancelled.
+pub type FileDialogResponder = Box<dyn FnOnce(Vec<PathBuf>) + Send>;
@@ -1,13 +1,14 @@
 use std::hash::Hash;
 
 use crate::{
-    Affine, BorderWidth, CornerRadius, Curve, Offset, Paint, Painter, Paragraph, Recording, Rect,
-    Svg,
+    Affine, BorderWidth, Color, CornerRadius, Curve, Offset, Paint, Painter, Paragraph, Point,
+    Recording, Rect, Svg, SvgStyle,
 };
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Clip {
     Rect(Rect, CornerRadius),
+    Curve(Curve),
 }
 
 impl From<Rect> for Clip {
@@ -22,10 +23,110 @@ impl From<Rect> for Option<Clip> {
     }
 }
 
+impl From<Curve> for Clip {
+    fn from(curve: Curve) -> Self {
+        Self::Curve(curve)
+    }
+}
+
 impl Clip {
-    pub const fn bounds(&self) -> Rect {
+    pub fn bounds(&self) -> Rect {
         match self {
             Clip::Rect(rect, _) => *rect,
+            Clip::Curve(curve) => curve.bounds(),
+        }
+    }
+
+    /// Check whether `point` is inside the clip.
+    pub fn contains(&self, point: Point) -> bool {
+        match self {
+            Clip::Rect(rect, radius) => {
+                if !rect.contains(point) {
+                    return false;
+                }
+
+                // the centers of the corner arcs, and their radii
+                let corners = [
+                    (
+                        rect.top_left() + radius.top_left,
+                        radius.top_left,
+                    ),
+                    (
+                        Point::new(
+                            rect.right() - radius.top_right,
+                            rect.top() + radius.top_right,
+                        ),
+                        radius.top_right,
+                    ),
+                    (
+                        rect.bottom_right() - radius.bottom_right,
+                        radius.bottom_right,
+                    ),
+                    (
+                        Point::new(
+                            rect.left() + radius.bottom_left,
+                            rect.bottom() - radius.bottom_left,
+                        ),
+                        radius.bottom_left,
+                    ),
+                ];
+
+                corners.into_iter().all(|(center, radius)| {
+                    let outside_x = (point.x < center.x) == (center.x < rect.center().x);
+                    let outside_y = (point.y < center.y) == (center.y < rect.center().y);
+
+                    !(radius > 0.0 && outside_x && outside_y) || point.distance(center) <= radius
+                })
+            }
+
+            Clip::Curve(curve) => curve.contains(point),
+        }
+    }
+}
+
+/// A shape casting a shadow, see [`Canvas::draw_shadow`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    Rect(Rect, CornerRadius),
+    Curve(Curve),
+}
+
+impl From<Rect> for Shape {
+    fn from(rect: Rect) -> Self {
+        Self::Rect(rect, CornerRadius::all(0.0))
+    }
+}
+
+impl From<Curve> for Shape {
+    fn from(curve: Curve) -> Self {
+        Self::Curve(curve)
+    }
+}
+
+/// How the contents of a [`Canvas::layer`] are composited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayerOptions {
+    /// The opacity of the layer, between `0.0` and `1.0`.
+    pub opacity: f32,
+
+    /// The radius of the blur applied to the contents of the layer.
+    pub blur: f32,
+
+    /// The radius of the blur applied to what has been drawn behind the layer.
+    pub backdrop_blur: f32,
+
+    /// A row major 4x5 matrix transforming the colors of the layer, the last column is added
+    /// to the components.
+    pub color_matrix: Option<[f32; 20]>,
+}
+
+impl Default for LayerOptions {
+    fn default() -> Self {
+        Self {
+            opacity:       1.0,
+            blur:          0.0,
+            backdrop_blur: 0.0,
+            color_matrix:  None,
         }
     }
 }
@@ -43,7 +144,8 @@ pub trait Canvas {
 
     fn transform(&mut self, affine: Affine, f: &mut dyn FnMut(&mut dyn Canvas));
 
-    fn layer(&mut self, f: &mut dyn FnMut(&mut dyn Canvas));
+    /// Draw `f` into a layer, composited with `options` when done.
+    fn layer(&mut self, options: &LayerOptions, f: &mut dyn FnMut(&mut dyn Canvas));
 
     #[must_use]
     fn record(
@@ -63,9 +165,24 @@ pub trait Canvas {
 
     fn draw_border(&mut self, rect: Rect, width: BorderWidth, radius: CornerRadius, paint: &Paint);
 
+    /// Draw the shadow cast by `shape` when lifted `elevation` above the canvas.
+    ///
+    /// The shadow extends at most `2.0 * elevation` past `shape`.
+    fn draw_shadow(&mut self, shape: &Shape, elevation: f32, color: Color);
+
     fn draw_text(&mut self, paragraph: &Paragraph, max_width: f32, offset: Offset);
 
-    fn draw_svg(&mut self, svg: &Svg);
+    /// Draw `svg` restyled with `style`, see [`SvgData::restyled`](crate::SvgData::restyled).
+    fn draw_svg(&mut self, svg: &Svg, style: &SvgStyle);
 
     fn draw_recording(&mut self, rect: Rect, recording: &Recording);
+
+    /// Read back the color of the pixel at `point` in physical pixels, of what has been drawn
+    /// to the canvas so far.
+    ///
+    /// Returns `None` if the canvas can't be read from.
+    fn sample(&mut self, point: Point) -> Option<Color> {
+        let _ = point;
+        None
+    }
 }
@@ -4,7 +4,7 @@ use std::{
     sync::{Arc, Weak},
 };
 
-use crate::Point;
+use crate::{Point, Rect};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Fill {
@@ -147,6 +147,114 @@ impl CurveData {
         }
     }
 
+    /// Get a rectangle containing the curve.
+    ///
+    /// The rectangle contains the control points of the curve, so it is not always tight.
+    pub fn bounds(&self) -> Rect {
+        let Some(&first) = self.points.first() else {
+            return Rect::default();
+        };
+
+        let (min, max) = (self.points.iter()).fold((first, first), |(min, max), &point| {
+            (min.min(point), max.max(point))
+        });
+
+        Rect { min, max }
+    }
+
+    /// Check whether `point` is inside the curve, according to its [`Fill`].
+    ///
+    /// Open contours are treated as if they were closed.
+    pub fn contains(&self, point: Point) -> bool {
+        // quads and cubics are flattened into this many lines
+        const STEPS: usize = 16;
+
+        let mut winding = 0;
+        let mut start = Point::ORIGIN;
+        let mut current = Point::ORIGIN;
+
+        let mut line = |a: Point, b: Point| {
+            if a.y <= point.y {
+                if b.y > point.y && cross(a, b, point) > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y <= point.y && cross(a, b, point) < 0.0 {
+                winding -= 1;
+            }
+        };
+
+        for segment in self.iter() {
+            match segment {
+                CurveSegment::Move(p) => {
+                    line(current, start);
+                    start = p;
+                    current = p;
+                }
+
+                CurveSegment::Line(p) => {
+                    line(current, p);
+                    current = p;
+                }
+
+                CurveSegment::Quad(a, p) => {
+                    let mut previous = current;
+
+                    for i in 1..=STEPS {
+                        let t = i as f32 / STEPS as f32;
+                        let u = 1.0 - t;
+
+                        let next = Point::new(
+                            u * u * current.x + 2.0 * u * t * a.x + t * t * p.x,
+                            u * u * current.y + 2.0 * u * t * a.y + t * t * p.y,
+                        );
+
+                        line(previous, next);
+                        previous = next;
+                    }
+
+                    current = p;
+                }
+
+                CurveSegment::Cubic(a, b, p) => {
+                    let mut previous = current;
+
+                    for i in 1..=STEPS {
+                        let t = i as f32 / STEPS as f32;
+                        let u = 1.0 - t;
+
+                        let next = Point::new(
+                            u * u * u * current.x
+                                + 3.0 * u * u * t * a.x
+                                + 3.0 * u * t * t * b.x
+                                + t * t * t * p.x,
+                            u * u * u * current.y
+                                + 3.0 * u * u * t * a.y
+                                + 3.0 * u * t * t * b.y
+                                + t * t * t * p.y,
+                        );
+
+                        line(previous, next);
+                        previous = next;
+                    }
+
+                    current = p;
+                }
+
+                CurveSegment::Close => {
+                    line(current, start);
+                    current = start;
+                }
+            }
+        }
+
+        line(current, start);
+
+        match self.fill {
+            Fill::Winding => winding != 0,
+            Fill::EvenOdd => winding % 2 != 0,
+        }
+    }
+
     pub fn iter(&self) -> impl ExactSizeIterator<Item = CurveSegment> {
         const EXPECT: &str = "invariants of `Curve` should be upheld";
 
@@ -210,3 +318,8 @@ pub enum CurveSegment {
     Cubic(Point, Point, Point),
     Close,
 }
+
+// positive when `point` is left of the line from `a` to `b`
+fn cross(a: Point, b: Point, point: Point) -> f32 {
+    (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y)
+}
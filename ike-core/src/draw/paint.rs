@@ -1,10 +1,276 @@
 use std::hash::{Hash, Hasher};
 
-use crate::Color;
+use crate::{Affine, Color, Image, Point};
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Shader {
     Solid(Color),
+    Gradient(Gradient),
+    Image(ImageShader),
+}
+
+impl From<Color> for Shader {
+    fn from(color: Color) -> Self {
+        Shader::Solid(color)
+    }
+}
+
+impl From<Gradient> for Shader {
+    fn from(gradient: Gradient) -> Self {
+        Shader::Gradient(gradient)
+    }
+}
+
+impl From<ImageShader> for Shader {
+    fn from(image: ImageShader) -> Self {
+        Shader::Image(image)
+    }
+}
+
+/// How a shader is extended outside of its bounds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Spread {
+    /// Extend the edge colors.
+    #[default]
+    Pad,
+
+    /// Repeat the shader.
+    Repeat,
+
+    /// Repeat the shader, mirroring every other repetition.
+    Reflect,
+
+    /// Draw nothing outside of the bounds.
+    Decal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientStop {
+    /// The position of the stop, between `0.0` and `1.0`.
+    pub offset: f32,
+    pub color:  Color,
+}
+
+impl GradientStop {
+    pub const fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+impl Eq for GradientStop {}
+
+impl Hash for GradientStop {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.to_bits().hash(state);
+        self.color.hash(state);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// A gradient along the line from `start` to `end`.
+    Linear { start: Point, end: Point },
+
+    /// A gradient from `center` out to `radius`.
+    Radial { center: Point, radius: f32 },
+
+    /// A gradient between two circles.
+    Conical {
+        start:        Point,
+        start_radius: f32,
+        end:          Point,
+        end_radius:   f32,
+    },
+
+    /// A gradient sweeping around `center`, between two angles in radians.
+    Sweep {
+        center:      Point,
+        start_angle: f32,
+        end_angle:   f32,
+    },
+}
+
+impl Eq for GradientKind {}
+
+impl Hash for GradientKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        fn hash_point<H: Hasher>(point: Point, state: &mut H) {
+            point.x.to_bits().hash(state);
+            point.y.to_bits().hash(state);
+        }
+
+        std::mem::discriminant(self).hash(state);
+
+        match *self {
+            GradientKind::Linear { start, end } => {
+                hash_point(start, state);
+                hash_point(end, state);
+            }
+
+            GradientKind::Radial { center, radius } => {
+                hash_point(center, state);
+                radius.to_bits().hash(state);
+            }
+
+            GradientKind::Conical {
+                start,
+                start_radius,
+                end,
+                end_radius,
+            } => {
+                hash_point(start, state);
+                start_radius.to_bits().hash(state);
+                hash_point(end, state);
+                end_radius.to_bits().hash(state);
+            }
+
+            GradientKind::Sweep {
+                center,
+                start_angle,
+                end_angle,
+            } => {
+                hash_point(center, state);
+                start_angle.to_bits().hash(state);
+                end_angle.to_bits().hash(state);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gradient {
+    pub kind:   GradientKind,
+    pub stops:  Vec<GradientStop>,
+    pub spread: Spread,
+}
+
+impl Gradient {
+    pub fn new(kind: GradientKind, stops: impl Into<Vec<GradientStop>>) -> Self {
+        Self {
+            kind,
+            stops: stops.into(),
+            spread: Spread::Pad,
+        }
+    }
+
+    pub fn linear(start: Point, end: Point, stops: impl Into<Vec<GradientStop>>) -> Self {
+        Self::new(
+            GradientKind::Linear { start, end },
+            stops,
+        )
+    }
+
+    pub fn radial(center: Point, radius: f32, stops: impl Into<Vec<GradientStop>>) -> Self {
+        Self::new(
+            GradientKind::Radial { center, radius },
+            stops,
+        )
+    }
+
+    pub fn conical(
+        start: Point,
+        start_radius: f32,
+        end: Point,
+        end_radius: f32,
+        stops: impl Into<Vec<GradientStop>>,
+    ) -> Self {
+        let kind = GradientKind::Conical {
+            start,
+            start_radius,
+            end,
+            end_radius,
+        };
+
+        Self::new(kind, stops)
+    }
+
+    /// Create a gradient sweeping a full turn around `center`.
+    pub fn sweep(center: Point, stops: impl Into<Vec<GradientStop>>) -> Self {
+        let kind = GradientKind::Sweep {
+            center,
+            start_angle: 0.0,
+            end_angle: std::f32::consts::TAU,
+        };
+
+        Self::new(kind, stops)
+    }
+
+    pub fn with_spread(mut self, spread: Spread) -> Self {
+        self.spread = spread;
+        self
+    }
+}
+
+/// How an image is sampled when scaled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Filter {
+    Nearest,
+
+    #[default]
+    Linear,
+
+    /// Linear filtering between mipmap levels, best for downscaling.
+    Mipmap,
+
+    /// Bicubic filtering, best for upscaling.
+    Cubic,
+}
+
+/// Fill with an [`Image`], or a repeating pattern of it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageShader {
+    pub image:     Image,
+    pub spread_x:  Spread,
+    pub spread_y:  Spread,
+    pub filter:    Filter,
+    pub transform: Affine,
+}
+
+impl ImageShader {
+    pub fn new(image: Image) -> Self {
+        Self {
+            image,
+            spread_x: Spread::Decal,
+            spread_y: Spread::Decal,
+            filter: Filter::Linear,
+            transform: Affine::IDENTITY,
+        }
+    }
+
+    /// Create a shader repeating `image` in both directions.
+    pub fn pattern(image: Image) -> Self {
+        Self::new(image).with_spread(Spread::Repeat, Spread::Repeat)
+    }
+
+    pub fn with_spread(mut self, x: Spread, y: Spread) -> Self {
+        self.spread_x = x;
+        self.spread_y = y;
+        self
+    }
+
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Affine) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Eq for ImageShader {}
+
+impl Hash for ImageShader {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.image.hash(state);
+        self.spread_x.hash(state);
+        self.spread_y.hash(state);
+        self.filter.hash(state);
+        self.transform.matrix.matrix.map(f32::to_bits).hash(state);
+        self.transform.offset.x.to_bits().hash(state);
+        self.transform.offset.y.to_bits().hash(state);
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -64,11 +330,14 @@ impl Hash for Stroke {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Paint {
     pub shader: Shader,
     pub blend:  Blend,
     pub stroke: Stroke,
+
+    /// Dither the shader, hiding banding in smooth gradients.
+    pub dither: bool,
 }
 
 impl Default for Paint {
@@ -77,6 +346,7 @@ impl Default for Paint {
             shader: Shader::Solid(Color::BLACK),
             blend:  Blend::SrcOver,
             stroke: Stroke::default(),
+            dither: false,
         }
     }
 }
@@ -89,3 +359,22 @@ impl From<Color> for Paint {
         }
     }
 }
+
+impl From<Gradient> for Paint {
+    fn from(gradient: Gradient) -> Self {
+        Self {
+            shader: Shader::Gradient(gradient),
+            dither: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ImageShader> for Paint {
+    fn from(image: ImageShader) -> Self {
+        Self {
+            shader: Shader::Image(image),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Point;
+
+/// A navigation back, e.g. from the back gesture on android.
+///
+/// A gesture starts with [`BackEvent::Started`], and ends with either [`BackEvent::Invoked`],
+/// or [`BackEvent::Cancelled`] if the user changed their mind. The back button only sends
+/// [`BackEvent::Invoked`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum BackEvent {
+    Started(BackGesture),
+    Progressed(BackGesture),
+    Cancelled,
+    Invoked,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackGesture {
+    /// How far the gesture has progressed, from `0.0` to `1.0`.
+    pub progress: f32,
+
+    /// The edge of the window the gesture started from.
+    pub edge: BackEdge,
+
+    /// The position of the touch, in window coordinates.
+    pub position: Point,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BackEdge {
+    Left,
+    Right,
+
+    /// The gesture didn't start from an edge, e.g. when made with a stylus.
+    None,
+}
@@ -1,4 +1,4 @@
-pub use keyboard_types::{Key, Modifiers, NamedKey};
+pub use keyboard_types::{Code, Key, Location, Modifiers, NamedKey};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum KeyEvent {
@@ -6,10 +6,57 @@ pub enum KeyEvent {
     Up(KeyPressEvent),
 }
 
+/// A key of a keyboard reported by the backend, see
+/// [`World::seat_key_pressed`](crate::World::seat_key_pressed).
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyInput {
+    pub key: Key,
+
+    /// The physical key, independent of the keyboard layout, [`Code::Unidentified`] if the
+    /// platform doesn't report it.
+    pub code: Code,
+
+    /// Where on the keyboard the key is, e.g. the left or right shift.
+    pub location: Location,
+    pub text:     Option<String>,
+    pub repeat:   bool,
+}
+
+impl KeyInput {
+    /// A key that isn't tied to a physical key, e.g. one synthesized by the platform.
+    pub fn new(key: Key) -> Self {
+        Self {
+            key,
+            code: Code::Unidentified,
+            location: Location::Standard,
+            text: None,
+            repeat: false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct KeyPressEvent {
-    pub key:       Key,
+    pub key: Key,
+
+    /// The physical key, independent of the keyboard layout, [`Code::Unidentified`] if the
+    /// platform doesn't report it.
+    pub code: Code,
+
+    /// Where on the keyboard the key is, e.g. the left or right shift.
+    pub location:  Location,
     pub modifiers: Modifiers,
     pub text:      Option<String>,
     pub repeat:    bool,
 }
+
+impl KeyPressEvent {
+    /// Whether the key activates the focused widget like a click, i.e. enter or space.
+    pub fn is_activation(&self) -> bool {
+        match self.key {
+            Key::Named(NamedKey::Enter) => true,
+            Key::Character(ref c) => c == " ",
+            _ => false,
+        }
+    }
+}
@@ -1,11 +1,17 @@
+mod back;
 mod key;
 mod pointer;
+mod seat;
 mod text;
+mod tool;
 mod touch;
 
+pub use back::*;
 pub use key::*;
 pub use pointer::*;
+pub use seat::*;
 pub use text::*;
+pub use tool::*;
 pub use touch::*;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
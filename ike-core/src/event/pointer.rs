@@ -2,7 +2,7 @@ use std::hash::{DefaultHasher, Hash, Hasher};
 
 pub use cursor_icon::CursorIcon;
 
-use crate::{Offset, Point, WidgetId};
+use crate::{Image, Offset, Point, SeatId, Tool, WidgetId};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum PointerEvent {
@@ -19,12 +19,14 @@ pub struct PointerButtonEvent {
     pub pointer:  PointerId,
     pub button:   PointerButton,
     pub position: Point,
+    pub tool:     Tool,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct PointerMoveEvent {
     pub pointer:  PointerId,
     pub position: Point,
+    pub tool:     Tool,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -40,6 +42,49 @@ pub struct PointerScrollEvent {
     pub delta:    ScrollDelta,
 }
 
+/// What the cursor looks like over a widget.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cursor {
+    Icon(CursorIcon),
+
+    /// A custom image, with `hotspot` in pixels of the image at the point of the cursor.
+    Image {
+        image:   Image,
+        hotspot: Point,
+    },
+
+    /// The cursor isn't shown.
+    Hidden,
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Self::Icon(CursorIcon::Default)
+    }
+}
+
+impl From<CursorIcon> for Cursor {
+    fn from(icon: CursorIcon) -> Self {
+        Self::Icon(icon)
+    }
+}
+
+/// How the cursor is held in a window, see
+/// [`EventCx::set_cursor_grab`](crate::EventCx::set_cursor_grab).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorGrab {
+    /// The cursor moves freely.
+    #[default]
+    None,
+
+    /// The cursor can't leave the window.
+    Confined,
+
+    /// The cursor stays in place, while pointer moves are still reported, e.g. for dragging
+    /// a value without running into the edges of the screen.
+    Locked,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PointerButton {
     Primary,
@@ -60,12 +105,32 @@ pub enum PointerPropagate {
 #[derive(Clone, Debug, PartialEq)]
 pub struct Pointer {
     pub(crate) id:       PointerId,
+    pub(crate) seat:     SeatId,
     pub(crate) position: Point,
+    pub(crate) tool:     Tool,
     pub(crate) hovering: Option<WidgetId>,
     pub(crate) capturer: Option<WidgetId>,
 }
 
 impl Pointer {
+    pub fn id(&self) -> PointerId {
+        self.id
+    }
+
+    /// The seat the pointer belongs to.
+    pub fn seat(&self) -> SeatId {
+        self.seat
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// The tool that last moved the pointer.
+    pub fn tool(&self) -> Tool {
+        self.tool
+    }
+
     pub(crate) fn target(&self) -> Option<WidgetId> {
         self.capturer.or(self.hovering)
     }
@@ -86,7 +151,11 @@ impl PointerId {
         }
     }
 
-    pub fn from_u64(data: u64) -> Self {
+    pub const fn from_u64(data: u64) -> Self {
         Self { data }
     }
+
+    pub const fn as_u64(self) -> u64 {
+        self.data
+    }
 }
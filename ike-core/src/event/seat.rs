@@ -0,0 +1,75 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{Code, KeyPressEvent, Modifiers, WidgetId};
+
+/// A group of input devices with its own keyboard focus, like a Wayland seat.
+///
+/// Every window has the [`SeatId::PRIMARY`] seat, other seats are added when the platform
+/// reports input from them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SeatId {
+    data: u64,
+}
+
+impl SeatId {
+    /// The seat of platforms without multi-seat support, text input and touch always belong
+    /// to it.
+    pub const PRIMARY: Self = Self::from_u64(0);
+
+    pub fn from_hash(hash: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        hash.hash(&mut hasher);
+
+        Self {
+            data: hasher.finish(),
+        }
+    }
+
+    pub const fn from_u64(data: u64) -> Self {
+        Self { data }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Seat {
+    pub(crate) id:        SeatId,
+    pub(crate) modifiers: Modifiers,
+    pub(crate) focused:   Option<WidgetId>,
+
+    /// The keys held down, and the widgets they were pressed on.
+    pub(crate) pressed: Vec<(KeyPressEvent, Option<WidgetId>)>,
+}
+
+impl Seat {
+    pub(crate) fn new(id: SeatId) -> Self {
+        Self {
+            id,
+            modifiers: Modifiers::empty(),
+            focused: None,
+            pressed: Vec::new(),
+        }
+    }
+
+    pub fn id(&self) -> SeatId {
+        self.id
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// The widget with the keyboard focus of the seat.
+    pub fn focused(&self) -> Option<WidgetId> {
+        self.focused
+    }
+
+    /// Whether the physical key `code` is held down.
+    pub fn is_key_pressed(&self, code: Code) -> bool {
+        self.pressed.iter().any(|(event, _)| event.code == code)
+    }
+
+    /// The keys held down, in the order they were pressed.
+    pub fn pressed_keys(&self) -> impl Iterator<Item = &KeyPressEvent> {
+        self.pressed.iter().map(|(event, _)| event)
+    }
+}
@@ -15,6 +15,15 @@ pub struct TextPasteEvent {
 pub enum ImeEvent {
     Start,
     Select(Range<usize>),
+
+    /// Replace the text being composed, `cursor` is relative to `text`.
+    ///
+    /// An empty `text` removes the composition.
+    Preedit {
+        text:   String,
+        cursor: Option<Range<usize>>,
+    },
+
     Commit(String),
     End,
 }
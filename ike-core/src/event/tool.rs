@@ -0,0 +1,67 @@
+use crate::Offset;
+
+/// The kind of device producing pointer or touch input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ToolKind {
+    #[default]
+    Unknown,
+    Mouse,
+    Finger,
+    Stylus,
+
+    /// The eraser end of a stylus.
+    Eraser,
+}
+
+/// The device producing a pointer or touch event, and how a stylus is held.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tool {
+    pub kind: ToolKind,
+
+    /// The pressure from `0.0` to `1.0`, always `1.0` for tools that don't sense pressure.
+    pub pressure: f32,
+
+    /// The angle in radians between the tool and the normal of the screen, `0.0` when the tool
+    /// is held upright.
+    pub tilt: f32,
+
+    /// The clockwise angle in radians from up of the direction the tool is tilted towards.
+    pub azimuth: f32,
+}
+
+impl Default for Tool {
+    fn default() -> Self {
+        Self::new(ToolKind::Unknown)
+    }
+}
+
+impl Tool {
+    pub const MOUSE: Self = Self::new(ToolKind::Mouse);
+    pub const FINGER: Self = Self::new(ToolKind::Finger);
+
+    /// A tool of `kind` held upright at full pressure.
+    pub const fn new(kind: ToolKind) -> Self {
+        Self {
+            kind,
+            pressure: 1.0,
+            tilt: 0.0,
+            azimuth: 0.0,
+        }
+    }
+
+    pub fn is_stylus(&self) -> bool {
+        matches!(
+            self.kind,
+            ToolKind::Stylus | ToolKind::Eraser
+        )
+    }
+
+    /// The direction the tool is tilted towards, with a length of the sine of the tilt.
+    pub fn tilt_offset(&self) -> Offset {
+        let length = self.tilt.sin();
+        Offset::new(
+            self.azimuth.sin() * length,
+            -self.azimuth.cos() * length,
+        )
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use crate::{Offset, Point, WidgetId};
+use crate::{Offset, Point, Tool, WidgetId};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TouchEvent {
@@ -17,12 +17,14 @@ pub enum TouchEvent {
 pub struct TouchPressEvent {
     pub touch:    TouchId,
     pub position: Point,
+    pub tool:     Tool,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct TouchMoveEvent {
     pub touch:    TouchId,
     pub position: Point,
+    pub tool:     Tool,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -31,6 +33,12 @@ pub enum Gesture {
     LongTap(TapGesture),
     DoubleTap(TapGesture),
     Pan(PanGesture),
+
+    /// A panning touch was released while moving.
+    Fling(FlingGesture),
+
+    Pinch(PinchGesture),
+    Rotate(RotateGesture),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -45,6 +53,48 @@ pub struct PanGesture {
     pub start:    Point,
     pub position: Point,
     pub delta:    Offset,
+
+    /// The smoothed velocity of the touch in points per second.
+    pub velocity: Offset,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlingGesture {
+    pub touch:    TouchId,
+    pub position: Point,
+
+    /// The velocity of the touch when released in points per second.
+    pub velocity: Offset,
+}
+
+/// Two touches moving towards or away from each other.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PinchGesture {
+    pub touches: [TouchId; 2],
+
+    /// The point halfway between the touches.
+    pub focal: Point,
+
+    /// The distance between the touches relative to when they were pressed.
+    pub scale: f32,
+
+    /// The factor the scale changed by since the last event.
+    pub delta: f32,
+}
+
+/// Two touches rotating around each other.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RotateGesture {
+    pub touches: [TouchId; 2],
+
+    /// The point halfway between the touches.
+    pub focal: Point,
+
+    /// The clockwise angle in radians relative to when the touches were pressed.
+    pub angle: f32,
+
+    /// The change in angle since the last event.
+    pub delta: f32,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -61,7 +111,17 @@ pub struct TouchSettings {
     pub double_tap_slop: f32,
     pub double_tap_time: Duration,
     pub long_tap_time:   Duration,
+    pub long_tap_slop:   f32,
     pub pan_distance:    f32,
+
+    /// The minimum velocity in points per second a pan has to be released with to fling.
+    pub fling_velocity: f32,
+
+    /// How far the distance between two touches has to change before a pinch starts.
+    pub pinch_distance: f32,
+
+    /// How far in radians two touches have to rotate before a rotation starts.
+    pub rotate_angle: f32,
 }
 
 impl Default for TouchSettings {
@@ -72,7 +132,11 @@ impl Default for TouchSettings {
             double_tap_slop: 20.0,
             double_tap_time: Duration::from_millis(300),
             long_tap_time:   Duration::from_millis(500),
+            long_tap_slop:   10.0,
             pan_distance:    10.0,
+            fling_velocity:  50.0,
+            pinch_distance:  20.0,
+            rotate_angle:    0.2,
         }
     }
 }
@@ -83,18 +147,51 @@ pub struct Touch {
     pub(crate) current_position: Point,
     pub(crate) start_position:   Point,
     pub(crate) start_time:       Instant,
+    pub(crate) tool:             Tool,
     pub(crate) state:            TouchState,
     pub(crate) capturer:         Option<WidgetId>,
+    pub(crate) is_down:          bool,
+    pub(crate) velocity:         Offset,
+    pub(crate) last_move:        Instant,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum TouchState {
     None,
     Tapped(Point, Instant),
+    LongTapped,
     Panning,
+
+    /// Part of a [`MultiTouch`], neither taps nor pans.
+    Multi,
+}
+
+/// Two touches pressed at the same time, recognized as pinch and rotate gestures.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MultiTouch {
+    pub(crate) touches:     [TouchId; 2],
+    pub(crate) start_span:  f32,
+    pub(crate) start_angle: f32,
+    pub(crate) scale:       f32,
+    pub(crate) angle:       f32,
+    pub(crate) is_pinching: bool,
+    pub(crate) is_rotating: bool,
 }
 
 impl Touch {
+    pub fn id(&self) -> TouchId {
+        self.id
+    }
+
+    pub fn position(&self) -> Point {
+        self.current_position
+    }
+
+    /// The tool that last moved the touch.
+    pub fn tool(&self) -> Tool {
+        self.tool
+    }
+
     pub fn distance(&self) -> f32 {
         self.start_position.distance(self.current_position)
     }
@@ -122,4 +219,8 @@ impl TouchId {
     pub fn from_u64(data: u64) -> Self {
         Self { data }
     }
+
+    pub const fn as_u64(self) -> u64 {
+        self.data
+    }
 }
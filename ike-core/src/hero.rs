@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use crate::{Rect, WidgetId, WindowId};
+
+/// Coordinates the transitions of [`Hero`](crate::widgets::Hero)s between screens.
+///
+/// The coordinator remembers where the last hero shown with each tag is in its window. When a
+/// hero with the same tag arrives, e.g. because a navigator swapped the screen it's on, it
+/// takes that rect and flies from there to its own. Heroes that leave are forgotten after the
+/// next compose of their window, so only heroes swapped in the same frame are matched.
+#[derive(Default)]
+pub(crate) struct HeroCoordinator {
+    heroes: HashMap<String, HeroRecord>,
+}
+
+struct HeroRecord {
+    widget:   WidgetId,
+    window:   WindowId,
+    rect:     Rect,
+    has_left: bool,
+}
+
+impl HeroCoordinator {
+    /// Record that the hero `widget` is shown at `rect`, in window coordinates.
+    pub fn shown(&mut self, tag: &str, widget: WidgetId, window: WindowId, rect: Rect) {
+        let record = HeroRecord {
+            widget,
+            window,
+            rect,
+            has_left: false,
+        };
+
+        match self.heroes.get_mut(tag) {
+            Some(existing) => *existing = record,
+            None => {
+                self.heroes.insert(tag.to_owned(), record);
+            }
+        }
+    }
+
+    /// Record that the hero `widget` is no longer shown.
+    pub fn left(&mut self, tag: &str, widget: WidgetId) {
+        if let Some(record) = self.heroes.get_mut(tag)
+            && record.widget == widget
+        {
+            record.has_left = true;
+        }
+    }
+
+    /// Get the rect the hero `widget` should fly from, if another hero with the same tag was
+    /// last shown in the same window.
+    pub fn arrive(&self, tag: &str, widget: WidgetId, window: WindowId) -> Option<Rect> {
+        let record = self.heroes.get(tag)?;
+
+        match record.widget != widget && record.window == window {
+            true => Some(record.rect),
+            false => None,
+        }
+    }
+
+    /// Forget the heroes of `window` that have left, called after it's composed.
+    pub fn prune(&mut self, window: WindowId) {
+        self.heroes
+            .retain(|_, record| !(record.has_left && record.window == window));
+    }
+}
@@ -1 +1,130 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::{Arc, Weak},
+};
 
+/// A raster image.
+///
+/// Images are compared and hashed by identity, cloning an image is cheap and shares its
+/// pixels.
+#[derive(Clone, Debug)]
+pub struct Image {
+    data: Arc<ImageData>,
+}
+
+impl Image {
+    pub fn new(data: ImageData) -> Self {
+        Self {
+            data: Arc::new(data),
+        }
+    }
+
+    /// Create an image from unpremultiplied RGBA pixels, in row-major order.
+    ///
+    /// # Panics
+    /// - If `pixels` does not contain `width * height` pixels.
+    pub fn from_rgba8(width: u32, height: u32, pixels: impl Into<Vec<u8>>) -> Self {
+        let pixels = pixels.into();
+
+        assert_eq!(
+            pixels.len(),
+            width as usize * height as usize * 4,
+            "image pixels must match its size",
+        );
+
+        Self::new(ImageData {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn downgrade(this: &Self) -> WeakImage {
+        WeakImage {
+            data: Arc::downgrade(&this.data),
+        }
+    }
+}
+
+impl Deref for Image {
+    type Target = ImageData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl From<ImageData> for Image {
+    fn from(data: ImageData) -> Self {
+        Self::new(data)
+    }
+}
+
+impl PartialEq for Image {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for Image {}
+
+impl Hash for Image {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.data).hash(state);
+    }
+}
+
+/// The data of an [`Image`].
+#[derive(Clone, PartialEq)]
+pub struct ImageData {
+    /// Width in pixels.
+    pub width: u32,
+
+    /// Height in pixels.
+    pub height: u32,
+
+    /// Unpremultiplied RGBA pixels, in row-major order.
+    pub pixels: Vec<u8>,
+}
+
+impl fmt::Debug for ImageData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageData")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WeakImage {
+    data: Weak<ImageData>,
+}
+
+impl WeakImage {
+    pub fn upgrade(&self) -> Option<Image> {
+        Some(Image {
+            data: self.data.upgrade()?,
+        })
+    }
+
+    pub fn strong_count(&self) -> usize {
+        self.data.strong_count()
+    }
+}
+
+impl PartialEq for WeakImage {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for WeakImage {}
+
+impl Hash for WeakImage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.as_ptr().hash(state);
+    }
+}
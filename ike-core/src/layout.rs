@@ -16,6 +16,56 @@ pub const fn pixel_floor(x: f32, scale: f32) -> f32 {
     f32::floor(x * scale + EPSILON) / scale
 }
 
+/// The direction content is laid out in horizontally, inherited down the widget tree, see
+/// [`LayoutCx::layout_direction`].
+///
+/// In right-to-left layouts horizontal stacks place their first child on the right, and the
+/// horizontal alignment and padding of widgets are mirrored, so what is specified as left is
+/// the leading edge, and what is specified as right is the trailing edge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl LayoutDirection {
+    /// Languages written right-to-left, by their ISO 639 code.
+    const RTL_LANGUAGES: &[&str] = &[
+        "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ks", "ku", "ps", "sd", "ug", "ur", "yi",
+    ];
+
+    /// The direction of the language of `locale`, e.g. `ar-EG`, `he_IL.UTF-8` or `en-US`.
+    ///
+    /// Unknown languages are left-to-right.
+    pub fn from_locale(locale: &str) -> Self {
+        let language = locale
+            .split(['-', '_', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if Self::RTL_LANGUAGES.contains(&language.as_str()) {
+            LayoutDirection::Rtl
+        } else {
+            LayoutDirection::Ltr
+        }
+    }
+
+    pub fn is_rtl(self) -> bool {
+        matches!(self, LayoutDirection::Rtl)
+    }
+
+    /// Resolve a horizontal alignment from the leading edge, where `0.0` is the leading edge
+    /// and `1.0` the trailing edge, to one from the left edge.
+    pub fn align(self, x: f32) -> f32 {
+        match self {
+            LayoutDirection::Ltr => x,
+            LayoutDirection::Rtl => 1.0 - x,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CornerRadius {
     pub top_left:     f32,
@@ -152,6 +202,11 @@ impl From<f32> for BorderWidth {
     }
 }
 
+/// Padding around the contents of a widget.
+///
+/// Widgets placing their contents with [`Padding::leading_offset`] treat `left` and `right` as
+/// the leading and trailing padding, swapping them in right-to-left layouts. Physical insets,
+/// like those of the window, are placed with [`Padding::aligned_offset`].
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Padding {
     pub right:  f32,
@@ -201,6 +256,19 @@ impl Padding {
         Offset::new(self.left, self.top)
     }
 
+    /// Swap `left` and `right` in right-to-left layouts.
+    pub const fn resolve(self, direction: LayoutDirection) -> Self {
+        match direction {
+            LayoutDirection::Ltr => self,
+            LayoutDirection::Rtl => Self {
+                left:   self.right,
+                right:  self.left,
+                top:    self.top,
+                bottom: self.bottom,
+            },
+        }
+    }
+
     /// Compute the offset aligned to the device pixel grid.
     pub fn aligned_offset(self, cx: &LayoutCx<'_>) -> Offset {
         if cx.is_subpixel() || !cx.settings().render.pixel_align {
@@ -209,6 +277,12 @@ impl Padding {
 
         self.offset().pixel_ceil(cx.scale())
     }
+
+    /// Compute the offset aligned to the device pixel grid, with `left` and `right` swapped
+    /// in right-to-left layouts.
+    pub fn leading_offset(self, cx: &LayoutCx<'_>) -> Offset {
+        self.resolve(cx.layout_direction()).aligned_offset(cx)
+    }
 }
 
 impl From<[f32; 4]> for Padding {
@@ -1,19 +1,26 @@
 #![warn(clippy::unwrap_used)]
 
+mod allocations;
 mod axis;
 mod build;
 mod color;
 mod context;
 mod debug;
+mod dialog;
 mod draw;
 mod event;
+mod hero;
 mod image;
 mod layout;
 mod math;
 mod painter;
+mod playback;
+mod popup;
+mod print;
 mod record;
 mod svg;
 mod text;
+mod timings;
 mod transition;
 mod widget;
 mod window;
@@ -23,35 +30,56 @@ pub(crate) mod passes;
 
 pub mod widgets;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use allocations::{Allocations, CountingAllocator, FrameAllocations, FramePhase};
 pub use axis::Axis;
 pub use build::Builder;
 pub use color::Color;
 pub use context::{ComposeCx, DrawCx, EventCx, LayoutCx, MutCx, RefCx, UpdateCx};
-pub use debug::DebugSettings;
+pub use debug::{ColorBlindness, DebugSettings};
+pub use dialog::{FileDialog, FileDialogKind, FileDialogResponder, FileFilter};
 pub use draw::{
-    Blend, Canvas, Cap, Clip, Curve, CurveData, CurveSegment, CurveVerb, Fill, Join, Paint,
-    PixelRect, Shader, Stroke, WeakCurve,
+    Blend, Canvas, Cap, Clip, Curve, CurveData, CurveSegment, CurveVerb, Fill, Filter, Gradient,
+    GradientKind, GradientStop, ImageShader, Join, LayerOptions, Paint, PixelRect, Shader, Shape,
+    Spread, Stroke, WeakCurve,
 };
 pub use event::{
-    CursorIcon, Gesture, ImeEvent, Key, KeyEvent, KeyPressEvent, Modifiers, NamedKey, PanGesture,
-    Pointer, PointerButton, PointerButtonEvent, PointerEvent, PointerId, PointerMoveEvent,
-    PointerPropagate, PointerScrollEvent, Propagate, ScrollDelta, TapGesture, TextEvent,
-    TextPasteEvent, Touch, TouchEvent, TouchId, TouchMoveEvent, TouchPressEvent, TouchPropagate,
-    TouchSettings,
+    BackEdge, BackEvent, BackGesture, Code, Cursor, CursorGrab, CursorIcon, FlingGesture, Gesture,
+    ImeEvent, Key, KeyEvent, KeyInput, KeyPressEvent, Location, Modifiers, NamedKey, PanGesture,
+    PinchGesture, Pointer, PointerButton, PointerButtonEvent, PointerEvent, PointerId,
+    PointerMoveEvent, PointerPropagate, PointerScrollEvent, Propagate, RotateGesture, ScrollDelta,
+    Seat, SeatId, TapGesture, TextEvent, TextPasteEvent, Tool, ToolKind, Touch, TouchEvent,
+    TouchId, TouchMoveEvent, TouchPressEvent, TouchPropagate, TouchSettings,
+};
+pub use image::{Image, ImageData, WeakImage};
+pub use layout::{
+    BorderWidth, CornerRadius, LayoutDirection, Padding, pixel_ceil, pixel_floor, pixel_round,
 };
-pub use layout::{BorderWidth, CornerRadius, Padding, pixel_ceil, pixel_floor, pixel_round};
 pub use math::{Affine, Matrix, Offset, Point, Rect, Size, Space};
-pub use painter::Painter;
-pub use record::{RecordSettings, Recorder, Recording, RecordingData, WeakRecording};
-pub use svg::{Svg, SvgData, WeakSvg};
+pub use painter::{Painter, TextCacheStats};
+pub use playback::{InputEvent, InputRecording, ParseInputError, Playback, RecordedInput};
+pub use popup::{PopupAlign, PopupAnchor, PopupSide};
+pub use print::{PageSetup, Printer};
+pub use record::{
+    MemoryPressure, RecordSettings, Recorder, Recording, RecordingData, WeakRecording,
+};
+pub use svg::{Svg, SvgData, SvgStyle, WeakSvg};
 pub use text::{
     FontStretch, FontStyle, FontWeight, GlyphCluster, Paragraph, TextAlign, TextDirection,
-    TextLayoutLine, TextStyle, TextWrap, WeakParagraph,
+    TextLayoutLine, TextLink, TextStyle, TextWrap, WeakParagraph,
+};
+pub use timings::FrameTimings;
+pub use transition::{
+    AnimationSettings, Interpolate, Keyframe, Spring, Transition, TransitionCurve, Transitioned,
 };
-pub use transition::{Interpolate, Transition, TransitionCurve, Transitioned};
 pub use widget::{AnyWidgetId, ChildUpdate, Update, Widget, WidgetId, WidgetState};
-pub use window::{Layer, LayerId, Window, WindowId, WindowSizing};
+pub use window::{
+    ColorScheme, Layer, LayerId, Lifecycle, Presentation, ResizeEdge, Window, WindowGeometry,
+    WindowId, WindowLevel, WindowSizing,
+};
 pub use world::{
-    AnyWidget, GetError, ImeSignal, RenderSettings, Settings, Signal, WidgetMut, WidgetRef,
-    WindowUpdate, World,
+    AnimationClock, AnyWidget, GetError, ImePurpose, ImeSignal, RenderSettings, Settings, Signal,
+    SvgResponder, Throttle, ThrottleSettings, WidgetMut, WidgetRef, WindowUpdate, World,
 };
@@ -39,6 +39,10 @@ impl Offset {
         }
     }
 
+    pub fn length(self) -> f32 {
+        f32::hypot(self.x, self.y)
+    }
+
     pub const fn floor(self) -> Self {
         Self {
             x: self.x.floor(),
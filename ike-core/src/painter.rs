@@ -6,4 +6,49 @@ pub trait Painter {
     fn measure_text(&mut self, paragraph: &Paragraph, max_width: f32) -> Size;
 
     fn layout_text(&mut self, paragraph: &Paragraph, max_width: f32) -> Vec<TextLayoutLine>;
+
+    /// Shape `paragraphs` ahead of them being measured, each with the max width it's expected
+    /// to be measured with.
+    ///
+    /// Called before layout with the text of the widgets about to be laid out, so painters can
+    /// shape it in parallel and memoize the sizes. The expected widths are guesses, paragraphs
+    /// measured with another width are shaped again by [`Painter::measure_text`].
+    fn prepare_text(&mut self, paragraphs: &[(&Paragraph, f32)]) {
+        let _ = paragraphs;
+    }
+
+    /// The usage of the cache of shaped text, if the painter keeps one.
+    fn text_cache_stats(&self) -> Option<TextCacheStats> {
+        None
+    }
+}
+
+/// The usage of the cache a [`Painter`] keeps shaped text in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextCacheStats {
+    /// The number of shaped paragraphs cached.
+    pub entries: usize,
+
+    /// The estimated memory used by the cached paragraphs.
+    pub bytes: u64,
+
+    /// The memory the cache is allowed to use before evicting the least recently used
+    /// paragraphs.
+    pub budget: u64,
+
+    /// The number of times shaped text was found in the cache.
+    pub hits: u64,
+
+    /// The number of times text had to be shaped.
+    pub misses: u64,
+}
+
+impl TextCacheStats {
+    /// The fraction of lookups found in the cache, between `0.0` and `1.0`.
+    pub fn hit_rate(&self) -> f32 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f32 / total as f32,
+        }
+    }
 }
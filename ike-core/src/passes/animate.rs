@@ -2,8 +2,22 @@ use std::time::Duration;
 
 use crate::{WidgetMut, WindowId, World, passes};
 
-pub(crate) fn animate_window(world: &mut World, window: WindowId, delta_time: Duration) {
-    let Some(window) = world.window(window) else {
+pub(crate) fn animate_window(world: &mut World, window_id: WindowId, delta_time: Duration) {
+    if let Some(window) = world.window(window_id) {
+        window.animate_requested.set(false);
+    }
+
+    passes::touch::long_taps(world, window_id);
+
+    let Some(window) = world.window_mut(window_id) else {
+        return;
+    };
+
+    if window.color.animate(delta_time) {
+        world.state.request_animate(window_id);
+    }
+
+    let Some(window) = world.window(window_id) else {
         return;
     };
 
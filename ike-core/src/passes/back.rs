@@ -0,0 +1,56 @@
+use crate::{
+    BackEvent, Key, KeyInput, NamedKey, SeatId, WidgetMut, WindowId, World, passes,
+    widgets::Navigator,
+};
+
+/// Handle a back navigation of `window`, returning whether it was, or will be, handled.
+///
+/// Invoking goes back like the back key, gestures move the innermost navigator along, unless
+/// there's a dismiss scope to dismiss instead.
+pub(crate) fn back(world: &mut World, window: WindowId, event: BackEvent) -> bool {
+    match event {
+        BackEvent::Started(gesture) | BackEvent::Progressed(gesture) => {
+            if passes::dismiss::find_scope(world, window).is_some() {
+                return true;
+            }
+
+            with_navigator(world, window, |navigator| {
+                Navigator::set_back_progress(navigator, gesture.progress);
+            })
+        }
+
+        BackEvent::Cancelled => with_navigator(world, window, Navigator::cancel_back),
+
+        BackEvent::Invoked => {
+            let input = KeyInput::new(Key::Named(NamedKey::GoBack));
+            let seat = SeatId::PRIMARY;
+
+            let handled = passes::key::pressed(world, window, seat, input.clone(), true);
+            passes::key::pressed(world, window, seat, input, false);
+            handled
+        }
+    }
+}
+
+fn with_navigator(
+    world: &mut World,
+    window: WindowId,
+    f: impl FnOnce(&mut WidgetMut<'_, Navigator>),
+) -> bool {
+    let Some(navigator) = passes::dismiss::find_navigator(world, window) else {
+        return false;
+    };
+
+    match world.widget_mut(navigator) {
+        Ok(widget) => match widget.downcast::<Navigator>() {
+            Some(mut navigator) => {
+                f(&mut navigator);
+                true
+            }
+
+            None => false,
+        },
+
+        Err(_) => false,
+    }
+}
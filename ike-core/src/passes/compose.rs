@@ -8,6 +8,7 @@ pub(crate) fn compose_window(world: &mut World, window: WindowId) {
     };
 
     let scale = window.scale();
+    let zoom = window.zoom();
 
     for i in 0..window.layers.len() {
         // compose the layers widget
@@ -15,9 +16,11 @@ pub(crate) fn compose_window(world: &mut World, window: WindowId) {
             && let Some(layer) = window.layers().get(i)
             && let Ok(widget) = world.widget_mut(layer.widget)
         {
-            compose_widget(widget, Affine::IDENTITY, scale);
+            compose_widget(widget, Affine::scale(zoom, zoom), scale);
         }
     }
+
+    world.state.heroes.prune(window_id);
 }
 
 pub(crate) fn compose_widget(
@@ -45,7 +48,7 @@ pub(crate) fn compose_widget(
     let mut cx = widget.cx.as_compose_cx(scale);
     widget.widget.compose(&mut cx);
 
-    let mut bounds = widget.cx.rect();
+    let mut bounds = widget.cx.rect().expand(widget.cx.state.overflow);
 
     let mut widget = passes::hierarchy::for_each_child(widget, |child| {
         if let Ok(child) = child
@@ -1,7 +1,10 @@
+use std::{fmt::Write, time::Duration};
+
 use crate::{
-    Affine, BorderWidth, Canvas, Clip, Color, CornerRadius, FontStretch, FontStyle, FontWeight,
-    Offset, Paint, Paragraph, Point, TextAlign, TextStyle, TextWrap, WidgetRef, WindowId, World,
-    record::DisplayMemorySize,
+    Affine, Allocations, AnyWidget, BorderWidth, Canvas, Clip, Color, CornerRadius, FontStretch,
+    FontStyle, FontWeight, Offset, Paint, Paragraph, Point, Rect, Shader, Size, TextAlign,
+    TextStyle, TextWrap, WidgetRef, WindowId, World, allocations::FramePhase, passes,
+    record::DisplayMemorySize, timings::FrameHistory, widgets,
 };
 
 pub(crate) fn bounds_overlay_window(world: &World, window: WindowId, canvas: &mut dyn Canvas) {
@@ -45,12 +48,17 @@ pub(crate) fn recorder_overlay_window(world: &World, window: WindowId, canvas: &
         }
     }
 
+    let memory_budget = world
+        .settings()
+        .record
+        .memory_budget(world.memory_pressure());
+
     let mut paragraph = Paragraph::new(1.0, TextAlign::Start, TextWrap::Word);
     paragraph.push(
         format!(
             "{} / {}",
             DisplayMemorySize(world.recorder().memory_usage()),
-            DisplayMemorySize(world.settings().record.max_memory_usage),
+            DisplayMemorySize(memory_budget),
         ),
         TextStyle {
             font_size:    16.0,
@@ -140,3 +148,634 @@ pub(crate) fn recorder_overlay_widget(widget: &WidgetRef<'_>, canvas: &mut dyn C
         );
     }
 }
+
+pub(crate) fn contrast_overlay_window(world: &World, window: WindowId, canvas: &mut dyn Canvas) {
+    let Some(window) = world.window(window) else {
+        return;
+    };
+
+    let scale = window.scale();
+
+    for layer in window.layers() {
+        if let Ok(widget) = world.widget(layer.widget) {
+            contrast_overlay_widget(&widget, scale, canvas);
+        }
+    }
+}
+
+pub(crate) fn contrast_overlay_widget(widget: &WidgetRef<'_>, scale: f32, canvas: &mut dyn Canvas) {
+    if widget.cx.is_stashed() {
+        return;
+    }
+
+    for child in widget.cx.iter_children().flatten() {
+        contrast_overlay_widget(&child, scale, canvas);
+    }
+
+    let Some(paragraph) = widget.widget.paragraph() else {
+        return;
+    };
+
+    let Some((_, style)) = paragraph
+        .sections()
+        .find(|(text, _)| !text.trim().is_empty())
+    else {
+        return;
+    };
+
+    let Shader::Solid(color) = style.paint.shader else {
+        return;
+    };
+
+    // the corner of the text is assumed to show the background
+    let corner = widget.cx.global_transform() * Point::new(0.5, 0.5);
+    let physical = Point::new(corner.x * scale, corner.y * scale);
+
+    let Some(background) = canvas.sample(physical) else {
+        return;
+    };
+
+    let color = background.mix_rgb(color, color.a);
+    let ratio = color.contrast_ratio(background);
+
+    let is_large = style.font_size >= 24.0
+        || style.font_size >= 18.66 && style.font_weight.0 >= FontWeight::BOLD.0;
+
+    let minimum = match is_large {
+        true => 3.0,
+        false => 4.5,
+    };
+
+    if ratio >= minimum {
+        return;
+    }
+
+    let mut paragraph = Paragraph::new(1.0, TextAlign::Start, TextWrap::None);
+    paragraph.push(
+        format!("{ratio:.2}:1"),
+        TextStyle {
+            font_size:    10.0,
+            font_family:  String::from("Inter Variable"),
+            font_weight:  FontWeight::BOLD,
+            font_stretch: FontStretch::Normal,
+            font_style:   FontStyle::Normal,
+            paint:        Paint::from(Color::WHITE),
+        },
+    );
+
+    let size = canvas.painter().measure_text(&paragraph, f32::INFINITY);
+
+    canvas.transform(
+        widget.cx.global_transform(),
+        &mut |canvas| {
+            canvas.draw_border(
+                widget.cx.rect(),
+                BorderWidth::all(1.0),
+                CornerRadius::all(0.0),
+                &Paint::from(Color::RED),
+            );
+
+            canvas.draw_rect(
+                Rect::min_size(Point::ORIGIN, size),
+                CornerRadius::all(0.0),
+                &Paint::from(Color::RED),
+            );
+
+            canvas.draw_text(&paragraph, f32::INFINITY, Offset::ZERO);
+        },
+    );
+}
+
+pub(crate) fn allocation_overlay_window(world: &World, window: WindowId, canvas: &mut dyn Canvas) {
+    let Some(window) = world.window(window) else {
+        return;
+    };
+
+    let allocations = world.frame_allocations();
+    let mut text = String::new();
+
+    if Allocations::current_thread() == Allocations::ZERO {
+        text.push_str("allocations aren't counted, see `CountingAllocator`\n");
+    }
+
+    for phase in FramePhase::ALL {
+        let Allocations { count, bytes } = allocations.get(phase);
+        let _ = writeln!(
+            text,
+            "{phase:?}: {count} ({})",
+            DisplayMemorySize(bytes),
+        );
+    }
+
+    let Allocations { count, bytes } = allocations.total();
+    let _ = write!(
+        text,
+        "Total: {count} ({})",
+        DisplayMemorySize(bytes)
+    );
+
+    let mut paragraph = Paragraph::new(1.0, TextAlign::End, TextWrap::None);
+    paragraph.push(
+        text,
+        TextStyle {
+            font_size:    12.0,
+            font_family:  String::from("Inter Variable"),
+            font_weight:  FontWeight::NORMAL,
+            font_stretch: FontStretch::Normal,
+            font_style:   FontStyle::Normal,
+            paint:        Paint::from(Color::RED),
+        },
+    );
+
+    let width = canvas
+        .painter()
+        .measure_text(&paragraph, f32::INFINITY)
+        .width;
+
+    canvas.draw_text(
+        &paragraph,
+        f32::INFINITY,
+        Offset::new(window.size().width - width - 4.0, 4.0),
+    );
+}
+
+pub(crate) fn performance_overlay_window(world: &World, window: WindowId, canvas: &mut dyn Canvas) {
+    let Some(window) = world.window(window) else {
+        return;
+    };
+
+    // the graphs are scaled so a frame at 60 fps fills the width of the bar
+    const BUDGET: f32 = 1000.0 / 60.0;
+    const WIDTH: f32 = FrameHistory::LEN as f32 * 2.0;
+    const GRAPH_HEIGHT: f32 = 48.0;
+
+    let timings = world.frame_timings();
+    let history = &window.frame_history;
+
+    let style = |color: Color| TextStyle {
+        font_size:    12.0,
+        font_family:  String::from("Inter Variable"),
+        font_weight:  FontWeight::NORMAL,
+        font_stretch: FontStretch::Normal,
+        font_style:   FontStyle::Normal,
+        paint:        Paint::from(color),
+    };
+
+    let mut paragraph = Paragraph::new(1.0, TextAlign::Start, TextWrap::None);
+    paragraph.push(
+        format!(
+            "{:.0} fps, {:.2} ms\n",
+            history.fps(),
+            millis(timings.total()),
+        ),
+        style(Color::WHITE),
+    );
+
+    for phase in FramePhase::ALL {
+        paragraph.push(
+            format!(
+                "{phase:?}: {:.2} ms\n",
+                millis(timings.get(phase))
+            ),
+            style(phase_color(phase)),
+        );
+    }
+
+    let count = world.widget_count();
+    let capacity = world.widget_capacity();
+
+    paragraph.push(
+        format!(
+            "{count} widgets in {capacity} slots ({:.0}%)",
+            count as f32 / capacity.max(1) as f32 * 100.0,
+        ),
+        style(Color::WHITE),
+    );
+
+    if let Some(stats) = canvas.painter().text_cache_stats() {
+        paragraph.push(
+            format!(
+                "\n{} paragraphs cached, {}/{}, {:.0}% hits",
+                stats.entries,
+                DisplayMemorySize(stats.bytes),
+                DisplayMemorySize(stats.budget),
+                stats.hit_rate() * 100.0,
+            ),
+            style(Color::WHITE),
+        );
+    }
+
+    let text = canvas.painter().measure_text(&paragraph, f32::INFINITY);
+    let height = text.height + 8.0 + 6.0 + 8.0 + GRAPH_HEIGHT;
+
+    let origin = Point::new(8.0, window.size().height - height - 8.0);
+
+    canvas.draw_rect(
+        Rect::min_size(origin, Size::new(WIDTH, height)).expand(4.0),
+        CornerRadius::all(4.0),
+        &Paint::from(Color::BLACK.fade(0.8)),
+    );
+
+    canvas.draw_text(
+        &paragraph,
+        f32::INFINITY,
+        origin - Point::ORIGIN,
+    );
+
+    // the phases of the last frame, stacked
+    let mut x = origin.x;
+    let y = origin.y + text.height + 8.0;
+
+    for phase in FramePhase::ALL {
+        let width = millis(timings.get(phase)) / BUDGET * WIDTH;
+        let width = width.min(origin.x + WIDTH - x);
+
+        canvas.draw_rect(
+            Rect::min_size(Point::new(x, y), Size::new(width, 6.0)),
+            CornerRadius::all(0.0),
+            &Paint::from(phase_color(phase)),
+        );
+
+        x += width;
+    }
+
+    // the time between recent frames, a bar each, with the line at the budget
+    let bottom = y + 6.0 + 8.0 + GRAPH_HEIGHT;
+    let offset = FrameHistory::LEN - history.intervals().len();
+
+    for (i, interval) in history.intervals().enumerate() {
+        let ratio = millis(interval) / (BUDGET * 2.0);
+        let height = f32::min(ratio, 1.0) * GRAPH_HEIGHT;
+
+        let color = match ratio > 0.5 {
+            true => Color::RED,
+            false => Color::GREEN,
+        };
+
+        canvas.draw_rect(
+            Rect::min_size(
+                Point::new(
+                    origin.x + (offset + i) as f32 * 2.0,
+                    bottom - height,
+                ),
+                Size::new(2.0, height),
+            ),
+            CornerRadius::all(0.0),
+            &Paint::from(color.fade(0.8)),
+        );
+    }
+
+    canvas.draw_rect(
+        Rect::min_size(
+            Point::new(origin.x, bottom - GRAPH_HEIGHT / 2.0),
+            Size::new(WIDTH, 1.0),
+        ),
+        CornerRadius::all(0.0),
+        &Paint::from(Color::WHITE.fade(0.5)),
+    );
+}
+
+fn phase_color(phase: FramePhase) -> Color {
+    match phase {
+        FramePhase::Animate => Color::PURPLE,
+        FramePhase::Layout => Color::CYAN,
+        FramePhase::Compose => Color::BLUE,
+        FramePhase::Hover => Color::WHITE,
+        FramePhase::Record => Color::YELLOW,
+        FramePhase::Draw => Color::GREEN,
+        FramePhase::Sample => Color::RED,
+    }
+}
+
+fn millis(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+pub(crate) fn layout_overlay_window(world: &World, window: WindowId, canvas: &mut dyn Canvas) {
+    let Some(window) = world.window(window) else {
+        return;
+    };
+
+    for layer in window.layers() {
+        if let Ok(widget) = world.widget(layer.widget) {
+            layout_overlay_widget(&widget, canvas);
+        }
+    }
+}
+
+pub(crate) fn layout_overlay_widget(widget: &WidgetRef<'_>, canvas: &mut dyn Canvas) {
+    if widget.cx.is_stashed() {
+        return;
+    }
+
+    let rect = widget.cx.rect();
+
+    // the baselines are laid out before drawing, as the canvas is borrowed while drawing
+    let baselines: Vec<_> = (widget.widget.paragraph())
+        .map(|paragraph| canvas.painter().layout_text(paragraph, widget.cx.width()))
+        .unwrap_or_default();
+
+    canvas.transform(
+        widget.cx.global_transform(),
+        &mut |canvas| {
+            let mut inner = rect;
+
+            if let Some(container) = widgets::Container::downcast_ref(&*widget.widget) {
+                let border = container.border_width();
+
+                inner = Rect {
+                    min: Point::new(
+                        rect.min.x + border.left,
+                        rect.min.y + border.top,
+                    ),
+                    max: Point::new(
+                        rect.max.x - border.right,
+                        rect.max.y - border.bottom,
+                    ),
+                };
+
+                for border in inset_rects(rect, inner) {
+                    canvas.draw_rect(
+                        border,
+                        CornerRadius::all(0.0),
+                        &Paint::from(Color::YELLOW.fade(0.4)),
+                    );
+                }
+            }
+
+            if let Some(content) = content_rect(widget) {
+                for padding in inset_rects(inner, content.intersection(inner)) {
+                    canvas.draw_rect(
+                        padding,
+                        CornerRadius::all(0.0),
+                        &Paint::from(Color::GREEN.fade(0.25)),
+                    );
+                }
+            }
+
+            if let Some(stack) = widgets::Stack::downcast_ref(&*widget.widget) {
+                let axis = stack.axis();
+                let (_, minor) = axis.unpack_size(widget.cx.size());
+
+                for (index, allocated) in stack.allocated() {
+                    let Ok(child) = widget.cx.get_nth_child(index) else {
+                        continue;
+                    };
+
+                    let (major, _) = axis.unpack_point(child.cx.transform() * Point::ORIGIN);
+
+                    let slot = Rect::min_size(
+                        axis.pack_point(major, 0.0),
+                        axis.pack_size(allocated, minor),
+                    );
+
+                    canvas.draw_rect(
+                        slot,
+                        CornerRadius::all(0.0),
+                        &Paint::from(Color::PURPLE.fade(0.15)),
+                    );
+
+                    canvas.draw_border(
+                        slot,
+                        BorderWidth::all(1.0),
+                        CornerRadius::all(0.0),
+                        &Paint::from(Color::PURPLE.fade(0.6)),
+                    );
+                }
+            }
+
+            for line in &baselines {
+                canvas.draw_rect(
+                    Rect {
+                        min: Point::new(line.left(), line.baseline - 0.5),
+                        max: Point::new(line.right(), line.baseline + 0.5),
+                    },
+                    CornerRadius::all(0.0),
+                    &Paint::from(Color::CYAN),
+                );
+            }
+
+            if let Some(clip) = widget.cx.clip() {
+                canvas.draw_border(
+                    clip.bounds(),
+                    BorderWidth::all(1.0),
+                    CornerRadius::all(0.0),
+                    &Paint::from(Color::rgb(1.0, 0.5, 0.0).fade(0.8)),
+                );
+            }
+
+            canvas.draw_border(
+                rect,
+                BorderWidth::all(1.0),
+                CornerRadius::all(0.0),
+                &Paint::from(Color::RED.fade(0.6)),
+            );
+        },
+    );
+
+    for child in widget.cx.iter_children().flatten() {
+        layout_overlay_widget(&child, canvas);
+    }
+}
+
+pub(crate) fn inspector_overlay_window(world: &World, window: WindowId, canvas: &mut dyn Canvas) {
+    let Some(window) = world.window(window) else {
+        return;
+    };
+
+    let Some(widget) = passes::inspect::inspected(world, window.id()) else {
+        return;
+    };
+
+    let rect = widget.cx.rect();
+
+    let content = content_rect(&widget).map(|content| content.intersection(rect));
+
+    canvas.transform(
+        widget.cx.global_transform(),
+        &mut |canvas| {
+            canvas.draw_rect(
+                rect,
+                CornerRadius::all(0.0),
+                &Paint::from(Color::BLUE.fade(0.2)),
+            );
+
+            if let Some(content) = content {
+                for padding in inset_rects(rect, content) {
+                    canvas.draw_rect(
+                        padding,
+                        CornerRadius::all(0.0),
+                        &Paint::from(Color::GREEN.fade(0.3)),
+                    );
+                }
+            }
+
+            for child in widget.cx.iter_children().flatten() {
+                canvas.draw_border(
+                    child.cx.rect().transform_bounds(child.cx.transform()),
+                    BorderWidth::all(1.0),
+                    CornerRadius::all(0.0),
+                    &Paint::from(Color::BLUE.fade(0.5)),
+                );
+            }
+
+            canvas.draw_border(
+                rect,
+                BorderWidth::all(1.0),
+                CornerRadius::all(0.0),
+                &Paint::from(Color::BLUE),
+            );
+        },
+    );
+
+    let bounds = passes::inspect::global_rect(&widget);
+    let mut text = passes::inspect::describe(&widget);
+
+    let _ = write!(
+        text,
+        "\n({:.1}, {:.1}) {:.1}x{:.1}",
+        bounds.left(),
+        bounds.top(),
+        widget.cx.width(),
+        widget.cx.height(),
+    );
+
+    if let Some(test_id) = widget.cx.test_id() {
+        let _ = write!(text, "\ntest id: {test_id}");
+    }
+
+    if let Some(content) = content {
+        let _ = write!(
+            text,
+            "\npadding: {:.1} {:.1} {:.1} {:.1}",
+            content.top() - rect.top(),
+            rect.right() - content.right(),
+            rect.bottom() - content.bottom(),
+            content.left() - rect.left(),
+        );
+    }
+
+    let flags = [
+        ("hovered", widget.cx.is_hovered()),
+        ("active", widget.cx.is_active()),
+        ("focused", widget.cx.is_focused()),
+        ("stashed", widget.cx.is_stashed()),
+        ("disabled", widget.cx.is_disabled()),
+    ];
+
+    let accepts = [
+        (
+            "pointer",
+            widget.cx.hierarchy.accepts_pointer(),
+        ),
+        (
+            "focus",
+            widget.cx.hierarchy.accepts_focus(),
+        ),
+        (
+            "text",
+            widget.cx.hierarchy.accepts_text(),
+        ),
+        (
+            "dismiss",
+            widget.cx.hierarchy.accepts_dismiss(),
+        ),
+    ];
+
+    for (label, flags) in [("state", &flags[..]), ("accepts", &accepts[..])] {
+        let names: Vec<_> = flags
+            .iter()
+            .filter_map(|&(name, is_set)| is_set.then_some(name))
+            .collect();
+
+        if !names.is_empty() {
+            let _ = write!(text, "\n{label}: {}", names.join(", "));
+        }
+    }
+
+    let mut paragraph = Paragraph::new(1.0, TextAlign::Start, TextWrap::None);
+    paragraph.push(
+        text,
+        TextStyle {
+            font_size:    12.0,
+            font_family:  String::from("Inter Variable"),
+            font_weight:  FontWeight::NORMAL,
+            font_stretch: FontStretch::Normal,
+            font_style:   FontStyle::Normal,
+            paint:        Paint::from(Color::WHITE),
+        },
+    );
+
+    let size = canvas.painter().measure_text(&paragraph, f32::INFINITY);
+    let window_size = window.size();
+
+    // below the widget, or above it when there's no room
+    let mut y = bounds.bottom() + 8.0;
+
+    if y + size.height + 4.0 > window_size.height {
+        y = bounds.top() - size.height - 8.0;
+    }
+
+    let x = f32::min(
+        bounds.left() + 4.0,
+        window_size.width - size.width - 4.0,
+    );
+    let position = Point::new(x.max(4.0), y.max(4.0));
+
+    canvas.draw_rect(
+        Rect::min_size(position, size).expand(4.0),
+        CornerRadius::all(4.0),
+        &Paint::from(Color::BLACK.fade(0.8)),
+    );
+
+    canvas.draw_text(
+        &paragraph,
+        f32::INFINITY,
+        position - Point::ORIGIN,
+    );
+}
+
+/// The bounds of the children of `widget`, in its local space.
+fn content_rect(widget: &WidgetRef<'_>) -> Option<Rect> {
+    widget
+        .cx
+        .iter_children()
+        .flatten()
+        .map(|child| child.cx.rect().transform_bounds(child.cx.transform()))
+        .reduce(union)
+}
+
+/// The top, bottom, left and right bands of `outer` around `inner`.
+fn inset_rects(outer: Rect, inner: Rect) -> [Rect; 4] {
+    [
+        Rect {
+            min: outer.min,
+            max: Point::new(outer.max.x, inner.min.y),
+        },
+        Rect {
+            min: Point::new(outer.min.x, inner.max.y),
+            max: outer.max,
+        },
+        Rect {
+            min: Point::new(outer.min.x, inner.min.y),
+            max: Point::new(inner.min.x, inner.max.y),
+        },
+        Rect {
+            min: Point::new(inner.max.x, inner.min.y),
+            max: Point::new(outer.max.x, inner.max.y),
+        },
+    ]
+}
+
+fn union(a: Rect, b: Rect) -> Rect {
+    Rect {
+        min: Point::new(
+            a.min.x.min(b.min.x),
+            a.min.y.min(b.min.y),
+        ),
+        max: Point::new(
+            a.max.x.max(b.max.x),
+            a.max.y.max(b.max.y),
+        ),
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    AnyWidget, GetError, Update, WidgetId, WidgetRef, WindowId, World, passes, widgets::Navigator,
+};
+
+/// Find the innermost dismiss scope of `window`, preferring scopes drawn on top.
+pub(crate) fn find_scope(world: &World, window: WindowId) -> Option<WidgetId> {
+    let window = world.window(window)?;
+
+    for layer in window.layers.iter().rev() {
+        if let Ok(root) = world.widget(layer.widget)
+            && let Some(scope) = find_scope_in(&root)
+        {
+            return Some(scope);
+        }
+    }
+
+    None
+}
+
+fn find_scope_in(widget: &WidgetRef<'_>) -> Option<WidgetId> {
+    if widget.cx.is_stashed() {
+        return None;
+    }
+
+    let children = widget.cx.iter_children().rev();
+
+    for child in children.flatten() {
+        if let Some(scope) = find_scope_in(&child) {
+            return Some(scope);
+        }
+    }
+
+    widget
+        .cx
+        .hierarchy
+        .accepts_dismiss()
+        .then(|| widget.cx.id())
+}
+
+/// Dismiss the innermost scope of `window`, returning whether there was one.
+pub(crate) fn dismiss(world: &mut World, window: WindowId) -> bool {
+    match find_scope(world, window) {
+        Some(scope) => send_dismiss(world, scope).is_ok(),
+        None => false,
+    }
+}
+
+/// Dismiss the innermost scope of `window` if `target` is outside of it, returning the dismissed
+/// scope.
+pub(crate) fn dismiss_outside(
+    world: &mut World,
+    window: WindowId,
+    target: Option<WidgetId>,
+) -> Option<WidgetId> {
+    let scope = find_scope(world, window)?;
+
+    if let Some(target) = target
+        && passes::hierarchy::is_descendant(&world.widgets, scope, target)
+    {
+        return None;
+    }
+
+    send_dismiss(world, scope).ok()?;
+    Some(scope)
+}
+
+fn send_dismiss(world: &mut World, scope: WidgetId) -> Result<(), GetError> {
+    let mut widget = world.widget_mut(scope)?;
+    passes::update::widget(&mut widget, Update::Dismiss);
+    Ok(())
+}
+
+/// Go back in the innermost [`Navigator`] of `window` that can, returning whether there was one.
+pub(crate) fn go_back(world: &mut World, window: WindowId) -> bool {
+    let Some(navigator) = find_navigator(world, window) else {
+        return false;
+    };
+
+    match world.widget_mut(navigator) {
+        Ok(widget) => match widget.downcast::<Navigator>() {
+            Some(mut navigator) => Navigator::go_back(&mut navigator),
+            None => false,
+        },
+
+        Err(_) => false,
+    }
+}
+
+pub(crate) fn find_navigator(world: &World, window: WindowId) -> Option<WidgetId> {
+    let window = world.window(window)?;
+
+    for layer in window.layers.iter().rev() {
+        if let Ok(root) = world.widget(layer.widget)
+            && let Some(navigator) = find_navigator_in(&root)
+        {
+            return Some(navigator);
+        }
+    }
+
+    None
+}
+
+fn find_navigator_in(widget: &WidgetRef<'_>) -> Option<WidgetId> {
+    if widget.cx.is_stashed() {
+        return None;
+    }
+
+    let children = widget.cx.iter_children().rev();
+
+    for child in children.flatten() {
+        if let Some(navigator) = find_navigator_in(&child) {
+            return Some(navigator);
+        }
+    }
+
+    Navigator::downcast_ref(&*widget.widget)
+        .is_some_and(Navigator::can_go_back)
+        .then(|| widget.cx.id())
+}
@@ -1,4 +1,4 @@
-use crate::{Canvas, WidgetMut, WindowId, World};
+use crate::{Affine, Canvas, LayerOptions, WidgetMut, WindowId, World};
 
 pub(crate) fn draw_window(world: &mut World, window: WindowId, canvas: &mut dyn Canvas) {
     let Some(window) = world.window(window) else {
@@ -6,12 +6,19 @@ pub(crate) fn draw_window(world: &mut World, window: WindowId, canvas: &mut dyn
     };
 
     let scale = window.scale();
+    let zoom = window.zoom();
+    let layers = window.layers.clone();
 
-    for layer in window.layers.clone().iter() {
-        if let Ok(mut widget) = world.widget_mut(layer.widget) {
-            draw_widget(&mut widget, canvas, scale);
-        }
-    }
+    canvas.transform(
+        Affine::scale(zoom, zoom),
+        &mut |canvas| {
+            for layer in layers.iter() {
+                if let Ok(mut widget) = world.widget_mut(layer.widget) {
+                    draw_widget(&mut widget, canvas, scale);
+                }
+            }
+        },
+    );
 }
 
 pub(crate) fn draw_widget(widget: &mut WidgetMut<'_>, canvas: &mut dyn Canvas, scale: f32) {
@@ -42,13 +49,23 @@ pub(crate) fn draw_widget(widget: &mut WidgetMut<'_>, canvas: &mut dyn Canvas, s
 }
 
 pub(crate) fn draw_widget_clipped(widget: &mut WidgetMut<'_>, canvas: &mut dyn Canvas, scale: f32) {
-    if let Some(ref clip) = widget.cx.state.clip {
-        canvas.clip(&clip.clone(), &mut |canvas| {
-            draw_widget_raw(widget, canvas, scale);
-        });
-    } else {
-        draw_widget_raw(widget, canvas, scale);
-    }
+    with_opacity(
+        widget.cx.state.opacity,
+        canvas,
+        &mut |canvas| {
+            widget
+                .widget
+                .draw_behind(&mut widget.cx.as_draw_cx(), canvas);
+
+            if let Some(ref clip) = widget.cx.state.clip {
+                canvas.clip(&clip.clone(), &mut |canvas| {
+                    draw_widget_raw(widget, canvas, scale);
+                });
+            } else {
+                draw_widget_raw(widget, canvas, scale);
+            }
+        },
+    );
 }
 
 pub(crate) fn draw_widget_raw(widget: &mut WidgetMut<'_>, canvas: &mut dyn Canvas, scale: f32) {
@@ -62,3 +79,54 @@ pub(crate) fn draw_widget_raw(widget: &mut WidgetMut<'_>, canvas: &mut dyn Canva
 
     widget.widget.draw_over(&mut widget.cx.as_draw_cx(), canvas);
 }
+
+// recordings are rasterized, so they are skipped entirely when exporting
+pub(crate) fn export_widget(widget: &mut WidgetMut<'_>, canvas: &mut dyn Canvas) {
+    with_opacity(
+        widget.cx.state.opacity,
+        canvas,
+        &mut |canvas| {
+            widget
+                .widget
+                .draw_behind(&mut widget.cx.as_draw_cx(), canvas);
+
+            if let Some(ref clip) = widget.cx.state.clip {
+                canvas.clip(&clip.clone(), &mut |canvas| {
+                    export_widget_raw(widget, canvas);
+                });
+            } else {
+                export_widget_raw(widget, canvas);
+            }
+        },
+    );
+}
+
+// fully transparent widgets are skipped, and opaque ones don't need a layer
+fn with_opacity(opacity: f32, canvas: &mut dyn Canvas, f: &mut dyn FnMut(&mut dyn Canvas)) {
+    if opacity >= 1.0 {
+        f(canvas);
+    } else if opacity > 0.0 {
+        let options = LayerOptions {
+            opacity,
+            ..Default::default()
+        };
+
+        canvas.layer(&options, f);
+    }
+}
+
+fn export_widget_raw(widget: &mut WidgetMut<'_>, canvas: &mut dyn Canvas) {
+    widget.widget.draw(&mut widget.cx.as_draw_cx(), canvas);
+
+    for &child in widget.cx.hierarchy.children.iter() {
+        if let Ok(mut child) = widget.cx.get_widget_mut(child)
+            && !child.cx.is_stashed()
+        {
+            canvas.transform(child.cx.transform(), &mut |canvas| {
+                export_widget(&mut child, canvas);
+            });
+        }
+    }
+
+    widget.widget.draw_over(&mut widget.cx.as_draw_cx(), canvas);
+}
@@ -1,8 +1,13 @@
-use crate::{EventCx, Widget, WidgetId, WindowId, World, context::FocusUpdate, passes};
+use crate::{
+    EventCx, SeatId, Widget, WidgetId, WindowId, World,
+    context::{FocusUpdate, GrabUpdate},
+    passes,
+};
 
 pub(crate) fn send_event<P>(
     world: &mut World,
     window: WindowId,
+    seat: SeatId,
     target: WidgetId,
     bubble: P,
     on_event: impl Fn(&mut dyn Widget, &mut EventCx<'_>) -> P,
@@ -11,6 +16,7 @@ where
     P: Eq + Copy,
 {
     let mut focus = FocusUpdate::None;
+    let mut grab = GrabUpdate::None;
     let mut current = Some(target);
     let mut propagate = bubble;
 
@@ -24,14 +30,20 @@ where
             state:     &mut widget.cx.state,
             hierarchy: widget.cx.hierarchy,
             focus:     &mut focus,
+            grab:      &mut grab,
         };
 
-        propagate = on_event(&mut *widget.widget, &mut cx);
+        // disabled widgets don't handle events, their enabled ancestors still do
+        if !cx.is_disabled() {
+            propagate = on_event(&mut *widget.widget, &mut cx);
+        }
+
         current = widget.cx.parent();
     }
 
     passes::hierarchy::propagate_down(&world.widgets, target);
-    passes::focus::update(world, window, focus);
+    passes::focus::update(world, window, seat, focus);
+    passes::grab::update(world, window, grab);
 
     propagate
 }
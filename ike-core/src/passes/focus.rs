@@ -1,51 +1,75 @@
 use std::mem;
 
 use crate::{
-    ImeEvent, ImeSignal, Signal, TextEvent, WidgetId, WidgetRef, Window, WindowId, World,
-    context::FocusUpdate, passes,
+    ImeEvent, ImeSignal, SeatId, Signal, TextEvent, WidgetId, WidgetRef, Window, WindowId, World,
+    context::FocusUpdate,
+    passes::{self, hierarchy::is_descendant},
 };
 
-pub(crate) fn update(world: &mut World, window: WindowId, update: FocusUpdate) {
+pub(crate) fn update(world: &mut World, window: WindowId, seat: SeatId, update: FocusUpdate) {
     match update {
         FocusUpdate::None => {}
 
         FocusUpdate::Take => {
-            transfer(world, window, None);
+            transfer(world, window, seat, None);
         }
 
         FocusUpdate::Target(target) => {
-            transfer(world, window, Some(target));
+            transfer(world, window, seat, Some(target));
         }
 
         FocusUpdate::Next => {
-            next(world, window, true);
+            next(world, window, seat, true);
         }
 
         FocusUpdate::Previous => {
-            next(world, window, false);
+            next(world, window, seat, false);
         }
     }
 }
 
-pub(crate) fn next(world: &mut World, window: WindowId, forward: bool) {
+pub(crate) fn next(world: &mut World, window: WindowId, seat: SeatId, forward: bool) {
     if let Some(window) = world.window(window) {
-        let focused = find_next(world, window, forward);
-        transfer(world, window.id(), focused);
+        let focused = find_next(world, window, seat, forward);
+        transfer(world, window.id(), seat, focused);
     }
 }
 
-pub(crate) fn transfer(world: &mut World, window: WindowId, target: Option<WidgetId>) {
+pub(crate) fn transfer(
+    world: &mut World,
+    window: WindowId,
+    seat: SeatId,
+    target: Option<WidgetId>,
+) {
     let window_id = window;
 
+    // disabled widgets can't be focused
+    if let Some(target) = target
+        && world
+            .widget(target)
+            .is_ok_and(|widget| widget.cx.is_disabled())
+    {
+        return;
+    }
+
     let Some(window) = world.window_mut(window_id) else {
         return;
     };
 
-    if window.focused == target {
+    if window.focused(seat) == target {
         return;
     }
 
-    let current = mem::replace(&mut window.focused, target);
+    let current = mem::replace(
+        &mut window.seat_mut(seat).focused,
+        target,
+    );
+
+    // another seat might still have the current widget focused
+    let current = current.filter(|&current| !window.is_focusing(current));
+
+    // the platform ime only follows the primary seat
+    let is_primary = seat == SeatId::PRIMARY;
 
     // remove focus from the current widget
     if let Some(current) = current
@@ -53,13 +77,14 @@ pub(crate) fn transfer(world: &mut World, window: WindowId, target: Option<Widge
     {
         widget.set_focused(false);
 
-        if widget.cx.hierarchy.accepts_text() && target.is_none() {
+        if widget.cx.hierarchy.accepts_text() && target.is_none() && is_primary {
             widget.cx.world.emit_signal(Signal::Ime(ImeSignal::End));
         }
     }
 
     // emit ime end event for currently focused widget
     if let Some(current) = current
+        && is_primary
         && world
             .widget(current)
             .is_ok_and(|current| current.cx.hierarchy.accepts_text())
@@ -72,16 +97,17 @@ pub(crate) fn transfer(world: &mut World, window: WindowId, target: Option<Widge
         let rect = world.widget_mut(target).map(|mut widget| {
             widget.set_focused(true);
 
-            if widget.cx.hierarchy.accepts_text() {
+            if widget.cx.hierarchy.accepts_text() && is_primary {
                 widget.cx.world.emit_signal(Signal::Ime(ImeSignal::Start));
             }
 
             widget.cx.rect()
         });
 
-        if world
-            .widget(target)
-            .is_ok_and(|widget| widget.cx.hierarchy.accepts_text())
+        if is_primary
+            && world
+                .widget(target)
+                .is_ok_and(|widget| widget.cx.hierarchy.accepts_text())
         {
             let event = TextEvent::Ime(ImeEvent::Start);
             passes::text::send_event(world, window_id, target, &event);
@@ -141,8 +167,8 @@ fn find_first_child_from(widget: &WidgetRef, forward: bool) -> Option<WidgetId>
     None
 }
 
-fn find_next(world: &World, window: &Window, forward: bool) -> Option<WidgetId> {
-    let Some(focused) = window.focused else {
+fn find_next(world: &World, window: &Window, seat: SeatId, forward: bool) -> Option<WidgetId> {
+    let Some(focused) = window.focused(seat) else {
         return find_first(world, window, forward);
     };
 
@@ -156,10 +182,8 @@ fn find_next(world: &World, window: &Window, forward: bool) -> Option<WidgetId>
         let mut layers = window.layers().iter();
 
         for layer in layers.by_ref() {
-            let widget = world.widget(layer.widget).ok()?;
-
-            if widget.cx.hierarchy.has_focused() {
-                if let Some(focusable) = find_next_from(world, layer.widget, forward) {
+            if is_descendant(&world.widgets, layer.widget, focused) {
+                if let Some(focusable) = find_next_from(world, layer.widget, focused, forward) {
                     return Some(focusable);
                 } else {
                     break;
@@ -178,10 +202,8 @@ fn find_next(world: &World, window: &Window, forward: bool) -> Option<WidgetId>
         let mut layers = window.layers().iter().rev();
 
         for layer in layers.by_ref() {
-            let widget = world.widget(layer.widget).ok()?;
-
-            if widget.cx.hierarchy.has_focused() {
-                if let Some(focusable) = find_next_from(world, layer.widget, forward) {
+            if is_descendant(&world.widgets, layer.widget, focused) {
+                if let Some(focusable) = find_next_from(world, layer.widget, focused, forward) {
                     return Some(focusable);
                 } else {
                     break;
@@ -201,10 +223,16 @@ fn find_next(world: &World, window: &Window, forward: bool) -> Option<WidgetId>
     None
 }
 
-fn find_next_from(world: &World, current: WidgetId, forward: bool) -> Option<WidgetId> {
+// `has_focused` is shared by all seats, so follow the path to the focused widget of the seat
+fn find_next_from(
+    world: &World,
+    current: WidgetId,
+    focused: WidgetId,
+    forward: bool,
+) -> Option<WidgetId> {
     let widget = world.widget(current).ok()?;
 
-    if !widget.cx.hierarchy.has_focused() {
+    if !is_descendant(&world.widgets, current, focused) {
         return find_first_from(&widget, forward);
     }
 
@@ -212,10 +240,8 @@ fn find_next_from(world: &World, current: WidgetId, forward: bool) -> Option<Wid
         let mut children = widget.cx.children().iter().copied();
 
         for child in children.by_ref() {
-            let child = world.widget(child).ok()?;
-
-            if child.cx.hierarchy.has_focused() {
-                if let Some(focusable) = find_next_from(world, child.cx.id(), forward) {
+            if is_descendant(&world.widgets, child, focused) {
+                if let Some(focusable) = find_next_from(world, child, focused, forward) {
                     return Some(focusable);
                 } else {
                     break;
@@ -234,10 +260,8 @@ fn find_next_from(world: &World, current: WidgetId, forward: bool) -> Option<Wid
         let mut children = widget.cx.children().iter().copied().rev();
 
         for child in children.by_ref() {
-            let child = world.widget(child).ok()?;
-
-            if child.cx.hierarchy.has_focused() {
-                if let Some(focusable) = find_next_from(world, child.cx.id(), forward) {
+            if is_descendant(&world.widgets, child, focused) {
+                if let Some(focusable) = find_next_from(world, child, focused, forward) {
                     return Some(focusable);
                 } else {
                     break;
@@ -0,0 +1,63 @@
+use std::mem;
+
+use crate::{Update, WidgetId, Window, WindowId, World, context::GrabUpdate, passes};
+
+pub(crate) fn update(world: &mut World, window: WindowId, update: GrabUpdate) {
+    match update {
+        GrabUpdate::None => {}
+
+        GrabUpdate::Grab(target) => {
+            set(world, window, Some(target));
+        }
+
+        GrabUpdate::Release(target) => {
+            if world.window(window).is_some_and(|w| w.grab == Some(target)) {
+                set(world, window, None);
+            }
+        }
+    }
+}
+
+pub(crate) fn set(world: &mut World, window: WindowId, target: Option<WidgetId>) {
+    let Some(window) = world.window_mut(window) else {
+        return;
+    };
+
+    if window.grab == target {
+        return;
+    }
+
+    let current = mem::replace(&mut window.grab, target);
+
+    if let Some(current) = current
+        && let Ok(mut widget) = world.widget_mut(current)
+    {
+        passes::update::widget(&mut widget, Update::Grabbed(false));
+    }
+
+    if let Some(target) = target
+        && let Ok(mut widget) = world.widget_mut(target)
+    {
+        passes::update::widget(&mut widget, Update::Grabbed(true));
+    }
+}
+
+/// Redirect `target` to the widget grabbing input in `window`, unless `target` is a descendant
+/// of it.
+pub(crate) fn redirect(
+    world: &World,
+    window: &Window,
+    target: Option<WidgetId>,
+) -> Option<WidgetId> {
+    let Some(grab) = window.grab else {
+        return target;
+    };
+
+    match target {
+        Some(target) if passes::hierarchy::is_descendant(&world.widgets, grab, target) => {
+            Some(target)
+        }
+
+        _ => Some(grab),
+    }
+}
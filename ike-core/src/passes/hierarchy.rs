@@ -1,8 +1,12 @@
 use std::mem;
 
 use crate::{
-    Builder, ChildUpdate, GetError, Update, Widget, WidgetId, WidgetMut, WindowId, World,
-    debug::debug_panic, passes, world::Widgets,
+    Builder, ChildUpdate, GetError, ImeSignal, LayoutDirection, Signal, Update, Widget, WidgetId,
+    WidgetMut, WindowId, World,
+    debug::debug_panic,
+    passes,
+    widget::WidgetHierarchy,
+    world::{Widgets, WorldState},
 };
 
 pub(crate) fn insert_child(world: &mut World, parent: WidgetId, index: usize, child: WidgetId) {
@@ -210,71 +214,91 @@ pub(crate) fn set_window(world: &mut World, widget: WidgetId, window: Option<Win
         && let Some(window) = previous
         && let Some(window) = world.state.window_mut(window)
     {
+        let mut hovered = Vec::new();
+
+        // more than one pointer can be hovering the widget
         for pointer in &mut window.pointers {
-            if let Some(hovered) = pointer.hovering
-                && is_descendant(&world.widgets, widget, hovered)
+            if let Some(widget) = pointer
+                .hovering
+                .take_if(|hovering| is_descendant(&world.widgets, widget, *hovering))
             {
-                pointer.hovering = None;
-
-                if let Ok(mut widget) = world.widget_mut(hovered) {
-                    widget.set_hovered(false);
-                }
+                hovered.push(widget);
+            }
+        }
 
-                break;
+        for hovered in hovered {
+            if let Ok(mut widget) = world.widget_mut(hovered) {
+                widget.set_hovered(false);
             }
         }
     }
 
-    if has_active {
+    if has_active
+        && let Some(window) = previous
+        && let Some(window) = world.state.window_mut(window)
+    {
+        let mut capturers = Vec::new();
+
         // remove widget from pointer capturer
-        if let Some(window) = previous
-            && let Some(window) = world.state.window_mut(window)
-        {
-            for pointer in &mut window.pointers {
-                if let Some(capturer) = pointer.capturer
-                    && is_descendant(&world.widgets, widget, capturer)
-                {
-                    pointer.capturer = None;
+        for pointer in &mut window.pointers {
+            if let Some(capturer) = pointer
+                .capturer
+                .take_if(|capturer| is_descendant(&world.widgets, widget, *capturer))
+            {
+                capturers.push(capturer);
+            }
+        }
 
-                    if let Ok(mut widget) = world.widget_mut(capturer) {
-                        widget.set_active(false);
-                    }
+        // remove widget from touch capturer, a pinch or rotation captures more than one touch
+        for touch in &mut window.touches {
+            if let Some(capturer) = touch.capturer
+                && is_descendant(&world.widgets, widget, capturer)
+            {
+                touch.capturer = None;
+                capturers.push(capturer);
+            }
+        }
 
-                    break;
-                }
+        for capturer in capturers {
+            if let Ok(mut widget) = world.widget_mut(capturer) {
+                widget.set_active(false);
             }
         }
+    }
 
-        // remove widget from touch capturer
-        if let Some(window) = previous
-            && let Some(window) = world.state.window_mut(window)
-        {
-            for touch in &mut window.touches {
-                if let Some(capturer) = touch.capturer
-                    && is_descendant(&world.widgets, widget, capturer)
-                {
-                    touch.capturer = None;
+    // remove widget from the focus of the seats
+    if has_focused
+        && let Some(window) = previous
+        && let Some(window) = world.state.window_mut(window)
+    {
+        let mut focused = Vec::new();
 
-                    if let Ok(mut widget) = world.widget_mut(capturer) {
-                        widget.set_active(false);
-                    }
+        for seat in &mut window.seats {
+            if let Some(widget) = seat
+                .focused
+                .take_if(|focused| is_descendant(&world.widgets, widget, *focused))
+            {
+                focused.push(widget);
+            }
+        }
 
-                    break;
-                }
+        for focused in focused {
+            if let Ok(mut widget) = world.widget_mut(focused) {
+                widget.set_focused(false);
             }
         }
     }
 
-    // remove widget from window focused
-    if has_focused
-        && let Some(window) = previous
+    // release the input grab of the window
+    if let Some(window) = previous
         && let Some(window) = world.state.window_mut(window)
-        && let Some(focused) = window.focused
+        && let Some(grab) = window.grab
+        && is_descendant(&world.widgets, widget, grab)
     {
-        window.focused = None;
+        window.grab = None;
 
-        if let Ok(mut widget) = world.widget_mut(focused) {
-            widget.set_focused(false);
+        if let Ok(mut widget) = world.widget_mut(grab) {
+            passes::update::widget(&mut widget, Update::Grabbed(false));
         }
     }
 
@@ -372,7 +396,10 @@ where
 {
     for &child in widget.cx.hierarchy.children.iter() {
         let Ok(mut child) = widget.cx.get_widget_mut(child) else {
-            debug_panic!("set_stashed called while descendant is borrowed");
+            debug_panic!(
+                "set_stashed called while descendant {} is borrowed",
+                widget.cx.widgets.describe(child),
+            );
             continue;
         };
 
@@ -399,15 +426,18 @@ pub(crate) fn set_disabled<T>(widget: &mut WidgetMut<'_, T>, is_disabled: bool)
 where
     T: Widget + ?Sized,
 {
-    // if we want to be un-disabled, but our parent is disabled, do nothing
-    if !widget.cx.is_disabled()
-        && let Ok(parent) = widget.cx.get_parent()
-        && parent.cx.is_disabled()
-    {
-        return;
-    }
+    widget.cx.hierarchy.set_self_disabled(is_disabled);
+
+    // a widget that isn't disabled itself stays disabled while its parent is
+    let is_parent_disabled = widget
+        .cx
+        .get_parent()
+        .is_ok_and(|parent| parent.cx.is_disabled());
 
-    set_disabled_recursive(widget, is_disabled);
+    set_disabled_recursive(
+        widget,
+        is_disabled || is_parent_disabled,
+    );
     propagate_down(widget.cx.widgets, widget.cx.id());
 }
 
@@ -417,15 +447,24 @@ where
 {
     for &child in widget.cx.hierarchy.children.iter() {
         let Ok(mut child) = widget.cx.get_widget_mut(child) else {
-            debug_panic!("set_disabled called while descendant is borrowed");
+            debug_panic!(
+                "set_disabled called while descendant {} is borrowed",
+                widget.cx.widgets.describe(child),
+            );
             continue;
         };
 
-        set_disabled_recursive(&mut child, is_disabled);
+        // descendants disabled themselves stay disabled when this widget is enabled
+        let is_child_disabled = is_disabled || child.cx.hierarchy.is_self_disabled();
+        set_disabled_recursive(&mut child, is_child_disabled);
     }
 
     widget.cx.hierarchy.propagate_down(widget.cx.widgets);
 
+    if is_disabled {
+        release_disabled(widget);
+    }
+
     if widget.cx.hierarchy.is_disabled() != is_disabled {
         widget.widget.update(
             &mut widget.cx.as_update_cx(),
@@ -435,3 +474,101 @@ where
 
     widget.cx.hierarchy.set_disabled(is_disabled);
 }
+
+/// Remove the focus and pointer captures of a `widget` that is being disabled, it no longer
+/// receives the events that would release them.
+fn release_disabled<T>(widget: &mut WidgetMut<'_, T>)
+where
+    T: Widget + ?Sized,
+{
+    let id = widget.cx.id();
+    let window = widget.cx.hierarchy.window;
+
+    if widget.cx.hierarchy.is_focused() {
+        if let Some(window) = window
+            && let Some(window) = widget.cx.world.window_mut(window)
+        {
+            for seat in &mut window.seats {
+                seat.focused.take_if(|focused| *focused == id);
+            }
+        }
+
+        // the widget isn't disabled yet, so it still accepts text if it did
+        if widget.cx.hierarchy.accepts_text() {
+            widget.cx.world.emit_signal(Signal::Ime(ImeSignal::End));
+        }
+
+        widget.set_focused(false);
+    }
+
+    if widget.cx.hierarchy.is_active() {
+        if let Some(window) = window
+            && let Some(window) = widget.cx.world.window_mut(window)
+        {
+            for pointer in &mut window.pointers {
+                pointer.capturer.take_if(|capturer| *capturer == id);
+            }
+
+            for touch in &mut window.touches {
+                touch.capturer.take_if(|capturer| *capturer == id);
+            }
+        }
+
+        widget.set_active(false);
+    }
+}
+
+/// Resolve the layout direction of `hierarchy`, from the closest ancestor that sets one, or the
+/// layout direction of the platform.
+pub(crate) fn layout_direction(
+    widgets: &Widgets,
+    world: &WorldState,
+    hierarchy: &WidgetHierarchy,
+) -> LayoutDirection {
+    let mut current = Some(hierarchy);
+
+    while let Some(hierarchy) = current {
+        if let Some(direction) = hierarchy.direction.get() {
+            return direction;
+        }
+
+        current = hierarchy
+            .parent
+            .and_then(|parent| widgets.get_hierarchy(parent));
+    }
+
+    world.layout_direction
+}
+
+pub(crate) fn set_layout_direction<T>(
+    widget: &mut WidgetMut<'_, T>,
+    direction: Option<LayoutDirection>,
+) where
+    T: Widget + ?Sized,
+{
+    if widget.cx.hierarchy.direction.get() == direction {
+        return;
+    }
+
+    widget.cx.hierarchy.direction.set(direction);
+
+    for &child in widget.cx.hierarchy.children.iter() {
+        let Ok(child) = widget.cx.get_widget_mut(child) else {
+            debug_panic!(
+                "set_layout_direction called while descendant {} is borrowed",
+                widget.cx.widgets.describe(child),
+            );
+            continue;
+        };
+
+        passes::update::widget_recursive(child, &Update::LayoutDirectionChanged);
+    }
+
+    widget.widget.update(
+        &mut widget.cx.as_update_cx(),
+        Update::LayoutDirectionChanged,
+    );
+
+    widget.cx.request_layout();
+    widget.cx.request_draw();
+}
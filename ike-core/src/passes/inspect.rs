@@ -0,0 +1,193 @@
+use std::fmt::Write;
+
+use crate::{
+    Builder, Key, Modifiers, NamedKey, Point, Rect, WidgetId, WidgetRef, WindowId, World, passes,
+};
+
+/// Toggle the inspector with `Ctrl+Shift+I` in debug builds, and walk the tree with the keys
+/// while it's open, returning whether the key was used by the inspector.
+pub(crate) fn key_pressed(
+    world: &mut World,
+    window: WindowId,
+    key: &Key,
+    modifiers: Modifiers,
+    pressed: bool,
+) -> bool {
+    let is_chord = cfg!(debug_assertions)
+        && modifiers.contains(Modifiers::CONTROL | Modifiers::SHIFT)
+        && matches!(key, Key::Character(c) if c.eq_ignore_ascii_case("i"));
+
+    if is_chord {
+        if pressed {
+            let is_open = world.settings().debug.inspector;
+            set_open(world, window, !is_open);
+        }
+
+        return true;
+    }
+
+    if !world.settings().debug.inspector {
+        return false;
+    }
+
+    if !pressed {
+        return true;
+    }
+
+    match key {
+        Key::Named(NamedKey::Escape) => set_open(world, window, false),
+        Key::Named(NamedKey::Enter) => log(world, window),
+
+        Key::Named(NamedKey::ArrowUp) => walk(world, window, |widget| {
+            widget.cx.parent()
+        }),
+
+        Key::Named(NamedKey::ArrowDown) => walk(world, window, |widget| {
+            widget.cx.children().first().copied()
+        }),
+
+        Key::Named(NamedKey::ArrowLeft) => walk(world, window, |widget| {
+            sibling(widget, -1)
+        }),
+        Key::Named(NamedKey::ArrowRight) => walk(world, window, |widget| {
+            sibling(widget, 1)
+        }),
+
+        _ => {}
+    }
+
+    true
+}
+
+/// Pick the widget at `position` while the inspector is open, returning whether it is.
+pub(crate) fn pointer_moved(world: &mut World, window: WindowId, position: Point) -> bool {
+    if !world.settings().debug.inspector {
+        return false;
+    }
+
+    let picked = world
+        .window(window)
+        .and_then(|win| passes::query::find_widget_at(world, win, position));
+
+    set_inspected(world, window, picked);
+    true
+}
+
+/// Log the inspected widget when pressed while the inspector is open, returning whether it is.
+pub(crate) fn pointer_pressed(world: &mut World, window: WindowId, pressed: bool) -> bool {
+    if !world.settings().debug.inspector {
+        return false;
+    }
+
+    if pressed {
+        log(world, window);
+    }
+
+    true
+}
+
+/// The inspected widget of `window`, if it still exists.
+pub(crate) fn inspected(world: &World, window: WindowId) -> Option<WidgetRef<'_>> {
+    let inspected = world.state.get_window(window)?.inspected?;
+    world.get_widget(inspected).ok()
+}
+
+/// The type and id of `widget`, e.g. `Button 4:0`.
+pub(crate) fn describe(widget: &WidgetRef<'_>) -> String {
+    format!(
+        "{} {:?}",
+        widget.cx.state.short_name,
+        widget.cx.id()
+    )
+}
+
+/// The bounds of `widget` in its window.
+pub(crate) fn global_rect(widget: &WidgetRef<'_>) -> Rect {
+    let transform = widget.cx.global_transform();
+    widget.cx.rect().transform_bounds(transform)
+}
+
+fn set_open(world: &mut World, window: WindowId, is_open: bool) {
+    world.settings_mut().debug.inspector = is_open;
+
+    // start with the widget under the pointer, if there is one
+    let picked = match is_open {
+        true => world.window(window).and_then(|win| {
+            let pointer = win.pointers.first()?;
+            passes::query::find_widget_at(world, win, pointer.position())
+        }),
+
+        false => None,
+    };
+
+    set_inspected(world, window, picked);
+}
+
+fn set_inspected(world: &mut World, window: WindowId, widget: Option<WidgetId>) {
+    if let Some(win) = world.state.get_window_mut(window)
+        && win.inspected != widget
+    {
+        win.inspected = widget;
+        world.state.request_redraw(window);
+    }
+}
+
+fn walk(world: &mut World, window: WindowId, f: impl FnOnce(&WidgetRef<'_>) -> Option<WidgetId>) {
+    let Some(next) = inspected(world, window).and_then(|widget| f(&widget)) else {
+        return;
+    };
+
+    set_inspected(world, window, Some(next));
+}
+
+fn sibling(widget: &WidgetRef<'_>, offset: isize) -> Option<WidgetId> {
+    let parent = widget.cx.get_parent().ok()?;
+    let children = parent.cx.children();
+
+    let index = children.iter().position(|&child| child == widget.cx.id())?;
+    let index = index.checked_add_signed(offset)?;
+
+    children.get(index).copied()
+}
+
+/// Log the inspected widget and its ancestors, from the root down.
+fn log(world: &World, window: WindowId) {
+    let Some(widget) = inspected(world, window) else {
+        return;
+    };
+
+    let mut chain = vec![widget.cx.id()];
+    let mut parent = widget.cx.parent();
+
+    while let Some(widget) = parent {
+        chain.push(widget);
+        parent = world
+            .get_widget(widget)
+            .ok()
+            .and_then(|widget| widget.cx.parent());
+    }
+
+    let mut text = String::new();
+
+    for (depth, &widget) in chain.iter().rev().enumerate() {
+        let Ok(widget) = world.get_widget(widget) else {
+            continue;
+        };
+
+        let rect = global_rect(&widget);
+
+        let _ = writeln!(
+            text,
+            "{:indent$}{} at ({}, {}) size {}x{}",
+            "",
+            describe(&widget),
+            rect.left(),
+            rect.top(),
+            rect.width(),
+            rect.height(),
+            indent = depth * 2,
+        );
+    }
+
+    tracing::info!("inspected widget:\n{text}");
+}
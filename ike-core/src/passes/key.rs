@@ -1,10 +1,16 @@
 use crate::{
-    Key, KeyEvent, KeyPressEvent, Modifiers, NamedKey, Propagate, WidgetId, WindowId, World, passes,
+    Code, ColorBlindness, Key, KeyEvent, KeyInput, KeyPressEvent, Modifiers, NamedKey, Propagate,
+    Seat, SeatId, WidgetId, WindowId, World, passes,
 };
 
-pub(crate) fn modifiers_changed(world: &mut World, window: WindowId, modifiers: Modifiers) -> bool {
+pub(crate) fn modifiers_changed(
+    world: &mut World,
+    window: WindowId,
+    seat: SeatId,
+    modifiers: Modifiers,
+) -> bool {
     if let Some(window) = world.window_mut(window) {
-        window.modifiers = modifiers;
+        window.seat_mut(seat).modifiers = modifiers;
     }
 
     true
@@ -13,33 +19,69 @@ pub(crate) fn modifiers_changed(world: &mut World, window: WindowId, modifiers:
 pub(crate) fn pressed(
     world: &mut World,
     window: WindowId,
-    key: Key,
-    repeat: bool,
-    text: Option<&str>,
+    seat: SeatId,
+    input: KeyInput,
     pressed: bool,
 ) -> bool {
+    let KeyInput {
+        key,
+        code,
+        location,
+        text,
+        repeat,
+    } = input;
+
     let window_id = window;
 
+    let Some(window) = world.window_mut(window_id) else {
+        return false;
+    };
+
+    let Seat {
+        modifiers, focused, ..
+    } = *window.seat_mut(seat);
+
+    if passes::inspect::key_pressed(
+        world, window_id, &key, modifiers, pressed,
+    ) {
+        return true;
+    }
+
     let Some(window) = world.window(window_id) else {
         return false;
     };
 
     let event = KeyPressEvent {
         key: key.clone(),
-        modifiers: window.modifiers,
-        text: text.map(Into::into),
+        code,
+        location,
+        modifiers,
+        text,
         repeat,
     };
 
+    let target = passes::grab::redirect(world, window, focused);
+
+    // a released key goes to the widget it was pressed on, even if the focus moved since
+    let target = match pressed {
+        true => {
+            track_pressed(world, window_id, seat, &event, target);
+            target
+        }
+
+        false => match track_released(world, window_id, seat, &event) {
+            Some(pressed_on) if world.widget(pressed_on).is_ok() => Some(pressed_on),
+            _ => target,
+        },
+    };
+
     let event = match pressed {
         true => KeyEvent::Down(event),
         false => KeyEvent::Up(event),
     };
 
-    let modifiers = window.modifiers;
-
-    let mut handled = if let Some(target) = window.focused
-        && let Propagate::Handled = send_event(world, window_id, target, &event)
+    let mut handled = if let Some(target) = target
+        && let Propagate::Handled = send_event(world, window_id, seat, target, &event)
     {
         true
     } else {
@@ -53,8 +95,50 @@ pub(crate) fn pressed(
         handled = true;
     }
 
+    if key == Key::Named(NamedKey::Escape)
+        && pressed
+        && !handled
+        && world.window(window_id).is_some_and(|w| w.grab.is_some())
+    {
+        passes::grab::set(world, window_id, None);
+        handled = true;
+    }
+
+    if matches!(
+        key,
+        Key::Named(NamedKey::Escape | NamedKey::GoBack | NamedKey::BrowserBack)
+    ) && pressed
+        && !handled
+    {
+        handled = passes::dismiss::dismiss(world, window_id);
+    }
+
+    if matches!(
+        key,
+        Key::Named(NamedKey::GoBack | NamedKey::BrowserBack)
+    ) && pressed
+        && !handled
+    {
+        handled = passes::dismiss::go_back(world, window_id);
+    }
+
     if key == Key::Named(NamedKey::Tab) && pressed && !handled {
-        passes::focus::next(world, window_id, !modifiers.shift());
+        passes::focus::next(
+            world,
+            window_id,
+            seat,
+            !modifiers.shift(),
+        );
+    }
+
+    if key == Key::Named(NamedKey::F5) && pressed && !handled && cfg!(debug_assertions) {
+        world.settings_mut().debug.performance_overlay ^= true;
+        world.state.request_redraw(window_id);
+    }
+
+    if key == Key::Named(NamedKey::F6) && pressed && !handled && cfg!(debug_assertions) {
+        world.settings_mut().debug.layout_overlay ^= true;
+        world.state.request_redraw(window_id);
     }
 
     if key == Key::Named(NamedKey::F7) && pressed && !handled && cfg!(debug_assertions) {
@@ -67,18 +151,107 @@ pub(crate) fn pressed(
         world.state.request_redraw(window_id);
     }
 
+    if key == Key::Named(NamedKey::F9) && pressed && !handled && cfg!(debug_assertions) {
+        let debug = &mut world.settings_mut().debug;
+
+        debug.color_blindness = match debug.color_blindness {
+            None => Some(ColorBlindness::Protanopia),
+            Some(ColorBlindness::Protanopia) => Some(ColorBlindness::Deuteranopia),
+            Some(ColorBlindness::Deuteranopia) => Some(ColorBlindness::Tritanopia),
+            Some(ColorBlindness::Tritanopia) => None,
+        };
+
+        world.state.request_redraw(window_id);
+    }
+
+    if key == Key::Named(NamedKey::F10) && pressed && !handled && cfg!(debug_assertions) {
+        world.settings_mut().debug.contrast_overlay ^= true;
+        world.state.request_redraw(window_id);
+    }
+
     handled
 }
 
+/// Release the keys held down in `window`, e.g. when it loses focus and the platform won't
+/// report them being released.
+pub(crate) fn release_all(world: &mut World, window_id: WindowId) {
+    let Some(window) = world.window_mut(window_id) else {
+        return;
+    };
+
+    let mut released = Vec::new();
+
+    for seat in &mut window.seats {
+        for (event, target) in seat.pressed.drain(..) {
+            released.push((seat.id, event, target));
+        }
+    }
+
+    for (seat, mut event, target) in released {
+        event.text = None;
+        event.repeat = false;
+
+        if let Some(target) = target {
+            send_event(
+                world,
+                window_id,
+                seat,
+                target,
+                &KeyEvent::Up(event),
+            );
+        }
+    }
+}
+
+fn is_same_key(a: &KeyPressEvent, b: &KeyPressEvent) -> bool {
+    match a.code {
+        Code::Unidentified => a.key == b.key,
+        code => code == b.code,
+    }
+}
+
+fn track_pressed(
+    world: &mut World,
+    window: WindowId,
+    seat: SeatId,
+    event: &KeyPressEvent,
+    target: Option<WidgetId>,
+) {
+    if let Some(window) = world.window_mut(window) {
+        let pressed = &mut window.seat_mut(seat).pressed;
+
+        match pressed.iter_mut().find(|(e, _)| is_same_key(e, event)) {
+            // a repeated key stays with the widget it was first pressed on
+            Some((pressed, _)) => pressed.repeat = event.repeat,
+            None => pressed.push((event.clone(), target)),
+        }
+    }
+}
+
+fn track_released(
+    world: &mut World,
+    window: WindowId,
+    seat: SeatId,
+    event: &KeyPressEvent,
+) -> Option<WidgetId> {
+    let window = world.window_mut(window)?;
+    let pressed = &mut window.seat_mut(seat).pressed;
+
+    let index = pressed.iter().position(|(e, _)| is_same_key(e, event))?;
+    pressed.remove(index).1
+}
+
 pub(crate) fn send_event(
     world: &mut World,
     window: WindowId,
+    seat: SeatId,
     target: WidgetId,
     event: &KeyEvent,
 ) -> Propagate {
     passes::event::send_event(
         world,
         window,
+        seat,
         target,
         Propagate::Bubble,
         |widget, cx| widget.on_key_event(cx, event),
@@ -1,5 +1,6 @@
 use crate::{
-    Affine, LayoutCx, Painter, Size, Space, WidgetId, WidgetMut, WindowId, WindowSizing, World,
+    Affine, LayoutCx, Painter, Size, Space, WidgetId, WidgetMut, WidgetRef, WindowId, WindowSizing,
+    World,
 };
 
 pub(crate) fn layout_window(
@@ -12,16 +13,19 @@ pub(crate) fn layout_window(
     let window = world.window(window_id)?;
 
     let scale = window.scale();
+    let zoom = window.zoom();
     let sizing = window.sizing();
 
-    // compute the max size of the base layer
+    // compute the max size of the base layer, in the units of the zoomed contents
     let max_size = match sizing {
         WindowSizing::FitContent => Size::INFINITY,
-        WindowSizing::Resizable { .. } => window.size(),
+        WindowSizing::Resizable { .. } => window.size() * zoom.recip(),
     };
 
     let _span = tracing::info_span!("layout");
 
+    prepare_text(world, window_id, painter);
+
     // compute layout of the base layer
     let size = if let Some(layer) = window.get_base_layer()
         && let Ok(mut widget) = world.widget_mut(layer.widget)
@@ -41,7 +45,7 @@ pub(crate) fn layout_window(
 
     // update window size
     if let WindowSizing::FitContent = sizing {
-        window.size = size;
+        window.size = size * zoom;
     }
 
     // compute layout of layers in reverse
@@ -65,7 +69,58 @@ pub(crate) fn layout_window(
         }
     }
 
-    matches!(sizing, WindowSizing::FitContent).then_some(size)
+    matches!(sizing, WindowSizing::FitContent).then_some(size * zoom)
+}
+
+/// Let `painter` shape the text of the widgets about to be laid out in one batch.
+fn prepare_text(world: &World, window: WindowId, painter: &mut dyn Painter) {
+    let Some(window) = world.window(window) else {
+        return;
+    };
+
+    let mut texts = Vec::new();
+
+    for layer in window.layers() {
+        if let Ok(widget) = world.widget(layer.widget) {
+            collect_text(&widget, None, &mut texts);
+        }
+    }
+
+    // the widgets stay borrowed while the painter reads their paragraphs
+    let widgets: Vec<_> = (texts.into_iter())
+        .filter_map(|(id, width)| Some((world.widget(id).ok()?, width)))
+        .collect();
+
+    let paragraphs: Vec<_> = (widgets.iter())
+        .filter_map(|(widget, width)| Some((widget.widget.paragraph()?, *width)))
+        .collect();
+
+    if !paragraphs.is_empty() {
+        painter.prepare_text(&paragraphs);
+    }
+}
+
+/// Collect the widgets with text about to be laid out, with the width they're expected to get.
+fn collect_text(widget: &WidgetRef<'_>, width: Option<f32>, texts: &mut Vec<(WidgetId, f32)>) {
+    if !widget.cx.hierarchy.needs_layout() || widget.cx.is_stashed() {
+        return;
+    }
+
+    // widgets that haven't been laid out yet are guessed to get the width of their parent
+    let width = match widget.cx.state.previous_space {
+        Some(space) => Some(space.max.width),
+        None => width,
+    };
+
+    if let Some(width) = width
+        && widget.widget.paragraph().is_some()
+    {
+        texts.push((widget.cx.id(), width));
+    }
+
+    for child in widget.cx.iter_children().flatten() {
+        collect_text(&child, width, texts);
+    }
 }
 
 pub(crate) fn layout_widget(
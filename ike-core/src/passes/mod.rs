@@ -1,15 +1,21 @@
 pub(crate) mod animate;
+pub(crate) mod back;
 pub(crate) mod compose;
 pub(crate) mod debug;
+pub(crate) mod dismiss;
 pub(crate) mod draw;
 pub(crate) mod event;
 pub(crate) mod focus;
+pub(crate) mod grab;
 pub(crate) mod hierarchy;
+pub(crate) mod inspect;
 pub(crate) mod key;
 pub(crate) mod layout;
 pub(crate) mod pointer;
+pub(crate) mod print;
 pub(crate) mod query;
 pub(crate) mod record;
+pub(crate) mod sample;
 pub(crate) mod scroll;
 pub(crate) mod text;
 pub(crate) mod touch;
@@ -1,14 +1,22 @@
 use crate::{
-    CursorIcon, Point, Pointer, PointerButton, PointerButtonEvent, PointerEvent, PointerId,
-    PointerMoveEvent, PointerPropagate, PointerScrollEvent, ScrollDelta, WidgetId, WindowId, World,
-    debug::debug_panic, passes,
+    Cursor, Point, Pointer, PointerButton, PointerButtonEvent, PointerEvent, PointerId,
+    PointerMoveEvent, PointerPropagate, PointerScrollEvent, ScrollDelta, SeatId, Tool, WidgetId,
+    WindowId, World, debug::debug_panic, passes,
 };
 
-pub(crate) fn entered(world: &mut World, window: WindowId, pointer: PointerId) -> bool {
+pub(crate) fn entered(
+    world: &mut World,
+    window: WindowId,
+    seat: SeatId,
+    pointer: PointerId,
+) -> bool {
     if let Some(window) = world.state.window_mut(window) {
+        window.seat_mut(seat);
         window.pointers.push(Pointer {
-            id:       pointer,
+            id: pointer,
+            seat,
             position: Point::ORIGIN,
+            tool: Tool::default(),
             hovering: None,
             capturer: None,
         });
@@ -24,6 +32,7 @@ pub(crate) fn left(world: &mut World, window: WindowId, pointer: PointerId) -> b
         let pointer = window.pointers.swap_remove(index);
 
         if let Some(hovered) = pointer.hovering
+            && !window.is_hovering(hovered)
             && let Ok(mut widget) = world.widget_mut(hovered)
         {
             widget.set_hovered(false);
@@ -38,30 +47,42 @@ pub(crate) fn moved(
     window: WindowId,
     pointer: PointerId,
     position: Point,
+    tool: Tool,
 ) -> bool {
     let window_id = window;
     let pointer_id = pointer;
 
-    let capturer = if let Some(window) = world.state.window_mut(window_id)
+    let (seat, capturer) = if let Some(window) = world.state.window_mut(window_id)
         && let Some(pointer) = window.pointer_mut(pointer_id)
     {
         pointer.position = position;
-        pointer.capturer
+        pointer.tool = tool;
+        (pointer.seat, pointer.capturer)
     } else {
-        None
+        (SeatId::PRIMARY, None)
     };
 
+    if passes::inspect::pointer_moved(world, window_id, position) {
+        return true;
+    }
+
     let hovered = update_pointer_hovered(world, window_id, pointer_id);
 
+    let target = match world.window(window_id) {
+        Some(window) => passes::grab::redirect(world, window, capturer.or(hovered)),
+        None => None,
+    };
+
     let event = PointerMoveEvent {
         pointer: pointer_id,
         position,
+        tool,
     };
 
     let event = PointerEvent::Move(event);
 
-    let mut handled = if let Some(target) = capturer.or(hovered) {
-        match send_event(world, window_id, target, &event) {
+    let mut handled = if let Some(target) = target {
+        match send_event(world, window_id, seat, target, &event) {
             PointerPropagate::Bubble => false,
             PointerPropagate::Handled => true,
 
@@ -95,6 +116,10 @@ pub(crate) fn pressed(
     let window_id = window;
     let pointer_id = pointer;
 
+    if passes::inspect::pointer_pressed(world, window_id, pressed) {
+        return true;
+    }
+
     let Some(window) = world.window(window_id) else {
         return false;
     };
@@ -104,11 +129,13 @@ pub(crate) fn pressed(
     };
 
     let position = pointer.position;
+    let seat = pointer.seat;
 
     let event = PointerButtonEvent {
         button,
         position,
         pointer: pointer_id,
+        tool: pointer.tool,
     };
 
     let event = match pressed {
@@ -116,8 +143,14 @@ pub(crate) fn pressed(
         false => PointerEvent::Up(event),
     };
 
-    let mut handled = pointer.target().is_some_and(|target| {
-        let handled = match send_event(world, window_id, target, &event) {
+    let target = passes::grab::redirect(world, window, pointer.target());
+
+    if pressed && passes::dismiss::dismiss_outside(world, window_id, target).is_some() {
+        return true;
+    }
+
+    let mut handled = target.is_some_and(|target| {
+        let handled = match send_event(world, window_id, seat, target, &event) {
             PointerPropagate::Bubble => false,
             PointerPropagate::Handled => true,
 
@@ -142,19 +175,27 @@ pub(crate) fn pressed(
             }
         };
 
-        let target_is_active = world.widget(target).is_ok_and(|t| t.cx.is_active());
-
-        if !pressed && target_is_active {
-            if let Ok(mut widget) = world.widget_mut(target) {
-                widget.set_active(false);
-            }
+        let is_capturer = world
+            .window(window_id)
+            .and_then(|window| window.pointer(pointer_id))
+            .is_some_and(|pointer| pointer.capturer == Some(target));
 
+        // only release the capture of this pointer, another might still be holding the target
+        if !pressed && is_capturer {
             if let Some(window) = world.window_mut(window_id)
                 && let Some(pointer) = window.pointer_mut(pointer_id)
             {
                 pointer.capturer = None;
             }
 
+            if world
+                .window(window_id)
+                .is_some_and(|w| !w.is_capturing(target))
+                && let Ok(mut widget) = world.widget_mut(target)
+            {
+                widget.set_active(false);
+            }
+
             update_pointer_hovered(world, window_id, pointer_id);
         }
 
@@ -171,13 +212,13 @@ pub(crate) fn pressed(
     if pressed
         && !handled
         && let Some(window) = world.window(window_id)
-        && let Some(focused) = window.focused
+        && let Some(focused) = window.focused(seat)
         && world.widget(focused).is_ok_and(|widget| {
             let local = widget.cx.global_transform().inverse() * position;
             !widget.cx.rect().contains(local)
         })
     {
-        passes::focus::transfer(world, window_id, None);
+        passes::focus::transfer(world, window_id, seat, None);
     }
 
     handled
@@ -192,25 +233,27 @@ pub(crate) fn scrolled(
     let window_id = window;
     let pointer_id = pointer;
 
-    let Some(window) = world.state.window_mut(window_id) else {
+    let Some(window) = world.window(window_id) else {
         return false;
     };
 
-    let Some(pointer) = window.pointer_mut(pointer_id) else {
+    let Some(pointer) = window.pointer(pointer_id) else {
         return false;
     };
 
-    let Some(target) = pointer.target() else {
+    let Some(target) = passes::grab::redirect(world, window, pointer.target()) else {
         return false;
     };
 
+    let seat = pointer.seat;
+
     let event = PointerEvent::Scroll(PointerScrollEvent {
         position: pointer.position,
         pointer: pointer_id,
         delta,
     });
 
-    match send_event(world, window_id, target, &event) {
+    match send_event(world, window_id, seat, target, &event) {
         PointerPropagate::Bubble => false,
         PointerPropagate::Handled => true,
 
@@ -225,12 +268,14 @@ pub(crate) fn scrolled(
 pub(crate) fn send_event(
     world: &mut World,
     window: WindowId,
+    seat: SeatId,
     target: WidgetId,
     event: &PointerEvent,
 ) -> PointerPropagate {
     passes::event::send_event(
         world,
         window,
+        seat,
         target,
         PointerPropagate::Bubble,
         |widget, cx| widget.on_pointer_event(cx, event),
@@ -239,12 +284,14 @@ pub(crate) fn send_event(
 
 pub(crate) fn update_window_hovered(world: &mut World, window: WindowId) {
     let window_id = window;
-    if let Some(window) = world.window(window) {
-        let pointers: Vec<_> = window.pointers.iter().map(|p| p.id).collect();
+    let mut i = 0;
 
-        for pointer in pointers {
-            update_pointer_hovered(world, window_id, pointer);
-        }
+    // this runs every frame, so avoid collecting the pointers
+    while let Some(window) = world.window(window_id)
+        && let Some(pointer) = window.pointers.get(i)
+    {
+        update_pointer_hovered(world, window_id, pointer.id);
+        i += 1;
     }
 }
 
@@ -262,12 +309,15 @@ pub(crate) fn update_pointer_hovered(
     let position = pointer.position;
 
     if let Some(active) = pointer.capturer {
+        let is_hovered_by_other = (window.pointers.iter())
+            .any(|other| other.id != pointer_id && other.hovering == Some(active));
+
         if let Ok(mut widget) = world.widget_mut(active) {
             (widget.cx.world).set_window_cursor(window_id, widget.cx.cursor());
 
             let local = widget.cx.global_transform().inverse() * position;
             let is_hovered = widget.cx.rect().contains(local);
-            widget.set_hovered(is_hovered);
+            widget.set_hovered(is_hovered || is_hovered_by_other);
 
             return is_hovered.then_some(active);
         } else {
@@ -277,8 +327,20 @@ pub(crate) fn update_pointer_hovered(
 
     let hovered = passes::query::find_widget_at(world, window, position);
 
-    if pointer.hovering != hovered {
-        if let Some(current) = pointer.hovering
+    let current = pointer.hovering;
+
+    if let Some(window) = world.window_mut(window_id)
+        && let Some(pointer) = window.pointer_mut(pointer_id)
+    {
+        pointer.hovering = hovered;
+    }
+
+    if current != hovered {
+        // other pointers might still be hovering the current widget
+        if let Some(current) = current
+            && world
+                .window(window_id)
+                .is_some_and(|w| !w.is_hovering(current))
             && let Ok(mut widget) = world.widget_mut(current)
         {
             widget.set_hovered(false);
@@ -291,18 +353,12 @@ pub(crate) fn update_pointer_hovered(
         }
     }
 
-    if let Some(window) = world.window_mut(window_id)
-        && let Some(pointer) = window.pointer_mut(pointer_id)
-    {
-        pointer.hovering = hovered;
-    }
-
     if let Some(hovered) = hovered
         && let Ok(widget) = world.widget_mut(hovered)
     {
         (widget.cx.world).set_window_cursor(window_id, widget.cx.cursor());
     } else {
-        (world.state).set_window_cursor(window_id, CursorIcon::Default);
+        (world.state).set_window_cursor(window_id, Cursor::default());
     }
 
     hovered
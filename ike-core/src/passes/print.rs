@@ -0,0 +1,76 @@
+use crate::{
+    Affine, Clip, CornerRadius, Offset, PageSetup, Printer, Size, Space, WidgetId, World, passes,
+};
+
+pub(crate) fn print_widget(
+    world: &mut World,
+    widget: WidgetId,
+    setup: &PageSetup,
+    printer: &mut dyn Printer,
+) -> usize {
+    let _span = tracing::info_span!("print");
+
+    let content = setup.content_rect();
+
+    // the margins leave no room for contents, which would be split into endless pages
+    if content.width() <= 0.0 || content.height() <= 0.0 {
+        tracing::warn!(
+            ?setup,
+            "page setup has no room for contents"
+        );
+        return 0;
+    }
+
+    let Ok(mut widget) = world.widget_mut(widget) else {
+        return 0;
+    };
+
+    // the widget is laid out to the width of the page, and then split into pages
+    let space = Space::new(
+        Size::new(content.width(), 0.0),
+        Size::new(content.width(), f32::INFINITY),
+    );
+
+    let size = passes::layout::layout_widget(
+        &mut widget,
+        space,
+        printer.painter(),
+        1.0,
+    );
+    let mut widget = passes::compose::compose_widget(widget, Affine::IDENTITY, 1.0);
+
+    let pages = match size.height.is_finite() {
+        true => f32::ceil(size.height / content.height()).max(1.0) as usize,
+        false => {
+            tracing::warn!("printed widget has an infinite height");
+            0
+        }
+    };
+
+    let clip = Clip::Rect(content, CornerRadius::all(0.0));
+
+    for page in 0..pages {
+        let offset = Offset::new(
+            content.left(),
+            content.top() - content.height() * page as f32,
+        );
+
+        printer.page(setup.size, &mut |canvas| {
+            canvas.clip(&clip, &mut |canvas| {
+                canvas.transform(
+                    Affine::translate(offset),
+                    &mut |canvas| {
+                        passes::draw::export_widget(&mut widget, canvas);
+                    },
+                );
+            });
+        });
+    }
+
+    // the widget was laid out for the page, so the window it belongs to has to redo it
+    widget.cx.request_layout();
+    widget.cx.request_compose();
+    widget.cx.request_draw();
+
+    pages
+}
@@ -1,5 +1,32 @@
 use crate::{Point, WidgetId, Window, World};
 
+/// Find the first widget with `test_id`, searching the windows in the order they were created,
+/// and the widgets of each window parents first, in the order they're drawn.
+pub(crate) fn find_by_test_id(world: &World, test_id: &str) -> Option<WidgetId> {
+    for window in &world.state.windows {
+        let mut stack: Vec<_> = window
+            .layers
+            .iter()
+            .rev()
+            .map(|layer| layer.widget)
+            .collect();
+
+        while let Some(id) = stack.pop() {
+            let Ok(widget) = world.widget(id) else {
+                continue;
+            };
+
+            if widget.cx.test_id() == Some(test_id) {
+                return Some(id);
+            }
+
+            stack.extend(widget.cx.children().iter().rev());
+        }
+    }
+
+    None
+}
+
 pub(crate) fn find_widget_at(world: &World, window: &Window, position: Point) -> Option<WidgetId> {
     for layer in window.layers.clone().iter().rev() {
         if let Ok(root) = world.widget(layer.widget)
@@ -15,6 +15,7 @@ pub(crate) fn record_window(world: &mut World, window: WindowId, canvas: &mut dy
 
     world.state.recorder.frame(
         &world.state.settings.record,
+        world.state.memory_pressure,
         &world.widgets,
     );
 }
@@ -36,10 +37,16 @@ fn record_widget(mut widget: WidgetMut<'_>, canvas: &mut dyn Canvas, scale: f32)
     let memory_estimate = (bounds.size().area() * scale * scale * 4.0) as u64;
     let total_memory_estimate = widget.cx.world.recorder.memory_usage() + memory_estimate;
 
-    if draw_cost >= widget.cx.world.settings.record.cost_threshold
+    let is_worth_recording = draw_cost >= widget.cx.world.settings.record.cost_threshold
         && widget.cx.state.stable_draws >= 3
-        && bounds.size().area() > 256.0
-        && total_memory_estimate < widget.cx.world.settings.record.max_memory_usage
+        && bounds.size().area() > 256.0;
+
+    // cached widgets are recorded as soon as they've been drawn unchanged once
+    let is_cached = widget.cx.state.is_cached && widget.cx.state.stable_draws >= 1;
+
+    if (is_worth_recording || is_cached)
+        && total_memory_estimate
+            < (widget.cx.world.settings.record).memory_budget(widget.cx.world.memory_pressure)
     {
         let bounds = widget.cx.state.bounds;
         let width = (bounds.width() * scale).round() as u32;
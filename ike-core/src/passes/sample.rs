@@ -0,0 +1,26 @@
+use std::mem;
+
+use crate::{Builder, Canvas, Point, Update, WindowId, World, passes};
+
+pub(crate) fn sample_window(world: &mut World, window: WindowId, canvas: &mut dyn Canvas) {
+    let Some(window) = world.window_mut(window) else {
+        return;
+    };
+
+    let scale = window.scale();
+
+    for (target, point) in mem::take(&mut window.samples) {
+        let physical = Point::new(point.x * scale, point.y * scale);
+
+        let Some(color) = canvas.sample(physical) else {
+            continue;
+        };
+
+        if let Ok(mut widget) = world.get_widget_mut(target) {
+            passes::update::widget(
+                &mut widget,
+                Update::Sampled(point, color),
+            );
+        }
+    }
+}
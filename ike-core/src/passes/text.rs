@@ -1,10 +1,12 @@
 use std::ops::Range;
 
-use crate::{ImeEvent, Propagate, TextEvent, TextPasteEvent, WidgetId, WindowId, World, passes};
+use crate::{
+    ImeEvent, Propagate, SeatId, TextEvent, TextPasteEvent, WidgetId, WindowId, World, passes,
+};
 
 pub(crate) fn pasted(world: &mut World, window: WindowId, contents: String) -> bool {
     if let Some(window) = world.window(window)
-        && let Some(focused) = window.focused
+        && let Some(focused) = window.focused(SeatId::PRIMARY)
     {
         let event = TextEvent::Paste(TextPasteEvent { contents });
         send_event(world, window.id, focused, &event) == Propagate::Handled
@@ -15,7 +17,7 @@ pub(crate) fn pasted(world: &mut World, window: WindowId, contents: String) -> b
 
 pub(crate) fn ime_commit(world: &mut World, window: WindowId, text: String) -> bool {
     if let Some(window) = world.window(window)
-        && let Some(focused) = window.focused
+        && let Some(focused) = window.focused(SeatId::PRIMARY)
     {
         let event = TextEvent::Ime(ImeEvent::Commit(text));
         send_event(world, window.id, focused, &event) == Propagate::Handled
@@ -24,9 +26,25 @@ pub(crate) fn ime_commit(world: &mut World, window: WindowId, text: String) -> b
     }
 }
 
+pub(crate) fn ime_preedit(
+    world: &mut World,
+    window: WindowId,
+    text: String,
+    cursor: Option<Range<usize>>,
+) -> bool {
+    if let Some(window) = world.window(window)
+        && let Some(focused) = window.focused(SeatId::PRIMARY)
+    {
+        let event = TextEvent::Ime(ImeEvent::Preedit { text, cursor });
+        send_event(world, window.id, focused, &event) == Propagate::Handled
+    } else {
+        false
+    }
+}
+
 pub(crate) fn ime_select(world: &mut World, window: WindowId, selection: Range<usize>) -> bool {
     if let Some(window) = world.window(window)
-        && let Some(focused) = window.focused
+        && let Some(focused) = window.focused(SeatId::PRIMARY)
     {
         let event = TextEvent::Ime(ImeEvent::Select(selection));
         send_event(world, window.id, focused, &event) == Propagate::Handled
@@ -44,6 +62,7 @@ pub(crate) fn send_event(
     passes::event::send_event(
         world,
         window,
+        SeatId::PRIMARY,
         target,
         Propagate::Bubble,
         |widget, cx| widget.on_text_event(cx, event),
@@ -1,11 +1,29 @@
-use std::time::Instant;
+use std::{
+    f32::consts::{PI, TAU},
+    time::{Duration, Instant},
+};
 
 use crate::{
-    Gesture, PanGesture, Point, TapGesture, Touch, TouchEvent, TouchId, TouchMoveEvent,
-    TouchPressEvent, TouchPropagate, WidgetId, Window, WindowId, World, event::TouchState, passes,
+    FlingGesture, Gesture, Offset, PanGesture, PinchGesture, Point, RotateGesture, SeatId,
+    TapGesture, Tool, Touch, TouchEvent, TouchId, TouchMoveEvent, TouchPressEvent, TouchPropagate,
+    WidgetId, Window, WindowId, World,
+    event::{MultiTouch, TouchState},
+    passes,
 };
 
-pub(crate) fn down(world: &mut World, window: WindowId, touch: TouchId, position: Point) -> bool {
+/// How long a touch can be held still before being released and still fling.
+const FLING_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// The weight of the latest move when computing the velocity of a touch.
+const VELOCITY_SMOOTHING: f32 = 0.6;
+
+pub(crate) fn down(
+    world: &mut World,
+    window: WindowId,
+    touch: TouchId,
+    position: Point,
+    tool: Tool,
+) -> bool {
     let window_id = window;
 
     let Some(window) = world.state.window_mut(window) else {
@@ -17,30 +35,68 @@ pub(crate) fn down(world: &mut World, window: WindowId, touch: TouchId, position
             touch.current_position = position;
             touch.start_position = position;
             touch.start_time = Instant::now();
+            touch.tool = tool;
             touch.capturer = None;
+            touch.is_down = true;
+            touch.velocity = Offset::ZERO;
+            touch.last_move = Instant::now();
+
+            // keep a previous tap around to recognize double taps
+            if !matches!(touch.state, TouchState::Tapped(..)) {
+                touch.state = TouchState::None;
+            }
         }
 
         None => {
             window.touches.push(Touch {
-                id:               touch,
+                id: touch,
                 current_position: position,
-                start_position:   position,
-                start_time:       Instant::now(),
-                state:            TouchState::None,
-                capturer:         None,
+                start_position: position,
+                start_time: Instant::now(),
+                tool,
+                state: TouchState::None,
+                capturer: None,
+                is_down: true,
+                velocity: Offset::ZERO,
+                last_move: Instant::now(),
             });
         }
     }
 
-    if let Some(window) = world.window(window_id)
+    begin_multi_touch(window, touch);
+
+    // animation frames are used to time long taps
+    world.state.request_animate(window_id);
+
+    let target = if let Some(window) = world.window(window_id)
+        && let Some(touch) = window.touch(touch)
+    {
+        find_touch_target(world, window, touch, position)
+    } else {
+        None
+    };
+
+    if let Some(scope) = passes::dismiss::dismiss_outside(world, window_id, target) {
+        // the rest of the touch goes to the dismissed scope, so it doesn't activate anything
+        if let Some(window) = world.window_mut(window_id)
+            && let Some(touch) = window.touch_mut(touch)
+        {
+            touch.capturer = Some(scope);
+        }
+
+        return true;
+    }
+
+    if let Some(target) = target
+        && let Some(window) = world.window(window_id)
         && let Some(touch) = window.touch(touch)
-        && let Some(target) = find_touch_target(world, window, touch, position)
     {
         let touch_id = touch.id;
 
         let event = TouchEvent::Down(TouchPressEvent {
             touch: touch_id,
             position,
+            tool,
         });
 
         match send_event(world, window_id, target, &event) {
@@ -66,7 +122,13 @@ pub(crate) fn down(world: &mut World, window: WindowId, touch: TouchId, position
     }
 }
 
-pub(crate) fn up(world: &mut World, window: WindowId, touch: TouchId, position: Point) -> bool {
+pub(crate) fn up(
+    world: &mut World,
+    window: WindowId,
+    touch: TouchId,
+    position: Point,
+    tool: Tool,
+) -> bool {
     let window_id = window;
     let touch_id = touch;
 
@@ -74,18 +136,32 @@ pub(crate) fn up(world: &mut World, window: WindowId, touch: TouchId, position:
     let tap_time = world.settings().touch.tap_time;
     let double_tap_slop = world.settings().touch.double_tap_slop;
     let double_tap_time = world.settings().touch.double_tap_time;
+    let fling_velocity = world.settings().touch.fling_velocity;
 
     let Some(window) = world.state.window_mut(window_id) else {
         return false;
     };
 
+    if (window.multi_touch.as_ref()).is_some_and(|multi| multi.touches.contains(&touch_id)) {
+        window.multi_touch = None;
+    }
+
     let Some(touch) = window.touches.iter_mut().find(|t| t.id == touch_id) else {
         return false;
     };
 
+    touch.is_down = false;
+    touch.tool = tool;
+
     let mut events = Vec::new();
 
-    if let TouchState::Tapped(tap_position, tap_time) = touch.state
+    // long taps and multi touch gestures aren't also taps
+    if matches!(
+        touch.state,
+        TouchState::LongTapped | TouchState::Multi
+    ) {
+        touch.state = TouchState::None;
+    } else if let TouchState::Tapped(tap_position, tap_time) = touch.state
         && tap_position.distance(position) < double_tap_slop
         && tap_time.elapsed() < double_tap_time
     {
@@ -117,6 +193,22 @@ pub(crate) fn up(world: &mut World, window: WindowId, touch: TouchId, position:
 
         events.push(tap_event);
     } else {
+        // a touch held still before being released doesn't fling
+        if matches!(touch.state, TouchState::Panning)
+            && touch.last_move.elapsed() < FLING_TIMEOUT
+            && touch.velocity.length() >= fling_velocity
+        {
+            tracing::trace!(?touch_id, velocity = ?touch.velocity, "touch fling");
+
+            events.push(TouchEvent::Gesture(Gesture::Fling(
+                FlingGesture {
+                    touch: touch_id,
+                    position,
+                    velocity: touch.velocity,
+                },
+            )));
+        }
+
         touch.state = TouchState::None;
     }
 
@@ -125,6 +217,7 @@ pub(crate) fn up(world: &mut World, window: WindowId, touch: TouchId, position:
     let up_event = TouchEvent::Up(TouchPressEvent {
         touch: touch_id,
         position,
+        tool,
     });
 
     events.push(up_event);
@@ -140,22 +233,21 @@ pub(crate) fn up(world: &mut World, window: WindowId, touch: TouchId, position:
     if !matches!(touch_state, TouchState::Panning)
         && !handled
         && let Some(window) = world.window(window_id)
-        && let Some(focused) = window.focused
+        && let Some(focused) = window.focused(SeatId::PRIMARY)
         && world.widget(focused).is_ok_and(|widget| {
             let local = widget.cx.global_transform().inverse() * position;
             !widget.cx.rect().contains(local)
         })
     {
-        passes::focus::transfer(world, window_id, None);
+        passes::focus::transfer(world, window_id, SeatId::PRIMARY, None);
     }
 
-    if let Some(window) = world.window(window_id)
-        && let Some(touch) = window.touch(touch_id)
-        && let Some(target) = find_touch_target(world, window, touch, position)
-        && let Ok(mut widget) = world.widget_mut(target)
-    {
-        widget.set_active(false);
-    }
+    let target = match world.window(window_id) {
+        Some(window) => window
+            .touch(touch_id)
+            .and_then(|touch| find_touch_target(world, window, touch, position)),
+        None => None,
+    };
 
     if let Some(window) = world.window_mut(window_id)
         && let Some(touch) = window.touch_mut(touch_id)
@@ -163,10 +255,26 @@ pub(crate) fn up(world: &mut World, window: WindowId, touch: TouchId, position:
         touch.capturer = None;
     }
 
+    // other touches or pointers might still be holding the target
+    if let Some(target) = target
+        && world
+            .window(window_id)
+            .is_some_and(|w| !w.is_capturing(target))
+        && let Ok(mut widget) = world.widget_mut(target)
+    {
+        widget.set_active(false);
+    }
+
     handled
 }
 
-pub(crate) fn moved(world: &mut World, window: WindowId, touch: TouchId, position: Point) -> bool {
+pub(crate) fn moved(
+    world: &mut World,
+    window: WindowId,
+    touch: TouchId,
+    position: Point,
+    tool: Tool,
+) -> bool {
     let window_id = window;
     let touch_id = touch;
 
@@ -182,10 +290,24 @@ pub(crate) fn moved(world: &mut World, window: WindowId, touch: TouchId, positio
 
     let delta = position - touch.current_position;
     touch.current_position = position;
+    touch.tool = tool;
+
+    let now = Instant::now();
+    let dt = now.duration_since(touch.last_move).as_secs_f32();
+    touch.last_move = now;
+
+    // smooth the velocity, as the timing of move events is noisy
+    if dt > 0.0 {
+        let velocity = delta / dt;
+        touch.velocity =
+            touch.velocity * (1.0 - VELOCITY_SMOOTHING) + velocity * VELOCITY_SMOOTHING;
+    }
 
     let mut handled = false;
 
-    if touch.distance() > pan_distance || matches!(touch.state, TouchState::Panning) {
+    if matches!(touch.state, TouchState::Multi) {
+        handled |= multi_touch_moved(world, window_id);
+    } else if touch.distance() > pan_distance || matches!(touch.state, TouchState::Panning) {
         tracing::trace!(?touch_id, ?position, "touch pan");
 
         // if we're starting a pan gesture, target it at the start position
@@ -205,6 +327,7 @@ pub(crate) fn moved(world: &mut World, window: WindowId, touch: TouchId, positio
                 start: touch.start_position,
                 position,
                 delta,
+                velocity: touch.velocity,
             }));
 
             handled |= match send_event(world, window_id, target, &pan_event) {
@@ -231,6 +354,7 @@ pub(crate) fn moved(world: &mut World, window: WindowId, touch: TouchId, positio
     let event = TouchEvent::Move(TouchMoveEvent {
         touch: touch_id,
         position,
+        tool,
     });
 
     send_event_at(
@@ -238,6 +362,250 @@ pub(crate) fn moved(world: &mut World, window: WindowId, touch: TouchId, positio
     ) || handled
 }
 
+/// Recognize long taps of touches held in place, called every animation frame.
+pub(crate) fn long_taps(world: &mut World, window_id: WindowId) {
+    let long_tap_time = world.settings().touch.long_tap_time;
+    let long_tap_slop = world.settings().touch.long_tap_slop;
+
+    let Some(window) = world.state.window_mut(window_id) else {
+        return;
+    };
+
+    let mut long_taps = Vec::new();
+    let mut is_pending = false;
+
+    for touch in &mut window.touches {
+        if !touch.is_down
+            || touch.distance() >= long_tap_slop
+            || !matches!(
+                touch.state,
+                TouchState::None | TouchState::Tapped(..)
+            )
+        {
+            continue;
+        }
+
+        if touch.duration() < long_tap_time {
+            is_pending = true;
+            continue;
+        }
+
+        tracing::trace!(touch = ?touch.id, "touch long tap");
+
+        touch.state = TouchState::LongTapped;
+        long_taps.push((touch.id, touch.current_position));
+    }
+
+    if is_pending {
+        world.state.request_animate(window_id);
+    }
+
+    for (touch, position) in long_taps {
+        let event = TouchEvent::Gesture(Gesture::LongTap(TapGesture {
+            touch,
+            position,
+        }));
+        send_event_at(
+            world, window_id, touch, position, &event,
+        );
+    }
+}
+
+/// Start a pinch and rotate recognizer if `touch` is the second touch pressed.
+fn begin_multi_touch(window: &mut Window, touch: TouchId) {
+    if window.multi_touch.is_some() {
+        return;
+    }
+
+    let mut pressed = window.touches.iter().filter(|t| t.is_down);
+
+    let (Some(first), Some(second), None) = (
+        pressed.next(),
+        pressed.next(),
+        pressed.next(),
+    ) else {
+        return;
+    };
+
+    let (first, second) = match first.id == touch {
+        true => (second, first),
+        false => (first, second),
+    };
+
+    let offset = second.current_position - first.current_position;
+
+    window.multi_touch = Some(MultiTouch {
+        touches:     [first.id, second.id],
+        start_span:  first.current_position.distance(second.current_position),
+        start_angle: f32::atan2(offset.y, offset.x),
+        scale:       1.0,
+        angle:       0.0,
+        is_pinching: false,
+        is_rotating: false,
+    });
+
+    // an ongoing pan or pending tap of the first touch is cancelled
+    for t in &mut window.touches {
+        if t.is_down {
+            t.state = TouchState::Multi;
+        }
+    }
+}
+
+/// Send pinch and rotate gestures for the touches of the current [`MultiTouch`].
+///
+/// Each gesture is only recognized once its touches have moved past the threshold in
+/// [`TouchSettings`](crate::TouchSettings), after which both can be recognized at the same time.
+fn multi_touch_moved(world: &mut World, window_id: WindowId) -> bool {
+    let pinch_distance = world.settings().touch.pinch_distance;
+    let rotate_angle = world.settings().touch.rotate_angle;
+
+    let Some(window) = world.state.window_mut(window_id) else {
+        return false;
+    };
+
+    let Some(multi) = window.multi_touch.clone() else {
+        return false;
+    };
+
+    let [first, second] = multi.touches;
+
+    let (Some(first), Some(second)) = (
+        window.touch(first),
+        window.touch(second),
+    ) else {
+        return false;
+    };
+
+    let first = first.current_position;
+    let second = second.current_position;
+
+    let offset = second - first;
+    let focal = first + offset / 2.0;
+    let span = first.distance(second);
+
+    let scale = match multi.start_span > 0.0 {
+        true => span / multi.start_span,
+        false => 1.0,
+    };
+
+    let angle = wrap_angle(f32::atan2(offset.y, offset.x) - multi.start_angle);
+
+    let mut events = Vec::new();
+
+    let Some(multi) = window.multi_touch.as_mut() else {
+        return false;
+    };
+
+    multi.is_pinching |= (span - multi.start_span).abs() > pinch_distance;
+    multi.is_rotating |= angle.abs() > rotate_angle;
+
+    if multi.is_pinching {
+        tracing::trace!(?focal, scale, "touch pinch");
+
+        events.push(TouchEvent::Gesture(Gesture::Pinch(
+            PinchGesture {
+                touches: multi.touches,
+                focal,
+                scale,
+                delta: match multi.scale > 0.0 {
+                    true => scale / multi.scale,
+                    false => 1.0,
+                },
+            },
+        )));
+
+        multi.scale = scale;
+    }
+
+    if multi.is_rotating {
+        tracing::trace!(?focal, angle, "touch rotate");
+
+        events.push(TouchEvent::Gesture(Gesture::Rotate(
+            RotateGesture {
+                touches: multi.touches,
+                focal,
+                angle,
+                delta: wrap_angle(angle - multi.angle),
+            },
+        )));
+
+        multi.angle = angle;
+    }
+
+    let touches = multi.touches;
+    let mut handled = false;
+
+    for event in events {
+        let Some(window) = world.window(window_id) else {
+            break;
+        };
+
+        let Some(touch) = window.touch(touches[0]) else {
+            break;
+        };
+
+        let Some(target) = find_touch_target(world, window, touch, focal) else {
+            break;
+        };
+
+        handled |= match send_event(world, window_id, target, &event) {
+            TouchPropagate::Bubble => false,
+            TouchPropagate::Handled => true,
+
+            TouchPropagate::Capture => {
+                if let Ok(mut widget) = world.widget_mut(target) {
+                    widget.set_active(true);
+                }
+
+                if let Some(window) = world.window_mut(window_id) {
+                    for touch in &mut window.touches {
+                        if touches.contains(&touch.id) {
+                            touch.capturer = Some(target);
+                        }
+                    }
+                }
+
+                true
+            }
+        };
+    }
+
+    handled
+}
+
+pub(crate) fn gesture(
+    world: &mut World,
+    window_id: WindowId,
+    position: Point,
+    gesture: Gesture,
+) -> bool {
+    let target = match world.window(window_id) {
+        Some(window) => {
+            let target = passes::query::find_widget_at(world, window, position);
+            passes::grab::redirect(world, window, target)
+        }
+
+        None => return false,
+    };
+
+    let Some(target) = target else {
+        return false;
+    };
+
+    // platform gestures have no touch to capture, so capturing is the same as handling
+    let event = TouchEvent::Gesture(gesture);
+    !matches!(
+        send_event(world, window_id, target, &event),
+        TouchPropagate::Bubble
+    )
+}
+
+/// Wrap `angle` to `-PI..PI`.
+fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
 fn send_event_at(
     world: &mut World,
     window: WindowId,
@@ -254,7 +622,7 @@ fn send_event_at(
             TouchPropagate::Handled => true,
             TouchPropagate::Capture => {
                 tracing::error!(
-                    "touch capture is only valid in response to `TouchEvent::Down` or `Gesture::Pan`"
+                    "touch capture is only valid in response to `TouchEvent::Down`, `Gesture::Pan`, `Gesture::Pinch` or `Gesture::Rotate`"
                 );
 
                 true
@@ -271,10 +639,12 @@ fn find_touch_target(
     touch: &Touch,
     position: Point,
 ) -> Option<WidgetId> {
-    match touch.capturer {
+    let target = match touch.capturer {
         Some(target) => Some(target),
         None => passes::query::find_widget_at(world, window, position),
-    }
+    };
+
+    passes::grab::redirect(world, window, target)
 }
 
 pub(crate) fn send_event(
@@ -286,6 +656,7 @@ pub(crate) fn send_event(
     passes::event::send_event(
         world,
         window,
+        SeatId::PRIMARY,
         target,
         TouchPropagate::Bubble,
         |widget, cx| widget.on_touch_event(cx, event),
@@ -22,6 +22,12 @@ pub(crate) fn widget_recursive(mut widget: WidgetMut<'_>, update: &Update) {
         widget.cx.hierarchy.request_layout();
     }
 
+    // text may be shaped differently even if it keeps its size
+    if let Update::FontsChanged | Update::LayoutDirectionChanged = update {
+        widget.cx.hierarchy.request_layout();
+        widget.cx.hierarchy.request_draw();
+    }
+
     self::widget(&mut widget, update.clone());
 
     passes::hierarchy::for_each_child(widget, |child| {
@@ -0,0 +1,668 @@
+use std::{
+    error::Error,
+    fmt,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    AnimationClock, Canvas, Code, Key, KeyInput, Location, Modifiers, Offset, Point, PointerButton,
+    PointerId, ScrollDelta, SeatId, Size, Tool, ToolKind, TouchId, WindowId, World,
+};
+
+/// An input event received by a window, see [`World::start_input_recording`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputEvent {
+    PointerEntered {
+        pointer: PointerId,
+    },
+
+    PointerLeft {
+        pointer: PointerId,
+    },
+
+    PointerMoved {
+        pointer:  PointerId,
+        position: Point,
+        tool:     ToolKind,
+    },
+
+    PointerPressed {
+        pointer: PointerId,
+        button:  PointerButton,
+        pressed: bool,
+    },
+
+    PointerScrolled {
+        pointer: PointerId,
+        delta:   ScrollDelta,
+    },
+
+    ModifiersChanged(Modifiers),
+
+    KeyPressed {
+        key:      Key,
+        code:     Code,
+        location: Location,
+        repeat:   bool,
+        text:     Option<String>,
+        pressed:  bool,
+    },
+
+    TextPasted(String),
+    ImeCommitted(String),
+
+    TouchDown {
+        touch:    TouchId,
+        position: Point,
+    },
+
+    TouchUp {
+        touch:    TouchId,
+        position: Point,
+    },
+
+    TouchMoved {
+        touch:    TouchId,
+        position: Point,
+    },
+
+    Resized(Size),
+    Focused(bool),
+}
+
+impl InputEvent {
+    /// Send the event to `window`, as the backend would have, returning whether it was
+    /// handled.
+    pub fn send(&self, world: &mut World, window: WindowId) -> bool {
+        match self {
+            InputEvent::PointerEntered { pointer } => world.pointer_entered(window, *pointer),
+            InputEvent::PointerLeft { pointer } => world.pointer_left(window, *pointer),
+
+            InputEvent::PointerMoved {
+                pointer,
+                position,
+                tool,
+            } => world.pointer_moved(
+                window,
+                *pointer,
+                *position,
+                Tool::new(*tool),
+            ),
+
+            InputEvent::PointerPressed {
+                pointer,
+                button,
+                pressed,
+            } => world.pointer_pressed(window, *pointer, *button, *pressed),
+
+            InputEvent::PointerScrolled { pointer, delta } => {
+                world.pointer_scrolled(window, *pointer, delta.clone())
+            }
+
+            InputEvent::ModifiersChanged(modifiers) => world.modifiers_changed(window, *modifiers),
+
+            InputEvent::KeyPressed {
+                key,
+                code,
+                location,
+                repeat,
+                text,
+                pressed,
+            } => {
+                let input = KeyInput {
+                    key:      key.clone(),
+                    code:     *code,
+                    location: *location,
+                    text:     text.clone(),
+                    repeat:   *repeat,
+                };
+
+                world.seat_key_pressed(window, SeatId::PRIMARY, input, *pressed)
+            }
+
+            InputEvent::TextPasted(text) => world.text_pasted(window, text.clone()),
+            InputEvent::ImeCommitted(text) => world.ime_commit_text(window, text.clone()),
+
+            InputEvent::TouchDown { touch, position } => {
+                world.touch_down(window, *touch, *position, Tool::FINGER)
+            }
+
+            InputEvent::TouchUp { touch, position } => {
+                world.touch_up(window, *touch, *position, Tool::FINGER)
+            }
+
+            InputEvent::TouchMoved { touch, position } => {
+                world.touch_move(window, *touch, *position, Tool::FINGER)
+            }
+
+            InputEvent::Resized(size) => {
+                world.window_resized(window, *size);
+                true
+            }
+
+            InputEvent::Focused(is_focused) => {
+                world.window_focused(window, *is_focused);
+                true
+            }
+        }
+    }
+}
+
+/// An [`InputEvent`] and when it was received, relative to the start of the recording.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedInput {
+    pub time:  Duration,
+    pub event: InputEvent,
+}
+
+/// The input received by a window, recorded with [`World::start_input_recording`] and played
+/// back with [`Playback`].
+///
+/// Recordings are stored as text, one event per line, with [`ToString`] and [`FromStr`].
+/// Gestures recognized by the platform, going back and IME composition aren't recorded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputRecording {
+    pub events: Vec<RecordedInput>,
+}
+
+impl InputRecording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The time from the start of the recording to the last event.
+    pub fn duration(&self) -> Duration {
+        self.events
+            .last()
+            .map_or(Duration::ZERO, |input| input.time)
+    }
+
+    /// Play back every event into `window`, see [`Playback`].
+    pub fn replay(&self, world: &mut World, window: WindowId, canvas: &mut dyn Canvas) {
+        let mut playback = Playback::new(self);
+        while playback.step(world, window, canvas).is_some() {}
+    }
+}
+
+/// Plays an [`InputRecording`] back into a window, an event at a time.
+///
+/// Before each event the animations are advanced by the time since the previous event with
+/// [`World::advance`], and after it a frame is drawn, so with a manual
+/// [`AnimationClock`](crate::AnimationClock) playback is deterministic.
+pub struct Playback<'a> {
+    recording: &'a InputRecording,
+    index:     usize,
+    time:      Duration,
+}
+
+impl<'a> Playback<'a> {
+    pub fn new(recording: &'a InputRecording) -> Self {
+        Self {
+            recording,
+            index: 0,
+            time: Duration::ZERO,
+        }
+    }
+
+    /// Whether every event has been played back.
+    pub fn is_finished(&self) -> bool {
+        self.index >= self.recording.events.len()
+    }
+
+    /// Play back the next event, returning it, or `None` when every event has been played.
+    pub fn step(
+        &mut self,
+        world: &mut World,
+        window: WindowId,
+        canvas: &mut dyn Canvas,
+    ) -> Option<&'a RecordedInput> {
+        let input = self.recording.events.get(self.index)?;
+        self.index += 1;
+
+        world.advance(input.time.saturating_sub(self.time));
+        self.time = input.time;
+
+        input.event.send(world, window);
+        world.draw(window, canvas);
+
+        Some(input)
+    }
+}
+
+/// Records the input of a window, see [`World::start_input_recording`].
+pub(crate) struct InputRecorder {
+    pub window:    WindowId,
+    pub start:     Instant,
+    pub recording: InputRecording,
+
+    /// The animation time when recording started, used with a manual clock.
+    pub start_time: Duration,
+}
+
+impl InputRecorder {
+    pub fn new(window: WindowId, time: Duration) -> Self {
+        Self {
+            window,
+            start: Instant::now(),
+            recording: InputRecording::new(),
+            start_time: time,
+        }
+    }
+
+    /// Record `event` if it was received by the recorded window, `time` is the current
+    /// animation time.
+    pub fn record(
+        &mut self,
+        window: WindowId,
+        clock: AnimationClock,
+        time: Duration,
+        event: impl FnOnce() -> InputEvent,
+    ) {
+        if window != self.window {
+            return;
+        }
+
+        // with a manual clock the recording is timed by the animations, so it can be recorded
+        // in a test as well
+        let time = match clock {
+            AnimationClock::Real => self.start.elapsed(),
+            AnimationClock::Manual { .. } => time.saturating_sub(self.start_time),
+        };
+
+        self.recording.events.push(RecordedInput {
+            time,
+            event: event(),
+        });
+    }
+}
+
+/// An error parsing an [`InputRecording`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseInputError {
+    pub line:    usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl Error for ParseInputError {}
+
+impl fmt::Display for InputRecording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for input in &self.events {
+            write!(f, "{}", input.time.as_micros())?;
+
+            match &input.event {
+                InputEvent::PointerEntered { pointer } => {
+                    write!(f, " enter {}", pointer.as_u64())?;
+                }
+
+                InputEvent::PointerLeft { pointer } => {
+                    write!(f, " leave {}", pointer.as_u64())?;
+                }
+
+                InputEvent::PointerMoved {
+                    pointer,
+                    position,
+                    tool,
+                } => write!(
+                    f,
+                    " move {} {} {} {}",
+                    pointer.as_u64(),
+                    position.x,
+                    position.y,
+                    tool_name(*tool),
+                )?,
+
+                InputEvent::PointerPressed {
+                    pointer,
+                    button,
+                    pressed,
+                } => write!(
+                    f,
+                    " press {} {} {}",
+                    pointer.as_u64(),
+                    button_name(*button),
+                    pressed,
+                )?,
+
+                InputEvent::PointerScrolled { pointer, delta } => {
+                    let (kind, delta) = match delta {
+                        ScrollDelta::Line(delta) => ("line", delta),
+                        ScrollDelta::Pixel(delta) => ("pixel", delta),
+                    };
+
+                    write!(
+                        f,
+                        " scroll {} {kind} {} {}",
+                        pointer.as_u64(),
+                        delta.x,
+                        delta.y,
+                    )?;
+                }
+
+                InputEvent::ModifiersChanged(modifiers) => {
+                    write!(f, " modifiers {}", modifiers.bits())?;
+                }
+
+                InputEvent::KeyPressed {
+                    key,
+                    code,
+                    location,
+                    repeat,
+                    text,
+                    pressed,
+                } => {
+                    write!(
+                        f,
+                        " key {} {} {} {} {}",
+                        quote(&key.to_string()),
+                        code,
+                        location_name(*location),
+                        repeat,
+                        pressed,
+                    )?;
+
+                    if let Some(text) = text {
+                        write!(f, " {}", quote(text))?;
+                    }
+                }
+
+                InputEvent::TextPasted(text) => write!(f, " paste {}", quote(text))?,
+                InputEvent::ImeCommitted(text) => write!(f, " ime {}", quote(text))?,
+
+                InputEvent::TouchDown { touch, position } => write!(
+                    f,
+                    " touch-down {} {} {}",
+                    touch.as_u64(),
+                    position.x,
+                    position.y,
+                )?,
+
+                InputEvent::TouchUp { touch, position } => write!(
+                    f,
+                    " touch-up {} {} {}",
+                    touch.as_u64(),
+                    position.x,
+                    position.y,
+                )?,
+
+                InputEvent::TouchMoved { touch, position } => write!(
+                    f,
+                    " touch-move {} {} {}",
+                    touch.as_u64(),
+                    position.x,
+                    position.y,
+                )?,
+
+                InputEvent::Resized(size) => write!(
+                    f,
+                    " resize {} {}",
+                    size.width, size.height
+                )?,
+                InputEvent::Focused(is_focused) => write!(f, " focus {is_focused}")?,
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for InputRecording {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut recording = InputRecording::new();
+
+        for (index, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let input = parse_line(line).map_err(|message| ParseInputError {
+                line: index + 1,
+                message,
+            })?;
+
+            recording.events.push(input);
+        }
+
+        Ok(recording)
+    }
+}
+
+fn parse_line(line: &str) -> Result<RecordedInput, String> {
+    let tokens = tokenize(line)?;
+    let mut tokens = tokens.iter().map(String::as_str);
+
+    let mut next = || {
+        tokens
+            .next()
+            .ok_or_else(|| String::from("unexpected end of line"))
+    };
+
+    let time = Duration::from_micros(parse(next()?)?);
+
+    let event = match next()? {
+        "enter" => InputEvent::PointerEntered {
+            pointer: PointerId::from_u64(parse(next()?)?),
+        },
+
+        "leave" => InputEvent::PointerLeft {
+            pointer: PointerId::from_u64(parse(next()?)?),
+        },
+
+        "move" => InputEvent::PointerMoved {
+            pointer:  PointerId::from_u64(parse(next()?)?),
+            position: Point::new(parse(next()?)?, parse(next()?)?),
+            tool:     parse_tool(next()?)?,
+        },
+
+        "press" => InputEvent::PointerPressed {
+            pointer: PointerId::from_u64(parse(next()?)?),
+            button:  parse_button(next()?)?,
+            pressed: parse(next()?)?,
+        },
+
+        "scroll" => {
+            let pointer = PointerId::from_u64(parse(next()?)?);
+            let kind = next()?;
+            let delta = Offset::new(parse(next()?)?, parse(next()?)?);
+
+            let delta = match kind {
+                "line" => ScrollDelta::Line(delta),
+                "pixel" => ScrollDelta::Pixel(delta),
+                kind => return Err(format!("invalid scroll delta `{kind}`")),
+            };
+
+            InputEvent::PointerScrolled { pointer, delta }
+        }
+
+        "modifiers" => InputEvent::ModifiersChanged(Modifiers::from_bits_truncate(parse(
+            next()?,
+        )?)),
+
+        "key" => InputEvent::KeyPressed {
+            key:      parse(next()?)?,
+            code:     parse(next()?)?,
+            location: parse_location(next()?)?,
+            repeat:   parse(next()?)?,
+            pressed:  parse(next()?)?,
+            text:     next().ok().map(String::from),
+        },
+
+        "paste" => InputEvent::TextPasted(String::from(next()?)),
+        "ime" => InputEvent::ImeCommitted(String::from(next()?)),
+
+        "touch-down" => InputEvent::TouchDown {
+            touch:    TouchId::from_u64(parse(next()?)?),
+            position: Point::new(parse(next()?)?, parse(next()?)?),
+        },
+
+        "touch-up" => InputEvent::TouchUp {
+            touch:    TouchId::from_u64(parse(next()?)?),
+            position: Point::new(parse(next()?)?, parse(next()?)?),
+        },
+
+        "touch-move" => InputEvent::TouchMoved {
+            touch:    TouchId::from_u64(parse(next()?)?),
+            position: Point::new(parse(next()?)?, parse(next()?)?),
+        },
+
+        "resize" => InputEvent::Resized(Size::new(
+            parse(next()?)?,
+            parse(next()?)?,
+        )),
+
+        "focus" => InputEvent::Focused(parse(next()?)?),
+
+        event => return Err(format!("unknown event `{event}`")),
+    };
+
+    Ok(RecordedInput { time, event })
+}
+
+fn parse<T: FromStr>(token: &str) -> Result<T, String> {
+    token
+        .parse()
+        .map_err(|_| format!("invalid value `{token}`"))
+}
+
+/// Split `line` at spaces, except inside quotes.
+fn tokenize(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' => {}
+
+            '"' => {
+                let mut token = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => token.push('\n'),
+                            Some('r') => token.push('\r'),
+                            Some('t') => token.push('\t'),
+                            Some(c) => token.push(c),
+                            None => return Err(String::from("unterminated escape")),
+                        },
+                        Some(c) => token.push(c),
+                        None => return Err(String::from("unterminated string")),
+                    }
+                }
+
+                tokens.push(token);
+            }
+
+            c => {
+                let mut token = String::from(c);
+
+                while let Some(&c) = chars.peek()
+                    && c != ' '
+                {
+                    token.push(c);
+                    chars.next();
+                }
+
+                tokens.push(token);
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+fn tool_name(tool: ToolKind) -> &'static str {
+    match tool {
+        ToolKind::Unknown => "unknown",
+        ToolKind::Mouse => "mouse",
+        ToolKind::Finger => "finger",
+        ToolKind::Stylus => "stylus",
+        ToolKind::Eraser => "eraser",
+    }
+}
+
+fn parse_tool(token: &str) -> Result<ToolKind, String> {
+    Ok(match token {
+        "unknown" => ToolKind::Unknown,
+        "mouse" => ToolKind::Mouse,
+        "finger" => ToolKind::Finger,
+        "stylus" => ToolKind::Stylus,
+        "eraser" => ToolKind::Eraser,
+        token => return Err(format!("invalid tool `{token}`")),
+    })
+}
+
+fn button_name(button: PointerButton) -> String {
+    match button {
+        PointerButton::Primary => String::from("primary"),
+        PointerButton::Secondary => String::from("secondary"),
+        PointerButton::Tertiary => String::from("tertiary"),
+        PointerButton::Backward => String::from("backward"),
+        PointerButton::Forward => String::from("forward"),
+        PointerButton::Other(button) => button.to_string(),
+    }
+}
+
+fn parse_button(token: &str) -> Result<PointerButton, String> {
+    Ok(match token {
+        "primary" => PointerButton::Primary,
+        "secondary" => PointerButton::Secondary,
+        "tertiary" => PointerButton::Tertiary,
+        "backward" => PointerButton::Backward,
+        "forward" => PointerButton::Forward,
+        token => PointerButton::Other(parse(token)?),
+    })
+}
+
+fn location_name(location: Location) -> &'static str {
+    match location {
+        Location::Standard => "standard",
+        Location::Left => "left",
+        Location::Right => "right",
+        Location::Numpad => "numpad",
+    }
+}
+
+fn parse_location(token: &str) -> Result<Location, String> {
+    Ok(match token {
+        "standard" => Location::Standard,
+        "left" => Location::Left,
+        "right" => Location::Right,
+        "numpad" => Location::Numpad,
+        token => return Err(format!("invalid location `{token}`")),
+    })
+}
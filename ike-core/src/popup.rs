@@ -0,0 +1,157 @@
+use crate::{Point, Rect, Size};
+
+/// The side of its anchor a popup window is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PopupSide {
+    Top,
+
+    #[default]
+    Bottom,
+
+    Left,
+    Right,
+}
+
+impl PopupSide {
+    pub const fn flip(self) -> Self {
+        match self {
+            PopupSide::Top => PopupSide::Bottom,
+            PopupSide::Bottom => PopupSide::Top,
+            PopupSide::Left => PopupSide::Right,
+            PopupSide::Right => PopupSide::Left,
+        }
+    }
+}
+
+/// How a popup window is aligned along the side of its anchor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PopupAlign {
+    /// Aligned with the left or top edge of the anchor.
+    #[default]
+    Start,
+
+    Center,
+
+    /// Aligned with the right or bottom edge of the anchor.
+    End,
+}
+
+/// Where a popup window is placed, next to a rectangle in its parent window, e.g. the button
+/// that opened a menu.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PopupAnchor {
+    /// The rectangle in the parent window the popup is placed next to.
+    pub rect: Rect,
+
+    /// The side of `rect` the popup is placed on, flipped when the popup doesn't fit there.
+    pub side: PopupSide,
+
+    pub align: PopupAlign,
+
+    /// The gap between `rect` and the popup.
+    pub gap: f32,
+}
+
+impl PopupAnchor {
+    pub const fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            side: PopupSide::Bottom,
+            align: PopupAlign::Start,
+            gap: 0.0,
+        }
+    }
+
+    /// Anchor to a point, e.g. where a context menu was requested.
+    pub const fn point(point: Point) -> Self {
+        Self::new(Rect {
+            min: point,
+            max: point,
+        })
+    }
+
+    pub const fn with_side(mut self, side: PopupSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub const fn with_align(mut self, align: PopupAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub const fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Place a popup of `size` next to the anchor, staying within `bounds`, e.g. the monitor.
+    ///
+    /// The anchor must be in the same coordinates as `bounds`. The popup is flipped to the
+    /// opposite side when there's more room there, and slides along the side to stay within
+    /// `bounds`.
+    pub fn place(&self, size: Size, bounds: Rect) -> Point {
+        let side = match self.fits(self.side, size, bounds) {
+            true => self.side,
+            false if self.room(self.side.flip(), bounds) > self.room(self.side, bounds) => {
+                self.side.flip()
+            }
+            false => self.side,
+        };
+
+        let rect = self.rect;
+
+        let (x, y) = match side {
+            PopupSide::Top => (
+                self.aligned(rect.left(), rect.right(), size.width),
+                rect.top() - self.gap - size.height,
+            ),
+
+            PopupSide::Bottom => (
+                self.aligned(rect.left(), rect.right(), size.width),
+                rect.bottom() + self.gap,
+            ),
+
+            PopupSide::Left => (
+                rect.left() - self.gap - size.width,
+                self.aligned(rect.top(), rect.bottom(), size.height),
+            ),
+
+            PopupSide::Right => (
+                rect.right() + self.gap,
+                self.aligned(rect.top(), rect.bottom(), size.height),
+            ),
+        };
+
+        // slide along the side, keeping the start edge visible when the popup is too large
+        let x = x.min(bounds.right() - size.width).max(bounds.left());
+        let y = y.min(bounds.bottom() - size.height).max(bounds.top());
+
+        Point::new(x, y)
+    }
+
+    fn aligned(&self, start: f32, end: f32, length: f32) -> f32 {
+        match self.align {
+            PopupAlign::Start => start,
+            PopupAlign::Center => (start + end - length) / 2.0,
+            PopupAlign::End => end - length,
+        }
+    }
+
+    fn fits(&self, side: PopupSide, size: Size, bounds: Rect) -> bool {
+        match side {
+            PopupSide::Top | PopupSide::Bottom => self.room(side, bounds) >= size.height,
+            PopupSide::Left | PopupSide::Right => self.room(side, bounds) >= size.width,
+        }
+    }
+
+    /// The room between the anchor and the edge of `bounds` on `side`.
+    fn room(&self, side: PopupSide, bounds: Rect) -> f32 {
+        match side {
+            PopupSide::Top => self.rect.top() - self.gap - bounds.top(),
+            PopupSide::Bottom => bounds.bottom() - self.rect.bottom() - self.gap,
+            PopupSide::Left => self.rect.left() - self.gap - bounds.left(),
+            PopupSide::Right => bounds.right() - self.rect.right() - self.gap,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{Canvas, Padding, Painter, Point, Rect, Size};
+
+/// The page format used when printing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageSetup {
+    /// The size of a page in points, `1/72` of an inch.
+    pub size: Size,
+
+    /// The margin around the printable area of the page.
+    pub margin: Padding,
+}
+
+impl Default for PageSetup {
+    fn default() -> Self {
+        Self::A4
+    }
+}
+
+impl PageSetup {
+    /// ISO A4, `210 x 297 mm`.
+    pub const A4: Self = Self::new(Size::new(595.0, 842.0));
+
+    /// US Letter, `8.5 x 11 in`.
+    pub const LETTER: Self = Self::new(Size::new(612.0, 792.0));
+
+    /// US Legal, `8.5 x 14 in`.
+    pub const LEGAL: Self = Self::new(Size::new(612.0, 1008.0));
+
+    pub const fn new(size: Size) -> Self {
+        Self {
+            size,
+            margin: Padding::all(36.0),
+        }
+    }
+
+    pub const fn with_margin(mut self, margin: Padding) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    pub const fn landscape(mut self) -> Self {
+        self.size = Size::new(self.size.height, self.size.width);
+        self
+    }
+
+    /// The area of the page contents are drawn in.
+    pub const fn content_rect(&self) -> Rect {
+        Rect {
+            min: Point::new(self.margin.left, self.margin.top),
+            max: Point::new(
+                self.size.width - self.margin.right,
+                self.size.height - self.margin.bottom,
+            ),
+        }
+    }
+}
+
+/// A target for printed documents, see [`World::print`](crate::World::print).
+pub trait Printer {
+    fn painter(&mut self) -> &mut dyn Painter;
+
+    /// Begin a new page of `size`, and draw it with `f`.
+    fn page(&mut self, size: Size, f: &mut dyn FnMut(&mut dyn Canvas));
+}
@@ -3,6 +3,7 @@ use std::{
     collections::HashMap,
     fmt,
     hash::{BuildHasherDefault, Hash, Hasher},
+    mem,
     ops::Deref,
     sync::{Arc, Weak},
 };
@@ -40,11 +41,41 @@ impl Default for RecordSettings {
     }
 }
 
+impl RecordSettings {
+    /// The memory recordings may use under `pressure`.
+    pub fn memory_budget(&self, pressure: MemoryPressure) -> u64 {
+        match pressure {
+            MemoryPressure::None => self.max_memory_usage,
+            MemoryPressure::Moderate => self.max_memory_usage / 4,
+            MemoryPressure::Critical => 0,
+        }
+    }
+}
+
+/// How urgently the platform is asking the application to release memory.
+///
+/// Reported by the backend with [`World::memory_pressure_changed`](crate::World::memory_pressure_changed),
+/// caches shrink their budgets while it is raised.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MemoryPressure {
+    #[default]
+    None,
+
+    /// Memory is running low, caches should be kept small.
+    Moderate,
+
+    /// The process is at risk of being killed, everything that can be rebuilt should be released.
+    Critical,
+}
+
 #[derive(Debug)]
 pub struct Recorder {
     memory_usage: u64,
     frame_count:  u64,
     entries:      HashMap<WidgetId, RecorderEntry, BuildSeaHasher>,
+
+    // reused between frames by `cull_memory`
+    cull_buffer: Vec<(WidgetId, f32)>,
 }
 
 #[derive(Debug)]
@@ -68,6 +99,7 @@ impl Recorder {
             memory_usage: 0,
             frame_count:  0,
             entries:      HashMap::default(),
+            cull_buffer:  Vec::new(),
         }
     }
 
@@ -134,7 +166,17 @@ impl Recorder {
         self.entries.retain(|id, _| widgets.contains(*id));
     }
 
-    pub(crate) fn frame(&mut self, settings: &RecordSettings, widgets: &Widgets) {
+    /// Remove recordings until at most `max_memory_usage` bytes are used.
+    pub fn trim(&mut self, max_memory_usage: u64) {
+        self.cull_to(max_memory_usage);
+    }
+
+    pub(crate) fn frame(
+        &mut self,
+        settings: &RecordSettings,
+        pressure: MemoryPressure,
+        widgets: &Widgets,
+    ) {
         self.cleanup(widgets);
         self.frame_count += 1;
 
@@ -149,12 +191,12 @@ impl Recorder {
             !should_remove
         });
 
-        self.cull_memory(settings);
+        self.cull_memory(settings.memory_budget(pressure));
     }
 
     /// Cull recordings if memory usage exceeds `3/4` of `max_memory_usage`.
-    fn cull_memory(&mut self, settings: &RecordSettings) {
-        let cull_threshold = settings.max_memory_usage * 3 / 4;
+    fn cull_memory(&mut self, max_memory_usage: u64) {
+        let cull_threshold = max_memory_usage * 3 / 4;
 
         if self.memory_usage <= cull_threshold {
             return;
@@ -163,10 +205,21 @@ impl Recorder {
         tracing::warn!(
             "recorder memory usage exceeds 75% ({}/{}), consider increasing",
             DisplayMemorySize(self.memory_usage),
-            DisplayMemorySize(settings.max_memory_usage)
+            DisplayMemorySize(max_memory_usage)
         );
 
-        let mut widgets = Vec::new();
+        self.cull_to(cull_threshold);
+    }
+
+    /// Remove the recordings with the highest memory usage relative to their cost first, until
+    /// at most `cull_threshold` bytes are used.
+    fn cull_to(&mut self, cull_threshold: u64) {
+        if self.memory_usage <= cull_threshold {
+            return;
+        }
+
+        let mut widgets = mem::take(&mut self.cull_buffer);
+        widgets.clear();
 
         for (widget, entry) in self.entries.iter() {
             let weighted_cost = entry.recording.memory as f32 / entry.cost_estimate;
@@ -188,6 +241,8 @@ impl Recorder {
 
             self.memory_usage -= entry.recording.memory;
         }
+
+        self.cull_buffer = widgets;
     }
 }
 
@@ -6,6 +6,8 @@ use std::{
     sync::{Arc, Weak},
 };
 
+use crate::Color;
+
 #[macro_export]
 macro_rules! include_svg {
     ($path:literal) => {
@@ -104,6 +106,94 @@ impl SvgData {
     pub fn bytes(&self) -> &[u8] {
         &self.bytes
     }
+
+    /// The document restyled with `style`, this is what's drawn by
+    /// [`Canvas::draw_svg`](crate::Canvas::draw_svg).
+    pub fn restyled(&self, style: &SvgStyle) -> Cow<'_, [u8]> {
+        if style.is_plain() {
+            return Cow::Borrowed(&self.bytes);
+        }
+
+        let text = String::from_utf8_lossy(&self.bytes);
+
+        let Some((start, end)) = root_tag(&text) else {
+            return Cow::Borrowed(&self.bytes);
+        };
+
+        let mut root = text[start..end].to_owned();
+        let mut body = text[end..].to_owned();
+
+        if let Some(fill) = style.fill {
+            root = replace_paint(&root, "fill", fill);
+            body = replace_paint(&body, "fill", fill);
+
+            // shapes without a fill are black, unless they inherit one
+            if attribute_span(&root, "fill").is_none() {
+                root = set_attribute(&root, "fill", &hex(fill));
+            }
+        }
+
+        if let Some(stroke) = style.stroke {
+            root = replace_paint(&root, "stroke", stroke);
+            body = replace_paint(&body, "stroke", stroke);
+        }
+
+        if let Some(color) = style.color {
+            root = set_attribute(&root, "color", &hex(color));
+        }
+
+        if let Some(ref node) = style.node {
+            let Some(close) = body.rfind("</svg>") else {
+                return Cow::Borrowed(&self.bytes);
+            };
+
+            // the document becomes a definition, only the use of the node in it is drawn
+            body.replace_range(
+                close..,
+                &format!("</defs><use xlink:href=\"#{node}\"/></svg>"),
+            );
+            body.insert_str(1, "<defs>");
+
+            if attribute_span(&root, "xmlns:xlink").is_none() {
+                root = set_attribute(
+                    &root,
+                    "xmlns:xlink",
+                    "http://www.w3.org/1999/xlink",
+                );
+            }
+        }
+
+        let mut restyled = String::with_capacity(text.len());
+        restyled.push_str(&text[..start]);
+        restyled.push_str(&root);
+        restyled.push_str(&body);
+
+        Cow::Owned(restyled.into_bytes())
+    }
+
+    /// Extract the element with `id`, and its children, into a document of its own, the same
+    /// size as this one.
+    ///
+    /// The transforms of the ancestors of the element aren't applied.
+    pub fn extract(&self, id: &str) -> Option<SvgData> {
+        let text = String::from_utf8_lossy(&self.bytes);
+
+        if !text.contains(&format!("id=\"{id}\"")) && !text.contains(&format!("id='{id}'")) {
+            return None;
+        }
+
+        let style = SvgStyle {
+            node: Some(id.to_owned()),
+            ..Default::default()
+        };
+
+        match self.restyled(&style) {
+            Cow::Owned(bytes) => Some(SvgData {
+                bytes: Cow::Owned(bytes),
+            }),
+            Cow::Borrowed(_) => None,
+        }
+    }
 }
 
 impl fmt::Debug for SvgData {
@@ -111,3 +201,163 @@ impl fmt::Debug for SvgData {
         f.debug_struct("SvgData").finish_non_exhaustive()
     }
 }
+
+/// How an [`Svg`] is drawn, see [`SvgData::restyled`].
+///
+/// The alpha of the colors is ignored, draw the svg in a layer to fade it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgStyle {
+    /// The color of `currentColor`, which is black by default.
+    pub color: Option<Color>,
+
+    /// Replace the fill of everything that isn't unfilled.
+    pub fill: Option<Color>,
+
+    /// Replace the stroke of everything that's stroked.
+    pub stroke: Option<Color>,
+
+    /// Only draw the element with this id, and its children, see [`SvgData::extract`].
+    pub node: Option<String>,
+}
+
+impl SvgStyle {
+    /// Whether the svg is drawn as it is.
+    pub fn is_plain(&self) -> bool {
+        self.color.is_none() && self.fill.is_none() && self.stroke.is_none() && self.node.is_none()
+    }
+}
+
+/// Find the start tag of the root `<svg` element, without the closing `>`.
+fn root_tag(text: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+
+    while let Some(index) = text[offset..].find("<svg") {
+        let start = offset + index;
+        offset = start + 4;
+
+        if !text[offset..].starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+            continue;
+        }
+
+        let mut end = offset + text[offset..].find('>')?;
+
+        if text[..end].ends_with('/') {
+            end -= 1;
+        }
+
+        return Some((start, end));
+    }
+
+    None
+}
+
+/// Replace the values of the `name` attributes, and style properties, in `text` with `color`,
+/// except for those that are `none`.
+fn replace_paint(text: &str, name: &str, color: Color) -> String {
+    let color = hex(color);
+    let mut replaced = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find(name) {
+        replaced.push_str(&rest[..index]);
+        replaced.push_str(name);
+        rest = &rest[index + name.len()..];
+
+        let is_start = replaced[..replaced.len() - name.len()]
+            .chars()
+            .next_back()
+            .is_none_or(|c| c.is_whitespace() || matches!(c, ';' | '"' | '\''));
+
+        let Some((start, end)) = value_span(rest).filter(|_| is_start) else {
+            continue;
+        };
+
+        replaced.push_str(&rest[..start]);
+
+        match rest[start..end].trim() {
+            "none" => replaced.push_str(&rest[start..end]),
+            _ => replaced.push_str(&color),
+        }
+
+        rest = &rest[end..];
+    }
+
+    replaced.push_str(rest);
+    replaced
+}
+
+/// Find the value following the name of an attribute, `="value"`, or a style property,
+/// `: value;`, at the start of `text`.
+fn value_span(text: &str) -> Option<(usize, usize)> {
+    let trimmed = text.trim_start();
+
+    match trimmed.chars().next()? {
+        '=' => {
+            let value = trimmed[1..].trim_start();
+            let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+
+            let start = text.len() - value.len() + 1;
+            let end = start + text[start..].find(quote)?;
+            Some((start, end))
+        }
+
+        ':' => {
+            let start = text.len() - trimmed.len() + 1;
+            let end = text[start..]
+                .find([';', '"', '\''])
+                .map_or(text.len(), |end| start + end);
+            Some((start, end))
+        }
+
+        _ => None,
+    }
+}
+
+/// Find the value of the attribute `name` in the start tag `tag`.
+fn attribute_span(tag: &str, name: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+
+    while let Some(index) = tag[offset..].find(name) {
+        let start = offset + index;
+        offset = start + name.len();
+
+        let is_start = tag[..start].ends_with(char::is_whitespace);
+        let is_attribute = tag[offset..].trim_start().starts_with('=');
+
+        if let Some((start, end)) = value_span(&tag[offset..])
+            && is_start
+            && is_attribute
+        {
+            return Some((offset + start, offset + end));
+        }
+    }
+
+    None
+}
+
+/// Set the attribute `name` in the start tag `tag` to `value`.
+fn set_attribute(tag: &str, name: &str, value: &str) -> String {
+    match attribute_span(tag, name) {
+        Some((start, end)) => format!(
+            "{}{value}{}",
+            &tag[..start],
+            &tag[end..]
+        ),
+        None => format!(
+            "{} {name}=\"{value}\"{}",
+            &tag[..4],
+            &tag[4..]
+        ),
+    }
+}
+
+fn hex(color: Color) -> String {
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+    )
+}
@@ -0,0 +1,251 @@
+//! Generators of widget insertions, removals and borrows, for fuzzing the widget arena and the
+//! hierarchy built on it.
+
+use std::mem;
+
+use proptest::{collection, prelude::*, test_runner::TestCaseError};
+
+use crate::{
+    AnyWidgetId, Builder, GetError, WidgetId, World,
+    widgets::{Spacer, Stack},
+};
+
+/// A single operation on the widgets of a [`World`], widgets are picked by index into the live
+/// widgets an [`Arena`] has created so far, wrapping around.
+#[derive(Clone, Debug)]
+pub enum ArenaOp {
+    /// Add a [`Spacer`].
+    InsertLeaf,
+
+    /// Add a [`Stack`].
+    InsertParent,
+
+    /// Remove a widget and its descendants.
+    Remove(usize),
+
+    /// Add a widget without a parent as the last child of another.
+    AddChild { parent: usize, child: usize },
+
+    /// Borrow a widget as the right and the wrong type, picked from the removed widgets too.
+    Borrow(usize),
+}
+
+/// Any [`ArenaOp`].
+pub fn arena_op() -> impl Strategy<Value = ArenaOp> {
+    prop_oneof![
+        3 => Just(ArenaOp::InsertLeaf),
+        2 => Just(ArenaOp::InsertParent),
+        2 => any::<usize>().prop_map(ArenaOp::Remove),
+        3 => (any::<usize>(), any::<usize>())
+            .prop_map(|(parent, child)| ArenaOp::AddChild { parent, child }),
+        2 => any::<usize>().prop_map(ArenaOp::Borrow),
+    ]
+}
+
+/// A sequence of up to `max` operations.
+pub fn arena_ops(max: usize) -> impl Strategy<Value = Vec<ArenaOp>> {
+    collection::vec(arena_op(), 0..max)
+}
+
+/// A model of the widgets created by a sequence of [`ArenaOp`]s, checked against the world.
+#[derive(Debug, Default)]
+pub struct Arena {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    id:        WidgetId,
+    is_live:   bool,
+    is_parent: bool,
+    parent:    Option<usize>,
+    children:  Vec<usize>,
+}
+
+impl Arena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply `op` to `world`, then check the invariants of every widget created so far.
+    pub fn apply(&mut self, world: &mut World, op: &ArenaOp) -> Result<(), TestCaseError> {
+        match *op {
+            ArenaOp::InsertLeaf => {
+                let id = Spacer::new(world).id().upcast();
+                self.push(id, false);
+            }
+
+            ArenaOp::InsertParent => {
+                let id = Stack::new(world).id().upcast();
+                self.push(id, true);
+            }
+
+            ArenaOp::Remove(index) => {
+                if let Some(index) = self.pick(index, |_| true) {
+                    world.remove_widget(self.entries[index].id);
+                    self.remove(index);
+                }
+            }
+
+            ArenaOp::AddChild { parent, child } => {
+                if let Some(parent) = self.pick(parent, |entry| entry.is_parent)
+                    && let Some(child) = self.pick(child, |entry| entry.parent.is_none())
+                    && !self.is_ancestor(child, parent)
+                {
+                    world.add_child(
+                        self.entries[parent].id,
+                        self.entries[child].id,
+                    );
+                    self.entries[child].parent = Some(parent);
+                    self.entries[parent].children.push(child);
+                }
+            }
+
+            ArenaOp::Borrow(index) => {
+                if !self.entries.is_empty() {
+                    let entry = &self.entries[index % self.entries.len()];
+                    Self::check_borrow(world, entry)?;
+                }
+            }
+        }
+
+        self.check(world)
+    }
+
+    /// Check that exactly the widgets that weren't removed exist, with the children they
+    /// were given in order.
+    pub fn check(&self, world: &World) -> Result<(), TestCaseError> {
+        for entry in &self.entries {
+            prop_assert_eq!(
+                world.get_widget(entry.id).is_ok(),
+                entry.is_live,
+                "{:?}",
+                entry.id,
+            );
+
+            if !entry.is_live {
+                continue;
+            }
+
+            let parent = world
+                .get_widget(entry.id)
+                .ok()
+                .and_then(|widget| widget.cx.parent());
+            let expected = entry.parent.map(|parent| self.entries[parent].id);
+            prop_assert_eq!(parent, expected);
+
+            let children: Vec<_> = (entry.children.iter())
+                .map(|&child| self.entries[child].id)
+                .collect();
+
+            prop_assert_eq!(
+                world.children(entry.id),
+                children.as_slice()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn check_borrow(world: &mut World, entry: &Entry) -> Result<(), TestCaseError> {
+        let stack = world
+            .get_widget(WidgetId::<Stack>::downcast_unchecked(
+                entry.id,
+            ))
+            .err();
+        let spacer = world
+            .get_widget(WidgetId::<Spacer>::downcast_unchecked(
+                entry.id,
+            ))
+            .err();
+
+        let (right, wrong) = match entry.is_parent {
+            true => (stack, spacer),
+            false => (spacer, stack),
+        };
+
+        match entry.is_live {
+            true => {
+                prop_assert!(right.is_none());
+                prop_assert!(matches!(
+                    wrong,
+                    Some(GetError::InvalidType)
+                ));
+            }
+
+            false => {
+                prop_assert!(matches!(
+                    right,
+                    Some(GetError::InvalidId)
+                ));
+                prop_assert!(matches!(
+                    wrong,
+                    Some(GetError::InvalidId)
+                ));
+            }
+        }
+
+        prop_assert_eq!(
+            world.get_widget_mut(entry.id).is_ok(),
+            entry.is_live
+        );
+
+        Ok(())
+    }
+
+    fn push(&mut self, id: WidgetId, is_parent: bool) {
+        // ids are only reused with a new generation
+        for entry in &self.entries {
+            assert_ne!(entry.id, id, "widget id was reused");
+        }
+
+        self.entries.push(Entry {
+            id,
+            is_live: true,
+            is_parent,
+            parent: None,
+            children: Vec::new(),
+        });
+    }
+
+    /// Pick a live entry matching `filter` by `index`, wrapping around.
+    fn pick(&self, index: usize, filter: impl Fn(&Entry) -> bool) -> Option<usize> {
+        let matching: Vec<_> = (0..self.entries.len())
+            .filter(|&i| self.entries[i].is_live && filter(&self.entries[i]))
+            .collect();
+
+        matching.get(index % matching.len().max(1)).copied()
+    }
+
+    /// Remove `index` from its parent, and it and its descendants from the world.
+    fn remove(&mut self, index: usize) {
+        if let Some(parent) = self.entries[index].parent {
+            self.entries[parent]
+                .children
+                .retain(|&child| child != index);
+        }
+
+        self.kill(index);
+    }
+
+    fn kill(&mut self, index: usize) {
+        self.entries[index].is_live = false;
+
+        for child in mem::take(&mut self.entries[index].children) {
+            self.kill(child);
+        }
+    }
+
+    fn is_ancestor(&self, ancestor: usize, mut index: usize) -> bool {
+        loop {
+            if index == ancestor {
+                return true;
+            }
+
+            match self.entries[index].parent {
+                Some(parent) => index = parent,
+                None => return false,
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    Affine, BorderWidth, Canvas, Clip, Color, CornerRadius, Curve, GlyphCluster, LayerOptions,
+    Offset, Paint, Painter, Paragraph, Point, Recording, Rect, Shape, Size, Svg, SvgStyle,
+    TextDirection, TextLayoutLine, TextWrap,
+};
+
+/// A [`Painter`] laying out text in a monospace font, without loading any fonts.
+///
+/// Every character is `char_width` times the font size wide, lines end after each newline and
+/// word wrapped paragraphs break at any character.
+#[derive(Clone, Debug)]
+pub struct HeadlessPainter {
+    pub char_width: f32,
+}
+
+impl Default for HeadlessPainter {
+    fn default() -> Self {
+        Self { char_width: 0.5 }
+    }
+}
+
+impl HeadlessPainter {
+    fn font_size(paragraph: &Paragraph, index: usize) -> f32 {
+        paragraph
+            .sections
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= index)
+            .or(paragraph.sections.first())
+            .map_or(16.0, |(_, style)| style.font_size)
+    }
+}
+
+impl Painter for HeadlessPainter {
+    fn measure_svg(&mut self, _svg: &Svg) -> Size {
+        Size::ZERO
+    }
+
+    fn measure_text(&mut self, paragraph: &Paragraph, max_width: f32) -> Size {
+        let lines = self.layout_text(paragraph, max_width);
+
+        let width = lines.iter().map(|line| line.width).fold(0.0, f32::max);
+        let height = lines.last().map_or(0.0, |line| line.bottom());
+
+        Size::new(width, height)
+    }
+
+    fn layout_text(&mut self, paragraph: &Paragraph, max_width: f32) -> Vec<TextLayoutLine> {
+        let line_height = Self::font_size(paragraph, 0) * paragraph.line_height;
+        let wrap = paragraph.wrap == TextWrap::Word;
+
+        let new_line = |start_index: usize, top: f32| TextLayoutLine {
+            ascent: line_height * 0.8,
+            descent: line_height * 0.2,
+            left: 0.0,
+            width: 0.0,
+            height: line_height,
+            baseline: top + line_height * 0.8,
+            start_index,
+            end_index: start_index,
+            glyphs: Vec::new(),
+        };
+
+        let mut lines = Vec::new();
+        let mut line = new_line(0, 0.0);
+
+        for (index, c) in paragraph.text.char_indices() {
+            let width = Self::font_size(paragraph, index) * self.char_width;
+
+            if wrap && !line.glyphs.is_empty() && line.width + width > max_width {
+                let top = line.bottom();
+                lines.push(std::mem::replace(
+                    &mut line,
+                    new_line(index, top),
+                ));
+            }
+
+            let end_index = index + c.len_utf8();
+
+            line.glyphs.push(GlyphCluster {
+                bounds: Rect {
+                    min: Point::new(line.width, line.top()),
+                    max: Point::new(line.width + width, line.bottom()),
+                },
+                start_index: index,
+                end_index,
+                direction: TextDirection::Ltr,
+            });
+
+            line.width += width;
+            line.end_index = end_index;
+
+            if c == '\n' {
+                let top = line.bottom();
+                lines.push(std::mem::replace(
+                    &mut line,
+                    new_line(end_index, top),
+                ));
+            }
+        }
+
+        lines.push(line);
+        lines
+    }
+}
+
+/// A [`Canvas`] that draws nothing, for running the passes of a [`World`](crate::World) with
+/// [`World::draw`](crate::World::draw) without a renderer.
+#[derive(Clone, Debug, Default)]
+pub struct HeadlessCanvas {
+    pub painter: HeadlessPainter,
+}
+
+impl Canvas for HeadlessCanvas {
+    fn painter(&mut self) -> &mut dyn Painter {
+        &mut self.painter
+    }
+
+    fn transform(&mut self, _affine: Affine, f: &mut dyn FnMut(&mut dyn Canvas)) {
+        f(self);
+    }
+
+    fn layer(&mut self, _options: &LayerOptions, f: &mut dyn FnMut(&mut dyn Canvas)) {
+        f(self);
+    }
+
+    fn record(
+        &mut self,
+        _width: u32,
+        _height: u32,
+        _f: &mut dyn FnMut(&mut dyn Canvas),
+    ) -> Option<Recording> {
+        None
+    }
+
+    fn clip(&mut self, _clip: &Clip, f: &mut dyn FnMut(&mut dyn Canvas)) {
+        f(self);
+    }
+
+    fn fill(&mut self, _paint: &Paint) {}
+
+    fn draw_curve(&mut self, _curve: &Curve, _paint: &Paint) {}
+
+    fn draw_rect(&mut self, _rect: Rect, _corners: CornerRadius, _paint: &Paint) {}
+
+    fn draw_border(
+        &mut self,
+        _rect: Rect,
+        _width: BorderWidth,
+        _radius: CornerRadius,
+        _paint: &Paint,
+    ) {
+    }
+
+    fn draw_shadow(&mut self, _shape: &Shape, _elevation: f32, _color: Color) {}
+
+    fn draw_text(&mut self, _paragraph: &Paragraph, _max_width: f32, _offset: Offset) {}
+
+    fn draw_svg(&mut self, _svg: &Svg, _style: &SvgStyle) {}
+
+    fn draw_recording(&mut self, _rect: Rect, _recording: &Recording) {}
+}
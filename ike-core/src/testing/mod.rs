@@ -0,0 +1,18 @@
+//! Tools for fuzzing widgets with [`proptest`], enabled with the `testing` feature.
+//!
+//! The generators in [`text`] and [`arena`] are the ones `ike-core` fuzzes its own
+//! [`TextArea`](crate::widgets::TextArea) and widget arena with, and can be reused for custom
+//! widgets, driven by a [`World`](crate::World) drawn to a [`HeadlessCanvas`].
+//!
+//! [`Tester`] drives a window of widgets like a user would, for writing tests that click,
+//! type and query widgets without a backend.
+
+mod headless;
+mod tester;
+
+pub mod arena;
+pub mod text;
+
+pub use headless::{HeadlessCanvas, HeadlessPainter};
+pub use proptest;
+pub use tester::Tester;
@@ -0,0 +1,417 @@
+use std::{cell::RefCell, mem, rc::Rc, time::Duration};
+
+use crate::{
+    AnimationClock, AnyWidget, AnyWidgetId, Builder, Key, Layer, NamedKey, Offset, Point,
+    PointerButton, PointerId, Rect, ScrollDelta, Settings, Signal, Size, Tool, TouchId, Widget,
+    WidgetId, WidgetRef, WindowId, World,
+    testing::HeadlessCanvas,
+    widgets::{Label, TextArea},
+};
+
+/// Drives a window of widgets without a backend, for testing them.
+///
+/// The window is laid out and drawn to a [`HeadlessCanvas`] after every event, and animations
+/// only advance with [`Tester::advance`]. Widgets can be found by type or text, and clicked,
+/// typed into and inspected like a user would.
+///
+/// Clipboard and deferred signals are handled by the tester, every other signal is kept for
+/// inspection, see [`Tester::take_signals`].
+pub struct Tester {
+    pub world:  World,
+    pub window: WindowId,
+    pub canvas: HeadlessCanvas,
+
+    signals:   Rc<RefCell<Vec<Signal>>>,
+    unhandled: Vec<Signal>,
+    clipboard: String,
+    next_id:   u64,
+}
+
+impl Tester {
+    /// The size of the window, unless resized with [`Tester::resize`].
+    pub const SIZE: Size = Size::new(800.0, 600.0);
+
+    /// The longest [`Tester::settle`] waits for animations to end.
+    pub const SETTLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// The pointer used for pointer input, it's in the window from the start.
+    pub const POINTER: PointerId = PointerId::from_u64(0);
+
+    /// Open a window of the widget built by `contents`.
+    pub fn new<T>(contents: impl FnOnce(&mut World) -> WidgetId<T>) -> Self
+    where
+        T: ?Sized,
+    {
+        Self::with_settings(Settings::default(), contents)
+    }
+
+    pub fn with_settings<T>(
+        settings: Settings,
+        contents: impl FnOnce(&mut World) -> WidgetId<T>,
+    ) -> Self
+    where
+        T: ?Sized,
+    {
+        let signals = Rc::new(RefCell::new(Vec::new()));
+
+        let mut world = World::new(
+            Box::new({
+                let signals = signals.clone();
+                move |signal| signals.borrow_mut().push(signal)
+            }),
+            settings,
+        );
+
+        world.set_animation_clock(AnimationClock::manual());
+
+        let contents = contents(&mut world);
+        let window = world.create_window(contents.upcast());
+
+        let mut tester = Self {
+            world,
+            window,
+            canvas: HeadlessCanvas::default(),
+
+            signals,
+            unhandled: Vec::new(),
+            clipboard: String::new(),
+            next_id: 1,
+        };
+
+        tester.world.window_resized(window, Self::SIZE);
+        tester.world.window_focused(window, true);
+        tester.world.pointer_entered(window, Self::POINTER);
+        tester.pump();
+
+        tester
+    }
+
+    /// Handle pending signals and draw a frame.
+    pub fn pump(&mut self) {
+        self.handle_signals();
+        self.world.draw(self.window, &mut self.canvas);
+        self.handle_signals();
+    }
+
+    /// Advance animations by `duration`, a frame at a time, and draw a frame.
+    pub fn advance(&mut self, duration: Duration) {
+        self.world.advance(duration);
+        self.pump();
+    }
+
+    /// Advance animations until none are running, returning how long that took.
+    ///
+    /// # Panics
+    ///
+    /// When animations are still running after [`Tester::SETTLE_TIMEOUT`], e.g. the blinking
+    /// cursor of a focused text area.
+    pub fn settle(&mut self) -> Duration {
+        let frame = match self.world.animation_clock() {
+            AnimationClock::Manual { frame } if !frame.is_zero() => frame,
+            _ => Duration::from_millis(16),
+        };
+
+        let mut elapsed = Duration::ZERO;
+
+        while self.is_animating() {
+            assert!(
+                elapsed < Self::SETTLE_TIMEOUT,
+                "animations didn't settle within {:?}",
+                Self::SETTLE_TIMEOUT,
+            );
+
+            self.advance(frame);
+            elapsed += frame;
+        }
+
+        elapsed
+    }
+
+    /// Whether an animation of the window is running.
+    pub fn is_animating(&self) -> bool {
+        self.world
+            .get_window(self.window)
+            .is_some_and(|window| window.animate_requested.get())
+    }
+
+    /// The signals emitted since the last call, except those handled by the tester.
+    pub fn take_signals(&mut self) -> Vec<Signal> {
+        self.handle_signals();
+        mem::take(&mut self.unhandled)
+    }
+
+    /// The text last copied to the clipboard, pasting requests paste this.
+    pub fn clipboard(&self) -> &str {
+        &self.clipboard
+    }
+
+    pub fn set_clipboard(&mut self, contents: impl Into<String>) {
+        self.clipboard = contents.into();
+    }
+
+    fn handle_signals(&mut self) {
+        loop {
+            let signals = mem::take(&mut *self.signals.borrow_mut());
+
+            if signals.is_empty() {
+                break;
+            }
+
+            for signal in signals {
+                match signal {
+                    Signal::Mutate(mutate) => mutate(&mut self.world),
+                    Signal::ClipboardSet(contents) => self.clipboard = contents,
+
+                    Signal::ClipboardPaste(window) => {
+                        let contents = self.clipboard.clone();
+                        self.world.text_pasted(window, contents);
+                    }
+
+                    Signal::RequestRedraw { .. } | Signal::RequestAnimate { .. } => {}
+                    signal => self.unhandled.push(signal),
+                }
+            }
+        }
+    }
+
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+impl Tester {
+    pub fn resize(&mut self, size: Size) {
+        self.world.window_resized(self.window, size);
+        self.pump();
+    }
+
+    /// Move the pointer to `position` in the window.
+    pub fn hover(&mut self, position: Point) {
+        self.world.pointer_moved(
+            self.window,
+            Self::POINTER,
+            position,
+            Tool::MOUSE,
+        );
+        self.pump();
+    }
+
+    /// Move the pointer to `position` and press `button`.
+    pub fn press(&mut self, position: Point, button: PointerButton) {
+        self.hover(position);
+        self.world
+            .pointer_pressed(self.window, Self::POINTER, button, true);
+        self.pump();
+    }
+
+    /// Release `button` where the pointer is.
+    pub fn release(&mut self, button: PointerButton) {
+        self.world.pointer_pressed(
+            self.window,
+            Self::POINTER,
+            button,
+            false,
+        );
+        self.pump();
+    }
+
+    /// Press and release the primary button at `position`.
+    pub fn click(&mut self, position: Point) {
+        self.press(position, PointerButton::Primary);
+        self.release(PointerButton::Primary);
+    }
+
+    /// Click the center of `widget`.
+    pub fn click_widget(&mut self, widget: impl AnyWidgetId) {
+        let center = self.rect(widget).center();
+        self.click(center);
+    }
+
+    /// Press the primary button at `from`, move to `to` in `steps` moves and release it.
+    pub fn drag(&mut self, from: Point, to: Point, steps: u32) {
+        self.press(from, PointerButton::Primary);
+
+        for step in 1..=steps.max(1) {
+            let t = step as f32 / steps.max(1) as f32;
+            self.hover(from + (to - from) * t);
+        }
+
+        self.release(PointerButton::Primary);
+    }
+
+    /// Scroll by `delta` pixels at `position`.
+    pub fn scroll(&mut self, position: Point, delta: Offset) {
+        self.hover(position);
+        self.world.pointer_scrolled(
+            self.window,
+            Self::POINTER,
+            ScrollDelta::Pixel(delta),
+        );
+        self.pump();
+    }
+
+    /// Touch down and up at `position` with a new finger.
+    pub fn tap(&mut self, position: Point) {
+        let touch = TouchId::from_u64(self.next_id());
+
+        self.world.touch_down(
+            self.window,
+            touch,
+            position,
+            Tool::FINGER,
+        );
+        self.pump();
+
+        self.world.touch_up(
+            self.window,
+            touch,
+            position,
+            Tool::FINGER,
+        );
+        self.pump();
+    }
+
+    /// Press and release `key`, with the text of character keys.
+    pub fn key(&mut self, key: Key) {
+        let text = match key {
+            Key::Character(ref text) => Some(text.clone()),
+            Key::Named(NamedKey::Enter) => Some(String::from("\r")),
+            _ => None,
+        };
+
+        self.world.key_pressed(
+            self.window,
+            key.clone(),
+            false,
+            text.as_deref(),
+            true,
+        );
+        self.pump();
+
+        self.world.key_pressed(self.window, key, false, None, false);
+        self.pump();
+    }
+
+    /// Type `text` into the focused widget, a key at a time.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.key(Key::Character(c.to_string()));
+        }
+    }
+
+    /// Focus `widget` for keyboard input.
+    pub fn focus(&mut self, widget: impl AnyWidgetId) {
+        self.world.set_focus(self.window, Some(widget.upcast()));
+        self.pump();
+    }
+}
+
+impl Tester {
+    /// Every widget in the window, parents before their children, in the order they're drawn.
+    pub fn widgets(&self) -> Vec<WidgetId> {
+        let mut widgets = Vec::new();
+
+        let Some(window) = self.world.get_window(self.window) else {
+            return widgets;
+        };
+
+        let mut stack: Vec<_> = window.layers().iter().rev().map(Layer::widget).collect();
+
+        while let Some(id) = stack.pop() {
+            if let Ok(widget) = self.world.get_widget(id) {
+                stack.extend(widget.cx.children().iter().rev());
+            }
+
+            widgets.push(id);
+        }
+
+        widgets
+    }
+
+    /// The first widget of type `T`.
+    pub fn find<T: Widget>(&self) -> Option<WidgetId<T>> {
+        self.find_all().into_iter().next()
+    }
+
+    /// Every widget of type `T`.
+    pub fn find_all<T: Widget>(&self) -> Vec<WidgetId<T>> {
+        self.widgets()
+            .into_iter()
+            .filter_map(|id| self.world.get_widget(id).ok()?.downcast::<T>())
+            .map(|widget| widget.id())
+            .collect()
+    }
+
+    /// The widget with `test_id`, see [`World::find_by_test_id`].
+    pub fn find_by_test_id(&self, test_id: &str) -> Option<WidgetId> {
+        self.world.find_by_test_id(test_id)
+    }
+
+    /// The first widget showing exactly `text`, see [`Tester::text`].
+    pub fn find_text(&self, text: &str) -> Option<WidgetId> {
+        self.widgets()
+            .into_iter()
+            .find(|&id| self.text(id).is_some_and(|t| t == text))
+    }
+
+    /// Every widget `predicate` holds for.
+    pub fn find_where(&self, predicate: impl Fn(&WidgetRef<'_>) -> bool) -> Vec<WidgetId> {
+        self.widgets()
+            .into_iter()
+            .filter(|&id| self.world.get_widget(id).is_ok_and(|w| predicate(&w)))
+            .collect()
+    }
+
+    /// The text shown by `widget`, if it's a [`Label`] or [`TextArea`].
+    pub fn text(&self, widget: impl AnyWidgetId) -> Option<String> {
+        let widget = self.world.get_widget(widget.upcast()).ok()?;
+
+        if let Some(label) = Label::downcast_ref(&*widget.widget) {
+            return Some(label.paragraph().text.clone());
+        }
+
+        if let Some(text_area) = TextArea::<true>::downcast_ref(&*widget.widget) {
+            return Some(String::from(text_area.text()));
+        }
+
+        let text_area = TextArea::<false>::downcast_ref(&*widget.widget)?;
+        Some(String::from(text_area.text()))
+    }
+
+    /// Get `widget`.
+    ///
+    /// # Panics
+    ///
+    /// When `widget` doesn't exist.
+    #[track_caller]
+    pub fn get<T>(&self, widget: WidgetId<T>) -> WidgetRef<'_, T>
+    where
+        T: ?Sized + AnyWidget,
+    {
+        match self.world.get_widget(widget) {
+            Ok(widget) => widget,
+            Err(err) => panic!("failed getting widget {widget:?}: {err}"),
+        }
+    }
+
+    /// The bounds of `widget` in the window.
+    #[track_caller]
+    pub fn rect(&self, widget: impl AnyWidgetId) -> Rect {
+        let widget = self.get(widget.upcast());
+        widget
+            .cx
+            .rect()
+            .transform_bounds(widget.cx.global_transform())
+    }
+}
+
+impl Builder for Tester {
+    fn world(&self) -> &World {
+        &self.world
+    }
+
+    fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+}
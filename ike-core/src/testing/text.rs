@@ -0,0 +1,192 @@
+//! Generators of random text and edits, for fuzzing text input widgets.
+
+use std::ops::Range;
+
+use keyboard_types::{Key, Modifiers, NamedKey};
+use proptest::{collection, prelude::*, test_runner::TestCaseError};
+
+use crate::{Canvas, WindowId, World, widgets::TextArea};
+
+/// Text mixing ascii, multi-byte characters, combining marks, emoji and line breaks, which are
+/// the characters most likely to break byte offsets.
+pub fn unicode_text() -> impl Strategy<Value = String> {
+    let char = prop_oneof![
+        4 => proptest::char::range('a', 'z'),
+        1 => Just(' '),
+        1 => Just('\n'),
+        1 => Just('\r'),
+        1 => proptest::char::range('\u{00e0}', '\u{00ff}'),
+        1 => proptest::char::range('\u{0300}', '\u{036f}'),
+        1 => proptest::char::range('\u{4e00}', '\u{4e20}'),
+        1 => proptest::char::range('\u{1f600}', '\u{1f64f}'),
+        1 => any::<char>(),
+    ];
+
+    collection::vec(char, 0..12).prop_map(String::from_iter)
+}
+
+/// A byte range that might be reversed, or reach past the end of the text it's applied to.
+pub fn byte_range() -> impl Strategy<Value = Range<usize>> {
+    (0usize..48, 0usize..48).prop_map(|(start, end)| start..end)
+}
+
+/// A single edit of the focused text input of a window.
+#[derive(Clone, Debug)]
+pub enum TextEdit {
+    /// Type `text` as a key press.
+    Type(String),
+
+    /// Press and release a named key, with shift and the action modifier.
+    Press {
+        key:    NamedKey,
+        shift:  bool,
+        action: bool,
+    },
+
+    /// Paste `text`.
+    Paste(String),
+
+    /// Compose `text` with the input method.
+    Preedit {
+        text:   String,
+        cursor: Option<Range<usize>>,
+    },
+
+    /// Commit `text` with the input method.
+    Commit(String),
+
+    /// Select a range with the input method.
+    Select(Range<usize>),
+
+    /// Draw the window, laying out the text again.
+    Draw,
+}
+
+impl TextEdit {
+    /// Apply the edit to the focused widget of `window`.
+    pub fn apply(&self, world: &mut World, window: WindowId, canvas: &mut dyn Canvas) {
+        match self {
+            TextEdit::Type(text) => {
+                let key = Key::Character(text.clone());
+
+                world.key_pressed(
+                    window,
+                    key.clone(),
+                    false,
+                    Some(text),
+                    true,
+                );
+                world.key_pressed(window, key, false, None, false);
+            }
+
+            TextEdit::Press { key, shift, action } => {
+                let mut modifiers = Modifiers::empty();
+                modifiers.set(Modifiers::SHIFT, *shift);
+
+                match cfg!(target_os = "macos") {
+                    true => modifiers.set(Modifiers::META, *action),
+                    false => modifiers.set(Modifiers::CONTROL, *action),
+                }
+
+                world.modifiers_changed(window, modifiers);
+                world.key_pressed(
+                    window,
+                    Key::Named(*key),
+                    false,
+                    None,
+                    true,
+                );
+                world.key_pressed(
+                    window,
+                    Key::Named(*key),
+                    false,
+                    None,
+                    false,
+                );
+                world.modifiers_changed(window, Modifiers::empty());
+            }
+
+            TextEdit::Paste(text) => {
+                world.text_pasted(window, text.clone());
+            }
+
+            TextEdit::Preedit { text, cursor } => {
+                world.ime_preedit(window, text.clone(), cursor.clone());
+            }
+
+            TextEdit::Commit(text) => {
+                world.ime_commit_text(window, text.clone());
+            }
+
+            TextEdit::Select(selection) => {
+                world.ime_select(window, selection.clone());
+            }
+
+            TextEdit::Draw => {
+                world.draw(window, canvas);
+            }
+        }
+    }
+}
+
+/// Any [`TextEdit`].
+pub fn text_edit() -> impl Strategy<Value = TextEdit> {
+    let key = prop_oneof![
+        Just(NamedKey::ArrowLeft),
+        Just(NamedKey::ArrowRight),
+        Just(NamedKey::ArrowUp),
+        Just(NamedKey::ArrowDown),
+        Just(NamedKey::Home),
+        Just(NamedKey::End),
+        Just(NamedKey::Backspace),
+        Just(NamedKey::Delete),
+        Just(NamedKey::Enter),
+    ];
+
+    prop_oneof![
+        3 => unicode_text().prop_map(TextEdit::Type),
+        4 => (key, any::<bool>(), any::<bool>())
+            .prop_map(|(key, shift, action)| TextEdit::Press { key, shift, action }),
+        1 => unicode_text().prop_map(TextEdit::Paste),
+        2 => (unicode_text(), proptest::option::of(byte_range()))
+            .prop_map(|(text, cursor)| TextEdit::Preedit { text, cursor }),
+        1 => unicode_text().prop_map(TextEdit::Commit),
+        1 => byte_range().prop_map(TextEdit::Select),
+        2 => Just(TextEdit::Draw),
+    ]
+}
+
+/// A sequence of up to `max` edits.
+pub fn text_edits(max: usize) -> impl Strategy<Value = Vec<TextEdit>> {
+    collection::vec(text_edit(), 0..max)
+}
+
+/// Check that `index` is a char boundary of `text`.
+pub fn check_char_boundary(text: &str, index: usize, what: &str) -> Result<(), TestCaseError> {
+    prop_assert!(
+        text.is_char_boundary(index),
+        "{what} {index} is not a char boundary of {text:?}",
+    );
+
+    Ok(())
+}
+
+/// Check that the cursor and selection of `text_area` are valid offsets into its text.
+pub fn check_text_area<const EDITABLE: bool>(
+    text_area: &TextArea<EDITABLE>,
+) -> Result<(), TestCaseError> {
+    let text = text_area.text();
+    let selection = text_area.selection();
+
+    check_char_boundary(text, text_area.cursor(), "cursor")?;
+    check_char_boundary(text, selection.start, "selection start")?;
+    check_char_boundary(text, selection.end, "selection end")?;
+
+    prop_assert!(
+        selection.start == text_area.cursor() || selection.end == text_area.cursor(),
+        "cursor {} is not at an end of the selection {selection:?}",
+        text_area.cursor(),
+    );
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::{
     hash::{Hash, Hasher},
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Range},
     sync::{Arc, Weak},
 };
 
@@ -33,6 +33,7 @@ impl Paragraph {
                 wrap,
                 text: String::new(),
                 sections: Vec::new(),
+                links: Vec::new(),
             }),
         }
     }
@@ -49,9 +50,28 @@ impl Paragraph {
         self.text.push_str(text.as_ref());
     }
 
+    /// Push `text` linking to `target`, e.g. a url or an id the app knows.
+    pub fn push_link(
+        &mut self,
+        text: impl AsRef<str>,
+        style: TextStyle,
+        target: impl Into<String>,
+    ) {
+        let start = self.text.len();
+        self.push(text, style);
+
+        let link = TextLink {
+            range:  start..self.text.len(),
+            target: target.into(),
+        };
+
+        self.links.push(link);
+    }
+
     pub fn clear(&mut self) {
         self.text.clear();
         self.sections.clear();
+        self.links.clear();
     }
 
     pub fn sections(&self) -> impl Iterator<Item = (&str, &TextStyle)> {
@@ -123,6 +143,23 @@ pub struct ParagraphData {
     pub wrap:        TextWrap,
     pub text:        String,
     pub sections:    Vec<(usize, TextStyle)>,
+
+    /// The ranges of the text that are links, see [`Paragraph::push_link`].
+    pub links: Vec<TextLink>,
+}
+
+impl ParagraphData {
+    /// The link the byte at `index` is part of.
+    pub fn link_at(&self, index: usize) -> Option<&TextLink> {
+        self.links.iter().find(|link| link.range.contains(&index))
+    }
+}
+
+/// A range of the text of a [`Paragraph`] linking to `target`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextLink {
+    pub range:  Range<usize>,
+    pub target: String,
 }
 
 #[derive(Clone, Debug)]
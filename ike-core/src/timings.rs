@@ -0,0 +1,73 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::FramePhase;
+
+/// The time taken by each [`FramePhase`] of a frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameTimings {
+    phases: [Duration; FramePhase::ALL.len()],
+}
+
+impl FrameTimings {
+    pub fn get(&self, phase: FramePhase) -> Duration {
+        self.phases[phase as usize]
+    }
+
+    /// The time taken by every phase of the frame.
+    pub fn total(&self) -> Duration {
+        self.phases.iter().sum()
+    }
+
+    pub(crate) fn add(&mut self, phase: FramePhase, duration: Duration) {
+        self.phases[phase as usize] += duration;
+    }
+}
+
+/// The time between the frames last drawn in a window.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FrameHistory {
+    last:      Option<Instant>,
+    intervals: VecDeque<Duration>,
+}
+
+impl FrameHistory {
+    /// The number of frames kept.
+    pub const LEN: usize = 120;
+
+    /// Frames further apart than this are assumed to be idle, not slow.
+    const IDLE: Duration = Duration::from_millis(250);
+
+    pub fn push(&mut self, now: Instant) {
+        if let Some(last) = self.last.replace(now) {
+            let interval = now - last;
+
+            if interval > Self::IDLE {
+                return;
+            }
+
+            if self.intervals.len() == Self::LEN {
+                self.intervals.pop_front();
+            }
+
+            self.intervals.push_back(interval);
+        }
+    }
+
+    /// The intervals between frames, oldest first.
+    pub fn intervals(&self) -> impl ExactSizeIterator<Item = Duration> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// The average frames per second over the history.
+    pub fn fps(&self) -> f32 {
+        let total: Duration = self.intervals.iter().sum();
+
+        match total.is_zero() {
+            true => 0.0,
+            false => self.intervals.len() as f32 / total.as_secs_f32(),
+        }
+    }
+}
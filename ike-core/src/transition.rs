@@ -1,8 +1,13 @@
-use std::{f32::consts::PI, ops::Deref, time::Duration};
+use std::{
+    f32::consts::PI,
+    ops::Deref,
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+    time::Duration,
+};
 
-use crate::{Color, Offset, Padding, Size};
+use crate::{Color, Offset, Padding, Point, Rect, Size};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TransitionCurve {
     Linear,
     Ease,
@@ -11,6 +16,15 @@ pub enum TransitionCurve {
     BackIn,
     BackOut,
     Back,
+
+    /// Follow a physical spring, the duration of the [`Transition`] is ignored.
+    ///
+    /// [`Transitioned`] simulates the spring, keeping its velocity when the target changes
+    /// mid-transition.
+    Spring(Spring),
+
+    /// Pass through a sequence of keyframes, each reached with its own curve.
+    Keyframes(&'static [Keyframe]),
 }
 
 impl TransitionCurve {
@@ -44,8 +58,139 @@ impl TransitionCurve {
             TransitionCurve::BackOut => {
                 1.0 + (C + 1.0) * f32::powi(t - 1.0, 3) + C * f32::powi(t - 1.0, 2)
             }
+
+            // stretched over the time the spring takes to settle
+            TransitionCurve::Spring(spring) => {
+                let mut position = 0.0;
+                let mut velocity = 0.0;
+                spring.step(
+                    &mut position,
+                    &mut velocity,
+                    t * spring.settle_time(),
+                );
+
+                position
+            }
+
+            TransitionCurve::Keyframes(keyframes) => {
+                let mut previous = Keyframe::new(0.0, 0.0, TransitionCurve::Linear);
+
+                for &keyframe in keyframes {
+                    if t < keyframe.time {
+                        let length = keyframe.time - previous.time;
+                        let t = (t - previous.time) / length;
+                        let t = keyframe.curve.apply(t);
+
+                        return previous.value + (keyframe.value - previous.value) * t;
+                    }
+
+                    previous = keyframe;
+                }
+
+                previous.value
+            }
+        }
+    }
+}
+
+/// The parameters of a damped spring, pulling a value towards its target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring {
+    /// How strongly the spring pulls towards the target.
+    pub stiffness: f32,
+
+    /// How strongly movement is resisted, low damping oscillates around the target.
+    pub damping: f32,
+
+    /// How heavy the value is, heavier values are slower to start and to stop.
+    pub mass: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::SMOOTH
+    }
+}
+
+impl Spring {
+    /// Settles without overshooting.
+    pub const SMOOTH: Self = Self::new(170.0, 26.0);
+
+    /// Overshoots slightly before settling.
+    pub const SNAPPY: Self = Self::new(300.0, 20.0);
+
+    /// Oscillates noticeably around the target.
+    pub const BOUNCY: Self = Self::new(180.0, 12.0);
+
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    pub const fn with_mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Advance `position` and `velocity` towards `1.0` by `time` seconds.
+    pub fn step(&self, position: &mut f32, velocity: &mut f32, time: f32) {
+        // small steps keep stiff springs stable
+        const STEP: f32 = 1.0 / 240.0;
+
+        let mass = self.mass.max(f32::EPSILON);
+        let mut time = time;
+
+        while time > 0.0 {
+            let dt = time.min(STEP);
+            time -= dt;
+
+            let force = -self.stiffness * (*position - 1.0) - self.damping * *velocity;
+            *velocity += force / mass * dt;
+            *position += *velocity * dt;
         }
     }
+
+    /// Whether a spring at `position` moving with `velocity` has come to rest.
+    pub fn is_settled(&self, position: f32, velocity: f32) -> bool {
+        (position - 1.0).abs() < 1e-3 && velocity.abs() < 1e-2
+    }
+
+    /// Approximately how long the spring takes to settle from rest, in seconds.
+    pub fn settle_time(&self) -> f32 {
+        let mass = self.mass.max(f32::EPSILON);
+        let frequency = f32::sqrt(self.stiffness.max(f32::EPSILON) / mass);
+        let ratio = self.damping / (2.0 * f32::sqrt(self.stiffness.max(f32::EPSILON) * mass));
+
+        // the slowest decaying part of the motion decides when the spring is at rest
+        let decay = match ratio < 1.0 {
+            true => ratio * frequency,
+            false => frequency * (ratio - f32::sqrt(ratio * ratio - 1.0)),
+        };
+
+        f32::ln(1000.0) / decay.max(f32::EPSILON)
+    }
+}
+
+/// A point in [`TransitionCurve::Keyframes`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Keyframe {
+    /// When the keyframe is reached, as a fraction of the duration between `0.0` and `1.0`.
+    pub time: f32,
+
+    /// The progress at the keyframe, `0.0` is the start and `1.0` the end value.
+    pub value: f32,
+
+    /// The curve used to reach the keyframe from the previous one.
+    pub curve: TransitionCurve,
+}
+
+impl Keyframe {
+    pub const fn new(time: f32, value: f32, curve: TransitionCurve) -> Self {
+        Self { time, value, curve }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -106,10 +251,100 @@ impl Transition {
             duration,
         }
     }
+
+    /// Transition with a physical spring, taking as long as it needs to settle.
+    pub const fn spring(spring: Spring) -> Self {
+        Self {
+            curve:    TransitionCurve::Spring(spring),
+            duration: 0.0,
+        }
+    }
+
+    /// Transition through `keyframes`, which should end with a keyframe at `(1.0, 1.0)`.
+    pub const fn keyframes(duration: f32, keyframes: &'static [Keyframe]) -> Self {
+        Self {
+            curve: TransitionCurve::Keyframes(keyframes),
+            duration,
+        }
+    }
+
+    fn spring_curve(&self) -> Option<Spring> {
+        match self.curve {
+            TransitionCurve::Spring(spring) => Some(spring),
+            _ => None,
+        }
+    }
+
+    /// The duration in seconds, scaled by the current [`AnimationSettings`].
+    pub fn scaled_duration(&self) -> f32 {
+        AnimationSettings::get().scale_duration(self.duration)
+    }
+}
+
+static ANIMATION_SCALE: AtomicU32 = AtomicU32::new(f32::to_bits(1.0));
+static REDUCE_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Process-wide settings consulted by every [`Transitioned`].
+///
+/// The backends populate these from the accessibility settings of the OS at startup, unless
+/// overridden by [`Settings::animation`](crate::Settings::animation).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimationSettings {
+    /// Factor every transition duration is multiplied by, `0.0` disables transitions.
+    pub scale: f32,
+
+    /// Whether the user prefers reduced motion, transitions complete instantly when set.
+    pub reduce_motion: bool,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self {
+            scale:         1.0,
+            reduce_motion: false,
+        }
+    }
+}
+
+impl AnimationSettings {
+    /// Get the current settings.
+    pub fn get() -> Self {
+        Self {
+            scale:         f32::from_bits(ANIMATION_SCALE.load(Ordering::Relaxed)),
+            reduce_motion: REDUCE_MOTION.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Replace the current settings, affecting transitions already in progress.
+    pub fn set(settings: Self) {
+        let scale = settings.scale.max(0.0);
+
+        ANIMATION_SCALE.store(scale.to_bits(), Ordering::Relaxed);
+        REDUCE_MOTION.store(
+            settings.reduce_motion,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Scale `duration` in seconds by these settings.
+    pub fn scale_duration(&self, duration: f32) -> f32 {
+        if self.reduce_motion {
+            0.0
+        } else {
+            duration * self.scale.max(0.0)
+        }
+    }
 }
 
 pub trait Interpolate {
     fn interpolate(start: &Self, end: &Self, x: f32) -> Self;
+
+    /// The distance between two values, used to keep the velocity of a spring when its target
+    /// changes mid-transition.
+    fn distance(start: &Self, end: &Self) -> f32 {
+        let _ = (start, end);
+        1.0
+    }
 }
 
 pub struct Transitioned<T> {
@@ -118,6 +353,10 @@ pub struct Transitioned<T> {
     start:      T,
     end:        T,
     time:       f32,
+
+    /// The progress of a spring from `start` to `end`, and its velocity.
+    position: f32,
+    velocity: f32,
 }
 
 impl<T> Transitioned<T>
@@ -129,18 +368,22 @@ where
             current: value.clone(),
             start: value.clone(),
             end: value,
-            time: transition.duration,
+            time: transition.scaled_duration(),
             transition,
+
+            position: 1.0,
+            velocity: 0.0,
         }
     }
 
     pub fn set_transition(&mut self, transition: Transition) {
-        if self.is_complete() {
-            self.time = transition.duration;
-        }
-
+        let is_complete = self.is_complete();
         self.transition = transition;
 
+        if is_complete {
+            self.complete();
+        }
+
         self.update_current();
     }
 
@@ -149,7 +392,7 @@ where
         self.end = value.clone();
         self.start = value.clone();
         self.current = value.clone();
-        self.time = self.transition.duration;
+        self.complete();
     }
 
     /// Get the starting value.
@@ -175,9 +418,19 @@ where
             return false;
         }
 
+        // keep the speed of a moving spring, relative to the new distance
+        let distance = T::distance(&self.start, &self.end);
+        let new_distance = T::distance(&self.current, &target);
+
+        self.velocity = match new_distance > 0.0 {
+            true => self.velocity * distance / new_distance,
+            false => 0.0,
+        };
+
         self.start = self.current.clone();
         self.end = target;
         self.time = 0.0;
+        self.position = 0.0;
 
         self.update_current();
 
@@ -188,8 +441,26 @@ where
     ///
     /// Returns whether `request_animate` should be called.
     pub fn animate(&mut self, dt: Duration) -> bool {
-        self.time += dt.as_secs_f32();
-        self.time = self.time.clamp(0.0, self.transition.duration);
+        if let Some(spring) = self.transition.spring_curve() {
+            // springs have no duration, so the animation scale slows down time instead
+            let scale = AnimationSettings::get().scale_duration(1.0);
+
+            if scale > 0.0 {
+                let dt = dt.as_secs_f32() / scale;
+                spring.step(
+                    &mut self.position,
+                    &mut self.velocity,
+                    dt,
+                );
+            }
+
+            if scale <= 0.0 || spring.is_settled(self.position, self.velocity) {
+                self.complete();
+            }
+        } else {
+            self.time += dt.as_secs_f32();
+            self.time = self.time.clamp(0.0, self.transition.scaled_duration());
+        }
 
         self.update_current();
 
@@ -198,17 +469,31 @@ where
 
     /// Check if the transition has reached the end.
     pub fn is_complete(&self) -> bool {
-        self.time >= self.transition.duration
+        match self.transition.spring_curve() {
+            Some(..) => self.position == 1.0 && self.velocity == 0.0,
+            None => self.time >= self.transition.scaled_duration(),
+        }
+    }
+
+    fn complete(&mut self) {
+        self.time = self.transition.scaled_duration();
+        self.position = 1.0;
+        self.velocity = 0.0;
     }
 
     fn update_current(&mut self) {
-        if self.transition.duration == 0.0 {
+        if self.is_complete() {
             self.current = self.end.clone();
             return;
         }
 
-        let fraction = self.time / self.transition.duration;
-        let position = self.transition.curve.apply(fraction);
+        let position = match self.transition.spring_curve() {
+            Some(..) => self.position,
+            None => {
+                let fraction = self.time / self.transition.scaled_duration();
+                self.transition.curve.apply(fraction.min(1.0))
+            }
+        };
 
         self.current = T::interpolate(&self.start, &self.end, position);
     }
@@ -226,6 +511,10 @@ impl Interpolate for f32 {
     fn interpolate(start: &Self, end: &Self, x: f32) -> Self {
         *start * (1.0 - x) + *end * x
     }
+
+    fn distance(start: &Self, end: &Self) -> f32 {
+        (end - start).abs()
+    }
 }
 
 impl Interpolate for Color {
@@ -237,6 +526,15 @@ impl Interpolate for Color {
             a: f32::interpolate(&start.a, &end.a, x),
         }
     }
+
+    fn distance(start: &Self, end: &Self) -> f32 {
+        let r = end.r - start.r;
+        let g = end.g - start.g;
+        let b = end.b - start.b;
+        let a = end.a - start.a;
+
+        f32::sqrt(r * r + g * g + b * b + a * a)
+    }
 }
 
 impl Interpolate for Offset {
@@ -246,6 +544,10 @@ impl Interpolate for Offset {
             y: f32::interpolate(&from.y, &to.y, x),
         }
     }
+
+    fn distance(from: &Self, to: &Self) -> f32 {
+        (*to - *from).length()
+    }
 }
 
 impl Interpolate for Size {
@@ -255,6 +557,13 @@ impl Interpolate for Size {
             height: f32::interpolate(&from.height, &to.height, x),
         }
     }
+
+    fn distance(from: &Self, to: &Self) -> f32 {
+        f32::hypot(
+            to.width - from.width,
+            to.height - from.height,
+        )
+    }
 }
 
 impl Interpolate for Padding {
@@ -266,4 +575,36 @@ impl Interpolate for Padding {
             bottom: f32::interpolate(&start.bottom, &end.bottom, x),
         }
     }
+
+    fn distance(start: &Self, end: &Self) -> f32 {
+        let left = end.left - start.left;
+        let top = end.top - start.top;
+        let right = end.right - start.right;
+        let bottom = end.bottom - start.bottom;
+
+        f32::sqrt(left * left + top * top + right * right + bottom * bottom)
+    }
+}
+
+impl Interpolate for Rect {
+    fn interpolate(start: &Self, end: &Self, x: f32) -> Self {
+        let min = Point::new(
+            f32::interpolate(&start.min.x, &end.min.x, x),
+            f32::interpolate(&start.min.y, &end.min.y, x),
+        );
+
+        let max = Point::new(
+            f32::interpolate(&start.max.x, &end.max.x, x),
+            f32::interpolate(&start.max.y, &end.max.y, x),
+        );
+
+        Self { min, max }
+    }
+
+    fn distance(start: &Self, end: &Self) -> f32 {
+        let min = end.min - start.min;
+        let max = end.max - start.max;
+
+        f32::hypot(min.length(), max.length())
+    }
 }
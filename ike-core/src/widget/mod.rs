@@ -8,9 +8,9 @@ use std::{
 };
 
 use crate::{
-    Canvas, Clip, ComposeCx, DrawCx, EventCx, KeyEvent, LayoutCx, Padding, Point, PointerEvent,
-    PointerPropagate, Propagate, Rect, RefCx, Size, Space, TextEvent, TouchEvent, TouchPropagate,
-    UpdateCx,
+    Canvas, Color, ComposeCx, DrawCx, EventCx, KeyEvent, LayoutCx, Padding, Paragraph, Point,
+    PointerEvent, PointerPropagate, Propagate, Rect, RefCx, Size, Space, TextEvent, TouchEvent,
+    TouchPropagate, UpdateCx,
 };
 
 mod state;
@@ -24,6 +24,12 @@ pub trait Widget: Any {
         let _ = cx;
     }
 
+    /// Draw behind the widget, outside of its clip, e.g. to cast a shadow.
+    fn draw_behind(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        let _ = cx;
+        let _ = canvas;
+    }
+
     fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
         let _ = cx;
         let _ = canvas;
@@ -79,14 +85,10 @@ pub trait Widget: Any {
             return None;
         }
 
-        match cx.clip() {
-            Some(Clip::Rect(rect, _)) => {
-                if !rect.contains(local) {
-                    return None;
-                }
-            }
-
-            None => {}
+        if let Some(clip) = cx.clip()
+            && !clip.contains(local)
+        {
+            return None;
         }
 
         for child in cx.iter_children().flatten().rev() {
@@ -102,6 +104,12 @@ pub trait Widget: Any {
         }
     }
 
+    /// The text the widget lays out, shaped ahead of layout together with that of other
+    /// widgets, see [`Painter::prepare_text`](crate::Painter::prepare_text).
+    fn paragraph(&self) -> Option<&Paragraph> {
+        None
+    }
+
     fn accepts_pointer() -> bool
     where
         Self: Sized,
@@ -122,6 +130,17 @@ pub trait Widget: Any {
     {
         false
     }
+
+    /// Whether the widget is a dismiss scope, receiving [`Update::Dismiss`] when escape is
+    /// pressed, or the window is clicked outside of it.
+    ///
+    /// When scopes are nested, the innermost one is dismissed first.
+    fn accepts_dismiss() -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -134,7 +153,20 @@ pub enum Update {
     Focused(bool),
     Stashed(bool),
     Disabled(bool),
+    /// The widget has gained or lost the input grab of its window.
+    Grabbed(bool),
+    /// The widget is asked to dismiss itself, see [`Widget::accepts_dismiss`].
+    Dismiss,
     ScrollTo(Rect),
+    /// The color sampled at a point requested with
+    /// [`EventCx::request_sample`](crate::EventCx::request_sample).
+    Sampled(Point, Color),
+    /// The fonts text is shaped with have changed, see
+    /// [`World::fonts_changed`](crate::World::fonts_changed).
+    FontsChanged,
+    /// The layout direction of the widget may have changed, see
+    /// [`LayoutCx::layout_direction`](crate::LayoutCx::layout_direction).
+    LayoutDirectionChanged,
 
     /// [`Window::is_focused`](crate::Window::is_focused) has changed.
     WindowFocused(bool),
@@ -144,6 +176,11 @@ pub enum Update {
     WindowScaled(f32),
     /// [`Window::insets`](crate::Window::insets) have changed.
     WindowInset(Padding),
+    /// [`Window::keyboard_height`](crate::Window::keyboard_height) has changed.
+    WindowKeyboard(f32),
+    /// Whether the window is redacted has changed, see
+    /// [`World::set_window_redacted`](crate::World::set_window_redacted).
+    WindowRedacted(bool),
 
     Children(ChildUpdate),
 }
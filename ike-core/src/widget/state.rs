@@ -1,12 +1,13 @@
 use std::{cell::Cell, rc::Rc};
 
 use crate::{
-    Affine, Clip, CursorIcon, Rect, Size, Space, Widget, WidgetId, WindowId, world::Widgets,
+    Affine, Clip, Cursor, LayoutDirection, Rect, Size, Space, Widget, WidgetId, WindowId,
+    world::Widgets,
 };
 
 bitflags::bitflags! {
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-    pub struct WidgetFlags: u16 {
+    pub struct WidgetFlags: u32 {
         const IS_HOVERED = 1 << 0;
         const IS_FOCUSED = 1 << 1;
         const IS_ACTIVE  = 1 << 2;
@@ -26,6 +27,11 @@ bitflags::bitflags! {
         const ACCEPTS_POINTER = 1 << 12;
         const ACCEPTS_FOCUS   = 1 << 13;
         const ACCEPTS_TEXT    = 1 << 14;
+        const ACCEPTS_DISMISS = 1 << 15;
+
+        /// The widget was disabled itself, rather than through an ancestor, so it stays
+        /// disabled when the ancestor is enabled.
+        const IS_SELF_DISABLED = 1 << 16;
     }
 }
 
@@ -41,6 +47,11 @@ impl WidgetFlags {
         flags.set(Self::ACCEPTS_FOCUS, T::accepts_focus());
         flags.set(Self::ACCEPTS_TEXT, T::accepts_text());
 
+        flags.set(
+            Self::ACCEPTS_DISMISS,
+            T::accepts_dismiss(),
+        );
+
         flags
     }
 
@@ -88,6 +99,10 @@ pub struct WidgetHierarchy {
     pub(crate) parent:   Option<WidgetId>,
     pub(crate) children: Rc<Vec<WidgetId>>,
     pub(crate) flags:    Cell<WidgetFlags>,
+
+    /// The layout direction of the widget and its descendants, inherited from the parent when
+    /// `None`.
+    pub(crate) direction: Cell<Option<LayoutDirection>>,
 }
 
 impl WidgetHierarchy {
@@ -97,6 +112,8 @@ impl WidgetHierarchy {
             parent:   None,
             children: Rc::new(Vec::new()),
             flags:    Cell::new(WidgetFlags::new::<T>()),
+
+            direction: Cell::new(None),
         }
     }
 
@@ -199,6 +216,16 @@ impl WidgetHierarchy {
         })
     }
 
+    pub fn set_accepts_focus(&self, accepts_focus: bool) {
+        self.flags.update(|mut flags| {
+            flags.set(
+                WidgetFlags::ACCEPTS_FOCUS,
+                accepts_focus,
+            );
+            flags
+        })
+    }
+
     pub fn set_stashed(&self, is_stashed: bool) {
         self.flags.update(|mut flags| {
             flags.set(WidgetFlags::IS_STASHED, is_stashed);
@@ -213,6 +240,16 @@ impl WidgetHierarchy {
         })
     }
 
+    pub fn set_self_disabled(&self, is_disabled: bool) {
+        self.flags.update(|mut flags| {
+            flags.set(
+                WidgetFlags::IS_SELF_DISABLED,
+                is_disabled,
+            );
+            flags
+        })
+    }
+
     pub fn is_hovered(&self) -> bool {
         self.flags.get().contains(WidgetFlags::IS_HOVERED)
     }
@@ -233,6 +270,10 @@ impl WidgetHierarchy {
         self.flags.get().contains(WidgetFlags::IS_DISABLED)
     }
 
+    pub fn is_self_disabled(&self) -> bool {
+        self.flags.get().contains(WidgetFlags::IS_SELF_DISABLED)
+    }
+
     pub fn has_hovered(&self) -> bool {
         self.flags.get().contains(WidgetFlags::HAS_HOVERED)
     }
@@ -285,6 +326,15 @@ impl WidgetHierarchy {
             && !self.is_stashed()
             && !self.is_disabled()
     }
+
+    /// Get whether the widget is currently a dismiss scope.
+    ///
+    /// This is true if the [`Widget`] accepts dismiss and is not `stashed` or `disabled`.
+    pub fn accepts_dismiss(&self) -> bool {
+        self.flags.get().contains(WidgetFlags::ACCEPTS_DISMISS)
+            && !self.is_stashed()
+            && !self.is_disabled()
+    }
 }
 
 #[derive(Debug)]
@@ -295,14 +345,20 @@ pub struct WidgetState {
     pub(crate) bounds:           Rect,
     pub(crate) size:             Size,
     pub(crate) previous_space:   Option<Space>,
-    pub(crate) cursor:           CursorIcon,
+    pub(crate) cursor:           Cursor,
 
     pub(crate) is_subpixel:  bool,
+    pub(crate) is_cached:    bool,
     pub(crate) stable_draws: u32,
     pub(crate) clip:         Option<Clip>,
+    pub(crate) overflow:     f32,
+    pub(crate) opacity:      f32,
+
+    /// A name that identifies the widget to tests and tooling, see
+    /// [`World::find_by_test_id`](crate::World::find_by_test_id).
+    pub(crate) test_id: Option<String>,
 
     pub(crate) tracing_span: tracing::Span,
-    #[allow(dead_code, reason = "used for debug purposes")]
     pub(crate) short_name:   &'static str,
     #[allow(dead_code, reason = "used for debug purposes")]
     pub(crate) type_name:    &'static str,
@@ -319,11 +375,16 @@ impl WidgetState {
             bounds:           Rect::default(),
             size:             Size::new(0.0, 0.0),
             previous_space:   None,
-            cursor:           CursorIcon::Default,
+            cursor:           Cursor::default(),
 
             is_subpixel:  false,
+            is_cached:    false,
             stable_draws: 0,
             clip:         None,
+            overflow:     0.0,
+            opacity:      1.0,
+
+            test_id: None,
 
             tracing_span: tracing::error_span!(
                 "Widget",
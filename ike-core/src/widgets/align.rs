@@ -1,11 +1,14 @@
 use crate::{AnyWidgetId, Builder, LayoutCx, Offset, Size, Space, Widget, WidgetMut};
 
+/// Aligns its contents within the space it's laid out in, where `x` is measured from the
+/// leading edge, so it's mirrored in right-to-left layouts.
 pub struct Aligned {
     x: f32,
     y: f32,
 }
 
 impl Aligned {
+    #[track_caller]
     pub fn new(
         cx: &mut impl Builder,
         x: f32,
@@ -49,7 +52,7 @@ impl Widget for Aligned {
         let excess_height = size.height - child_size.height;
 
         let position = Offset::new(
-            excess_width * self.x,
+            excess_width * cx.layout_direction().align(self.x),
             excess_height * self.y,
         );
 
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use crate::{
+    Affine, Builder, ComposeCx, LayoutCx, Offset, Point, Size, Space, Transition, Transitioned,
+    UpdateCx, Widget, WidgetMut,
+};
+
+/// How the contents of an [`AnimatedVisibility`] look while hidden, and how they transition.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VisibilityTransition {
+    pub transition: Transition,
+
+    /// The opacity of the contents when hidden.
+    pub opacity: f32,
+
+    /// The offset of the contents when hidden, relative to their size.
+    pub offset: Offset,
+
+    /// The scale of the contents when hidden.
+    pub scale: f32,
+}
+
+impl VisibilityTransition {
+    /// Show and hide instantly.
+    pub const NONE: Self = Self {
+        transition: Transition::INSTANT,
+        opacity:    1.0,
+        offset:     Offset::ZERO,
+        scale:      1.0,
+    };
+
+    pub const fn fade(transition: Transition) -> Self {
+        Self::NONE.with_transition(transition).with_fade()
+    }
+
+    /// Slide by `offset`, relative to the size of the contents.
+    pub const fn slide(offset: Offset, transition: Transition) -> Self {
+        Self::NONE.with_transition(transition).with_slide(offset)
+    }
+
+    pub const fn scale(scale: f32, transition: Transition) -> Self {
+        Self::NONE.with_transition(transition).with_scale(scale)
+    }
+
+    pub const fn with_transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    pub const fn with_fade(mut self) -> Self {
+        self.opacity = 0.0;
+        self
+    }
+
+    pub const fn with_slide(mut self, offset: Offset) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub const fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+/// Animates its contents in when shown and out when hidden.
+///
+/// The contents are kept while they animate out, after which [`AnimatedVisibility::set_on_hidden`]
+/// is called so they can be removed.
+pub struct AnimatedVisibility {
+    progress:  Transitioned<f32>,
+    enter:     VisibilityTransition,
+    exit:      VisibilityTransition,
+    is_hiding: bool,
+    is_hidden: bool,
+    on_hidden: Box<dyn FnMut()>,
+}
+
+impl AnimatedVisibility {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            progress:  Transitioned::new(0.0, Transition::INSTANT),
+            enter:     VisibilityTransition::NONE,
+            exit:      VisibilityTransition::NONE,
+            is_hiding: false,
+            is_hidden: true,
+            on_hidden: Box::new(|| {}),
+        })
+        .finish()
+    }
+
+    /// Animate the contents in, reversing the exit animation if it's playing.
+    pub fn show(this: &mut WidgetMut<'_, Self>) {
+        this.widget.is_hiding = false;
+        this.widget.is_hidden = false;
+        // begin first, so the new transition starts from where the old one was
+        let transition = this.widget.enter.transition;
+        this.widget.progress.begin(1.0);
+        this.widget.progress.set_transition(transition);
+
+        this.cx.request_compose();
+        this.cx.request_animate();
+    }
+
+    /// Animate the contents out.
+    pub fn hide(this: &mut WidgetMut<'_, Self>) {
+        this.widget.is_hiding = true;
+        let transition = this.widget.exit.transition;
+        this.widget.progress.begin(0.0);
+        this.widget.progress.set_transition(transition);
+
+        this.cx.request_compose();
+        this.cx.request_animate();
+    }
+
+    pub fn set_enter(this: &mut WidgetMut<'_, Self>, enter: VisibilityTransition) {
+        this.widget.enter = enter;
+        this.cx.request_compose();
+    }
+
+    pub fn set_exit(this: &mut WidgetMut<'_, Self>, exit: VisibilityTransition) {
+        this.widget.exit = exit;
+        this.cx.request_compose();
+    }
+
+    /// Called when the contents have animated out, and should be removed.
+    pub fn set_on_hidden(this: &mut WidgetMut<'_, Self>, on_hidden: impl FnMut() + 'static) {
+        this.widget.on_hidden = Box::new(on_hidden);
+    }
+
+    pub fn is_hiding(&self) -> bool {
+        self.is_hiding
+    }
+}
+
+impl Widget for AnimatedVisibility {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        match cx.children().is_empty() {
+            true => space.min,
+            false => cx.layout_nth_child(0, space),
+        }
+    }
+
+    fn compose(&mut self, cx: &mut ComposeCx<'_>) {
+        let hidden = match self.is_hiding {
+            true => self.exit,
+            false => self.enter,
+        };
+
+        // how far the contents are from being fully shown
+        let t = 1.0 - *self.progress;
+
+        let opacity = 1.0 + (hidden.opacity - 1.0) * t;
+        let scale = 1.0 + (hidden.scale - 1.0) * t;
+        let offset = Offset::new(
+            hidden.offset.x * cx.width() * t,
+            hidden.offset.y * cx.height() * t,
+        );
+
+        let transform = Affine::translate(cx.rect().center() - Point::ORIGIN)
+            * Affine::translate(offset)
+            * Affine::scale(scale, scale)
+            * Affine::translate(Point::ORIGIN - cx.rect().center());
+
+        cx.set_opacity(opacity);
+        cx.place_nth_child(0, transform);
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_compose();
+
+        if self.progress.animate(dt) {
+            cx.request_animate();
+            cx.set_subpixel(true);
+            return;
+        }
+
+        cx.set_subpixel(false);
+
+        if self.is_hiding && !self.is_hidden {
+            self.is_hidden = true;
+            (self.on_hidden)();
+        }
+    }
+}
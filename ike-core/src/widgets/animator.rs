@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use crate::{AnyWidgetId, Builder, LayoutCx, Size, Space, UpdateCx, Widget, WidgetMut};
+
+/// Calls a callback every frame while running, to drive animations of its contents from
+/// outside the widget, e.g. by rebuilding them with an interpolated value.
+pub struct Animator {
+    is_running: bool,
+    on_animate: Box<dyn FnMut(Duration)>,
+}
+
+impl Animator {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            is_running: false,
+            on_animate: Box::new(|_| {}),
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    /// Set whether `on_animate` is called every frame.
+    pub fn set_running(this: &mut WidgetMut<Self>, is_running: bool) {
+        this.widget.is_running = is_running;
+
+        if is_running {
+            this.cx.request_animate();
+        }
+    }
+
+    /// Set the callback called with the time since the last frame.
+    pub fn set_on_animate(this: &mut WidgetMut<Self>, on_animate: impl FnMut(Duration) + 'static) {
+        this.widget.on_animate = Box::new(on_animate);
+    }
+}
+
+impl Widget for Animator {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        if self.is_running {
+            (self.on_animate)(dt);
+            cx.request_animate();
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use std::time::Duration;
 
 use crate::{
-    AnyWidgetId, BorderWidth, Builder, Canvas, Color, CornerRadius, DrawCx, EventCx, Gesture, Key,
-    KeyEvent, LayoutCx, NamedKey, Padding, Paint, PointerButton, PointerEvent, PointerPropagate,
-    Propagate, Size, Space, TouchEvent, TouchPropagate, Transition, Transitioned, Widget,
+    AnyWidgetId, BorderWidth, Builder, Canvas, Color, CornerRadius, DrawCx, EventCx, Gesture,
+    KeyEvent, LayoutCx, Padding, Paint, PointerButton, PointerEvent, PointerId, PointerPropagate,
+    Propagate, Shape, Size, Space, TouchEvent, TouchPropagate, Transition, Transitioned, Widget,
     WidgetMut, context::UpdateCx, widget::Update,
 };
 
@@ -17,10 +17,19 @@ pub struct Button {
     active_color:  Color,
     border_color:  Color,
     focus_color:   Color,
+    elevation:     f32,
+    shadow_color:  Color,
     on_click:      Box<dyn FnMut()>,
+
+    /// The pointer that pressed the button, releasing other pointers doesn't click it.
+    pressed: Option<PointerId>,
+
+    /// Whether the button is held down with the keyboard, it's clicked when the key is released.
+    key_pressed: bool,
 }
 
 impl Button {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
         cx.build_widget(Button {
             padding:       Padding::all(8.0),
@@ -32,7 +41,11 @@ impl Button {
             active_color:  Color::BLUE,
             border_color:  Color::BLACK,
             focus_color:   Color::BLUE,
+            elevation:     0.0,
+            shadow_color:  Color::BLACK.fade(0.4),
             on_click:      Box::new(|| {}),
+            pressed:       None,
+            key_pressed:   false,
         })
         .with_child(child)
         .finish()
@@ -63,6 +76,17 @@ impl Button {
         this.cx.request_draw();
     }
 
+    /// Set how far above its parent the button is lifted, casting a shadow when positive.
+    pub fn set_elevation(this: &mut WidgetMut<Self>, elevation: f32) {
+        this.widget.elevation = elevation;
+        this.cx.request_layout();
+    }
+
+    pub fn set_shadow_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.shadow_color = color;
+        this.cx.request_draw();
+    }
+
     pub fn set_idle_color(this: &mut WidgetMut<Self>, color: Color) {
         this.widget.idle_color = color;
         Self::update_color(this);
@@ -83,13 +107,11 @@ impl Button {
     }
 
     fn update_color(this: &mut WidgetMut<Self>) {
-        let color = if this.cx.is_active() {
-            this.widget.active_color
-        } else if this.cx.is_hovered() {
-            this.widget.hovered_color
-        } else {
-            this.widget.idle_color
-        };
+        let color = this.widget.target_color(
+            this.cx.is_disabled(),
+            this.cx.is_active(),
+            this.cx.is_hovered(),
+        );
 
         this.cx.request_draw();
 
@@ -101,17 +123,46 @@ impl Button {
     pub fn set_on_click(this: &mut WidgetMut<Self>, on_click: impl FnMut() + 'static) {
         this.widget.on_click = Box::new(on_click);
     }
+
+    fn target_color(&self, is_disabled: bool, is_active: bool, is_hovered: bool) -> Color {
+        if is_disabled {
+            self.idle_color
+        } else if is_active || self.key_pressed {
+            self.active_color
+        } else if is_hovered {
+            self.hovered_color
+        } else {
+            self.idle_color
+        }
+    }
+
+    fn set_key_pressed(&mut self, cx: &mut EventCx<'_>, key_pressed: bool) {
+        self.key_pressed = key_pressed;
+        cx.request_draw();
+
+        let color = self.target_color(
+            cx.is_disabled(),
+            cx.is_active(),
+            cx.is_hovered(),
+        );
+
+        if self.color.begin(color) {
+            cx.request_animate();
+        }
+    }
 }
 
 impl Widget for Button {
     fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.set_overflow(self.elevation.max(0.0) * 2.0);
+
         let size = {
             let space = self.padding.layout_down(cx, space);
             let space = self.border_width.layout_down(cx, space);
             cx.layout_nth_child(0, space)
         };
 
-        let offset = self.padding.aligned_offset(cx) + self.border_width.aligned_offset(cx);
+        let offset = self.padding.leading_offset(cx) + self.border_width.aligned_offset(cx);
         cx.place_nth_child(0, offset);
 
         let size = self.padding.layout_up(cx, size);
@@ -119,6 +170,16 @@ impl Widget for Button {
         space.constrain(size)
     }
 
+    fn draw_behind(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        if self.elevation > 0.0 {
+            canvas.draw_shadow(
+                &Shape::Rect(cx.rect(), self.corner_radius),
+                self.elevation,
+                self.shadow_color,
+            );
+        }
+    }
+
     fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
         canvas.draw_rect(
             cx.rect(),
@@ -147,16 +208,28 @@ impl Widget for Button {
 
     fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
         match update {
-            Update::Hovered(..) | Update::Active(..) | Update::Focused(..) => {
+            Update::Hovered(..)
+            | Update::Active(..)
+            | Update::Focused(..)
+            | Update::Disabled(..) => {
+                // losing focus while held down with the keyboard releases without clicking
+                if !cx.is_focused() {
+                    self.key_pressed = false;
+                }
+
+                // the release of a pointer that pressed the button before it was disabled is
+                // never received
+                if cx.is_disabled() {
+                    self.pressed = None;
+                }
+
                 cx.request_draw();
 
-                let color = if cx.is_active() {
-                    self.active_color
-                } else if cx.is_hovered() {
-                    self.hovered_color
-                } else {
-                    self.idle_color
-                };
+                let color = self.target_color(
+                    cx.is_disabled(),
+                    cx.is_active(),
+                    cx.is_hovered(),
+                );
 
                 if self.color.begin(color) {
                     cx.request_animate();
@@ -178,11 +251,19 @@ impl Widget for Button {
     fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
         match event {
             PointerEvent::Down(event) if event.button == PointerButton::Primary => {
+                self.pressed = Some(event.pointer);
                 PointerPropagate::Capture
             }
 
-            PointerEvent::Up(..) if cx.is_hovered() && cx.is_active() => {
-                (self.on_click)();
+            PointerEvent::Up(event) if self.pressed == Some(event.pointer) => {
+                self.pressed = None;
+
+                let local = cx.global_transform().inverse() * event.position;
+
+                if cx.rect().contains(local) {
+                    (self.on_click)();
+                }
+
                 PointerPropagate::Bubble
             }
 
@@ -201,13 +282,23 @@ impl Widget for Button {
         }
     }
 
-    fn on_key_event(&mut self, _cx: &mut EventCx<'_>, event: &KeyEvent) -> Propagate {
+    fn on_key_event(&mut self, cx: &mut EventCx<'_>, event: &KeyEvent) -> Propagate {
         match event {
-            KeyEvent::Down(event)
-                if matches!(event.key, Key::Character(ref c) if c == " ")
-                    || event.key == Key::Named(NamedKey::Enter) =>
-            {
-                (self.on_click)();
+            KeyEvent::Down(event) if event.is_activation() => {
+                if !event.repeat && !self.key_pressed {
+                    self.set_key_pressed(cx, true);
+                }
+
+                Propagate::Handled
+            }
+
+            KeyEvent::Up(event) if event.is_activation() && self.key_pressed => {
+                self.set_key_pressed(cx, false);
+
+                // keys are released without being reported when the window loses focus
+                if cx.is_focused() && cx.is_window_focused() {
+                    (self.on_click)();
+                }
 
                 Propagate::Handled
             }
@@ -0,0 +1,27 @@
+use crate::{AnyWidgetId, Builder, LayoutCx, Size, Space, Widget, WidgetMut};
+
+/// Rasterizes its contents once, and draws the image until one of them requests a draw, see
+/// [`MutCx::set_cached`](crate::MutCx::set_cached).
+///
+/// This is meant for large contents that rarely change, e.g. long prose in a scroll view.
+pub struct Cache;
+
+impl Cache {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        let mut this = cx.build_widget(Self).with_child(child).finish();
+        this.cx.set_cached(true);
+        this
+    }
+
+    /// Whether the contents are cached, they're drawn every frame when not.
+    pub fn set_enabled(this: &mut WidgetMut<Self>, enabled: bool) {
+        this.cx.set_cached(enabled);
+    }
+}
+
+impl Widget for Cache {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+}
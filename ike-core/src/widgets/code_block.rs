@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use crate::{
+    AnyWidgetId, Builder, EventCx, LayoutCx, Offset, PointerEvent, PointerPropagate, Size, Space,
+    UpdateCx, Widget, WidgetMut,
+};
+
+/// A block of code, with a button in the top right corner shown while it's hovered.
+///
+/// The first child is the contents, the second is the button.
+pub struct CodeBlock {
+    margin:     f32,
+    is_hovered: bool,
+}
+
+impl CodeBlock {
+    #[track_caller]
+    pub fn new(
+        cx: &mut impl Builder,
+        contents: impl AnyWidgetId,
+        button: impl AnyWidgetId,
+    ) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            margin:     8.0,
+            is_hovered: false,
+        })
+        .with_child(contents)
+        .with_child(button)
+        .finish()
+    }
+
+    /// Set the space between the button and the corner.
+    pub fn set_margin(this: &mut WidgetMut<Self>, margin: f32) {
+        this.widget.margin = margin;
+        this.cx.request_layout();
+    }
+}
+
+impl Widget for CodeBlock {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let size = cx.layout_nth_child(0, space);
+
+        let button_space = Space::new(Size::ZERO, size);
+        let button_size = cx.layout_nth_child(1, button_space);
+
+        let x = size.width - button_size.width - self.margin;
+        cx.place_nth_child(1, Offset::new(x.max(0.0), self.margin));
+        cx.set_child_stashed(1, !self.is_hovered);
+
+        size
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, _dt: Duration) {
+        // only the innermost widget is told when the pointer leaves, so check until it does
+        match cx.has_hovered() {
+            true => cx.request_animate(),
+            false => {
+                self.is_hovered = false;
+                cx.request_layout();
+            }
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        if let PointerEvent::Move(..) = event
+            && !self.is_hovered
+        {
+            self.is_hovered = true;
+
+            cx.request_layout();
+            cx.request_animate();
+        }
+
+        PointerPropagate::Bubble
+    }
+}
@@ -11,6 +11,7 @@ pub struct Constrain {
 }
 
 impl Constrain {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
         cx.build_widget(Self {
             min_size: Transitioned::new(Size::all(0.0), Transition::INSTANT),
@@ -1,29 +1,45 @@
+use std::time::Duration;
+
 use crate::{
     AnyWidgetId, BorderWidth, Builder, Canvas, Color, CornerRadius, DrawCx, LayoutCx, Padding,
-    Paint, Size, Space, Widget, WidgetMut,
+    Paint, Point, Rect, Shape, Size, Space, Transition, Transitioned, UpdateCx, Widget, WidgetMut,
 };
 
 pub struct Container {
     padding:          Padding,
     border_width:     BorderWidth,
     corner_radius:    CornerRadius,
-    background_color: Color,
-    border_color:     Color,
+    background_color: Transitioned<Color>,
+    border_color:     Transitioned<Color>,
+    elevation:        f32,
+    shadow_color:     Color,
+    clip_contents:    bool,
 }
 
 impl Container {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
         cx.build_widget(Container {
             padding:          Padding::all(8.0),
             border_width:     BorderWidth::all(1.0),
             corner_radius:    CornerRadius::all(8.0),
-            background_color: Color::rgb(0.9, 0.9, 0.9),
-            border_color:     Color::BLACK,
+            background_color: Transitioned::new(
+                Color::rgb(0.9, 0.9, 0.9),
+                Transition::INSTANT,
+            ),
+            border_color:     Transitioned::new(Color::BLACK, Transition::INSTANT),
+            elevation:        0.0,
+            shadow_color:     Color::BLACK.fade(0.4),
+            clip_contents:    false,
         })
         .with_child(child)
         .finish()
     }
 
+    pub(crate) fn border_width(&self) -> BorderWidth {
+        self.border_width
+    }
+
     pub fn set_padding(this: &mut WidgetMut<Self>, padding: Padding) {
         this.widget.padding = padding;
         this.cx.request_layout();
@@ -37,44 +53,111 @@ impl Container {
     pub fn set_corner_radius(this: &mut WidgetMut<Self>, corner_radius: CornerRadius) {
         this.widget.corner_radius = corner_radius;
         this.cx.request_draw();
+
+        if this.widget.clip_contents {
+            this.cx.request_layout();
+        }
     }
 
     pub fn set_background_color(this: &mut WidgetMut<Self>, color: Color) {
-        this.widget.background_color = color;
+        if this.widget.background_color.begin(color) {
+            this.cx.request_animate();
+        }
+
         this.cx.request_draw();
     }
 
     pub fn set_border_color(this: &mut WidgetMut<Self>, color: Color) {
-        this.widget.border_color = color;
+        if this.widget.border_color.begin(color) {
+            this.cx.request_animate();
+        }
+
         this.cx.request_draw();
     }
+
+    /// Set how far above its parent the container is lifted, casting a shadow when positive.
+    pub fn set_elevation(this: &mut WidgetMut<Self>, elevation: f32) {
+        this.widget.elevation = elevation;
+        this.cx.request_layout();
+    }
+
+    pub fn set_shadow_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.shadow_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Set whether the contents are clipped to the rounded corners of the container.
+    pub fn set_clip_contents(this: &mut WidgetMut<Self>, clip_contents: bool) {
+        this.widget.clip_contents = clip_contents;
+        this.cx.request_layout();
+    }
+
+    /// Set the transition used when the background or border color changes.
+    pub fn set_color_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.background_color.set_transition(transition);
+        this.widget.border_color.set_transition(transition);
+    }
 }
 
 impl Widget for Container {
     fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.set_overflow(self.elevation.max(0.0) * 2.0);
+
         let space = self.padding.layout_down(cx, space);
         let space = self.border_width.layout_down(cx, space);
         let size = cx.layout_nth_child(0, space);
 
-        let offset = self.padding.aligned_offset(cx) + self.border_width.aligned_offset(cx);
+        let offset = self.padding.leading_offset(cx) + self.border_width.aligned_offset(cx);
         cx.place_nth_child(0, offset);
 
         let size = self.padding.layout_up(cx, size);
-        self.border_width.layout_up(cx, size)
+        let size = self.border_width.layout_up(cx, size);
+
+        if self.clip_contents {
+            cx.set_clip_rrect(
+                Rect::min_size(Point::ORIGIN, size),
+                self.corner_radius,
+            );
+        } else {
+            cx.set_clip(None);
+        }
+
+        size
+    }
+
+    fn draw_behind(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        if self.elevation > 0.0 {
+            canvas.draw_shadow(
+                &Shape::Rect(cx.rect(), self.corner_radius),
+                self.elevation,
+                self.shadow_color,
+            );
+        }
     }
 
     fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
         canvas.draw_rect(
             cx.rect(),
             self.corner_radius,
-            &Paint::from(self.background_color),
+            &Paint::from(*self.background_color),
         );
 
         canvas.draw_border(
             cx.rect(),
             self.border_width,
             self.corner_radius,
-            &Paint::from(self.border_color),
+            &Paint::from(*self.border_color),
         );
     }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_draw();
+
+        let background = self.background_color.animate(dt);
+        let border = self.border_color.animate(dt);
+
+        if background || border {
+            cx.request_animate();
+        }
+    }
 }
@@ -0,0 +1,452 @@
+use std::time::Duration;
+
+use crate::{
+    Builder, Canvas, ChildUpdate, Color, CornerRadius, CursorIcon, DrawCx, EventCx, LayoutCx,
+    Offset, Paint, Point, PointerButton, PointerEvent, PointerId, PointerPropagate, Rect, Size,
+    Space, Transition, Transitioned, Update, UpdateCx, Widget, WidgetMut,
+};
+
+/// The cells of a [`Dashboard`] covered by a tile.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub column:  usize,
+    pub row:     usize,
+    pub columns: usize,
+    pub rows:    usize,
+}
+
+impl Tile {
+    pub const fn new(column: usize, row: usize, columns: usize, rows: usize) -> Self {
+        Self {
+            column,
+            row,
+            columns,
+            rows,
+        }
+    }
+
+    pub const fn overlaps(&self, other: &Self) -> bool {
+        self.column < other.column + other.columns
+            && other.column < self.column + self.columns
+            && self.row < other.row + other.rows
+            && other.row < self.row + self.rows
+    }
+}
+
+/// Move tiles as far up as they can go without overlapping, keeping the `pinned` tile in place.
+fn resolve(tiles: &mut [Tile], pinned: Option<usize>) {
+    let mut order: Vec<_> = (0..tiles.len()).filter(|&i| Some(i) != pinned).collect();
+    order.sort_by_key(|&i| (tiles[i].row, tiles[i].column));
+
+    let mut placed: Vec<_> = pinned.into_iter().collect();
+
+    for i in order {
+        let mut tile = tiles[i];
+        tile.row = 0;
+
+        while let Some(&other) = placed.iter().find(|&&j| tiles[j].overlaps(&tile)) {
+            tile.row = tiles[other].row + tiles[other].rows;
+        }
+
+        tiles[i] = tile;
+        placed.push(i);
+    }
+}
+
+enum DragKind {
+    Move(Offset),
+    Resize,
+}
+
+struct Drag {
+    index:    usize,
+    kind:     DragKind,
+    pointer:  PointerId,
+    position: Point,
+}
+
+/// A grid of tiles that can be rearranged and resized by the user.
+///
+/// Tiles are dragged by any part of them that doesn't handle pointer events itself, and resized
+/// by dragging their bottom right corner. Other tiles make room for the dragged one, and every
+/// tile floats up to fill empty rows. The arrangement is reported with
+/// [`Dashboard::set_on_change`] once the user lets go, so it can be persisted.
+pub struct Dashboard {
+    tiles:     Vec<Tile>,
+    positions: Vec<Option<Transitioned<Offset>>>,
+
+    columns:           usize,
+    row_height:        f32,
+    gap:               f32,
+    resizable:         bool,
+    transition:        Transition,
+    placeholder_color: Color,
+
+    #[allow(clippy::type_complexity)]
+    on_change: Box<dyn FnMut(&[Tile])>,
+
+    pitch: Size,
+    drag:  Option<Drag>,
+}
+
+impl Dashboard {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            tiles:     Vec::new(),
+            positions: Vec::new(),
+
+            columns:           12,
+            row_height:        80.0,
+            gap:               8.0,
+            resizable:         true,
+            transition:        Transition::ease(0.2),
+            placeholder_color: Color::BLUE.fade(0.2),
+
+            on_change: Box::new(|_| {}),
+
+            pitch: Size::ZERO,
+            drag:  None,
+        })
+        .finish()
+    }
+
+    /// Set the tile of the child at `index`.
+    pub fn set_tile(this: &mut WidgetMut<Self>, index: usize, tile: Tile) {
+        this.widget.tiles[index] = tile;
+
+        if this.widget.reflow(None) {
+            this.cx.request_animate();
+        }
+
+        this.cx.request_layout();
+    }
+
+    pub fn get_tile(&self, index: usize) -> Tile {
+        self.tiles[index]
+    }
+
+    pub fn set_columns(this: &mut WidgetMut<Self>, columns: usize) {
+        this.widget.columns = columns.max(1);
+        this.cx.request_layout();
+    }
+
+    pub fn set_row_height(this: &mut WidgetMut<Self>, row_height: f32) {
+        this.widget.row_height = row_height;
+        this.cx.request_layout();
+    }
+
+    pub fn set_gap(this: &mut WidgetMut<Self>, gap: f32) {
+        this.widget.gap = gap;
+        this.cx.request_layout();
+    }
+
+    pub fn set_resizable(this: &mut WidgetMut<Self>, resizable: bool) {
+        this.widget.resizable = resizable;
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.transition = transition;
+
+        for position in this.widget.positions.iter_mut().flatten() {
+            position.set_transition(transition);
+        }
+    }
+
+    pub fn set_placeholder_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.placeholder_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Set the callback called with every tile when the user has rearranged them.
+    pub fn set_on_change(this: &mut WidgetMut<Self>, on_change: impl FnMut(&[Tile]) + 'static) {
+        this.widget.on_change = Box::new(on_change);
+    }
+
+    /// Resolve collisions and start moving tiles to their new positions.
+    ///
+    /// Returns whether `request_animate` should be called.
+    fn reflow(&mut self, pinned: Option<usize>) -> bool {
+        resolve(&mut self.tiles, pinned);
+
+        let mut animate = false;
+
+        for (tile, position) in self.tiles.iter().zip(&mut self.positions) {
+            let target = Offset::new(tile.column as f32, tile.row as f32);
+
+            // tiles that haven't been placed yet appear in place
+            match position {
+                Some(position) => animate |= position.begin(target),
+                None => {
+                    *position = Some(Transitioned::new(
+                        target,
+                        self.transition,
+                    ))
+                }
+            }
+        }
+
+        animate
+    }
+
+    fn tile_rect(&self, tile: Tile) -> Rect {
+        let min = Point::new(
+            tile.column as f32 * self.pitch.width,
+            tile.row as f32 * self.pitch.height,
+        );
+
+        let size = Size::new(
+            tile.columns as f32 * self.pitch.width - self.gap,
+            tile.rows as f32 * self.pitch.height - self.gap,
+        );
+
+        Rect::min_size(min, size.max(Size::ZERO))
+    }
+
+    fn tile_at(&self, point: Point) -> Option<usize> {
+        (0..self.tiles.len())
+            .rev()
+            .find(|&i| self.tile_rect(self.tiles[i]).contains(point))
+    }
+
+    fn is_corner(&self, index: usize, point: Point) -> bool {
+        let corner = self.tile_rect(self.tiles[index]).bottom_right();
+        self.resizable && corner.x - point.x <= 16.0 && corner.y - point.y <= 16.0
+    }
+}
+
+impl Widget for Dashboard {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let width = match space.max.width.is_finite() {
+            true => space.max.width,
+            false => space.min.width,
+        };
+
+        let columns = self.columns as f32;
+        let cell_width = f32::max(
+            (width - self.gap * (columns - 1.0)) / columns,
+            0.0,
+        );
+        self.pitch = Size::new(
+            cell_width + self.gap,
+            self.row_height + self.gap,
+        );
+
+        let mut height: f32 = 0.0;
+
+        for i in 0..self.tiles.len() {
+            let rect = self.tile_rect(self.tiles[i]);
+            cx.layout_nth_child(i, Space::new(rect.size(), rect.size()));
+
+            // the dragged tile follows the pointer, the rest move to their place on the grid
+            let position = match self.drag {
+                Some(Drag {
+                    index,
+                    kind: DragKind::Move(grab),
+                    position,
+                    ..
+                }) if index == i => position - grab,
+
+                _ => match self.positions[i] {
+                    Some(ref position) => Point::new(
+                        position.x * self.pitch.width,
+                        position.y * self.pitch.height,
+                    ),
+
+                    None => rect.min,
+                },
+            };
+
+            cx.place_nth_child(i, position - Point::ORIGIN);
+            height = height.max(rect.max.y);
+        }
+
+        space.constrain(Size::new(width, height))
+    }
+
+    fn draw(&mut self, _cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        if let Some(ref drag) = self.drag {
+            canvas.draw_rect(
+                self.tile_rect(self.tiles[drag.index]),
+                CornerRadius::all(4.0),
+                &Paint::from(self.placeholder_color),
+            );
+        }
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::Children(update) = update {
+            match update {
+                ChildUpdate::Inserted(index) => {
+                    self.tiles.insert(index, Tile::new(0, 0, 1, 1));
+                    self.positions.insert(index, None);
+                }
+
+                ChildUpdate::Removed(index) => {
+                    self.tiles.remove(index);
+                    self.positions.remove(index);
+                    self.drag = None;
+                }
+
+                ChildUpdate::Replaced(..) => {}
+
+                ChildUpdate::Swapped(a, b) => {
+                    self.tiles.swap(a, b);
+                    self.positions.swap(a, b);
+                }
+            }
+        }
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        let mut animating = false;
+
+        for position in self.positions.iter_mut().flatten() {
+            animating |= position.animate(dt);
+        }
+
+        if animating {
+            cx.request_animate();
+        }
+
+        cx.request_layout();
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let transform = cx.global_transform().inverse();
+
+        match event {
+            PointerEvent::Down(event)
+                if event.button == PointerButton::Primary && self.drag.is_none() =>
+            {
+                let local = transform * event.position;
+
+                let Some(index) = self.tile_at(local) else {
+                    return PointerPropagate::Bubble;
+                };
+
+                let kind = match self.is_corner(index, local) {
+                    true => DragKind::Resize,
+                    false => DragKind::Move(local - self.tile_rect(self.tiles[index]).min),
+                };
+
+                cx.set_cursor(match kind {
+                    DragKind::Resize => CursorIcon::SeResize,
+                    DragKind::Move(..) => CursorIcon::Grabbing,
+                });
+
+                self.drag = Some(Drag {
+                    index,
+                    kind,
+                    pointer: event.pointer,
+                    position: local,
+                });
+
+                cx.request_layout();
+                cx.request_draw();
+
+                PointerPropagate::Capture
+            }
+
+            PointerEvent::Move(event) => {
+                let local = transform * event.position;
+
+                let Some(ref mut drag) = self.drag else {
+                    let corner = self
+                        .tile_at(local)
+                        .is_some_and(|i| self.is_corner(i, local));
+
+                    cx.set_cursor(match corner {
+                        true => CursorIcon::SeResize,
+                        false => CursorIcon::Default,
+                    });
+
+                    return PointerPropagate::Bubble;
+                };
+
+                // only the pointer that started the drag moves the tile
+                if drag.pointer != event.pointer {
+                    return PointerPropagate::Bubble;
+                }
+
+                drag.position = local;
+
+                let index = drag.index;
+                let mut tile = self.tiles[index];
+
+                match drag.kind {
+                    DragKind::Move(grab) => {
+                        let min = local - grab;
+                        let column = (min.x / self.pitch.width).round().max(0.0) as usize;
+                        let row = (min.y / self.pitch.height).round().max(0.0) as usize;
+
+                        tile.column = column.min(self.columns.saturating_sub(tile.columns));
+                        tile.row = row;
+                    }
+
+                    DragKind::Resize => {
+                        let min = self.tile_rect(tile).min;
+                        let columns = ((local.x - min.x + self.gap) / self.pitch.width).round();
+                        let rows = ((local.y - min.y + self.gap) / self.pitch.height).round();
+
+                        tile.columns = (columns.max(1.0) as usize)
+                            .min(self.columns.saturating_sub(tile.column))
+                            .max(1);
+                        tile.rows = rows.max(1.0) as usize;
+                    }
+                }
+
+                if tile != self.tiles[index] {
+                    self.tiles[index] = tile;
+
+                    if self.reflow(Some(index)) {
+                        cx.request_animate();
+                    }
+
+                    cx.request_draw();
+                }
+
+                cx.request_layout();
+
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Up(event) if event.button == PointerButton::Primary => {
+                let Some(drag) = self.drag.take_if(|drag| drag.pointer == event.pointer) else {
+                    return PointerPropagate::Bubble;
+                };
+
+                // let the dragged tile glide from where it was dropped into its place
+                if let DragKind::Move(grab) = drag.kind {
+                    let min = drag.position - grab;
+                    let position = Offset::new(
+                        min.x / self.pitch.width,
+                        min.y / self.pitch.height,
+                    );
+
+                    if let Some(ref mut transitioned) = self.positions[drag.index] {
+                        transitioned.set(position);
+                    }
+                }
+
+                if self.reflow(None) {
+                    cx.request_animate();
+                }
+
+                cx.set_cursor(CursorIcon::Default);
+                cx.request_layout();
+                cx.request_draw();
+
+                (self.on_change)(&self.tiles);
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{AnyWidgetId, Builder, LayoutCx, LayoutDirection, Size, Space, Widget, WidgetMut};
+
+/// Lays out its contents in a [`LayoutDirection`], regardless of the direction of its parent.
+pub struct Direction;
+
+impl Direction {
+    #[track_caller]
+    pub fn new(
+        cx: &mut impl Builder,
+        direction: LayoutDirection,
+        contents: impl AnyWidgetId,
+    ) -> WidgetMut<'_, Self> {
+        let mut widget = cx.build_widget(Direction).with_child(contents).finish();
+        widget.set_layout_direction(Some(direction));
+        widget
+    }
+
+    pub fn set_direction(this: &mut WidgetMut<Self>, direction: LayoutDirection) {
+        this.set_layout_direction(Some(direction));
+    }
+}
+
+impl Widget for Direction {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+}
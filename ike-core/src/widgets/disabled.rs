@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use crate::{
+    AnyWidgetId, Builder, ComposeCx, LayoutCx, Size, Space, Transition, Transitioned, UpdateCx,
+    Widget, WidgetMut,
+};
+
+/// Disables its descendants, dimming them while they are disabled.
+///
+/// Disabled widgets don't receive pointer, touch, key or text events, and can't be focused, see
+/// [`WidgetMut::set_disabled`].
+pub struct Disabled {
+    is_disabled: bool,
+    opacity:     Transitioned<f32>,
+
+    /// The opacity of the descendants while they are disabled.
+    disabled_opacity: f32,
+}
+
+impl Disabled {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            is_disabled:      false,
+            opacity:          Transitioned::new(1.0, Transition::INSTANT),
+            disabled_opacity: 0.5,
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    pub fn set_disabled(this: &mut WidgetMut<'_, Self>, is_disabled: bool) {
+        this.widget.is_disabled = is_disabled;
+        this.set_disabled(is_disabled);
+        Self::update_opacity(this);
+    }
+
+    pub fn set_disabled_opacity(this: &mut WidgetMut<'_, Self>, opacity: f32) {
+        this.widget.disabled_opacity = opacity;
+        Self::update_opacity(this);
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<'_, Self>, transition: Transition) {
+        this.widget.opacity.set_transition(transition);
+    }
+
+    fn update_opacity(this: &mut WidgetMut<'_, Self>) {
+        let opacity = match this.widget.is_disabled {
+            true => this.widget.disabled_opacity,
+            false => 1.0,
+        };
+
+        this.cx.request_compose();
+
+        if this.widget.opacity.begin(opacity) {
+            this.cx.request_animate();
+        }
+    }
+}
+
+impl Widget for Disabled {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+
+    fn compose(&mut self, cx: &mut ComposeCx<'_>) {
+        cx.set_opacity(*self.opacity);
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_compose();
+
+        if self.opacity.animate(dt) {
+            cx.request_animate();
+        }
+    }
+}
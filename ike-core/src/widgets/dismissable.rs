@@ -0,0 +1,39 @@
+use crate::{AnyWidgetId, Builder, LayoutCx, Size, Space, Update, UpdateCx, Widget, WidgetMut};
+
+/// A dismiss scope, for dialogs, popovers and menus.
+///
+/// Escape, back, or pressing outside of the child dismisses the innermost scope in the window.
+pub struct Dismissable {
+    on_dismiss: Box<dyn FnMut()>,
+}
+
+impl Dismissable {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            on_dismiss: Box::new(|| {}),
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    pub fn set_on_dismiss(this: &mut WidgetMut<Self>, on_dismiss: impl FnMut() + 'static) {
+        this.widget.on_dismiss = Box::new(on_dismiss);
+    }
+}
+
+impl Widget for Dismissable {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::Dismiss = update {
+            (self.on_dismiss)();
+        }
+    }
+
+    fn accepts_dismiss() -> bool {
+        true
+    }
+}
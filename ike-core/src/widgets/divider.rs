@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use crate::{
-    Axis, Builder, Canvas, Color, CornerRadius, DrawCx, LayoutCx, Paint, Rect, Size, Space, Widget,
-    WidgetMut,
+    Axis, Builder, Canvas, Color, CornerRadius, DrawCx, LayoutCx, Paint, Rect, Size, Space,
+    Transition, Transitioned, UpdateCx, Widget, WidgetMut,
 };
 
 pub struct Divider {
@@ -9,10 +11,11 @@ pub struct Divider {
     inset:         f32,
     padding:       f32,
     corner_radius: CornerRadius,
-    color:         Color,
+    color:         Transitioned<Color>,
 }
 
 impl Divider {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
         cx.build_widget(Self {
             axis:          Axis::Horizontal,
@@ -20,7 +23,7 @@ impl Divider {
             inset:         8.0,
             padding:       8.0,
             corner_radius: CornerRadius::all(0.0),
-            color:         Color::BLACK,
+            color:         Transitioned::new(Color::BLACK, Transition::INSTANT),
         })
         .finish()
     }
@@ -51,9 +54,17 @@ impl Divider {
     }
 
     pub fn set_color(this: &mut WidgetMut<Self>, color: Color) {
-        this.widget.color = color;
+        if this.widget.color.begin(color) {
+            this.cx.request_animate();
+        }
+
         this.cx.request_draw();
     }
+
+    /// Set the transition used when the color changes.
+    pub fn set_color_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.color.set_transition(transition);
+    }
 }
 
 impl Widget for Divider {
@@ -78,7 +89,15 @@ impl Widget for Divider {
         canvas.draw_rect(
             rect,
             self.corner_radius,
-            &Paint::from(self.color),
+            &Paint::from(*self.color),
         );
     }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_draw();
+
+        if self.color.animate(dt) {
+            cx.request_animate();
+        }
+    }
 }
@@ -0,0 +1,170 @@
+use std::time::Duration;
+
+use crate::{
+    Affine, Builder, Canvas, DrawCx, EventCx, LayoutCx, PointerEvent, PointerPropagate, Size,
+    Space, TouchEvent, TouchPropagate, UpdateCx, Widget, WidgetMut,
+};
+
+/// An area drawn by a callback, for custom drawing without implementing a [`Widget`].
+///
+/// The callback is given the size of the area, and the time it has been animated for. Pointer
+/// and touch events are passed on in the local coordinates of the area.
+pub struct DrawArea {
+    size:        Size,
+    time:        Duration,
+    is_animated: bool,
+
+    #[allow(clippy::type_complexity)]
+    draw:       Box<dyn FnMut(&mut dyn Canvas, Size, Duration)>,
+    on_pointer: Box<dyn FnMut(&PointerEvent) -> bool>,
+    on_touch:   Box<dyn FnMut(&TouchEvent) -> bool>,
+}
+
+impl DrawArea {
+    #[track_caller]
+    pub fn new(
+        cx: &mut impl Builder,
+        draw: impl FnMut(&mut dyn Canvas, Size, Duration) + 'static,
+    ) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            size:        Size::all(100.0),
+            time:        Duration::ZERO,
+            is_animated: false,
+
+            draw:       Box::new(draw),
+            on_pointer: Box::new(|_| false),
+            on_touch:   Box::new(|_| false),
+        })
+        .finish()
+    }
+
+    /// Set the callback drawing the area, it's called again every time the area is drawn.
+    pub fn set_draw(
+        this: &mut WidgetMut<Self>,
+        draw: impl FnMut(&mut dyn Canvas, Size, Duration) + 'static,
+    ) {
+        this.widget.draw = Box::new(draw);
+        this.cx.request_draw();
+    }
+
+    /// Set the size the area would like to be, default is `100x100`.
+    pub fn set_size(this: &mut WidgetMut<Self>, size: Size) {
+        this.widget.size = size;
+        this.cx.request_layout();
+    }
+
+    /// Set whether the area is redrawn every frame, advancing the time passed to the callback.
+    pub fn set_animated(this: &mut WidgetMut<Self>, is_animated: bool) {
+        this.widget.is_animated = is_animated;
+
+        if is_animated {
+            this.cx.request_animate();
+        }
+    }
+
+    /// Set the callback called with pointer events, returning `true` handles the event.
+    ///
+    /// Handling [`PointerEvent::Down`] captures the pointer until it's released.
+    pub fn set_on_pointer(
+        this: &mut WidgetMut<Self>,
+        on_pointer: impl FnMut(&PointerEvent) -> bool + 'static,
+    ) {
+        this.widget.on_pointer = Box::new(on_pointer);
+    }
+
+    /// Set the callback called with touch events, returning `true` handles the event.
+    ///
+    /// Handling [`TouchEvent::Down`] captures the touch until it's released, gestures are
+    /// passed on unchanged.
+    pub fn set_on_touch(
+        this: &mut WidgetMut<Self>,
+        on_touch: impl FnMut(&TouchEvent) -> bool + 'static,
+    ) {
+        this.widget.on_touch = Box::new(on_touch);
+    }
+}
+
+impl Widget for DrawArea {
+    fn layout(&mut self, _cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        space.constrain(self.size)
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        (self.draw)(canvas, cx.size(), self.time);
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        if self.is_animated {
+            self.time += dt;
+
+            cx.request_draw();
+            cx.request_animate();
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let event = localize_pointer(event, cx.global_transform().inverse());
+        let is_handled = (self.on_pointer)(&event);
+
+        match event {
+            PointerEvent::Down(..) if is_handled => PointerPropagate::Capture,
+            _ if is_handled => PointerPropagate::Handled,
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn on_touch_event(&mut self, cx: &mut EventCx<'_>, event: &TouchEvent) -> TouchPropagate {
+        let event = localize_touch(event, cx.global_transform().inverse());
+        let is_handled = (self.on_touch)(&event);
+
+        match event {
+            TouchEvent::Down(..) if is_handled => TouchPropagate::Capture,
+            _ if is_handled => TouchPropagate::Handled,
+            _ => TouchPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
+
+fn localize_pointer(event: &PointerEvent, transform: Affine) -> PointerEvent {
+    let mut event = event.clone();
+
+    match event {
+        PointerEvent::Down(ref mut event) | PointerEvent::Up(ref mut event) => {
+            event.position = transform * event.position;
+        }
+
+        PointerEvent::Move(ref mut event) => {
+            event.position = transform * event.position;
+        }
+
+        PointerEvent::Scroll(ref mut event) => {
+            event.position = transform * event.position;
+        }
+
+        PointerEvent::Enter(..) | PointerEvent::Leave(..) => {}
+    }
+
+    event
+}
+
+fn localize_touch(event: &TouchEvent, transform: Affine) -> TouchEvent {
+    let mut event = event.clone();
+
+    match event {
+        TouchEvent::Down(ref mut event) | TouchEvent::Up(ref mut event) => {
+            event.position = transform * event.position;
+        }
+
+        TouchEvent::Move(ref mut event) => {
+            event.position = transform * event.position;
+        }
+
+        TouchEvent::Gesture(..) => {}
+    }
+
+    event
+}
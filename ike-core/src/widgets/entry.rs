@@ -1,9 +1,26 @@
+use std::{mem, time::Duration};
+
 use crate::{
-    BorderWidth, Builder, Canvas, Color, CornerRadius, DrawCx, GetError, LayoutCx, Padding, Paint,
-    Paragraph, Size, Space, TextAlign, TextWrap, Widget, WidgetId, WidgetMut, WidgetRef,
-    widgets::{Label, NewlineBehaviour, SubmitBehaviour, TextArea},
+    BorderWidth, Builder, Canvas, Color, CornerRadius, Curve, DrawCx, EventCx, Gesture, GetError,
+    Gradient, GradientStop, LayoutCx, Offset, Padding, Paint, Paragraph, Point, PointerButton,
+    PointerEvent, PointerId, PointerPropagate, Rect, Size, Space, TextAlign, TextStyle, TextWrap,
+    TouchEvent, TouchPropagate, Transition, Transitioned, UpdateCx, Widget, WidgetId, WidgetMut,
+    WidgetRef,
+    widgets::{Gutter, Label, NewlineBehaviour, SubmitBehaviour, TextArea},
 };
 
+/// The size of the clear button and the reveal toggle.
+const ICON_SIZE: f32 = 18.0;
+
+/// The space between the text and each of the adornments placed around it.
+const ADORNMENT_GAP: f32 = 8.0;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Icon {
+    Clear,
+    Reveal,
+}
+
 pub struct Entry {
     text_area:   WidgetId<TextArea<true>>,
     placeholder: WidgetId<Label>,
@@ -13,14 +30,38 @@ pub struct Entry {
     padding:          Padding,
     border_width:     BorderWidth,
     corner_radius:    CornerRadius,
-    background_color: Color,
-    border_color:     Color,
+    background_color: Transitioned<Color>,
+    border_color:     Transitioned<Color>,
     focus_color:      Color,
+    error_color:      Color,
+    reveal_color:     Color,
+    clear_color:      Color,
+    is_secure:        bool,
+    reveal_toggle:    bool,
+    clear_button:     bool,
+    counter:          Option<TextStyle>,
+    leading:          Option<WidgetId>,
+    trailing:         Option<WidgetId>,
+
+    is_revealed:    bool,
+    has_text:       bool,
+    counter_text:   Option<Paragraph>,
+    counter_offset: Offset,
+    clear_rect:     Rect,
+    reveal_rect:    Rect,
+    pressed:        Option<(PointerId, Icon)>,
 }
 
 impl Entry {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, paragraph: Paragraph) -> WidgetMut<'_, Self> {
-        let text_area = TextArea::new(cx, paragraph).id();
+        // entries are single line, so enter submits rather than inserting a newline
+        let text_area = {
+            let mut text_area = TextArea::new(cx, paragraph);
+            TextArea::set_newline_behaviour(&mut text_area, NewlineBehaviour::Never);
+            text_area.id()
+        };
+
         let placeholder = Paragraph::new(16.0, TextAlign::Start, TextWrap::None);
         let placeholder = Label::new(cx, placeholder).id();
 
@@ -33,9 +74,26 @@ impl Entry {
             padding: Padding::all(8.0),
             border_width: BorderWidth::all(1.0),
             corner_radius: CornerRadius::all(8.0),
-            background_color: Color::WHITE,
-            border_color: Color::BLACK,
+            background_color: Transitioned::new(Color::WHITE, Transition::INSTANT),
+            border_color: Transitioned::new(Color::BLACK, Transition::INSTANT),
             focus_color: Color::BLUE,
+            error_color: Color::RED,
+            reveal_color: Color::BLACK,
+            clear_color: Color::BLACK,
+            is_secure: false,
+            reveal_toggle: false,
+            clear_button: false,
+            counter: None,
+            leading: None,
+            trailing: None,
+
+            is_revealed: false,
+            has_text: false,
+            counter_text: None,
+            counter_offset: Offset::ZERO,
+            clear_rect: Rect::min_size(Point::ORIGIN, Size::all(0.0)),
+            reveal_rect: Rect::min_size(Point::ORIGIN, Size::all(0.0)),
+            pressed: None,
         })
         .with_child(text_area)
         .with_child(placeholder)
@@ -74,20 +132,102 @@ impl Entry {
     }
 
     pub fn set_background_color(this: &mut WidgetMut<Self>, color: Color) {
-        this.widget.background_color = color;
+        if this.widget.background_color.begin(color) {
+            this.cx.request_animate();
+        }
+
         this.cx.request_draw();
     }
 
     pub fn set_border_color(this: &mut WidgetMut<Self>, color: Color) {
-        this.widget.border_color = color;
+        if this.widget.border_color.begin(color) {
+            this.cx.request_animate();
+        }
+
         this.cx.request_draw();
     }
 
+    /// Set the transition used when the background or border color changes.
+    pub fn set_color_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.background_color.set_transition(transition);
+        this.widget.border_color.set_transition(transition);
+    }
+
     pub fn set_focus_color(this: &mut WidgetMut<Self>, color: Color) {
         this.widget.focus_color = color;
         this.cx.request_draw();
     }
 
+    /// Set the color of the border drawn while the text is invalid.
+    pub fn set_error_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.error_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Hide the text, e.g. for passwords, see [`TextArea::set_secure`].
+    pub fn set_secure(this: &mut WidgetMut<Self>, secure: bool) {
+        this.widget.is_secure = secure;
+        this.widget.is_revealed = false;
+
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_secure(&mut text_area, secure);
+        }
+
+        this.cx.request_layout();
+    }
+
+    /// Show a button revealing secure text while it's toggled on.
+    pub fn set_reveal_toggle(this: &mut WidgetMut<Self>, reveal_toggle: bool) {
+        this.widget.reveal_toggle = reveal_toggle;
+        this.cx.request_layout();
+    }
+
+    pub fn set_reveal_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.reveal_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Show a button clearing the text, while there is any.
+    pub fn set_clear_button(this: &mut WidgetMut<Self>, clear_button: bool) {
+        this.widget.clear_button = clear_button;
+        this.cx.request_layout();
+    }
+
+    pub fn set_clear_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.clear_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Show the number of characters after the text, and the maximum if there is one, drawn
+    /// with `style`.
+    pub fn set_counter(this: &mut WidgetMut<Self>, counter: Option<TextStyle>) {
+        this.widget.counter = counter;
+        this.cx.request_layout();
+    }
+
+    /// Limit the number of characters, see [`TextArea::set_max_length`].
+    pub fn set_max_length(this: &mut WidgetMut<Self>, max_length: Option<usize>) {
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_max_length(&mut text_area, max_length);
+        }
+
+        this.cx.request_layout();
+    }
+
+    /// Place `leading` before the text, e.g. an icon, the previous widget there is removed.
+    pub fn set_leading(cx: &mut impl Builder, this: WidgetId<Self>, leading: Option<WidgetId>) {
+        Self::set_adornment(cx, this, leading, |entry| {
+            &mut entry.leading
+        });
+    }
+
+    /// Place `trailing` after the text, e.g. a unit, the previous widget there is removed.
+    pub fn set_trailing(cx: &mut impl Builder, this: WidgetId<Self>, trailing: Option<WidgetId>) {
+        Self::set_adornment(cx, this, trailing, |entry| {
+            &mut entry.trailing
+        });
+    }
+
     pub fn set_text(this: &mut WidgetMut<Self>, paragraph: Paragraph) {
         if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
             TextArea::set_text(&mut text_area, paragraph);
@@ -106,12 +246,24 @@ impl Entry {
         }
     }
 
+    pub fn set_composing_color(this: &mut WidgetMut<Self>, color: Color) {
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_composing_color(&mut text_area, color);
+        }
+    }
+
     pub fn set_handle_color(this: &mut WidgetMut<Self>, color: Color) {
         if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
             TextArea::set_handle_color(&mut text_area, color);
         }
     }
 
+    pub fn set_toolbar_color(this: &mut WidgetMut<Self>, color: Color) {
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_toolbar_color(&mut text_area, color);
+        }
+    }
+
     pub fn set_blink_rate(this: &mut WidgetMut<Self>, rate: f32) {
         if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
             TextArea::set_blink_rate(&mut text_area, rate);
@@ -142,6 +294,38 @@ impl Entry {
         }
     }
 
+    pub fn set_gutter(this: &mut WidgetMut<Self>, gutter: Option<Gutter>) {
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_gutter(&mut text_area, gutter);
+        }
+    }
+
+    pub fn set_line_marker(
+        this: &mut WidgetMut<Self>,
+        line_marker: impl FnMut(usize) -> Option<Color> + 'static,
+    ) {
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_line_marker(&mut text_area, line_marker);
+        }
+    }
+
+    pub fn set_filter(this: &mut WidgetMut<Self>, filter: impl FnMut(&str) -> bool + 'static) {
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_filter(&mut text_area, filter);
+        }
+    }
+
+    pub fn set_validator(
+        this: &mut WidgetMut<Self>,
+        validator: impl FnMut(&str) -> bool + 'static,
+    ) {
+        if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
+            TextArea::set_validator(&mut text_area, validator);
+        }
+
+        this.cx.request_draw();
+    }
+
     pub fn set_on_change(this: &mut WidgetMut<Self>, on_change: impl FnMut(&str) + 'static) {
         if let Ok(mut text_area) = this.cx.get_widget_mut(this.widget.text_area) {
             TextArea::set_on_change(&mut text_area, on_change);
@@ -167,6 +351,152 @@ impl Entry {
     }
 }
 
+impl Entry {
+    fn set_adornment(
+        cx: &mut impl Builder,
+        this: WidgetId<Self>,
+        adornment: Option<WidgetId>,
+        slot: fn(&mut Self) -> &mut Option<WidgetId>,
+    ) {
+        let Ok(mut entry) = cx.get_widget_mut(this) else {
+            return;
+        };
+
+        let previous = mem::replace(slot(&mut entry.widget), adornment);
+        entry.cx.request_layout();
+        drop(entry);
+
+        if previous == adornment {
+            return;
+        }
+
+        if let Some(previous) = previous {
+            cx.remove_widget(previous);
+        }
+
+        if let Some(adornment) = adornment {
+            cx.add_child(this, adornment);
+        }
+    }
+
+    fn shows_clear(&self) -> bool {
+        self.clear_button && self.has_text
+    }
+
+    fn shows_reveal(&self) -> bool {
+        self.is_secure && self.reveal_toggle
+    }
+
+    fn icon_at(&self, point: Point) -> Option<Icon> {
+        if self.shows_clear() && self.clear_rect.contains(point) {
+            Some(Icon::Clear)
+        } else if self.shows_reveal() && self.reveal_rect.contains(point) {
+            Some(Icon::Reveal)
+        } else {
+            None
+        }
+    }
+
+    fn activate(&mut self, cx: &mut EventCx<'_>, icon: Icon) {
+        let Ok(mut text_area) = cx.get_widget_mut(self.text_area) else {
+            return;
+        };
+
+        match icon {
+            Icon::Clear => TextArea::clear(&mut text_area),
+
+            Icon::Reveal => {
+                self.is_revealed = !self.is_revealed;
+                TextArea::set_secure(&mut text_area, !self.is_revealed);
+            }
+        }
+
+        drop(text_area);
+        cx.request_draw();
+    }
+
+    /// Draw a cross.
+    fn draw_clear(&self, canvas: &mut dyn Canvas) {
+        let rect = self.clear_rect;
+        let inset = Offset::all(rect.width() / 4.0);
+        let paint = Paint::from(self.clear_color);
+
+        let (min, max) = (rect.min + inset, rect.max - inset);
+
+        canvas.draw_curve(&line(min, max, 1.5), &paint);
+        canvas.draw_curve(
+            &line(
+                Point::new(min.x, max.y),
+                Point::new(max.x, min.y),
+                1.5,
+            ),
+            &paint,
+        );
+    }
+
+    /// Draw an eye, crossed out while the text is hidden.
+    fn draw_reveal(&self, canvas: &mut dyn Canvas) {
+        let rect = self.reveal_rect;
+        let center = rect.center();
+        let radius = rect.height() / 3.0;
+        let paint = Paint::from(self.reveal_color);
+        let background = Paint::from(*self.background_color);
+
+        let mut eye = Curve::new();
+        eye.move_to(center - Offset::new(rect.width() / 2.0, 0.0));
+        eye.quad_to(
+            center - Offset::new(0.0, radius * 2.0),
+            center + Offset::new(rect.width() / 2.0, 0.0),
+        );
+        eye.quad_to(
+            center + Offset::new(0.0, radius * 2.0),
+            center - Offset::new(rect.width() / 2.0, 0.0),
+        );
+        eye.close();
+
+        canvas.draw_curve(&eye, &paint);
+
+        // the iris is cut out of the eye with the background
+        let circle = |radius: f32| {
+            let rect = Rect::min_size(
+                center - Offset::all(radius),
+                Size::all(radius * 2.0),
+            );
+
+            (rect, CornerRadius::all(radius))
+        };
+
+        let (iris, corners) = circle(radius * 0.8);
+        canvas.draw_rect(iris, corners, &background);
+
+        let (pupil, corners) = circle(radius * 0.45);
+        canvas.draw_rect(pupil, corners, &paint);
+
+        if !self.is_revealed {
+            let from = rect.top_left();
+            let to = rect.max;
+
+            canvas.draw_curve(&line(from, to, 4.0), &background);
+            canvas.draw_curve(&line(from, to, 1.5), &paint);
+        }
+    }
+}
+
+/// A straight line from `from` to `to`, as a filled ribbon `width` wide.
+fn line(from: Point, to: Point, width: f32) -> Curve {
+    let d = to - from;
+    let length = f32::max(f32::hypot(d.x, d.y), f32::EPSILON);
+    let normal = Offset::new(-d.y, d.x) * (width / 2.0 / length);
+
+    let mut curve = Curve::new();
+    curve.move_to(from + normal);
+    curve.line_to(to + normal);
+    curve.line_to(to - normal);
+    curve.line_to(from - normal);
+    curve.close();
+    curve
+}
+
 impl Widget for Entry {
     fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
         let mut space = self.padding.layout_down(cx, space);
@@ -176,21 +506,99 @@ impl Widget for Entry {
         space.max.width = space.max.width.min(self.max_width);
         space.min.width = space.min.width.min(space.max.width);
 
-        let has_text = cx
-            .get_widget(self.text_area)
-            .is_ok_and(|w| w.widget.text().is_empty());
-
-        cx.set_child_stashed(1, !has_text);
+        let (length, max_length) = cx.get_widget(self.text_area).map_or((0, None), |w| {
+            let length = w.widget.text().chars().count();
+            (length, w.widget.max_length())
+        });
+
+        self.has_text = length > 0;
+        cx.set_child_stashed(1, self.has_text);
+
+        // the adornments are given as much space as they want, the text gets what's left
+        let loose = Space::new(Size::ZERO, space.max);
+        let leading = self.leading.map(|leading| cx.layout_child(leading, loose));
+        let trailing = self
+            .trailing
+            .map(|trailing| cx.layout_child(trailing, loose));
+
+        self.counter_text = self.counter.clone().map(|style| {
+            let mut paragraph = Paragraph::new(1.2, TextAlign::Start, TextWrap::None);
+
+            match max_length {
+                Some(max_length) => paragraph.push(format!("{length}/{max_length}"), style),
+                None => paragraph.push(length.to_string(), style),
+            }
+
+            paragraph
+        });
+
+        let counter = self
+            .counter_text
+            .as_ref()
+            .map(|counter| cx.measure_text(counter, f32::INFINITY));
+        let clear = self.shows_clear().then_some(Size::all(ICON_SIZE));
+        let reveal = self.shows_reveal().then_some(Size::all(ICON_SIZE));
+
+        let adornments = [leading, trailing, counter, clear, reveal];
+        let adornments_width: f32 = adornments
+            .iter()
+            .flatten()
+            .map(|size| size.width + ADORNMENT_GAP)
+            .sum();
+
+        space.max.width = f32::max(space.max.width - adornments_width, 0.0);
+        space.min.width = f32::max(space.min.width - adornments_width, 0.0);
 
         let placeholder_size = cx.layout_child(self.placeholder, space);
         space.min.width = space.min.width.max(placeholder_size.width);
-        let text_size = cx.layout_child(self.text_area, space);
+        let text_size = cx.layout_child(self.text_area, space).max(placeholder_size);
+
+        let height = adornments
+            .iter()
+            .flatten()
+            .fold(text_size.height, |height, size| {
+                height.max(size.height)
+            });
+
+        // everything is placed in a row, centered vertically
+        let offset = self.padding.leading_offset(cx) + self.border_width.aligned_offset(cx);
+        let mut x = 0.0;
+
+        let mut place = |size: Size| {
+            let min = Point::new(
+                offset.x + x,
+                offset.y + (height - size.height) / 2.0,
+            );
+
+            x += size.width + ADORNMENT_GAP;
+            Rect::min_size(min, size)
+        };
 
-        let offset = self.padding.aligned_offset(cx) + self.border_width.aligned_offset(cx);
-        cx.place_child(self.text_area, offset);
-        cx.place_child(self.placeholder, offset);
+        if let (Some(id), Some(size)) = (self.leading, leading) {
+            cx.place_child(id, place(size).min - Point::ORIGIN);
+        }
 
-        let size = text_size.max(placeholder_size);
+        let text = place(text_size).min - Point::ORIGIN;
+        cx.place_child(self.text_area, text);
+        cx.place_child(self.placeholder, text);
+
+        if let (Some(id), Some(size)) = (self.trailing, trailing) {
+            cx.place_child(id, place(size).min - Point::ORIGIN);
+        }
+
+        if let Some(size) = counter {
+            self.counter_offset = place(size).min - Point::ORIGIN;
+        }
+
+        if let Some(size) = clear {
+            self.clear_rect = place(size);
+        }
+
+        if let Some(size) = reveal {
+            self.reveal_rect = place(size);
+        }
+
+        let size = Size::new(x - ADORNMENT_GAP, height);
         let size = self.padding.layout_up(cx, size);
         self.border_width.layout_up(cx, size)
     }
@@ -199,19 +607,103 @@ impl Widget for Entry {
         canvas.draw_rect(
             cx.rect(),
             self.corner_radius,
-            &Paint::from(self.background_color),
+            &Paint::from(*self.background_color),
         );
 
         canvas.draw_border(
             cx.rect(),
             self.border_width,
             self.corner_radius,
-            &Paint::from(self.border_color),
+            &Paint::from(*self.border_color),
         );
     }
 
     fn draw_over(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
-        if cx.has_focused() && cx.is_window_focused() {
+        if let Some(ref counter) = self.counter_text {
+            canvas.draw_text(
+                counter,
+                f32::INFINITY,
+                self.counter_offset,
+            );
+        }
+
+        if self.shows_clear() {
+            self.draw_clear(canvas);
+        }
+
+        if self.shows_reveal() {
+            self.draw_reveal(canvas);
+        }
+
+        if !cx.has_focused()
+            && let Ok(text_area) = cx.get_widget(self.text_area)
+        {
+            let rect = text_area
+                .cx
+                .rect()
+                .transform_bounds(text_area.cx.transform());
+            let hidden_before = text_area.widget.scroll();
+            let hidden_after = text_area.widget.overflow() - hidden_before;
+
+            // fade out text that has been clipped, hinting that there's more
+            let width = f32::min(24.0, rect.width() / 4.0);
+            let background = *self.background_color;
+
+            if hidden_before > 1.0 {
+                let fade = Rect {
+                    min: rect.min,
+                    max: Point::new(rect.left() + width, rect.bottom()),
+                };
+                let gradient = Gradient::linear(
+                    fade.top_right(),
+                    fade.top_left(),
+                    [
+                        GradientStop::new(0.0, background.fade(0.0)),
+                        GradientStop::new(1.0, background),
+                    ],
+                );
+
+                canvas.draw_rect(
+                    fade,
+                    CornerRadius::all(0.0),
+                    &Paint::from(gradient),
+                );
+            }
+
+            if hidden_after > 1.0 {
+                let fade = Rect {
+                    min: Point::new(rect.right() - width, rect.top()),
+                    max: rect.max,
+                };
+                let gradient = Gradient::linear(
+                    fade.top_left(),
+                    fade.top_right(),
+                    [
+                        GradientStop::new(0.0, background.fade(0.0)),
+                        GradientStop::new(1.0, background),
+                    ],
+                );
+
+                canvas.draw_rect(
+                    fade,
+                    CornerRadius::all(0.0),
+                    &Paint::from(gradient),
+                );
+            }
+        }
+
+        let is_valid = cx
+            .get_widget(self.text_area)
+            .is_ok_and(|w| w.widget.is_valid());
+
+        if !is_valid {
+            canvas.draw_border(
+                cx.rect(),
+                BorderWidth::all(2.0),
+                self.corner_radius,
+                &Paint::from(self.error_color),
+            );
+        } else if cx.has_focused() && cx.is_window_focused() {
             canvas.draw_border(
                 cx.rect(),
                 BorderWidth::all(2.0),
@@ -220,4 +712,70 @@ impl Widget for Entry {
             );
         }
     }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        match event {
+            PointerEvent::Down(event) if event.button == PointerButton::Primary => {
+                let local = cx.global_transform().inverse() * event.position;
+
+                let Some(icon) = self.icon_at(local) else {
+                    return PointerPropagate::Bubble;
+                };
+
+                self.pressed = Some((event.pointer, icon));
+                PointerPropagate::Capture
+            }
+
+            PointerEvent::Up(event)
+                if self
+                    .pressed
+                    .is_some_and(|(pointer, _)| pointer == event.pointer) =>
+            {
+                let local = cx.global_transform().inverse() * event.position;
+
+                // the icon is only activated if the pointer is released over it
+                if let Some((_, icon)) = self.pressed.take()
+                    && self.icon_at(local) == Some(icon)
+                {
+                    self.activate(cx, icon);
+                }
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn on_touch_event(&mut self, cx: &mut EventCx<'_>, event: &TouchEvent) -> TouchPropagate {
+        match event {
+            TouchEvent::Gesture(Gesture::Tap(event)) => {
+                let local = cx.global_transform().inverse() * event.position;
+
+                let Some(icon) = self.icon_at(local) else {
+                    return TouchPropagate::Bubble;
+                };
+
+                self.activate(cx, icon);
+                TouchPropagate::Handled
+            }
+
+            _ => TouchPropagate::Bubble,
+        }
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_draw();
+
+        let background = self.background_color.animate(dt);
+        let border = self.border_color.animate(dt);
+
+        if background || border {
+            cx.request_animate();
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
 }
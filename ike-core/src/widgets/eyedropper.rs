@@ -0,0 +1,100 @@
+use crate::{
+    AnyWidgetId, Builder, Color, CursorIcon, EventCx, LayoutCx, PointerButton, PointerEvent,
+    PointerId, PointerPropagate, Size, Space, Update, UpdateCx, Widget, WidgetMut,
+};
+
+/// Picks colors from what is drawn in the window.
+///
+/// Pressing the eyedropper and dragging the pointer samples the pixel under it, the color under
+/// the pointer when it's released is picked. Only the app's own windows can be sampled.
+pub struct Eyedropper {
+    picking:     Option<PointerId>,
+    is_released: bool,
+    on_sample:   Box<dyn FnMut(Color)>,
+    on_pick:     Box<dyn FnMut(Color)>,
+}
+
+impl Eyedropper {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            picking:     None,
+            is_released: false,
+            on_sample:   Box::new(|_| {}),
+            on_pick:     Box::new(|_| {}),
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    /// Whether the user is currently picking a color.
+    pub fn is_picking(&self) -> bool {
+        self.picking.is_some()
+    }
+
+    /// Called with the color under the pointer while picking, for previewing it.
+    pub fn set_on_sample(this: &mut WidgetMut<Self>, on_sample: impl FnMut(Color) + 'static) {
+        this.widget.on_sample = Box::new(on_sample);
+    }
+
+    /// Called with the picked color when the pointer is released.
+    pub fn set_on_pick(this: &mut WidgetMut<Self>, on_pick: impl FnMut(Color) + 'static) {
+        this.widget.on_pick = Box::new(on_pick);
+    }
+}
+
+impl Widget for Eyedropper {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::Sampled(_, color) = update {
+            (self.on_sample)(color);
+
+            if self.is_released {
+                self.is_released = false;
+                (self.on_pick)(color);
+            }
+
+            if self.picking.is_none() {
+                cx.set_cursor(CursorIcon::Default);
+            }
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        match event {
+            PointerEvent::Down(event)
+                if event.button == PointerButton::Primary && self.picking.is_none() =>
+            {
+                self.picking = Some(event.pointer);
+                self.is_released = false;
+
+                cx.set_cursor(CursorIcon::Crosshair);
+                cx.request_sample(event.position);
+
+                PointerPropagate::Capture
+            }
+
+            PointerEvent::Move(event) if self.picking == Some(event.pointer) => {
+                cx.request_sample(event.position);
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Up(event) if self.picking == Some(event.pointer) => {
+                self.picking = None;
+                self.is_released = true;
+
+                cx.request_sample(event.position);
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
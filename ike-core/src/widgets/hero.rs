@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use crate::{
+    Affine, AnyWidgetId, Builder, ComposeCx, Interpolate, LayoutCx, Point, Rect, Size, Space,
+    Transition, Transitioned, Update, UpdateCx, Widget, WidgetMut,
+};
+
+/// A widget shared between screens, that flies from where it was on the previous screen to
+/// where it is on the new one.
+///
+/// When a hero is shown, and a hero with the same tag was shown in the same window until this
+/// frame, the contents animate from the rect of the other hero to their own. This works across
+/// layers, but the contents are still drawn in the layer of the new hero, and clipped by its
+/// ancestors.
+pub struct Hero {
+    tag:         String,
+    progress:    Transitioned<f32>,
+    from:        Rect,
+    is_arriving: bool,
+}
+
+impl Hero {
+    #[track_caller]
+    pub fn new(
+        cx: &mut impl Builder,
+        tag: impl Into<String>,
+        child: impl AnyWidgetId,
+    ) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            tag:         tag.into(),
+            progress:    Transitioned::new(1.0, Transition::ease(0.3)),
+            from:        Rect::min_size(Point::ORIGIN, Size::ZERO),
+            is_arriving: false,
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    /// Set the tag, changing it doesn't start a flight.
+    pub fn set_tag(this: &mut WidgetMut<Self>, tag: impl Into<String>) {
+        let tag = tag.into();
+
+        if this.widget.tag != tag {
+            let id = this.cx.id();
+            this.cx.heroes().left(&this.widget.tag, id);
+            this.widget.tag = tag;
+            this.cx.request_compose();
+        }
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.progress.set_transition(transition);
+    }
+
+    fn arrive(&mut self, cx: &mut ComposeCx<'_>) {
+        self.is_arriving = false;
+
+        let Some(window) = cx.window() else {
+            return;
+        };
+
+        let id = cx.id();
+
+        if let Some(from) = cx.heroes().arrive(&self.tag, id, window) {
+            self.from = from;
+            self.progress.set(0.0);
+            self.progress.begin(1.0);
+
+            cx.request_animate();
+        }
+    }
+}
+
+impl Widget for Hero {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+
+    fn compose(&mut self, cx: &mut ComposeCx<'_>) {
+        if self.is_arriving {
+            self.arrive(cx);
+        }
+
+        let global_transform = cx.global_transform();
+
+        // the rect the contents are shown at, in local coordinates
+        let from = self.from.transform_bounds(global_transform.inverse());
+        let rect = Rect::interpolate(&from, &cx.rect(), *self.progress);
+
+        let transform = Affine::scale_translate(
+            rect.width() / cx.width().max(f32::EPSILON),
+            rect.height() / cx.height().max(f32::EPSILON),
+            rect.min - Point::ORIGIN,
+        );
+
+        cx.place_nth_child(0, transform);
+
+        if let Some(window) = cx.window() {
+            let id = cx.id();
+            let rect = rect.transform_bounds(global_transform);
+            cx.heroes().shown(&self.tag, id, window, rect);
+        }
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_compose();
+
+        if self.progress.animate(dt) {
+            cx.request_animate();
+            cx.set_subpixel(true);
+        } else {
+            cx.set_subpixel(false);
+        }
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        match update {
+            Update::Added | Update::Stashed(false) => {
+                self.is_arriving = true;
+                cx.request_compose();
+            }
+
+            Update::Removed | Update::Stashed(true) => {
+                let id = cx.id();
+                cx.heroes().left(&self.tag, id);
+            }
+
+            _ => {}
+        }
+    }
+}
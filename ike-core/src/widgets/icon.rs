@@ -0,0 +1,191 @@
+use crate::{
+    Affine, Blend, Builder, Canvas, Color, DrawCx, FontStretch, FontStyle, FontWeight,
+    LayerOptions, LayoutCx, Offset, Paint, Paragraph, Shader, Size, Space, Svg, SvgStyle,
+    TextAlign, TextStyle, TextWrap, Widget, WidgetMut,
+};
+
+/// The shape of an [`Icon`], either an svg or a character of an icon font.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IconGlyph {
+    Svg(Svg),
+
+    Font {
+        font_family: String,
+        glyph:       char,
+    },
+}
+
+impl From<Svg> for IconGlyph {
+    fn from(svg: Svg) -> Self {
+        IconGlyph::Svg(svg)
+    }
+}
+
+/// A square icon, drawn in a single color.
+///
+/// Svg glyphs are scaled to fit the icon and tinted with its color, font glyphs are drawn with
+/// the size of the icon as their font size.
+pub struct Icon {
+    glyph:     Option<IconGlyph>,
+    size:      f32,
+    color:     Color,
+    mirrored:  bool,
+    paragraph: Option<Paragraph>,
+    text_size: Size,
+}
+
+impl Icon {
+    #[track_caller]
+    pub fn new(
+        cx: &mut impl Builder,
+        glyph: Option<IconGlyph>,
+        size: f32,
+        color: Color,
+    ) -> WidgetMut<'_, Self> {
+        let mut icon = Self {
+            glyph,
+            size,
+            color,
+            mirrored: false,
+            paragraph: None,
+            text_size: Size::ZERO,
+        };
+
+        icon.build_paragraph();
+        cx.build_widget(icon).finish()
+    }
+
+    /// Set the glyph of the icon, an icon without a glyph draws nothing.
+    pub fn set_glyph(this: &mut WidgetMut<Self>, glyph: Option<IconGlyph>) {
+        this.widget.glyph = glyph;
+        this.widget.build_paragraph();
+        this.cx.request_layout();
+    }
+
+    pub fn set_size(this: &mut WidgetMut<Self>, size: f32) {
+        this.widget.size = size;
+        this.widget.build_paragraph();
+        this.cx.request_layout();
+    }
+
+    pub fn set_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.color = color;
+        this.widget.build_paragraph();
+        this.cx.request_draw();
+    }
+
+    /// Set whether the icon is mirrored horizontally in right-to-left layouts, e.g. for arrows
+    /// pointing forward or back.
+    pub fn set_mirrored(this: &mut WidgetMut<Self>, mirrored: bool) {
+        if this.widget.mirrored != mirrored {
+            this.widget.mirrored = mirrored;
+            this.cx.request_draw();
+        }
+    }
+
+    pub fn glyph(&self) -> Option<&IconGlyph> {
+        self.glyph.as_ref()
+    }
+
+    fn build_paragraph(&mut self) {
+        let Some(IconGlyph::Font {
+            ref font_family,
+            glyph,
+        }) = self.glyph
+        else {
+            self.paragraph = None;
+            return;
+        };
+
+        let style = TextStyle {
+            font_size:    self.size,
+            font_family:  font_family.clone(),
+            font_weight:  FontWeight::NORMAL,
+            font_stretch: FontStretch::Normal,
+            font_style:   FontStyle::Normal,
+            paint:        Paint::from(self.color),
+        };
+
+        let mut paragraph = Paragraph::new(1.0, TextAlign::Center, TextWrap::None);
+        paragraph.push(glyph.encode_utf8(&mut [0; 4]), style);
+        self.paragraph = Some(paragraph);
+    }
+}
+
+impl Widget for Icon {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        if let Some(ref paragraph) = self.paragraph {
+            self.text_size = cx.measure_text(paragraph, f32::INFINITY);
+        }
+
+        space.constrain(Size::all(self.size))
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        if self.mirrored && cx.layout_direction().is_rtl() {
+            let mirror = Affine::scale_translate(-1.0, 1.0, Offset::new(cx.width(), 0.0));
+            canvas.transform(mirror, &mut |canvas| {
+                self.draw_glyph(cx, canvas)
+            });
+        } else {
+            self.draw_glyph(cx, canvas);
+        }
+    }
+}
+
+impl Icon {
+    fn draw_glyph(&self, cx: &DrawCx<'_>, canvas: &mut dyn Canvas) {
+        match self.glyph {
+            Some(IconGlyph::Svg(ref svg)) => {
+                let size = canvas.painter().measure_svg(svg);
+
+                if size.has_zero_area() {
+                    return;
+                }
+
+                let scale = f32::min(
+                    cx.width() / size.width,
+                    cx.height() / size.height,
+                );
+
+                let offset = Offset {
+                    x: (cx.width() - size.width * scale) / 2.0,
+                    y: (cx.height() - size.height * scale) / 2.0,
+                };
+
+                canvas.layer(
+                    &LayerOptions::default(),
+                    &mut |canvas| {
+                        let transform = Affine::scale_translate(scale, scale, offset);
+                        canvas.transform(transform, &mut |canvas| {
+                            canvas.draw_svg(svg, &SvgStyle::default())
+                        });
+
+                        let paint = Paint {
+                            shader: Shader::Solid(self.color),
+                            blend: Blend::SrcIn,
+                            ..Default::default()
+                        };
+
+                        canvas.fill(&paint);
+                    },
+                );
+            }
+
+            Some(IconGlyph::Font { .. }) => {
+                let Some(ref paragraph) = self.paragraph else {
+                    return;
+                };
+
+                let offset = Offset {
+                    x: (cx.width() - self.text_size.width) / 2.0,
+                    y: (cx.height() - self.text_size.height) / 2.0,
+                };
+
+                canvas.draw_text(paragraph, self.text_size.width, offset);
+            }
+
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use crate::{
+    AnyWidgetId, Builder, LayoutCx, Padding, Size, Space, Transition, Transitioned, Update,
+    UpdateCx, Widget, WidgetMut,
+};
+
+/// Pads the bottom of its child by the height of the on-screen keyboard, see
+/// [`Window::keyboard_height`](crate::Window::keyboard_height).
+///
+/// The padding assumes the widget reaches the bottom of the window, e.g. when it's the contents
+/// of a [`SafeArea`](super::SafeArea) that doesn't avoid the keyboard itself.
+pub struct ImePadding {
+    padding: Transitioned<Padding>,
+}
+
+impl ImePadding {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(ImePadding {
+            padding: Transitioned::new(Padding::all(0.0), Transition::INSTANT),
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.padding.set_transition(transition);
+    }
+}
+
+impl Widget for ImePadding {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let space = self.padding.layout_down(cx, space);
+        let size = cx.layout_nth_child(0, space);
+        cx.place_nth_child(0, self.padding.aligned_offset(cx));
+        self.padding.layout_up(cx, size)
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_layout();
+
+        if self.padding.animate(dt) {
+            cx.request_animate();
+            cx.set_subpixel(true);
+        } else {
+            cx.set_subpixel(false);
+        }
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::WindowKeyboard(height) = update {
+            cx.request_layout();
+
+            let padding = Padding {
+                bottom: height,
+                ..Padding::all(0.0)
+            };
+
+            if self.padding.begin(padding) {
+                cx.request_animate();
+            }
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{
+    Affine, Builder, ChildUpdate, Clip, EventCx, Gesture, LayoutCx, Modifiers, Offset, Point,
+    PointerButton, PointerEvent, PointerId, PointerPropagate, Rect, ScrollDelta, Size, Space,
+    TouchEvent, TouchPropagate, Update, UpdateCx, Widget, WidgetMut,
+};
+
+struct Item {
+    position: Point,
+    size:     Option<Size>,
+    culled:   bool,
+}
+
+/// An unbounded plane with children placed at absolute positions.
+///
+/// The plane can be panned by dragging the background or scrolling, and zoomed by scrolling
+/// while holding control, or by double tapping. Children outside the viewport are stashed, and
+/// are neither laid out nor drawn. Since the size of a stashed child isn't known, the size it
+/// had when it was last visible is used to determine if it has come back into view.
+pub struct InfiniteCanvas {
+    items:    Vec<Item>,
+    pan:      Offset,
+    zoom:     f32,
+    min_zoom: f32,
+    max_zoom: f32,
+    drag_pan: bool,
+
+    on_view_change: Box<dyn FnMut(Offset, f32)>,
+
+    panning: Option<(PointerId, Point)>,
+}
+
+impl InfiniteCanvas {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            items:    Vec::new(),
+            pan:      Offset::ZERO,
+            zoom:     1.0,
+            min_zoom: 0.1,
+            max_zoom: 10.0,
+            drag_pan: true,
+
+            on_view_change: Box::new(|_, _| {}),
+
+            panning: None,
+        })
+        .finish()
+    }
+
+    /// Set the position of the child at `index`, in the coordinates of the plane.
+    pub fn set_position(this: &mut WidgetMut<Self>, index: usize, position: Point) {
+        this.widget.items[index].position = position;
+        this.cx.request_layout();
+    }
+
+    pub fn get_position(&self, index: usize) -> Point {
+        self.items[index].position
+    }
+
+    /// Set the `pan` and `zoom` of the view, the plane is drawn scaled by `zoom` and offset by
+    /// `pan`.
+    pub fn set_view(this: &mut WidgetMut<Self>, pan: Offset, zoom: f32) {
+        this.widget.pan = pan;
+        this.widget.zoom = zoom.clamp(
+            this.widget.min_zoom,
+            this.widget.max_zoom,
+        );
+        this.cx.request_layout();
+    }
+
+    pub fn pan(&self) -> Offset {
+        self.pan
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub fn set_zoom_range(this: &mut WidgetMut<Self>, min: f32, max: f32) {
+        this.widget.min_zoom = min;
+        this.widget.max_zoom = max;
+        this.widget.zoom = this.widget.zoom.clamp(min, max);
+        this.cx.request_layout();
+    }
+
+    /// Set whether dragging the background with the primary button pans the view, the middle
+    /// button always does.
+    pub fn set_drag_pan(this: &mut WidgetMut<Self>, drag_pan: bool) {
+        this.widget.drag_pan = drag_pan;
+    }
+
+    /// Set the callback called with the new pan and zoom when the user moves the view.
+    pub fn set_on_view_change(
+        this: &mut WidgetMut<Self>,
+        on_view_change: impl FnMut(Offset, f32) + 'static,
+    ) {
+        this.widget.on_view_change = Box::new(on_view_change);
+    }
+
+    /// Convert a `point` in local coordinates to the coordinates of the plane.
+    pub fn to_plane(&self, point: Point) -> Point {
+        Point::ORIGIN + (point - Point::ORIGIN - self.pan) / self.zoom
+    }
+
+    /// Convert a `point` in the coordinates of the plane to local coordinates.
+    pub fn to_local(&self, point: Point) -> Point {
+        Point::ORIGIN + (point - Point::ORIGIN) * self.zoom + self.pan
+    }
+
+    /// The number of children on the plane.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Get the bounds of the child at `index` in the coordinates of the plane, if it has been
+    /// laid out.
+    pub fn get_bounds(&self, index: usize) -> Option<Rect> {
+        let item = self.items.get(index)?;
+        Some(Rect::min_size(
+            item.position,
+            item.size?,
+        ))
+    }
+
+    fn move_view(&mut self, cx: &mut EventCx<'_>, pan: Offset, zoom: f32) {
+        self.pan = pan;
+        self.zoom = zoom;
+        cx.request_layout();
+
+        (self.on_view_change)(self.pan, self.zoom);
+    }
+
+    fn zoom_around(&mut self, cx: &mut EventCx<'_>, factor: f32, point: Point) {
+        let zoom = f32::clamp(
+            self.zoom * factor,
+            self.min_zoom,
+            self.max_zoom,
+        );
+
+        // keep the point under the pointer in place
+        let plane = self.to_plane(point);
+        let pan = point - Point::ORIGIN - (plane - Point::ORIGIN) * zoom;
+
+        self.move_view(cx, pan, zoom);
+    }
+}
+
+impl Widget for InfiniteCanvas {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let size = match space.max.is_finite() {
+            true => space.max,
+            false => space.min,
+        };
+
+        let viewport = Rect {
+            min: self.to_plane(Point::ORIGIN),
+            max: self.to_plane(Point::new(size.width, size.height)),
+        };
+
+        let child_space = Space::new(Size::ZERO, Size::all(f32::INFINITY));
+
+        for (i, item) in self.items.iter_mut().enumerate() {
+            let culled = item.size.is_some_and(|size| {
+                let bounds = Rect::min_size(item.position, size);
+                let overlap = bounds.intersection(viewport);
+
+                overlap.min.x > overlap.max.x || overlap.min.y > overlap.max.y
+            });
+
+            if culled != item.culled {
+                item.culled = culled;
+                cx.set_child_stashed(i, culled);
+            }
+
+            if culled {
+                continue;
+            }
+
+            item.size = Some(cx.layout_nth_child(i, child_space));
+
+            let transform = Affine::translate(self.pan)
+                * Affine::scale(self.zoom, self.zoom)
+                * Affine::translate(item.position - Point::ORIGIN);
+
+            cx.place_nth_child(i, transform);
+        }
+
+        cx.set_clip(Clip::from(Rect::min_size(
+            Point::ORIGIN,
+            size,
+        )));
+
+        size
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::Children(update) = update {
+            let item = Item {
+                position: Point::ORIGIN,
+                size:     None,
+                culled:   false,
+            };
+
+            match update {
+                ChildUpdate::Inserted(index) => {
+                    self.items.insert(index, item);
+                }
+
+                ChildUpdate::Removed(index) => {
+                    self.items.remove(index);
+                }
+
+                ChildUpdate::Replaced(index) => {
+                    self.items[index].size = None;
+                    self.items[index].culled = false;
+                }
+
+                ChildUpdate::Swapped(a, b) => {
+                    self.items.swap(a, b);
+                }
+            }
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let transform = cx.global_transform().inverse();
+
+        match event {
+            PointerEvent::Down(event)
+                if event.button == PointerButton::Tertiary
+                    || event.button == PointerButton::Primary && self.drag_pan =>
+            {
+                self.panning = Some((
+                    event.pointer,
+                    transform * event.position,
+                ));
+                PointerPropagate::Capture
+            }
+
+            PointerEvent::Move(event) => {
+                let Some((pointer, previous)) = self.panning else {
+                    return PointerPropagate::Bubble;
+                };
+
+                if pointer != event.pointer {
+                    return PointerPropagate::Bubble;
+                }
+
+                let local = transform * event.position;
+                self.panning = Some((pointer, local));
+
+                let pan = self.pan + (local - previous);
+                self.move_view(cx, pan, self.zoom);
+
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Up(event)
+                if self
+                    .panning
+                    .is_some_and(|(pointer, _)| pointer == event.pointer) =>
+            {
+                self.panning = None;
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Scroll(event) => {
+                let offset = match event.delta {
+                    ScrollDelta::Line(offset) => offset * 120.0,
+                    ScrollDelta::Pixel(offset) => offset,
+                };
+
+                let modifiers = cx.get_window().map_or(Modifiers::empty(), |window| {
+                    window.pointer_modifiers(event.pointer)
+                });
+
+                if modifiers.ctrl() {
+                    let local = transform * event.position;
+                    self.zoom_around(
+                        cx,
+                        f32::powf(1.1, offset.y / 120.0),
+                        local,
+                    );
+                } else {
+                    self.move_view(cx, self.pan + offset, self.zoom);
+                }
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn on_touch_event(&mut self, cx: &mut EventCx<'_>, event: &TouchEvent) -> TouchPropagate {
+        match event {
+            TouchEvent::Gesture(Gesture::Pan(event)) => {
+                self.move_view(cx, self.pan + event.delta, self.zoom);
+                TouchPropagate::Capture
+            }
+
+            TouchEvent::Gesture(Gesture::Pinch(event)) => {
+                let local = cx.global_transform().inverse() * event.focal;
+                self.zoom_around(cx, event.delta, local);
+
+                TouchPropagate::Capture
+            }
+
+            TouchEvent::Gesture(Gesture::DoubleTap(event)) => {
+                let local = cx.global_transform().inverse() * event.position;
+                self.zoom_around(cx, 2.0, local);
+
+                TouchPropagate::Handled
+            }
+
+            _ => TouchPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
@@ -8,6 +8,7 @@ pub struct Label {
 }
 
 impl Label {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, paragraph: Paragraph) -> WidgetMut<'_, Self> {
         cx.build_widget(Self { paragraph }).finish()
     }
@@ -17,6 +18,10 @@ impl Label {
         this.cx.request_layout();
         this.cx.request_draw();
     }
+
+    pub fn paragraph(&self) -> &Paragraph {
+        &self.paragraph
+    }
 }
 
 impl Widget for Label {
@@ -35,4 +40,8 @@ impl Widget for Label {
             Offset::ZERO,
         );
     }
+
+    fn paragraph(&self) -> Option<&Paragraph> {
+        Some(&self.paragraph)
+    }
 }
@@ -0,0 +1,32 @@
+use crate::{AnyWidgetId, Builder, LayoutCx, Size, Space, Widget, WidgetMut};
+
+/// Holds contents that are built on demand.
+///
+/// Without contents, or with stashed contents, takes up the minimum size of its space.
+pub struct Lazy;
+
+impl Lazy {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self).finish()
+    }
+
+    /// Set the contents, replacing any previous contents.
+    pub fn set_contents(cx: &mut impl Builder, this: impl AnyWidgetId, contents: impl AnyWidgetId) {
+        if cx.children(this).is_empty() {
+            cx.add_child(this, contents);
+        } else {
+            cx.set_child(this, 0, contents);
+        }
+    }
+}
+
+impl Widget for Lazy {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        if cx.children().is_empty() {
+            return space.min;
+        }
+
+        cx.layout_nth_child(0, space)
+    }
+}
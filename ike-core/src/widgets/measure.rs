@@ -0,0 +1,78 @@
+use crate::{AnyWidgetId, Builder, LayoutCx, Size, Space, Widget, WidgetMut};
+
+/// Reports the space it's laid out in, and the size of its contents, when they change.
+///
+/// Without contents, takes up the minimum size of its space.
+pub struct Measure {
+    on_space: Option<Box<dyn FnMut(Size, Size)>>,
+    on_size:  Option<Box<dyn FnMut(Size)>>,
+    space:    Option<(Size, Size)>,
+    size:     Option<Size>,
+}
+
+impl Measure {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            on_space: None,
+            on_size:  None,
+            space:    None,
+            size:     None,
+        })
+        .finish()
+    }
+
+    /// Set the contents, replacing any previous contents.
+    pub fn set_contents(cx: &mut impl Builder, this: impl AnyWidgetId, contents: impl AnyWidgetId) {
+        if cx.children(this).is_empty() {
+            cx.add_child(this, contents);
+        } else {
+            cx.set_child(this, 0, contents);
+        }
+    }
+
+    /// Call `on_space` with the maximum size of the space, and the size of the window, when
+    /// either changes.
+    pub fn set_on_space(this: &mut WidgetMut<Self>, on_space: impl FnMut(Size, Size) + 'static) {
+        this.widget.on_space = Some(Box::new(on_space));
+        this.widget.space = None;
+        this.cx.request_layout();
+    }
+
+    /// Call `on_size` with the size of the contents when it changes.
+    pub fn set_on_size(this: &mut WidgetMut<Self>, on_size: impl FnMut(Size) + 'static) {
+        this.widget.on_size = Some(Box::new(on_size));
+        this.widget.size = None;
+        this.cx.request_layout();
+    }
+}
+
+impl Widget for Measure {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let window = cx.get_window().map_or(Size::ZERO, |window| window.size());
+
+        if self.space != Some((space.max, window)) {
+            self.space = Some((space.max, window));
+
+            if let Some(ref mut on_space) = self.on_space {
+                on_space(space.max, window);
+            }
+        }
+
+        if cx.children().is_empty() {
+            return space.min;
+        }
+
+        let size = cx.layout_nth_child(0, space);
+
+        if self.size != Some(size) {
+            self.size = Some(size);
+
+            if let Some(ref mut on_size) = self.on_size {
+                on_size(size);
+            }
+        }
+
+        size
+    }
+}
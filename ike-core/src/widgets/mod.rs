@@ -1,34 +1,88 @@
 mod align;
+mod animated_visibility;
+mod animator;
 mod button;
+mod cache;
+mod code_block;
 mod constrain;
 mod container;
+mod dashboard;
+mod direction;
+mod disabled;
+mod dismissable;
 mod divider;
+mod draw_area;
 mod entry;
+mod eyedropper;
+mod hero;
+mod icon;
+mod ime_padding;
+mod infinite_canvas;
 mod label;
+mod lazy;
+mod measure;
+mod navigator;
+mod node_graph;
+mod opacity;
 mod pad;
 mod picture;
+mod plot_area;
+mod redact;
 mod safe_area;
 mod scroll;
 mod spacer;
 mod stack;
+mod sticky;
+mod table;
+mod tabs;
 mod text;
 mod text_area;
+mod toasts;
 mod transform;
+mod window_area;
+mod zoom;
 mod zstack;
 
 pub use align::Aligned;
+pub use animated_visibility::{AnimatedVisibility, VisibilityTransition};
+pub use animator::Animator;
 pub use button::Button;
+pub use cache::Cache;
+pub use code_block::CodeBlock;
 pub use constrain::Constrain;
 pub use container::Container;
+pub use dashboard::{Dashboard, Tile};
+pub use direction::Direction;
+pub use disabled::Disabled;
+pub use dismissable::Dismissable;
 pub use divider::Divider;
+pub use draw_area::DrawArea;
 pub use entry::Entry;
+pub use eyedropper::Eyedropper;
+pub use hero::Hero;
+pub use icon::{Icon, IconGlyph};
+pub use ime_padding::ImePadding;
+pub use infinite_canvas::InfiniteCanvas;
 pub use label::Label;
+pub use lazy::Lazy;
+pub use measure::Measure;
+pub use navigator::Navigator;
+pub use node_graph::{NodeGraph, Port, PortDirection, Wire};
+pub use opacity::Opacity;
 pub use pad::Pad;
 pub use picture::{Fit, Picturable, Picture};
+pub use plot_area::PlotArea;
+pub use redact::Redact;
 pub use safe_area::SafeArea;
-pub use scroll::Scroll;
+pub use scroll::{Overscroll, Scroll, ScrollTarget};
 pub use spacer::Spacer;
 pub use stack::{Align, Justify, Stack};
-pub use text_area::{NewlineBehaviour, SubmitBehaviour, TextArea};
+pub use sticky::Sticky;
+pub use table::{ColumnWidth, SortOrder, Table, TableColumn};
+pub use tabs::Tabs;
+pub use text_area::{Gutter, NewlineBehaviour, SubmitBehaviour, TextArea};
+pub use toasts::Toasts;
 pub use transform::Transform;
+pub use window_area::{WindowArea, WindowRegion};
+pub use zoom::Zoom;
 pub use zstack::ZStack;
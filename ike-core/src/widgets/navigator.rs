@@ -0,0 +1,268 @@
+use std::time::Duration;
+
+use crate::{
+    AnyWidgetId, Builder, ChildUpdate, LayoutCx, Offset, Point, Rect, Size, Space, Transition,
+    Transitioned, Update, UpdateCx, Widget, WidgetId, WidgetMut,
+};
+
+struct Page {
+    progress:   Transitioned<f32>,
+    is_leaving: bool,
+    is_removed: bool,
+}
+
+impl Page {
+    fn is_settled(&self) -> bool {
+        !self.is_leaving && self.progress.is_complete()
+    }
+}
+
+/// A stack of pages, showing the one on top.
+///
+/// Every child is a page, the last being on top. Pages slide in over the previous one when
+/// inserted, and out when popped with [`Navigator::pop`], after which
+/// [`Navigator::set_on_remove`] is called so the page can be removed. Pages covered by a settled
+/// page are stashed, which keeps their state, like how far they're scrolled.
+pub struct Navigator {
+    pages:      Vec<Page>,
+    transition: Transition,
+    parallax:   f32,
+    on_remove:  Box<dyn FnMut(WidgetId)>,
+    on_back:    Box<dyn FnMut()>,
+}
+
+impl Navigator {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            pages:      Vec::new(),
+            transition: Transition::ease(0.3),
+            parallax:   0.3,
+            on_remove:  Box::new(|_| {}),
+            on_back:    Box::new(|| {}),
+        })
+        .finish()
+    }
+
+    /// Slide `page` out, it's removed by [`Navigator::set_on_remove`] once it has.
+    ///
+    /// A page covered by another is removed once the pages above it have settled, without
+    /// sliding out, so replacing the top page slides the new one in over it.
+    pub fn pop(this: &mut WidgetMut<Self>, page: impl AnyWidgetId) {
+        let page = page.upcast();
+
+        let Some(index) = this.cx.children().iter().position(|child| *child == page) else {
+            return;
+        };
+
+        let page = &mut this.widget.pages[index];
+        page.is_leaving = true;
+        page.progress.begin(0.0);
+
+        this.cx.request_layout();
+        this.cx.request_animate();
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.transition = transition;
+
+        for page in &mut this.widget.pages {
+            page.progress.set_transition(transition);
+        }
+    }
+
+    /// Set how far the page below the one sliding is moved, relative to the width.
+    pub fn set_parallax(this: &mut WidgetMut<Self>, parallax: f32) {
+        this.widget.parallax = parallax;
+        this.cx.request_layout();
+    }
+
+    /// Called with a page when it has slid out, and should be removed.
+    pub fn set_on_remove(this: &mut WidgetMut<Self>, on_remove: impl FnMut(WidgetId) + 'static) {
+        this.widget.on_remove = Box::new(on_remove);
+    }
+
+    /// Called when the user navigates back, e.g. with the back button, while there's a page to
+    /// go back to, see [`Navigator::can_go_back`].
+    pub fn set_on_back(this: &mut WidgetMut<Self>, on_back: impl FnMut() + 'static) {
+        this.widget.on_back = Box::new(on_back);
+    }
+
+    /// Whether there's more than one page that isn't leaving.
+    pub fn can_go_back(&self) -> bool {
+        self.pages.iter().filter(|page| !page.is_leaving).count() > 1
+    }
+
+    /// Go back a page, returning whether there was one to go back to.
+    pub fn go_back(this: &mut WidgetMut<Self>) -> bool {
+        if !this.widget.can_go_back() {
+            return false;
+        }
+
+        (this.widget.on_back)();
+        true
+    }
+
+    /// Move the top page along with a back gesture, `progress` going from `0.0` to `1.0`.
+    ///
+    /// The gesture either ends with [`Navigator::go_back`], which slides the page out from where
+    /// the gesture left it, or [`Navigator::cancel_back`].
+    pub fn set_back_progress(this: &mut WidgetMut<Self>, progress: f32) {
+        if !this.widget.can_go_back() {
+            return;
+        }
+
+        if let Some(page) = this.widget.pages.iter_mut().rfind(|page| !page.is_leaving) {
+            page.progress.set(1.0 - progress.clamp(0.0, 1.0));
+            this.cx.request_layout();
+        }
+    }
+
+    /// Slide the top page back in place, after a cancelled back gesture.
+    pub fn cancel_back(this: &mut WidgetMut<Self>) {
+        if let Some(page) = this.widget.pages.iter_mut().rfind(|page| !page.is_leaving) {
+            page.progress.begin(1.0);
+
+            this.cx.request_layout();
+            this.cx.request_animate();
+        }
+    }
+
+    fn new_page(&self, index: usize) -> Page {
+        let mut progress = Transitioned::new(0.0, self.transition);
+
+        // the first page is shown right away, there's nothing for it to slide over
+        match index == 0 && self.pages.is_empty() {
+            true => progress.set(1.0),
+            false => {
+                progress.begin(1.0);
+            }
+        }
+
+        Page {
+            progress,
+            is_leaving: false,
+            is_removed: false,
+        }
+    }
+
+    /// The index of the bottom page that is drawn.
+    fn base(&self) -> usize {
+        (self.pages.iter()).rposition(Page::is_settled).unwrap_or(0)
+    }
+}
+
+impl Widget for Navigator {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let base = self.base();
+        let mut size = space.min;
+
+        for (i, page) in self.pages.iter().enumerate() {
+            cx.set_child_stashed(i, i < base);
+
+            if i < base {
+                continue;
+            }
+
+            let page_size = cx.layout_nth_child(i, space);
+
+            if !page.is_leaving {
+                size = page_size;
+            }
+        }
+
+        for (i, page) in self.pages.iter().enumerate().skip(base) {
+            let above = self.pages.get(i + 1);
+
+            // pages covered by one that isn't leaving stay where they are
+            let is_covered = above.is_some_and(|above| !above.is_leaving);
+
+            let mut x = match page.is_leaving && is_covered {
+                true => 0.0,
+                false => size.width * (1.0 - *page.progress),
+            };
+
+            if let Some(above) = above {
+                x -= size.width * self.parallax * *above.progress;
+            }
+
+            cx.place_nth_child(i, Offset::new(x, 0.0));
+        }
+
+        cx.set_clip(Rect::min_size(Point::ORIGIN, size));
+
+        size
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        let Update::Children(update) = update else {
+            return;
+        };
+
+        match update {
+            ChildUpdate::Inserted(index) => {
+                let page = self.new_page(index);
+                self.pages.insert(index, page);
+
+                cx.request_layout();
+                cx.request_animate();
+            }
+
+            ChildUpdate::Removed(index) => {
+                self.pages.remove(index);
+                cx.request_layout();
+            }
+
+            ChildUpdate::Replaced(..) => {
+                cx.request_layout();
+            }
+
+            ChildUpdate::Swapped(a, b) => {
+                self.pages.swap(a, b);
+                cx.request_layout();
+            }
+        }
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        let mut is_moving = false;
+
+        for page in &mut self.pages {
+            if page.progress.animate(dt) {
+                is_moving = true;
+            }
+        }
+
+        let mut removed = Vec::new();
+
+        for i in 0..self.pages.len() {
+            let page = &self.pages[i];
+
+            if !page.is_leaving || page.is_removed {
+                continue;
+            }
+
+            let above = &self.pages[i + 1..];
+            let is_covered = above.iter().any(|above| !above.is_leaving);
+            let is_hidden = page.progress.is_complete() || is_covered;
+
+            if is_hidden && above.iter().all(|above| above.progress.is_complete()) {
+                self.pages[i].is_removed = true;
+                removed.push(cx.children()[i]);
+            }
+        }
+
+        cx.request_layout();
+
+        if is_moving {
+            cx.request_animate();
+            cx.set_subpixel(true);
+        } else {
+            cx.set_subpixel(false);
+        }
+
+        for page in removed {
+            (self.on_remove)(page);
+        }
+    }
+}
@@ -0,0 +1,679 @@
+use crate::{
+    BorderWidth, Builder, Canvas, Color, CornerRadius, Curve, DrawCx, EventCx, Key, KeyEvent,
+    LayoutCx, Modifiers, NamedKey, Offset, Paint, Point, PointerButton, PointerEvent, PointerId,
+    PointerPropagate, Propagate, Rect, Size, Space, Widget, WidgetId, WidgetMut,
+    widgets::InfiniteCanvas,
+};
+
+/// Whether a [`Port`] is an input or an output of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PortDirection {
+    Input,
+    Output,
+}
+
+/// A port of a node in a [`NodeGraph`].
+///
+/// Inputs are placed along the left edge of the node, and outputs along the right. A wire can
+/// only connect an output to an input of the same `kind`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Port {
+    pub direction: PortDirection,
+    pub kind:      u32,
+    pub color:     Color,
+}
+
+/// A wire connecting the output port `from` to the input port `to`.
+///
+/// Ports are identified by the index of their node, and their index in the ports of that node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Wire {
+    pub from: (usize, usize),
+    pub to:   (usize, usize),
+}
+
+enum Drag {
+    Nodes(Point),
+    Wire((usize, usize), Point),
+    Marquee(Point, Point),
+}
+
+/// A graph of nodes connected by wires.
+///
+/// The nodes are the children of an [`InfiniteCanvas`], and the graph draws the ports and wires
+/// between them. Nodes are dragged by any part of them that doesn't handle pointer events
+/// itself, and wires are created by dragging from one port to another. The graph never changes
+/// its wires itself, instead the callbacks are called and the wires are expected to be set again.
+pub struct NodeGraph {
+    canvas: WidgetId<InfiniteCanvas>,
+
+    ports:         Vec<Vec<Port>>,
+    wires:         Vec<Wire>,
+    port_radius:   f32,
+    wire_width:    f32,
+    wire_color:    Color,
+    select_color:  Color,
+    marquee_color: Color,
+
+    on_move:       Box<dyn FnMut(usize, Point)>,
+    on_connect:    Box<dyn FnMut(Wire)>,
+    on_disconnect: Box<dyn FnMut(usize)>,
+    #[allow(clippy::type_complexity)]
+    on_delete:     Box<dyn FnMut(&[usize])>,
+    #[allow(clippy::type_complexity)]
+    on_select:     Box<dyn FnMut(&[usize])>,
+
+    selected:      Vec<usize>,
+    selected_wire: Option<usize>,
+    drag:          Option<(PointerId, Drag)>,
+}
+
+impl NodeGraph {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, canvas: WidgetId<InfiniteCanvas>) -> WidgetMut<'_, Self> {
+        if let Ok(mut canvas) = cx.get_widget_mut(canvas) {
+            InfiniteCanvas::set_drag_pan(&mut canvas, false);
+        }
+
+        cx.build_widget(Self {
+            canvas,
+
+            ports: Vec::new(),
+            wires: Vec::new(),
+            port_radius: 5.0,
+            wire_width: 2.0,
+            wire_color: Color::BLACK,
+            select_color: Color::BLUE,
+            marquee_color: Color::BLUE.fade(0.2),
+
+            on_move: Box::new(|_, _| {}),
+            on_connect: Box::new(|_| {}),
+            on_disconnect: Box::new(|_| {}),
+            on_delete: Box::new(|_| {}),
+            on_select: Box::new(|_| {}),
+
+            selected: Vec::new(),
+            selected_wire: None,
+            drag: None,
+        })
+        .with_child(canvas)
+        .finish()
+    }
+
+    /// Set the ports of every node, indexed by node.
+    pub fn set_ports(this: &mut WidgetMut<Self>, ports: Vec<Vec<Port>>) {
+        this.widget.ports = ports;
+        this.cx.request_draw();
+    }
+
+    pub fn set_wires(this: &mut WidgetMut<Self>, wires: Vec<Wire>) {
+        if this.widget.wires != wires {
+            this.widget.selected_wire = None;
+        }
+
+        this.widget.wires = wires;
+        this.cx.request_draw();
+    }
+
+    pub fn set_selected(this: &mut WidgetMut<Self>, selected: Vec<usize>) {
+        this.widget.selected = selected;
+        this.cx.request_draw();
+    }
+
+    pub fn set_port_radius(this: &mut WidgetMut<Self>, radius: f32) {
+        this.widget.port_radius = radius;
+        this.cx.request_draw();
+    }
+
+    pub fn set_wire_width(this: &mut WidgetMut<Self>, width: f32) {
+        this.widget.wire_width = width;
+        this.cx.request_draw();
+    }
+
+    pub fn set_wire_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.wire_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_select_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.select_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_marquee_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.marquee_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Set the callback called with the new position of a node when it's dragged.
+    pub fn set_on_move(this: &mut WidgetMut<Self>, on_move: impl FnMut(usize, Point) + 'static) {
+        this.widget.on_move = Box::new(on_move);
+    }
+
+    /// Set the callback called when the user connects two ports.
+    pub fn set_on_connect(this: &mut WidgetMut<Self>, on_connect: impl FnMut(Wire) + 'static) {
+        this.widget.on_connect = Box::new(on_connect);
+    }
+
+    /// Set the callback called with the index of a wire when the user deletes it.
+    pub fn set_on_disconnect(
+        this: &mut WidgetMut<Self>,
+        on_disconnect: impl FnMut(usize) + 'static,
+    ) {
+        this.widget.on_disconnect = Box::new(on_disconnect);
+    }
+
+    /// Set the callback called with the selected nodes when the user deletes them.
+    pub fn set_on_delete(this: &mut WidgetMut<Self>, on_delete: impl FnMut(&[usize]) + 'static) {
+        this.widget.on_delete = Box::new(on_delete);
+    }
+
+    /// Set the callback called with the selected nodes when the selection changes.
+    pub fn set_on_select(this: &mut WidgetMut<Self>, on_select: impl FnMut(&[usize]) + 'static) {
+        this.widget.on_select = Box::new(on_select);
+    }
+
+    fn port_position(&self, canvas: &InfiniteCanvas, node: usize, port: usize) -> Option<Point> {
+        let bounds = canvas.get_bounds(node)?;
+        let ports = self.ports.get(node)?;
+        let direction = ports.get(port)?.direction;
+
+        // ports are spread evenly along the edge of their direction
+        let count = ports.iter().filter(|p| p.direction == direction).count();
+        let nth = ports[..port]
+            .iter()
+            .filter(|p| p.direction == direction)
+            .count();
+
+        let x = match direction {
+            PortDirection::Input => bounds.min.x,
+            PortDirection::Output => bounds.max.x,
+        };
+
+        let y = bounds.min.y + bounds.height() * (nth + 1) as f32 / (count + 1) as f32;
+
+        Some(canvas.to_local(Point::new(x, y)))
+    }
+
+    fn port_at(&self, canvas: &InfiniteCanvas, point: Point) -> Option<(usize, usize)> {
+        let reach = self.port_radius * 2.0;
+
+        for (node, ports) in self.ports.iter().enumerate().rev() {
+            for port in 0..ports.len() {
+                if let Some(position) = self.port_position(canvas, node, port)
+                    && position.distance(point) <= reach
+                {
+                    return Some((node, port));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn node_at(canvas: &InfiniteCanvas, point: Point) -> Option<usize> {
+        let point = canvas.to_plane(point);
+
+        (0..canvas.len()).rev().find(|&node| {
+            canvas
+                .get_bounds(node)
+                .is_some_and(|bounds| bounds.contains(point))
+        })
+    }
+
+    fn nodes_in(canvas: &InfiniteCanvas, rect: Rect) -> Vec<usize> {
+        let rect = Rect {
+            min: canvas.to_plane(rect.min),
+            max: canvas.to_plane(rect.max),
+        };
+
+        (0..canvas.len())
+            .filter(|&node| {
+                canvas.get_bounds(node).is_some_and(|bounds| {
+                    let overlap = bounds.intersection(rect);
+                    overlap.min.x <= overlap.max.x && overlap.min.y <= overlap.max.y
+                })
+            })
+            .collect()
+    }
+
+    fn wire_points(&self, canvas: &InfiniteCanvas, from: Point, to: Point) -> [Point; 4] {
+        let bend = f32::max(
+            (to.x - from.x).abs() / 2.0,
+            40.0 * canvas.zoom(),
+        );
+
+        [
+            from,
+            from + Offset::new(bend, 0.0),
+            to - Offset::new(bend, 0.0),
+            to,
+        ]
+    }
+
+    fn wire_at(&self, canvas: &InfiniteCanvas, point: Point) -> Option<usize> {
+        let reach = self.wire_width / 2.0 + 4.0;
+
+        self.wires.iter().rposition(|wire| {
+            let (Some(from), Some(to)) = (
+                self.port_position(canvas, wire.from.0, wire.from.1),
+                self.port_position(canvas, wire.to.0, wire.to.1),
+            ) else {
+                return false;
+            };
+
+            let points = sample_bezier(self.wire_points(canvas, from, to));
+            points
+                .windows(2)
+                .any(|segment| segment_distance(segment[0], segment[1], point) <= reach)
+        })
+    }
+
+    fn connect(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let (Some(port_a), Some(port_b)) = (
+            self.ports.get(a.0).and_then(|ports| ports.get(a.1)),
+            self.ports.get(b.0).and_then(|ports| ports.get(b.1)),
+        ) else {
+            return;
+        };
+
+        if a.0 == b.0 || port_a.kind != port_b.kind || port_a.direction == port_b.direction {
+            return;
+        }
+
+        let wire = match port_a.direction {
+            PortDirection::Output => Wire { from: a, to: b },
+            PortDirection::Input => Wire { from: b, to: a },
+        };
+
+        if !self.wires.contains(&wire) {
+            (self.on_connect)(wire);
+        }
+    }
+
+    fn select(&mut self, cx: &mut EventCx<'_>, selected: Vec<usize>) {
+        if self.selected != selected {
+            self.selected = selected;
+            (self.on_select)(&self.selected);
+        }
+
+        cx.request_draw();
+    }
+
+    fn draw_wire(&self, canvas: &mut dyn Canvas, points: [Point; 4], width: f32, paint: &Paint) {
+        // strokes are drawn as filled ribbons, offset along the normal of the curve
+        let points = sample_bezier(points);
+        let mut curve = Curve::new();
+
+        let normal = |i: usize| {
+            let a = points[i.saturating_sub(1)];
+            let b = points[usize::min(i + 1, points.len() - 1)];
+            let d = b - a;
+            let length = f32::max(f32::hypot(d.x, d.y), f32::EPSILON);
+
+            Offset::new(-d.y, d.x) * (width / 2.0 / length)
+        };
+
+        curve.move_to(points[0] + normal(0));
+
+        for (i, &point) in points.iter().enumerate().skip(1) {
+            curve.line_to(point + normal(i));
+        }
+
+        for (i, &point) in points.iter().enumerate().rev() {
+            curve.line_to(point - normal(i));
+        }
+
+        curve.close();
+        canvas.draw_curve(&curve, paint);
+    }
+}
+
+const WIRE_SAMPLES: usize = 24;
+
+fn sample_bezier([a, b, c, d]: [Point; 4]) -> [Point; WIRE_SAMPLES + 1] {
+    let mut points = [Point::ORIGIN; WIRE_SAMPLES + 1];
+
+    for (i, point) in points.iter_mut().enumerate() {
+        let t = i as f32 / WIRE_SAMPLES as f32;
+        let u = 1.0 - t;
+
+        let x = u * u * u * a.x + 3.0 * u * u * t * b.x + 3.0 * u * t * t * c.x + t * t * t * d.x;
+        let y = u * u * u * a.y + 3.0 * u * u * t * b.y + 3.0 * u * t * t * c.y + t * t * t * d.y;
+
+        *point = Point::new(x, y);
+    }
+
+    points
+}
+
+fn segment_distance(a: Point, b: Point, point: Point) -> f32 {
+    let ab = b - a;
+    let ap = point - a;
+
+    let length = ab.x * ab.x + ab.y * ab.y;
+    let t = match length > 0.0 {
+        true => f32::clamp(
+            (ap.x * ab.x + ap.y * ab.y) / length,
+            0.0,
+            1.0,
+        ),
+        false => 0.0,
+    };
+
+    point.distance(a + ab * t)
+}
+
+impl Widget for NodeGraph {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let size = cx.layout_nth_child(0, space);
+        cx.place_nth_child(0, Offset::ZERO);
+
+        // the wires follow the nodes and the view, both of which only change on layout
+        cx.request_draw();
+
+        size
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        let Ok(graph) = cx.get_child(self.canvas) else {
+            return;
+        };
+
+        let graph = &*graph.widget;
+
+        for (i, wire) in self.wires.iter().enumerate() {
+            let (Some(from), Some(to)) = (
+                self.port_position(graph, wire.from.0, wire.from.1),
+                self.port_position(graph, wire.to.0, wire.to.1),
+            ) else {
+                continue;
+            };
+
+            let color = match self.selected_wire == Some(i) {
+                true => self.select_color,
+                false => self.wire_color,
+            };
+
+            let points = self.wire_points(graph, from, to);
+            self.draw_wire(
+                canvas,
+                points,
+                self.wire_width * graph.zoom(),
+                &Paint::from(color),
+            );
+        }
+
+        // selected nodes are outlined, the outline is drawn beneath the node itself
+        for &node in &self.selected {
+            let Some(bounds) = graph.get_bounds(node) else {
+                continue;
+            };
+
+            let rect = Rect {
+                min: graph.to_local(bounds.min),
+                max: graph.to_local(bounds.max),
+            };
+
+            canvas.draw_border(
+                rect.expand(2.0),
+                BorderWidth::all(2.0),
+                CornerRadius::all(4.0),
+                &Paint::from(self.select_color),
+            );
+        }
+    }
+
+    fn draw_over(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        let Ok(graph) = cx.get_child(self.canvas) else {
+            return;
+        };
+
+        let graph = &*graph.widget;
+        let radius = self.port_radius * graph.zoom();
+
+        for (node, ports) in self.ports.iter().enumerate() {
+            for (i, port) in ports.iter().enumerate() {
+                if let Some(position) = self.port_position(graph, node, i) {
+                    canvas.draw_rect(
+                        Rect::min_size(
+                            position - Offset::all(radius),
+                            Size::all(radius * 2.0),
+                        ),
+                        CornerRadius::all(radius),
+                        &Paint::from(port.color),
+                    );
+                }
+            }
+        }
+
+        match self.drag {
+            Some((_, Drag::Wire((node, port), end))) => {
+                let Some(start) = self.port_position(graph, node, port) else {
+                    return;
+                };
+
+                let (from, to) = match self.ports[node][port].direction {
+                    PortDirection::Output => (start, end),
+                    PortDirection::Input => (end, start),
+                };
+
+                let points = self.wire_points(graph, from, to);
+                self.draw_wire(
+                    canvas,
+                    points,
+                    self.wire_width * graph.zoom(),
+                    &Paint::from(self.ports[node][port].color),
+                );
+            }
+
+            Some((_, Drag::Marquee(start, end))) => {
+                let rect = Rect {
+                    min: start.min(end),
+                    max: start.max(end),
+                };
+
+                canvas.draw_rect(
+                    rect,
+                    CornerRadius::all(0.0),
+                    &Paint::from(self.marquee_color),
+                );
+            }
+
+            _ => {}
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let transform = cx.global_transform().inverse();
+
+        match event {
+            PointerEvent::Down(event)
+                if event.button == PointerButton::Primary && self.drag.is_none() =>
+            {
+                let local = transform * event.position;
+
+                let Ok((port, node, wire)) = cx.get_child(self.canvas).map(|graph| {
+                    (
+                        self.port_at(&graph.widget, local),
+                        Self::node_at(&graph.widget, local),
+                        self.wire_at(&graph.widget, local),
+                    )
+                }) else {
+                    return PointerPropagate::Bubble;
+                };
+
+                let modifiers = cx.get_window().map_or(Modifiers::empty(), |window| {
+                    window.pointer_modifiers(event.pointer)
+                });
+
+                cx.request_focus();
+                self.selected_wire = None;
+
+                if let Some(port) = port {
+                    self.drag = Some((event.pointer, Drag::Wire(port, local)));
+                } else if let Some(node) = node {
+                    let mut selected = self.selected.clone();
+
+                    if modifiers.shift() {
+                        match selected.iter().position(|&n| n == node) {
+                            Some(i) => {
+                                selected.remove(i);
+                            }
+                            None => selected.push(node),
+                        }
+                    } else if !selected.contains(&node) {
+                        selected = vec![node];
+                    }
+
+                    self.select(cx, selected);
+                    self.drag = Some((event.pointer, Drag::Nodes(local)));
+                } else if let Some(wire) = wire {
+                    self.selected_wire = Some(wire);
+                } else {
+                    if !modifiers.shift() {
+                        self.select(cx, Vec::new());
+                    }
+
+                    self.drag = Some((
+                        event.pointer,
+                        Drag::Marquee(local, local),
+                    ));
+                }
+
+                cx.request_draw();
+
+                PointerPropagate::Capture
+            }
+
+            PointerEvent::Move(event) => {
+                let local = transform * event.position;
+
+                let drag = match self.drag {
+                    // only the pointer that started the drag moves it
+                    Some((pointer, ref mut drag)) if pointer == event.pointer => drag,
+                    _ => return PointerPropagate::Bubble,
+                };
+
+                match drag {
+                    Drag::Nodes(previous) => {
+                        let delta = local - *previous;
+                        *previous = local;
+
+                        let Ok(mut graph) = cx.get_child_mut(self.canvas) else {
+                            return PointerPropagate::Bubble;
+                        };
+
+                        let delta = delta / graph.widget.zoom();
+
+                        for &node in &self.selected {
+                            if node >= graph.widget.len() {
+                                continue;
+                            }
+
+                            let position = graph.widget.get_position(node) + delta;
+                            InfiniteCanvas::set_position(&mut graph, node, position);
+                            (self.on_move)(node, position);
+                        }
+                    }
+
+                    Drag::Wire(_, end) | Drag::Marquee(_, end) => {
+                        *end = local;
+                        cx.request_draw();
+                    }
+                }
+
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Up(event) if event.button == PointerButton::Primary => {
+                let local = transform * event.position;
+
+                let Some((_, drag)) = self.drag.take_if(|(pointer, _)| *pointer == event.pointer)
+                else {
+                    return PointerPropagate::Bubble;
+                };
+
+                match drag {
+                    Drag::Wire(from, _) => {
+                        let to = cx
+                            .get_child(self.canvas)
+                            .ok()
+                            .and_then(|graph| self.port_at(&graph.widget, local));
+
+                        if let Some(to) = to {
+                            self.connect(from, to);
+                        }
+                    }
+
+                    Drag::Marquee(start, end) => {
+                        let marquee = Rect {
+                            min: start.min(end),
+                            max: start.max(end),
+                        };
+
+                        let nodes = cx.get_child(self.canvas).map_or(Vec::new(), |graph| {
+                            Self::nodes_in(&graph.widget, marquee)
+                        });
+
+                        let mut selected = self.selected.clone();
+
+                        for node in nodes {
+                            if !selected.contains(&node) {
+                                selected.push(node);
+                            }
+                        }
+
+                        self.select(cx, selected);
+                    }
+
+                    Drag::Nodes(..) => {}
+                }
+
+                cx.request_draw();
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn on_key_event(&mut self, cx: &mut EventCx<'_>, event: &KeyEvent) -> Propagate {
+        let KeyEvent::Down(event) = event else {
+            return Propagate::Bubble;
+        };
+
+        match event.key {
+            Key::Named(NamedKey::Delete | NamedKey::Backspace) => {
+                if let Some(wire) = self.selected_wire.take() {
+                    (self.on_disconnect)(wire);
+                } else if !self.selected.is_empty() {
+                    let selected = std::mem::take(&mut self.selected);
+                    (self.on_delete)(&selected);
+                    (self.on_select)(&self.selected);
+                } else {
+                    return Propagate::Bubble;
+                }
+
+                cx.request_draw();
+                Propagate::Handled
+            }
+
+            Key::Named(NamedKey::Escape) if !self.selected.is_empty() => {
+                self.select(cx, Vec::new());
+                Propagate::Handled
+            }
+
+            _ => Propagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+
+    fn accepts_focus() -> bool {
+        true
+    }
+}
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use crate::{
+    AnyWidgetId, Builder, ComposeCx, LayoutCx, Size, Space, Transition, Transitioned, UpdateCx,
+    Widget, WidgetMut,
+};
+
+pub struct Opacity {
+    opacity: Transitioned<f32>,
+}
+
+impl Opacity {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            opacity: Transitioned::new(1.0, Transition::INSTANT),
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    pub fn set_opacity(this: &mut WidgetMut<'_, Self>, opacity: f32) {
+        this.cx.request_compose();
+
+        if this.widget.opacity.begin(opacity) {
+            this.cx.request_animate();
+        }
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<'_, Self>, transition: Transition) {
+        this.widget.opacity.set_transition(transition);
+    }
+}
+
+impl Widget for Opacity {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+
+    fn compose(&mut self, cx: &mut ComposeCx<'_>) {
+        cx.set_opacity(*self.opacity);
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_compose();
+
+        if self.opacity.animate(dt) {
+            cx.request_animate();
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub struct Pad {
 }
 
 impl Pad {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
         cx.build_widget(Pad {
             padding: Padding::all(8.0),
@@ -23,7 +24,7 @@ impl Widget for Pad {
     fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
         let space = self.padding.layout_down(cx, space);
         let size = cx.layout_nth_child(0, space);
-        cx.place_nth_child(0, self.padding.aligned_offset(cx));
+        cx.place_nth_child(0, self.padding.leading_offset(cx));
         self.padding.layout_up(cx, size)
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    Affine, Blend, Builder, Canvas, Color, DrawCx, LayoutCx, Offset, Paint, Shader, Size, Space,
-    Svg, SvgData, Widget, WidgetMut,
+    Affine, Blend, Builder, Canvas, Color, DrawCx, LayerOptions, LayoutCx, Offset, Paint, Shader,
+    Size, Space, Svg, SvgData, SvgStyle, Widget, WidgetMut,
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -33,14 +33,17 @@ pub struct Picture {
     contents: Picturable,
     fit:      Fit,
     color:    Option<Color>,
+    style:    SvgStyle,
 }
 
 impl Picture {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, contents: Picturable) -> WidgetMut<'_, Self> {
         cx.build_widget(Self {
             contents,
             fit: Fit::None,
             color: None,
+            style: SvgStyle::default(),
         })
         .finish()
     }
@@ -59,6 +62,14 @@ impl Picture {
         this.widget.color = color;
         this.cx.request_draw();
     }
+
+    /// Set the style svgs are drawn with, e.g. the color of `currentColor`.
+    ///
+    /// Unlike [`Picture::set_color`], this only recolors the parts of the svg chosen by `style`.
+    pub fn set_svg_style(this: &mut WidgetMut<Self>, style: SvgStyle) {
+        this.widget.style = style;
+        this.cx.request_draw();
+    }
 }
 
 impl Widget for Picture {
@@ -107,22 +118,25 @@ impl Widget for Picture {
 
         match self.contents {
             Picturable::Svg(ref svg) => {
-                canvas.layer(&mut |canvas| {
-                    let transform = Affine::scale_translate(sx, sy, offset);
-                    canvas.transform(transform, &mut |canvas| {
-                        canvas.draw_svg(svg)
-                    });
-
-                    if let Some(color) = self.color {
-                        let paint = Paint {
-                            shader: Shader::Solid(color),
-                            blend: Blend::SrcIn,
-                            ..Default::default()
-                        };
-
-                        canvas.fill(&paint);
-                    }
-                });
+                canvas.layer(
+                    &LayerOptions::default(),
+                    &mut |canvas| {
+                        let transform = Affine::scale_translate(sx, sy, offset);
+                        canvas.transform(transform, &mut |canvas| {
+                            canvas.draw_svg(svg, &self.style)
+                        });
+
+                        if let Some(color) = self.color {
+                            let paint = Paint {
+                                shader: Shader::Solid(color),
+                                blend: Blend::SrcIn,
+                                ..Default::default()
+                            };
+
+                            canvas.fill(&paint);
+                        }
+                    },
+                );
             }
         }
     }
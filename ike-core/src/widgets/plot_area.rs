@@ -0,0 +1,327 @@
+use crate::{
+    AnyWidgetId, Builder, Canvas, Color, CornerRadius, DrawCx, EventCx, Gesture, LayoutCx, Offset,
+    Padding, Paint, Paragraph, Point, PointerButton, PointerEvent, PointerId, PointerPropagate,
+    Rect, ScrollDelta, Size, Space, TouchEvent, TouchPropagate, Update, UpdateCx, Widget,
+    WidgetMut,
+};
+
+/// The interactive area of a chart.
+///
+/// Draws a crosshair at the pointer, highlights the data point nearest to it along with a
+/// tooltip, lets the user brush a horizontal range by dragging, and reports scrolling and
+/// pinching as zoom. The contents are expected to draw the chart itself, and `points` should be
+/// set to the positions of its data points, in the local coordinates of the plot area.
+pub struct PlotArea {
+    points:          Vec<Point>,
+    snap_distance:   f32,
+    tooltip:         Option<Paragraph>,
+    tooltip_padding: Padding,
+    tooltip_size:    Size,
+
+    crosshair_color: Color,
+    point_color:     Color,
+    brush_color:     Color,
+    tooltip_color:   Color,
+
+    on_hover: Box<dyn FnMut(Option<usize>)>,
+    on_brush: Box<dyn FnMut(f32, f32)>,
+    on_zoom:  Box<dyn FnMut(f32, Point)>,
+
+    pointer:  Option<Point>,
+    nearest:  Option<usize>,
+    brush:    Option<(f32, f32)>,
+    brushing: Option<PointerId>,
+}
+
+impl PlotArea {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, contents: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            points:          Vec::new(),
+            snap_distance:   32.0,
+            tooltip:         None,
+            tooltip_padding: Padding::all(4.0),
+            tooltip_size:    Size::ZERO,
+
+            crosshair_color: Color::BLACK,
+            point_color:     Color::BLUE,
+            brush_color:     Color::BLUE.fade(0.2),
+            tooltip_color:   Color::WHITE,
+
+            on_hover: Box::new(|_| {}),
+            on_brush: Box::new(|_, _| {}),
+            on_zoom:  Box::new(|_, _| {}),
+
+            pointer:  None,
+            nearest:  None,
+            brush:    None,
+            brushing: None,
+        })
+        .with_child(contents)
+        .finish()
+    }
+
+    /// Set the positions of the data points, used to find the point nearest the pointer.
+    pub fn set_points(this: &mut WidgetMut<Self>, points: Vec<Point>) {
+        this.widget.points = points;
+        this.widget.nearest = None;
+        this.cx.request_draw();
+    }
+
+    /// Set the maximum distance from the pointer a data point can be hovered at.
+    pub fn set_snap_distance(this: &mut WidgetMut<Self>, distance: f32) {
+        this.widget.snap_distance = distance;
+    }
+
+    /// Set the tooltip shown next to the hovered data point.
+    pub fn set_tooltip(this: &mut WidgetMut<Self>, tooltip: Option<Paragraph>) {
+        this.widget.tooltip = tooltip;
+        this.cx.request_layout();
+        this.cx.request_draw();
+    }
+
+    pub fn set_tooltip_padding(this: &mut WidgetMut<Self>, padding: Padding) {
+        this.widget.tooltip_padding = padding;
+        this.cx.request_layout();
+    }
+
+    pub fn set_crosshair_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.crosshair_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_point_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.point_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_brush_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.brush_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_tooltip_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.tooltip_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Set the callback called when the hovered data point changes.
+    pub fn set_on_hover(this: &mut WidgetMut<Self>, on_hover: impl FnMut(Option<usize>) + 'static) {
+        this.widget.on_hover = Box::new(on_hover);
+    }
+
+    /// Set the callback called with the `start` and `end` of a brushed range.
+    pub fn set_on_brush(this: &mut WidgetMut<Self>, on_brush: impl FnMut(f32, f32) + 'static) {
+        this.widget.on_brush = Box::new(on_brush);
+    }
+
+    /// Set the callback called with a zoom factor, and the point to zoom around.
+    pub fn set_on_zoom(this: &mut WidgetMut<Self>, on_zoom: impl FnMut(f32, Point) + 'static) {
+        this.widget.on_zoom = Box::new(on_zoom);
+    }
+
+    fn set_pointer(&mut self, cx: &mut EventCx<'_>, pointer: Option<Point>) {
+        let nearest = pointer.and_then(|pointer| {
+            let (index, distance) = self
+                .points
+                .iter()
+                .map(|point| point.distance(pointer))
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+            (distance <= self.snap_distance).then_some(index)
+        });
+
+        self.pointer = pointer;
+        cx.request_draw();
+
+        if nearest != self.nearest {
+            self.nearest = nearest;
+            (self.on_hover)(nearest);
+        }
+    }
+}
+
+impl Widget for PlotArea {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        if let Some(ref tooltip) = self.tooltip {
+            self.tooltip_size =
+                cx.measure_text(tooltip, f32::INFINITY) + self.tooltip_padding.size();
+        }
+
+        let size = cx.layout_nth_child(0, space);
+        cx.place_nth_child(0, Offset::ZERO);
+
+        space.constrain(size)
+    }
+
+    fn draw_over(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        if let Some((start, end)) = self.brush {
+            let brush = Rect {
+                min: Point::new(start.min(end), 0.0),
+                max: Point::new(start.max(end), cx.size().height),
+            };
+
+            canvas.draw_rect(
+                brush,
+                CornerRadius::all(0.0),
+                &Paint::from(self.brush_color),
+            );
+        }
+
+        let Some(pointer) = self.pointer else {
+            return;
+        };
+
+        let size = cx.size();
+
+        canvas.draw_rect(
+            Rect::min_size(
+                Point::new(pointer.x, 0.0),
+                Size::new(1.0, size.height),
+            ),
+            CornerRadius::all(0.0),
+            &Paint::from(self.crosshair_color),
+        );
+
+        canvas.draw_rect(
+            Rect::min_size(
+                Point::new(0.0, pointer.y),
+                Size::new(size.width, 1.0),
+            ),
+            CornerRadius::all(0.0),
+            &Paint::from(self.crosshair_color),
+        );
+
+        let Some(point) = self.nearest.and_then(|index| self.points.get(index)) else {
+            return;
+        };
+
+        canvas.draw_rect(
+            Rect::min_size(
+                *point - Offset::all(4.0),
+                Size::all(8.0),
+            ),
+            CornerRadius::all(4.0),
+            &Paint::from(self.point_color),
+        );
+
+        if let Some(ref tooltip) = self.tooltip {
+            // place the tooltip above and to the right of the point, unless it doesn't fit
+            let mut min = *point + Offset::new(8.0, -8.0 - self.tooltip_size.height);
+
+            if min.x + self.tooltip_size.width > size.width {
+                min.x = point.x - 8.0 - self.tooltip_size.width;
+            }
+
+            if min.y < 0.0 {
+                min.y = point.y + 8.0;
+            }
+
+            let rect = Rect::min_size(min, self.tooltip_size);
+
+            canvas.draw_rect(
+                rect,
+                CornerRadius::all(4.0),
+                &Paint::from(self.tooltip_color),
+            );
+
+            canvas.draw_text(
+                tooltip,
+                f32::INFINITY,
+                rect.min - Point::ORIGIN + self.tooltip_padding.offset(),
+            );
+        }
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::Hovered(false) = update
+            && self.brush.is_none()
+        {
+            self.pointer = None;
+            cx.request_draw();
+
+            if self.nearest.take().is_some() {
+                (self.on_hover)(None);
+            }
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let transform = cx.global_transform().inverse();
+
+        match event {
+            PointerEvent::Move(event) => {
+                let local = transform * event.position;
+                self.set_pointer(cx, Some(local));
+
+                if let Some((_, ref mut end)) = self.brush
+                    && self.brushing == Some(event.pointer)
+                {
+                    *end = local.x.clamp(0.0, cx.size().width);
+                }
+
+                PointerPropagate::Bubble
+            }
+
+            PointerEvent::Down(event)
+                if event.button == PointerButton::Primary && self.brush.is_none() =>
+            {
+                let local = transform * event.position;
+                self.brush = Some((local.x, local.x));
+                self.brushing = Some(event.pointer);
+                cx.request_draw();
+
+                PointerPropagate::Capture
+            }
+
+            PointerEvent::Up(event)
+                if event.button == PointerButton::Primary
+                    && self.brushing == Some(event.pointer) =>
+            {
+                self.brushing = None;
+
+                if let Some((start, end)) = self.brush.take()
+                    && (end - start).abs() > 2.0
+                {
+                    (self.on_brush)(start.min(end), start.max(end));
+                }
+
+                cx.request_draw();
+
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Scroll(event) => {
+                let delta = match event.delta {
+                    ScrollDelta::Line(offset) => offset.y,
+                    ScrollDelta::Pixel(offset) => offset.y / 120.0,
+                };
+
+                let local = transform * event.position;
+                (self.on_zoom)(f32::powf(1.1, delta), local);
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn on_touch_event(&mut self, cx: &mut EventCx<'_>, event: &TouchEvent) -> TouchPropagate {
+        match event {
+            TouchEvent::Gesture(Gesture::Pinch(event)) => {
+                let local = cx.global_transform().inverse() * event.focal;
+                (self.on_zoom)(event.delta, local);
+
+                TouchPropagate::Capture
+            }
+
+            _ => TouchPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    AnyWidgetId, Builder, Canvas, Color, CornerRadius, DrawCx, LayoutCx, Paint, Size, Space,
+    Update, UpdateCx, Widget, WidgetMut,
+};
+
+/// Replaces its contents by a placeholder block while they're redacted, see
+/// [`World::set_redacted`](crate::World::set_redacted).
+///
+/// The contents keep their size, and aren't drawn or interacted with while redacted.
+pub struct Redact {
+    color:         Color,
+    corner_radius: CornerRadius,
+}
+
+impl Redact {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            color:         Color::BLACK,
+            corner_radius: CornerRadius::all(4.0),
+        })
+        .with_child(child)
+        .finish()
+    }
+
+    pub fn set_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_corner_radius(this: &mut WidgetMut<Self>, corner_radius: CornerRadius) {
+        this.widget.corner_radius = corner_radius;
+        this.cx.request_draw();
+    }
+}
+
+impl Widget for Redact {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let is_redacted = cx.is_redacted();
+        cx.set_child_stashed(0, is_redacted);
+        cx.layout_nth_child(0, space)
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        if cx.is_redacted() {
+            canvas.draw_rect(
+                cx.rect(),
+                self.corner_radius,
+                &Paint::from(self.color),
+            );
+        }
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::Added | Update::WindowRedacted(..) = update {
+            cx.request_layout();
+            cx.request_draw();
+        }
+    }
+}
@@ -6,13 +6,20 @@ use crate::{
 };
 
 pub struct SafeArea {
-    insets: Transitioned<Padding>,
+    insets:         Transitioned<Padding>,
+    window_insets:  Padding,
+    keyboard:       f32,
+    avoid_keyboard: bool,
 }
 
 impl SafeArea {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
         cx.build_widget(SafeArea {
-            insets: Transitioned::new(Padding::all(0.0), Transition::INSTANT),
+            insets:         Transitioned::new(Padding::all(0.0), Transition::INSTANT),
+            window_insets:  Padding::all(0.0),
+            keyboard:       0.0,
+            avoid_keyboard: true,
         })
         .with_child(child)
         .finish()
@@ -21,6 +28,30 @@ impl SafeArea {
     pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
         this.widget.insets.set_transition(transition);
     }
+
+    /// Set whether the bottom inset grows to avoid the on-screen keyboard, on by default.
+    ///
+    /// Turn this off to only avoid the keyboard with an [`ImePadding`](super::ImePadding) further
+    /// down the tree.
+    pub fn set_avoid_keyboard(this: &mut WidgetMut<Self>, avoid_keyboard: bool) {
+        this.widget.avoid_keyboard = avoid_keyboard;
+
+        if this.widget.update_insets() {
+            this.cx.request_animate();
+        }
+
+        this.cx.request_layout();
+    }
+
+    fn update_insets(&mut self) -> bool {
+        let mut insets = self.window_insets;
+
+        if self.avoid_keyboard {
+            insets.bottom = insets.bottom.max(self.keyboard);
+        }
+
+        self.insets.begin(insets)
+    }
 }
 
 impl Widget for SafeArea {
@@ -43,12 +74,16 @@ impl Widget for SafeArea {
     }
 
     fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
-        if let Update::WindowInset(insets) = update {
-            cx.request_layout();
+        match update {
+            Update::WindowInset(insets) => self.window_insets = insets,
+            Update::WindowKeyboard(height) => self.keyboard = height,
+            _ => return,
+        }
 
-            if self.insets.begin(insets) {
-                cx.request_animate();
-            }
+        cx.request_layout();
+
+        if self.update_insets() {
+            cx.request_animate();
         }
     }
 }
@@ -1,22 +1,79 @@
 use std::time::Duration;
 
 use crate::{
-    AnyWidgetId, Axis, BorderWidth, Builder, Canvas, Color, ComposeCx, CornerRadius, DrawCx,
-    EventCx, Gesture, LayoutCx, Offset, Padding, Paint, Point, PointerButton, PointerEvent,
-    PointerPropagate, Rect, ScrollDelta, Size, Space, TouchEvent, TouchPropagate, Transition,
-    Transitioned, Update, UpdateCx, Widget, WidgetId, WidgetMut,
+    Affine, AnimationSettings, AnyWidgetId, Axis, BorderWidth, Builder, Canvas, Clip, Color,
+    ComposeCx, CornerRadius, DrawCx, EventCx, Gesture, LayoutCx, Offset, Padding, Paint, Point,
+    PointerButton, PointerEvent, PointerId, PointerMoveEvent, PointerPropagate, Recording, Rect,
+    ScrollDelta, Size, Space, TouchEvent, TouchPropagate, Transition, Transitioned, Update,
+    UpdateCx, Widget, WidgetId, WidgetMut,
 };
 
+/// What a [`Scroll`] does when its contents are dragged or flung past their edges.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Overscroll {
+    /// Stop at the edges.
+    #[default]
+    None,
+
+    /// Let the contents follow with increasing resistance, and spring back when released.
+    Bounce,
+
+    /// Show a glow at the edge, growing the further the contents are pulled.
+    Glow,
+}
+
+/// Where [`Scroll::scroll_to`] scrolls the contents of a [`Scroll`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrollTarget {
+    /// Scroll to an offset from the top left of the contents.
+    Offset(Offset),
+
+    /// Scroll to a fraction of the scrollable distance along each axis, `(0, 1)` is the bottom.
+    Fraction(Offset),
+
+    /// Scroll the `n`th child of the contents into view.
+    Child(usize),
+
+    /// Scroll a widget inside the contents into view.
+    Widget(WidgetId),
+}
+
+impl ScrollTarget {
+    pub const START: Self = Self::Fraction(Offset::new(0.0, 0.0));
+    pub const END: Self = Self::Fraction(Offset::new(0.0, 1.0));
+}
+
 pub struct Scroll {
-    portal:  WidgetId<Portal>,
-    vbar:    WidgetId<ScrollBar>,
-    hbar:    WidgetId<ScrollBar>,
-    overlay: bool,
+    portal:   WidgetId<Portal>,
+    vbar:     WidgetId<ScrollBar>,
+    hbar:     WidgetId<ScrollBar>,
+    map:      WidgetId<Minimap>,
+    vertical: bool,
+    minimap:  bool,
+    overlay:  bool,
+    laid_out: bool,
 
     scroll: Transitioned<Offset>,
+    target: Option<(ScrollTarget, bool)>,
+
+    kinetic:    bool,
+    friction:   f32,
+    overscroll: Overscroll,
+    glow_color: Color,
+
+    /// The fling velocity in points per second.
+    velocity:    Offset,
+    /// How far the contents are pulled past the edges, before resistance is applied.
+    pull:        Offset,
+    is_dragging: bool,
+
+    /// The last move of a pointer captured by the contents, used to scroll when it's dragged
+    /// past the edges.
+    auto_scroll: Option<PointerMoveEvent>,
 }
 
 impl Scroll {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
         let portal = cx
             .build_widget(Portal {
@@ -39,22 +96,47 @@ impl Scroll {
             .finish()
             .id();
 
+        let map = cx.build_widget(Minimap::new(portal)).finish().id();
+
         cx.set_stashed(hbar, true);
+        cx.set_stashed(map, true);
 
         cx.build_widget(Self {
             vbar,
             hbar,
             portal,
+            map,
+            vertical: true,
+            minimap: false,
             overlay: false,
+            laid_out: false,
 
             scroll: Transitioned::new(Offset::ZERO, Transition::ease(0.25)),
+            target: None,
+
+            kinetic: true,
+            friction: 0.1,
+            overscroll: Overscroll::None,
+            glow_color: Color::BLACK.fade(0.2),
+
+            velocity: Offset::ZERO,
+            pull: Offset::ZERO,
+            is_dragging: false,
+
+            auto_scroll: None,
         })
         .with_child(portal)
         .with_child(vbar)
         .with_child(hbar)
+        .with_child(map)
         .finish()
     }
 
+    /// The offset the contents are currently scrolled to.
+    pub fn offset(&self) -> Offset {
+        *self.scroll
+    }
+
     pub fn is_child(cx: &impl Builder, this: WidgetId<Self>, child: impl AnyWidgetId) -> bool {
         cx.get_widget(this)
             .is_ok_and(|this| cx.is_child(this.widget.portal, child))
@@ -72,27 +154,73 @@ impl Scroll {
     }
 
     pub fn set_vertical(this: &mut WidgetMut<Self>, enabled: bool) {
-        if let Ok(mut vbar) = this.cx.get_widget_mut(this.widget.vbar) {
-            vbar.set_stashed(!enabled);
+        this.widget.vertical = enabled;
+        Self::update_vertical(this);
+    }
+
+    pub fn set_horizontal(this: &mut WidgetMut<Self>, enabled: bool) {
+        if let Ok(mut hbar) = this.cx.get_widget_mut(this.widget.hbar) {
+            hbar.set_stashed(!enabled);
         }
 
         if let Ok(mut portal) = this.cx.get_widget_mut(this.widget.portal) {
-            portal.widget.loosen_height = enabled;
+            portal.widget.loosen_width = enabled;
             portal.cx.request_layout();
         }
     }
 
-    pub fn set_horizontal(this: &mut WidgetMut<Self>, enabled: bool) {
-        if let Ok(mut hbar) = this.cx.get_widget_mut(this.widget.hbar) {
-            hbar.set_stashed(!enabled);
+    /// Replace the vertical scroll bar with a minimap, a scaled down preview of the contents
+    /// showing the visible area, which can be clicked and dragged to scroll.
+    ///
+    /// The preview is redrawn when the contents are laid out, changes that only affect how the
+    /// contents are drawn are not reflected until then.
+    pub fn set_minimap(this: &mut WidgetMut<Self>, enabled: bool) {
+        this.widget.minimap = enabled;
+
+        if let Ok(mut map) = this.cx.get_widget_mut(this.widget.map) {
+            map.set_stashed(!enabled);
+        }
+
+        Self::update_vertical(this);
+    }
+
+    /// The minimap replaces the vertical scroll bar, and scrolls vertically even when the
+    /// scroll bar is disabled.
+    fn update_vertical(this: &mut WidgetMut<Self>) {
+        let vertical = this.widget.vertical;
+        let minimap = this.widget.minimap;
+
+        if let Ok(mut vbar) = this.cx.get_widget_mut(this.widget.vbar) {
+            vbar.set_stashed(!vertical || minimap);
         }
 
         if let Ok(mut portal) = this.cx.get_widget_mut(this.widget.portal) {
-            portal.widget.loosen_width = enabled;
+            portal.widget.loosen_height = vertical || minimap;
             portal.cx.request_layout();
         }
     }
 
+    pub fn set_minimap_width(this: &mut WidgetMut<Self>, width: f32) {
+        if let Ok(mut map) = this.cx.get_widget_mut(this.widget.map) {
+            map.widget.width = width;
+            map.cx.request_layout();
+        }
+    }
+
+    pub fn set_minimap_paint(this: &mut WidgetMut<Self>, paint: Paint) {
+        if let Ok(mut map) = this.cx.get_widget_mut(this.widget.map) {
+            map.widget.background_paint = paint;
+            map.cx.request_draw();
+        }
+    }
+
+    pub fn set_minimap_viewport_paint(this: &mut WidgetMut<Self>, paint: Paint) {
+        if let Ok(mut map) = this.cx.get_widget_mut(this.widget.map) {
+            map.widget.viewport_paint = paint;
+            map.cx.request_draw();
+        }
+    }
+
     pub fn set_bar_thickness(this: &mut WidgetMut<Self>, thickness: f32) {
         if let Ok(mut vbar) = this.cx.get_widget_mut(this.widget.vbar) {
             vbar.widget.thickness = thickness;
@@ -192,6 +320,323 @@ impl Scroll {
     pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
         this.widget.scroll.set_transition(transition);
     }
+
+    /// Scroll to `target`, with the transition of the scroll if `animate` is `true`.
+    ///
+    /// The target is resolved once the contents have been laid out, so scrolling to
+    /// [`ScrollTarget::END`] right after adding contents scrolls to the new end.
+    pub fn scroll_to(this: &mut WidgetMut<Self>, target: ScrollTarget, animate: bool) {
+        this.widget.target = Some((target, animate));
+        this.cx.request_animate();
+        this.cx.request_compose();
+    }
+
+    /// Set whether releasing a pan keeps scrolling with its velocity, defaults to `true`.
+    pub fn set_kinetic(this: &mut WidgetMut<Self>, enabled: bool) {
+        this.widget.kinetic = enabled;
+
+        if !enabled {
+            this.widget.velocity = Offset::ZERO;
+        }
+    }
+
+    /// Set the fraction of the fling velocity kept after a second, defaults to `0.1`.
+    pub fn set_friction(this: &mut WidgetMut<Self>, friction: f32) {
+        this.widget.friction = friction.clamp(0.0, 1.0);
+    }
+
+    pub fn set_overscroll(this: &mut WidgetMut<Self>, overscroll: Overscroll) {
+        this.widget.overscroll = overscroll;
+
+        if let Overscroll::None = overscroll {
+            this.widget.pull = Offset::ZERO;
+        }
+
+        this.cx.request_compose();
+        this.cx.request_draw();
+    }
+
+    /// Set the color of the glow of [`Overscroll::Glow`].
+    pub fn set_glow_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.glow_color = color;
+        this.cx.request_draw();
+    }
+}
+
+impl Scroll {
+    /// The scroll needed to bring `target`, relative to the visible area, into view.
+    fn reveal(&self, mut target: Rect, size: Size, overflow: Size) -> Offset {
+        target.min += Offset::all(-8.0);
+        target.max += Offset::all(8.0);
+
+        let mut x = if target.max.x - target.min.x > size.width {
+            target.center().x - size.width / 2.0
+        } else if target.min.x < 0.0 {
+            target.min.x
+        } else if target.max.x > size.width {
+            target.max.x - size.width
+        } else {
+            0.0
+        };
+
+        let mut y = if target.max.y - target.min.y > size.height {
+            target.center().y - size.height / 2.0
+        } else if target.min.y < 0.0 {
+            target.min.y
+        } else if target.max.y > size.height {
+            target.max.y - size.height
+        } else {
+            0.0
+        };
+
+        x += self.scroll.x;
+        y += self.scroll.y;
+        x = x.clamp(0.0, overflow.width);
+        y = y.clamp(0.0, overflow.height);
+
+        Offset::new(x, y)
+    }
+
+    fn resolve_target(&self, cx: &ComposeCx<'_>, target: ScrollTarget) -> Option<Offset> {
+        let portal = cx.get_child(self.portal).ok()?;
+        let overflow = portal.widget.overflow;
+        let contents = *portal.cx.children().first()?;
+
+        let widget = match target {
+            ScrollTarget::Offset(offset) => {
+                let x = offset.x.clamp(0.0, overflow.width);
+                let y = offset.y.clamp(0.0, overflow.height);
+                return Some(Offset::new(x, y));
+            }
+
+            ScrollTarget::Fraction(fraction) => {
+                let x = fraction.x.clamp(0.0, 1.0) * overflow.width;
+                let y = fraction.y.clamp(0.0, 1.0) * overflow.height;
+                return Some(Offset::new(x, y));
+            }
+
+            ScrollTarget::Child(index) => {
+                let contents = cx.get_widget(contents).ok()?;
+                *contents.cx.children().get(index)?
+            }
+
+            ScrollTarget::Widget(widget) => widget,
+        };
+
+        // transform the rect of the widget to the coordinates of the contents
+        let mut current = cx.get_widget(widget).ok()?;
+        let mut rect = current.cx.rect();
+
+        while current.cx.id() != contents {
+            rect.min = current.cx.transform() * rect.min;
+            rect.max = current.cx.transform() * rect.max;
+            current = cx.get_widget(current.cx.parent()?).ok()?;
+        }
+
+        rect.min = rect.min - *self.scroll;
+        rect.max = rect.max - *self.scroll;
+
+        Some(self.reveal(rect, portal.cx.size(), overflow))
+    }
+
+    fn can_overscroll(&self) -> bool {
+        !matches!(self.overscroll, Overscroll::None) && !AnimationSettings::get().reduce_motion
+    }
+
+    /// Move the contents to `position`, which includes the pull, pulling them past the edges
+    /// if overscroll is enabled.
+    fn move_to(&mut self, cx: &EventCx<'_>, position: Offset) {
+        let Ok(portal) = cx.get_child(self.portal) else {
+            return;
+        };
+
+        let overflow = portal.widget.overflow;
+
+        let mut scroll = position;
+        scroll.x = scroll.x.clamp(0.0, overflow.width);
+        scroll.y = scroll.y.clamp(0.0, overflow.height);
+
+        if self.can_overscroll() {
+            // only pull along the axes that can scroll
+            let pull = position - scroll;
+            self.pull.x = if portal.widget.loosen_width {
+                pull.x
+            } else {
+                0.0
+            };
+            self.pull.y = if portal.widget.loosen_height {
+                pull.y
+            } else {
+                0.0
+            };
+        } else {
+            self.pull = Offset::ZERO;
+        }
+
+        self.scroll.set(scroll);
+    }
+
+    /// Advance the fling and the spring back of the pull, returning whether either is ongoing.
+    fn animate_kinetic(&mut self, cx: &mut UpdateCx<'_>, dt: f32) -> bool {
+        let Ok(overflow) = cx
+            .get_child(self.portal)
+            .map(|portal| portal.widget.overflow)
+        else {
+            return false;
+        };
+
+        if self.velocity != Offset::ZERO {
+            let position = self.scroll.end() + self.pull + self.velocity * dt;
+
+            let mut scroll = position;
+            scroll.x = scroll.x.clamp(0.0, overflow.width);
+            scroll.y = scroll.y.clamp(0.0, overflow.height);
+
+            let hit = position - scroll;
+            self.scroll.set(scroll);
+
+            self.velocity *= self.friction.powf(dt);
+
+            for (velocity, pull, hit) in [
+                (
+                    &mut self.velocity.x,
+                    &mut self.pull.x,
+                    hit.x,
+                ),
+                (
+                    &mut self.velocity.y,
+                    &mut self.pull.y,
+                    hit.y,
+                ),
+            ] {
+                if hit == 0.0 {
+                    continue;
+                }
+
+                if self.overscroll == Overscroll::None || AnimationSettings::get().reduce_motion {
+                    *velocity = 0.0;
+                    continue;
+                }
+
+                // the contents run past the edge, and are quickly slowed down
+                *pull = hit;
+                *velocity *= f32::exp(-OVERSCROLL_DAMPING * dt);
+            }
+
+            if self.velocity.length() < MIN_FLING_VELOCITY {
+                self.velocity = Offset::ZERO;
+            }
+        }
+
+        if !self.is_dragging {
+            for (velocity, pull) in [
+                (self.velocity.x, &mut self.pull.x),
+                (self.velocity.y, &mut self.pull.y),
+            ] {
+                // spring back once the contents stop moving away from the edge
+                if velocity * *pull <= 0.0 {
+                    *pull *= f32::exp(-SPRING_BACK * dt);
+                }
+
+                if pull.abs() < 0.5 {
+                    *pull = 0.0;
+                }
+            }
+        }
+
+        self.velocity != Offset::ZERO || self.pull != Offset::ZERO
+    }
+
+    fn animate_auto_scroll(&mut self, cx: &mut UpdateCx<'_>, dt: f32) -> bool {
+        let Some(ref event) = self.auto_scroll else {
+            return false;
+        };
+
+        let Ok(overflow) = cx
+            .get_child(self.portal)
+            .map(|portal| portal.widget.overflow)
+        else {
+            return false;
+        };
+
+        let local = cx.global_transform().inverse() * event.position;
+        let velocity = auto_scroll_velocity(cx.rect(), local);
+
+        let mut scroll = self.scroll.end() + velocity * dt;
+        scroll.x = scroll.x.clamp(0.0, overflow.width);
+        scroll.y = scroll.y.clamp(0.0, overflow.height);
+
+        if scroll == self.scroll.end() {
+            return false;
+        }
+
+        self.scroll.set(scroll);
+
+        // the contents moved under the pointer, so replay the move to let the capturer follow,
+        // e.g. to extend a text selection
+        if let Some(window) = cx.window() {
+            let event = event.clone();
+
+            cx.defer(move |world| {
+                world.pointer_moved(
+                    window,
+                    event.pointer,
+                    event.position,
+                    event.tool,
+                );
+            });
+        }
+
+        true
+    }
+
+    /// The pull with resistance applied, the further the contents are pulled the slower they
+    /// follow.
+    fn resisted_pull(&self, size: Size) -> Offset {
+        fn resist(pull: f32, length: f32) -> f32 {
+            if length <= 0.0 {
+                return 0.0;
+            }
+
+            (1.0 - 1.0 / (pull.abs() * 0.55 / length + 1.0)) * length * pull.signum()
+        }
+
+        Offset::new(
+            resist(self.pull.x, size.width),
+            resist(self.pull.y, size.height),
+        )
+    }
+}
+
+/// How quickly contents flung past an edge are slowed down.
+const OVERSCROLL_DAMPING: f32 = 25.0;
+
+/// How quickly pulled contents spring back to the edge.
+const SPRING_BACK: f32 = 12.0;
+
+/// The velocity below which a fling stops, in points per second.
+const MIN_FLING_VELOCITY: f32 = 10.0;
+
+/// How fast contents scroll when a pointer is dragged past an edge, in points per second for
+/// every point the pointer is past it.
+const AUTO_SCROLL_SPEED: f32 = 10.0;
+
+/// The velocity to scroll with, when a pointer dragging inside `rect` is at `point`.
+pub(crate) fn auto_scroll_velocity(rect: Rect, point: Point) -> Offset {
+    let past = |value: f32, min: f32, max: f32| {
+        if value < min {
+            value - min
+        } else if value > max {
+            value - max
+        } else {
+            0.0
+        }
+    };
+
+    Offset::new(
+        past(point.x, rect.left(), rect.right()),
+        past(point.y, rect.top(), rect.bottom()),
+    ) * AUTO_SCROLL_SPEED
 }
 
 impl Widget for Scroll {
@@ -206,6 +651,16 @@ impl Widget for Scroll {
                 + vbar.widget.border_width.size().height
         });
 
+        let map_width = cx.get_child(self.map).map_or(0.0, |map| {
+            if map.cx.is_stashed() {
+                return 0.0;
+            }
+
+            map.widget.width
+        });
+
+        let vbar_width = vbar_width + map_width;
+
         let hbar_height = cx.get_child(self.hbar).map_or(0.0, |hbar| {
             if hbar.cx.is_stashed() || self.overlay {
                 return 0.0;
@@ -252,6 +707,17 @@ impl Widget for Scroll {
             },
         );
 
+        let map_size = cx.layout_child(
+            self.map,
+            Space::new(
+                Size::new(map_width, 0.0),
+                Size::new(
+                    map_width,
+                    child_size.height.min(space.max.height),
+                ),
+            ),
+        );
+
         if self.overlay {
             let vbar = Offset {
                 x: child_size.width - vbar_size.width,
@@ -275,9 +741,16 @@ impl Widget for Scroll {
             );
         }
 
+        cx.place_child(
+            self.map,
+            Offset::new(child_size.width + vbar_size.width, 0.0),
+        );
+
+        self.laid_out = true;
+
         let mut size = child_size;
 
-        size.width += vbar_size.width;
+        size.width += vbar_size.width + map_size.width;
         size.height += hbar_size.height;
 
         space.constrain(size)
@@ -291,6 +764,19 @@ impl Widget for Scroll {
             return;
         };
 
+        if let Some((target, animate)) = self.target.take()
+            && let Some(scroll) = self.resolve_target(cx, target)
+        {
+            self.velocity = Offset::ZERO;
+            self.pull = Offset::ZERO;
+
+            if animate {
+                self.scroll.begin(scroll);
+            } else {
+                self.scroll.set(scroll);
+            }
+        }
+
         let mut scroll = *self.scroll;
         scroll.x = scroll.x.clamp(0.0, overflow.width);
         scroll.y = scroll.y.clamp(0.0, overflow.height);
@@ -336,22 +822,47 @@ impl Widget for Scroll {
             hbar.cx.request_draw();
         }
 
+        let mut offset = *self.scroll;
+
+        if let Overscroll::Bounce = self.overscroll
+            && let Ok(size) = cx.get_child(self.portal).map(|portal| portal.cx.size())
+        {
+            offset += self.resisted_pull(size);
+        }
+
         if let Ok(mut portal) = cx.get_child_mut(self.portal)
-            && portal.widget.offset != *self.scroll
+            && portal.widget.offset != offset
         {
-            portal.widget.offset = *self.scroll;
+            portal.widget.offset = offset;
             portal.cx.request_compose();
         }
 
         if scroll != *self.scroll {
             self.scroll.set(scroll);
         }
+
+        if let Ok(mut map) = cx.get_child_mut(self.map)
+            && !map.cx.is_stashed()
+        {
+            map.widget.dirty |= self.laid_out;
+            map.cx.request_draw();
+        }
+
+        self.laid_out = false;
     }
 
     fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
         cx.request_compose();
 
-        if self.scroll.animate(dt) {
+        let is_kinetic = self.animate_kinetic(cx, dt.as_secs_f32());
+        let is_auto_scrolling = self.animate_auto_scroll(cx, dt.as_secs_f32());
+
+        if let Overscroll::Glow = self.overscroll {
+            cx.request_draw();
+        }
+
+        // a pending target only starts its transition when composed
+        if self.scroll.animate(dt) | is_kinetic | is_auto_scrolling || self.target.is_some() {
             cx.request_animate();
             cx.set_subpixel(true);
         } else {
@@ -360,41 +871,16 @@ impl Widget for Scroll {
     }
 
     fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
-        if let Update::ScrollTo(mut target) = update
+        if let Update::ScrollTo(target) = update
             && let Ok(size) = cx.get_child(self.portal).map(|portal| portal.cx.size())
             && let Ok(overflow) = cx
                 .get_child(self.portal)
                 .map(|portal| portal.widget.overflow)
         {
-            target.min += Offset::all(-8.0);
-            target.max += Offset::all(8.0);
-
-            let mut x = if target.max.x - target.min.x > size.width {
-                target.center().x - size.width / 2.0
-            } else if target.min.x < 0.0 {
-                target.min.x
-            } else if target.max.x > size.width {
-                target.max.x - size.width
-            } else {
-                0.0
-            };
-
-            let mut y = if target.max.y - target.min.y > size.height {
-                target.center().y - size.height / 2.0
-            } else if target.min.y < 0.0 {
-                target.min.y
-            } else if target.max.y > size.height {
-                target.max.y - size.height
-            } else {
-                0.0
-            };
-
-            x += self.scroll.x;
-            y += self.scroll.y;
-            x = x.clamp(0.0, overflow.width);
-            y = y.clamp(0.0, overflow.height);
+            let scroll = self.reveal(target, size, overflow);
+            self.velocity = Offset::ZERO;
 
-            if self.scroll.begin(Offset::new(x, y)) {
+            if self.scroll.begin(scroll) {
                 cx.request_animate();
             }
 
@@ -416,6 +902,9 @@ impl Widget for Scroll {
 
         match event {
             PointerEvent::Scroll(event) => {
+                // touching the contents with a touchpad stops a fling
+                self.velocity = Offset::ZERO;
+
                 let mut scroll = self.scroll.end();
                 scroll += match event.delta {
                     ScrollDelta::Line(mut offset) => {
@@ -438,43 +927,172 @@ impl Widget for Scroll {
                 scroll.x = scroll.x.clamp(0.0, overflow.width);
                 scroll.y = scroll.y.clamp(0.0, overflow.height);
 
-                if self.scroll.begin(scroll) {
-                    cx.request_animate();
+                match event.delta {
+                    // touchpads send many small deltas, which should follow the fingers exactly
+                    ScrollDelta::Pixel(..) => {
+                        self.scroll.set(scroll);
+
+                        cx.request_compose();
+                        cx.request_draw();
+                    }
+
+                    ScrollDelta::Line(..) => {
+                        if self.scroll.begin(scroll) {
+                            cx.request_animate();
+                        }
+                    }
                 }
 
                 PointerPropagate::Handled
             }
 
+            // a pointer captured by the contents, e.g. selecting text, scrolls past the edges
+            PointerEvent::Move(event)
+                if cx
+                    .get_child(self.portal)
+                    .is_ok_and(|portal| portal.cx.has_active()) =>
+            {
+                self.auto_scroll = Some(event.clone());
+                cx.request_animate();
+
+                PointerPropagate::Bubble
+            }
+
+            PointerEvent::Up(event)
+                if (self.auto_scroll.as_ref()).is_some_and(|e| e.pointer == event.pointer) =>
+            {
+                self.auto_scroll = None;
+                PointerPropagate::Bubble
+            }
+
             _ => PointerPropagate::Bubble,
         }
     }
 
     fn on_touch_event(&mut self, cx: &mut EventCx<'_>, event: &TouchEvent) -> TouchPropagate {
-        let Ok(overflow) = cx
-            .get_child(self.portal)
-            .map(|portal| portal.widget.overflow)
-        else {
-            return TouchPropagate::Bubble;
-        };
-
         match event {
+            // touching the contents stops a fling
+            TouchEvent::Down(..) => {
+                self.velocity = Offset::ZERO;
+                TouchPropagate::Bubble
+            }
+
             TouchEvent::Gesture(Gesture::Pan(event)) => {
-                let mut scroll = self.scroll.end();
-                scroll -= event.delta;
+                self.velocity = Offset::ZERO;
+                self.is_dragging = true;
 
-                scroll.x = scroll.x.clamp(0.0, overflow.width);
-                scroll.y = scroll.y.clamp(0.0, overflow.height);
+                let position = self.scroll.end() + self.pull - event.delta;
+                self.move_to(cx, position);
 
-                self.scroll.set(scroll);
                 cx.request_compose();
+                cx.request_draw();
 
                 TouchPropagate::Capture
             }
 
+            TouchEvent::Gesture(Gesture::Fling(event)) => {
+                let Ok((horizontal, vertical)) = cx.get_child(self.portal).map(|portal| {
+                    (
+                        portal.widget.loosen_width,
+                        portal.widget.loosen_height,
+                    )
+                }) else {
+                    return TouchPropagate::Bubble;
+                };
+
+                if self.kinetic && !AnimationSettings::get().reduce_motion {
+                    self.velocity = -event.velocity;
+
+                    if !horizontal {
+                        self.velocity.x = 0.0;
+                    }
+
+                    if !vertical {
+                        self.velocity.y = 0.0;
+                    }
+                }
+
+                self.is_dragging = false;
+                cx.request_animate();
+
+                TouchPropagate::Handled
+            }
+
+            TouchEvent::Up(..) if self.is_dragging => {
+                // spring back from the edges
+                self.is_dragging = false;
+                cx.request_animate();
+
+                TouchPropagate::Bubble
+            }
+
             _ => TouchPropagate::Bubble,
         }
     }
 
+    fn draw_over(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        if self.overscroll != Overscroll::Glow || self.pull == Offset::ZERO {
+            return;
+        }
+
+        let Ok(size) = cx.get_child(self.portal).map(|portal| portal.cx.size()) else {
+            return;
+        };
+
+        let pull = self.resisted_pull(size);
+        let portal = Rect::min_size(Point::ORIGIN, size);
+
+        let color = self.glow_color;
+
+        // an ellipse centered on the edge the contents are pulled past
+        let glows = [
+            (
+                pull.x,
+                size.height,
+                Point::new(
+                    if pull.x < 0.0 { 0.0 } else { size.width },
+                    size.height / 2.0,
+                ),
+            ),
+            (
+                pull.y,
+                size.width,
+                Point::new(
+                    size.width / 2.0,
+                    if pull.y < 0.0 { 0.0 } else { size.height },
+                ),
+            ),
+        ];
+
+        canvas.clip(
+            &Clip::Rect(portal, CornerRadius::all(0.0)),
+            &mut |canvas| {
+                for (i, &(pull, length, center)) in glows.iter().enumerate() {
+                    if pull == 0.0 {
+                        continue;
+                    }
+
+                    let depth = pull.abs() * 0.5;
+                    let intensity = f32::min(depth / (length * 0.25), 1.0);
+
+                    let half = match i {
+                        0 => Offset::new(depth, length * 0.75),
+                        _ => Offset::new(length * 0.75, depth),
+                    };
+
+                    canvas.draw_rect(
+                        Rect {
+                            min: center - half,
+                            max: center + half,
+                        },
+                        CornerRadius::all(depth),
+                        &Paint::from(color.fade(intensity)),
+                    );
+                }
+            },
+        );
+    }
+
     fn accepts_pointer() -> bool {
         true
     }
@@ -534,6 +1152,7 @@ struct ScrollBar {
     knob_length: f32,
     scroll:      f32,
     draw_offset: f32,
+    dragging:    Option<PointerId>,
 
     portal: WidgetId<Portal>,
 }
@@ -555,6 +1174,7 @@ impl ScrollBar {
             knob_length: 0.5,
             scroll: 0.0,
             draw_offset: 0.0,
+            dragging: None,
 
             portal,
         }
@@ -622,7 +1242,9 @@ impl Widget for ScrollBar {
         let end = start + length * self.knob_length;
 
         match event {
-            PointerEvent::Down(event) if event.button == PointerButton::Primary => {
+            PointerEvent::Down(event)
+                if event.button == PointerButton::Primary && !cx.is_active() =>
+            {
                 let local = cx.global_transform().inverse() * event.position;
                 let (local, _) = self.axis.unpack_point(local);
 
@@ -633,12 +1255,13 @@ impl Widget for ScrollBar {
                 }
 
                 self.draw_offset = local - offset - self.scroll * space;
+                self.dragging = Some(event.pointer);
                 cx.request_draw();
 
                 PointerPropagate::Capture
             }
 
-            PointerEvent::Move(event) if cx.is_active() => {
+            PointerEvent::Move(event) if self.dragging == Some(event.pointer) => {
                 let local = cx.global_transform().inverse() * event.position;
                 let (local, _) = self.axis.unpack_point(local);
 
@@ -651,6 +1274,11 @@ impl Widget for ScrollBar {
                 PointerPropagate::Bubble
             }
 
+            PointerEvent::Up(event) if self.dragging == Some(event.pointer) => {
+                self.dragging = None;
+                PointerPropagate::Bubble
+            }
+
             _ => PointerPropagate::Bubble,
         }
     }
@@ -683,3 +1311,228 @@ fn set_scroll(bar: &mut ScrollBar, cx: &mut EventCx<'_>) {
         scroll.widget.scroll.set(end);
     }
 }
+
+struct Minimap {
+    width:            f32,
+    background_paint: Paint,
+    viewport_paint:   Paint,
+
+    recording:   Option<Recording>,
+    scale:       f32,
+    dirty:       bool,
+    factor:      f32,
+    map_offset:  f32,
+    drag_offset: f32,
+    dragging:    Option<PointerId>,
+
+    portal: WidgetId<Portal>,
+}
+
+impl Minimap {
+    fn new(portal: WidgetId<Portal>) -> Self {
+        Self {
+            width: 96.0,
+            background_paint: Paint::from(Color::TRANSPARENT),
+            viewport_paint: Paint::from(Color::BLACK.fade(0.2)),
+
+            recording: None,
+            scale: 1.0,
+            dirty: true,
+            factor: 0.0,
+            map_offset: 0.0,
+            drag_offset: 0.0,
+            dragging: None,
+
+            portal,
+        }
+    }
+
+    // the largest height in pixels of the preview
+    const MAX_PIXELS: f32 = 8192.0;
+}
+
+impl Widget for Minimap {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        if cx.scale() != self.scale {
+            self.scale = cx.scale();
+            self.dirty = true;
+        }
+
+        space.max
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        canvas.draw_rect(
+            cx.rect(),
+            CornerRadius::all(0.0),
+            &self.background_paint,
+        );
+
+        let Ok((contents, offset, viewport)) = cx.get_widget(self.portal).map(|portal| {
+            (
+                portal.cx.children().first().copied(),
+                portal.widget.offset,
+                portal.cx.size(),
+            )
+        }) else {
+            return;
+        };
+
+        let Some(contents) = contents else {
+            return;
+        };
+
+        let Ok(size) = cx.get_widget(contents).map(|contents| contents.cx.size()) else {
+            return;
+        };
+
+        if size.width <= 0.0 || size.height <= 0.0 {
+            return;
+        }
+
+        let scale = self.scale;
+
+        self.factor = f32::min(
+            cx.width() / size.width,
+            Self::MAX_PIXELS / (size.height * scale),
+        );
+
+        // when the preview is taller than the minimap, it's scrolled along with the contents
+        let overflow = f32::max(
+            size.height * self.factor - cx.height(),
+            0.0,
+        );
+        let excess = f32::max(size.height - viewport.height, 0.0);
+
+        self.map_offset = match excess > 0.0 {
+            true => overflow * offset.y / excess,
+            false => 0.0,
+        };
+
+        let preview = Rect::min_size(Point::ORIGIN, size * self.factor);
+
+        if self.dirty || self.recording.is_none() {
+            let width = (preview.width() * scale).ceil() as u32;
+            let height = (preview.height() * scale).ceil() as u32;
+            let factor = self.factor * scale;
+
+            self.recording = canvas.record(width, height, &mut |canvas| {
+                canvas.transform(
+                    Affine::scale(factor, factor),
+                    &mut |canvas| {
+                        cx.draw_widget(contents, canvas);
+                    },
+                );
+            });
+
+            self.dirty = false;
+        }
+
+        let clip = Clip::Rect(cx.rect(), CornerRadius::all(0.0));
+        let transform = Affine::translate(Offset::new(0.0, -self.map_offset));
+
+        canvas.clip(&clip, &mut |canvas| {
+            canvas.transform(transform, &mut |canvas| {
+                match self.recording {
+                    Some(ref recording) => canvas.draw_recording(preview, recording),
+
+                    // canvases without a surface can't record, draw the contents directly
+                    None => {
+                        let factor = self.factor;
+                        canvas.transform(
+                            Affine::scale(factor, factor),
+                            &mut |canvas| {
+                                cx.draw_widget(contents, canvas);
+                            },
+                        );
+                    }
+                }
+
+                let viewport = Rect::min_size(
+                    Point::new(0.0, offset.y * self.factor),
+                    Size::new(
+                        cx.width(),
+                        viewport.height * self.factor,
+                    ),
+                );
+
+                canvas.draw_rect(
+                    viewport,
+                    CornerRadius::all(0.0),
+                    &self.viewport_paint,
+                );
+            });
+        });
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let Ok((offset, viewport)) = cx
+            .get_widget(self.portal)
+            .map(|portal| (portal.widget.offset, portal.cx.size()))
+        else {
+            return PointerPropagate::Bubble;
+        };
+
+        if self.factor <= 0.0 {
+            return PointerPropagate::Bubble;
+        }
+
+        let local = |cx: &EventCx<'_>, position: Point| {
+            let local = cx.global_transform().inverse() * position;
+            (local.y + self.map_offset) / self.factor
+        };
+
+        match event {
+            PointerEvent::Down(event)
+                if event.button == PointerButton::Primary && !cx.is_active() =>
+            {
+                let position = local(cx, event.position);
+
+                // clicking outside the viewport centers it on the pointer
+                self.drag_offset = if (offset.y..offset.y + viewport.height).contains(&position) {
+                    position - offset.y
+                } else {
+                    viewport.height / 2.0
+                };
+
+                jump_to(self, cx, position - self.drag_offset);
+                self.dragging = Some(event.pointer);
+
+                PointerPropagate::Capture
+            }
+
+            PointerEvent::Move(event) if self.dragging == Some(event.pointer) => {
+                let position = local(cx, event.position);
+                jump_to(self, cx, position - self.drag_offset);
+
+                PointerPropagate::Bubble
+            }
+
+            PointerEvent::Up(event) if self.dragging == Some(event.pointer) => {
+                self.dragging = None;
+                PointerPropagate::Bubble
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
+
+fn jump_to(map: &Minimap, cx: &mut EventCx<'_>, y: f32) {
+    if let Ok(overflow) = cx
+        .get_widget(map.portal)
+        .map(|portal| portal.widget.overflow)
+        && let Ok(parent) = cx.get_parent_mut()
+        && let Some(mut scroll) = parent.downcast::<Scroll>()
+    {
+        let mut end = scroll.widget.scroll.end();
+        end.y = y.clamp(0.0, overflow.height);
+
+        scroll.cx.request_compose();
+        scroll.widget.scroll.set(end);
+    }
+}
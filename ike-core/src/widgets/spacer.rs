@@ -5,6 +5,7 @@ pub struct Spacer {
 }
 
 impl Spacer {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
         cx.build_widget(Self { size: Size::ZERO }).finish()
     }
@@ -27,9 +27,13 @@ pub struct Stack {
     gap:     f32,
 
     flex: Vec<(f32, bool)>,
+
+    /// The major size given to each flexible child in the last layout.
+    allocated: Vec<f32>,
 }
 
 impl Stack {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
         cx.build_widget(Self {
             axis:    Axis::Vertical,
@@ -37,7 +41,8 @@ impl Stack {
             align:   Align::Center,
             gap:     0.0,
 
-            flex: Vec::new(),
+            flex:      Vec::new(),
+            allocated: Vec::new(),
         })
         .finish()
     }
@@ -51,6 +56,17 @@ impl Stack {
         self.flex[index]
     }
 
+    pub(crate) fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// The major size given to the flexible children in the last layout, by index.
+    pub(crate) fn allocated(&self) -> impl Iterator<Item = (usize, f32)> + '_ {
+        let allocated = self.flex.iter().zip(&self.allocated).enumerate();
+
+        allocated.filter_map(|(i, (&(flex, _), &allocated))| (flex > 0.0).then_some((i, allocated)))
+    }
+
     pub fn set_axis(this: &mut WidgetMut<Self>, axis: Axis) {
         this.widget.axis = axis;
         this.cx.request_layout();
@@ -85,6 +101,9 @@ impl Widget for Stack {
         let child_count = cx.children().len();
         let total_gap = self.gap * child_count.saturating_sub(1) as f32;
 
+        self.allocated.clear();
+        self.allocated.resize(self.flex.len(), 0.0);
+
         let mut flex_sum = 0.0;
         let mut major_sum = total_gap;
         let mut minor_sum = min_minor;
@@ -144,6 +163,8 @@ impl Widget for Stack {
                 subpixel_max_major
             };
 
+            self.allocated[i] = max_major;
+
             let space = Space::new(
                 self.axis.pack_size(0.0, child_min_minor),
                 self.axis.pack_size(max_major, max_minor),
@@ -176,6 +197,8 @@ impl Widget for Stack {
                 subpixel_major
             };
 
+            self.allocated[i] = major;
+
             let space = Space::new(
                 self.axis.pack_size(major, child_min_minor),
                 self.axis.pack_size(major, max_minor),
@@ -208,6 +231,12 @@ impl Widget for Stack {
             Justify::SpaceEvenly => gap,
         };
 
+        // in right-to-left layouts children are placed as in left-to-right layouts, and then
+        // mirrored, so horizontal stacks start on the right, and vertical stacks align their
+        // children to the right
+        let width = self.axis.pack_size(major, minor).width;
+        let is_rtl = cx.layout_direction().is_rtl();
+
         for i in 0..child_count {
             let size = cx
                 .get_nth_child(i)
@@ -222,7 +251,12 @@ impl Widget for Stack {
                 Align::End => excess_minor,
             };
 
-            let offset = self.axis.pack_offset(justify, align);
+            let mut offset = self.axis.pack_offset(justify, align);
+
+            if is_rtl {
+                offset.x = width - offset.x - size.width;
+            }
+
             cx.place_nth_child(i, offset);
 
             justify += child_major + gap;
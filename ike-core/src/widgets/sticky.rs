@@ -0,0 +1,113 @@
+use crate::{AnyWidgetId, Axis, Builder, ComposeCx, LayoutCx, Size, Space, Widget, WidgetMut};
+
+/// A section with a header that sticks to the start of the visible area of an enclosing
+/// [`Scroll`](super::Scroll) while the section is visible.
+///
+/// The header is pushed away by the end of its section, so a list of sticky sections hands the
+/// header over from one section to the next. The visible area is the clip of the nearest
+/// ancestor with one, which is the viewport of the scroll unless something in between clips.
+pub struct Sticky {
+    axis:   Axis,
+    header: f32,
+}
+
+impl Sticky {
+    #[track_caller]
+    pub fn new(
+        cx: &mut impl Builder,
+        header: impl AnyWidgetId,
+        contents: impl AnyWidgetId,
+    ) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            axis:   Axis::Vertical,
+            header: 0.0,
+        })
+        .with_child(contents)
+        .with_child(header)
+        .finish()
+    }
+
+    /// Set the axis the header sticks along, defaults to [`Axis::Vertical`].
+    pub fn set_axis(this: &mut WidgetMut<Self>, axis: Axis) {
+        this.widget.axis = axis;
+        this.cx.request_layout();
+    }
+}
+
+impl Sticky {
+    const CONTENTS: usize = 0;
+    const HEADER: usize = 1;
+
+    /// The start of the visible area along the axis, relative to the section.
+    fn visible_start(&self, cx: &ComposeCx<'_>) -> Option<f32> {
+        let mut ancestor = cx.get_parent().ok()?;
+
+        loop {
+            if let Some(clip) = ancestor.cx.clip() {
+                let start = ancestor.cx.global_transform() * clip.bounds().min;
+                let start = cx.global_transform().inverse() * start;
+                let (start, _) = self.axis.unpack_point(start);
+
+                return Some(start);
+            }
+
+            ancestor = cx.get_widget(ancestor.cx.parent()?).ok()?;
+        }
+    }
+}
+
+impl Widget for Sticky {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let (max_major, max_minor) = self.axis.unpack_size(space.max);
+        let (_, min_minor) = self.axis.unpack_size(space.min);
+
+        let header = cx.layout_nth_child(
+            Self::HEADER,
+            Space::new(
+                self.axis.pack_size(0.0, min_minor),
+                self.axis.pack_size(max_major, max_minor),
+            ),
+        );
+
+        let (header_major, header_minor) = self.axis.unpack_size(header);
+
+        let contents = cx.layout_nth_child(
+            Self::CONTENTS,
+            Space::new(
+                self.axis.pack_size(0.0, min_minor),
+                self.axis.pack_size(
+                    (max_major - header_major).max(0.0),
+                    max_minor,
+                ),
+            ),
+        );
+
+        let (contents_major, contents_minor) = self.axis.unpack_size(contents);
+
+        cx.place_nth_child(
+            Self::CONTENTS,
+            self.axis.pack_offset(header_major, 0.0),
+        );
+
+        self.header = header_major;
+
+        space.constrain(self.axis.pack_size(
+            header_major + contents_major,
+            header_minor.max(contents_minor),
+        ))
+    }
+
+    fn compose(&mut self, cx: &mut ComposeCx<'_>) {
+        let (length, _) = self.axis.unpack_size(cx.size());
+        let end = (length - self.header).max(0.0);
+
+        let offset = self
+            .visible_start(cx)
+            .map_or(0.0, |start| start.clamp(0.0, end));
+
+        cx.place_nth_child(
+            Self::HEADER,
+            self.axis.pack_offset(offset, 0.0),
+        );
+    }
+}
@@ -0,0 +1,763 @@
+use std::ops::Range;
+
+use crate::{
+    BorderWidth, Builder, Canvas, Clip, Color, CornerRadius, CursorIcon, Curve, DrawCx, EventCx,
+    Key, KeyEvent, LayoutCx, Modifiers, NamedKey, Offset, Paint, Paragraph, Point, PointerButton,
+    PointerEvent, PointerId, PointerPropagate, Propagate, Rect, ScrollDelta, Size, Space,
+    TextAlign, TextWrap, Update, UpdateCx, Widget, WidgetMut,
+};
+
+/// How the width of a [`TableColumn`] is determined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnWidth {
+    /// A fixed width.
+    Fixed(f32),
+
+    /// A share of the width left over after fixed columns are laid out.
+    Flex(f32),
+}
+
+#[derive(Clone, Debug)]
+pub struct TableColumn {
+    pub title:     Paragraph,
+    pub width:     ColumnWidth,
+    pub min_width: f32,
+    pub resizable: bool,
+    pub sortable:  bool,
+}
+
+impl TableColumn {
+    pub fn new(title: Paragraph) -> Self {
+        Self {
+            title,
+            width: ColumnWidth::Flex(1.0),
+            min_width: 32.0,
+            resizable: true,
+            sortable: false,
+        }
+    }
+}
+
+/// The order a column of a [`Table`] is sorted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// A table of rows and columns of text.
+///
+/// Only the rows that are visible are drawn, and cells are created on demand by the function
+/// set with [`Table::set_cells`], this allows tables with a large number of rows.
+pub struct Table {
+    columns:       Vec<TableColumn>,
+    widths:        Vec<f32>,
+    rows:          usize,
+    cells:         Box<dyn FnMut(usize, usize) -> Paragraph>,
+    row_height:    f32,
+    header_height: f32,
+    cell_padding:  f32,
+    text_height:   f32,
+    sort:          Option<(usize, SortOrder)>,
+
+    header_color:   Color,
+    row_color:      Color,
+    alt_row_color:  Color,
+    hovered_color:  Color,
+    selected_color: Color,
+    divider_color:  Color,
+    sort_color:     Color,
+    focus_color:    Color,
+
+    on_sort:   Box<dyn FnMut(usize)>,
+    #[allow(clippy::type_complexity)]
+    on_select: Box<dyn FnMut(&[usize])>,
+    #[allow(clippy::type_complexity)]
+    on_copy:   Box<dyn FnMut(&[usize])>,
+    on_save:   Option<Box<dyn FnMut()>>,
+
+    #[allow(clippy::type_complexity)]
+    on_visible: Box<dyn FnMut(Range<usize>)>,
+
+    selected: Vec<usize>,
+    anchor:   Option<usize>,
+    cursor:   Option<usize>,
+    hovered:  Option<usize>,
+    resizing: Option<(PointerId, usize, f32, f32)>,
+    scroll:   f32,
+    visible:  Range<usize>,
+}
+
+impl Table {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            columns:       Vec::new(),
+            widths:        Vec::new(),
+            rows:          0,
+            cells:         Box::new(|_, _| Paragraph::new(1.0, TextAlign::Start, TextWrap::None)),
+            row_height:    28.0,
+            header_height: 32.0,
+            cell_padding:  8.0,
+            text_height:   0.0,
+            sort:          None,
+
+            header_color:   Color::WHITE,
+            row_color:      Color::WHITE,
+            alt_row_color:  Color::WHITE,
+            hovered_color:  Color::GREEN,
+            selected_color: Color::BLUE,
+            divider_color:  Color::BLACK,
+            sort_color:     Color::BLACK,
+            focus_color:    Color::BLUE,
+
+            on_sort:   Box::new(|_| {}),
+            on_select: Box::new(|_| {}),
+            on_copy:   Box::new(|_| {}),
+            on_save:   None,
+
+            on_visible: Box::new(|_| {}),
+
+            selected: Vec::new(),
+            anchor:   None,
+            cursor:   None,
+            hovered:  None,
+            resizing: None,
+            scroll:   0.0,
+            visible:  0..0,
+        })
+        .finish()
+    }
+
+    pub fn set_columns(this: &mut WidgetMut<Self>, columns: Vec<TableColumn>) {
+        this.widget.columns = columns;
+        this.cx.request_layout();
+        this.cx.request_draw();
+    }
+
+    /// Set the number of rows, and the function used to create the cell at `(row, column)`.
+    pub fn set_cells(
+        this: &mut WidgetMut<Self>,
+        rows: usize,
+        cells: impl FnMut(usize, usize) -> Paragraph + 'static,
+    ) {
+        this.widget.rows = rows;
+        this.widget.cells = Box::new(cells);
+        this.widget.selected.retain(|&row| row < rows);
+        this.cx.request_layout();
+        this.cx.request_draw();
+    }
+
+    /// Set the number of rows, keeping the function used to create cells.
+    ///
+    /// This also redraws the visible cells, use it when the data behind them has changed.
+    pub fn set_rows(this: &mut WidgetMut<Self>, rows: usize) {
+        this.widget.rows = rows;
+        this.widget.selected.retain(|&row| row < rows);
+        this.cx.request_layout();
+        this.cx.request_draw();
+    }
+
+    pub fn set_selected(this: &mut WidgetMut<Self>, mut selected: Vec<usize>) {
+        selected.sort_unstable();
+        selected.dedup();
+
+        this.widget.selected = selected;
+        this.cx.request_draw();
+    }
+
+    /// Set the column the rows are sorted by, this is only used to draw the sort indicator.
+    pub fn set_sort(this: &mut WidgetMut<Self>, sort: Option<(usize, SortOrder)>) {
+        this.widget.sort = sort;
+        this.cx.request_draw();
+    }
+
+    pub fn set_row_height(this: &mut WidgetMut<Self>, height: f32) {
+        this.widget.row_height = height;
+        this.cx.request_layout();
+    }
+
+    pub fn set_header_height(this: &mut WidgetMut<Self>, height: f32) {
+        this.widget.header_height = height;
+        this.cx.request_layout();
+    }
+
+    pub fn set_cell_padding(this: &mut WidgetMut<Self>, padding: f32) {
+        this.widget.cell_padding = padding;
+        this.cx.request_layout();
+    }
+
+    pub fn set_header_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.header_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_row_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.row_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_alt_row_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.alt_row_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_hovered_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.hovered_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_selected_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.selected_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_divider_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.divider_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Set the color of the outline drawn around the row under the keyboard cursor when the
+    /// table is focused.
+    pub fn set_focus_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.focus_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_sort_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.sort_color = color;
+        this.cx.request_draw();
+    }
+
+    /// Set the callback called when the header of a sortable column is clicked.
+    pub fn set_on_sort(this: &mut WidgetMut<Self>, on_sort: impl FnMut(usize) + 'static) {
+        this.widget.on_sort = Box::new(on_sort);
+    }
+
+    /// Set the callback called with the selected rows when the selection changes.
+    pub fn set_on_select(this: &mut WidgetMut<Self>, on_select: impl FnMut(&[usize]) + 'static) {
+        this.widget.on_select = Box::new(on_select);
+    }
+
+    /// Set the callback called with the selected rows when they are copied.
+    pub fn set_on_copy(this: &mut WidgetMut<Self>, on_copy: impl FnMut(&[usize]) + 'static) {
+        this.widget.on_copy = Box::new(on_copy);
+    }
+
+    /// Set the callback called when the rows are saved with the action modifier and `s`.
+    ///
+    /// Without one the key is passed on, e.g. to a save shortcut of the application.
+    pub fn set_on_save(this: &mut WidgetMut<Self>, on_save: Option<Box<dyn FnMut()>>) {
+        this.widget.on_save = on_save;
+    }
+
+    /// Set the callback called with the range of visible rows when it changes.
+    pub fn set_on_visible(
+        this: &mut WidgetMut<Self>,
+        on_visible: impl FnMut(Range<usize>) + 'static,
+    ) {
+        this.widget.on_visible = Box::new(on_visible);
+        this.widget.visible = 0..0;
+        this.cx.request_draw();
+    }
+
+    fn body_height(&self, size: Size) -> f32 {
+        f32::max(size.height - self.header_height, 0.0)
+    }
+
+    fn max_scroll(&self, size: Size) -> f32 {
+        f32::max(
+            self.rows as f32 * self.row_height - self.body_height(size),
+            0.0,
+        )
+    }
+
+    fn row_at(&self, point: Point) -> Option<usize> {
+        if point.y < self.header_height {
+            return None;
+        }
+
+        let row = ((point.y - self.header_height + self.scroll) / self.row_height).floor();
+        (row >= 0.0 && (row as usize) < self.rows).then_some(row as usize)
+    }
+
+    fn column_at(&self, x: f32) -> Option<usize> {
+        let mut left = 0.0;
+
+        for (i, width) in self.widths.iter().enumerate() {
+            if (left..left + width).contains(&x) {
+                return Some(i);
+            }
+
+            left += width;
+        }
+
+        None
+    }
+
+    // the column whose right edge is near `x`, dragging it resizes the column
+    fn divider_at(&self, x: f32) -> Option<usize> {
+        let mut right = 0.0;
+
+        for (i, width) in self.widths.iter().enumerate() {
+            right += width;
+
+            if (x - right).abs() <= 4.0 && self.columns[i].resizable {
+                return Some(i);
+            }
+        }
+
+        None
+    }
+
+    fn select(&mut self, cx: &mut EventCx<'_>, row: usize, modifiers: Modifiers) {
+        let toggle = match cfg!(target_os = "macos") {
+            true => modifiers.meta(),
+            false => modifiers.ctrl(),
+        };
+
+        if modifiers.shift()
+            && let Some(anchor) = self.anchor
+        {
+            self.selected = (anchor.min(row)..=anchor.max(row)).collect();
+        } else if toggle {
+            match self.selected.binary_search(&row) {
+                Ok(index) => {
+                    self.selected.remove(index);
+                }
+
+                Err(index) => self.selected.insert(index, row),
+            }
+
+            self.anchor = Some(row);
+        } else {
+            self.selected = vec![row];
+            self.anchor = Some(row);
+        }
+
+        self.cursor = Some(row);
+        (self.on_select)(&self.selected);
+
+        self.scroll_to(cx, row);
+        cx.request_draw();
+    }
+
+    fn scroll_to(&mut self, cx: &mut EventCx<'_>, row: usize) {
+        let top = row as f32 * self.row_height;
+        let bottom = top + self.row_height;
+        let body = self.body_height(cx.size());
+
+        if top < self.scroll {
+            self.scroll = top;
+        } else if bottom > self.scroll + body {
+            self.scroll = bottom - body;
+        }
+    }
+}
+
+impl Widget for Table {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        self.text_height = 0.0;
+
+        for column in &self.columns {
+            let size = cx.measure_text(&column.title, f32::INFINITY);
+            self.text_height = self.text_height.max(size.height);
+        }
+
+        let fixed: f32 = (self.columns.iter())
+            .filter_map(|column| match column.width {
+                ColumnWidth::Fixed(width) => Some(width.max(column.min_width)),
+                ColumnWidth::Flex(_) => None,
+            })
+            .sum();
+
+        let total_flex: f32 = (self.columns.iter())
+            .filter_map(|column| match column.width {
+                ColumnWidth::Fixed(_) => None,
+                ColumnWidth::Flex(flex) => Some(flex),
+            })
+            .sum();
+
+        let width = match space.max.width.is_finite() {
+            true => space.max.width,
+            false => fixed + total_flex * 100.0,
+        };
+
+        let remaining = f32::max(width - fixed, 0.0);
+
+        self.widths.clear();
+        self.widths.extend(self.columns.iter().map(|column| {
+            let width = match column.width {
+                ColumnWidth::Fixed(width) => width,
+                ColumnWidth::Flex(flex) if flex > 0.0 => remaining * flex / total_flex,
+                ColumnWidth::Flex(_) => 0.0,
+            };
+
+            width.max(column.min_width)
+        }));
+
+        let height = self.header_height + self.rows as f32 * self.row_height;
+        let size = space.constrain(Size::new(width, height));
+
+        self.scroll = self.scroll.clamp(0.0, self.max_scroll(size));
+        cx.set_clip(Rect::min_size(Point::ORIGIN, size));
+
+        size
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        let size = cx.size();
+        let text_offset = f32::max(self.row_height - self.text_height, 0.0) / 2.0;
+
+        // only the visible rows are drawn
+        let first = (self.scroll / self.row_height).floor() as usize;
+        let count = (self.body_height(size) / self.row_height).ceil() as usize + 1;
+        let last = usize::min(first + count, self.rows);
+
+        let visible = usize::min(first, last)..last;
+        if visible != self.visible {
+            self.visible = visible.clone();
+            (self.on_visible)(visible);
+        }
+
+        for row in first..last {
+            let y = self.header_height + row as f32 * self.row_height - self.scroll;
+            let rect = Rect::min_size(
+                Point::new(0.0, y),
+                Size::new(size.width, self.row_height),
+            );
+
+            let color = if self.selected.binary_search(&row).is_ok() {
+                self.selected_color
+            } else if self.hovered == Some(row) {
+                self.hovered_color
+            } else if row % 2 == 1 {
+                self.alt_row_color
+            } else {
+                self.row_color
+            };
+
+            canvas.draw_rect(
+                rect,
+                CornerRadius::all(0.0),
+                &Paint::from(color),
+            );
+
+            let mut x = 0.0;
+
+            for (column, &width) in self.widths.iter().enumerate() {
+                let cell = (self.cells)(row, column);
+                let rect = Rect::min_size(
+                    Point::new(x, y),
+                    Size::new(width, self.row_height),
+                );
+
+                canvas.clip(&Clip::from(rect), &mut |canvas| {
+                    canvas.draw_text(
+                        &cell,
+                        width - self.cell_padding * 2.0,
+                        Offset::new(x + self.cell_padding, y + text_offset),
+                    );
+                });
+
+                x += width;
+            }
+        }
+
+        // the row moved with the keyboard is outlined, so it's visible before it's selected
+        let cursor = self.cursor.unwrap_or(0);
+
+        if cx.is_focused() && cx.is_window_focused() && (first..last).contains(&cursor) {
+            let y = self.header_height + cursor as f32 * self.row_height - self.scroll;
+            let rect = Rect::min_size(
+                Point::new(0.0, y),
+                Size::new(size.width, self.row_height),
+            );
+
+            canvas.draw_border(
+                rect,
+                BorderWidth::all(2.0),
+                CornerRadius::all(0.0),
+                &Paint::from(self.focus_color),
+            );
+        }
+
+        // the header is drawn last, so it stays on top of the rows
+        let header = Rect::min_size(
+            Point::ORIGIN,
+            Size::new(size.width, self.header_height),
+        );
+
+        canvas.draw_rect(
+            header,
+            CornerRadius::all(0.0),
+            &Paint::from(self.header_color),
+        );
+
+        let text_offset = f32::max(
+            self.header_height - self.text_height,
+            0.0,
+        ) / 2.0;
+        let mut x = 0.0;
+
+        for (i, (column, &width)) in self.columns.iter().zip(&self.widths).enumerate() {
+            let rect = Rect::min_size(
+                Point::new(x, 0.0),
+                Size::new(width, self.header_height),
+            );
+
+            canvas.clip(&Clip::from(rect), &mut |canvas| {
+                canvas.draw_text(
+                    &column.title,
+                    f32::INFINITY,
+                    Offset::new(x + self.cell_padding, text_offset),
+                );
+            });
+
+            if let Some((column, order)) = self.sort
+                && column == i
+            {
+                let center = Point::new(
+                    x + width - self.cell_padding - 4.0,
+                    self.header_height / 2.0,
+                );
+
+                let (tip, base) = match order {
+                    SortOrder::Ascending => (-3.0, 3.0),
+                    SortOrder::Descending => (3.0, -3.0),
+                };
+
+                let mut curve = Curve::new();
+                curve.move_to(center + Offset::new(0.0, tip));
+                curve.line_to(center + Offset::new(4.0, base));
+                curve.line_to(center + Offset::new(-4.0, base));
+                curve.close();
+
+                canvas.draw_curve(&curve, &Paint::from(self.sort_color));
+            }
+
+            x += width;
+
+            canvas.draw_rect(
+                Rect::min_size(
+                    Point::new(x - 1.0, 0.0),
+                    Size::new(1.0, self.header_height),
+                ),
+                CornerRadius::all(0.0),
+                &Paint::from(self.divider_color),
+            );
+        }
+
+        canvas.draw_rect(
+            Rect::min_size(
+                Point::new(0.0, self.header_height - 1.0),
+                Size::new(size.width, 1.0),
+            ),
+            CornerRadius::all(0.0),
+            &Paint::from(self.divider_color),
+        );
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        match update {
+            Update::Hovered(false) => {
+                self.hovered = None;
+                cx.request_draw();
+            }
+
+            Update::Focused(..) | Update::WindowFocused(..) => {
+                cx.request_draw();
+            }
+
+            _ => {}
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let transform = cx.global_transform().inverse();
+
+        match event {
+            PointerEvent::Move(event) => {
+                let local = transform * event.position;
+
+                if let Some((pointer, column, start, width)) = self.resizing
+                    && pointer == event.pointer
+                {
+                    let width = f32::max(
+                        width + local.x - start,
+                        self.columns[column].min_width,
+                    );
+
+                    self.columns[column].width = ColumnWidth::Fixed(width);
+                    cx.request_layout();
+                    cx.request_draw();
+
+                    return PointerPropagate::Handled;
+                }
+
+                let resize = local.y < self.header_height && self.divider_at(local.x).is_some();
+
+                cx.set_cursor(match resize {
+                    true => CursorIcon::ColResize,
+                    false => CursorIcon::Default,
+                });
+
+                let hovered = self.row_at(local);
+
+                if hovered != self.hovered {
+                    self.hovered = hovered;
+                    cx.request_draw();
+                }
+
+                PointerPropagate::Bubble
+            }
+
+            PointerEvent::Down(event) if event.button == PointerButton::Primary => {
+                let local = transform * event.position;
+                cx.request_focus();
+
+                if local.y < self.header_height {
+                    if let Some(column) = self.divider_at(local.x)
+                        && self.resizing.is_none()
+                    {
+                        let width = self.widths[column];
+                        self.resizing = Some((event.pointer, column, local.x, width));
+                        return PointerPropagate::Capture;
+                    }
+
+                    if let Some(column) = self.column_at(local.x)
+                        && self.columns[column].sortable
+                    {
+                        (self.on_sort)(column);
+                    }
+
+                    return PointerPropagate::Handled;
+                }
+
+                if let Some(row) = self.row_at(local) {
+                    let modifiers = cx.get_window().map_or(Modifiers::empty(), |window| {
+                        window.pointer_modifiers(event.pointer)
+                    });
+
+                    self.select(cx, row, modifiers);
+                }
+
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Up(event)
+                if self
+                    .resizing
+                    .is_some_and(|(pointer, ..)| pointer == event.pointer) =>
+            {
+                self.resizing = None;
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Scroll(event) => {
+                let delta = match event.delta {
+                    ScrollDelta::Line(offset) => offset.y * self.row_height * 3.0,
+                    ScrollDelta::Pixel(offset) => offset.y,
+                };
+
+                let scroll = (self.scroll - delta).clamp(0.0, self.max_scroll(cx.size()));
+
+                if scroll != self.scroll {
+                    self.scroll = scroll;
+                    cx.request_draw();
+                }
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn on_key_event(&mut self, cx: &mut EventCx<'_>, event: &KeyEvent) -> Propagate {
+        let KeyEvent::Down(event) = event else {
+            return Propagate::Bubble;
+        };
+
+        if self.rows == 0 {
+            return Propagate::Bubble;
+        }
+
+        let action_mod = match cfg!(target_os = "macos") {
+            true => event.modifiers.meta(),
+            false => event.modifiers.ctrl(),
+        };
+
+        // only shift is passed on, moving with the keyboard never toggles rows
+        let modifiers = event.modifiers & Modifiers::SHIFT;
+        let last = self.rows - 1;
+
+        // activating the row under the cursor selects it, or toggles it with the modifier
+        if event.is_activation() {
+            if !event.repeat {
+                let row = usize::min(self.cursor.unwrap_or(0), last);
+                self.select(cx, row, event.modifiers);
+            }
+
+            return Propagate::Handled;
+        }
+
+        match event.key {
+            Key::Named(NamedKey::ArrowUp) => {
+                let row = self.cursor.map_or(0, |row| row.saturating_sub(1));
+                self.select(cx, row, modifiers);
+                Propagate::Handled
+            }
+
+            Key::Named(NamedKey::ArrowDown) => {
+                let row = self.cursor.map_or(0, |row| usize::min(row + 1, last));
+                self.select(cx, row, modifiers);
+                Propagate::Handled
+            }
+
+            Key::Named(NamedKey::Home) => {
+                self.select(cx, 0, modifiers);
+                Propagate::Handled
+            }
+
+            Key::Named(NamedKey::End) => {
+                self.select(cx, last, modifiers);
+                Propagate::Handled
+            }
+
+            Key::Character(ref c) if c == "a" && action_mod => {
+                self.selected = (0..self.rows).collect();
+                (self.on_select)(&self.selected);
+                cx.request_draw();
+                Propagate::Handled
+            }
+
+            Key::Character(ref c) if c == "c" && action_mod => {
+                if !self.selected.is_empty() {
+                    (self.on_copy)(&self.selected);
+                }
+
+                Propagate::Handled
+            }
+
+            Key::Character(ref c) if c == "s" && action_mod => match self.on_save {
+                Some(ref mut on_save) => {
+                    on_save();
+                    Propagate::Handled
+                }
+
+                None => Propagate::Bubble,
+            },
+
+            _ => Propagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+
+    fn accepts_focus() -> bool {
+        true
+    }
+}
@@ -0,0 +1,510 @@
+use std::time::Duration;
+
+use crate::{
+    Affine, AnyWidgetId, BorderWidth, Builder, Canvas, Clip, Color, CornerRadius, DrawCx, EventCx,
+    Key, KeyEvent, LayoutCx, NamedKey, Offset, Padding, Paint, Paragraph, Point, PointerButton,
+    PointerEvent, PointerPropagate, Propagate, Rect, ScrollDelta, Size, Space, Transition,
+    Transitioned, Update, UpdateCx, Widget, WidgetMut,
+};
+
+pub struct Tabs {
+    tabs:       Vec<Paragraph>,
+    selected:   usize,
+    closable:   bool,
+    padding:    Padding,
+    close_size: f32,
+
+    bar_color:       Color,
+    hovered_color:   Color,
+    indicator_color: Color,
+    close_color:     Color,
+    focus_color:     Color,
+    indicator_width: f32,
+
+    on_select: Box<dyn FnMut(usize)>,
+    on_close:  Box<dyn FnMut(usize)>,
+
+    rects:      Vec<Rect>,
+    hovered:    Option<usize>,
+    bar_width:  f32,
+    bar_height: f32,
+    overflow:   f32,
+    moved:      bool,
+
+    scroll:          Transitioned<f32>,
+    indicator_start: Transitioned<f32>,
+    indicator_end:   Transitioned<f32>,
+}
+
+impl Tabs {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, contents: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            tabs:       Vec::new(),
+            selected:   0,
+            closable:   false,
+            padding:    Padding::all(8.0),
+            close_size: 8.0,
+
+            bar_color:       Color::WHITE,
+            hovered_color:   Color::GREEN,
+            indicator_color: Color::BLUE,
+            close_color:     Color::BLACK,
+            focus_color:     Color::BLUE,
+            indicator_width: 2.0,
+
+            on_select: Box::new(|_| {}),
+            on_close:  Box::new(|_| {}),
+
+            rects:      Vec::new(),
+            hovered:    None,
+            bar_width:  0.0,
+            bar_height: 0.0,
+            overflow:   0.0,
+            moved:      false,
+
+            scroll:          Transitioned::new(0.0, Transition::ease(0.25)),
+            indicator_start: Transitioned::new(0.0, Transition::ease(0.15)),
+            indicator_end:   Transitioned::new(0.0, Transition::ease(0.15)),
+        })
+        .with_child(contents)
+        .finish()
+    }
+
+    /// Set the contents of the active tab.
+    pub fn set_contents(cx: &mut impl Builder, this: impl AnyWidgetId, contents: impl AnyWidgetId) {
+        cx.set_child(this, 0, contents);
+    }
+
+    pub fn set_tabs(this: &mut WidgetMut<Self>, tabs: Vec<Paragraph>) {
+        this.widget.tabs = tabs;
+        this.widget.hovered = None;
+        this.cx.request_layout();
+        this.cx.request_draw();
+    }
+
+    pub fn set_selected(this: &mut WidgetMut<Self>, selected: usize) {
+        this.widget.selected = selected;
+        this.widget.moved = true;
+        this.cx.request_animate();
+        this.cx.request_draw();
+    }
+
+    pub fn set_closable(this: &mut WidgetMut<Self>, closable: bool) {
+        this.widget.closable = closable;
+        this.cx.request_layout();
+    }
+
+    pub fn set_padding(this: &mut WidgetMut<Self>, padding: Padding) {
+        this.widget.padding = padding;
+        this.cx.request_layout();
+    }
+
+    pub fn set_close_size(this: &mut WidgetMut<Self>, size: f32) {
+        this.widget.close_size = size;
+        this.cx.request_layout();
+    }
+
+    pub fn set_bar_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.bar_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_hovered_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.hovered_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_indicator_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.indicator_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_indicator_width(this: &mut WidgetMut<Self>, width: f32) {
+        this.widget.indicator_width = width;
+        this.cx.request_draw();
+    }
+
+    pub fn set_close_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.close_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_focus_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.focus_color = color;
+        this.cx.request_draw();
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.scroll.set_transition(transition);
+        this.widget.indicator_start.set_transition(transition);
+        this.widget.indicator_end.set_transition(transition);
+    }
+
+    /// Set the callback called when a tab is selected.
+    ///
+    /// The selected tab is not changed by the widget, call [`Tabs::set_selected`] in response.
+    pub fn set_on_select(this: &mut WidgetMut<Self>, on_select: impl FnMut(usize) + 'static) {
+        this.widget.on_select = Box::new(on_select);
+    }
+
+    /// Set the callback called when the close button of a tab is pressed.
+    pub fn set_on_close(this: &mut WidgetMut<Self>, on_close: impl FnMut(usize) + 'static) {
+        this.widget.on_close = Box::new(on_close);
+    }
+
+    fn close_rect(&self, tab: Rect) -> Rect {
+        Rect::min_size(
+            Point::new(
+                tab.right() - self.padding.right - self.close_size,
+                tab.center().y - self.close_size / 2.0,
+            ),
+            Size::all(self.close_size),
+        )
+    }
+
+    fn select(&mut self, cx: &mut EventCx<'_>, index: usize) {
+        if index < self.tabs.len() && index != self.selected {
+            (self.on_select)(index);
+        }
+
+        cx.request_focus();
+    }
+}
+
+impl Widget for Tabs {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        self.rects.clear();
+        self.bar_height = 0.0;
+
+        let mut x = 0.0;
+
+        for tab in &self.tabs {
+            let text = cx.measure_text(tab, f32::INFINITY);
+
+            let mut size = text + self.padding.size();
+
+            if self.closable {
+                size.width += self.padding.left + self.close_size;
+            }
+
+            self.rects.push(Rect::min_size(Point::new(x, 0.0), size));
+            self.bar_height = self.bar_height.max(size.height);
+
+            x += size.width;
+        }
+
+        for rect in &mut self.rects {
+            rect.max.y = self.bar_height;
+        }
+
+        let width = if space.max.width.is_finite() {
+            space.max.width
+        } else {
+            x
+        };
+
+        self.overflow = f32::max(x - width, 0.0);
+
+        let contents = Space {
+            min: Size::new(
+                width,
+                f32::max(space.min.height - self.bar_height, 0.0),
+            ),
+            max: Size::new(
+                width,
+                f32::max(space.max.height - self.bar_height, 0.0),
+            ),
+        };
+
+        let contents = cx.layout_nth_child(0, contents);
+        cx.place_nth_child(0, Offset::new(0.0, self.bar_height));
+
+        // the indicator is only animated when the selection changes, see `animate`
+        if let Some(&rect) = self.rects.get(self.selected)
+            && self.indicator_start.is_complete()
+            && self.indicator_end.is_complete()
+        {
+            self.indicator_start.set(rect.left());
+            self.indicator_end.set(rect.right());
+        }
+
+        self.bar_width = width;
+
+        if self.scroll.end() > self.overflow {
+            self.scroll.set(self.overflow);
+        }
+
+        space.constrain(Size::new(
+            width,
+            self.bar_height + contents.height,
+        ))
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        let bar = Rect::min_size(
+            Point::ORIGIN,
+            Size::new(cx.width(), self.bar_height),
+        );
+
+        canvas.draw_rect(
+            bar,
+            CornerRadius::all(0.0),
+            &Paint::from(self.bar_color),
+        );
+
+        let clip = Clip::Rect(bar, CornerRadius::all(0.0));
+        let offset = Offset::new(-*self.scroll, 0.0);
+
+        canvas.clip(&clip, &mut |canvas| {
+            canvas.transform(
+                Affine::translate(offset),
+                &mut |canvas| {
+                    for (i, (tab, &rect)) in self.tabs.iter().zip(&self.rects).enumerate() {
+                        if self.hovered == Some(i) {
+                            canvas.draw_rect(
+                                rect,
+                                CornerRadius::all(0.0),
+                                &Paint::from(self.hovered_color),
+                            );
+                        }
+
+                        canvas.draw_text(
+                            tab,
+                            f32::INFINITY,
+                            rect.min - Point::ORIGIN + self.padding.offset(),
+                        );
+
+                        if self.closable {
+                            draw_close(
+                                canvas,
+                                self.close_rect(rect),
+                                self.close_color,
+                            );
+                        }
+                    }
+
+                    let indicator = Rect {
+                        min: Point::new(
+                            *self.indicator_start,
+                            self.bar_height - self.indicator_width,
+                        ),
+                        max: Point::new(*self.indicator_end, self.bar_height),
+                    };
+
+                    canvas.draw_rect(
+                        indicator,
+                        CornerRadius::all(0.0),
+                        &Paint::from(self.indicator_color),
+                    );
+                },
+            );
+        });
+
+        if cx.is_focused()
+            && cx.is_window_focused()
+            && let Some(&rect) = self.rects.get(self.selected)
+        {
+            let rect = Rect {
+                min: rect.min + offset,
+                max: rect.max + offset,
+            };
+
+            canvas.draw_border(
+                rect,
+                BorderWidth::all(1.0),
+                CornerRadius::all(0.0),
+                &Paint::from(self.focus_color),
+            );
+        }
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        match update {
+            Update::Hovered(false) => {
+                self.hovered = None;
+                cx.request_draw();
+            }
+
+            Update::Focused(..) => {
+                cx.request_draw();
+            }
+
+            _ => {}
+        }
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        cx.request_draw();
+
+        if self.moved
+            && let Some(&rect) = self.rects.get(self.selected)
+        {
+            self.moved = false;
+            self.indicator_start.begin(rect.left());
+            self.indicator_end.begin(rect.right());
+
+            // keep the selected tab in view
+            let mut scroll = self.scroll.end();
+
+            if rect.left() < scroll {
+                scroll = rect.left();
+            } else if rect.right() > scroll + self.bar_width {
+                scroll = rect.right() - self.bar_width;
+            }
+
+            self.scroll.begin(scroll.clamp(0.0, self.overflow));
+        }
+
+        let scroll = self.scroll.animate(dt);
+        let start = self.indicator_start.animate(dt);
+        let end = self.indicator_end.animate(dt);
+
+        if scroll || start || end {
+            cx.request_animate();
+            cx.set_subpixel(true);
+        } else {
+            cx.set_subpixel(false);
+        }
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        let transform = cx.global_transform().inverse();
+        let scroll = Offset::new(*self.scroll, 0.0);
+        let local = |position: Point| {
+            let local = transform * position;
+            (local, local + scroll)
+        };
+
+        match event {
+            PointerEvent::Move(event) => {
+                let (position, bar) = local(event.position);
+
+                let hovered = match position.y < self.bar_height {
+                    true => self.rects.iter().position(|rect| rect.contains(bar)),
+                    false => None,
+                };
+
+                if hovered != self.hovered {
+                    self.hovered = hovered;
+                    cx.request_draw();
+                }
+
+                PointerPropagate::Bubble
+            }
+
+            PointerEvent::Down(event) if event.button == PointerButton::Primary => {
+                let (position, bar) = local(event.position);
+
+                if position.y >= self.bar_height {
+                    return PointerPropagate::Bubble;
+                }
+
+                let Some(index) = self.rects.iter().position(|rect| rect.contains(bar)) else {
+                    return PointerPropagate::Bubble;
+                };
+
+                if self.closable && self.close_rect(self.rects[index]).contains(bar) {
+                    (self.on_close)(index);
+                } else {
+                    self.select(cx, index);
+                }
+
+                PointerPropagate::Handled
+            }
+
+            PointerEvent::Scroll(event) if self.overflow > 0.0 => {
+                let (position, _) = local(event.position);
+
+                if position.y >= self.bar_height {
+                    return PointerPropagate::Bubble;
+                }
+
+                let delta = match event.delta {
+                    ScrollDelta::Line(offset) => offset * 120.0,
+                    ScrollDelta::Pixel(offset) => offset,
+                };
+
+                let delta = match delta.x == 0.0 {
+                    true => delta.y,
+                    false => delta.x,
+                };
+
+                let scroll = (self.scroll.end() - delta).clamp(0.0, self.overflow);
+
+                if self.scroll.begin(scroll) {
+                    cx.request_animate();
+                }
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn on_key_event(&mut self, cx: &mut EventCx<'_>, event: &KeyEvent) -> Propagate {
+        if self.tabs.is_empty() {
+            return Propagate::Bubble;
+        }
+
+        let last = self.tabs.len() - 1;
+
+        match event {
+            KeyEvent::Down(event) if event.key == Key::Named(NamedKey::ArrowLeft) => {
+                self.select(cx, self.selected.saturating_sub(1));
+                Propagate::Handled
+            }
+
+            KeyEvent::Down(event) if event.key == Key::Named(NamedKey::ArrowRight) => {
+                self.select(cx, usize::min(self.selected + 1, last));
+                Propagate::Handled
+            }
+
+            KeyEvent::Down(event) if event.key == Key::Named(NamedKey::Home) => {
+                self.select(cx, 0);
+                Propagate::Handled
+            }
+
+            KeyEvent::Down(event) if event.key == Key::Named(NamedKey::End) => {
+                self.select(cx, last);
+                Propagate::Handled
+            }
+
+            _ => Propagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+
+    fn accepts_focus() -> bool {
+        true
+    }
+}
+
+fn draw_close(canvas: &mut dyn Canvas, rect: Rect, color: Color) {
+    let thickness = f32::max(rect.width() / 6.0, 1.0);
+    let length = rect.width() * std::f32::consts::SQRT_2;
+
+    let bar = Rect::min_size(
+        Point::new(-length / 2.0, -thickness / 2.0),
+        Size::new(length, thickness),
+    );
+
+    let center = Affine::translate(rect.center() - Point::ORIGIN);
+
+    for angle in [std::f32::consts::FRAC_PI_4, -std::f32::consts::FRAC_PI_4] {
+        canvas.transform(
+            center * Affine::rotate(angle),
+            &mut |canvas| {
+                canvas.draw_rect(
+                    bar,
+                    CornerRadius::all(thickness / 2.0),
+                    &Paint::from(color),
+                );
+            },
+        );
+    }
+}
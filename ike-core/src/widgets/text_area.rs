@@ -1,13 +1,14 @@
-use std::time::Duration;
+use std::{ops::Range, time::Duration};
 
 use keyboard_types::NamedKey;
 
 use crate::{
     Affine, AnyWidgetId, Builder, Canvas, Color, ComposeCx, CornerRadius, CursorIcon, DrawCx,
-    EventCx, Gesture, ImeEvent, Key, KeyEvent, LayoutCx, MutCx, Offset, Paint, Paragraph, Point,
-    PointerButton, PointerEvent, PointerPropagate, Propagate, Rect, Size, Space, TextLayoutLine,
-    TouchEvent, TouchPropagate, Update, UpdateCx, Widget, WidgetId, WidgetMut, World,
-    event::TextEvent, window::LayerId,
+    EventCx, FontStretch, FontStyle, FontWeight, Gesture, ImeEvent, ImePurpose, Key, KeyEvent,
+    LayoutCx, MutCx, Offset, Paint, Paragraph, Point, PointerButton, PointerEvent, PointerId,
+    PointerPropagate, Propagate, Rect, Shape, Size, Space, TextAlign, TextLayoutLine, TextStyle,
+    TextWrap, TouchEvent, TouchPropagate, Update, UpdateCx, Widget, WidgetId, WidgetMut, WindowId,
+    World, event::TextEvent, widgets::scroll::auto_scroll_velocity, window::LayerId,
 };
 
 /// When should newlines be inserted in a [`TextArea`].
@@ -43,76 +44,147 @@ pub struct SubmitBehaviour {
     pub clear_text: bool,
 }
 
+/// A gutter of line numbers drawn beside the text of a [`TextArea`].
+#[derive(Clone, Debug)]
+pub struct Gutter {
+    /// The style of the line numbers.
+    pub style: TextStyle,
+
+    /// The space on either side of the line numbers.
+    pub padding: f32,
+
+    pub background_color: Color,
+
+    /// The color of the indicator drawn in place of a line number, when a line is soft-wrapped.
+    pub wrap_color: Color,
+}
+
 pub struct TextArea<const EDITABLE: bool> {
     paragraph:         Paragraph,
     selection_color:   Color,
     cursor_color:      Color,
+    composing_color:   Color,
     handle_color:      Color,
+    toolbar_color:     Color,
     blink_rate:        f32,
     handle_size:       f32,
     handles_enabled:   bool,
     newline_behaviour: NewlineBehaviour,
     submit_behaviour:  SubmitBehaviour,
+    gutter:            Option<Gutter>,
+    secure:            bool,
+    max_length:        Option<usize>,
 
     #[allow(clippy::type_complexity)]
-    on_change: Option<Box<dyn FnMut(&str)>>,
+    on_change:   Option<Box<dyn FnMut(&str)>>,
+    #[allow(clippy::type_complexity)]
+    on_submit:   Option<Box<dyn FnMut(&str)>>,
+    #[allow(clippy::type_complexity)]
+    on_link:     Option<Box<dyn FnMut(&str)>>,
+    #[allow(clippy::type_complexity)]
+    line_marker: Option<Box<dyn FnMut(usize) -> Option<Color>>>,
     #[allow(clippy::type_complexity)]
-    on_submit: Option<Box<dyn FnMut(&str)>>,
+    filter:      Option<Box<dyn FnMut(&str) -> bool>>,
+    #[allow(clippy::type_complexity)]
+    validator:   Option<Box<dyn FnMut(&str) -> bool>>,
 
+    is_valid:         bool,
+    masked:           Option<Paragraph>,
     lines:            Vec<TextLayoutLine>,
     cursor:           usize,
     selection:        Option<usize>,
+    composing:        Option<Range<usize>>,
+    scroll:           f32,
+    overflow:         f32,
     blink:            f32,
     cursor_anchor:    Option<f32>,
     cursor_handle:    Option<(LayerId, WidgetId<Handle<EDITABLE>>)>,
     selection_handle: Option<(LayerId, WidgetId<Handle<EDITABLE>>)>,
+    toolbar:          Option<(LayerId, WidgetId<Toolbar<EDITABLE>>)>,
+    selecting:        Option<PointerId>,
+    drag_position:    Option<Point>,
+    line_numbers:     Vec<Paragraph>,
+    wrap_indicator:   Option<Paragraph>,
+    number_width:     f32,
+    number_baseline:  f32,
+    gutter_width:     f32,
+    hovered_link:     Option<usize>,
+    pressed_link:     Option<usize>,
 }
 
 impl<const EDITABLE: bool> TextArea<EDITABLE> {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, paragraph: Paragraph) -> WidgetMut<'_, Self> {
         let cursor = paragraph.text.len();
-
-        cx.build_widget(Self {
-            paragraph,
-            selection_color: Color::BLUE,
-            cursor_color: Color::BLACK,
-            handle_color: Color::GREEN,
-            blink_rate: 5.0,
-            handle_size: 20.0,
-            handles_enabled: true,
-            newline_behaviour: NewlineBehaviour::Enter,
-            submit_behaviour: SubmitBehaviour::default(),
-
-            on_change: None,
-            on_submit: None,
-
-            lines: Vec::new(),
-            cursor,
-            selection: None,
-            blink: 0.0,
-            cursor_anchor: None,
-            cursor_handle: None,
-            selection_handle: None,
-        })
-        .finish()
+        let has_links = !paragraph.links.is_empty();
+
+        let mut this = cx
+            .build_widget(Self {
+                paragraph,
+                selection_color: Color::BLUE,
+                cursor_color: Color::BLACK,
+                composing_color: Color::BLACK,
+                handle_color: Color::GREEN,
+                toolbar_color: Color::WHITE,
+                blink_rate: 5.0,
+                handle_size: 20.0,
+                handles_enabled: true,
+                newline_behaviour: NewlineBehaviour::Enter,
+                submit_behaviour: SubmitBehaviour::default(),
+                gutter: None,
+                secure: false,
+                max_length: None,
+
+                on_change: None,
+                on_submit: None,
+                on_link: None,
+                line_marker: None,
+                filter: None,
+                validator: None,
+
+                is_valid: true,
+                masked: None,
+                lines: Vec::new(),
+                cursor,
+                selection: None,
+                composing: None,
+                scroll: 0.0,
+                overflow: 0.0,
+                blink: 0.0,
+                cursor_anchor: None,
+                cursor_handle: None,
+                selection_handle: None,
+                toolbar: None,
+                selecting: None,
+                drag_position: None,
+                line_numbers: Vec::new(),
+                wrap_indicator: None,
+                number_width: 0.0,
+                number_baseline: 0.0,
+                gutter_width: 0.0,
+                hovered_link: None,
+                pressed_link: None,
+            })
+            .finish();
+
+        // read-only text is focusable with links, so they can be activated with the keyboard
+        this.cx.set_accepts_focus(EDITABLE || has_links);
+        this
     }
 
     pub fn set_text(this: &mut WidgetMut<Self>, paragraph: Paragraph) {
         // the new text might place the cursor in the middle of a unicode character,
         // in which case we want to move the cursor to avoid crashes.
 
-        this.widget.cursor = this.widget.cursor.min(paragraph.text.len());
-        while !paragraph.text.is_char_boundary(this.widget.cursor) {
-            this.widget.cursor -= 1;
-        }
+        this.widget.cursor = floor_char_boundary(&paragraph.text, this.widget.cursor);
 
         if let Some(ref mut selection) = this.widget.selection {
-            *selection = (*selection).min(paragraph.text.len());
-            while !paragraph.text.is_char_boundary(*selection) {
-                *selection -= 1;
-            }
+            *selection = floor_char_boundary(&paragraph.text, *selection);
         }
 
+        // whatever was being composed is replaced by the new text
+        this.widget.composing = None;
+
         if this.cx.is_focused() {
             if this.widget.text() != paragraph.text {
                 this.cx.restart_ime();
@@ -124,6 +196,40 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         }
 
         this.widget.paragraph = paragraph;
+        this.widget.hovered_link = None;
+        this.widget.pressed_link = None;
+        this.widget.validate();
+
+        let has_links = !this.widget.paragraph.links.is_empty();
+        this.cx.set_accepts_focus(EDITABLE || has_links);
+
+        this.cx.request_layout();
+        this.cx.request_draw();
+    }
+
+    /// Remove all of the text, as if it was deleted, `on_change` is called with the empty text.
+    pub fn clear(this: &mut WidgetMut<Self>) {
+        if this.widget.paragraph.text.is_empty() {
+            return;
+        }
+
+        this.widget.paragraph.text.clear();
+        this.widget.composing = None;
+        this.widget.set_cursor(0, false);
+
+        if this.cx.is_focused() && EDITABLE {
+            this.cx.restart_ime();
+            this.cx.set_ime_text(String::new());
+
+            this.widget.set_selection_mut(&mut this.cx);
+        }
+
+        this.cx.defer(this.widget.remove_handles());
+        this.widget.validate();
+
+        if let Some(ref mut on_change) = this.widget.on_change {
+            on_change("");
+        }
 
         this.cx.request_layout();
         this.cx.request_draw();
@@ -139,6 +245,12 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         this.cx.request_draw();
     }
 
+    /// Set the color of the underline drawn below text being composed by an IME.
+    pub fn set_composing_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.composing_color = color;
+        this.cx.request_draw();
+    }
+
     pub fn set_handle_color(this: &mut WidgetMut<Self>, color: Color) {
         this.widget.handle_color = color;
 
@@ -157,6 +269,19 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         }
     }
 
+    /// Set the background color of the toolbar with cut, copy and paste shown on touch, its
+    /// labels use the cursor color.
+    pub fn set_toolbar_color(this: &mut WidgetMut<Self>, color: Color) {
+        this.widget.toolbar_color = color;
+
+        if let Some((_layer, toolbar)) = this.widget.toolbar
+            && let Ok(mut toolbar) = this.cx.get_widget_mut(toolbar)
+        {
+            toolbar.widget.color = color;
+            toolbar.cx.request_draw();
+        }
+    }
+
     pub fn set_handle_size(this: &mut WidgetMut<Self>, size: f32) {
         this.widget.handle_size = size;
         this.cx.request_compose();
@@ -179,6 +304,10 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
     pub fn set_handles_enabled(this: &mut WidgetMut<Self>, enabled: bool) {
         this.widget.handles_enabled = enabled;
 
+        if !enabled {
+            this.cx.defer(this.widget.remove_toolbar());
+        }
+
         if !enabled
             && let Some(window) = this.cx.window()
             && let Some((layer, _handle)) = this.widget.cursor_handle.take()
@@ -212,6 +341,61 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         this.cx.request_draw();
     }
 
+    pub fn set_gutter(this: &mut WidgetMut<Self>, gutter: Option<Gutter>) {
+        this.widget.gutter = gutter;
+        this.widget.line_numbers.clear();
+        this.widget.wrap_indicator = None;
+        this.cx.request_layout();
+    }
+
+    /// Draw every character as a bullet, and disable copying the text, e.g. for passwords.
+    pub fn set_secure(this: &mut WidgetMut<Self>, secure: bool) {
+        if this.widget.secure == secure {
+            return;
+        }
+
+        this.widget.secure = secure;
+
+        if this.cx.is_focused() && EDITABLE {
+            this.cx.set_ime_purpose(this.widget.ime_purpose());
+        }
+
+        this.cx.request_layout();
+        this.cx.request_draw();
+    }
+
+    /// Set a callback choosing the color of the marker drawn behind the number of a line,
+    /// e.g. to show breakpoints, the callback is passed the index of the line.
+    pub fn set_line_marker(
+        this: &mut WidgetMut<Self>,
+        line_marker: impl FnMut(usize) -> Option<Color> + 'static,
+    ) {
+        this.widget.line_marker = Some(Box::new(line_marker));
+        this.cx.request_draw();
+    }
+
+    /// Reject edits that would make `filter` return `false` for the edited text.
+    ///
+    /// Only insertions are filtered, removing text is always allowed.
+    pub fn set_filter(this: &mut WidgetMut<Self>, filter: impl FnMut(&str) -> bool + 'static) {
+        this.widget.filter = Some(Box::new(filter));
+    }
+
+    /// Reject edits that would make the text longer than `max_length` characters.
+    pub fn set_max_length(this: &mut WidgetMut<Self>, max_length: Option<usize>) {
+        this.widget.max_length = max_length;
+    }
+
+    /// Validate the text with `validator`, `on_submit` isn't called while the text is invalid.
+    pub fn set_validator(
+        this: &mut WidgetMut<Self>,
+        validator: impl FnMut(&str) -> bool + 'static,
+    ) {
+        this.widget.validator = Some(Box::new(validator));
+        this.widget.validate();
+        this.cx.request_draw();
+    }
+
     pub fn set_on_change(this: &mut WidgetMut<Self>, on_change: impl FnMut(&str) + 'static) {
         this.widget.on_change = Some(Box::new(on_change));
     }
@@ -220,13 +404,71 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         this.widget.on_submit = Some(Box::new(on_submit));
     }
 
+    /// Set the callback called with the target of a link when it's clicked, tapped, or
+    /// activated with enter while the cursor is in it, see [`Paragraph::push_link`].
+    ///
+    /// Links are only active when the text isn't editable.
+    pub fn set_on_link(this: &mut WidgetMut<Self>, on_link: impl FnMut(&str) + 'static) {
+        this.widget.on_link = Some(Box::new(on_link));
+    }
+
     pub fn text(&self) -> &str {
         &self.paragraph.text
     }
+
+    pub fn paragraph(&self) -> &Paragraph {
+        &self.paragraph
+    }
+
+    /// How far the text is scrolled horizontally to keep the cursor visible.
+    pub fn scroll(&self) -> f32 {
+        self.scroll
+    }
+
+    /// How much wider the text is than the area, this is only non-zero when editable.
+    pub fn overflow(&self) -> f32 {
+        self.overflow
+    }
+
+    /// The byte offset of the cursor in the text.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The selected range of the text, empty at the cursor when nothing is selected.
+    pub fn selection(&self) -> Range<usize> {
+        match self.selection {
+            Some(selection) => self.cursor.min(selection)..self.cursor.max(selection),
+            None => self.cursor..self.cursor,
+        }
+    }
+
+    /// The range of the text being composed by the input method.
+    pub fn composing(&self) -> Option<Range<usize>> {
+        self.composing.clone()
+    }
+
+    /// The maximum number of characters, see [`TextArea::set_max_length`].
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Whether the text is hidden, see [`TextArea::set_secure`].
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    /// Whether the text was accepted by the validator, see [`TextArea::set_validator`].
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
 }
 
 impl<const EDITABLE: bool> TextArea<EDITABLE> {
     fn set_cursor(&mut self, cursor: usize, select: bool) {
+        // positions found in the lines are stale until the next layout, when the text was edited
+        let cursor = floor_char_boundary(&self.paragraph.text, cursor);
+
         if !select {
             self.selection = None
         } else if self.selection.is_none() {
@@ -242,6 +484,8 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
     }
 
     fn find_point(&self, mut point: Point, constrain: bool) -> usize {
+        point.x += self.scroll - self.gutter_width;
+
         if let Some(first) = self.lines.first()
             && let Some(last) = self.lines.last()
             && constrain
@@ -280,6 +524,55 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         line.end_index
     }
 
+    /// The index of the link at `point`, hit against the bounds of its glyphs.
+    fn find_link(&self, mut point: Point) -> Option<usize> {
+        if EDITABLE {
+            return None;
+        }
+
+        point.x += self.scroll - self.gutter_width;
+
+        let line =
+            (self.lines.iter()).find(|line| point.y >= line.top() && point.y <= line.bottom())?;
+
+        let glyph = (line.glyphs.iter())
+            .find(|glyph| point.x >= glyph.bounds.left() && point.x <= glyph.bounds.right())?;
+
+        (self.paragraph.links.iter()).position(|link| link.range.contains(&glyph.start_index))
+    }
+
+    /// The index of the link the cursor is in.
+    fn cursor_link(&self) -> Option<usize> {
+        if EDITABLE {
+            return None;
+        }
+
+        (self.paragraph.links.iter()).position(|link| link.range.contains(&self.cursor))
+    }
+
+    fn set_hovered_link(&mut self, cx: &mut EventCx<'_>, link: Option<usize>) {
+        if self.hovered_link == link {
+            return;
+        }
+
+        self.hovered_link = link;
+
+        match link {
+            Some(..) => cx.set_cursor(CursorIcon::Pointer),
+            None => cx.set_cursor(CursorIcon::Text),
+        }
+
+        cx.request_draw();
+    }
+
+    fn activate_link(&mut self, link: usize) {
+        if let Some(ref mut on_link) = self.on_link
+            && let Some(link) = self.paragraph.links.get(link)
+        {
+            on_link(&link.target);
+        }
+    }
+
     fn get_selection(&self) -> Option<&str> {
         match self.selection {
             Some(selection) => {
@@ -329,6 +622,25 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         };
     }
 
+    fn move_line_start(&mut self, select: bool) {
+        let start = self.current_line().map_or(0, |line| line.start_index);
+        self.set_cursor(start, select);
+    }
+
+    fn move_line_end(&mut self, select: bool) {
+        let end = match self.current_line() {
+            Some(line) => match self.paragraph.text.get(line.start_index..line.end_index) {
+                // don't move past the newline ending the line
+                Some(text) => line.start_index + text.trim_end_matches(['\n', '\r']).len(),
+                None => self.paragraph.text.len(),
+            },
+
+            None => self.paragraph.text.len(),
+        };
+
+        self.set_cursor(end, select);
+    }
+
     fn move_start(&mut self) {
         if let Some(selection) = self.selection {
             self.set_cursor(self.cursor.min(selection), false);
@@ -424,10 +736,128 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         true
     }
 
-    fn insert_text(&mut self, text: &str) {
+    /// Insert `text` at the cursor, returns whether it was accepted by the filter.
+    fn insert_text(&mut self, text: &str) -> bool {
+        if !self.accepts_insert(text) {
+            return false;
+        }
+
         self.remove_selection();
         self.paragraph.text.insert_str(self.cursor, text);
         self.set_cursor(self.cursor + text.len(), false);
+
+        true
+    }
+
+    /// Whether the filter, and the maximum length, accept the text resulting from inserting
+    /// `text` at the cursor.
+    fn accepts_insert(&mut self, text: &str) -> bool {
+        if self.filter.is_none() && self.max_length.is_none() {
+            return true;
+        }
+
+        let (start, end) = match self.selection {
+            Some(selection) => (
+                self.cursor.min(selection),
+                self.cursor.max(selection),
+            ),
+            None => (self.cursor, self.cursor),
+        };
+
+        let mut edited = self.paragraph.text.clone();
+        edited.replace_range(start..end, text);
+
+        if let Some(max_length) = self.max_length
+            && edited.chars().count() > max_length
+        {
+            return false;
+        }
+
+        match self.filter {
+            Some(ref mut filter) => filter(&edited),
+            None => true,
+        }
+    }
+
+    fn ime_purpose(&self) -> ImePurpose {
+        match self.secure {
+            true => ImePurpose::Password,
+            false => ImePurpose::Normal,
+        }
+    }
+
+    fn validate(&mut self) {
+        self.is_valid = match self.validator {
+            Some(ref mut validator) => validator(&self.paragraph.text),
+            None => true,
+        };
+    }
+
+    /// Select the text being composed, so that it's replaced by the next insertion.
+    fn select_composing(&mut self) {
+        let Some(composing) = self.composing.take() else {
+            return;
+        };
+
+        // edits made while composing might have moved the range out of the text
+        let start = floor_char_boundary(&self.paragraph.text, composing.start);
+        let end = floor_char_boundary(&self.paragraph.text, composing.end);
+
+        self.cursor = end;
+        self.selection = (start != end).then_some(start);
+    }
+
+    fn draw_composing(&self, canvas: &mut dyn Canvas) {
+        if let Some(ref composing) = self.composing {
+            self.draw_underline(
+                canvas,
+                composing,
+                &Paint::from(self.composing_color),
+            );
+        }
+    }
+
+    /// Underline a link with the paint of its text.
+    fn draw_link(&self, canvas: &mut dyn Canvas, link: usize) {
+        let Some(link) = self.paragraph.links.get(link) else {
+            return;
+        };
+
+        let style = (self.paragraph.sections.iter().rev())
+            .find(|(start, _)| *start <= link.range.start)
+            .map(|(_, style)| style);
+
+        if let Some(style) = style {
+            self.draw_underline(canvas, &link.range, &style.paint);
+        }
+    }
+
+    fn draw_underline(&self, canvas: &mut dyn Canvas, range: &Range<usize>, paint: &Paint) {
+        for line in &self.lines {
+            let mut left = line.right();
+            let mut right = line.left();
+
+            for glyph in &line.glyphs {
+                if glyph.start_index >= range.start && glyph.end_index <= range.end {
+                    left = left.min(glyph.bounds.left());
+                    right = right.max(glyph.bounds.right());
+                }
+            }
+
+            if left >= right {
+                continue;
+            }
+
+            let top = line.baseline + line.descent / 3.0;
+            let thickness = f32::max(line.descent / 6.0, 1.0);
+
+            let rect = Rect {
+                min: Point::new(left, top),
+                max: Point::new(right, top + thickness),
+            };
+
+            canvas.draw_rect(rect, CornerRadius::all(0.0), paint);
+        }
     }
 
     fn draw_selection(&self, canvas: &mut dyn Canvas) {
@@ -550,7 +980,7 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             None => (self.cursor, self.cursor),
         };
 
-        cx.set_ime_selection(start..end, None);
+        cx.set_ime_selection(start..end, self.composing.clone());
     }
 
     fn set_selection_mut(&mut self, cx: &mut MutCx<'_>) {
@@ -570,10 +1000,19 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             None => (self.cursor, self.cursor),
         };
 
-        cx.set_ime_selection(start..end, None);
+        cx.set_ime_selection(start..end, self.composing.clone());
     }
 
     fn text_changed(&mut self, cx: &mut EventCx<'_>) {
+        self.composing_changed(cx);
+        self.validate();
+
+        if let Some(ref mut on_change) = self.on_change {
+            on_change(&self.paragraph.text);
+        }
+    }
+
+    fn text_changed_mut(&mut self, cx: &mut MutCx<'_>) {
         cx.set_ime_text(self.text().to_owned());
 
         cx.request_layout();
@@ -581,87 +1020,350 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         cx.request_draw();
         cx.request_animate();
 
+        cx.defer(self.remove_handles());
+        self.validate();
+
         if let Some(ref mut on_change) = self.on_change {
             on_change(&self.paragraph.text);
         }
+    }
+
+    /// Like [`Self::text_changed`], but for text that is still being composed and therefore
+    /// isn't reported to `on_change` yet.
+    fn composing_changed(&mut self, cx: &mut EventCx<'_>) {
+        cx.set_ime_text(self.text().to_owned());
+
+        cx.request_layout();
+        cx.request_compose();
+        cx.request_draw();
+        cx.request_animate();
 
         cx.defer(self.remove_handles());
     }
 
-    fn create_handle(&mut self, cx: &mut EventCx<'_>, offset: usize, is_cursor: bool) {
-        if let Some(window) = cx.window()
-            && self.handles_enabled
+    /// Select the word around the cursor, returns whether anything was selected.
+    fn select_word(&mut self) -> bool {
+        let mut selection = self.cursor;
+
+        while let Some(c) = self.paragraph.text[self.cursor..].chars().next()
+            && !c.is_whitespace()
         {
-            let (position, offset) = self.handle_position(cx.rect(), offset);
-            let position = cx.global_transform() * position;
+            self.cursor += c.len_utf8();
+        }
+
+        while let Some(c) = self.paragraph.text[..selection].chars().next_back()
+            && !c.is_whitespace()
+        {
+            selection -= c.len_utf8();
+        }
+
+        if self.cursor == selection {
+            return false;
+        }
 
-            let size = self.handle_size;
-            let color = self.handle_color;
+        self.selection = Some(selection);
+        true
+    }
+
+    fn create_handle(&mut self, cx: &mut EventCx<'_>, offset: usize, is_cursor: bool) {
+        if let Some(window) = cx.window() {
             let this = WidgetId::<Self>::downcast_unchecked(cx.id());
+            let transform = cx.global_transform();
+
+            cx.defer(self.build_handle(
+                window,
+                this,
+                cx.rect(),
+                transform,
+                offset,
+                is_cursor,
+            ));
+        }
+    }
 
-            cx.defer(move |world| {
-                if let Ok(text_area) = world.get_widget(this) {
-                    if is_cursor && text_area.widget.cursor_handle.is_some() {
-                        return;
-                    }
+    fn build_handle(
+        &mut self,
+        window: WindowId,
+        this: WidgetId<Self>,
+        rect: Rect,
+        transform: Affine,
+        offset: usize,
+        is_cursor: bool,
+    ) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
+        let (position, offset) = self.handle_position(rect, offset);
+        let position = transform * position;
+
+        let enabled = self.handles_enabled;
+        let size = self.handle_size;
+        let color = self.handle_color;
 
-                    if !is_cursor && text_area.widget.selection_handle.is_some() {
-                        return;
-                    }
+        move |world| {
+            if !enabled {
+                return;
+            }
+
+            if let Ok(text_area) = world.get_widget(this) {
+                if is_cursor && text_area.widget.cursor_handle.is_some() {
+                    return;
                 }
 
-                let handle = world
-                    .build_widget(Handle {
-                        size,
-                        offset,
-                        color,
-                        is_cursor,
-                        text_area: this,
-                    })
-                    .finish()
-                    .id();
-
-                let layer = world.add_layer(window, position, handle);
-
-                if let Ok(mut text_area) = world.get_widget_mut(this) {
-                    if is_cursor {
-                        text_area.widget.cursor_handle = Some((layer, handle));
-                    } else {
-                        text_area.widget.selection_handle = Some((layer, handle));
-                    }
+                if !is_cursor && text_area.widget.selection_handle.is_some() {
+                    return;
                 }
-            });
+            }
+
+            let handle = world
+                .build_widget(Handle {
+                    size,
+                    offset,
+                    color,
+                    is_cursor,
+                    text_area: this,
+                })
+                .finish()
+                .id();
+
+            let layer = world.add_layer(window, position, handle);
+
+            if let Ok(mut text_area) = world.get_widget_mut(this) {
+                if is_cursor {
+                    text_area.widget.cursor_handle = Some((layer, handle));
+                } else {
+                    text_area.widget.selection_handle = Some((layer, handle));
+                }
+            }
         }
     }
 
-    fn remove_handles(&mut self) -> impl FnOnce(&mut World) + 'static {
-        let cursor = self.remove_cursor_handle();
-        let selection = self.remove_selection_handle();
+    /// Show the toolbar with cut, copy and paste above the selection, or the cursor.
+    fn show_toolbar(&mut self, cx: &mut EventCx<'_>) {
+        if let Some(window) = cx.window() {
+            let this = WidgetId::<Self>::downcast_unchecked(cx.id());
+            let transform = cx.global_transform();
 
-        move |world| {
-            cursor(world);
-            selection(world);
+            cx.defer(self.build_toolbar(window, this, cx.rect(), transform));
         }
     }
 
-    fn remove_cursor_handle(&mut self) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
-        let cursor_handle = self.cursor_handle.take();
+    fn build_toolbar(
+        &mut self,
+        window: WindowId,
+        this: WidgetId<Self>,
+        rect: Rect,
+        transform: Affine,
+    ) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
+        let position = transform * self.toolbar_position(rect);
+
+        let enabled = self.handles_enabled;
+        let actions = self.toolbar_actions();
+        let color = self.toolbar_color;
+        let text_color = self.cursor_color;
+        let font_size = self.toolbar_font_size();
+        let font_family = self.toolbar_font_family();
 
         move |world| {
-            if let Some((layer, widget)) = cursor_handle
-                && let Some(window) = world
-                    .get_widget(widget)
-                    .ok()
-                    .and_then(|widget| widget.cx.window())
+            if !enabled {
+                return;
+            }
+
+            if let Ok(text_area) = world.get_widget(this)
+                && text_area.widget.toolbar.is_some()
             {
-                world.remove_layer(window, layer);
+                return;
+            }
+
+            let toolbar = world
+                .build_widget(Toolbar {
+                    actions,
+                    labels: Vec::new(),
+                    font_size,
+                    font_family,
+                    color,
+                    text_color,
+                    text_area: this,
+                })
+                .finish()
+                .id();
+
+            let layer = world.add_layer(window, position, toolbar);
+
+            if let Ok(mut text_area) = world.get_widget_mut(this) {
+                text_area.widget.toolbar = Some((layer, toolbar));
             }
         }
     }
 
-    fn remove_selection_handle(&mut self) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
-        let selection_handle = self.selection_handle.take();
-
+    /// Apply an `action` chosen from the toolbar of the text area `this`.
+    fn toolbar_action(world: &mut World, this: WidgetId<Self>, action: ToolbarAction) {
+        let Ok(mut text_area) = world.get_widget_mut(this) else {
+            return;
+        };
+
+        let selection = text_area.widget.get_selection().map(str::to_owned);
+        let window = text_area.cx.window();
+        let rect = text_area.cx.rect();
+        let transform = text_area.cx.global_transform();
+
+        let remove = text_area.widget.remove_toolbar();
+        let mut build = Vec::new();
+
+        match action {
+            ToolbarAction::Cut => {
+                text_area.widget.remove_selection();
+                text_area.widget.text_changed_mut(&mut text_area.cx);
+                text_area.widget.set_selection_mut(&mut text_area.cx);
+            }
+
+            ToolbarAction::Copy | ToolbarAction::Paste => {}
+
+            ToolbarAction::SelectAll => {
+                let len = text_area.widget.paragraph.text.len();
+                text_area.widget.cursor = len;
+                text_area.widget.selection = Some(0);
+                text_area.widget.set_selection_mut(&mut text_area.cx);
+
+                text_area.cx.request_compose();
+                text_area.cx.request_draw();
+
+                if let Some(window) = window {
+                    let widget = &mut text_area.widget;
+
+                    build.push(Box::new(
+                        widget.build_handle(window, this, rect, transform, len, true),
+                    )
+                        as Box<dyn FnOnce(&mut World)>);
+
+                    build.push(Box::new(widget.build_handle(
+                        window, this, rect, transform, 0, false,
+                    )));
+
+                    // show the toolbar again, now with the actions for the selection
+                    build.push(Box::new(
+                        widget.build_toolbar(window, this, rect, transform),
+                    ));
+                }
+            }
+        }
+
+        drop(text_area);
+
+        if let Some(selection) = selection
+            && matches!(
+                action,
+                ToolbarAction::Cut | ToolbarAction::Copy
+            )
+        {
+            world.set_clipboard(selection);
+        }
+
+        remove(world);
+
+        for build in build {
+            build(world);
+        }
+    }
+
+    fn toolbar_actions(&self) -> Vec<ToolbarAction> {
+        let mut actions = Vec::new();
+
+        // secure text never leaves the text area
+        if self.selection.is_some() && !self.secure {
+            if EDITABLE {
+                actions.push(ToolbarAction::Cut);
+            }
+
+            actions.push(ToolbarAction::Copy);
+        }
+
+        if EDITABLE {
+            actions.push(ToolbarAction::Paste);
+        }
+
+        if self.selection().len() < self.paragraph.text.len() {
+            actions.push(ToolbarAction::SelectAll);
+        }
+
+        actions
+    }
+
+    /// The labels of the toolbar use the font of the start of the text.
+    fn toolbar_font_size(&self) -> f32 {
+        (self.paragraph.sections.first()).map_or(16.0, |(_, style)| style.font_size)
+    }
+
+    fn toolbar_font_family(&self) -> String {
+        (self.paragraph.sections.first()).map_or_else(String::new, |(_, style)| {
+            style.font_family.clone()
+        })
+    }
+
+    /// The position of the toolbar above the start of the selection, relative to the text area.
+    fn toolbar_position(&self, rect: Rect) -> Point {
+        let offset = self.selection().start;
+        let height = Toolbar::<EDITABLE>::height(self.toolbar_font_size());
+
+        match self
+            .lines
+            .iter()
+            .find(|l| offset >= l.start_index && offset <= l.end_index)
+        {
+            Some(line) => Point::new(
+                Self::cursor_offset_in_line(offset, line) - self.scroll + self.gutter_width,
+                line.top() - height - TOOLBAR_GAP,
+            ),
+
+            None => Point::new(
+                rect.left(),
+                rect.top() - height - TOOLBAR_GAP,
+            ),
+        }
+    }
+
+    fn remove_handles(&mut self) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
+        let cursor = self.remove_cursor_handle();
+        let selection = self.remove_selection_handle();
+        let toolbar = self.remove_toolbar();
+
+        move |world| {
+            cursor(world);
+            selection(world);
+            toolbar(world);
+        }
+    }
+
+    fn remove_toolbar(&mut self) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
+        let toolbar = self.toolbar.take();
+
+        move |world| {
+            if let Some((layer, widget)) = toolbar
+                && let Some(window) = world
+                    .get_widget(widget)
+                    .ok()
+                    .and_then(|widget| widget.cx.window())
+            {
+                world.remove_layer(window, layer);
+            }
+        }
+    }
+
+    fn remove_cursor_handle(&mut self) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
+        let cursor_handle = self.cursor_handle.take();
+
+        move |world| {
+            if let Some((layer, widget)) = cursor_handle
+                && let Some(window) = world
+                    .get_widget(widget)
+                    .ok()
+                    .and_then(|widget| widget.cx.window())
+            {
+                world.remove_layer(window, layer);
+            }
+        }
+    }
+
+    fn remove_selection_handle(&mut self) -> impl FnOnce(&mut World) + 'static + use<EDITABLE> {
+        let selection_handle = self.selection_handle.take();
+
         move |world| {
             if let Some((layer, widget)) = selection_handle
                 && let Some(window) = world
@@ -680,7 +1382,8 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             .iter()
             .find(|l| offset >= l.start_index && offset <= l.end_index)
         {
-            let offset = Self::cursor_offset_in_line(offset, line);
+            let offset =
+                Self::cursor_offset_in_line(offset, line) - self.scroll + self.gutter_width;
             let position = Point::new(
                 offset - self.handle_size / 2.0,
                 line.bottom(),
@@ -702,16 +1405,201 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             )
         }
     }
+
+    /// Scroll horizontally so that the cursor is within `width`.
+    fn scroll_to_cursor(&mut self, width: f32) {
+        let Some(line) = self.current_line() else {
+            return;
+        };
+
+        let offset = Self::cursor_offset_in_line(self.cursor, line);
+
+        if offset < self.scroll {
+            self.scroll = offset;
+        }
+
+        // leave room for the cursor itself
+        if offset + 1.0 > self.scroll + width {
+            self.scroll = offset + 1.0 - width;
+        }
+
+        self.scroll = self.scroll.clamp(0.0, self.overflow);
+    }
+
+    /// The part of `rect` the text is drawn in, right of the gutter.
+    fn text_rect(&self, mut rect: Rect) -> Rect {
+        rect.min.x = f32::min(
+            rect.min.x + self.gutter_width,
+            rect.max.x,
+        );
+        rect
+    }
+
+    fn layout_gutter(&mut self, cx: &mut LayoutCx<'_>) -> f32 {
+        let Some(ref gutter) = self.gutter else {
+            self.line_numbers.clear();
+            return 0.0;
+        };
+
+        // numbers are kept between layouts, as the number of lines rarely changes much
+        let count = self.paragraph.text.split('\n').count();
+        self.line_numbers.truncate(count);
+
+        while self.line_numbers.len() < count {
+            let mut number = Paragraph::new(
+                self.paragraph.line_height,
+                TextAlign::End,
+                TextWrap::None,
+            );
+
+            let text = (self.line_numbers.len() + 1).to_string();
+            number.push(text, gutter.style.clone());
+            self.line_numbers.push(number);
+        }
+
+        if self.wrap_indicator.is_none() {
+            let mut style = gutter.style.clone();
+            style.paint = Paint::from(gutter.wrap_color);
+
+            let mut indicator = Paragraph::new(
+                self.paragraph.line_height,
+                TextAlign::End,
+                TextWrap::None,
+            );
+
+            indicator.push("↪", style);
+            self.wrap_indicator = Some(indicator);
+        }
+
+        // digits usually have the same width, so the last number is the widest
+        if let Some(last) = self.line_numbers.last() {
+            let lines = cx.layout_text(last, f32::INFINITY);
+
+            self.number_width = cx.measure_text(last, f32::INFINITY).width;
+            self.number_baseline = lines.first().map_or(0.0, |line| line.baseline);
+        }
+
+        self.number_width + gutter.padding * 2.0
+    }
+
+    fn draw_gutter(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        let Some(ref gutter) = self.gutter else {
+            return;
+        };
+
+        let rect = Rect {
+            min: cx.rect().min,
+            max: Point::new(
+                cx.rect().left() + self.gutter_width,
+                cx.rect().bottom(),
+            ),
+        };
+
+        canvas.draw_rect(
+            rect,
+            CornerRadius::all(0.0),
+            &Paint::from(gutter.background_color),
+        );
+
+        let text = &self.paragraph.text;
+        let mut number = 0;
+        let mut counted = 0;
+
+        for line in &self.lines {
+            number += text[counted..line.start_index].matches('\n').count();
+            counted = line.start_index;
+
+            let offset = Offset::new(
+                gutter.padding,
+                line.baseline - self.number_baseline,
+            );
+
+            // lines not starting after a newline continue a soft-wrapped line
+            if line.start_index > 0 && !text[..line.start_index].ends_with('\n') {
+                if let Some(ref indicator) = self.wrap_indicator {
+                    canvas.draw_text(indicator, self.number_width, offset);
+                }
+
+                continue;
+            }
+
+            if let Some(ref mut line_marker) = self.line_marker
+                && let Some(color) = line_marker(number)
+            {
+                let size = f32::min(line.height, self.gutter_width);
+                let center = Point::new(rect.center().x, line.rect().center().y);
+
+                let marker = Rect::min_size(
+                    center - Offset::all(size / 2.0),
+                    Size::all(size),
+                );
+
+                canvas.draw_rect(
+                    marker,
+                    CornerRadius::all(size / 2.0),
+                    &Paint::from(color),
+                );
+            }
+
+            if let Some(paragraph) = self.line_numbers.get(number) {
+                canvas.draw_text(paragraph, self.number_width, offset);
+            }
+        }
+    }
 }
 
 impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
-        self.lines = cx.layout_text(&self.paragraph, space.max.width);
-        let size = cx.measure_text(&self.paragraph, space.max.width);
-        space.constrain(size)
+        self.gutter_width = self.layout_gutter(cx);
+
+        let max_width = f32::max(space.max.width - self.gutter_width, 0.0);
+
+        self.masked = self.secure.then(|| mask_paragraph(&self.paragraph));
+        let paragraph = self.masked.as_ref().unwrap_or(&self.paragraph);
+
+        self.lines = cx.layout_text(paragraph, max_width);
+        let mut size = cx.measure_text(paragraph, max_width);
+
+        if self.secure {
+            unmask_lines(&mut self.lines, &self.paragraph.text);
+        }
+
+        size.width += self.gutter_width;
+
+        let constrained = space.constrain(size);
+
+        // text that doesn't fit is scrolled horizontally, so the cursor can be followed
+        self.overflow = match EDITABLE {
+            true => f32::max(
+                size.width + 1.0 - constrained.width,
+                0.0,
+            ),
+            false => 0.0,
+        };
+
+        self.scroll = self.scroll.clamp(0.0, self.overflow);
+
+        match self.overflow > 0.0 {
+            true => cx.set_clip(Rect::min_size(
+                Point::ORIGIN,
+                constrained,
+            )),
+            false => cx.set_clip(None),
+        }
+
+        constrained
     }
 
     fn compose(&mut self, cx: &mut ComposeCx<'_>) {
+        if cx.is_focused() {
+            let scroll = self.scroll;
+            self.scroll_to_cursor(cx.width() - self.gutter_width);
+
+            if self.scroll != scroll {
+                cx.request_draw();
+            }
+        }
+
         if let Some(window) = cx.window()
             && let Some((layer, _handle)) = self.cursor_handle
         {
@@ -734,45 +1622,106 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 world.set_layer_position(window, layer, position);
             });
         }
+
+        if let Some(window) = cx.window()
+            && let Some((layer, toolbar)) = self.toolbar
+        {
+            let position = cx.global_transform() * self.toolbar_position(cx.rect());
+            let actions = self.toolbar_actions();
+
+            if let Ok(mut toolbar) = cx.get_widget_mut(toolbar)
+                && toolbar.widget.actions != actions
+            {
+                toolbar.widget.actions = actions;
+                toolbar.cx.request_layout();
+            }
+
+            cx.defer(move |world| {
+                world.set_layer_position(window, layer, position);
+            });
+        }
     }
 
     fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
-        canvas.draw_text(
-            &self.paragraph,
-            cx.width(),
-            Offset::all(0.0),
-        );
+        let transform = Affine::translate(Offset::new(
+            self.gutter_width - self.scroll,
+            0.0,
+        ));
+
+        canvas.transform(transform, &mut |canvas| {
+            canvas.draw_text(
+                self.masked.as_ref().unwrap_or(&self.paragraph),
+                cx.width() - self.gutter_width,
+                Offset::all(0.0),
+            );
 
-        if !cx.is_focused() {
-            return;
-        }
+            if let Some(link) = self.hovered_link {
+                self.draw_link(canvas, link);
+            }
 
-        self.draw_selection(canvas);
+            if !cx.is_focused() {
+                return;
+            }
 
-        if cx.is_window_focused() && EDITABLE {
-            self.draw_cursor(cx, canvas);
-        }
+            if let Some(link) = self.cursor_link()
+                && self.hovered_link != Some(link)
+            {
+                self.draw_link(canvas, link);
+            }
+
+            self.draw_composing(canvas);
+            self.draw_selection(canvas);
+
+            if cx.is_window_focused() && EDITABLE {
+                self.draw_cursor(cx, canvas);
+            }
+        });
+
+        // the gutter stays in place while the text is scrolled beneath it
+        self.draw_gutter(cx, canvas);
     }
 
     fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
         match update {
             Update::Focused(..) | Update::WindowFocused(..) => {
-                // remove the handle
+                // remove the handle, and show the start of the text again
                 if let Update::Focused(false) = update {
                     cx.defer(self.remove_handles());
+                    self.scroll = 0.0;
                 }
 
+                cx.request_compose();
                 cx.request_draw();
 
                 if cx.is_focused() {
                     cx.request_animate()
                 }
+
+                if let Update::Focused(true) = update
+                    && EDITABLE
+                {
+                    cx.set_ime_purpose(self.ime_purpose());
+                }
+
+                // focusing read-only text with the keyboard starts at its first link
+                if let Update::Focused(true) = update
+                    && !EDITABLE
+                    && self.cursor_link().is_none()
+                    && let Some(link) = self.paragraph.links.first()
+                {
+                    self.cursor = link.range.start;
+                    self.selection = None;
+                }
             }
 
             Update::Hovered(true) => {
                 cx.set_cursor(CursorIcon::Text);
             }
 
+            Update::Hovered(false) if self.hovered_link.take().is_some() => {
+                cx.request_draw();
+            }
+
             Update::Removed => {
                 cx.defer(self.remove_handles());
             }
@@ -784,6 +1733,26 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
         cx.request_draw();
 
+        // scroll while a selection is dragged past the edges
+        if let Some(position) = self.drag_position
+            && self.overflow > 0.0
+        {
+            let rect = self.text_rect(cx.rect());
+            let velocity = auto_scroll_velocity(rect, position).x;
+            let scroll = (self.scroll + velocity * dt.as_secs_f32()).clamp(0.0, self.overflow);
+
+            if scroll != self.scroll {
+                self.scroll = scroll;
+
+                let x = position.x.clamp(rect.left(), rect.right());
+                let cursor = self.find_point(Point::new(x, position.y), false);
+                self.set_cursor(cursor, true);
+
+                cx.request_compose();
+                cx.request_animate();
+            }
+        }
+
         if cx.is_focused() && self.selection.is_none() && cx.is_window_focused() {
             self.blink += dt.as_secs_f32() * self.blink_rate;
 
@@ -797,6 +1766,9 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 let local = cx.global_transform().inverse() * event.position;
                 let cursor = self.find_point(local, false);
 
+                self.pressed_link = self.find_link(local);
+
+                self.selecting = Some(event.pointer);
                 self.set_cursor(cursor, false);
                 self.set_selection_event(cx);
 
@@ -808,8 +1780,16 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 PointerPropagate::Capture
             }
 
-            PointerEvent::Move(event) if cx.is_active() => {
-                let local = cx.global_transform().inverse() * event.position;
+            PointerEvent::Move(event) if self.selecting == Some(event.pointer) => {
+                let mut local = cx.global_transform().inverse() * event.position;
+
+                // past the edges the cursor stays at the edge, while the text is scrolled
+                if self.overflow > 0.0 {
+                    self.drag_position = Some(local);
+                    let rect = self.text_rect(cx.rect());
+                    local.x = local.x.clamp(rect.left(), rect.right());
+                }
+
                 let cursor = self.find_point(local, false);
 
                 self.set_cursor(cursor, true);
@@ -822,6 +1802,34 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 PointerPropagate::Bubble
             }
 
+            PointerEvent::Up(event) if self.selecting == Some(event.pointer) => {
+                self.selecting = None;
+                self.drag_position = None;
+
+                // links are activated when released on, unless text was selected on the way
+                let local = cx.global_transform().inverse() * event.position;
+
+                if let Some(link) = self.pressed_link.take()
+                    && self.find_link(local) == Some(link)
+                    && self.selection.is_none()
+                {
+                    self.activate_link(link);
+                }
+
+                // the selection might have been extended while scrolling
+                self.set_selection_event(cx);
+
+                PointerPropagate::Bubble
+            }
+
+            PointerEvent::Move(event) if !EDITABLE => {
+                let local = cx.global_transform().inverse() * event.position;
+                let link = self.find_link(local);
+                self.set_hovered_link(cx, link);
+
+                PointerPropagate::Bubble
+            }
+
             _ => PointerPropagate::Bubble,
         }
     }
@@ -832,6 +1840,27 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 let local = cx.global_transform().inverse() * event.position;
                 let cursor = self.find_point(local, true);
 
+                if let Some(link) = self.find_link(local) {
+                    self.activate_link(link);
+                    return TouchPropagate::Handled;
+                }
+
+                // tapping the cursor again toggles the toolbar
+                if cx.is_focused()
+                    && self.selection.is_none()
+                    && self.cursor == cursor
+                    && self.cursor_handle.is_some()
+                {
+                    match self.toolbar {
+                        Some(..) => cx.defer(self.remove_toolbar()),
+                        None => self.show_toolbar(cx),
+                    }
+
+                    return TouchPropagate::Handled;
+                }
+
+                cx.defer(self.remove_toolbar());
+
                 self.set_cursor(cursor, false);
                 self.set_selection_event(cx);
 
@@ -849,32 +1878,43 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
 
             TouchEvent::Gesture(Gesture::DoubleTap(..)) if cx.is_focused() => {
                 // select the tapped word
+                if self.select_word() {
+                    self.set_selection_event(cx);
 
-                let mut selection = self.cursor;
+                    cx.request_compose();
+                    cx.request_draw();
 
-                while let Some(c) = self.paragraph.text[self.cursor..].chars().next()
-                    && !c.is_whitespace()
-                {
-                    self.cursor += c.len_utf8();
+                    cx.defer(self.remove_handles());
+                    self.create_handle(cx, self.cursor, true);
+                    self.create_handle(cx, self.selection.unwrap_or(0), false);
+                    self.show_toolbar(cx);
                 }
 
-                while let Some(c) = self.paragraph.text[..selection].chars().next_back()
-                    && !c.is_whitespace()
-                {
-                    selection -= c.len_utf8();
-                }
+                TouchPropagate::Handled
+            }
 
-                if self.cursor != selection {
-                    self.selection = Some(selection);
+            TouchEvent::Gesture(Gesture::LongTap(event)) => {
+                // select the pressed word, or show the toolbar if there is no word
+                let local = cx.global_transform().inverse() * event.position;
+                let cursor = self.find_point(local, true);
 
-                    cx.request_compose();
-                    cx.request_draw();
+                self.set_cursor(cursor, false);
+                cx.defer(self.remove_handles());
 
-                    cx.defer(self.remove_handles());
-                    self.create_handle(cx, self.cursor, true);
-                    self.create_handle(cx, selection, false);
+                if self.select_word() {
+                    self.create_handle(cx, self.selection.unwrap_or(0), false);
                 }
 
+                self.set_selection_event(cx);
+
+                cx.request_compose();
+                cx.request_draw();
+                cx.request_focus();
+                cx.request_animate();
+
+                self.create_handle(cx, self.cursor, true);
+                self.show_toolbar(cx);
+
                 TouchPropagate::Handled
             }
 
@@ -892,13 +1932,20 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
 
                 match event.key {
                     Key::Character(ref c) if c == "c" && action_mod => {
-                        if let Some(selection) = self.get_selection() {
+                        if !self.secure
+                            && let Some(selection) = self.get_selection()
+                        {
                             cx.set_clipboard(selection.to_owned());
                         }
 
                         Propagate::Handled
                     }
 
+                    // cutting secure text would copy it, so it's ignored
+                    Key::Character(ref c) if c == "x" && action_mod && self.secure => {
+                        Propagate::Handled
+                    }
+
                     Key::Character(ref c) if c == "x" && action_mod => {
                         if let Some(selection) = self.get_selection() {
                             cx.set_clipboard(selection.to_owned());
@@ -924,6 +1971,57 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         Propagate::Handled
                     }
 
+                    Key::Named(NamedKey::Home) => {
+                        // on macos home and end always move to the ends of the text
+                        match event.modifiers.ctrl() || cfg!(target_os = "macos") {
+                            true => self.set_cursor(0, event.modifiers.shift()),
+                            false => self.move_line_start(event.modifiers.shift()),
+                        }
+
+                        self.set_selection_event(cx);
+
+                        cx.request_compose();
+                        cx.request_draw();
+
+                        Propagate::Handled
+                    }
+
+                    Key::Named(NamedKey::End) => {
+                        let end = self.paragraph.text.len();
+
+                        match event.modifiers.ctrl() || cfg!(target_os = "macos") {
+                            true => self.set_cursor(end, event.modifiers.shift()),
+                            false => self.move_line_end(event.modifiers.shift()),
+                        }
+
+                        self.set_selection_event(cx);
+
+                        cx.request_compose();
+                        cx.request_draw();
+
+                        Propagate::Handled
+                    }
+
+                    Key::Named(NamedKey::ArrowLeft) if action_mod && cfg!(target_os = "macos") => {
+                        self.move_line_start(event.modifiers.shift());
+                        self.set_selection_event(cx);
+
+                        cx.request_compose();
+                        cx.request_draw();
+
+                        Propagate::Handled
+                    }
+
+                    Key::Named(NamedKey::ArrowRight) if action_mod && cfg!(target_os = "macos") => {
+                        self.move_line_end(event.modifiers.shift());
+                        self.set_selection_event(cx);
+
+                        cx.request_compose();
+                        cx.request_draw();
+
+                        Propagate::Handled
+                    }
+
                     Key::Named(NamedKey::ArrowRight) => {
                         self.move_forward(event.modifiers.shift());
                         self.set_selection_event(cx);
@@ -997,6 +2095,14 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         Propagate::Handled
                     }
 
+                    Key::Named(NamedKey::Enter) if self.cursor_link().is_some() => {
+                        if let Some(link) = self.cursor_link() {
+                            self.activate_link(link);
+                        }
+
+                        Propagate::Handled
+                    }
+
                     Key::Named(NamedKey::Enter)
                         if self
                             .newline_behaviour
@@ -1017,6 +2123,11 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                             .insert_newline(event.modifiers.shift())
                             && EDITABLE =>
                     {
+                        // invalid text can't be submitted, the user has to correct it first
+                        if !self.is_valid {
+                            return Propagate::Handled;
+                        }
+
                         if let Some(ref mut on_submit) = self.on_submit {
                             on_submit(&self.paragraph.text);
                         }
@@ -1028,6 +2139,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         if self.submit_behaviour.clear_text {
                             self.paragraph.text.clear();
                             self.set_cursor(0, false);
+                            self.validate();
                         }
 
                         cx.request_layout();
@@ -1065,11 +2177,20 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 }
 
                 ImeEvent::Select(selection) => {
-                    if selection.start == selection.end {
-                        self.cursor = selection.start;
+                    // the input method might select past the text, or inside a character
+                    let start = floor_char_boundary(&self.paragraph.text, selection.start);
+                    let end = floor_char_boundary(&self.paragraph.text, selection.end);
+
+                    if start == end {
+                        self.cursor = start;
                     } else {
-                        self.selection = Some(selection.start);
-                        self.cursor = selection.end;
+                        self.selection = Some(start);
+                        self.cursor = end;
+
+                        // the selection is about to be replaced, which ends the composition
+                        if self.composing.take().is_some() {
+                            self.text_changed(cx);
+                        }
 
                         cx.request_compose();
                         cx.request_draw();
@@ -1078,7 +2199,34 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     Propagate::Handled
                 }
 
+                ImeEvent::Preedit { text, cursor } => {
+                    self.select_composing();
+
+                    let start = match self.selection {
+                        Some(selection) => usize::min(self.cursor, selection),
+                        None => self.cursor,
+                    };
+
+                    let inserted = self.insert_text(text);
+
+                    if inserted && !text.is_empty() {
+                        self.composing = Some(start..start + text.len());
+                    }
+
+                    if let Some(cursor) = cursor
+                        && text.is_char_boundary(cursor.end)
+                    {
+                        self.set_cursor(start + cursor.end, false);
+                    }
+
+                    self.composing_changed(cx);
+                    self.set_selection_event(cx);
+
+                    Propagate::Handled
+                }
+
                 ImeEvent::Commit(text) => {
+                    self.select_composing();
                     self.insert_text(text);
 
                     self.text_changed(cx);
@@ -1087,13 +2235,25 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     Propagate::Handled
                 }
 
-                ImeEvent::End => Propagate::Handled,
+                ImeEvent::End => {
+                    // the composed text is kept as if it was committed
+                    if self.composing.take().is_some() {
+                        self.text_changed(cx);
+                        self.set_selection_event(cx);
+                    }
+
+                    Propagate::Handled
+                }
             },
 
             _ => Propagate::Bubble,
         }
     }
 
+    fn paragraph(&self) -> Option<&Paragraph> {
+        Some(&self.paragraph)
+    }
+
     fn accepts_pointer() -> bool {
         true
     }
@@ -1107,6 +2267,62 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
     }
 }
 
+/// The character every character of secure text is drawn as.
+const BULLET: char = '\u{2022}';
+
+/// Replace every character of `paragraph` by a [`BULLET`], keeping the styles.
+fn mask_paragraph(paragraph: &Paragraph) -> Paragraph {
+    let mut masked = Paragraph::new(
+        paragraph.line_height,
+        paragraph.align,
+        paragraph.wrap,
+    );
+
+    for (text, style) in paragraph.sections() {
+        let bullets: String = text.chars().map(|_| BULLET).collect();
+        masked.push(bullets, style.clone());
+    }
+
+    masked
+}
+
+/// Map the indices of `lines` laid out from the [`mask_paragraph`] of `text`, back to `text`.
+fn unmask_lines(lines: &mut [TextLayoutLine], text: &str) {
+    let offsets: Vec<usize> = (text.char_indices())
+        .map(|(index, _)| index)
+        .chain([text.len()])
+        .collect();
+
+    let unmask = |index: usize| {
+        let nth = usize::min(
+            index / BULLET.len_utf8(),
+            offsets.len() - 1,
+        );
+        offsets[nth]
+    };
+
+    for line in lines {
+        line.start_index = unmask(line.start_index);
+        line.end_index = unmask(line.end_index);
+
+        for glyph in &mut line.glyphs {
+            glyph.start_index = unmask(glyph.start_index);
+            glyph.end_index = unmask(glyph.end_index);
+        }
+    }
+}
+
+/// The closest char boundary at or before `index`, clamped to the end of `text`.
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    index
+}
+
 struct Handle<const EDITABLE: bool> {
     size:      f32,
     offset:    f32,
@@ -1181,3 +2397,149 @@ impl<const EDITABLE: bool> Widget for Handle<EDITABLE> {
         true
     }
 }
+
+/// The horizontal and vertical padding around the labels of the toolbar.
+const TOOLBAR_PADDING: (f32, f32) = (12.0, 8.0);
+
+/// The gap between the toolbar and the line it is shown above.
+const TOOLBAR_GAP: f32 = 8.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ToolbarAction {
+    Cut,
+    Copy,
+    Paste,
+    SelectAll,
+}
+
+impl ToolbarAction {
+    fn label(self) -> &'static str {
+        match self {
+            ToolbarAction::Cut => "Cut",
+            ToolbarAction::Copy => "Copy",
+            ToolbarAction::Paste => "Paste",
+            ToolbarAction::SelectAll => "Select all",
+        }
+    }
+}
+
+struct Toolbar<const EDITABLE: bool> {
+    actions:     Vec<ToolbarAction>,
+    labels:      Vec<(Paragraph, f32)>,
+    font_size:   f32,
+    font_family: String,
+    color:       Color,
+    text_color:  Color,
+    text_area:   WidgetId<TextArea<EDITABLE>>,
+}
+
+impl<const EDITABLE: bool> Toolbar<EDITABLE> {
+    fn height(font_size: f32) -> f32 {
+        font_size * 1.2 + TOOLBAR_PADDING.1 * 2.0
+    }
+
+    fn find_action(&self, x: f32) -> Option<ToolbarAction> {
+        let mut left = 0.0;
+
+        for (action, (_, width)) in self.actions.iter().zip(&self.labels) {
+            left += width + TOOLBAR_PADDING.0 * 2.0;
+
+            if x < left {
+                return Some(*action);
+            }
+        }
+
+        None
+    }
+}
+
+impl<const EDITABLE: bool> Widget for Toolbar<EDITABLE> {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, _space: Space) -> Size {
+        self.labels.clear();
+
+        for action in &self.actions {
+            let mut paragraph = Paragraph::new(1.2, TextAlign::Start, TextWrap::None);
+            paragraph.push(
+                action.label(),
+                TextStyle {
+                    font_size:    self.font_size,
+                    font_family:  self.font_family.clone(),
+                    font_weight:  FontWeight::MEDIUM,
+                    font_stretch: FontStretch::Normal,
+                    font_style:   FontStyle::Normal,
+                    paint:        Paint::from(self.text_color),
+                },
+            );
+
+            let width = cx.measure_text(&paragraph, f32::INFINITY).width;
+            self.labels.push((paragraph, width));
+        }
+
+        let width = self
+            .labels
+            .iter()
+            .map(|(_, width)| width + TOOLBAR_PADDING.0 * 2.0)
+            .sum();
+
+        Size::new(width, Self::height(self.font_size))
+    }
+
+    fn draw(&mut self, cx: &mut DrawCx<'_>, canvas: &mut dyn Canvas) {
+        let radius = CornerRadius::all(cx.height() / 2.0);
+
+        canvas.draw_shadow(
+            &Shape::Rect(cx.rect(), radius),
+            4.0,
+            Color::BLACK.fade(0.3),
+        );
+        canvas.draw_rect(
+            cx.rect(),
+            radius,
+            &Paint::from(self.color),
+        );
+
+        let mut left = 0.0;
+
+        for (paragraph, width) in &self.labels {
+            let offset = Offset::new(
+                left + TOOLBAR_PADDING.0,
+                TOOLBAR_PADDING.1,
+            );
+
+            canvas.draw_text(paragraph, *width, offset);
+            left += width + TOOLBAR_PADDING.0 * 2.0;
+        }
+    }
+
+    fn on_touch_event(&mut self, cx: &mut EventCx<'_>, event: &TouchEvent) -> TouchPropagate {
+        match event {
+            TouchEvent::Gesture(Gesture::Tap(event)) => {
+                let local = cx.global_transform().inverse() * event.position;
+
+                let Some(action) = self.find_action(local.x) else {
+                    return TouchPropagate::Handled;
+                };
+
+                // the pasted text arrives as a text event to the focused text area
+                if let ToolbarAction::Paste = action {
+                    cx.request_paste();
+                }
+
+                let text_area = self.text_area;
+                cx.defer(move |world| {
+                    TextArea::<EDITABLE>::toolbar_action(world, text_area, action);
+                });
+
+                TouchPropagate::Handled
+            }
+
+            TouchEvent::Up(..) if cx.is_active() => TouchPropagate::Handled,
+
+            _ => TouchPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
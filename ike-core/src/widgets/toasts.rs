@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use crate::{
+    AnyWidgetId, Builder, ChildUpdate, LayoutCx, Offset, Size, Space, Transition, Transitioned,
+    Update, UpdateCx, Widget, WidgetId, WidgetMut,
+};
+
+struct Toast {
+    progress:   Transitioned<f32>,
+    remaining:  Option<Duration>,
+    is_leaving: bool,
+    is_expired: bool,
+}
+
+/// Transient toasts stacked over the bottom of `contents`.
+///
+/// The first child is the contents, every other child is a toast. Toasts slide in when added,
+/// and slide out when dismissed or when their timeout runs out, after which
+/// [`Toasts::set_on_expire`] is called so the toast can be removed. The timeout of a toast is
+/// paused while it's hovered.
+pub struct Toasts {
+    toasts:     Vec<Toast>,
+    spacing:    f32,
+    margin:     f32,
+    transition: Transition,
+    on_expire:  Box<dyn FnMut(WidgetId)>,
+}
+
+impl Toasts {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, contents: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self {
+            toasts:     Vec::new(),
+            spacing:    8.0,
+            margin:     16.0,
+            transition: Transition::ease(0.2),
+            on_expire:  Box::new(|_| {}),
+        })
+        .with_child(contents)
+        .finish()
+    }
+
+    /// Set the time before the toast at `index` is dismissed, `None` keeps it until dismissed.
+    pub fn set_timeout(this: &mut WidgetMut<Self>, index: usize, timeout: Option<Duration>) {
+        this.widget.toasts[index].remaining = timeout;
+        this.cx.request_animate();
+    }
+
+    /// Slide the toast at `index` out.
+    pub fn dismiss(this: &mut WidgetMut<Self>, index: usize) {
+        let toast = &mut this.widget.toasts[index];
+        toast.is_leaving = true;
+        toast.progress.begin(0.0);
+
+        this.cx.request_animate();
+    }
+
+    pub fn set_spacing(this: &mut WidgetMut<Self>, spacing: f32) {
+        this.widget.spacing = spacing;
+        this.cx.request_layout();
+    }
+
+    pub fn set_margin(this: &mut WidgetMut<Self>, margin: f32) {
+        this.widget.margin = margin;
+        this.cx.request_layout();
+    }
+
+    pub fn set_transition(this: &mut WidgetMut<Self>, transition: Transition) {
+        this.widget.transition = transition;
+
+        for toast in &mut this.widget.toasts {
+            toast.progress.set_transition(transition);
+        }
+    }
+
+    /// Called with the toast widget when it has slid out, and should be removed.
+    pub fn set_on_expire(this: &mut WidgetMut<Self>, on_expire: impl FnMut(WidgetId) + 'static) {
+        this.widget.on_expire = Box::new(on_expire);
+    }
+
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    fn new_toast(&self) -> Toast {
+        let mut progress = Transitioned::new(0.0, self.transition);
+        progress.begin(1.0);
+
+        Toast {
+            progress,
+            remaining: None,
+            is_leaving: false,
+            is_expired: false,
+        }
+    }
+}
+
+impl Widget for Toasts {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let size = cx.layout_nth_child(0, space);
+
+        let max_width = f32::max(size.width - self.margin * 2.0, 0.0);
+        let toast_space = Space::new(
+            Size::ZERO,
+            Size::new(max_width, size.height),
+        );
+
+        let mut bottom = size.height - self.margin;
+
+        for (i, toast) in self.toasts.iter().enumerate().rev() {
+            let toast_size = cx.layout_nth_child(i + 1, toast_space);
+            let progress = *toast.progress;
+
+            let shown = bottom - toast_size.height;
+            let x = (size.width - toast_size.width) / 2.0;
+            let y = size.height + (shown - size.height) * progress;
+
+            cx.place_nth_child(i + 1, Offset::new(x, y));
+            bottom -= (toast_size.height + self.spacing) * progress;
+        }
+
+        size
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx<'_>, update: Update) {
+        let Update::Children(update) = update else {
+            return;
+        };
+
+        match update {
+            ChildUpdate::Inserted(0) | ChildUpdate::Removed(0) | ChildUpdate::Replaced(0) => {}
+
+            ChildUpdate::Inserted(index) => {
+                let toast = self.new_toast();
+                self.toasts.insert(index - 1, toast);
+                cx.request_animate();
+            }
+
+            ChildUpdate::Removed(index) => {
+                self.toasts.remove(index - 1);
+            }
+
+            ChildUpdate::Replaced(index) => {
+                self.toasts[index - 1] = self.new_toast();
+                cx.request_animate();
+            }
+
+            ChildUpdate::Swapped(a, b) => {
+                if a > 0 && b > 0 {
+                    self.toasts.swap(a - 1, b - 1);
+                }
+            }
+        }
+    }
+
+    fn animate(&mut self, cx: &mut UpdateCx<'_>, dt: Duration) {
+        let mut is_moving = false;
+        let mut is_timing = false;
+        let mut needs_layout = false;
+        let mut expired = Vec::new();
+
+        for (i, toast) in self.toasts.iter_mut().enumerate() {
+            let is_hovered = cx
+                .get_nth_child(i + 1)
+                .is_ok_and(|child| child.cx.has_hovered());
+
+            if !toast.is_leaving
+                && let Some(remaining) = toast.remaining
+            {
+                let remaining = match is_hovered {
+                    true => remaining,
+                    false => remaining.saturating_sub(dt),
+                };
+
+                toast.remaining = Some(remaining);
+
+                if remaining.is_zero() {
+                    toast.is_leaving = true;
+                    toast.progress.begin(0.0);
+                } else {
+                    is_timing = true;
+                }
+            }
+
+            needs_layout |= !toast.progress.is_complete();
+
+            if toast.progress.animate(dt) {
+                is_moving = true;
+            } else if toast.is_leaving && !toast.is_expired {
+                toast.is_expired = true;
+                expired.push(cx.children()[i + 1]);
+            }
+        }
+
+        if needs_layout {
+            cx.request_layout();
+        }
+
+        cx.set_subpixel(is_moving);
+
+        if is_moving || is_timing {
+            cx.request_animate();
+        }
+
+        for child in expired {
+            (self.on_expire)(child);
+        }
+    }
+}
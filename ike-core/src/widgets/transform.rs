@@ -10,15 +10,18 @@ pub struct Transform {
     rotation:    Transitioned<f32>,
     scale_x:     Transitioned<f32>,
     scale_y:     Transitioned<f32>,
+    affine:      Affine,
 }
 
 impl Transform {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
         cx.build_widget(Self {
             translation: Transitioned::new(Offset::ZERO, Transition::INSTANT),
             rotation:    Transitioned::new(0.0, Transition::INSTANT),
             scale_x:     Transitioned::new(1.0, Transition::INSTANT),
             scale_y:     Transitioned::new(1.0, Transition::INSTANT),
+            affine:      Affine::IDENTITY,
         })
         .with_child(child)
         .finish()
@@ -48,6 +51,15 @@ impl Transform {
         }
     }
 
+    /// Set an arbitrary transform, applied relative to the top left of the child before the
+    /// translation, rotation and scale.
+    ///
+    /// Unlike the other components this isn't animated.
+    pub fn set_affine(this: &mut WidgetMut<'_, Self>, affine: Affine) {
+        this.widget.affine = affine;
+        this.cx.request_compose();
+    }
+
     pub fn set_translation_transition(this: &mut WidgetMut<'_, Self>, transition: Transition) {
         this.widget.translation.set_transition(transition);
     }
@@ -72,7 +84,8 @@ impl Widget for Transform {
             * Affine::scale(*self.scale_x, *self.scale_y)
             * Affine::rotate(*self.rotation)
             * Affine::translate(*self.translation)
-            * Affine::translate(Point::ORIGIN - cx.rect().center());
+            * Affine::translate(Point::ORIGIN - cx.rect().center())
+            * self.affine;
 
         cx.place_nth_child(0, transform);
     }
@@ -0,0 +1,138 @@
+use std::time::Instant;
+
+use crate::{
+    AnyWidgetId, Builder, CursorIcon, EventCx, LayoutCx, PointerButton, PointerEvent, PointerId,
+    PointerPropagate, ResizeEdge, Size, Space, Widget, WidgetMut, Window,
+};
+
+/// What pressing a [`WindowArea`] does to its window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WindowRegion {
+    /// Pressing moves the window and double clicking toggles it maximized, like a titlebar.
+    Caption,
+
+    /// Pressing resizes the window from an edge.
+    Resize(ResizeEdge),
+
+    /// Clicking minimizes the window.
+    Minimize,
+
+    /// Clicking toggles the window maximized.
+    Maximize,
+
+    /// Clicking closes the window.
+    Close,
+}
+
+/// Marks its child as a region of a custom titlebar or window frame, for windows without
+/// decorations.
+///
+/// Pointer presses the child handles itself, e.g. buttons in the titlebar, aren't handled by the
+/// area.
+pub struct WindowArea {
+    region:     WindowRegion,
+    pressed:    Option<PointerId>,
+    last_press: Option<Instant>,
+}
+
+impl WindowArea {
+    #[track_caller]
+    pub fn new(
+        cx: &mut impl Builder,
+        region: WindowRegion,
+        child: impl AnyWidgetId,
+    ) -> WidgetMut<'_, Self> {
+        let mut this = cx
+            .build_widget(Self {
+                region,
+                pressed: None,
+                last_press: None,
+            })
+            .with_child(child)
+            .finish();
+
+        Self::set_region(&mut this, region);
+        this
+    }
+
+    pub fn set_region(this: &mut WidgetMut<Self>, region: WindowRegion) {
+        this.widget.region = region;
+        this.widget.pressed = None;
+
+        match region {
+            WindowRegion::Resize(edge) => this.cx.set_cursor(edge.cursor()),
+            _ => this.cx.set_cursor(CursorIcon::Default),
+        }
+    }
+
+    fn toggle_maximized(cx: &mut EventCx<'_>) {
+        let is_maximized = cx.get_window().is_some_and(Window::is_maximized);
+        cx.set_window_maximized(!is_maximized);
+    }
+}
+
+impl Widget for WindowArea {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        cx.layout_nth_child(0, space)
+    }
+
+    fn on_pointer_event(&mut self, cx: &mut EventCx<'_>, event: &PointerEvent) -> PointerPropagate {
+        match event {
+            PointerEvent::Down(event) if event.button == PointerButton::Primary => {
+                match self.region {
+                    WindowRegion::Caption => {
+                        let double_click = cx.settings().touch.double_tap_time;
+
+                        match self.last_press.take() {
+                            Some(last) if last.elapsed() < double_click => {
+                                Self::toggle_maximized(cx);
+                            }
+
+                            _ => {
+                                self.last_press = Some(Instant::now());
+                                cx.begin_window_drag();
+                            }
+                        }
+
+                        PointerPropagate::Handled
+                    }
+
+                    WindowRegion::Resize(edge) => {
+                        cx.begin_window_resize(edge);
+                        PointerPropagate::Handled
+                    }
+
+                    WindowRegion::Minimize | WindowRegion::Maximize | WindowRegion::Close => {
+                        self.pressed = Some(event.pointer);
+                        PointerPropagate::Capture
+                    }
+                }
+            }
+
+            PointerEvent::Up(event) if self.pressed == Some(event.pointer) => {
+                self.pressed = None;
+
+                let local = cx.global_transform().inverse() * event.position;
+
+                if !cx.rect().contains(local) {
+                    return PointerPropagate::Handled;
+                }
+
+                match self.region {
+                    WindowRegion::Minimize => cx.minimize_window(),
+                    WindowRegion::Maximize => Self::toggle_maximized(cx),
+                    WindowRegion::Close => cx.close_window(),
+                    WindowRegion::Caption | WindowRegion::Resize(..) => {}
+                }
+
+                PointerPropagate::Handled
+            }
+
+            _ => PointerPropagate::Bubble,
+        }
+    }
+
+    fn accepts_pointer() -> bool {
+        true
+    }
+}
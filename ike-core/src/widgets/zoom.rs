@@ -0,0 +1,41 @@
+use crate::{Affine, AnyWidgetId, Builder, ComposeCx, LayoutCx, Size, Space, Widget, WidgetMut};
+
+/// Magnifies its child, which is laid out in a correspondingly smaller space.
+///
+/// Unlike scaling with a [`Transform`](super::Transform), the child fills the zoomed space and
+/// is drawn at the zoomed resolution, so text stays crisp.
+pub struct Zoom {
+    zoom: f32,
+}
+
+impl Zoom {
+    #[track_caller]
+    pub fn new(cx: &mut impl Builder, child: impl AnyWidgetId) -> WidgetMut<'_, Self> {
+        cx.build_widget(Self { zoom: 1.0 })
+            .with_child(child)
+            .finish()
+    }
+
+    /// Set how much the child is magnified, `1.0` being its natural size.
+    pub fn set_zoom(this: &mut WidgetMut<Self>, zoom: f32) {
+        this.widget.zoom = zoom.max(f32::EPSILON);
+        this.cx.request_layout();
+        this.cx.request_compose();
+    }
+}
+
+impl Widget for Zoom {
+    fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
+        let child_space = Space::new(
+            space.min * self.zoom.recip(),
+            space.max * self.zoom.recip(),
+        );
+
+        let size = cx.layout_nth_child(0, child_space);
+        space.constrain(size * self.zoom)
+    }
+
+    fn compose(&mut self, cx: &mut ComposeCx<'_>) {
+        cx.place_nth_child(0, Affine::scale(self.zoom, self.zoom));
+    }
+}
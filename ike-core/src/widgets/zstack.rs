@@ -1,22 +1,128 @@
-use crate::{Builder, LayoutCx, Size, Space, Widget, WidgetMut};
+use crate::{
+    Builder, ChildUpdate, LayoutCx, Offset, Point, RefCx, Size, Space, Update, UpdateCx, Widget,
+    WidgetId, WidgetMut,
+};
 
-pub struct ZStack {}
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Layer {
+    alignment:    Option<(f32, f32)>,
+    pass_through: bool,
+}
+
+/// Children stacked on top of each other, the last child on top.
+///
+/// By default children are laid out in the full space of the stack and placed in the top
+/// leading corner. Children with an alignment are instead laid out loosely, and aligned within
+/// the stack once its size is known.
+pub struct ZStack {
+    layers: Vec<Layer>,
+}
 
 impl ZStack {
+    #[track_caller]
     pub fn new(cx: &mut impl Builder) -> WidgetMut<'_, Self> {
-        cx.build_widget(Self {}).finish()
+        cx.build_widget(Self { layers: Vec::new() }).finish()
+    }
+
+    /// Set the alignment of the child at `index`, where `(0.0, 0.0)` is the top leading corner
+    /// and `(1.0, 1.0)` is the bottom trailing, mirrored in right-to-left layouts.
+    pub fn set_alignment(this: &mut WidgetMut<Self>, index: usize, alignment: Option<(f32, f32)>) {
+        if this.widget.layers[index].alignment != alignment {
+            this.widget.layers[index].alignment = alignment;
+            this.cx.request_layout();
+        }
+    }
+
+    /// Set whether pointer events pass through the child at `index` to the children below it.
+    pub fn set_pass_through(this: &mut WidgetMut<Self>, index: usize, pass_through: bool) {
+        this.widget.layers[index].pass_through = pass_through;
     }
 }
 
 impl Widget for ZStack {
     fn layout(&mut self, cx: &mut LayoutCx<'_>, space: Space) -> Size {
         let mut size = space.min;
+        let mut sizes = Vec::with_capacity(self.layers.len());
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            let space = match layer.alignment {
+                Some(..) => Space::new(Size::ZERO, space.max),
+                None => space,
+            };
 
-        for i in 0..cx.children().len() {
             let child_size = cx.layout_nth_child(i, space);
             size = size.max(child_size);
+            sizes.push(child_size);
+        }
+
+        let size = space.constrain(size);
+        let direction = cx.layout_direction();
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            let (x, y) = layer.alignment.unwrap_or_default();
+            let x = direction.align(x);
+            let excess = size - sizes[i];
+
+            cx.place_nth_child(
+                i,
+                Offset::new(excess.width * x, excess.height * y),
+            );
+        }
+
+        size
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx<'_>, update: Update) {
+        if let Update::Children(update) = update {
+            match update {
+                ChildUpdate::Inserted(index) => {
+                    self.layers.insert(index, Layer::default());
+                }
+
+                ChildUpdate::Removed(index) => {
+                    self.layers.remove(index);
+                }
+
+                ChildUpdate::Replaced(index) => {
+                    self.layers[index] = Layer::default();
+                }
+
+                ChildUpdate::Swapped(a, b) => {
+                    self.layers.swap(a, b);
+                }
+            }
+        }
+    }
+
+    fn find_widget_at(&self, cx: &RefCx<'_>, point: Point) -> Option<WidgetId> {
+        let local = cx.global_transform().inverse() * point;
+
+        if !cx.rect().contains(local) || cx.is_stashed() {
+            return None;
+        }
+
+        if let Some(clip) = cx.clip()
+            && !clip.contains(local)
+        {
+            return None;
+        }
+
+        let mut index = self.layers.len();
+
+        for child in cx.iter_children().rev() {
+            index -= 1;
+
+            if self.layers[index].pass_through {
+                continue;
+            }
+
+            if let Ok(child) = child
+                && let Some(widget) = child.widget.find_widget_at(&child.cx, point)
+            {
+                return Some(widget);
+            }
         }
 
-        space.constrain(size)
+        None
     }
 }
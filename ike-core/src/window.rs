@@ -1,13 +1,15 @@
 use std::{
     any::Any,
+    cell::Cell,
     fmt,
     rc::Rc,
     sync::atomic::{AtomicU64, Ordering},
 };
 
 use crate::{
-    Color, CursorIcon, KeyEvent, Modifiers, Padding, Point, Pointer, PointerEvent, PointerId, Size,
-    Touch, TouchId, WidgetId, debug::debug_panic,
+    Color, Cursor, CursorGrab, CursorIcon, KeyEvent, Modifiers, Padding, Point, Pointer,
+    PointerEvent, PointerId, PopupAnchor, Seat, SeatId, Size, Touch, TouchId, Transition,
+    Transitioned, WidgetId, debug::debug_panic, event::MultiTouch, timings::FrameHistory,
 };
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -32,6 +34,31 @@ impl fmt::Debug for LayerId {
     }
 }
 
+/// The color scheme preferred by the platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+/// The state of the application in its lifecycle, as reported by the platform.
+///
+/// Only mobile platforms report this, desktop applications are always resumed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Lifecycle {
+    /// The application is in the foreground, and receives input.
+    #[default]
+    Resumed,
+
+    /// The application may still be visible, but doesn't receive input, e.g. behind a dialog of
+    /// another application.
+    Paused,
+
+    /// The application isn't visible, and may be killed without notice, state that should
+    /// survive that has to be saved with [`Builder::save_state`](crate::Builder::save_state).
+    Stopped,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum WindowSizing {
     FitContent,
@@ -42,6 +69,86 @@ pub enum WindowSizing {
     },
 }
 
+/// Where a window is stacked relative to the windows of other applications.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WindowLevel {
+    /// The window is kept below all other windows, e.g. for desktop widgets.
+    AlwaysOnBottom,
+
+    #[default]
+    Normal,
+
+    /// The window is kept above all other windows, e.g. for utility palettes and overlays.
+    AlwaysOnTop,
+}
+
+/// An edge or corner of a window, that it can be resized from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResizeEdge {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl ResizeEdge {
+    /// The cursor shown over the edge.
+    pub fn cursor(self) -> CursorIcon {
+        match self {
+            ResizeEdge::North => CursorIcon::NResize,
+            ResizeEdge::NorthEast => CursorIcon::NeResize,
+            ResizeEdge::East => CursorIcon::EResize,
+            ResizeEdge::SouthEast => CursorIcon::SeResize,
+            ResizeEdge::South => CursorIcon::SResize,
+            ResizeEdge::SouthWest => CursorIcon::SwResize,
+            ResizeEdge::West => CursorIcon::WResize,
+            ResizeEdge::NorthWest => CursorIcon::NwResize,
+        }
+    }
+}
+
+/// How the frames of a window are buffered on their way to the display.
+///
+/// More buffers keep the GPU busy and the frame pacing smooth, fewer frames in flight make input
+/// show up on the display sooner. Backends clamp both to what the surface supports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Presentation {
+    /// The number of images in the swapchain, `2` is double and `3` triple buffering.
+    pub buffers: u32,
+
+    /// The number of frames that can be queued before drawing waits for the oldest of them to
+    /// finish, `1` waits for the previous frame.
+    pub max_frames_in_flight: u32,
+}
+
+impl Presentation {
+    pub const DOUBLE_BUFFERED: Self = Self {
+        buffers:              2,
+        max_frames_in_flight: 2,
+    };
+
+    pub const TRIPLE_BUFFERED: Self = Self {
+        buffers:              3,
+        max_frames_in_flight: 2,
+    };
+
+    /// Double buffering, waiting for the previous frame before drawing the next.
+    pub const LOW_LATENCY: Self = Self {
+        buffers:              2,
+        max_frames_in_flight: 1,
+    };
+}
+
+impl Default for Presentation {
+    fn default() -> Self {
+        Self::DOUBLE_BUFFERED
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Layer {
     pub(crate) id:       LayerId,
@@ -76,32 +183,79 @@ impl Layer {
     }
 }
 
+/// The size and placement of a window on the desktop, e.g. for restoring it in the next run.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowGeometry {
+    pub size: Size,
+
+    /// The position of the window on the desktop, `None` where the platform doesn't expose it.
+    pub position: Option<Point>,
+
+    pub is_maximized: bool,
+}
+
 pub struct Window {
     pub(crate) id:     WindowId,
     pub(crate) layers: Rc<Vec<Layer>>,
 
-    pub(crate) modifiers: Modifiers,
-    pub(crate) pointers:  Vec<Pointer>,
-    pub(crate) touches:   Vec<Touch>,
+    pub(crate) seats:    Vec<Seat>,
+    pub(crate) pointers: Vec<Pointer>,
+    pub(crate) touches:  Vec<Touch>,
+
+    pub(crate) multi_touch: Option<MultiTouch>,
 
     pub(crate) on_key:     Box<dyn FnMut(&KeyEvent) -> bool>,
     pub(crate) on_pointer: Box<dyn FnMut(&PointerEvent) -> bool>,
 
-    pub(crate) focused: Option<WidgetId>,
+    pub(crate) on_geometry: Box<dyn FnMut(&WindowGeometry)>,
+
+    pub(crate) grab: Option<WidgetId>,
 
     pub(crate) properties: Vec<Box<dyn Any>>,
 
-    pub(crate) scale:        f32,
-    pub(crate) size:         Size,
-    pub(crate) insets:       Padding,
-    pub(crate) is_visible:   bool,
-    pub(crate) is_focused:   bool,
-    pub(crate) is_decorated: bool,
-
-    pub(crate) cursor: CursorIcon,
-    pub(crate) title:  String,
-    pub(crate) sizing: WindowSizing,
-    pub(crate) color:  Color,
+    pub(crate) scale:          f32,
+    pub(crate) scale_override: Option<f32>,
+    pub(crate) size:           Size,
+    pub(crate) position:       Option<Point>,
+    pub(crate) insets:         Padding,
+    pub(crate) keyboard:       f32,
+    pub(crate) is_visible:     bool,
+    pub(crate) is_focused:     bool,
+    pub(crate) is_occluded:    bool,
+    pub(crate) is_decorated:   bool,
+    pub(crate) is_maximized:   bool,
+    pub(crate) is_redacted:    bool,
+    pub(crate) is_blurred:     bool,
+    pub(crate) skip_taskbar:   bool,
+
+    pub(crate) opacity: f32,
+    pub(crate) level:   WindowLevel,
+
+    pub(crate) cursor:      Cursor,
+    pub(crate) grab_cursor: CursorGrab,
+    pub(crate) title:       String,
+    pub(crate) sizing:      WindowSizing,
+    pub(crate) color:       Transitioned<Color>,
+
+    pub(crate) presentation: Presentation,
+
+    pub(crate) parent:   Option<WindowId>,
+    pub(crate) is_modal: bool,
+    pub(crate) popup:    Option<PopupAnchor>,
+
+    pub(crate) samples: Vec<(WidgetId, Point)>,
+
+    /// The widget shown by the inspector, see [`DebugSettings::inspector`](crate::DebugSettings::inspector).
+    pub(crate) inspected: Option<WidgetId>,
+
+    /// When the last frames were drawn, see
+    /// [`DebugSettings::performance_overlay`](crate::DebugSettings::performance_overlay).
+    pub(crate) frame_history: FrameHistory,
+
+    /// Whether a redraw was signalled that hasn't been drawn yet.
+    pub(crate) redraw_requested:  Cell<bool>,
+    /// Whether an animation frame was signalled that hasn't been animated yet.
+    pub(crate) animate_requested: Cell<bool>,
 }
 
 impl Window {
@@ -115,32 +269,59 @@ impl Window {
                 position: Point::ORIGIN,
             }]),
 
-            modifiers: Modifiers::empty(),
+            seats: vec![Seat::new(SeatId::PRIMARY)],
             pointers: Vec::new(),
             touches: Vec::new(),
+            multi_touch: None,
 
             on_key: Box::new(|_| false),
             on_pointer: Box::new(|_| false),
 
-            focused: None,
+            on_geometry: Box::new(|_| {}),
+
+            grab: None,
 
             properties: Vec::new(),
 
             scale: 1.0,
+            scale_override: None,
             size: Size::new(800.0, 600.0),
+            position: None,
             insets: Padding::all(0.0),
+            keyboard: 0.0,
             is_visible: true,
             is_focused: false,
+            is_occluded: false,
             is_decorated: true,
+            is_maximized: false,
+            is_redacted: false,
+            is_blurred: false,
+            skip_taskbar: false,
+
+            opacity: 1.0,
+            level: WindowLevel::Normal,
 
-            cursor: CursorIcon::Default,
+            cursor: Cursor::default(),
+            grab_cursor: CursorGrab::None,
             title: String::new(),
             sizing: WindowSizing::Resizable {
                 default_size: Size::new(800.0, 600.0),
                 min_size:     Size::all(0.0),
                 max_size:     Size::all(f32::INFINITY),
             },
-            color: Color::WHITE,
+            color: Transitioned::new(Color::WHITE, Transition::INSTANT),
+            presentation: Presentation::default(),
+
+            parent: None,
+            is_modal: false,
+            popup: None,
+
+            samples: Vec::new(),
+            inspected: None,
+            frame_history: FrameHistory::default(),
+
+            redraw_requested: Cell::new(false),
+            animate_requested: Cell::new(false),
         }
     }
 
@@ -148,25 +329,85 @@ impl Window {
         self.id
     }
 
+    /// The number of pixels per unit the window is drawn with, either the scale factor of the
+    /// platform, or the override set with [`World::set_window_scale`](crate::World::set_window_scale).
     pub fn scale(&self) -> f32 {
+        self.scale_override.unwrap_or(self.scale)
+    }
+
+    /// The scale factor of the platform, regardless of any override.
+    pub fn platform_scale(&self) -> f32 {
         self.scale
     }
 
+    /// How much the contents are magnified relative to the scale factor of the platform, `1.0`
+    /// unless the scale is overridden.
+    pub fn zoom(&self) -> f32 {
+        self.scale() / self.scale
+    }
+
     pub fn size(&self) -> Size {
         self.size
     }
 
-    /// Get the insets of the window.
+    /// The position of the window on the desktop, `None` where the platform doesn't expose it,
+    /// e.g. on wayland.
+    pub fn position(&self) -> Option<Point> {
+        self.position
+    }
+
+    /// The size, position and maximized state of the window, see [`WindowGeometry`].
+    pub fn geometry(&self) -> WindowGeometry {
+        WindowGeometry {
+            size:         self.size,
+            position:     self.position,
+            is_maximized: self.is_maximized,
+        }
+    }
+
+    /// Get the insets of the window, covered by system bars and display cutouts.
     pub fn insets(&self) -> Padding {
         self.insets
     }
 
+    /// The height of the on-screen keyboard covering the bottom of the window, zero while it's
+    /// hidden.
+    pub fn keyboard_height(&self) -> f32 {
+        self.keyboard
+    }
+
+    /// The modifiers of the [primary seat](SeatId::PRIMARY).
     pub fn modifiers(&self) -> Modifiers {
-        self.modifiers
+        self.get_seat(SeatId::PRIMARY)
+            .map_or(Modifiers::empty(), Seat::modifiers)
+    }
+
+    pub fn seats(&self) -> &[Seat] {
+        &self.seats
     }
 
-    pub fn cursor(&self) -> CursorIcon {
-        self.cursor
+    pub fn get_seat(&self, id: SeatId) -> Option<&Seat> {
+        self.seats.iter().find(|seat| seat.id == id)
+    }
+
+    pub fn get_pointer(&self, id: PointerId) -> Option<&Pointer> {
+        self.pointers.iter().find(|pointer| pointer.id == id)
+    }
+
+    /// The modifiers of the seat `pointer` belongs to.
+    pub fn pointer_modifiers(&self, pointer: PointerId) -> Modifiers {
+        self.get_pointer(pointer)
+            .and_then(|pointer| self.get_seat(pointer.seat))
+            .map_or(Modifiers::empty(), Seat::modifiers)
+    }
+
+    pub fn cursor(&self) -> &Cursor {
+        &self.cursor
+    }
+
+    /// How the cursor is held in the window.
+    pub fn cursor_grab(&self) -> CursorGrab {
+        self.grab_cursor
     }
 
     pub fn title(&self) -> &str {
@@ -181,6 +422,11 @@ impl Window {
         self.is_focused
     }
 
+    /// Whether the window is completely covered by other windows, as reported by the platform.
+    pub fn is_occluded(&self) -> bool {
+        self.is_occluded
+    }
+
     pub fn is_visible(&self) -> bool {
         self.is_visible
     }
@@ -189,14 +435,76 @@ impl Window {
         self.is_decorated
     }
 
+    /// Whether the window is maximized, as reported by the platform.
+    pub fn is_maximized(&self) -> bool {
+        self.is_maximized
+    }
+
+    /// Whether content marked with [`Redact`](crate::widgets::Redact) is replaced by
+    /// placeholders, regardless of [`World::is_redacted`](crate::World::is_redacted).
+    pub fn is_redacted(&self) -> bool {
+        self.is_redacted
+    }
+
     pub fn color(&self) -> Color {
-        self.color
+        self.color.get()
+    }
+
+    /// The opacity of the window, applied to both its color and its contents.
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    pub fn level(&self) -> WindowLevel {
+        self.level
+    }
+
+    /// Whether the window is hidden from the taskbar.
+    pub fn skips_taskbar(&self) -> bool {
+        self.skip_taskbar
+    }
+
+    /// Whether what is behind the window is blurred, where the platform supports it.
+    pub fn is_blurred(&self) -> bool {
+        self.is_blurred
+    }
+
+    pub fn presentation(&self) -> Presentation {
+        self.presentation
+    }
+
+    /// The window owning this window, which is kept below it and closes it when removed.
+    pub fn parent(&self) -> Option<WindowId> {
+        self.parent
+    }
+
+    /// Whether the window blocks input to its [`parent`](Window::parent) while it's visible.
+    pub fn is_modal(&self) -> bool {
+        self.is_modal
+    }
+
+    /// Where the window is placed when it's a popup, e.g. a menu or tooltip, which isn't
+    /// clipped by its [`parent`](Window::parent).
+    pub fn popup(&self) -> Option<PopupAnchor> {
+        self.popup
+    }
+
+    /// The widget all input in the window is routed to, see
+    /// [`EventCx::grab_input`](crate::EventCx::grab_input).
+    pub fn input_grab(&self) -> Option<WidgetId> {
+        self.grab
     }
 
     pub fn layers(&self) -> &[Layer] {
         &self.layers
     }
 
+    /// Set the callback called when the [`geometry`](Window::geometry) of the window is changed
+    /// by the platform, e.g. because the user moved or resized it.
+    pub fn set_on_geometry(&mut self, on_geometry: Box<dyn FnMut(&WindowGeometry)>) {
+        self.on_geometry = on_geometry;
+    }
+
     pub fn set_on_key(&mut self, on_key: Box<dyn FnMut(&KeyEvent) -> bool>) {
         self.on_key = on_key;
     }
@@ -225,6 +533,39 @@ impl Window {
         self.layers_mut().first_mut()
     }
 
+    /// Get the seat with `id`, adding it if the window hasn't seen input from it yet.
+    pub(crate) fn seat_mut(&mut self, id: SeatId) -> &mut Seat {
+        let index = match self.seats.iter().position(|seat| seat.id == id) {
+            Some(index) => index,
+            None => {
+                self.seats.push(Seat::new(id));
+                self.seats.len() - 1
+            }
+        };
+
+        &mut self.seats[index]
+    }
+
+    pub(crate) fn focused(&self, seat: SeatId) -> Option<WidgetId> {
+        self.get_seat(seat).and_then(Seat::focused)
+    }
+
+    /// Whether any seat has `widget` focused.
+    pub(crate) fn is_focusing(&self, widget: WidgetId) -> bool {
+        self.seats.iter().any(|seat| seat.focused == Some(widget))
+    }
+
+    /// Whether any pointer is hovering `widget`.
+    pub(crate) fn is_hovering(&self, widget: WidgetId) -> bool {
+        (self.pointers.iter()).any(|pointer| pointer.hovering == Some(widget))
+    }
+
+    /// Whether any pointer or touch has been captured by `widget`.
+    pub(crate) fn is_capturing(&self, widget: WidgetId) -> bool {
+        (self.pointers.iter()).any(|pointer| pointer.capturer == Some(widget))
+            || (self.touches.iter()).any(|touch| touch.capturer == Some(widget))
+    }
+
     pub(crate) fn pointer(&self, id: PointerId) -> Option<&Pointer> {
         match self.pointers.iter().find(|pointer| pointer.id == id) {
             Some(pointer) => Some(pointer),
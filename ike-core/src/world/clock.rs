@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+/// What drives animations, see [`World::set_animation_clock`](crate::World::set_animation_clock).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationClock {
+    /// Animations are driven by the backend as frames are presented.
+    #[default]
+    Real,
+
+    /// Animations only advance with [`World::advance`](crate::World::advance), in steps of at
+    /// most `frame`, animating the same way regardless of how fast frames are presented.
+    Manual { frame: Duration },
+}
+
+impl AnimationClock {
+    /// A manual clock advancing in frames of `60 Hz`.
+    pub const fn manual() -> Self {
+        Self::Manual {
+            frame: Duration::from_nanos(16_666_667),
+        }
+    }
+}
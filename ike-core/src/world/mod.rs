@@ -1,3 +1,4 @@
+mod clock;
 mod settings;
 mod signal;
 mod state;
@@ -6,25 +7,33 @@ mod widget_ref;
 mod widgets;
 
 use std::{
+    mem,
     ops::Range,
+    panic,
     rc::Rc,
     sync::atomic::{AtomicU64, Ordering},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 pub(crate) use state::WorldState;
 pub(crate) use widgets::Widgets;
 
-pub use settings::{RenderSettings, Settings};
-pub use signal::{ImeSignal, Signal, WindowUpdate};
+pub use clock::AnimationClock;
+pub use settings::{RenderSettings, Settings, Throttle, ThrottleSettings};
+pub use signal::{ImePurpose, ImeSignal, Signal, SvgResponder, WindowUpdate};
 pub use widget_mut::WidgetMut;
 pub use widget_ref::WidgetRef;
 pub use widgets::{AnyWidget, GetError};
 
 use crate::{
-    AnyWidgetId, Builder, Canvas, Key, Layer, LayerId, Modifiers, Offset, Padding, Point,
-    PointerButton, PointerId, Recorder, ScrollDelta, Size, TouchId, Update, WidgetId, Window,
-    WindowId, debug::debug_panic, passes,
+    Allocations, AnyWidgetId, BackEvent, Builder, Canvas, ColorScheme, FrameTimings, Gesture, Key,
+    KeyInput, Layer, LayerId, LayerOptions, LayoutDirection, Lifecycle, MemoryPressure, Modifiers,
+    Offset, Padding, PageSetup, Point, PointerButton, PointerId, Printer, Recorder, ScrollDelta,
+    SeatId, Size, Tool, TouchId, Update, WidgetId, Window, WindowId,
+    allocations::{FrameAllocations, FramePhase, PhaseMeter},
+    debug::debug_panic,
+    passes,
+    playback::{InputEvent, InputRecorder, InputRecording},
 };
 
 pub struct World {
@@ -57,6 +66,132 @@ impl World {
     pub fn recorder_mut(&mut self) -> &mut Recorder {
         &mut self.state.recorder
     }
+
+    /// The color scheme preferred by the platform, `None` if the platform doesn't report one.
+    pub fn color_scheme(&self) -> Option<ColorScheme> {
+        self.state.color_scheme
+    }
+
+    /// Set the color scheme preferred by the platform, called by the backend when it changes.
+    ///
+    /// Returns whether the color scheme changed, in which case the view tree should be rebuilt
+    /// so themes following the color scheme are updated.
+    pub fn color_scheme_changed(&mut self, color_scheme: Option<ColorScheme>) -> bool {
+        let changed = self.state.color_scheme != color_scheme;
+        self.state.color_scheme = color_scheme;
+        changed
+    }
+
+    /// The layout direction of the language of the platform, inherited by every widget that
+    /// doesn't set its own, see [`WidgetMut::set_layout_direction`].
+    pub fn layout_direction(&self) -> LayoutDirection {
+        self.state.layout_direction
+    }
+
+    /// Set the layout direction of the language of the platform, called by the backend when it
+    /// changes.
+    ///
+    /// Returns whether it changed, in which case every window is laid out again.
+    pub fn layout_direction_changed(&mut self, direction: LayoutDirection) -> bool {
+        if self.state.layout_direction == direction {
+            return false;
+        }
+
+        self.state.layout_direction = direction;
+
+        let windows: Vec<_> = self.state.windows.iter().map(|window| window.id).collect();
+
+        for window in windows {
+            passes::update::window(
+                self,
+                window,
+                &Update::LayoutDirectionChanged,
+            );
+            self.state.request_redraw(window);
+        }
+
+        true
+    }
+
+    /// The state of the application in its lifecycle, see [`Lifecycle`].
+    pub fn lifecycle(&self) -> Lifecycle {
+        self.state.lifecycle
+    }
+
+    /// Set the state of the application in its lifecycle, called by the backend when it changes.
+    ///
+    /// Returns whether it changed, in which case the view tree should be rebuilt so views
+    /// observing the lifecycle are notified.
+    pub fn lifecycle_changed(&mut self, lifecycle: Lifecycle) -> bool {
+        let changed = self.state.lifecycle != lifecycle;
+        self.state.lifecycle = lifecycle;
+
+        // redraws requested while paused may have been dropped by the backend
+        if changed && lifecycle == Lifecycle::Resumed {
+            for window in &self.state.windows {
+                window.redraw_requested.set(false);
+            }
+        }
+
+        changed
+    }
+
+    /// Whether every window is redacted, see [`World::set_redacted`].
+    pub fn is_redacted(&self) -> bool {
+        self.state.is_redacted
+    }
+
+    /// Replace content marked with [`Redact`](crate::widgets::Redact) by placeholders in every
+    /// window, including ones created later, e.g. while the screen is shared.
+    ///
+    /// This only affects what is drawn, the content itself is left untouched.
+    pub fn set_redacted(&mut self, is_redacted: bool) {
+        let was_redacted = self.state.is_redacted;
+        self.state.is_redacted = is_redacted;
+
+        let windows: Vec<_> = (self.state.windows.iter())
+            .map(|window| (window.id, window.is_redacted))
+            .collect();
+
+        for (window, is_window_redacted) in windows {
+            if is_window_redacted || was_redacted == is_redacted {
+                continue;
+            }
+
+            let update = Update::WindowRedacted(is_redacted);
+            passes::update::window(self, window, &update);
+        }
+    }
+
+    /// Lay out and draw every window again, after fonts were loaded or unloaded by the painter.
+    pub fn fonts_changed(&mut self) {
+        let windows: Vec<_> = self.state.windows.iter().map(|window| window.id).collect();
+
+        for window in windows {
+            passes::update::window(self, window, &Update::FontsChanged);
+        }
+    }
+
+    /// How urgently the platform is asking for memory to be released.
+    pub fn memory_pressure(&self) -> MemoryPressure {
+        self.state.memory_pressure
+    }
+
+    /// Set the memory pressure reported by the platform, called by the backend when it changes.
+    ///
+    /// Recordings that no longer fit the reduced [`RecordSettings::memory_budget`] are released
+    /// immediately, returns whether the pressure changed.
+    ///
+    /// [`RecordSettings::memory_budget`]: crate::RecordSettings::memory_budget
+    pub fn memory_pressure_changed(&mut self, pressure: MemoryPressure) -> bool {
+        let changed = self.state.memory_pressure != pressure;
+        self.state.memory_pressure = pressure;
+
+        let budget = self.state.settings.record.memory_budget(pressure);
+        self.state.recorder.trim(budget);
+
+        changed
+    }
 }
 
 impl World {
@@ -74,7 +209,17 @@ impl World {
         id
     }
 
+    /// Remove `window`, and the windows it's the parent of.
     pub fn remove_window(&mut self, window: WindowId) {
+        let children: Vec<_> = (self.state.windows.iter())
+            .filter(|child| child.parent == Some(window))
+            .map(|child| child.id)
+            .collect();
+
+        for child in children {
+            self.remove_window(child);
+        }
+
         if let Some(index) = self.state.windows.iter().position(|w| w.id == window) {
             let window = self.state.windows.remove(index);
 
@@ -211,7 +356,20 @@ impl World {
         self.state.get_window_mut(id)
     }
 
+    /// Route all input in `window` to `widget` and its descendants, or release the grab with
+    /// `None`, see [`EventCx::grab_input`](crate::EventCx::grab_input).
+    pub fn set_input_grab(&mut self, window: WindowId, widget: Option<WidgetId>) {
+        passes::grab::set(self, window, widget);
+    }
+
+    /// Focus `widget` for the primary seat of `window`, or remove focus with `None`.
+    pub fn set_focus(&mut self, window: WindowId, widget: Option<WidgetId>) {
+        passes::focus::transfer(self, window, SeatId::PRIMARY, widget);
+    }
+
     pub fn window_resized(&mut self, window: WindowId, new_size: Size) {
+        self.record_input(window, || InputEvent::Resized(new_size));
+
         let window_id = window;
 
         let Some(window) = self.window_mut(window) else {
@@ -222,6 +380,8 @@ impl World {
 
         let update = Update::WindowResized(new_size);
         passes::update::window(self, window_id, &update);
+
+        self.window_geometry_changed(window_id);
     }
 
     pub fn window_scaled(&mut self, window: WindowId, new_size: Size, new_scale: f32) {
@@ -234,11 +394,39 @@ impl World {
         window.size = new_size;
         window.scale = new_scale;
 
+        let new_scale = window.scale();
+
         let update = Update::WindowResized(new_size);
         passes::update::window(self, window_id, &update);
 
         let update = Update::WindowScaled(new_scale);
         passes::update::window(self, window_id, &update);
+
+        self.window_geometry_changed(window_id);
+    }
+
+    /// Draw `window` with `scale` pixels per unit instead of the scale factor of the platform,
+    /// or follow the platform again with `None`.
+    ///
+    /// The contents are laid out in a correspondingly smaller or larger space and text is
+    /// shaped at the new scale, rather than the drawn pixels being scaled.
+    pub fn set_window_scale(&mut self, window: WindowId, scale: Option<f32>) {
+        let window_id = window;
+
+        let Some(window) = self.state.window_mut(window_id) else {
+            return;
+        };
+
+        let old_scale = window.scale();
+        window.scale_override = scale.filter(|scale| *scale > 0.0);
+        let new_scale = window.scale();
+
+        if old_scale != new_scale {
+            let update = Update::WindowScaled(new_scale);
+            passes::update::window(self, window_id, &update);
+
+            self.state.request_redraw(window_id);
+        }
     }
 
     pub fn window_inset(&mut self, window: WindowId, insets: Padding) {
@@ -254,7 +442,31 @@ impl World {
         passes::update::window(self, window_id, &update);
     }
 
+    /// The on-screen keyboard covering the bottom of `window` was shown, hidden or resized.
+    ///
+    /// This is called for every frame while the keyboard is animated in or out.
+    pub fn window_keyboard_resized(&mut self, window: WindowId, height: f32) {
+        let window_id = window;
+
+        let Some(window) = self.window_mut(window) else {
+            return;
+        };
+
+        if window.keyboard == height {
+            return;
+        }
+
+        window.keyboard = height;
+
+        let update = Update::WindowKeyboard(height);
+        passes::update::window(self, window_id, &update);
+    }
+
     pub fn window_focused(&mut self, window: WindowId, is_focused: bool) {
+        self.record_input(window, || {
+            InputEvent::Focused(is_focused)
+        });
+
         let window_id = window;
 
         let Some(window) = self.state.window_mut(window_id) else {
@@ -262,6 +474,12 @@ impl World {
         };
 
         window.is_focused = is_focused;
+        self.resume_throttled(window_id);
+
+        // keys released while the window is unfocused are never reported
+        if !is_focused {
+            passes::key::release_all(self, window_id);
+        }
 
         passes::update::window(
             self,
@@ -270,26 +488,155 @@ impl World {
         );
 
         if let Some(window) = self.state.window_mut(window_id)
-            && let Some(focused) = window.focused
+            && let Some(focused) = window.focused(SeatId::PRIMARY)
             && let Ok(widget) = self.widget(focused)
             && widget.cx.hierarchy.accepts_text()
         {
             widget.cx.world.emit_signal(Signal::Ime(ImeSignal::Start));
         }
     }
+
+    /// Replace content marked with [`Redact`](crate::widgets::Redact) by placeholders in
+    /// `window`, see [`World::set_redacted`].
+    pub fn set_window_redacted(&mut self, window: WindowId, is_redacted: bool) {
+        let window_id = window;
+        let is_all_redacted = self.state.is_redacted;
+
+        let Some(window) = self.state.window_mut(window_id) else {
+            return;
+        };
+
+        let was_redacted = window.is_redacted || is_all_redacted;
+        window.is_redacted = is_redacted;
+
+        let is_redacted = is_redacted || is_all_redacted;
+
+        if was_redacted != is_redacted {
+            let update = Update::WindowRedacted(is_redacted);
+            passes::update::window(self, window_id, &update);
+        }
+    }
+
+    /// Set whether `window` is maximized, as reported by the platform.
+    pub fn window_maximized(&mut self, window: WindowId, is_maximized: bool) {
+        if let Some(win) = self.state.window_mut(window)
+            && win.is_maximized != is_maximized
+        {
+            win.is_maximized = is_maximized;
+            self.window_geometry_changed(window);
+        }
+    }
+
+    /// Set the position of `window` on the desktop, as reported by the platform.
+    pub fn window_moved(&mut self, window: WindowId, position: Point) {
+        if let Some(win) = self.state.window_mut(window)
+            && win.position != Some(position)
+        {
+            win.position = Some(position);
+            self.window_geometry_changed(window);
+        }
+    }
+
+    fn window_geometry_changed(&mut self, window: WindowId) {
+        if let Some(win) = self.state.window_mut(window) {
+            let geometry = win.geometry();
+            (win.on_geometry)(&geometry);
+        }
+    }
+
+    /// Set whether `window` is hidden or completely covered by other windows.
+    pub fn window_occluded(&mut self, window: WindowId, is_occluded: bool) {
+        if let Some(win) = self.state.window_mut(window) {
+            win.is_occluded = is_occluded;
+            self.resume_throttled(window);
+        }
+    }
+
+    /// How animations of `window` should be scheduled, depending on whether it's in the
+    /// background, see [`ThrottleSettings`].
+    pub fn window_throttle(&self, window: WindowId) -> Throttle {
+        let Some(window) = self.state.get_window(window) else {
+            return Throttle::None;
+        };
+
+        let settings = &self.state.settings.throttle;
+
+        if window.is_occluded || !window.is_visible {
+            settings.occluded
+        } else if !window.is_focused {
+            settings.unfocused
+        } else {
+            Throttle::None
+        }
+    }
+
+    /// Redraw `window` if it was waiting to be animated and isn't paused anymore, so the
+    /// backend picks up the pending animation frame.
+    fn resume_throttled(&self, window: WindowId) {
+        if self.window_throttle(window) != Throttle::Pause
+            && let Some(win) = self.state.get_window(window)
+            && win.animate_requested.get()
+        {
+            self.state.request_redraw(window);
+        }
+    }
 }
 
 impl World {
+    /// Whether input to `window` is blocked by a modal child, which is brought to the front
+    /// instead.
+    fn is_input_blocked(&mut self, window: WindowId) -> bool {
+        let Some(modal) = self.state.window_modal(window) else {
+            return false;
+        };
+
+        self.state.update_window(modal, WindowUpdate::Focus);
+        true
+    }
+
+    /// A pointer of the [primary seat](SeatId::PRIMARY) entered `window`.
     pub fn pointer_entered(&mut self, window: WindowId, pointer: PointerId) -> bool {
-        passes::pointer::entered(self, window, pointer)
+        self.record_input(window, || InputEvent::PointerEntered {
+            pointer,
+        });
+
+        passes::pointer::entered(self, window, SeatId::PRIMARY, pointer)
+    }
+
+    /// A pointer of `seat` entered `window`, presses of it move the focus of `seat`.
+    pub fn seat_pointer_entered(
+        &mut self,
+        window: WindowId,
+        seat: SeatId,
+        pointer: PointerId,
+    ) -> bool {
+        passes::pointer::entered(self, window, seat, pointer)
     }
 
     pub fn pointer_left(&mut self, window: WindowId, pointer: PointerId) -> bool {
+        self.record_input(window, || InputEvent::PointerLeft {
+            pointer,
+        });
+
         passes::pointer::left(self, window, pointer)
     }
 
-    pub fn pointer_moved(&mut self, window: WindowId, pointer: PointerId, position: Point) -> bool {
-        passes::pointer::moved(self, window, pointer, position)
+    /// Move `pointer` to `position`, `tool` is reported with this and the following button
+    /// events.
+    pub fn pointer_moved(
+        &mut self,
+        window: WindowId,
+        pointer: PointerId,
+        position: Point,
+        tool: Tool,
+    ) -> bool {
+        self.record_input(window, || InputEvent::PointerMoved {
+            pointer,
+            position,
+            tool: tool.kind,
+        });
+
+        passes::pointer::moved(self, window, pointer, position, tool)
     }
 
     pub fn pointer_pressed(
@@ -299,6 +646,16 @@ impl World {
         button: PointerButton,
         pressed: bool,
     ) -> bool {
+        self.record_input(window, || InputEvent::PointerPressed {
+            pointer,
+            button,
+            pressed,
+        });
+
+        if pressed && self.is_input_blocked(window) {
+            return true;
+        }
+
         passes::pointer::pressed(self, window, pointer, button, pressed)
     }
 
@@ -308,15 +665,30 @@ impl World {
         pointer: PointerId,
         delta: ScrollDelta,
     ) -> bool {
+        self.record_input(window, || InputEvent::PointerScrolled {
+            pointer,
+            delta: delta.clone(),
+        });
+
+        if self.is_input_blocked(window) {
+            return true;
+        }
+
         passes::pointer::scrolled(self, window, pointer, delta)
     }
 }
 
 impl World {
     pub fn modifiers_changed(&mut self, window: WindowId, modifiers: Modifiers) -> bool {
-        passes::key::modifiers_changed(self, window, modifiers)
+        self.record_input(window, || {
+            InputEvent::ModifiersChanged(modifiers)
+        });
+
+        passes::key::modifiers_changed(self, window, SeatId::PRIMARY, modifiers)
     }
 
+    /// A key was pressed, for platforms that don't report the physical key, see
+    /// [`World::seat_key_pressed`].
     pub fn key_pressed(
         &mut self,
         window: WindowId,
@@ -325,75 +697,442 @@ impl World {
         text: Option<&str>,
         pressed: bool,
     ) -> bool {
-        passes::key::pressed(self, window, key, repeat, text, pressed)
+        let input = KeyInput {
+            text: text.map(String::from),
+            repeat,
+            ..KeyInput::new(key)
+        };
+
+        self.seat_key_pressed(window, SeatId::PRIMARY, input, pressed)
+    }
+
+    pub fn seat_modifiers_changed(
+        &mut self,
+        window: WindowId,
+        seat: SeatId,
+        modifiers: Modifiers,
+    ) -> bool {
+        passes::key::modifiers_changed(self, window, seat, modifiers)
+    }
+
+    /// A key of the keyboard of `seat` was pressed, it is sent to the focused widget of `seat`.
+    ///
+    /// Released keys are sent to the widget they were pressed on, even if the focus moved
+    /// while they were held.
+    pub fn seat_key_pressed(
+        &mut self,
+        window: WindowId,
+        seat: SeatId,
+        input: KeyInput,
+        pressed: bool,
+    ) -> bool {
+        self.record_input(window, || InputEvent::KeyPressed {
+            key: input.key.clone(),
+            code: input.code,
+            location: input.location,
+            repeat: input.repeat,
+            text: input.text.clone(),
+            pressed,
+        });
+
+        if pressed && self.is_input_blocked(window) {
+            return true;
+        }
+
+        passes::key::pressed(self, window, seat, input, pressed)
+    }
+}
+
+impl World {
+    /// Navigate back in `window`, returning whether anything handled it.
+    ///
+    /// When nothing handles [`BackEvent::Invoked`], the platform should go back itself, e.g. by
+    /// closing the activity on android.
+    pub fn back(&mut self, window: WindowId, event: BackEvent) -> bool {
+        passes::back::back(self, window, event)
     }
 }
 
 impl World {
     pub fn text_pasted(&mut self, window: WindowId, text: String) -> bool {
+        self.record_input(window, || {
+            InputEvent::TextPasted(text.clone())
+        });
+
+        if self.is_input_blocked(window) {
+            return true;
+        }
+
         passes::text::pasted(self, window, text)
     }
 
     pub fn ime_commit_text(&mut self, window: WindowId, text: String) -> bool {
+        self.record_input(window, || {
+            InputEvent::ImeCommitted(text.clone())
+        });
+
         passes::text::ime_commit(self, window, text)
     }
 
     pub fn ime_select(&mut self, window: WindowId, selection: Range<usize>) -> bool {
         passes::text::ime_select(self, window, selection)
     }
+
+    pub fn ime_preedit(
+        &mut self,
+        window: WindowId,
+        text: String,
+        cursor: Option<Range<usize>>,
+    ) -> bool {
+        passes::text::ime_preedit(self, window, text, cursor)
+    }
 }
 
 impl World {
-    pub fn touch_down(&mut self, window: WindowId, touch: TouchId, position: Point) -> bool {
-        passes::touch::down(self, window, touch, position)
+    pub fn touch_down(
+        &mut self,
+        window: WindowId,
+        touch: TouchId,
+        position: Point,
+        tool: Tool,
+    ) -> bool {
+        self.record_input(window, || InputEvent::TouchDown {
+            touch,
+            position,
+        });
+
+        if self.is_input_blocked(window) {
+            return true;
+        }
+
+        passes::touch::down(self, window, touch, position, tool)
     }
 
-    pub fn touch_up(&mut self, window: WindowId, touch: TouchId, position: Point) -> bool {
-        passes::touch::up(self, window, touch, position)
+    pub fn touch_up(
+        &mut self,
+        window: WindowId,
+        touch: TouchId,
+        position: Point,
+        tool: Tool,
+    ) -> bool {
+        self.record_input(window, || InputEvent::TouchUp {
+            touch,
+            position,
+        });
+
+        passes::touch::up(self, window, touch, position, tool)
     }
 
-    pub fn touch_move(&mut self, window: WindowId, touch: TouchId, position: Point) -> bool {
-        passes::touch::moved(self, window, touch, position)
+    pub fn touch_move(
+        &mut self,
+        window: WindowId,
+        touch: TouchId,
+        position: Point,
+        tool: Tool,
+    ) -> bool {
+        self.record_input(window, || InputEvent::TouchMoved {
+            touch,
+            position,
+        });
+
+        passes::touch::moved(self, window, touch, position, tool)
+    }
+
+    /// Send a `gesture` recognized by the platform, e.g. a pinch on a touchpad, to the widget
+    /// at `position`.
+    pub fn gesture(&mut self, window: WindowId, position: Point, gesture: Gesture) -> bool {
+        if self.is_input_blocked(window) {
+            return true;
+        }
+
+        passes::touch::gesture(self, window, position, gesture)
     }
 }
 
 impl World {
+    pub fn animation_clock(&self) -> AnimationClock {
+        self.state.clock
+    }
+
+    /// Set what drives animations.
+    ///
+    /// With [`AnimationClock::Manual`] the backend no longer animates, and animations only
+    /// advance with [`World::advance`], which makes them deterministic in tests.
+    pub fn set_animation_clock(&mut self, clock: AnimationClock) {
+        self.state.clock = clock;
+    }
+
+    /// The total time animations have been advanced by with [`World::advance`].
+    pub fn animation_time(&self) -> Duration {
+        self.state.time
+    }
+
+    /// Animate `window` by `delta_time`, called by the backend before drawing a frame.
+    ///
+    /// This does nothing when the [`AnimationClock`] is manual.
     pub fn animate(&mut self, window: WindowId, delta_time: Duration) {
-        passes::animate::animate_window(self, window, delta_time);
+        if let Some(window) = self.window(window) {
+            window.animate_requested.set(false);
+        }
+
+        if let AnimationClock::Real = self.state.clock {
+            let before = Allocations::current_thread();
+            let start = Instant::now();
+
+            passes::animate::animate_window(self, window, delta_time);
+
+            self.state.animate_allocations += Allocations::current_thread() - before;
+            self.state.animate_time += start.elapsed();
+        }
+    }
+
+    /// Advance the animations of every window by `duration`.
+    ///
+    /// With a manual [`AnimationClock`] the animations are advanced a frame at a time, exactly
+    /// as if `duration` had passed presenting frames.
+    pub fn advance(&mut self, duration: Duration) {
+        let frame = match self.state.clock {
+            AnimationClock::Manual { frame } if !frame.is_zero() => frame,
+            _ => duration,
+        };
+
+        let mut remaining = duration;
+
+        while !remaining.is_zero() {
+            let delta_time = remaining.min(frame);
+            remaining -= delta_time;
+            self.state.time += delta_time;
+
+            let windows: Vec<_> = self.state.windows.iter().map(|w| w.id).collect();
+
+            let before = Allocations::current_thread();
+            let start = Instant::now();
+
+            for window in windows {
+                passes::animate::animate_window(self, window, delta_time);
+            }
+
+            self.state.animate_allocations += Allocations::current_thread() - before;
+            self.state.animate_time += start.elapsed();
+        }
+    }
+
+    /// The allocations made in each phase of the last frame drawn, see [`CountingAllocator`].
+    pub fn frame_allocations(&self) -> &FrameAllocations {
+        &self.state.frame_allocations
+    }
+
+    /// The time taken by each phase of the last frame drawn.
+    pub fn frame_timings(&self) -> &FrameTimings {
+        &self.state.frame_timings
+    }
+
+    /// The number of widgets that exist.
+    pub fn widget_count(&self) -> usize {
+        self.widgets.len()
+    }
+
+    /// The number of widget slots allocated, including those freed for reuse.
+    pub fn widget_capacity(&self) -> usize {
+        self.widgets.capacity()
+    }
+
+    /// Called by the backend when it has handled or discarded a
+    /// [`Signal::RequestRedraw`](crate::Signal::RequestRedraw) for `window`, so later requests
+    /// are signalled again.
+    pub fn redraw_handled(&mut self, window: WindowId) {
+        if let Some(window) = self.state.get_window(window) {
+            window.redraw_requested.set(false);
+        }
     }
 
     pub fn draw(&mut self, window: WindowId, canvas: &mut dyn Canvas) -> Option<Size> {
+        // redraws requested while drawing are signalled again
+        if let Some(window) = self.window(window) {
+            window.redraw_requested.set(false);
+        }
+
+        let mut meter = PhaseMeter::start();
+
         let size = passes::layout::layout_window(self, window, canvas.painter());
+        meter.end(FramePhase::Layout);
+
         passes::compose::compose_window(self, window);
+        meter.end(FramePhase::Compose);
+
         passes::pointer::update_window_hovered(self, window);
+        meter.end(FramePhase::Hover);
+
         passes::record::record_window(self, window, canvas);
-        passes::draw::draw_window(self, window, canvas);
+        meter.end(FramePhase::Record);
+
+        let opacity = self.window(window).map_or(1.0, Window::opacity);
+        let color_matrix = (self.settings().debug.color_blindness)
+            .map(|color_blindness| color_blindness.color_matrix());
+
+        if opacity < 1.0 || color_matrix.is_some() {
+            let options = LayerOptions {
+                opacity,
+                color_matrix,
+                ..Default::default()
+            };
+
+            canvas.layer(&options, &mut |canvas| {
+                passes::draw::draw_window(self, window, canvas);
+            });
+        } else {
+            passes::draw::draw_window(self, window, canvas);
+        }
+
+        meter.end(FramePhase::Draw);
+
+        passes::sample::sample_window(self, window, canvas);
+        meter.end(FramePhase::Sample);
+
+        let (mut allocations, mut timings) = meter.finish();
+        allocations.add(
+            FramePhase::Animate,
+            mem::take(&mut self.state.animate_allocations),
+        );
+        timings.add(
+            FramePhase::Animate,
+            mem::take(&mut self.state.animate_time),
+        );
+        self.state.frame_allocations = allocations;
+        self.state.frame_timings = timings;
+
+        if let Some(window) = self.state.get_window_mut(window) {
+            window.frame_history.push(Instant::now());
+        }
+
+        if self.settings().debug.performance_overlay {
+            passes::debug::performance_overlay_window(self, window, canvas);
+        }
+
+        if self.settings().debug.contrast_overlay {
+            passes::debug::contrast_overlay_window(self, window, canvas);
+        }
+
+        if self.settings().debug.allocation_overlay {
+            passes::debug::allocation_overlay_window(self, window, canvas);
+        }
 
         if self.settings().debug.bounds_overlay {
             passes::debug::bounds_overlay_window(self, window, canvas);
         }
 
+        if self.settings().debug.layout_overlay {
+            passes::debug::layout_overlay_window(self, window, canvas);
+        }
+
         if self.settings().debug.recorder_overlay {
             passes::debug::recorder_overlay_window(self, window, canvas);
         }
 
+        if self.settings().debug.inspector {
+            passes::debug::inspector_overlay_window(self, window, canvas);
+        }
+
         size
     }
+
+    /// Print `widget` to `printer`, returning the number of pages printed.
+    ///
+    /// The widget is laid out to the width of the pages described by `setup`, and split into
+    /// as many pages as needed to fit its height.
+    pub fn print(
+        &mut self,
+        widget: impl AnyWidgetId,
+        setup: &PageSetup,
+        printer: &mut dyn Printer,
+    ) -> usize {
+        passes::print::print_widget(self, widget.upcast(), setup, printer)
+    }
+
+    /// Draw `widget` to `canvas` at the size it was last laid out with, for exporting it to
+    /// other formats.
+    ///
+    /// Unlike [`World::draw`] this never uses recordings, so `canvas` doesn't need to support them.
+    pub fn export(&mut self, widget: impl AnyWidgetId, canvas: &mut dyn Canvas) -> Option<Size> {
+        let mut widget = self.widget_mut(widget.upcast()).ok()?;
+        passes::draw::export_widget(&mut widget, canvas);
+
+        Some(widget.cx.size())
+    }
+}
+
+impl World {
+    /// Start recording the input received by `window`, replacing any recording in progress.
+    ///
+    /// Events are timed by the animations with a manual [`AnimationClock`], and by the real
+    /// time otherwise.
+    pub fn start_input_recording(&mut self, window: WindowId) {
+        self.state.input_recorder = Some(InputRecorder::new(
+            window,
+            self.state.time,
+        ));
+    }
+
+    /// Stop recording input, returning the recording, see [`World::start_input_recording`].
+    pub fn stop_input_recording(&mut self) -> Option<InputRecording> {
+        let recorder = self.state.input_recorder.take()?;
+        Some(recorder.recording)
+    }
+
+    pub fn is_recording_input(&self) -> bool {
+        self.state.input_recorder.is_some()
+    }
+
+    fn record_input(&mut self, window: WindowId, event: impl FnOnce() -> InputEvent) {
+        let clock = self.state.clock;
+        let time = self.state.time;
+
+        if let Some(recorder) = &mut self.state.input_recorder {
+            recorder.record(window, clock, time, event);
+        }
+    }
 }
 
 impl World {
+    /// Get where `widget` was created, this is only recorded in debug builds.
+    ///
+    /// The location is the caller of the outermost function marked `#[track_caller]` that
+    /// created the widget, e.g. of a widget constructor like [`Label::new`](crate::widgets::Label::new).
+    pub fn widget_location(
+        &self,
+        widget: impl AnyWidgetId,
+    ) -> Option<&'static panic::Location<'static>> {
+        self.widgets.location(widget.upcast())
+    }
+
+    /// Find the widget with `test_id`, see [`WidgetMut::set_test_id`].
+    ///
+    /// Test ids are expected to be unique, if more than one widget has `test_id` the first one
+    /// found is returned, searching parents before their children.
+    pub fn find_by_test_id(&self, test_id: &str) -> Option<WidgetId> {
+        passes::query::find_by_test_id(self, test_id)
+    }
+
     #[track_caller]
     pub(crate) fn widget(&self, id: WidgetId) -> Result<WidgetRef<'_>, GetError> {
         self.get_widget(id).map_err(|err| {
-            debug_panic!("failed getting widget {id:?}: {err}");
+            debug_panic!(
+                "failed getting widget {}: {err}",
+                self.widgets.describe(id)
+            );
             err
         })
     }
 
     #[track_caller]
     pub(crate) fn widget_mut(&mut self, id: WidgetId) -> Result<WidgetMut<'_>, GetError> {
-        self.get_widget_mut(id).map_err(|err| {
-            debug_panic!("failed getting widget {id:?}: {err}");
+        let widgets = &self.widgets;
+
+        widgets.get_mut(&mut self.state, id).map_err(|err| {
+            debug_panic!(
+                "failed getting widget {}: {err}",
+                widgets.describe(id)
+            );
             err
         })
     }
@@ -1,11 +1,17 @@
-use crate::{DebugSettings, event::TouchSettings, record::RecordSettings};
+use std::time::Duration;
+
+use crate::{AnimationSettings, DebugSettings, event::TouchSettings, record::RecordSettings};
 
 #[derive(Debug, Default)]
 pub struct Settings {
-    pub touch:  TouchSettings,
-    pub debug:  DebugSettings,
-    pub record: RecordSettings,
-    pub render: RenderSettings,
+    pub touch:    TouchSettings,
+    pub debug:    DebugSettings,
+    pub record:   RecordSettings,
+    pub render:   RenderSettings,
+    pub throttle: ThrottleSettings,
+
+    /// Override the [`AnimationSettings`] read from the OS.
+    pub animation: Option<AnimationSettings>,
 }
 
 #[derive(Debug)]
@@ -18,3 +24,42 @@ impl Default for RenderSettings {
         Self { pixel_align: true }
     }
 }
+
+/// How animations of a window are scheduled while it's in the background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Throttle {
+    /// Animate at the full frame rate.
+    None,
+
+    /// Animate at most once per interval.
+    Interval(Duration),
+
+    /// Don't animate until the window is in the foreground again.
+    Pause,
+}
+
+/// Settings for throttling windows in the background, see [`World::window_throttle`].
+///
+/// [`World::window_throttle`]: crate::World::window_throttle
+#[derive(Debug)]
+pub struct ThrottleSettings {
+    /// Throttling of windows without focus.
+    ///
+    /// Default is [`Throttle::None`].
+    pub unfocused: Throttle,
+
+    /// Throttling of windows that are hidden or completely covered, takes precedence over
+    /// [`ThrottleSettings::unfocused`].
+    ///
+    /// Default is [`Throttle::Pause`].
+    pub occluded: Throttle,
+}
+
+impl Default for ThrottleSettings {
+    fn default() -> Self {
+        Self {
+            unfocused: Throttle::None,
+            occluded:  Throttle::Pause,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::{fmt, ops::Range, time::Instant};
 
-use crate::{CursorIcon, Rect, WindowId, WindowSizing, World};
+use crate::{
+    Cursor, CursorGrab, FileDialog, FileDialogResponder, PageSetup, Point, PopupAnchor,
+    Presentation, Rect, ResizeEdge, WidgetId, WindowId, WindowLevel, WindowSizing, World,
+};
 
 pub enum Signal {
     /// `window` needs to be redraw.
@@ -17,6 +20,10 @@ pub enum Signal {
     /// Set the text contents of the clipboard.
     ClipboardSet(String),
 
+    /// Read the text contents of the clipboard, and paste them into `window` with
+    /// [`World::text_pasted`].
+    ClipboardPaste(WindowId),
+
     /// Create a window.
     CreateWindow(WindowId),
 
@@ -26,10 +33,41 @@ pub enum Signal {
     /// Update a window.
     UpdateWindow(WindowId, WindowUpdate),
 
+    /// Close a window, as if its close button was pressed.
+    CloseWindow(WindowId),
+
     /// Perform an action pertaining to IME.
     Ime(ImeSignal),
+
+    /// Print `widget` with the platform print dialog, see [`World::print`].
+    Print { widget: WidgetId, setup: PageSetup },
+
+    /// Show the platform file dialog, and call `respond` with the picked paths.
+    FileDialog {
+        dialog:  FileDialog,
+        respond: FileDialogResponder,
+    },
+
+    /// Set the state the platform saves for when the application is restarted.
+    SaveState(Vec<u8>),
+
+    /// Render `widget` as an SVG document with the painter of the backend, and call `respond`
+    /// with it, see [`World::export`].
+    RenderSvg {
+        widget:  WidgetId,
+        respond: SvgResponder,
+    },
+
+    /// Load a font with the painter of the backend, as the family `alias` if set.
+    LoadFont {
+        bytes: Vec<u8>,
+        alias: Option<String>,
+    },
 }
 
+/// Called with the SVG document rendered for [`Signal::RenderSvg`].
+pub type SvgResponder = Box<dyn FnOnce(String) + Send>;
+
 impl fmt::Debug for Signal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -48,6 +86,8 @@ impl fmt::Debug for Signal {
 
             Self::ClipboardSet(contents) => f.debug_tuple("ClipboardSet").field(contents).finish(),
 
+            Self::ClipboardPaste(window) => f.debug_tuple("ClipboardPaste").field(window).finish(),
+
             Self::CreateWindow(window) => f.debug_tuple("CreateWindow").field(window).finish(),
 
             Self::RemoveWindow(window) => f.debug_tuple("RemoveWindow").field(window).finish(),
@@ -58,7 +98,33 @@ impl fmt::Debug for Signal {
                 .field(update)
                 .finish(),
 
+            Self::CloseWindow(window) => f.debug_tuple("CloseWindow").field(window).finish(),
+
             Self::Ime(ime) => f.debug_tuple("Ime").field(ime).finish(),
+
+            Self::Print { widget, setup } => f
+                .debug_struct("Print")
+                .field("widget", widget)
+                .field("setup", setup)
+                .finish(),
+
+            Self::FileDialog { dialog, .. } => f
+                .debug_struct("FileDialog")
+                .field("dialog", dialog)
+                .finish_non_exhaustive(),
+
+            Self::SaveState(state) => f.debug_tuple("SaveState").field(&state.len()).finish(),
+
+            Self::RenderSvg { widget, .. } => f
+                .debug_struct("RenderSvg")
+                .field("widget", widget)
+                .finish_non_exhaustive(),
+
+            Self::LoadFont { bytes, alias } => f
+                .debug_struct("LoadFont")
+                .field("bytes", &bytes.len())
+                .field("alias", alias)
+                .finish(),
         }
     }
 }
@@ -69,7 +135,36 @@ pub enum WindowUpdate {
     Sizing(WindowSizing),
     Visible(bool),
     Decorated(bool),
-    Cursor(CursorIcon),
+    Level(WindowLevel),
+    SkipTaskbar(bool),
+
+    /// Blur what is behind the window, where the platform supports it.
+    Blur(bool),
+    Cursor(Cursor),
+    CursorGrab(CursorGrab),
+
+    /// Move the cursor to a point in the window.
+    CursorPosition(Point),
+    Presentation(Presentation),
+
+    /// Move the window with the pointer, while the primary button is held.
+    Drag,
+
+    /// Resize the window from an edge with the pointer, while the primary button is held.
+    DragResize(ResizeEdge),
+    Maximized(bool),
+    Minimized(bool),
+
+    /// Move the window to a position on the desktop, where the platform supports it.
+    Position(Point),
+
+    /// Bring the window to the front and focus it.
+    Focus,
+
+    /// Place the window next to an anchor in its parent, see [`Window::popup`].
+    ///
+    /// [`Window::popup`]: crate::Window::popup
+    Popup(PopupAnchor),
 }
 
 #[derive(Clone, Debug)]
@@ -82,4 +177,17 @@ pub enum ImeSignal {
         selection: Range<usize>,
         composing: Option<Range<usize>>,
     },
+
+    /// The kind of text being edited changed, see [`ImePurpose`].
+    Purpose(ImePurpose),
+}
+
+/// The kind of text edited with an input method, so that it can adapt to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ImePurpose {
+    #[default]
+    Normal,
+
+    /// Secret text, the input method shouldn't show, learn from or suggest it.
+    Password,
 }
@@ -1,10 +1,16 @@
-use std::time::Instant;
-
-use cursor_icon::CursorIcon;
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    mem,
+    time::{Duration, Instant},
+};
 
 use crate::{
-    Color, Recorder, Settings, Signal, Window, WindowId, WindowSizing, WindowUpdate,
-    debug::debug_panic,
+    Allocations, AnimationClock, Color, ColorScheme, Cursor, CursorGrab, FileDialog,
+    FileDialogResponder, FrameAllocations, FrameTimings, LayoutDirection, Lifecycle,
+    MemoryPressure, PageSetup, Point, PopupAnchor, Presentation, Recorder, ResizeEdge, Settings,
+    Signal, SvgResponder, Transition, WidgetId, Window, WindowId, WindowLevel, WindowSizing,
+    WindowUpdate, debug::debug_panic, hero::HeroCoordinator, playback::InputRecorder,
 };
 
 pub(crate) struct WorldState {
@@ -13,6 +19,49 @@ pub(crate) struct WorldState {
 
     pub windows:  Vec<Window>,
     pub recorder: Recorder,
+
+    /// The input being recorded, see
+    /// [`World::start_input_recording`](crate::World::start_input_recording).
+    pub input_recorder: Option<InputRecorder>,
+
+    pub color_scheme:    Option<ColorScheme>,
+    pub memory_pressure: MemoryPressure,
+    pub lifecycle:       Lifecycle,
+
+    /// The layout direction of the language of the platform, see
+    /// [`World::layout_direction_changed`](crate::World::layout_direction_changed).
+    pub layout_direction: LayoutDirection,
+
+    /// Whether every window is redacted, see [`World::set_redacted`](crate::World::set_redacted).
+    pub is_redacted: bool,
+
+    pub heroes: HeroCoordinator,
+
+    pub clock: AnimationClock,
+    pub time:  Duration,
+
+    /// Window updates waiting to be signalled, see [`WorldState::update_window`].
+    pub pending_updates: RefCell<Vec<(WindowId, WindowUpdate)>>,
+
+    /// The open transaction, see [`Builder::transaction`](crate::Builder::transaction).
+    pub transaction: RefCell<Option<Transaction>>,
+
+    /// Allocations made animating since the last frame was drawn.
+    pub animate_allocations: Allocations,
+    pub frame_allocations:   FrameAllocations,
+
+    /// Time spent animating since the last frame was drawn.
+    pub animate_time:  Duration,
+    pub frame_timings: FrameTimings,
+}
+
+/// Invalidations deferred until the end of a transaction.
+#[derive(Default)]
+pub(crate) struct Transaction {
+    /// The number of nested transactions.
+    pub depth:   usize,
+    pub widgets: HashSet<WidgetId>,
+    pub windows: HashSet<WindowId>,
 }
 
 impl WorldState {
@@ -23,6 +72,29 @@ impl WorldState {
 
             windows: Vec::new(),
             recorder: Recorder::new(),
+            input_recorder: None,
+
+            color_scheme: None,
+            memory_pressure: MemoryPressure::None,
+            lifecycle: Lifecycle::Resumed,
+
+            layout_direction: LayoutDirection::Ltr,
+
+            is_redacted: false,
+
+            heroes: HeroCoordinator::default(),
+
+            clock: AnimationClock::Real,
+            time: Duration::ZERO,
+
+            pending_updates: RefCell::new(Vec::new()),
+            transaction: RefCell::new(None),
+
+            animate_allocations: Allocations::ZERO,
+            frame_allocations: FrameAllocations::default(),
+
+            animate_time: Duration::ZERO,
+            frame_timings: FrameTimings::default(),
         }
     }
 }
@@ -57,52 +129,214 @@ impl WorldState {
     }
 
     pub fn set_window_title(&self, window: WindowId, title: String) {
-        self.emit_signal(Signal::UpdateWindow(
-            window,
-            WindowUpdate::Title(title),
-        ));
+        self.update_window(window, WindowUpdate::Title(title));
     }
 
     pub fn set_window_sizing(&self, window: WindowId, sizing: WindowSizing) {
-        self.emit_signal(Signal::UpdateWindow(
-            window,
-            WindowUpdate::Sizing(sizing),
-        ));
+        self.update_window(window, WindowUpdate::Sizing(sizing));
     }
 
     pub fn set_window_visible(&self, window: WindowId, visible: bool) {
-        self.emit_signal(Signal::UpdateWindow(
-            window,
-            WindowUpdate::Visible(visible),
-        ));
+        // hidden windows may not be redrawn, so request again once shown
+        if visible && let Some(win) = self.get_window(window) {
+            win.redraw_requested.set(false);
+        }
+
+        self.update_window(window, WindowUpdate::Visible(visible));
     }
 
     pub fn set_window_decorated(&self, window: WindowId, decorated: bool) {
-        self.emit_signal(Signal::UpdateWindow(
+        self.update_window(
             window,
             WindowUpdate::Decorated(decorated),
-        ));
+        );
+    }
+
+    /// Set the opacity of `window`, applied to both its color and its contents.
+    pub fn set_window_opacity(&mut self, window: WindowId, opacity: f32) {
+        let opacity = opacity.clamp(0.0, 1.0);
+
+        if let Some(window) = self.window_mut(window)
+            && window.opacity != opacity
+        {
+            window.opacity = opacity;
+
+            let window = window.id;
+            self.request_redraw(window);
+        }
+    }
+
+    pub fn set_window_level(&mut self, window: WindowId, level: WindowLevel) {
+        if let Some(window) = self.window_mut(window)
+            && window.level != level
+        {
+            window.level = level;
+
+            let window = window.id;
+            self.update_window(window, WindowUpdate::Level(level));
+        }
+    }
+
+    pub fn set_window_skip_taskbar(&mut self, window: WindowId, skip_taskbar: bool) {
+        if let Some(window) = self.window_mut(window)
+            && window.skip_taskbar != skip_taskbar
+        {
+            window.skip_taskbar = skip_taskbar;
+
+            let window = window.id;
+            self.update_window(
+                window,
+                WindowUpdate::SkipTaskbar(skip_taskbar),
+            );
+        }
+    }
+
+    /// Blur what is behind `window`, where the platform supports it.
+    pub fn set_window_blurred(&mut self, window: WindowId, blurred: bool) {
+        if let Some(window) = self.window_mut(window)
+            && window.is_blurred != blurred
+        {
+            window.is_blurred = blurred;
+
+            let window = window.id;
+            self.update_window(window, WindowUpdate::Blur(blurred));
+        }
     }
 
     pub fn set_window_color(&mut self, window: WindowId, color: Color) {
+        if let Some(window) = self.window_mut(window)
+            && window.color.begin(color)
+        {
+            let window = window.id;
+            self.request_animate(window);
+        }
+    }
+
+    /// Set the transition used when the color of `window` changes.
+    pub fn set_window_color_transition(&mut self, window: WindowId, transition: Transition) {
         if let Some(window) = self.window_mut(window) {
-            window.color = color;
+            window.color.set_transition(transition);
         }
     }
 
-    pub fn set_window_cursor(&mut self, window: WindowId, cursor: CursorIcon) {
+    /// Set how the frames of `window` are buffered, see [`Presentation`].
+    pub fn set_window_presentation(&mut self, window: WindowId, presentation: Presentation) {
         if let Some(window) = self.window_mut(window)
-            && window.cursor != cursor
+            && window.presentation != presentation
         {
-            window.cursor = cursor;
+            window.presentation = presentation;
 
             let window = window.id;
-            self.emit_signal(Signal::UpdateWindow(
+            self.update_window(
                 window,
-                WindowUpdate::Cursor(cursor),
-            ));
+                WindowUpdate::Presentation(presentation),
+            );
         }
     }
+
+    pub fn set_window_cursor(&mut self, window: WindowId, cursor: Cursor) {
+        if let Some(window) = self.window_mut(window)
+            && window.cursor != cursor
+        {
+            window.cursor = cursor.clone();
+
+            let window = window.id;
+            self.update_window(window, WindowUpdate::Cursor(cursor));
+        }
+    }
+
+    pub fn set_window_cursor_grab(&mut self, window: WindowId, grab: CursorGrab) {
+        if let Some(window) = self.window_mut(window)
+            && window.grab_cursor != grab
+        {
+            window.grab_cursor = grab;
+
+            let window = window.id;
+            self.update_window(window, WindowUpdate::CursorGrab(grab));
+        }
+    }
+
+    /// Set the window owning `window`, only windows created after their parent are placed
+    /// over it by the platform.
+    pub fn set_window_parent(&mut self, window: WindowId, parent: Option<WindowId>) {
+        if let Some(window) = self.window_mut(window) {
+            window.parent = parent;
+        }
+    }
+
+    /// Set whether `window` blocks input to its parent while it's visible.
+    pub fn set_window_modal(&mut self, window: WindowId, modal: bool) {
+        if let Some(window) = self.window_mut(window) {
+            window.is_modal = modal;
+        }
+    }
+
+    /// Make `window` a popup placed next to `anchor` in its parent, or a regular window with
+    /// `None`, see [`Window::popup`].
+    pub fn set_window_popup(&mut self, window: WindowId, anchor: Option<PopupAnchor>) {
+        if let Some(window) = self.window_mut(window)
+            && window.popup != anchor
+        {
+            window.popup = anchor;
+
+            let window = window.id;
+            if let Some(anchor) = anchor {
+                self.update_window(window, WindowUpdate::Popup(anchor));
+            }
+        }
+    }
+
+    /// The modal child of `window` input to it is blocked by, if any.
+    pub fn window_modal(&self, window: WindowId) -> Option<WindowId> {
+        (self.windows.iter())
+            .find(|child| child.parent == Some(window) && child.is_modal && child.is_visible)
+            .map(|child| child.id)
+    }
+
+    /// Move `window` with the pointer, while the primary button is held.
+    pub fn begin_window_drag(&self, window: WindowId) {
+        self.update_window(window, WindowUpdate::Drag);
+    }
+
+    /// Resize `window` from `edge` with the pointer, while the primary button is held.
+    pub fn begin_window_resize(&self, window: WindowId, edge: ResizeEdge) {
+        self.update_window(window, WindowUpdate::DragResize(edge));
+    }
+
+    pub fn set_window_maximized(&self, window: WindowId, maximized: bool) {
+        self.update_window(
+            window,
+            WindowUpdate::Maximized(maximized),
+        );
+    }
+
+    /// Move `window` to `position` on the desktop, where the platform supports it.
+    pub fn set_window_position(&mut self, window: WindowId, position: Point) {
+        if let Some(win) = self.window_mut(window) {
+            win.position = Some(position);
+        }
+
+        self.update_window(window, WindowUpdate::Position(position));
+    }
+
+    pub fn set_window_minimized(&self, window: WindowId, minimized: bool) {
+        self.update_window(
+            window,
+            WindowUpdate::Minimized(minimized),
+        );
+    }
+
+    /// Close `window`, as if its close button was pressed.
+    pub fn close_window(&self, window: WindowId) {
+        self.emit_signal(Signal::CloseWindow(window));
+    }
+
+    pub fn set_window_cursor_position(&self, window: WindowId, position: Point) {
+        self.update_window(
+            window,
+            WindowUpdate::CursorPosition(position),
+        );
+    }
 }
 
 impl WorldState {
@@ -110,14 +344,113 @@ impl WorldState {
         (self.signaller)(signal);
     }
 
+    /// Signal that `window` needs to be animated, unless that's already been signalled since
+    /// it was last animated.
     pub fn request_animate(&self, window: WindowId) {
+        if let Some(win) = self.get_window(window)
+            && win.animate_requested.replace(true)
+        {
+            return;
+        }
+
         self.emit_signal(Signal::RequestAnimate {
             window,
             start: Instant::now(),
         });
     }
 
+    /// Signal that `window` needs to be redrawn, unless that's already been signalled since it
+    /// was last drawn.
     pub fn request_redraw(&self, window: WindowId) {
+        if let Some(ref mut transaction) = *self.transaction.borrow_mut() {
+            transaction.windows.insert(window);
+            return;
+        }
+
+        if let Some(win) = self.get_window(window)
+            && win.redraw_requested.replace(true)
+        {
+            return;
+        }
+
         self.emit_signal(Signal::RequestRedraw { window });
     }
+
+    pub fn begin_transaction(&self) {
+        let mut transaction = self.transaction.borrow_mut();
+        transaction.get_or_insert_default().depth += 1;
+    }
+
+    /// End a transaction, returning the deferred invalidations if it was the outermost one.
+    pub fn end_transaction(&self) -> Option<Transaction> {
+        let mut transaction = self.transaction.borrow_mut();
+        let open = transaction.as_mut()?;
+        open.depth -= 1;
+
+        match open.depth {
+            0 => transaction.take(),
+            _ => None,
+        }
+    }
+
+    /// Defer propagating the invalidation of `widget` to its ancestors if a transaction is open.
+    pub fn defer_propagation(&self, widget: WidgetId) -> bool {
+        match *self.transaction.borrow_mut() {
+            Some(ref mut transaction) => {
+                transaction.widgets.insert(widget);
+                true
+            }
+
+            None => false,
+        }
+    }
+
+    /// Queue `update` for `window`, replacing a queued update of the same kind.
+    ///
+    /// The queued updates of a window are signalled together once the signals emitted before
+    /// the first of them have been handled.
+    pub fn update_window(&self, window: WindowId, update: WindowUpdate) {
+        let mut pending = self.pending_updates.borrow_mut();
+        let is_first = !pending.iter().any(|(id, _)| *id == window);
+
+        pending.retain(|(id, queued)| {
+            *id != window || mem::discriminant(queued) != mem::discriminant(&update)
+        });
+        pending.push((window, update));
+        drop(pending);
+
+        if is_first {
+            self.emit_signal(Signal::Mutate(Box::new(move |world| {
+                world.state.flush_window_updates(window);
+            })));
+        }
+    }
+
+    fn flush_window_updates(&self, window: WindowId) {
+        let mut updates = Vec::new();
+
+        self.pending_updates.borrow_mut().retain(|(id, update)| {
+            if *id == window {
+                updates.push(update.clone());
+            }
+
+            *id != window
+        });
+
+        for update in updates {
+            self.emit_signal(Signal::UpdateWindow(window, update));
+        }
+    }
+
+    pub fn print(&self, widget: WidgetId, setup: PageSetup) {
+        self.emit_signal(Signal::Print { widget, setup });
+    }
+
+    pub fn show_file_dialog(&self, dialog: FileDialog, respond: FileDialogResponder) {
+        self.emit_signal(Signal::FileDialog { dialog, respond });
+    }
+
+    pub fn render_svg(&self, widget: WidgetId, respond: SvgResponder) {
+        self.emit_signal(Signal::RenderSvg { widget, respond });
+    }
 }
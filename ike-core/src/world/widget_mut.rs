@@ -1,6 +1,6 @@
 use std::{cell::RefMut, marker::PhantomData};
 
-use crate::{AnyWidget, MutCx, Update, Widget, WidgetId, passes};
+use crate::{AnyWidget, LayoutDirection, MutCx, Update, Widget, WidgetId, passes};
 
 pub struct WidgetMut<'a, T = dyn Widget>
 where
@@ -66,6 +66,18 @@ where
         passes::hierarchy::set_disabled(self, is_disabled);
     }
 
+    /// Set the name that identifies the widget to tests and tooling, see
+    /// [`World::find_by_test_id`](crate::World::find_by_test_id).
+    pub fn set_test_id(&mut self, test_id: Option<String>) {
+        self.cx.state.test_id = test_id;
+    }
+
+    /// Lay out the widget and its descendants in `direction`, or inherit the direction of the
+    /// parent with `None`.
+    pub fn set_layout_direction(&mut self, direction: Option<LayoutDirection>) {
+        passes::hierarchy::set_layout_direction(self, direction);
+    }
+
     pub(crate) fn set_hovered(&mut self, is_hovered: bool) {
         if self.cx.hierarchy.is_hovered() == is_hovered {
             return;
@@ -3,6 +3,7 @@ use std::{
     cell::{Ref, RefCell, RefMut},
     fmt,
     marker::PhantomData,
+    panic::Location,
 };
 
 use crate::{
@@ -37,6 +38,10 @@ pub(crate) struct Widgets {
     widgets:   Vec<RefCell<Box<dyn Widget>>>,
     states:    Vec<RefCell<WidgetState>>,
     hierarchy: Vec<WidgetHierarchy>,
+
+    /// Where each widget was created, kept after it's removed until the index is reused, only
+    /// recorded in debug builds.
+    locations: Vec<Option<(u32, &'static Location<'static>)>>,
 }
 
 impl Default for Widgets {
@@ -52,6 +57,7 @@ impl Widgets {
             widgets:   Vec::new(),
             states:    Vec::new(),
             hierarchy: Vec::new(),
+            locations: Vec::new(),
         }
     }
 
@@ -59,6 +65,15 @@ impl Widgets {
         self.entities.contains(widget)
     }
 
+    pub fn len(&self) -> usize {
+        self.entities.entities.len() - self.entities.freed.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entities.entities.len()
+    }
+
+    #[track_caller]
     pub fn insert<T>(&mut self, widget: T) -> WidgetId<T>
     where
         T: Widget,
@@ -66,17 +81,24 @@ impl Widgets {
         let id = self.entities.alloc();
         let state = WidgetState::new::<T>(id);
 
+        let location = match cfg!(debug_assertions) {
+            true => Some((id.generation, Location::caller())),
+            false => None,
+        };
+
         if id.index as usize >= self.widgets.len() {
             debug_assert_eq!(id.index as usize, self.widgets.len());
 
             self.widgets.push(RefCell::new(Box::new(widget)));
             self.states.push(RefCell::new(state));
             self.hierarchy.push(WidgetHierarchy::new::<T>());
+            self.locations.push(location);
         } else {
             let index = id.index as usize;
             self.widgets[index] = RefCell::new(Box::new(widget));
             self.states[index] = RefCell::new(state);
             self.hierarchy[index] = WidgetHierarchy::new::<T>();
+            self.locations[index] = location;
         }
 
         WidgetId {
@@ -172,6 +194,22 @@ impl Widgets {
         Ok(WidgetMut { widget, cx })
     }
 
+    /// Get where `id` was created, also after it was removed, until its index is reused.
+    pub fn location(&self, id: WidgetId) -> Option<&'static Location<'static>> {
+        match self.locations.get(id.index as usize)? {
+            Some((generation, location)) if *generation == id.generation => Some(location),
+            _ => None,
+        }
+    }
+
+    /// Describe `id` for messages, with where it was created when known.
+    pub fn describe(&self, id: WidgetId) -> String {
+        match self.location(id) {
+            Some(location) => format!("{id:?} (created at {location})"),
+            None => format!("{id:?}"),
+        }
+    }
+
     pub fn get_hierarchy(&self, id: WidgetId) -> Option<&WidgetHierarchy> {
         self.hierarchy.get(id.index as usize)
     }
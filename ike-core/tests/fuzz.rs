@@ -0,0 +1,66 @@
+use ike_core::{
+    AnyWidgetId, Builder, Color, FontStretch, FontStyle, FontWeight, Paragraph, Settings, Size,
+    TextAlign, TextStyle, TextWrap, World,
+    testing::{
+        HeadlessCanvas,
+        arena::{Arena, arena_ops},
+        proptest::prelude::*,
+        text::{check_text_area, text_edits, unicode_text},
+    },
+    widgets::TextArea,
+};
+
+fn world() -> World {
+    World::new(Box::new(|_| {}), Settings::default())
+}
+
+fn style() -> TextStyle {
+    TextStyle {
+        font_size:    16.0,
+        font_family:  String::from("monospace"),
+        font_weight:  FontWeight::NORMAL,
+        font_stretch: FontStretch::Normal,
+        font_style:   FontStyle::Normal,
+        paint:        Color::BLACK.into(),
+    }
+}
+
+proptest! {
+    #[test]
+    fn text_area_edits(text in unicode_text(), edits in text_edits(64), wrap in any::<bool>()) {
+        let mut world = world();
+        let mut canvas = HeadlessCanvas::default();
+
+        let wrap = match wrap {
+            true => TextWrap::Word,
+            false => TextWrap::None,
+        };
+
+        let mut paragraph = Paragraph::new(1.2, TextAlign::Start, wrap);
+        paragraph.push(&text, style());
+
+        let text_area = TextArea::<true>::new(&mut world, paragraph).id();
+        let window = world.create_window(text_area.upcast());
+
+        world.window_resized(window, Size::new(64.0, 256.0));
+        world.set_focus(window, Some(text_area.upcast()));
+        world.draw(window, &mut canvas);
+
+        for edit in &edits {
+            edit.apply(&mut world, window, &mut canvas);
+
+            let text_area = world.get_widget(text_area).expect("text area should exist");
+            check_text_area(&text_area.widget)?;
+        }
+    }
+
+    #[test]
+    fn arena_ops_keep_invariants(ops in arena_ops(64)) {
+        let mut world = world();
+        let mut arena = Arena::new();
+
+        for op in &ops {
+            arena.apply(&mut world, op)?;
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use ike_core::{
+    AnimationClock, AnyWidgetId, Builder, InputRecording, Key, Paragraph, Point, PointerButton,
+    PointerId, Settings, Size, TextAlign, TextWrap, Tool, WidgetId, WindowId, World,
+    testing::HeadlessCanvas, widgets::TextArea,
+};
+
+fn focused_text_area() -> (
+    World,
+    WindowId,
+    WidgetId<TextArea<true>>,
+) {
+    let mut world = World::new(Box::new(|_| {}), Settings::default());
+    world.set_animation_clock(AnimationClock::manual());
+
+    let paragraph = Paragraph::new(1.2, TextAlign::Start, TextWrap::Word);
+    let text_area = TextArea::<true>::new(&mut world, paragraph).id();
+    let window = world.create_window(text_area.upcast());
+
+    world.set_focus(window, Some(text_area.upcast()));
+
+    (world, window, text_area)
+}
+
+fn text(world: &World, text_area: WidgetId<TextArea<true>>) -> String {
+    let text_area = world.get_widget(text_area).expect("text area should exist");
+    String::from(text_area.widget.text())
+}
+
+#[test]
+fn recorded_input_replays() {
+    let (mut world, window, text_area) = focused_text_area();
+    let mut canvas = HeadlessCanvas::default();
+    let pointer = PointerId::from_u64(1);
+
+    world.start_input_recording(window);
+    world.window_resized(window, Size::new(128.0, 64.0));
+    world.draw(window, &mut canvas);
+
+    world.pointer_entered(window, pointer);
+    world.pointer_moved(
+        window,
+        pointer,
+        Point::new(8.0, 8.0),
+        Tool::MOUSE,
+    );
+    world.pointer_pressed(
+        window,
+        pointer,
+        PointerButton::Primary,
+        true,
+    );
+    world.advance(Duration::from_millis(50));
+    world.pointer_pressed(
+        window,
+        pointer,
+        PointerButton::Primary,
+        false,
+    );
+
+    for c in ["h", "i"] {
+        let key = Key::Character(String::from(c));
+        world.key_pressed(
+            window,
+            key.clone(),
+            false,
+            Some(c),
+            true,
+        );
+        world.advance(Duration::from_millis(100));
+        world.key_pressed(window, key, false, None, false);
+    }
+
+    world.text_pasted(window, String::from(" \"there\"\n"));
+    world.draw(window, &mut canvas);
+
+    let recording = world
+        .stop_input_recording()
+        .expect("input should be recorded");
+    assert!(!world.is_recording_input());
+    assert_eq!(
+        recording.duration(),
+        Duration::from_millis(250)
+    );
+
+    let parsed: InputRecording = recording
+        .to_string()
+        .parse()
+        .expect("recording should parse");
+    assert_eq!(parsed, recording);
+
+    let (mut replayed, window, replayed_area) = focused_text_area();
+    parsed.replay(&mut replayed, window, &mut canvas);
+
+    assert_eq!(
+        text(&replayed, replayed_area),
+        text(&world, text_area),
+    );
+}
@@ -0,0 +1,304 @@
+use std::{cell::Cell, rc::Rc};
+
+use ike_core::{
+    Axis, Builder, Color, FontStretch, FontStyle, FontWeight, Key, LayoutDirection, NamedKey,
+    Paragraph, Point, TextAlign, TextStyle, TextWrap,
+    testing::Tester,
+    widgets::{Button, Disabled, Entry, Label, Stack, TextArea},
+};
+
+fn paragraph(text: &str) -> Paragraph {
+    let style = TextStyle {
+        font_size:    16.0,
+        font_family:  String::from("monospace"),
+        font_weight:  FontWeight::NORMAL,
+        font_stretch: FontStretch::Normal,
+        font_style:   FontStyle::Normal,
+        paint:        Color::BLACK.into(),
+    };
+
+    let mut paragraph = Paragraph::new(1.2, TextAlign::Start, TextWrap::None);
+    paragraph.push(text, style);
+    paragraph
+}
+
+#[test]
+fn click_button_by_text() {
+    let clicks = Rc::new(Cell::new(0));
+
+    let mut tester = Tester::new(|world| {
+        let label = Label::new(world, paragraph("click me")).id();
+        let mut button = Button::new(world, label);
+
+        Button::set_on_click(&mut button, {
+            let clicks = clicks.clone();
+            move || clicks.set(clicks.get() + 1)
+        });
+
+        button.id()
+    });
+
+    let label = tester.find_text("click me").expect("label should exist");
+    let button = tester.find::<Button>().expect("button should exist");
+
+    assert!(tester.rect(button).contains(tester.rect(label).center()));
+    assert_eq!(tester.find_all::<Label>().len(), 1);
+
+    tester.click_widget(label);
+    tester.settle();
+    assert_eq!(clicks.get(), 1);
+
+    let label = tester.find::<Label>().expect("label should exist");
+
+    {
+        let mut label = tester.get_widget_mut(label).expect("label should exist");
+        Label::set_text(&mut label, paragraph("clicked"));
+    }
+
+    tester.pump();
+
+    assert!(tester.find_text("click me").is_none());
+    assert!(tester.find_text("clicked").is_some());
+}
+
+#[test]
+fn click_button_by_test_id() {
+    let clicks = Rc::new(Cell::new(Vec::new()));
+
+    let mut tester = Tester::new(|world| {
+        let stack = {
+            let mut stack = Stack::new(world);
+            Stack::set_axis(&mut stack, Axis::Horizontal);
+            stack.id()
+        };
+
+        for name in ["cancel", "confirm"] {
+            let label = Label::new(world, paragraph(name)).id();
+
+            let button = {
+                let mut button = Button::new(world, label);
+                button.set_test_id(Some(format!("{name}-button")));
+
+                Button::set_on_click(&mut button, {
+                    let clicks = clicks.clone();
+                    move || {
+                        let mut names = clicks.take();
+                        names.push(name);
+                        clicks.set(names);
+                    }
+                });
+
+                button.id()
+            };
+
+            world.add_child(stack, button);
+        }
+
+        stack
+    });
+
+    assert!(tester.find_by_test_id("missing").is_none());
+
+    let confirm = tester
+        .find_by_test_id("confirm-button")
+        .expect("confirm button should exist");
+    assert_eq!(
+        tester.get(confirm).cx.test_id(),
+        Some("confirm-button")
+    );
+
+    tester.click_widget(confirm);
+    tester.settle();
+    assert_eq!(clicks.take(), vec!["confirm"]);
+}
+
+#[test]
+fn activate_button_with_keys() {
+    let clicks = Rc::new(Cell::new(0));
+
+    let mut tester = Tester::new(|world| {
+        let label = Label::new(world, paragraph("press me")).id();
+        let mut button = Button::new(world, label);
+
+        Button::set_on_click(&mut button, {
+            let clicks = clicks.clone();
+            move || clicks.set(clicks.get() + 1)
+        });
+
+        button.id()
+    });
+
+    let button = tester.find::<Button>().expect("button should exist");
+
+    tester.key(Key::Character(String::from(" ")));
+    assert_eq!(clicks.get(), 0);
+
+    tester.focus(button);
+
+    tester.key(Key::Character(String::from(" ")));
+    assert_eq!(clicks.get(), 1);
+
+    tester.key(Key::Named(NamedKey::Enter));
+    assert_eq!(clicks.get(), 2);
+
+    tester.key(Key::Character(String::from("a")));
+    assert_eq!(clicks.get(), 2);
+}
+
+#[test]
+fn disabled_button_ignores_input() {
+    let clicks = Rc::new(Cell::new(0));
+
+    let mut tester = Tester::new(|world| {
+        let label = Label::new(world, paragraph("disabled")).id();
+
+        let button = {
+            let mut button = Button::new(world, label);
+
+            Button::set_on_click(&mut button, {
+                let clicks = clicks.clone();
+                move || clicks.set(clicks.get() + 1)
+            });
+
+            button.id()
+        };
+
+        let mut disabled = Disabled::new(world, button);
+        Disabled::set_disabled(&mut disabled, true);
+        disabled.id()
+    });
+
+    let button = tester.find::<Button>().expect("button should exist");
+    let disabled = tester.find::<Disabled>().expect("disabled should exist");
+
+    assert!(tester.get(button).cx.is_disabled());
+
+    tester.click_widget(button);
+    tester.focus(button);
+    tester.key(Key::Named(NamedKey::Enter));
+    assert_eq!(clicks.get(), 0);
+    assert!(!tester.get(button).cx.is_focused());
+
+    {
+        let mut widget = tester
+            .get_widget_mut(disabled)
+            .expect("disabled should exist");
+        Disabled::set_disabled(&mut widget, false);
+    }
+
+    tester.pump();
+
+    assert!(!tester.get(button).cx.is_disabled());
+
+    tester.click_widget(button);
+    tester.settle();
+    assert_eq!(clicks.get(), 1);
+}
+
+#[test]
+fn entry_filters_and_blocks_invalid_submit() {
+    let submits = Rc::new(Cell::new(0));
+
+    let mut tester = Tester::new(|world| {
+        let mut entry = Entry::new(world, paragraph(""));
+
+        Entry::set_filter(&mut entry, |text| {
+            text.chars().all(|c| c.is_ascii_digit())
+        });
+        Entry::set_validator(&mut entry, |text| text.len() == 4);
+        Entry::set_on_submit(&mut entry, {
+            let submits = submits.clone();
+            move |_| submits.set(submits.get() + 1)
+        });
+
+        entry.id()
+    });
+
+    let text_area = tester
+        .find::<TextArea<true>>()
+        .expect("text area should exist");
+    tester.focus(text_area);
+
+    tester.type_text("1a2b");
+    assert_eq!(
+        tester.get(text_area).widget.text(),
+        "12"
+    );
+    assert!(!tester.get(text_area).widget.is_valid());
+
+    tester.key(Key::Named(NamedKey::Enter));
+    assert_eq!(submits.get(), 0);
+
+    tester.type_text("34");
+    assert!(tester.get(text_area).widget.is_valid());
+
+    tester.key(Key::Named(NamedKey::Enter));
+    assert_eq!(submits.get(), 1);
+}
+
+#[test]
+fn entry_limits_length_and_clears() {
+    let mut tester = Tester::new(|world| {
+        let mut entry = Entry::new(world, paragraph(""));
+        Entry::set_max_length(&mut entry, Some(3));
+        Entry::set_clear_button(&mut entry, true);
+        entry.id()
+    });
+
+    let entry = tester.find::<Entry>().expect("entry should exist");
+    let text_area = tester
+        .find::<TextArea<true>>()
+        .expect("text area should exist");
+    tester.focus(text_area);
+
+    tester.type_text("abcd");
+    assert_eq!(
+        tester.get(text_area).widget.text(),
+        "abc"
+    );
+
+    // the clear button is the last thing inside the padding and border
+    let rect = tester.rect(entry);
+    tester.click(Point::new(
+        rect.right() - 18.0,
+        rect.center().y,
+    ));
+    assert_eq!(tester.get(text_area).widget.text(), "");
+}
+
+#[test]
+fn stack_mirrors_in_rtl_layout() {
+    let mut tester = Tester::new(|world| {
+        let first = Label::new(world, paragraph("first")).id();
+        let second = Label::new(world, paragraph("second")).id();
+
+        let stack = {
+            let mut stack = Stack::new(world);
+            Stack::set_axis(&mut stack, Axis::Horizontal);
+            stack.id()
+        };
+
+        world.add_child(stack, first);
+        world.add_child(stack, second);
+
+        stack
+    });
+
+    let first = tester.find_text("first").expect("label should exist");
+    let second = tester.find_text("second").expect("label should exist");
+    assert!(tester.rect(first).center().x < tester.rect(second).center().x);
+
+    tester.world.layout_direction_changed(LayoutDirection::Rtl);
+    tester.pump();
+    assert!(tester.rect(first).center().x > tester.rect(second).center().x);
+
+    let stack = tester.find::<Stack>().expect("stack should exist");
+
+    {
+        let mut stack = tester.get_widget_mut(stack).expect("stack should exist");
+        stack.set_layout_direction(Some(LayoutDirection::Ltr));
+    }
+
+    tester.pump();
+    assert!(tester.rect(first).center().x < tester.rect(second).center().x);
+}
@@ -0,0 +1,212 @@
+use ike_core::{Code, Key, Location, NamedKey};
+
+/// Convert a Linux evdev key code to a [`Key`], assuming a US layout.
+///
+/// Returns the key, and the text it produces if any.
+pub(crate) fn convert_evdev_key(code: u32, shift: bool) -> (Key, Option<String>) {
+    let named = match code {
+        1 => NamedKey::Escape,
+        14 => NamedKey::Backspace,
+        15 => NamedKey::Tab,
+        28 | 96 => NamedKey::Enter,
+        29 | 97 => NamedKey::Control,
+        42 | 54 => NamedKey::Shift,
+        56 => NamedKey::Alt,
+        100 => NamedKey::AltGraph,
+        58 => NamedKey::CapsLock,
+        69 => NamedKey::NumLock,
+        70 => NamedKey::ScrollLock,
+        59..=68 => f_key(code - 58),
+        87 => NamedKey::F11,
+        88 => NamedKey::F12,
+        102 => NamedKey::Home,
+        103 => NamedKey::ArrowUp,
+        104 => NamedKey::PageUp,
+        105 => NamedKey::ArrowLeft,
+        106 => NamedKey::ArrowRight,
+        107 => NamedKey::End,
+        108 => NamedKey::ArrowDown,
+        109 => NamedKey::PageDown,
+        110 => NamedKey::Insert,
+        111 => NamedKey::Delete,
+        119 => NamedKey::Pause,
+        125 | 126 => NamedKey::Meta,
+        127 => NamedKey::ContextMenu,
+
+        _ => {
+            return match character(code, shift) {
+                Some(c) => (
+                    Key::Character(c.to_string()),
+                    Some(c.to_string()),
+                ),
+                None => (Key::Named(NamedKey::Unidentified), None),
+            };
+        }
+    };
+
+    let text = match named {
+        NamedKey::Enter => Some(String::from("\n")),
+        NamedKey::Tab => Some(String::from("\t")),
+        _ => None,
+    };
+
+    (Key::Named(named), text)
+}
+
+/// Convert a Linux evdev key code to the physical [`Code`] and [`Location`] of the key.
+pub(crate) fn convert_evdev_code(code: u32) -> (Code, Location) {
+    let location = match code {
+        29 | 42 | 56 | 125 => Location::Left,
+        54 | 97 | 100 | 126 => Location::Right,
+        55 | 71..=83 | 96 | 98 => Location::Numpad,
+        _ => Location::Standard,
+    };
+
+    let code = match code {
+        1 => Code::Escape,
+        2 => Code::Digit1,
+        3 => Code::Digit2,
+        4 => Code::Digit3,
+        5 => Code::Digit4,
+        6 => Code::Digit5,
+        7 => Code::Digit6,
+        8 => Code::Digit7,
+        9 => Code::Digit8,
+        10 => Code::Digit9,
+        11 => Code::Digit0,
+        12 => Code::Minus,
+        13 => Code::Equal,
+        14 => Code::Backspace,
+        15 => Code::Tab,
+        16 => Code::KeyQ,
+        17 => Code::KeyW,
+        18 => Code::KeyE,
+        19 => Code::KeyR,
+        20 => Code::KeyT,
+        21 => Code::KeyY,
+        22 => Code::KeyU,
+        23 => Code::KeyI,
+        24 => Code::KeyO,
+        25 => Code::KeyP,
+        26 => Code::BracketLeft,
+        27 => Code::BracketRight,
+        28 => Code::Enter,
+        29 => Code::ControlLeft,
+        30 => Code::KeyA,
+        31 => Code::KeyS,
+        32 => Code::KeyD,
+        33 => Code::KeyF,
+        34 => Code::KeyG,
+        35 => Code::KeyH,
+        36 => Code::KeyJ,
+        37 => Code::KeyK,
+        38 => Code::KeyL,
+        39 => Code::Semicolon,
+        40 => Code::Quote,
+        41 => Code::Backquote,
+        42 => Code::ShiftLeft,
+        43 => Code::Backslash,
+        44 => Code::KeyZ,
+        45 => Code::KeyX,
+        46 => Code::KeyC,
+        47 => Code::KeyV,
+        48 => Code::KeyB,
+        49 => Code::KeyN,
+        50 => Code::KeyM,
+        51 => Code::Comma,
+        52 => Code::Period,
+        53 => Code::Slash,
+        54 => Code::ShiftRight,
+        55 => Code::NumpadMultiply,
+        56 => Code::AltLeft,
+        57 => Code::Space,
+        58 => Code::CapsLock,
+        59 => Code::F1,
+        60 => Code::F2,
+        61 => Code::F3,
+        62 => Code::F4,
+        63 => Code::F5,
+        64 => Code::F6,
+        65 => Code::F7,
+        66 => Code::F8,
+        67 => Code::F9,
+        68 => Code::F10,
+        69 => Code::NumLock,
+        70 => Code::ScrollLock,
+        71 => Code::Numpad7,
+        72 => Code::Numpad8,
+        73 => Code::Numpad9,
+        74 => Code::NumpadSubtract,
+        75 => Code::Numpad4,
+        76 => Code::Numpad5,
+        77 => Code::Numpad6,
+        78 => Code::NumpadAdd,
+        79 => Code::Numpad1,
+        80 => Code::Numpad2,
+        81 => Code::Numpad3,
+        82 => Code::Numpad0,
+        83 => Code::NumpadDecimal,
+        86 => Code::IntlBackslash,
+        87 => Code::F11,
+        88 => Code::F12,
+        96 => Code::NumpadEnter,
+        97 => Code::ControlRight,
+        98 => Code::NumpadDivide,
+        99 => Code::PrintScreen,
+        100 => Code::AltRight,
+        102 => Code::Home,
+        103 => Code::ArrowUp,
+        104 => Code::PageUp,
+        105 => Code::ArrowLeft,
+        106 => Code::ArrowRight,
+        107 => Code::End,
+        108 => Code::ArrowDown,
+        109 => Code::PageDown,
+        110 => Code::Insert,
+        111 => Code::Delete,
+        113 => Code::AudioVolumeMute,
+        114 => Code::AudioVolumeDown,
+        115 => Code::AudioVolumeUp,
+        119 => Code::Pause,
+        125 => Code::MetaLeft,
+        126 => Code::MetaRight,
+        127 => Code::ContextMenu,
+        _ => Code::Unidentified,
+    };
+
+    (code, location)
+}
+
+fn f_key(n: u32) -> NamedKey {
+    match n {
+        1 => NamedKey::F1,
+        2 => NamedKey::F2,
+        3 => NamedKey::F3,
+        4 => NamedKey::F4,
+        5 => NamedKey::F5,
+        6 => NamedKey::F6,
+        7 => NamedKey::F7,
+        8 => NamedKey::F8,
+        9 => NamedKey::F9,
+        _ => NamedKey::F10,
+    }
+}
+
+fn character(code: u32, shift: bool) -> Option<char> {
+    const ROWS: [(u32, &str, &str); 4] = [
+        (2, "1234567890-=", "!@#$%^&*()_+"),
+        (16, "qwertyuiop[]", "QWERTYUIOP{}"),
+        (30, "asdfghjkl;'`", "ASDFGHJKL:\"~"),
+        (43, "\\zxcvbnm,./", "|ZXCVBNM<>?"),
+    ];
+
+    if code == 57 {
+        return Some(' ');
+    }
+
+    ROWS.iter().find_map(|&(start, lower, upper)| {
+        let index = code.checked_sub(start)? as usize;
+        let row = if shift { upper } else { lower };
+        row.chars().nth(index)
+    })
+}
@@ -0,0 +1,296 @@
+//! A backend rendering fullscreen directly to a display, without a display server.
+//!
+//! Frames are presented with Vulkan through `VK_KHR_display`, and input is read with libinput,
+//! making it suitable for kiosks and embedded devices. The app is a single window covering the
+//! first connected display.
+//!
+//! The process needs access to the GPU and input devices, usually by being a member of the
+//! `video` and `input` groups, and no display server may be running on the same display.
+
+#![warn(clippy::unwrap_used)]
+
+use std::{
+    collections::HashMap,
+    env,
+    io::{self, Read, Write},
+    os::{
+        fd::{AsRawFd, RawFd},
+        unix::net::UnixStream,
+    },
+    sync::mpsc::{Receiver, channel},
+};
+
+use ike_core::{Point, Size, TouchId, WindowId, WindowUpdate};
+use ike_shell::{Driver, Shell, ShellEvent, ShellProxy};
+use ike_skia::{SkiaPainter, vulkan::Surface};
+use input::Libinput;
+
+mod key;
+mod seat;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("vulkan error: {0}")]
+    Vulkan(#[from] ike_skia::vulkan::Error),
+
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("failed assigning libinput seat `{0}`")]
+    Seat(String),
+}
+
+pub fn run<T>(
+    data: &mut T,
+    build: ike_ori::UiBuilder<T>,
+    settings: ike_core::Settings,
+) -> Result<(), Error> {
+    let rt;
+    let _rt_guard = if tokio::runtime::Handle::try_current().is_err() {
+        rt = Some(tokio::runtime::Runtime::new()?);
+        rt.as_ref().map(|rt| rt.enter())
+    } else {
+        None
+    };
+
+    ike_core::AnimationSettings::set(settings.animation.unwrap_or_default());
+
+    let runtime = tokio::runtime::Handle::current();
+    let mut vulkan = ike_skia::vulkan::Context::new_direct()?;
+    let surface = Surface::new_display(&mut vulkan, false)?;
+
+    let mut painter = SkiaPainter::new();
+    painter.load_font(
+        include_bytes!("../../fonts/InterVariable.ttf"),
+        None,
+    );
+
+    let seat = env::var("IKE_SEAT").unwrap_or_else(|_| String::from("seat0"));
+    let mut libinput = Libinput::new_with_udev(seat::Interface);
+
+    if libinput.udev_assign_seat(&seat).is_err() {
+        return Err(Error::Seat(seat));
+    }
+
+    // the event loop sleeps in `poll`, the proxy wakes it by writing to the socket
+    let (waker, wake) = UnixStream::pair()?;
+    waker.set_nonblocking(true)?;
+    wake.set_nonblocking(true)?;
+
+    let (sender, receiver) = channel();
+    let proxy = ShellProxy::new(move |event| {
+        let _ = sender.send(event);
+        let _ = (&waker).write(&[1]);
+    });
+
+    let scale_factor = env::var("IKE_SCALE_FACTOR")
+        .ok()
+        .and_then(|scale| scale.parse().ok())
+        .unwrap_or(1.0);
+
+    let mut event_loop = EventLoop {
+        shell: Shell::new(
+            data, build, settings, painter, proxy, runtime,
+        ),
+        receiver,
+        wake,
+
+        libinput,
+        surface,
+        scale_factor,
+
+        window: None,
+        needs_redraw: false,
+
+        seats: Vec::new(),
+        touches: HashMap::new(),
+    };
+
+    let direction = ike_shell::system_layout_direction();
+    event_loop.shell.world().layout_direction_changed(direction);
+    event_loop
+        .shell
+        .locales_changed(&ike_shell::system_locales());
+
+    event_loop.shell.build();
+    event_loop.run()
+}
+
+struct EventLoop<'a, T> {
+    shell:    Shell<'a, T>,
+    receiver: Receiver<ShellEvent>,
+    wake:     UnixStream,
+
+    libinput:     Libinput,
+    surface:      Surface,
+    scale_factor: f32,
+
+    window:       Option<WindowId>,
+    needs_redraw: bool,
+
+    seats:   Vec<seat::InputSeat>,
+    touches: HashMap<u32, Point>,
+}
+
+impl<T> EventLoop<'_, T> {
+    fn run(&mut self) -> Result<(), Error> {
+        loop {
+            self.handle_events();
+
+            if self.needs_redraw {
+                self.redraw();
+            }
+
+            // don't sleep when a frame is pending, presenting throttles the loop instead
+            let timeout = match self.needs_redraw {
+                true => 0,
+                false => -1,
+            };
+
+            self.poll(timeout)?;
+            self.handle_input()?;
+        }
+    }
+
+    fn handle_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            if let Err(error) = self.handle_event(event) {
+                tracing::error!("{error}");
+            }
+        }
+    }
+
+    fn poll(&mut self, timeout: i32) -> Result<(), Error> {
+        let mut fds = [
+            poll_fd(self.libinput.as_raw_fd()),
+            poll_fd(self.wake.as_raw_fd()),
+        ];
+
+        let result = unsafe {
+            libc::poll(
+                fds.as_mut_ptr(),
+                fds.len() as _,
+                timeout,
+            )
+        };
+
+        if result < 0 {
+            let error = io::Error::last_os_error();
+
+            if error.kind() != io::ErrorKind::Interrupted {
+                return Err(error.into());
+            }
+        }
+
+        // drain the wake ups, the events themselves are in the channel
+        let mut buffer = [0; 64];
+        while matches!((&self.wake).read(&mut buffer), Ok(n) if n > 0) {}
+
+        Ok(())
+    }
+
+    fn redraw(&mut self) {
+        let Some(window) = self.window else {
+            return;
+        };
+
+        self.needs_redraw = false;
+
+        if self.shell.animate(window) {
+            self.handle_events();
+        }
+
+        let context = &mut self.shell.context;
+
+        let Some(desc) = context.world.get_window(window) else {
+            return;
+        };
+
+        let result = self.surface.draw(
+            &mut self.shell.painter,
+            desc.color().fade(desc.opacity()),
+            self.scale_factor,
+            || {},
+            |canvas| context.world.draw(window, canvas),
+        );
+
+        if result.is_err() {
+            tracing::error!("drawing failed");
+        }
+
+        self.shell.painter.cleanup();
+    }
+
+    fn logical_size(&self) -> Size {
+        let (width, height) = self.surface.size();
+
+        Size::new(
+            width as f32 / self.scale_factor,
+            height as f32 / self.scale_factor,
+        )
+    }
+
+    fn touch_id(slot: u32) -> TouchId {
+        TouchId::from_u64(slot as u64)
+    }
+}
+
+fn poll_fd(fd: RawFd) -> libc::pollfd {
+    libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    }
+}
+
+impl<'a, T> Driver<'a, T> for EventLoop<'a, T> {
+    type Error = Error;
+
+    fn shell(&mut self) -> &mut Shell<'a, T> {
+        &mut self.shell
+    }
+
+    fn request_redraw(&mut self, window: WindowId) {
+        if self.window == Some(window) {
+            self.needs_redraw = true;
+        }
+    }
+
+    fn create_window(&mut self, window: WindowId) -> Result<(), Error> {
+        if self.window.is_some() {
+            tracing::error!("the kms backend only supports one window!");
+            return Ok(());
+        }
+
+        let size = self.logical_size();
+        let world = self.shell.world();
+
+        world.window_scaled(window, size, self.scale_factor);
+        world.window_focused(window, true);
+
+        self.window = Some(window);
+        self.needs_redraw = true;
+
+        Ok(())
+    }
+
+    fn remove_window(&mut self, window: WindowId) {
+        if self.window == Some(window) {
+            self.window = None;
+
+            for seat in &mut self.seats {
+                seat.leave_window();
+            }
+        }
+    }
+
+    // the window always covers the display, and has no decorations or cursor
+    fn update_window(&mut self, window: WindowId, update: WindowUpdate) {
+        if self.window == Some(window)
+            && let WindowUpdate::Presentation(presentation) = update
+            && let Err(err) = self.surface.set_presentation(presentation)
+        {
+            tracing::error!("failed setting presentation: {err}");
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    fs::{File, OpenOptions},
+    os::{fd::OwnedFd, unix::fs::OpenOptionsExt},
+    path::Path,
+};
+
+use ike_core::{
+    Key, KeyInput, Modifiers, NamedKey, Offset, Point, PointerButton, PointerId, ScrollDelta,
+    SeatId, Size, Tool, WindowId,
+};
+use input::{
+    LibinputInterface,
+    event::{
+        Event, EventTrait,
+        keyboard::{KeyState, KeyboardEvent, KeyboardEventTrait},
+        pointer::{Axis, ButtonState, PointerEvent, PointerScrollEvent},
+        touch::{TouchEvent, TouchEventPosition, TouchEventSlot},
+    },
+};
+
+use crate::{Error, EventLoop, key};
+
+/// Opens input devices for libinput, the process needs permission to read them.
+pub(crate) struct Interface;
+
+impl LibinputInterface for Interface {
+    fn open_restricted(&mut self, path: &Path, flags: i32) -> Result<OwnedFd, i32> {
+        let access = flags & libc::O_ACCMODE;
+
+        OpenOptions::new()
+            .custom_flags(flags)
+            .read(access == libc::O_RDONLY || access == libc::O_RDWR)
+            .write(access == libc::O_WRONLY || access == libc::O_RDWR)
+            .open(path)
+            .map(OwnedFd::from)
+            .map_err(|err| err.raw_os_error().unwrap_or(libc::EIO))
+    }
+
+    fn close_restricted(&mut self, fd: OwnedFd) {
+        drop(File::from(fd));
+    }
+}
+
+/// The name libinput gives devices without a `WL_SEAT` udev property.
+const DEFAULT_SEAT: &str = "default";
+
+/// A logical seat of libinput, devices are assigned to one with the `WL_SEAT` udev property.
+pub(crate) struct InputSeat {
+    name:      String,
+    id:        SeatId,
+    modifiers: Modifiers,
+
+    // there is only one mouse per seat, libinput merges the pointer devices of a seat
+    pointer:         PointerId,
+    position:        Point,
+    pointer_entered: bool,
+}
+
+impl InputSeat {
+    fn new(name: &str) -> Self {
+        let id = match name {
+            DEFAULT_SEAT => SeatId::PRIMARY,
+            name => SeatId::from_hash(name),
+        };
+
+        Self {
+            name: name.to_owned(),
+            id,
+            modifiers: Modifiers::empty(),
+
+            pointer: PointerId::from_hash(name),
+            position: Point::ORIGIN,
+            pointer_entered: false,
+        }
+    }
+
+    pub(crate) fn leave_window(&mut self) {
+        self.pointer_entered = false;
+    }
+}
+
+// one line of the scroll wheel in the `v120` units of libinput
+const WHEEL_LINE: f64 = 120.0;
+
+impl<T> EventLoop<'_, T> {
+    pub(crate) fn handle_input(&mut self) -> Result<(), Error> {
+        self.libinput.dispatch()?;
+
+        while let Some(event) = self.libinput.next() {
+            let Some(window) = self.window else {
+                continue;
+            };
+
+            let index = self.input_seat(event.device().seat().logical_name());
+            let size = self.logical_size();
+
+            if matches!(event, Event::Pointer(..)) {
+                self.enter_pointer(window, index);
+            }
+
+            let seat = &mut self.seats[index];
+
+            match event {
+                Event::Keyboard(KeyboardEvent::Key(event)) => {
+                    let pressed = event.key_state() == KeyState::Pressed;
+                    let shift = seat.modifiers.shift();
+                    let (key, text) = key::convert_evdev_key(event.key(), shift);
+                    let (code, location) = key::convert_evdev_code(event.key());
+
+                    let modifier = match key {
+                        Key::Named(NamedKey::Shift) => Modifiers::SHIFT,
+                        Key::Named(NamedKey::Control) => Modifiers::CONTROL,
+                        Key::Named(NamedKey::Alt) => Modifiers::ALT,
+                        Key::Named(NamedKey::Meta) => Modifiers::META,
+                        _ => Modifiers::empty(),
+                    };
+
+                    if !modifier.is_empty() {
+                        seat.modifiers.set(modifier, pressed);
+                        (self.shell.world()).seat_modifiers_changed(
+                            window,
+                            seat.id,
+                            seat.modifiers,
+                        );
+                    }
+
+                    // text isn't produced while a shortcut is being pressed
+                    let text = text.filter(|_| {
+                        !seat.modifiers.ctrl() && !seat.modifiers.alt() && !seat.modifiers.meta()
+                    });
+
+                    let input = KeyInput {
+                        key,
+                        code,
+                        location,
+                        text,
+                        repeat: false,
+                    };
+
+                    (self.shell.world()).seat_key_pressed(window, seat.id, input, pressed);
+                }
+
+                Event::Pointer(PointerEvent::Motion(event)) => {
+                    let delta = Offset::new(event.dx() as f32, event.dy() as f32);
+
+                    let mut position = seat.position + delta / self.scale_factor;
+                    position.x = position.x.clamp(0.0, size.width);
+                    position.y = position.y.clamp(0.0, size.height);
+                    seat.position = position;
+
+                    (self.shell.world()).pointer_moved(
+                        window,
+                        seat.pointer,
+                        position,
+                        Tool::MOUSE,
+                    );
+                }
+
+                Event::Pointer(PointerEvent::MotionAbsolute(event)) => {
+                    seat.position = Point::new(
+                        event.absolute_x_transformed(size.width as u32) as f32,
+                        event.absolute_y_transformed(size.height as u32) as f32,
+                    );
+
+                    (self.shell.world()).pointer_moved(
+                        window,
+                        seat.pointer,
+                        seat.position,
+                        Tool::MOUSE,
+                    );
+                }
+
+                Event::Pointer(PointerEvent::Button(event)) => {
+                    let pressed = event.button_state() == ButtonState::Pressed;
+
+                    // the evdev codes of the mouse buttons
+                    let button = match event.button() {
+                        0x110 => PointerButton::Primary,
+                        0x111 => PointerButton::Secondary,
+                        0x112 => PointerButton::Tertiary,
+                        0x113 => PointerButton::Backward,
+                        0x114 => PointerButton::Forward,
+                        button => PointerButton::Other(button as u16),
+                    };
+
+                    (self.shell.world()).pointer_pressed(window, seat.pointer, button, pressed);
+                }
+
+                Event::Pointer(PointerEvent::ScrollWheel(event)) => {
+                    let axis = |axis| match event.has_axis(axis) {
+                        true => (event.scroll_value_v120(axis) / WHEEL_LINE) as f32,
+                        false => 0.0,
+                    };
+
+                    // libinput scrolls down with positive values, ike the other way around
+                    let delta = Offset::new(
+                        -axis(Axis::Horizontal),
+                        -axis(Axis::Vertical),
+                    );
+
+                    let delta = ScrollDelta::Line(delta);
+                    (self.shell.world()).pointer_scrolled(window, seat.pointer, delta);
+                }
+
+                Event::Pointer(PointerEvent::ScrollFinger(event)) => {
+                    let axis = |axis| match event.has_axis(axis) {
+                        true => event.scroll_value(axis) as f32,
+                        false => 0.0,
+                    };
+
+                    let delta = Offset::new(
+                        -axis(Axis::Horizontal),
+                        -axis(Axis::Vertical),
+                    );
+
+                    let delta = ScrollDelta::Pixel(delta / self.scale_factor);
+                    (self.shell.world()).pointer_scrolled(window, seat.pointer, delta);
+                }
+
+                Event::Touch(TouchEvent::Down(event)) => {
+                    let point = Self::touch_point(size, &event);
+                    let slot = event.seat_slot();
+                    self.touches.insert(slot, point);
+
+                    let touch = Self::touch_id(slot);
+                    (self.shell.world()).touch_down(window, touch, point, Tool::FINGER);
+                }
+
+                Event::Touch(TouchEvent::Motion(event)) => {
+                    let point = Self::touch_point(size, &event);
+                    let slot = event.seat_slot();
+                    self.touches.insert(slot, point);
+
+                    let touch = Self::touch_id(slot);
+                    (self.shell.world()).touch_move(window, touch, point, Tool::FINGER);
+                }
+
+                // the touch is lifted where it last moved to
+                Event::Touch(TouchEvent::Up(event)) => {
+                    let slot = event.seat_slot();
+
+                    if let Some(point) = self.touches.remove(&slot) {
+                        let touch = Self::touch_id(slot);
+                        (self.shell.world()).touch_up(window, touch, point, Tool::FINGER);
+                    }
+                }
+
+                Event::Touch(TouchEvent::Cancel(event)) => {
+                    let slot = event.seat_slot();
+
+                    if let Some(point) = self.touches.remove(&slot) {
+                        let touch = Self::touch_id(slot);
+                        (self.shell.world()).touch_up(window, touch, point, Tool::FINGER);
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        self.handle_events();
+
+        Ok(())
+    }
+
+    /// Find the seat named `name`, adding it the first time one of its devices sends an event.
+    fn input_seat(&mut self, name: &str) -> usize {
+        match self.seats.iter().position(|seat| seat.name == name) {
+            Some(index) => index,
+            None => {
+                self.seats.push(InputSeat::new(name));
+                self.seats.len() - 1
+            }
+        }
+    }
+
+    fn enter_pointer(&mut self, window: WindowId, index: usize) {
+        let seat = &mut self.seats[index];
+
+        if !seat.pointer_entered {
+            seat.pointer_entered = true;
+
+            let world = self.shell.world();
+            world.seat_pointer_entered(window, seat.id, seat.pointer);
+        }
+    }
+
+    fn touch_point(size: Size, event: &impl TouchEventPosition) -> Point {
+        Point::new(
+            event.x_transformed(size.width as u32) as f32,
+            event.y_transformed(size.height as u32) as f32,
+        )
+    }
+}
@@ -16,10 +16,10 @@ pub fn main(
             #[cfg(target_os = "android")]
             extern "C" fn ANativeActivity_onCreate(
                 activity: *mut std::ffi::c_void,
-                _saved_state: *mut std::ffi::c_void,
-                _saved_state_size: usize,
+                saved_state: *mut std::ffi::c_void,
+                saved_state_size: usize,
             ) {
-                ike::android_main(activity, #ident);
+                ike::android_main(activity, saved_state, saved_state_size, #ident);
             }
         };
     };
@@ -0,0 +1,64 @@
+use std::{
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll, Waker},
+};
+
+/// A flag cancelling the [`Cancellable`] futures it's shared with.
+#[derive(Default)]
+pub(crate) struct Cancel {
+    cancelled: AtomicBool,
+    waker:     Mutex<Option<Waker>>,
+}
+
+impl Cancel {
+    pub(crate) fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+
+        let waker = self
+            .waker
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take();
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// A future resolving to `None` once cancelled, dropping the inner future.
+pub(crate) struct Cancellable<F> {
+    future: Pin<Box<F>>,
+    cancel: Arc<Cancel>,
+}
+
+impl<F> Cancellable<F> {
+    pub(crate) fn new(future: F, cancel: Arc<Cancel>) -> Self {
+        Self {
+            future: Box::pin(future),
+            cancel,
+        }
+    }
+}
+
+impl<F: Future> Future for Cancellable<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        *self
+            .cancel
+            .waker
+            .lock()
+            .unwrap_or_else(|err| err.into_inner()) = Some(cx.waker().clone());
+
+        if self.cancel.cancelled.load(Ordering::Acquire) {
+            return Poll::Ready(None);
+        }
+
+        self.future.as_mut().poll(cx).map(Some)
+    }
+}
@@ -1,14 +1,151 @@
-use std::{any::Any, mem, sync::Arc};
+use std::{any::Any, fs, io, mem, path::Path, sync::Arc};
 
-use ike_core::{AnyWidgetId, Builder, Widget, WidgetId, World};
+use ike_core::{AnyWidgetId, Builder, FileDialog, Widget, WidgetId, World};
 use ori::{BaseElement, Element, Provider, Proxied, Proxy, Super};
 
-use crate::Resources;
+use crate::{
+    PickFiles, Resources, TaskHandle,
+    export::ExportSvg,
+    l10n::{Locale, Locales, Localization, Translator, Value},
+};
 
 pub struct Context {
     pub world:     World,
     pub proxy:     Arc<dyn Proxy>,
     pub resources: Resources,
+    pub locales:   Locales,
+}
+
+impl Context {
+    /// Export `widget` as an SVG document, at the size it was last laid out with.
+    ///
+    /// This returns an [`ExportSvg`] future rather than the `String` itself, since the document
+    /// is rendered by the backend. The future resolves to the document once it's done, and can
+    /// be spawned with the [`Proxy`] like [`Context::pick_files`].
+    pub fn export_svg(&mut self, widget: impl AnyWidgetId) -> ExportSvg {
+        let (future, respond) = ExportSvg::new();
+        self.render_svg(widget, respond);
+        future
+    }
+
+    /// Load the font in the file at `path`, see [`Builder::load_font`].
+    pub fn load_font_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        alias: Option<&str>,
+    ) -> io::Result<()> {
+        let bytes = fs::read(path)?;
+        self.load_font(bytes, alias.map(String::from));
+        Ok(())
+    }
+
+    /// Show the platform file dialog, resolving to the picked paths once it's closed.
+    ///
+    /// The returned future can be spawned with the [`Proxy`], and its result sent back to the
+    /// view as an event. On Android the paths are `content://` URIs from the Storage Access
+    /// Framework, which must be opened through the content resolver.
+    pub fn pick_files(&mut self, dialog: FileDialog) -> PickFiles {
+        let (future, respond) = PickFiles::new();
+        self.show_file_dialog(dialog, respond);
+        future
+    }
+
+    /// Spawn `future` with the [`Proxy`], returning a handle that cancels it when dropped.
+    ///
+    /// Unlike futures spawned with the proxy directly, which run to completion even once the
+    /// view that spawned them is gone, the handle is kept in the state of the view, and the
+    /// future is cancelled when the view is removed.
+    pub fn spawn_scoped(
+        &mut self,
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> TaskHandle {
+        let (handle, future) = TaskHandle::new(future);
+        self.proxy.spawn_boxed(Box::pin(future));
+        handle
+    }
+
+    /// The locale text is translated to, negotiated with the [`Localization`] provided by
+    /// [`localized`](crate::l10n::localized) if there is one.
+    pub fn locale(&self) -> Locale {
+        match self.get::<Translator>() {
+            Some(translator) => translator.locale.clone(),
+            None => self.locales.negotiate(None),
+        }
+    }
+
+    /// The [`Localization`] provided by [`localized`](crate::l10n::localized), if any.
+    pub fn localization(&self) -> Option<&Arc<Localization>> {
+        Some(&self.get::<Translator>()?.localization)
+    }
+
+    /// Translate the message `key` to the current [`locale`](Context::locale), with
+    /// `arguments`.
+    ///
+    /// Returns `key` itself if no catalog has the message, or no [`Localization`] is provided.
+    pub fn translate(&self, key: &str, arguments: &[(&str, Value)]) -> String {
+        let locale = self.locale();
+
+        let message = (self.localization())
+            .and_then(|localization| localization.format(&locale, key, arguments));
+
+        match message {
+            Some(message) => message,
+            None => {
+                tracing::debug!("message `{key}` not found for locale `{locale}`");
+                key.into()
+            }
+        }
+    }
+
+    /// Prefer `locale` over the locales of the platform, or follow the platform with `None`,
+    /// rebuilding the view if it changed.
+    pub fn set_locale(&mut self, locale: Option<Locale>) {
+        if self.locales.preferred != locale {
+            self.locales.preferred = locale;
+            self.proxy.rebuild();
+        }
+    }
+
+    /// Set the locales preferred by the platform, most preferred first, returning whether
+    /// they changed, in which case the view must be rebuilt.
+    pub fn system_locales_changed(&mut self, locales: Vec<Locale>) -> bool {
+        let changed = self.locales.system != locales;
+        self.locales.system = locales;
+        changed
+    }
+}
+
+#[cfg(feature = "persist")]
+impl Context {
+    /// The [`Preferences`](crate::persist::Preferences) provided by
+    /// [`persisted`](crate::persist::persisted), if any.
+    pub fn preferences(&self) -> Option<&crate::persist::Preferences> {
+        self.get()
+    }
+
+    /// Get the value of the preference `key` from the provided
+    /// [`Preferences`](crate::persist::Preferences), `None` if it isn't set or none are
+    /// provided.
+    pub fn preference<T: serde::de::DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.preferences()?.get(key)
+    }
+
+    /// Set the value of the preference `key` in the provided
+    /// [`Preferences`](crate::persist::Preferences), which are persisted in the background.
+    pub fn set_preference<T: serde::Serialize>(&mut self, key: &str, value: T) {
+        match self.preferences() {
+            Some(preferences) => preferences.set(&*self.proxy, key, value),
+            None => tracing::warn!("no preferences provided, `{key}` won't be persisted"),
+        }
+    }
+
+    /// Remove the preference `key` from the provided
+    /// [`Preferences`](crate::persist::Preferences).
+    pub fn remove_preference(&mut self, key: &str) {
+        if let Some(preferences) = self.preferences() {
+            preferences.remove(&*self.proxy, key);
+        }
+    }
 }
 
 impl Builder for Context {
@@ -0,0 +1,36 @@
+use std::{
+    path::PathBuf,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::oneshot;
+
+/// The paths picked in a file dialog, see [`Context::pick_files`](crate::Context::pick_files).
+///
+/// Resolves to no paths if the dialog was cancelled, or isn't supported by the platform.
+pub struct PickFiles {
+    receiver: oneshot::Receiver<Vec<PathBuf>>,
+}
+
+impl PickFiles {
+    pub(crate) fn new() -> (
+        Self,
+        impl FnOnce(Vec<PathBuf>) + Send + 'static,
+    ) {
+        let (sender, receiver) = oneshot::channel();
+        (Self { receiver }, move |paths| {
+            sender.send(paths)
+        })
+    }
+}
+
+impl Future for PickFiles {
+    type Output = Vec<PathBuf>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(Option::unwrap_or_default)
+    }
+}
@@ -0,0 +1,111 @@
+//! Helpers for exporting tabular data and widgets.
+
+use std::{
+    fmt::Write,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::oneshot;
+
+/// An SVG document being rendered, see [`Context::export_svg`](crate::Context::export_svg).
+///
+/// Resolves to an empty document if the backend dropped the request.
+pub struct ExportSvg {
+    receiver: oneshot::Receiver<String>,
+}
+
+impl ExportSvg {
+    pub(crate) fn new() -> (
+        Self,
+        impl FnOnce(String) + Send + 'static,
+    ) {
+        let (sender, receiver) = oneshot::channel();
+        (Self { receiver }, move |svg| {
+            sender.send(svg)
+        })
+    }
+}
+
+impl Future for ExportSvg {
+    type Output = String;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(Option::unwrap_or_default)
+    }
+}
+
+/// Format `rows` as tab separated values, suitable for pasting into spreadsheets.
+///
+/// `cell` formats the cell of a row at a column index.
+pub fn to_tsv<R>(
+    rows: impl IntoIterator<Item = R>,
+    columns: usize,
+    mut cell: impl FnMut(&R, usize) -> String,
+) -> String {
+    let mut output = String::new();
+
+    for row in rows {
+        for column in 0..columns {
+            if column > 0 {
+                output.push('\t');
+            }
+
+            // tabs and newlines would break the structure of the table
+            let text = cell(&row, column).replace(['\t', '\n', '\r'], " ");
+            output.push_str(&text);
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Format `rows` as comma separated values, with an optional row of `headers`.
+///
+/// `cell` formats the cell of a row at a column index.
+pub fn to_csv<R>(
+    headers: Option<&[&str]>,
+    rows: impl IntoIterator<Item = R>,
+    columns: usize,
+    mut cell: impl FnMut(&R, usize) -> String,
+) -> String {
+    let mut output = String::new();
+
+    if let Some(headers) = headers {
+        write_csv_row(&mut output, headers.iter().copied());
+    }
+
+    for row in rows {
+        let cells: Vec<_> = (0..columns).map(|column| cell(&row, column)).collect();
+        write_csv_row(
+            &mut output,
+            cells.iter().map(String::as_str),
+        );
+    }
+
+    output
+}
+
+fn write_csv_row<'a>(output: &mut String, cells: impl Iterator<Item = &'a str>) {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            output.push(',');
+        }
+
+        if cell.contains([',', '"', '\n', '\r']) {
+            let _ = write!(
+                output,
+                "\"{}\"",
+                cell.replace('"', "\"\"")
+            );
+        } else {
+            output.push_str(cell);
+        }
+    }
+
+    output.push_str("\r\n");
+}
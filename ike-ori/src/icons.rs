@@ -0,0 +1,109 @@
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use ike_core::{Svg, widgets::IconGlyph};
+
+/// A source of icons looked up by their logical name, like `"close"` or `"search"`.
+///
+/// Icon packs are registered in [`Icons`], which is provided to views through the
+/// [`Theme`](crate::Theme).
+pub trait IconPack: Send + Sync {
+    fn icon(&self, name: &str) -> Option<IconGlyph>;
+}
+
+/// An [`IconPack`] of icons stored in a map.
+#[derive(Clone, Debug, Default)]
+pub struct IconSet {
+    icons: HashMap<String, IconGlyph>,
+}
+
+impl IconSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an svg icon from the bytes of an svg document.
+    pub fn svg(mut self, name: impl Into<String>, bytes: &[u8]) -> Self {
+        self.insert(name, Svg::from_bytes(bytes));
+        self
+    }
+
+    /// Add an icon drawn as `glyph` of the icon font `font_family`.
+    pub fn glyph(mut self, name: impl Into<String>, font_family: &str, glyph: char) -> Self {
+        self.insert(
+            name,
+            IconGlyph::Font {
+                font_family: font_family.into(),
+                glyph,
+            },
+        );
+        self
+    }
+
+    /// Insert an icon, replacing any previous icon with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, glyph: impl Into<IconGlyph>) {
+        self.icons.insert(name.into(), glyph.into());
+    }
+
+    pub fn len(&self) -> usize {
+        self.icons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.icons.is_empty()
+    }
+}
+
+impl IconPack for IconSet {
+    fn icon(&self, name: &str) -> Option<IconGlyph> {
+        self.icons.get(name).cloned()
+    }
+}
+
+/// The registry of [`IconPack`]s [`icon`](crate::views::icon)s are looked up in.
+///
+/// Packs added later take precedence, so an app can override single icons of a pack provided by
+/// a crate. Provide the registry with [`Theme::with`](crate::Theme::with).
+#[derive(Clone, Default)]
+pub struct Icons {
+    packs: Vec<Arc<dyn IconPack>>,
+}
+
+impl Icons {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an icon pack, taking precedence over the packs already added.
+    pub fn with(mut self, pack: impl IconPack + 'static) -> Self {
+        self.push(pack);
+        self
+    }
+
+    pub fn push(&mut self, pack: impl IconPack + 'static) {
+        self.packs.push(Arc::new(pack));
+    }
+
+    /// Look up the icon called `name` in the packs, last added first.
+    pub fn get(&self, name: &str) -> Option<IconGlyph> {
+        self.packs.iter().rev().find_map(|pack| pack.icon(name))
+    }
+}
+
+impl PartialEq for Icons {
+    fn eq(&self, other: &Self) -> bool {
+        self.packs.len() == other.packs.len()
+            && self
+                .packs
+                .iter()
+                .zip(&other.packs)
+                .all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+impl fmt::Debug for Icons {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Icons")
+            .field("packs", &self.packs.len())
+            .finish()
+    }
+}
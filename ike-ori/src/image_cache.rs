@@ -0,0 +1,249 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use ike_core::Svg;
+
+enum Entry {
+    Loading {
+        waiters: Vec<Box<dyn FnOnce() + Send>>,
+    },
+
+    Loaded {
+        svg:       Svg,
+        size:      usize,
+        last_used: u64,
+    },
+
+    Failed,
+}
+
+struct Inner {
+    entries:  HashMap<PathBuf, Entry>,
+    capacity: usize,
+    size:     usize,
+    tick:     u64,
+}
+
+impl Inner {
+    fn touch(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Evict the least recently used images until the cache fits in its capacity.
+    fn trim(&mut self) {
+        while self.size > self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .filter_map(|(path, entry)| match entry {
+                    Entry::Loaded { last_used, .. } => Some((path, *last_used)),
+                    _ => None,
+                })
+                .min_by_key(|(_, last_used)| *last_used)
+                .map(|(path, _)| path.clone());
+
+            let Some(oldest) = oldest else {
+                break;
+            };
+
+            self.remove(&oldest);
+        }
+    }
+
+    fn remove(&mut self, path: &Path) {
+        if let Some(Entry::Loaded { size, .. }) = self.entries.remove(path) {
+            self.size -= size;
+        }
+    }
+}
+
+/// A shared cache of images loaded from disk, bounded by the number of bytes it holds.
+///
+/// Images are loaded on a background thread, either ahead of time with
+/// [`ImageCache::prefetch`], or when first shown by a
+/// [`cached_picture`](crate::views::cached_picture). When the cache grows past its capacity the
+/// least recently used images are evicted, widgets already showing them keep them alive.
+#[derive(Clone)]
+pub struct ImageCache {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Default for ImageCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImageCache {
+    /// The capacity of [`ImageCache::new`], `64 MiB`.
+    pub const DEFAULT_CAPACITY: usize = 64 * 1024 * 1024;
+
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Create a cache holding at most `capacity` bytes of images.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let inner = Inner {
+            entries: HashMap::new(),
+            capacity,
+            size: 0,
+            tick: 0,
+        };
+
+        Self {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// Set the capacity in bytes, evicting images if the cache no longer fits.
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut inner = self.lock();
+        inner.capacity = capacity;
+        inner.trim();
+    }
+
+    /// The number of bytes of images currently held.
+    pub fn size(&self) -> usize {
+        self.lock().size
+    }
+
+    /// Get the image at `path` if it's loaded.
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<Svg> {
+        let mut inner = self.lock();
+        let tick = inner.touch();
+
+        match inner.entries.get_mut(path.as_ref()) {
+            Some(Entry::Loaded { svg, last_used, .. }) => {
+                *last_used = tick;
+                Some(svg.clone())
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Whether the image at `path` is loaded, or being loaded.
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        matches!(
+            self.lock().entries.get(path.as_ref()),
+            Some(Entry::Loading { .. } | Entry::Loaded { .. }),
+        )
+    }
+
+    /// Start loading the image at `path` in the background, if it isn't already.
+    pub fn prefetch(&self, path: impl Into<PathBuf>) {
+        self.load(path.into(), || {});
+    }
+
+    /// Get the image at `path`, or start loading it and call `on_load` once it's done.
+    ///
+    /// `on_load` is also called if loading the image fails, in which case [`ImageCache::get`]
+    /// keeps returning `None` until the image is evicted.
+    pub fn load(
+        &self,
+        path: impl Into<PathBuf>,
+        on_load: impl FnOnce() + Send + 'static,
+    ) -> Option<Svg> {
+        let path = path.into();
+        let mut inner = self.lock();
+        let tick = inner.touch();
+
+        match inner.entries.get_mut(&path) {
+            Some(Entry::Loaded { svg, last_used, .. }) => {
+                *last_used = tick;
+                return Some(svg.clone());
+            }
+
+            Some(Entry::Loading { waiters }) => {
+                waiters.push(Box::new(on_load));
+                return None;
+            }
+
+            Some(Entry::Failed) => return None,
+
+            None => {}
+        }
+
+        let waiters: Vec<Box<dyn FnOnce() + Send>> = vec![Box::new(on_load)];
+        inner
+            .entries
+            .insert(path.clone(), Entry::Loading { waiters });
+        drop(inner);
+
+        let cache = self.clone();
+        std::thread::spawn(move || {
+            let result = std::fs::read(&path);
+            cache.finish(path, result);
+        });
+
+        None
+    }
+
+    /// Evict the image at `path`, it will be loaded again the next time it's requested.
+    pub fn evict(&self, path: impl AsRef<Path>) {
+        let mut inner = self.lock();
+
+        if !matches!(
+            inner.entries.get(path.as_ref()),
+            Some(Entry::Loading { .. })
+        ) {
+            inner.remove(path.as_ref());
+        }
+    }
+
+    /// Evict every loaded image.
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        inner
+            .entries
+            .retain(|_, entry| matches!(entry, Entry::Loading { .. }));
+        inner.size = 0;
+    }
+
+    fn finish(&self, path: PathBuf, result: std::io::Result<Vec<u8>>) {
+        let mut inner = self.lock();
+        let tick = inner.touch();
+
+        let entry = match result {
+            Ok(bytes) => Entry::Loaded {
+                svg:       Svg::from_bytes(&bytes),
+                size:      bytes.len(),
+                last_used: tick,
+            },
+
+            Err(err) => {
+                tracing::warn!(?path, %err, "loading image failed");
+                Entry::Failed
+            }
+        };
+
+        if let Entry::Loaded { size, .. } = entry {
+            inner.size += size;
+        }
+
+        let waiters = match inner.entries.insert(path, entry) {
+            Some(Entry::Loading { waiters }) => waiters,
+            _ => Vec::new(),
+        };
+
+        inner.trim();
+        drop(inner);
+
+        for waiter in waiters {
+            waiter();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+use ike_core::LayoutDirection;
+
+/// A language, optionally specific to a region, e.g. `de` or `pt-BR`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// The lowercase ISO 639 code of the language, e.g. `pt`.
+    pub language: String,
+
+    /// The uppercase ISO 3166 code of the region, e.g. `BR`.
+    pub region: Option<String>,
+}
+
+impl Locale {
+    pub fn new(language: impl Into<String>) -> Self {
+        Self {
+            language: language.into().to_ascii_lowercase(),
+            region:   None,
+        }
+    }
+
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into().to_ascii_uppercase());
+        self
+    }
+
+    /// Parse a BCP 47 language tag like `pt-BR` or `zh-Hant-TW`, or a POSIX locale like
+    /// `pt_BR.UTF-8`.
+    ///
+    /// Scripts, variants, encodings and modifiers are ignored. Returns `None` if there is no
+    /// language, or it is the default `C` or `POSIX` locale.
+    pub fn parse(locale: &str) -> Option<Self> {
+        let locale = locale.split(['.', '@']).next().unwrap_or_default();
+        let mut subtags = locale.split(['-', '_']);
+
+        let language = subtags.next()?;
+
+        let is_language =
+            (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic());
+
+        if !is_language {
+            return None;
+        }
+
+        // the region is either two letters or three digits, after an optional script
+        let region = subtags.find(|subtag| {
+            (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        });
+
+        let locale = Locale::new(language);
+
+        Some(match region {
+            Some(region) => locale.with_region(region),
+            None => locale,
+        })
+    }
+
+    /// The locale without its region, e.g. `pt` for `pt-BR`.
+    pub fn language_only(&self) -> Self {
+        Self::new(self.language.clone())
+    }
+
+    /// The direction the language is written in.
+    pub fn direction(&self) -> LayoutDirection {
+        LayoutDirection::from_locale(&self.language)
+    }
+
+    /// Pick the locale of `available` that best matches the locales in `requested`, which are
+    /// ordered from most to least preferred.
+    ///
+    /// For each requested locale in turn, an exact match is preferred, then a match of the
+    /// language alone, then the same language in any other region. Returns `None` if no
+    /// requested language is available.
+    pub fn negotiate<'a>(requested: &[Locale], available: &'a [Locale]) -> Option<&'a Locale> {
+        requested.iter().find_map(|requested| {
+            let same_language = |locale: &&Locale| locale.language == requested.language;
+
+            (available.iter().find(|locale| *locale == requested))
+                .or_else(|| {
+                    let language = requested.language_only();
+                    available.iter().find(|locale| **locale == language)
+                })
+                .or_else(|| available.iter().find(same_language))
+        })
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.region {
+            Some(ref region) => write!(f, "{}-{}", self.language, region),
+            None => write!(f, "{}", self.language),
+        }
+    }
+}
+
+impl From<&str> for Locale {
+    /// Parse `locale` with [`Locale::parse`], falling back to `und`, the undetermined language.
+    fn from(locale: &str) -> Self {
+        Locale::parse(locale).unwrap_or_else(|| Locale::new("und"))
+    }
+}
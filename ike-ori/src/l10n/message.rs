@@ -0,0 +1,473 @@
+use std::{error::Error, fmt, iter::Peekable, str::Chars};
+
+use crate::l10n::{Locale, PluralCategory};
+
+/// The value of an argument of a [`Message`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Number(f64),
+    String(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{number}"),
+            Value::String(string) => write!(f, "{string}"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(number: $ty) -> Self {
+                    Value::Number(number as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64
+);
+
+impl From<&str> for Value {
+    fn from(string: &str) -> Self {
+        Value::String(string.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(string: String) -> Self {
+        Value::String(string)
+    }
+}
+
+impl From<&String> for Value {
+    fn from(string: &String) -> Self {
+        Value::String(string.clone())
+    }
+}
+
+/// An error parsing a [`Message`] or a [`Catalog`](crate::l10n::Catalog).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line:    usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A message in the ICU MessageFormat syntax.
+///
+/// Messages are text with arguments in braces, e.g. `Hello {name}!`. Arguments can select a
+/// variant of the message by their plural category with `plural`, where `#` is replaced by the
+/// number, and exact numbers can be matched with `=`.
+///
+/// ```text
+/// {count, plural, =0 {No messages} one {# message} other {# messages}}
+/// ```
+///
+/// Or by their value with `select`, e.g. for grammatical gender.
+///
+/// ```text
+/// {gender, select, female {She replied} male {He replied} other {They replied}}
+/// ```
+///
+/// Literal braces are quoted with apostrophes, `'{'`, and a literal apostrophe is written as
+/// `''`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    parts: Vec<Part>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Part {
+    Text(String),
+    Argument(String),
+
+    /// `#` in a plural variant.
+    Number,
+
+    Plural {
+        argument: String,
+        variants: Vec<(Selector, Message)>,
+    },
+
+    Select {
+        argument: String,
+        variants: Vec<(String, Message)>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Selector {
+    Exact(f64),
+    Category(PluralCategory),
+}
+
+impl Message {
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser {
+            chars: source.chars().peekable(),
+            line:  1,
+        };
+
+        let message = parser.message(false)?;
+
+        match parser.chars.next() {
+            Some(c) => Err(parser.error(format!("unexpected `{c}`"))),
+            None => Ok(message),
+        }
+    }
+
+    /// Format the message in `locale`, with the arguments in `arguments`.
+    ///
+    /// Missing arguments are left in the output as `{name}`.
+    pub fn format(&self, locale: &Locale, arguments: &[(&str, Value)]) -> String {
+        let mut output = String::new();
+        self.format_into(&mut output, locale, arguments, None);
+        output
+    }
+
+    fn format_into(
+        &self,
+        output: &mut String,
+        locale: &Locale,
+        arguments: &[(&str, Value)],
+        number: Option<f64>,
+    ) {
+        let get = |name: &str| {
+            let value = arguments.iter().find(|(argument, _)| *argument == name);
+            value.map(|(_, value)| value)
+        };
+
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+
+                Part::Number => match number {
+                    Some(number) => output.push_str(&Value::Number(number).to_string()),
+                    None => output.push('#'),
+                },
+
+                Part::Argument(name) => match get(name) {
+                    Some(value) => output.push_str(&value.to_string()),
+                    None => missing(output, name),
+                },
+
+                Part::Plural { argument, variants } => {
+                    let Some(&Value::Number(number)) = get(argument) else {
+                        missing(output, argument);
+                        continue;
+                    };
+
+                    let category = PluralCategory::cardinal(&locale.language, number);
+
+                    let exact = variants.iter().find(|(selector, _)| {
+                        // exact selectors take precedence over categories
+                        *selector == Selector::Exact(number)
+                    });
+
+                    let variant = exact
+                        .or_else(|| {
+                            let selector = Selector::Category(category);
+                            variants.iter().find(|(s, _)| *s == selector)
+                        })
+                        .or_else(|| {
+                            let other = Selector::Category(PluralCategory::Other);
+                            variants.iter().find(|(s, _)| *s == other)
+                        });
+
+                    if let Some((_, message)) = variant {
+                        message.format_into(output, locale, arguments, Some(number));
+                    }
+                }
+
+                Part::Select { argument, variants } => {
+                    let key = match get(argument) {
+                        Some(Value::String(key)) => key.clone(),
+                        Some(value) => value.to_string(),
+                        None => String::from("other"),
+                    };
+
+                    let variant = (variants.iter().find(|(k, _)| *k == key))
+                        .or_else(|| variants.iter().find(|(k, _)| k == "other"));
+
+                    if let Some((_, message)) = variant {
+                        message.format_into(output, locale, arguments, number);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn missing(output: &mut String, name: &str) {
+    tracing::warn!("message argument `{name}` is missing");
+
+    output.push('{');
+    output.push_str(name);
+    output.push('}');
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line:  usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line:    self.line,
+            message: message.into(),
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+
+        if c == '\n' {
+            self.line += 1;
+        }
+
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.chars.next_if(|c| c.is_whitespace()) {
+            if c == '\n' {
+                self.line += 1;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(format!(
+                "expected `{expected}`, found `{c}`"
+            ))),
+            None => Err(self.error(format!("expected `{expected}`"))),
+        }
+    }
+
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+
+        while let Some(c) = self
+            .chars
+            .next_if(|c| !c.is_whitespace() && !"{},".contains(*c))
+        {
+            word.push(c);
+        }
+
+        word
+    }
+
+    /// Parse a message, until the end of the source or an unmatched `}`.
+    fn message(&mut self, is_plural: bool) -> Result<Message, ParseError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '}' => break,
+
+                '{' => {
+                    self.next();
+
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+
+                    parts.push(self.argument()?);
+                    self.expect('}')?;
+                }
+
+                '#' if is_plural => {
+                    self.next();
+
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+
+                    parts.push(Part::Number);
+                }
+
+                '\'' => {
+                    self.next();
+                    self.quoted(&mut text, is_plural);
+                }
+
+                _ => {
+                    self.next();
+                    text.push(c);
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+
+        Ok(Message { parts })
+    }
+
+    /// Parse the text after an apostrophe, which starts a quoted literal when followed by a
+    /// special character.
+    fn quoted(&mut self, text: &mut String, is_plural: bool) {
+        match self.chars.peek() {
+            Some('\'') => {
+                self.next();
+                text.push('\'');
+            }
+
+            Some('{' | '}') => self.literal(text),
+            Some('#') if is_plural => self.literal(text),
+            _ => text.push('\''),
+        }
+    }
+
+    /// Parse a quoted literal, until the next apostrophe that isn't escaped.
+    fn literal(&mut self, text: &mut String) {
+        while let Some(c) = self.next() {
+            if c == '\'' && self.chars.next_if_eq(&'\'').is_none() {
+                break;
+            }
+
+            text.push(c);
+        }
+    }
+
+    /// Parse an argument, after the opening brace.
+    fn argument(&mut self) -> Result<Part, ParseError> {
+        self.skip_whitespace();
+        let argument = self.word();
+        self.skip_whitespace();
+
+        if argument.is_empty() {
+            return Err(self.error("expected an argument name"));
+        }
+
+        if self.chars.next_if_eq(&',').is_none() {
+            return Ok(Part::Argument(argument));
+        }
+
+        self.skip_whitespace();
+        let kind = self.word();
+        self.skip_whitespace();
+
+        match kind.as_str() {
+            // numbers are formatted like any other argument
+            "number" => {
+                if self.chars.next_if_eq(&',').is_some() {
+                    self.skip_whitespace();
+                    self.word();
+                    self.skip_whitespace();
+                }
+
+                Ok(Part::Argument(argument))
+            }
+
+            "plural" => {
+                self.expect(',')?;
+
+                let variants = self.variants(true)?;
+                let mut selectors = Vec::with_capacity(variants.len());
+
+                for (selector, message) in variants {
+                    let selector = match selector.strip_prefix('=') {
+                        Some(number) => match number.parse() {
+                            Ok(number) => Selector::Exact(number),
+                            Err(_) => return Err(self.error(format!("invalid number `{number}`"))),
+                        },
+
+                        None => Selector::Category(self.category(&selector)?),
+                    };
+
+                    selectors.push((selector, message));
+                }
+
+                Ok(Part::Plural {
+                    argument,
+                    variants: selectors,
+                })
+            }
+
+            "select" => {
+                self.expect(',')?;
+
+                Ok(Part::Select {
+                    argument,
+                    variants: self.variants(false)?,
+                })
+            }
+
+            _ => Err(self.error(format!(
+                "unknown argument type `{kind}`"
+            ))),
+        }
+    }
+
+    fn category(&self, keyword: &str) -> Result<PluralCategory, ParseError> {
+        Ok(match keyword {
+            "zero" => PluralCategory::Zero,
+            "one" => PluralCategory::One,
+            "two" => PluralCategory::Two,
+            "few" => PluralCategory::Few,
+            "many" => PluralCategory::Many,
+            "other" => PluralCategory::Other,
+            _ => {
+                return Err(self.error(format!(
+                    "unknown plural category `{keyword}`"
+                )));
+            }
+        })
+    }
+
+    /// Parse the variants of a `plural` or `select` argument, until the closing brace.
+    fn variants(&mut self, is_plural: bool) -> Result<Vec<(String, Message)>, ParseError> {
+        let mut variants = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            if self.chars.peek() == Some(&'}') {
+                break;
+            }
+
+            let selector = self.word();
+
+            if selector.is_empty() {
+                return Err(self.error("expected a selector"));
+            }
+
+            self.skip_whitespace();
+            self.expect('{')?;
+            let message = self.message(is_plural)?;
+            self.expect('}')?;
+
+            variants.push((selector, message));
+        }
+
+        if !variants.iter().any(|(selector, _)| selector == "other") {
+            return Err(self.error("missing the `other` variant"));
+        }
+
+        Ok(variants)
+    }
+}
@@ -0,0 +1,385 @@
+//! Translation of the text of an application into the language of the user.
+//!
+//! Messages are written in the ICU MessageFormat syntax, see [`Message`], and collected per
+//! locale in [`Catalog`]s. The catalogs of an application are provided to its views as a
+//! [`Localization`] with [`localized`], which picks the catalog best matching the locales
+//! preferred by the user, and messages are translated with [`tr!`](crate::tr).
+//!
+//! ```ignore
+//! let localization = Localization::new("en")
+//!     .with(Catalog::parse("en", include_str!("../l10n/en.txt"))?)
+//!     .with(Catalog::parse("de", include_str!("../l10n/de.txt"))?);
+//!
+//! let data = Data {
+//!     localization: Arc::new(localization),
+//!     unread: 0,
+//! };
+//!
+//! fn ui(data: &Data) -> impl Effect<Data> {
+//!     localized(
+//!         data.localization.clone(),
+//!         window(tr!("unread", count = data.unread)),
+//!     )
+//! }
+//! ```
+//!
+//! The view is rebuilt when the preferred locales of the platform change, or the locale is
+//! set with [`Context::set_locale`](crate::Context::set_locale), so translated text follows
+//! the locale without any bookkeeping by the application.
+
+mod locale;
+mod message;
+mod plural;
+
+use std::{collections::HashMap, sync::Arc};
+
+use ori::{Action, Event, Provider, View, ViewMarker};
+
+use crate::Context;
+
+pub use locale::Locale;
+pub use message::{Message, ParseError, Value};
+pub use plural::PluralCategory;
+
+/// A [`Label`](crate::views::Label) showing the message `key`, translated to the locale of
+/// the [`Localization`] provided by [`localized`], see [`Context::translate`].
+///
+/// Arguments are passed by name, e.g. `tr!("unread", count = data.unread, name = "Ada")`.
+#[macro_export]
+macro_rules! tr {
+    ($key:expr $(,)?) => {
+        $crate::views::Label::translated($key, ::std::vec::Vec::new())
+    };
+
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::views::Label::translated(
+            $key,
+            ::std::vec![$((stringify!($name), $crate::l10n::Value::from($value))),+],
+        )
+    };
+}
+
+/// The messages of an application translated to a single locale.
+///
+/// Catalogs are parsed from text with one message per line, as `key = message`. Lines starting
+/// with whitespace continue the message of the previous line, and lines starting with `#` are
+/// comments.
+///
+/// ```text
+/// # the inbox
+/// inbox = Inbox
+/// unread = {count, plural,
+///     =0 {No unread messages}
+///     one {# unread message}
+///     other {# unread messages}}
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Catalog {
+    locale:   Locale,
+    messages: HashMap<String, Message>,
+}
+
+impl Catalog {
+    pub fn new(locale: impl Into<Locale>) -> Self {
+        Self {
+            locale:   locale.into(),
+            messages: HashMap::new(),
+        }
+    }
+
+    pub fn parse(locale: impl Into<Locale>, source: &str) -> Result<Self, ParseError> {
+        let mut catalog = Self::new(locale);
+        let mut entry: Option<(usize, String, String)> = None;
+
+        for (i, line) in source.lines().enumerate() {
+            let is_continued = line.starts_with(char::is_whitespace);
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if is_continued && let Some((_, _, ref mut message)) = entry {
+                message.push('\n');
+                message.push_str(line);
+                continue;
+            }
+
+            if let Some(entry) = entry.take() {
+                catalog.insert_source(entry)?;
+            }
+
+            let Some((key, message)) = line.split_once('=') else {
+                return Err(ParseError {
+                    line:    i + 1,
+                    message: String::from("expected `key = message`"),
+                });
+            };
+
+            entry = Some((
+                i + 1,
+                key.trim().into(),
+                message.trim().into(),
+            ));
+        }
+
+        if let Some(entry) = entry {
+            catalog.insert_source(entry)?;
+        }
+
+        Ok(catalog)
+    }
+
+    fn insert_source(
+        &mut self,
+        (line, key, source): (usize, String, String),
+    ) -> Result<(), ParseError> {
+        let message = Message::parse(&source).map_err(|err| ParseError {
+            line:    line + err.line - 1,
+            message: err.message,
+        })?;
+
+        self.insert(key, message);
+        Ok(())
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, message: Message) {
+        self.messages.insert(key.into(), message);
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Message> {
+        self.messages.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+}
+
+/// The [`Catalog`]s of an application, and the locale messages fall back to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Localization {
+    catalogs: Vec<Catalog>,
+    default:  Locale,
+}
+
+impl Localization {
+    /// Create a localization falling back to `default`, when no catalog matches the locales
+    /// preferred by the user, or a catalog is missing a message.
+    pub fn new(default: impl Into<Locale>) -> Self {
+        Self {
+            catalogs: Vec::new(),
+            default:  default.into(),
+        }
+    }
+
+    pub fn with(mut self, catalog: Catalog) -> Self {
+        self.push(catalog);
+        self
+    }
+
+    /// Add `catalog`, merging it with the catalog of the same locale if there is one.
+    pub fn push(&mut self, catalog: Catalog) {
+        match self
+            .catalogs
+            .iter_mut()
+            .find(|c| c.locale == catalog.locale)
+        {
+            Some(existing) => existing.messages.extend(catalog.messages),
+            None => self.catalogs.push(catalog),
+        }
+    }
+
+    pub fn default_locale(&self) -> &Locale {
+        &self.default
+    }
+
+    /// The locales there are catalogs for.
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.catalogs.iter().map(Catalog::locale)
+    }
+
+    /// Pick the locale best matching `requested`, ordered from most to least preferred, see
+    /// [`Locale::negotiate`].
+    pub fn negotiate(&self, requested: &[Locale]) -> Locale {
+        let available: Vec<_> = self.locales().cloned().collect();
+
+        match Locale::negotiate(requested, &available) {
+            Some(locale) => locale.clone(),
+            None => self.default.clone(),
+        }
+    }
+
+    /// Format the message `key` in `locale`, falling back to the catalog of the language of
+    /// `locale`, and then to the default locale.
+    pub fn format(
+        &self,
+        locale: &Locale,
+        key: &str,
+        arguments: &[(&str, Value)],
+    ) -> Option<String> {
+        let language = locale.language_only();
+
+        let message = [locale, &language, &self.default]
+            .into_iter()
+            .filter_map(|locale| self.catalogs.iter().find(|c| c.locale == *locale))
+            .find_map(|catalog| catalog.get(key))?;
+
+        Some(message.format(locale, arguments))
+    }
+}
+
+/// The locales preferred by the platform and the application, kept in the
+/// [`Context`](crate::Context).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Locales {
+    /// The locales preferred by the platform, most preferred first.
+    pub system: Vec<Locale>,
+
+    /// The locale preferred by the application, see
+    /// [`Context::set_locale`](crate::Context::set_locale).
+    pub preferred: Option<Locale>,
+}
+
+impl Locales {
+    /// Negotiate the locale text is translated to with the locales of `localization`, or
+    /// pick the most preferred locale without one.
+    pub fn negotiate(&self, localization: Option<&Localization>) -> Locale {
+        let requested: Vec<_> = (self.preferred.iter())
+            .chain(&self.system)
+            .cloned()
+            .collect();
+
+        match localization {
+            Some(localization) => localization.negotiate(&requested),
+            None => requested
+                .first()
+                .cloned()
+                .unwrap_or_else(|| Locale::new("en")),
+        }
+    }
+}
+
+/// The [`Localization`] provided by [`localized`], and the locale negotiated with it.
+#[derive(Clone)]
+pub(crate) struct Translator {
+    pub localization: Arc<Localization>,
+    pub locale:       Locale,
+}
+
+impl Translator {
+    fn push(&self, cx: &mut Context) {
+        cx.push(Box::new(self.clone()));
+    }
+
+    fn pop(cx: &mut Context) {
+        cx.pop::<Self>();
+    }
+}
+
+/// Provide `localization` to `contents`, translating the text of [`tr!`](crate::tr) in it.
+pub fn localized<V>(localization: Arc<Localization>, contents: V) -> Localized<V> {
+    Localized::new(localization, contents)
+}
+
+/// Provides a [`Localization`] to its contents.
+///
+/// The locale is negotiated from the locales of the localization and those preferred by the
+/// platform and the application, and the layout direction of the windows follows it.
+pub struct Localized<V> {
+    contents:     V,
+    localization: Arc<Localization>,
+}
+
+impl<V> Localized<V> {
+    pub fn new(localization: Arc<Localization>, contents: V) -> Self {
+        Self {
+            contents,
+            localization,
+        }
+    }
+
+    fn resolve(self, cx: &Context) -> (V, Translator) {
+        let locale = cx.locales.negotiate(Some(&self.localization));
+
+        let translator = Translator {
+            localization: self.localization,
+            locale,
+        };
+
+        (self.contents, translator)
+    }
+}
+
+impl<V> ViewMarker for Localized<V> {}
+impl<T, V> View<Context, T> for Localized<V>
+where
+    V: View<Context, T>,
+{
+    type Element = V::Element;
+    type State = (Translator, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, translator) = self.resolve(cx);
+
+        // when the application is translated, the layout follows the language of the
+        // translation rather than the language of the platform
+        cx.world
+            .layout_direction_changed(translator.locale.direction());
+
+        translator.push(cx);
+        let (element, state) = contents.build(cx, data);
+        Translator::pop(cx);
+
+        (element, (translator, state))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (translator, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        let (contents, new_translator) = self.resolve(cx);
+
+        if new_translator.locale != translator.locale {
+            cx.world
+                .layout_direction_changed(new_translator.locale.direction());
+        }
+
+        *translator = new_translator;
+
+        translator.push(cx);
+        contents.rebuild(element, state, cx, data);
+        Translator::pop(cx);
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (translator, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        translator.push(cx);
+        let action = V::event(element, state, cx, data, event);
+        Translator::pop(cx);
+
+        action
+    }
+
+    fn teardown(element: Self::Element, (translator, state): Self::State, cx: &mut Context) {
+        translator.push(cx);
+        V::teardown(element, state, cx);
+        Translator::pop(cx);
+    }
+}
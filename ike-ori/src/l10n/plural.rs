@@ -0,0 +1,120 @@
+/// The plural category of a number, as defined by the CLDR plural rules of a language.
+///
+/// Languages use different subsets of the categories, English only distinguishes
+/// [`PluralCategory::One`] and [`PluralCategory::Other`], while Arabic uses all six.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// The keyword of the category in messages, e.g. `one`.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    /// The cardinal plural category of `n` in `language`, e.g. `one` for `1` and `other` for
+    /// `2` in English.
+    ///
+    /// Languages without known rules use the rules of English.
+    pub fn cardinal(language: &str, n: f64) -> Self {
+        let n = n.abs();
+
+        // the integer digits, and whether there are visible fraction digits
+        let i = n.trunc() as u64;
+        let is_integer = n.fract() == 0.0;
+
+        let i10 = i % 10;
+        let i100 = i % 100;
+
+        match language {
+            // languages without plurals
+            "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "lo" | "my" | "km" => {
+                PluralCategory::Other
+            }
+
+            // zero and one are singular
+            "fr" | "pt" | "hi" | "bn" | "fa" | "am" | "gu" | "kn" | "zu" => {
+                if i <= 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+
+            "ru" | "uk" | "be" | "sr" | "hr" | "bs" if is_integer => {
+                if i10 == 1 && i100 != 11 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                    PluralCategory::Few
+                } else if matches!(language, "ru" | "uk" | "be") {
+                    PluralCategory::Many
+                } else {
+                    PluralCategory::Other
+                }
+            }
+
+            "pl" if is_integer => {
+                if i == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+
+            "cs" | "sk" => {
+                if !is_integer {
+                    PluralCategory::Many
+                } else if i == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&i) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Other
+                }
+            }
+
+            "ar" if is_integer => match i {
+                0 => PluralCategory::Zero,
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ if (3..=10).contains(&i100) => PluralCategory::Few,
+                _ if (11..=99).contains(&i100) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+
+            "he" | "iw" if is_integer => match i {
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ => PluralCategory::Other,
+            },
+
+            "ru" | "uk" | "be" | "sr" | "hr" | "bs" | "pl" | "ar" | "he" | "iw" => {
+                PluralCategory::Other
+            }
+
+            // one is singular, like in english
+            _ => {
+                if i == 1 && is_integer {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+        }
+    }
+}
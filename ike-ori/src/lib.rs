@@ -9,13 +9,38 @@ pub mod views {
     pub use views::*;
 }
 
+pub mod export;
+pub mod l10n;
+#[cfg(feature = "persist")]
+pub mod persist;
+
+mod cancel;
 mod context;
+mod dialog;
+mod icons;
+mod image_cache;
+mod oneshot;
+mod paged_rows;
 mod palette;
+mod rate;
 mod resources;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod task;
+mod theme;
 
 pub use context::{Context, Effect, View};
+pub use dialog::PickFiles;
+pub use icons::{IconPack, IconSet, Icons};
+pub use image_cache::ImageCache;
+pub use paged_rows::{PageError, PageFuture, PageSource, PagedRows};
 pub use palette::Palette;
+pub use rate::{RateLimit, RateLimiter};
 pub use resources::Resources;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteSource;
+pub use task::TaskHandle;
+pub use theme::{Theme, Themed, is_retheming, theme_transition, themed};
 
 pub type AnyEffect<T> = Box<dyn ori::AnyView<Context, T, ori::NoElement>>;
 pub type UiBuilder<T> = Box<dyn FnMut(&T) -> AnyEffect<T>>;
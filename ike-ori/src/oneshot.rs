@@ -0,0 +1,75 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+struct Shared<T> {
+    value:     Option<T>,
+    waker:     Option<Waker>,
+    is_closed: bool,
+}
+
+/// Create a channel sending a single value, the receiver resolves to `None` if the sender is
+/// dropped without sending.
+pub(crate) fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared {
+        value:     None,
+        waker:     None,
+        is_closed: false,
+    }));
+
+    let sender = Sender {
+        shared: shared.clone(),
+    };
+
+    (sender, Receiver { shared })
+}
+
+pub(crate) struct Sender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Sender<T> {
+    pub(crate) fn send(self, value: T) {
+        lock(&self.shared).value = Some(value);
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut shared = lock(&self.shared);
+        shared.is_closed = true;
+
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+pub(crate) struct Receiver<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Future for Receiver<T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = lock(&self.shared);
+
+        if let Some(value) = shared.value.take() {
+            return Poll::Ready(Some(value));
+        }
+
+        if shared.is_closed {
+            return Poll::Ready(None);
+        }
+
+        shared.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+fn lock<T>(shared: &Mutex<Shared<T>>) -> MutexGuard<'_, Shared<T>> {
+    shared.lock().unwrap_or_else(|err| err.into_inner())
+}
@@ -0,0 +1,278 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    ops::Range,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use ori::Proxy;
+
+pub type PageError = Box<dyn Error + Send + Sync>;
+
+pub type PageFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, PageError>> + Send + 'a>>;
+
+/// An async source of rows of text, e.g. the results of a database query.
+pub trait PageSource: Send + Sync + 'static {
+    /// The total number of rows.
+    fn count(&self) -> PageFuture<'_, usize>;
+
+    /// Fetch at most `limit` rows, starting at row `offset`.
+    fn fetch(&self, offset: usize, limit: usize) -> PageFuture<'_, Vec<Vec<String>>>;
+}
+
+enum Count {
+    Unknown,
+    Loading,
+    Known(usize),
+}
+
+enum Page {
+    Loading,
+    Loaded(Vec<Vec<String>>),
+}
+
+struct Inner {
+    count:      Count,
+    pages:      HashMap<usize, Page>,
+    visible:    Range<usize>,
+    generation: u64,
+}
+
+/// Rows loaded a page at a time from a [`PageSource`], shown with
+/// [`paged_table`](crate::views::paged_table).
+///
+/// Pages are loaded as they become visible, and the pages ahead of the direction the table is
+/// scrolled in are prefetched. Rows of pages that are still loading are shown as placeholders.
+#[derive(Clone)]
+pub struct PagedRows {
+    source:    Arc<dyn PageSource>,
+    inner:     Arc<Mutex<Inner>>,
+    page_size: usize,
+    prefetch:  usize,
+    max_pages: usize,
+}
+
+impl PagedRows {
+    pub fn new(source: impl PageSource) -> Self {
+        let inner = Inner {
+            count:      Count::Unknown,
+            pages:      HashMap::new(),
+            visible:    0..0,
+            generation: 0,
+        };
+
+        Self {
+            source:    Arc::new(source),
+            inner:     Arc::new(Mutex::new(inner)),
+            page_size: 100,
+            prefetch:  1,
+            max_pages: 32,
+        }
+    }
+
+    /// Set the number of rows in a page, defaults to `100`.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Set the number of pages to load ahead of the visible rows, defaults to `1`.
+    pub fn prefetch(mut self, pages: usize) -> Self {
+        self.prefetch = pages;
+        self
+    }
+
+    /// Set the number of pages kept loaded, defaults to `32`.
+    ///
+    /// Pages furthest from the visible rows are dropped first.
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = pages.max(1);
+        self
+    }
+
+    /// The total number of rows, `None` until it has been loaded.
+    pub fn count(&self) -> Option<usize> {
+        match self.lock().count {
+            Count::Known(count) => Some(count),
+            _ => None,
+        }
+    }
+
+    /// Get `row` if its page is loaded.
+    pub fn row(&self, row: usize) -> Option<Vec<String>> {
+        let inner = self.lock();
+
+        match inner.pages.get(&(row / self.page_size))? {
+            Page::Loaded(rows) => rows.get(row % self.page_size).cloned(),
+            Page::Loading => None,
+        }
+    }
+
+    /// Get the cell at `(row, column)` if its page is loaded.
+    pub fn cell(&self, row: usize, column: usize) -> Option<String> {
+        let inner = self.lock();
+
+        match inner.pages.get(&(row / self.page_size))? {
+            Page::Loaded(rows) => rows.get(row % self.page_size)?.get(column).cloned(),
+            Page::Loading => None,
+        }
+    }
+
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Incremented every time the rows are invalidated.
+    pub(crate) fn generation(&self) -> u64 {
+        self.lock().generation
+    }
+
+    /// Drop the count and every loaded page, they are loaded again when next shown.
+    pub fn invalidate(&self) {
+        let mut inner = self.lock();
+        inner.count = Count::Unknown;
+        inner.pages.clear();
+        inner.generation += 1;
+    }
+
+    /// Load the count and the pages needed to show the `visible` rows, calling `on_load` each
+    /// time something finishes loading.
+    pub(crate) fn show(
+        &self,
+        proxy: &Arc<dyn Proxy>,
+        visible: Range<usize>,
+        on_load: impl Fn() + Clone + Send + Sync + 'static,
+    ) {
+        let mut inner = self.lock();
+
+        if matches!(inner.count, Count::Unknown) {
+            inner.count = Count::Loading;
+            self.load_count(&inner, proxy, on_load.clone());
+        }
+
+        let Count::Known(count) = inner.count else {
+            inner.visible = visible;
+            return;
+        };
+
+        let pages = count.div_ceil(self.page_size);
+        let forward = visible.start >= inner.visible.start;
+
+        let first = visible.start / self.page_size;
+        let last = visible.end.saturating_sub(1).max(visible.start) / self.page_size;
+
+        // prefetch in the direction the rows are being scrolled
+        let needed = if forward {
+            first..=last + self.prefetch
+        } else {
+            first.saturating_sub(self.prefetch)..=last
+        };
+
+        for page in needed.filter(|&page| page < pages) {
+            if inner.pages.contains_key(&page) {
+                continue;
+            }
+
+            inner.pages.insert(page, Page::Loading);
+            self.load_page(&inner, proxy, page, on_load.clone());
+        }
+
+        inner.visible = visible;
+        self.trim(&mut inner, first, last);
+    }
+
+    fn load_count(
+        &self,
+        inner: &Inner,
+        proxy: &Arc<dyn Proxy>,
+        on_load: impl Fn() + Send + 'static,
+    ) {
+        let rows = self.clone();
+        let generation = inner.generation;
+
+        proxy.spawn_boxed(Box::pin(async move {
+            let result = rows.source.count().await;
+            let mut inner = rows.lock();
+
+            if inner.generation != generation {
+                return;
+            }
+
+            inner.count = match result {
+                Ok(count) => Count::Known(count),
+                Err(err) => {
+                    tracing::warn!(%err, "loading row count failed");
+                    Count::Unknown
+                }
+            };
+
+            drop(inner);
+            on_load();
+        }));
+    }
+
+    fn load_page(
+        &self,
+        inner: &Inner,
+        proxy: &Arc<dyn Proxy>,
+        page: usize,
+        on_load: impl Fn() + Send + 'static,
+    ) {
+        let rows = self.clone();
+        let generation = inner.generation;
+        let offset = page * self.page_size;
+
+        proxy.spawn_boxed(Box::pin(async move {
+            let result = rows.source.fetch(offset, rows.page_size).await;
+            let mut inner = rows.lock();
+
+            if inner.generation != generation {
+                return;
+            }
+
+            match result {
+                Ok(loaded) => {
+                    inner.pages.insert(page, Page::Loaded(loaded));
+                }
+
+                // the page is loaded again the next time it's shown
+                Err(err) => {
+                    tracing::warn!(%err, page, "loading rows failed");
+                    inner.pages.remove(&page);
+                }
+            }
+
+            drop(inner);
+            on_load();
+        }));
+    }
+
+    /// Drop the loaded pages furthest from the visible pages `first..=last`.
+    fn trim(&self, inner: &mut Inner, first: usize, last: usize) {
+        while inner.pages.len() > self.max_pages {
+            let furthest = inner
+                .pages
+                .iter()
+                .filter(|(_, page)| matches!(page, Page::Loaded(_)))
+                .map(|(&page, _)| page)
+                .filter(|page| !(first..=last).contains(page))
+                .max_by_key(|&page| {
+                    usize::max(
+                        first.saturating_sub(page),
+                        page.saturating_sub(last),
+                    )
+                });
+
+            let Some(furthest) = furthest else {
+                break;
+            };
+
+            inner.pages.remove(&furthest);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
@@ -54,6 +54,14 @@ impl Palette {
         }
     }
 
+    /// The color of shadows cast by elevated surfaces.
+    pub fn shadow(&self) -> Color {
+        match self.is_light() {
+            true => Color::BLACK.fade(0.2),
+            false => Color::BLACK.fade(0.5),
+        }
+    }
+
     fn level(color: Color, is_light: bool, level: i8) -> Color {
         if level == 0 {
             return color;
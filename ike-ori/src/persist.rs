@@ -0,0 +1,366 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use ike_core::{Point, Size, WindowGeometry};
+use ori::{Action, Event, Provider, Proxy, View, ViewMarker};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value, json};
+
+use crate::Context;
+
+/// A typed key-value store of settings persisted across runs, e.g. the theme picked by the
+/// user, or the geometry of windows, see [`Window::persist`](crate::views::Window::persist).
+///
+/// Values are stored as JSON in a single file, which is read when the store is opened, and
+/// written in the background through the [`Proxy`] whenever a value is set. Writes made while
+/// one is in progress are coalesced, so setting values often, e.g. while a window is being
+/// resized, is cheap.
+///
+/// Provide a store to views with [`persisted`], it's then available with
+/// [`Context::preference`] and [`Context::set_preference`].
+#[derive(Clone)]
+pub struct Preferences {
+    inner: Arc<Mutex<Inner>>,
+}
+
+struct Inner {
+    path:      Option<PathBuf>,
+    values:    Map<String, Value>,
+    is_dirty:  bool,
+    is_saving: bool,
+}
+
+impl Preferences {
+    /// Open the store in the file at `path`, starting empty if it doesn't exist or can't be
+    /// read.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+
+        let values = match fs::read(&path) {
+            Ok(bytes) => match serde_json::from_slice(&bytes) {
+                Ok(values) => values,
+                Err(err) => {
+                    tracing::warn!(
+                        "failed parsing preferences `{}`: {err}",
+                        path.display()
+                    );
+                    Map::new()
+                }
+            },
+
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Map::new(),
+            Err(err) => {
+                tracing::warn!(
+                    "failed reading preferences `{}`: {err}",
+                    path.display()
+                );
+                Map::new()
+            }
+        };
+
+        Self::with_values(Some(path), values)
+    }
+
+    /// Open the store of the application `name` in the configuration directory of the
+    /// platform, see [`config_dir`].
+    ///
+    /// Falls back to a store that isn't persisted if there is no configuration directory.
+    pub fn for_app(name: &str) -> Self {
+        match config_dir() {
+            Some(dir) => Self::open(dir.join(name).join("preferences.json")),
+            None => {
+                tracing::warn!("no configuration directory, preferences won't be persisted");
+                Self::in_memory()
+            }
+        }
+    }
+
+    /// Create a store that isn't persisted, e.g. for tests.
+    pub fn in_memory() -> Self {
+        Self::with_values(None, Map::new())
+    }
+
+    fn with_values(path: Option<PathBuf>, values: Map<String, Value>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                path,
+                values,
+                is_dirty: false,
+                is_saving: false,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// The file the store is persisted in, `None` if it isn't persisted.
+    pub fn path(&self) -> Option<PathBuf> {
+        self.lock().path.clone()
+    }
+
+    /// Get the value of `key`, `None` if it isn't set, or isn't a `T`.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.lock().values.get(key)?.clone();
+
+        match serde_json::from_value(value) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                tracing::warn!("failed reading preference `{key}`: {err}");
+                None
+            }
+        }
+    }
+
+    /// Set the value of `key`, and persist the store in the background with `proxy`.
+    pub fn set<T: Serialize>(&self, proxy: &dyn Proxy, key: &str, value: T) {
+        let value = match serde_json::to_value(value) {
+            Ok(value) => value,
+            Err(err) => {
+                tracing::warn!("failed writing preference `{key}`: {err}");
+                return;
+            }
+        };
+
+        let mut inner = self.lock();
+
+        if inner.values.get(key) != Some(&value) {
+            inner.values.insert(key.into(), value);
+            drop(inner);
+
+            self.save(proxy);
+        }
+    }
+
+    /// Remove the value of `key`, and persist the store in the background with `proxy`.
+    pub fn remove(&self, proxy: &dyn Proxy, key: &str) {
+        if self.lock().values.remove(key).is_some() {
+            self.save(proxy);
+        }
+    }
+
+    /// Persist the store in the background with `proxy`.
+    pub fn save(&self, proxy: &dyn Proxy) {
+        let mut inner = self.lock();
+
+        if inner.path.is_none() {
+            return;
+        }
+
+        inner.is_dirty = true;
+
+        // the write in progress picks up the change when it's done
+        if inner.is_saving {
+            return;
+        }
+
+        inner.is_saving = true;
+        drop(inner);
+
+        let preferences = self.clone();
+        proxy.spawn_boxed(Box::pin(async move {
+            // the file is written on a blocking thread, so it doesn't stall the runtime
+            let written = tokio::task::spawn_blocking(move || {
+                while let Some((path, contents)) = preferences.take_dirty() {
+                    if let Err(err) = write_atomic(&path, &contents) {
+                        tracing::warn!(
+                            "failed writing preferences `{}`: {err}",
+                            path.display()
+                        );
+                    }
+                }
+            });
+
+            if let Err(err) = written.await {
+                tracing::warn!("failed writing preferences: {err}");
+            }
+        }));
+    }
+
+    /// Take the contents to write if the store changed since it was last written.
+    fn take_dirty(&self) -> Option<(PathBuf, Vec<u8>)> {
+        let mut inner = self.lock();
+
+        let Some(path) = inner.path.clone().filter(|_| inner.is_dirty) else {
+            inner.is_saving = false;
+            return None;
+        };
+
+        inner.is_dirty = false;
+
+        match serde_json::to_vec_pretty(&inner.values) {
+            Ok(contents) => Some((path, contents)),
+            Err(err) => {
+                tracing::warn!("failed serializing preferences: {err}");
+                inner.is_saving = false;
+                None
+            }
+        }
+    }
+
+    pub(crate) fn window_geometry(&self, name: &str) -> Option<WindowGeometry> {
+        let value: Value = self.get(&window_key(name))?;
+
+        let size = Size::new(
+            value.get("width")?.as_f64()? as f32,
+            value.get("height")?.as_f64()? as f32,
+        );
+
+        let position = match (value.get("x"), value.get("y")) {
+            (Some(x), Some(y)) => Some(Point::new(
+                x.as_f64()? as f32,
+                y.as_f64()? as f32,
+            )),
+            _ => None,
+        };
+
+        let is_maximized = value.get("maximized")?.as_bool()?;
+
+        Some(WindowGeometry {
+            size,
+            position,
+            is_maximized,
+        })
+    }
+
+    pub(crate) fn set_window_geometry(
+        &self,
+        proxy: &dyn Proxy,
+        name: &str,
+        geometry: WindowGeometry,
+    ) {
+        let mut value = json!({
+            "width": geometry.size.width,
+            "height": geometry.size.height,
+            "maximized": geometry.is_maximized,
+        });
+
+        if let Some(position) = geometry.position {
+            value["x"] = json!(position.x);
+            value["y"] = json!(position.y);
+        }
+
+        self.set(proxy, &window_key(name), value);
+    }
+}
+
+fn window_key(name: &str) -> String {
+    format!("window.{name}")
+}
+
+/// Write `contents` to a temporary file next to `path` and rename it, so that the file is never
+/// left half written.
+fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, contents)?;
+    fs::rename(&temporary, path)
+}
+
+/// Provide `preferences` to `contents`, see [`Context::preference`].
+pub fn persisted<V>(preferences: Preferences, contents: V) -> Persisted<V> {
+    Persisted::new(preferences, contents)
+}
+
+/// Provides [`Preferences`] to its contents.
+pub struct Persisted<V> {
+    contents:    V,
+    preferences: Preferences,
+}
+
+impl<V> Persisted<V> {
+    pub fn new(preferences: Preferences, contents: V) -> Self {
+        Self {
+            contents,
+            preferences,
+        }
+    }
+}
+
+impl Preferences {
+    fn push(&self, cx: &mut Context) {
+        cx.push(Box::new(self.clone()));
+    }
+
+    fn pop(cx: &mut Context) {
+        cx.pop::<Self>();
+    }
+}
+
+impl<V> ViewMarker for Persisted<V> {}
+impl<T, V> View<Context, T> for Persisted<V>
+where
+    V: View<Context, T>,
+{
+    type Element = V::Element;
+    type State = (Preferences, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        self.preferences.push(cx);
+        let (element, state) = self.contents.build(cx, data);
+        Preferences::pop(cx);
+
+        (element, (self.preferences, state))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (preferences, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        *preferences = self.preferences;
+
+        preferences.push(cx);
+        self.contents.rebuild(element, state, cx, data);
+        Preferences::pop(cx);
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (preferences, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        preferences.push(cx);
+        let action = V::event(element, state, cx, data, event);
+        Preferences::pop(cx);
+
+        action
+    }
+
+    fn teardown(element: Self::Element, (preferences, state): Self::State, cx: &mut Context) {
+        preferences.push(cx);
+        V::teardown(element, state, cx);
+        Preferences::pop(cx);
+    }
+}
+
+/// The directory of the platform for configuration files, e.g. `~/.config` on linux.
+///
+/// Returns `None` on platforms without one, like android, where a path in the files directory
+/// of the application should be passed to [`Preferences::open`] instead.
+pub fn config_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+
+    if cfg!(target_os = "android") {
+        None
+    } else if cfg!(target_os = "windows") {
+        var("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| Path::new(&home).join("Library/Application Support"))
+    } else {
+        var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| Path::new(&home).join(".config")))
+    }
+}
@@ -0,0 +1,141 @@
+use std::time::{Duration, Instant};
+
+use ori::{Event, Proxied, Proxy, ViewId};
+
+use crate::{Context, TaskHandle};
+
+/// How often a callback is called when its input changes rapidly, e.g. to send a search query
+/// once the user stops typing, rather than on every keystroke.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimit {
+    /// Call the callback with every input.
+    #[default]
+    None,
+
+    /// Call the callback with the latest input, once no input arrived for the duration.
+    Debounce(Duration),
+
+    /// Call the callback at most once per duration, with the first input right away and the
+    /// latest input at the end of the interval.
+    Throttle(Duration),
+}
+
+/// The state of a [`RateLimit`]ed callback, kept across rebuilds by the view calling it.
+///
+/// Inputs are passed to [`RateLimiter::input`], and events to [`RateLimiter::event`], which
+/// both return the input to call the callback with, if any. Pending calls are cancelled when
+/// the limiter is dropped, so a view removed while input is pending doesn't call it.
+pub struct RateLimiter<I> {
+    limit:      RateLimit,
+    pending:    Option<I>,
+    last_call:  Option<Instant>,
+    timer:      Option<TaskHandle>,
+    generation: u64,
+}
+
+/// Sent to the view when the timer of a [`RateLimiter`] elapsed.
+struct Elapsed {
+    generation: u64,
+}
+
+impl<I> RateLimiter<I> {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            pending: None,
+            last_call: None,
+            timer: None,
+            generation: 0,
+        }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Set the limit, calls that are already pending are made with the previous limit.
+    pub fn set_limit(&mut self, limit: RateLimit) {
+        self.limit = limit;
+    }
+
+    /// Whether an input is waiting for the timer.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Pass an input of the view with `id`, returning it if the callback should be called
+    /// right away.
+    pub fn input(&mut self, cx: &mut Context, id: ViewId, input: I) -> Option<I> {
+        match self.limit {
+            RateLimit::None => Some(input),
+
+            RateLimit::Debounce(delay) => {
+                self.pending = Some(input);
+                self.start(cx, id, delay);
+                None
+            }
+
+            RateLimit::Throttle(interval) => {
+                if self.timer.is_some() {
+                    self.pending = Some(input);
+                    return None;
+                }
+
+                let now = Instant::now();
+                let elapsed = self.last_call.map(|last| now - last);
+
+                match elapsed {
+                    Some(elapsed) if elapsed < interval => {
+                        self.pending = Some(input);
+                        self.start(cx, id, interval - elapsed);
+                        None
+                    }
+
+                    _ => {
+                        self.last_call = Some(now);
+                        Some(input)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Handle `event` if it's the timer of the view with `id` elapsing, returning the input to
+    /// call the callback with.
+    pub fn event(&mut self, event: &mut Event, id: ViewId) -> Option<I> {
+        let elapsed = event.take_targeted::<Elapsed>(id)?;
+
+        // timers replaced before they were cancelled may still elapse
+        if elapsed.generation != self.generation {
+            return None;
+        }
+
+        self.timer = None;
+        self.last_call = Some(Instant::now());
+        self.pending.take()
+    }
+
+    /// Cancel the timer, returning the pending input to call the callback with right away,
+    /// e.g. when the entry it's debouncing is submitted.
+    pub fn flush(&mut self) -> Option<I> {
+        self.timer = None;
+        self.generation += 1;
+
+        let pending = self.pending.take()?;
+        self.last_call = Some(Instant::now());
+        Some(pending)
+    }
+
+    fn start(&mut self, cx: &mut Context, id: ViewId, delay: Duration) {
+        self.generation += 1;
+
+        let generation = self.generation;
+        let proxy = cx.proxy();
+
+        // replacing the handle cancels the previous timer
+        self.timer = Some(cx.spawn_scoped(async move {
+            tokio::time::sleep(delay).await;
+            proxy.event(Event::new(Elapsed { generation }, id));
+        }));
+    }
+}
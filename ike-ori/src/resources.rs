@@ -23,6 +23,15 @@ impl Resources {
             entries: Vec::new(),
         }
     }
+
+    /// Push a resource whose type is only known as `type_id`.
+    pub(crate) fn push_any(&mut self, type_id: TypeId, value: Box<dyn Any>) {
+        self.entries.push(Entry { value, type_id });
+    }
+
+    pub(crate) fn pop_any(&mut self) -> Option<Box<dyn Any>> {
+        Some(self.entries.pop()?.value)
+    }
 }
 
 impl Provider for Resources {
@@ -0,0 +1,74 @@
+use sqlx::{Row, SqlitePool, TypeInfo, ValueRef, sqlite::SqliteRow};
+
+use crate::{PageFuture, PageSource};
+
+/// A [`PageSource`] paging through the results of a SQLite query.
+///
+/// The query is wrapped in `SELECT COUNT(*) FROM (query)` to count the rows, and in
+/// `SELECT * FROM (query) LIMIT ? OFFSET ?` to fetch them, so it shouldn't limit the rows itself.
+/// Give it an `ORDER BY` for the pages to be stable.
+pub struct SqliteSource {
+    pool:  SqlitePool,
+    query: String,
+}
+
+impl SqliteSource {
+    pub fn new(pool: SqlitePool, query: impl Into<String>) -> Self {
+        Self {
+            pool,
+            query: query.into(),
+        }
+    }
+}
+
+impl PageSource for SqliteSource {
+    fn count(&self) -> PageFuture<'_, usize> {
+        Box::pin(async move {
+            let sql = format!("SELECT COUNT(*) FROM ({})", self.query);
+            let count: i64 = sqlx::query_scalar(&sql).fetch_one(&self.pool).await?;
+
+            Ok(count.max(0) as usize)
+        })
+    }
+
+    fn fetch(&self, offset: usize, limit: usize) -> PageFuture<'_, Vec<Vec<String>>> {
+        Box::pin(async move {
+            let sql = format!(
+                "SELECT * FROM ({}) LIMIT ? OFFSET ?",
+                self.query
+            );
+            let rows = sqlx::query(&sql)
+                .bind(limit as i64)
+                .bind(offset as i64)
+                .fetch_all(&self.pool)
+                .await?;
+
+            Ok(rows.iter().map(cells).collect::<Result<_, _>>()?)
+        })
+    }
+}
+
+/// Format the columns of `row` as text.
+fn cells(row: &SqliteRow) -> Result<Vec<String>, sqlx::Error> {
+    (0..row.len())
+        .map(|column| {
+            let value = row.try_get_raw(column)?;
+
+            if value.is_null() {
+                return Ok(String::new());
+            }
+
+            let cell = match value.type_info().name() {
+                "INTEGER" => row.try_get::<i64, _>(column)?.to_string(),
+                "REAL" => row.try_get::<f64, _>(column)?.to_string(),
+                "BLOB" => {
+                    let blob = row.try_get::<Vec<u8>, _>(column)?;
+                    format!("<{} bytes>", blob.len())
+                }
+                _ => row.try_get::<String, _>(column)?,
+            };
+
+            Ok(cell)
+        })
+        .collect()
+}
@@ -0,0 +1,72 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use crate::cancel::{Cancel, Cancellable};
+
+/// A handle to a future spawned with [`Context::spawn_scoped`](crate::Context::spawn_scoped),
+/// which cancels it when dropped.
+///
+/// Keep the handle in the state of the view that spawned the future, and it's cancelled when
+/// the view is removed.
+#[must_use = "the task is cancelled when the handle is dropped"]
+pub struct TaskHandle {
+    cancel:      Arc<Cancel>,
+    is_finished: Arc<AtomicBool>,
+    is_detached: bool,
+}
+
+impl TaskHandle {
+    /// Wrap `future` so that it's cancelled by the returned handle.
+    pub(crate) fn new(
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> (
+        Self,
+        impl Future<Output = ()> + Send + 'static,
+    ) {
+        let cancel = Arc::new(Cancel::default());
+        let is_finished = Arc::new(AtomicBool::new(false));
+
+        let future = {
+            let future = Cancellable::new(future, cancel.clone());
+            let is_finished = is_finished.clone();
+
+            async move {
+                future.await;
+                is_finished.store(true, Ordering::Release);
+            }
+        };
+
+        let handle = Self {
+            cancel,
+            is_finished,
+            is_detached: false,
+        };
+
+        (handle, future)
+    }
+
+    /// Cancel the task, dropping the future the next time it would be polled.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Whether the task completed, or was cancelled.
+    pub fn is_finished(&self) -> bool {
+        self.is_finished.load(Ordering::Acquire)
+    }
+
+    /// Let the task run to completion, even once the handle is dropped.
+    pub fn detach(mut self) {
+        self.is_detached = true;
+    }
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        if !self.is_detached {
+            self.cancel.cancel();
+        }
+    }
+}
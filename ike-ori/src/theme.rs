@@ -0,0 +1,279 @@
+use std::{
+    any::{Any, TypeId},
+    fmt,
+    sync::Arc,
+};
+
+use ike_core::{ColorScheme, Transition};
+use ori::{Action, Event, Provider, View, ViewMarker};
+
+use crate::{Context, Palette};
+
+trait ThemeEntry: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn boxed(&self) -> Box<dyn Any>;
+    fn dyn_eq(&self, other: &dyn ThemeEntry) -> bool;
+}
+
+impl<U> ThemeEntry for U
+where
+    U: Any + Clone + PartialEq + Send + Sync,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn boxed(&self) -> Box<dyn Any> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn ThemeEntry) -> bool {
+        other.as_any().downcast_ref::<U>() == Some(self)
+    }
+}
+
+/// A [`Palette`] together with the themes of individual views, like
+/// [`ButtonTheme`](crate::views::ButtonTheme).
+///
+/// Themes are provided to views with [`themed`], views without a theme in the [`Theme`] use
+/// their defaults.
+#[derive(Clone)]
+pub struct Theme {
+    pub palette: Palette,
+
+    /// The transition colors resolved through the theme animate with when the theme changes.
+    pub transition: Transition,
+
+    entries: Vec<(TypeId, Arc<dyn ThemeEntry>)>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new(Palette::default())
+    }
+}
+
+impl Theme {
+    pub fn new(palette: Palette) -> Self {
+        Self {
+            palette,
+            transition: Transition::ease(0.2),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self::new(Palette::dark())
+    }
+
+    pub fn light() -> Self {
+        Self::new(Palette::paper())
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Set the theme of a view, replacing any previous theme of the same type.
+    pub fn with<U>(mut self, theme: U) -> Self
+    where
+        U: Any + Clone + PartialEq + Send + Sync,
+    {
+        let type_id = TypeId::of::<U>();
+        self.entries.retain(|(id, _)| *id != type_id);
+        self.entries.push((type_id, Arc::new(theme)));
+        self
+    }
+
+    pub fn get<U: Any>(&self) -> Option<&U> {
+        let (_, entry) = self
+            .entries
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<U>())?;
+        entry.as_any().downcast_ref()
+    }
+
+    fn push(&self, cx: &mut Context, is_retheming: bool, reduce_motion: bool) {
+        let transition = if reduce_motion {
+            Transition::INSTANT
+        } else {
+            self.transition
+        };
+
+        cx.push(Box::new(self.palette.clone()));
+
+        for (type_id, entry) in &self.entries {
+            cx.resources.push_any(*type_id, entry.boxed());
+        }
+
+        cx.push(Box::new(ThemeTransition(transition)));
+        cx.push(Box::new(Retheme(is_retheming)));
+    }
+
+    fn pop(&self, cx: &mut Context) {
+        for _ in 0..self.entries.len() + 3 {
+            cx.resources.pop_any();
+        }
+    }
+}
+
+impl PartialEq for Theme {
+    fn eq(&self, other: &Self) -> bool {
+        self.palette == other.palette
+            && self.transition == other.transition
+            && self.entries.len() == other.entries.len()
+            && self.entries.iter().all(|(type_id, entry)| {
+                other
+                    .entries
+                    .iter()
+                    .any(|(id, other)| id == type_id && entry.dyn_eq(other.as_ref()))
+            })
+    }
+}
+
+impl fmt::Debug for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Theme")
+            .field("palette", &self.palette)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether the theme changed since the last rebuild.
+struct Retheme(bool);
+
+/// Whether views being rebuilt should reapply their themes, because the theme provided by
+/// [`themed`] changed.
+///
+/// Views only update widgets for properties that changed when rebuilt, and should treat every
+/// property resolved from a theme as changed while this is `true`.
+pub fn is_retheming(cx: &Context) -> bool {
+    cx.get::<Retheme>().is_some_and(|retheme| retheme.0)
+}
+
+struct ThemeTransition(Transition);
+
+/// The transition colors resolved through the current [`Theme`] should animate with.
+///
+/// This is [`Transition::INSTANT`] outside of [`themed`], or when motion is reduced, see
+/// [`Themed::reduce_motion`].
+pub fn theme_transition(cx: &Context) -> Transition {
+    cx.get::<ThemeTransition>()
+        .map_or(Transition::INSTANT, |transition| {
+            transition.0
+        })
+}
+
+/// Provide `theme` to `contents`.
+pub fn themed<V>(theme: Theme, contents: V) -> Themed<V> {
+    Themed::new(theme, contents)
+}
+
+/// Provides a [`Theme`] to its contents, optionally following the color scheme of the platform.
+///
+/// When the theme changes, either because a different theme is given or the platform switched
+/// between light and dark, the widgets of the contents are restyled in place, with colors
+/// animating over [`Theme::transition`].
+pub struct Themed<V> {
+    contents:      V,
+    theme:         Theme,
+    dark:          Option<Theme>,
+    reduce_motion: bool,
+}
+
+impl<V> Themed<V> {
+    pub fn new(theme: Theme, contents: V) -> Self {
+        Self {
+            contents,
+            theme,
+            dark: None,
+            reduce_motion: false,
+        }
+    }
+
+    /// Use `dark` instead of the theme while the platform prefers a dark color scheme.
+    pub fn dark(mut self, dark: Theme) -> Self {
+        self.dark = Some(dark);
+        self
+    }
+
+    /// Change colors instantly instead of animating them when the theme changes.
+    pub fn reduce_motion(mut self, reduce_motion: bool) -> Self {
+        self.reduce_motion = reduce_motion;
+        self
+    }
+
+    fn resolve(self, cx: &Context) -> (V, Theme, bool) {
+        let is_dark = cx.world.color_scheme() == Some(ColorScheme::Dark);
+
+        match self.dark {
+            Some(dark) if is_dark => (self.contents, dark, self.reduce_motion),
+            _ => (
+                self.contents,
+                self.theme,
+                self.reduce_motion,
+            ),
+        }
+    }
+}
+
+impl<V> ViewMarker for Themed<V> {}
+impl<T, V> View<Context, T> for Themed<V>
+where
+    V: View<Context, T>,
+{
+    type Element = V::Element;
+    type State = (Theme, bool, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, theme, reduce_motion) = self.resolve(cx);
+
+        theme.push(cx, false, reduce_motion);
+        let (element, state) = contents.build(cx, data);
+        theme.pop(cx);
+
+        (element, (theme, reduce_motion, state))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (theme, reduce_motion, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        let (contents, new_theme, new_reduce_motion) = self.resolve(cx);
+        let is_retheming = is_retheming(cx) || new_theme != *theme;
+        *theme = new_theme;
+        *reduce_motion = new_reduce_motion;
+
+        theme.push(cx, is_retheming, *reduce_motion);
+        contents.rebuild(element, state, cx, data);
+        theme.pop(cx);
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (theme, reduce_motion, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        theme.push(cx, false, *reduce_motion);
+        let action = V::event(element, state, cx, data, event);
+        theme.pop(cx);
+
+        action
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (theme, reduce_motion, state): Self::State,
+        cx: &mut Context,
+    ) {
+        theme.push(cx, false, reduce_motion);
+        V::teardown(element, state, cx);
+        theme.pop(cx);
+    }
+}
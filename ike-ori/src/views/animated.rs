@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use ike_core::{Builder, Interpolate, Transition, Transitioned, WidgetId, widgets};
+use ori::{Action, Event, Mut, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Animate `value` with `transition` whenever it changes between rebuilds.
+///
+/// The contents are built by `build` with the current value, and rebuilt every frame while
+/// the value is transitioning, so any property of a view, e.g. a size, color or padding, can
+/// be animated without writing a widget.
+pub fn animated<U, F, V>(value: U, transition: Transition, build: F) -> Animated<U, F>
+where
+    F: Fn(&U) -> V,
+{
+    Animated::new(value, transition, build)
+}
+
+pub struct Animated<U, F> {
+    value:      U,
+    transition: Transition,
+    build:      F,
+}
+
+impl<U, F> Animated<U, F> {
+    pub fn new(value: U, transition: Transition, build: F) -> Self {
+        Self {
+            value,
+            transition,
+            build,
+        }
+    }
+}
+
+struct AnimatedTick(Duration);
+
+impl<U, F> ViewMarker for Animated<U, F> {}
+impl<T, U, F, V> View<Context, T> for Animated<U, F>
+where
+    U: Interpolate + Clone + PartialEq + 'static,
+    F: Fn(&U) -> V + 'static,
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Animator>;
+    type State = (
+        ViewId,
+        Transitioned<U>,
+        F,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let value = Transitioned::new(self.value, self.transition);
+        let (contents, state) = (self.build)(&*value).build(cx, data);
+
+        let mut widget = widgets::Animator::new(cx, contents);
+        widgets::Animator::set_on_animate(&mut widget, move |dt| {
+            proxy.event(Event::new(AnimatedTick(dt), id));
+        });
+
+        (
+            widget.id(),
+            (id, value, self.build, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (_id, value, build, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        *build = self.build;
+
+        value.set_transition(self.transition);
+        let is_running = value.begin(self.value);
+
+        build(&**value).rebuild(contents, state, cx, data);
+
+        if is_running && let Ok(mut widget) = cx.get_widget_mut(*element) {
+            widgets::Animator::set_running(&mut widget, true);
+        }
+    }
+
+    fn event(
+        element: Mut<Context, Self::Element>,
+        (id, value, build, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let Some(AnimatedTick(dt)) = event.take_targeted(*id) else {
+            return V::event(contents, state, cx, data, event);
+        };
+
+        let is_running = value.animate(dt);
+        build(&**value).rebuild(contents, state, cx, data);
+
+        if !is_running && let Ok(mut widget) = cx.get_widget_mut(*element) {
+            widgets::Animator::set_running(&mut widget, false);
+        }
+
+        Action::new()
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _value, _build, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -0,0 +1,159 @@
+use ike_core::{
+    Builder, WidgetId,
+    widgets::{self, VisibilityTransition},
+};
+use ori::{Action, Event, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Animate `contents` in when they become `Some`, and out when they become `None`.
+///
+/// The contents are only removed once they've animated out.
+pub fn animated_visibility<V>(
+    contents: Option<V>,
+    enter: VisibilityTransition,
+    exit: VisibilityTransition,
+) -> AnimatedVisibility<V> {
+    AnimatedVisibility::new(contents, enter, exit)
+}
+
+pub struct AnimatedVisibility<V> {
+    contents: Option<V>,
+    enter:    VisibilityTransition,
+    exit:     VisibilityTransition,
+}
+
+impl<V> AnimatedVisibility<V> {
+    pub fn new(
+        contents: Option<V>,
+        enter: VisibilityTransition,
+        exit: VisibilityTransition,
+    ) -> Self {
+        Self {
+            contents,
+            enter,
+            exit,
+        }
+    }
+}
+
+struct Hidden;
+
+impl<V> ViewMarker for AnimatedVisibility<V> {}
+impl<T, V> View<Context, T> for AnimatedVisibility<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::AnimatedVisibility>;
+    type State = (ViewId, Option<(V::Element, V::State)>);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let element = {
+            let mut widget = widgets::AnimatedVisibility::new(cx);
+
+            widgets::AnimatedVisibility::set_enter(&mut widget, self.enter);
+            widgets::AnimatedVisibility::set_exit(&mut widget, self.exit);
+            widgets::AnimatedVisibility::set_on_hidden(&mut widget, move || {
+                proxy.event(Event::new(Hidden, id));
+            });
+
+            widget.id()
+        };
+
+        let contents = self.contents.map(|contents| {
+            let (contents, state) = contents.build(cx, data);
+            cx.add_child(element, contents);
+
+            if let Ok(mut widget) = cx.get_widget_mut(element) {
+                widgets::AnimatedVisibility::show(&mut widget);
+            }
+
+            (contents, state)
+        });
+
+        (element, (id, contents))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (_id, contents): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        if let Ok(mut widget) = cx.get_widget_mut(*element) {
+            widgets::AnimatedVisibility::set_enter(&mut widget, self.enter);
+            widgets::AnimatedVisibility::set_exit(&mut widget, self.exit);
+        }
+
+        match (self.contents, contents.as_mut()) {
+            (Some(view), Some((child, state))) => {
+                view.rebuild(child, state, cx, data);
+
+                // shown again before having animated out
+                if let Ok(mut widget) = cx.get_widget_mut(*element)
+                    && widget.widget.is_hiding()
+                {
+                    widgets::AnimatedVisibility::show(&mut widget);
+                }
+            }
+
+            (Some(view), None) => {
+                let (child, state) = view.build(cx, data);
+                cx.add_child(*element, child);
+
+                if let Ok(mut widget) = cx.get_widget_mut(*element) {
+                    widgets::AnimatedVisibility::show(&mut widget);
+                }
+
+                *contents = Some((child, state));
+            }
+
+            (None, Some(..)) => {
+                if let Ok(mut widget) = cx.get_widget_mut(*element)
+                    && !widget.widget.is_hiding()
+                {
+                    widgets::AnimatedVisibility::hide(&mut widget);
+                }
+            }
+
+            (None, None) => {}
+        }
+    }
+
+    fn event(
+        element: Mut<Context, Self::Element>,
+        (id, contents): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = match contents {
+            Some((child, state)) => V::event(child, state, cx, data, event),
+            None => Action::new(),
+        };
+
+        // the contents might have been shown again since they finished animating out
+        if event.take_targeted::<Hidden>(*id).is_some()
+            && cx
+                .get_widget_mut(*element)
+                .is_ok_and(|widget| widget.widget.is_hiding())
+            && let Some((child, state)) = contents.take()
+        {
+            V::teardown(child, state, cx);
+        }
+
+        action
+    }
+
+    fn teardown(element: Self::Element, (_id, contents): Self::State, cx: &mut Context) {
+        if let Some((child, state)) = contents {
+            V::teardown(child, state, cx);
+        }
+
+        cx.remove_widget(element);
+    }
+}
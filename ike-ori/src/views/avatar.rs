@@ -0,0 +1,263 @@
+use ike_core::{
+    Color, FontWeight, Svg, TextAlign, WidgetId,
+    widgets::{self, Fit, Picturable},
+};
+use ori::{Action, Event, Provider, View, ViewMarker};
+
+use crate::{
+    Context, Palette,
+    views::{
+        Constrain, Container, Label, Layered, Picture, ZStack, container, label, layered, picture,
+        size, zstack,
+    },
+};
+
+/// Create a circular [`Avatar`] showing either a picture or the initials of a name.
+pub fn avatar(contents: impl Into<AvatarContents>) -> Avatar {
+    Avatar::new(contents)
+}
+
+/// What an [`Avatar`] shows.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AvatarContents {
+    /// A picture, covering the avatar.
+    Picture(Picturable),
+
+    /// A name, shown as the initials of its first two words.
+    Name(String),
+}
+
+impl From<Picturable> for AvatarContents {
+    fn from(picture: Picturable) -> Self {
+        AvatarContents::Picture(picture)
+    }
+}
+
+impl From<Svg> for AvatarContents {
+    fn from(svg: Svg) -> Self {
+        AvatarContents::Picture(Picturable::Svg(svg))
+    }
+}
+
+impl From<&str> for AvatarContents {
+    fn from(name: &str) -> Self {
+        AvatarContents::Name(name.into())
+    }
+}
+
+impl From<String> for AvatarContents {
+    fn from(name: String) -> Self {
+        AvatarContents::Name(name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AvatarSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+    Custom(f32),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AvatarTheme {
+    pub small:       f32,
+    pub medium:      f32,
+    pub large:       f32,
+    pub font_scale:  f32,
+    pub font_weight: FontWeight,
+
+    /// The background of avatars showing initials, by default picked from the palette by the
+    /// name, so the same name always gets the same color.
+    pub color:      Option<Color>,
+    pub text_color: Option<Color>,
+}
+
+impl Default for AvatarTheme {
+    fn default() -> Self {
+        Self {
+            small:       24.0,
+            medium:      40.0,
+            large:       64.0,
+            font_scale:  0.4,
+            font_weight: FontWeight::MEDIUM,
+            color:       None,
+            text_color:  None,
+        }
+    }
+}
+
+pub struct Avatar {
+    contents:   AvatarContents,
+    size:       AvatarSize,
+    color:      Option<Color>,
+    text_color: Option<Color>,
+}
+
+impl Avatar {
+    pub fn new(contents: impl Into<AvatarContents>) -> Self {
+        Self {
+            contents:   contents.into(),
+            size:       AvatarSize::default(),
+            color:      None,
+            text_color: None,
+        }
+    }
+
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn small(self) -> Self {
+        self.size(AvatarSize::Small)
+    }
+
+    pub fn large(self) -> Self {
+        self.size(AvatarSize::Large)
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+}
+
+type Contents = Constrain<
+    Container<
+        ZStack<(
+            Option<Layered<Picture>>,
+            Option<Layered<Label>>,
+        )>,
+    >,
+>;
+
+impl Avatar {
+    fn get_diameter(&self, theme: &AvatarTheme) -> f32 {
+        match self.size {
+            AvatarSize::Small => theme.small,
+            AvatarSize::Medium => theme.medium,
+            AvatarSize::Large => theme.large,
+            AvatarSize::Custom(diameter) => diameter,
+        }
+    }
+
+    fn build_contents(&self, palette: &Palette, theme: &AvatarTheme) -> Contents {
+        let diameter = self.get_diameter(theme);
+
+        let (picture, name, color) = match self.contents {
+            AvatarContents::Picture(ref contents) => {
+                let picture = picture(Fit::Cover, contents.clone());
+                let color = self.color.unwrap_or(palette.surface(1));
+
+                (Some(layered(picture)), None, color)
+            }
+
+            AvatarContents::Name(ref name) => {
+                let text_color = self
+                    .text_color
+                    .unwrap_or_else(|| theme.text_color.unwrap_or(palette.background));
+
+                let initials = label(initials(name))
+                    .font_size(diameter * theme.font_scale)
+                    .font_weight(theme.font_weight)
+                    .line_height(1.0)
+                    .align(TextAlign::Center)
+                    .color(text_color);
+
+                let color = self
+                    .color
+                    .unwrap_or_else(|| theme.color.unwrap_or_else(|| name_color(palette, name)));
+
+                (
+                    None,
+                    Some(layered(initials).align(0.5, 0.5)),
+                    color,
+                )
+            }
+        };
+
+        let circle = container(zstack((picture, name)))
+            .padding(0.0)
+            .border_width(0.0)
+            .corner_radius(diameter / 2.0)
+            .background_color(color)
+            .clip_contents(true);
+
+        size([diameter, diameter], circle)
+    }
+}
+
+/// The uppercase initials of the first two words of `name`.
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Pick a color from the palette by `name`.
+fn name_color(palette: &Palette, name: &str) -> Color {
+    let colors = [
+        palette.primary,
+        palette.secondary,
+        palette.accent,
+        palette.success,
+        palette.warning,
+        palette.info,
+    ];
+
+    let hash = name.chars().fold(0u32, |hash, c| {
+        hash.wrapping_mul(31).wrapping_add(c as u32)
+    });
+
+    colors[hash as usize % colors.len()]
+}
+
+impl ViewMarker for Avatar {}
+impl<T> View<Context, T> for Avatar {
+    type Element = WidgetId<widgets::Constrain>;
+    type State = <Contents as View<Context, T>>::State;
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<AvatarTheme>();
+
+        self.build_contents(&palette, &theme).build(cx, data)
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<AvatarTheme>();
+
+        let contents = self.build_contents(&palette, &theme);
+        contents.rebuild(element, state, cx, data);
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        <Contents as View<Context, T>>::event(element, state, cx, data, event)
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context) {
+        <Contents as View<Context, T>>::teardown(element, state, cx);
+    }
+}
@@ -0,0 +1,209 @@
+use ike_core::{Color, Size, TextAlign, WidgetId, widgets};
+use ori::{Action, Event, Provider, View, ViewMarker};
+
+use crate::{
+    Context, Palette,
+    views::{
+        Constrain, Container, Label, Layered, Spacer, ZStack, container, label, layered, min_width,
+        spacer, zstack,
+    },
+};
+
+/// Show `indicator` in the top right corner of `contents`, e.g. the number of unread messages.
+pub fn badge<V>(contents: V, indicator: impl Into<BadgeIndicator>) -> Badge<V> {
+    Badge::new(contents, indicator)
+}
+
+/// What a [`Badge`] shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BadgeIndicator {
+    /// A number, the badge is hidden when it is zero.
+    Count(u32),
+
+    /// A small dot without a number.
+    Dot,
+
+    Hidden,
+}
+
+impl From<u32> for BadgeIndicator {
+    fn from(count: u32) -> Self {
+        BadgeIndicator::Count(count)
+    }
+}
+
+impl From<bool> for BadgeIndicator {
+    fn from(dot: bool) -> Self {
+        if dot {
+            BadgeIndicator::Dot
+        } else {
+            BadgeIndicator::Hidden
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgeTheme {
+    pub font_size:  f32,
+    pub padding:    f32,
+    pub dot_size:   f32,
+    pub max_count:  u32,
+    pub color:      Option<Color>,
+    pub text_color: Option<Color>,
+}
+
+impl Default for BadgeTheme {
+    fn default() -> Self {
+        Self {
+            font_size:  11.0,
+            padding:    3.0,
+            dot_size:   8.0,
+            max_count:  99,
+            color:      None,
+            text_color: None,
+        }
+    }
+}
+
+pub struct Badge<V> {
+    contents:   V,
+    indicator:  BadgeIndicator,
+    max_count:  Option<u32>,
+    color:      Option<Color>,
+    text_color: Option<Color>,
+}
+
+impl<V> Badge<V> {
+    pub fn new(contents: V, indicator: impl Into<BadgeIndicator>) -> Self {
+        Self {
+            contents,
+            indicator: indicator.into(),
+            max_count: None,
+            color: None,
+            text_color: None,
+        }
+    }
+
+    /// Counts above `max_count` are shown as `max_count+`.
+    pub fn max_count(mut self, max_count: u32) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+}
+
+type Contents<V> = ZStack<(
+    Layered<V>,
+    Option<Layered<Container<Constrain<Label>>>>,
+    Option<Layered<Container<Spacer>>>,
+)>;
+
+impl<V> Badge<V> {
+    fn build_contents(self, palette: &Palette, theme: &BadgeTheme) -> Contents<V> {
+        let color = self
+            .color
+            .unwrap_or_else(|| theme.color.unwrap_or(palette.danger));
+
+        let text_color = self
+            .text_color
+            .unwrap_or_else(|| theme.text_color.unwrap_or(palette.background));
+
+        let max_count = self.max_count.unwrap_or(theme.max_count);
+        let radius = theme.font_size / 2.0 + theme.padding;
+
+        let count = match self.indicator {
+            BadgeIndicator::Count(count) if count > 0 => {
+                let text = if count > max_count {
+                    format!("{max_count}+")
+                } else {
+                    count.to_string()
+                };
+
+                let text = label(text)
+                    .font_size(theme.font_size)
+                    .line_height(1.0)
+                    .align(TextAlign::Center)
+                    .color(text_color);
+
+                let pill = container(min_width(theme.font_size, text))
+                    .padding(theme.padding)
+                    .border_width(0.0)
+                    .corner_radius(radius)
+                    .background_color(color);
+
+                Some(layered(pill).align(1.0, 0.0).pass_through(true))
+            }
+
+            _ => None,
+        };
+
+        let dot = match self.indicator {
+            BadgeIndicator::Dot => {
+                let dot = container(spacer(Size::all(theme.dot_size)))
+                    .padding(0.0)
+                    .border_width(0.0)
+                    .corner_radius(theme.dot_size / 2.0)
+                    .background_color(color);
+
+                Some(layered(dot).align(1.0, 0.0).pass_through(true))
+            }
+
+            _ => None,
+        };
+
+        zstack((layered(self.contents), count, dot))
+    }
+}
+
+impl<V> ViewMarker for Badge<V> {}
+impl<T, V> View<Context, T> for Badge<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::ZStack>;
+    type State = <Contents<V> as View<Context, T>>::State;
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<BadgeTheme>();
+
+        self.build_contents(&palette, &theme).build(cx, data)
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<BadgeTheme>();
+
+        let contents = self.build_contents(&palette, &theme);
+        contents.rebuild(element, state, cx, data);
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        <Contents<V> as View<Context, T>>::event(element, state, cx, data, event)
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context) {
+        <Contents<V> as View<Context, T>>::teardown(element, state, cx);
+    }
+}
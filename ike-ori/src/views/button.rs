@@ -1,7 +1,7 @@
 use ike_core::{BorderWidth, Builder, Color, CornerRadius, Padding, Transition, WidgetId, widgets};
 use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
 
-use crate::{Context, Palette};
+use crate::{Context, Palette, is_retheming};
 
 pub fn button<T, V, A>(contents: V, on_click: impl FnMut(&mut T) -> A + 'static) -> Button<T, V>
 where
@@ -10,7 +10,7 @@ where
     Button::new(contents, on_click)
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ButtonTheme {
     pub padding:       Padding,
     pub border_width:  BorderWidth,
@@ -20,6 +20,8 @@ pub struct ButtonTheme {
     pub active_color:  Option<Color>,
     pub border_color:  Option<Color>,
     pub focus_color:   Option<Color>,
+    pub elevation:     f32,
+    pub shadow_color:  Option<Color>,
     pub transition:    Transition,
 }
 
@@ -34,6 +36,8 @@ impl Default for ButtonTheme {
             active_color:  None,
             border_color:  None,
             focus_color:   None,
+            elevation:     0.0,
+            shadow_color:  None,
             transition:    Transition::ease(0.05),
         }
     }
@@ -61,6 +65,8 @@ impl<T, V> Button<T, V> {
                 active_color:  None,
                 border_color:  None,
                 focus_color:   None,
+                elevation:     None,
+                shadow_color:  None,
                 transition:    None,
             },
         }
@@ -117,6 +123,17 @@ impl<T, V> Button<T, V> {
         self.properties.focus_color = Some(color);
         self
     }
+
+    /// Lift the button `elevation` above its parent, casting a shadow.
+    pub fn elevation(mut self, elevation: f32) -> Self {
+        self.properties.elevation = Some(elevation);
+        self
+    }
+
+    pub fn shadow_color(mut self, color: Color) -> Self {
+        self.properties.shadow_color = Some(color);
+        self
+    }
 }
 
 enum ButtonEvent {
@@ -134,6 +151,8 @@ pub struct Properties<T> {
     active_color:  Option<Color>,
     border_color:  Option<Color>,
     focus_color:   Option<Color>,
+    elevation:     Option<f32>,
+    shadow_color:  Option<Color>,
     transition:    Option<Transition>,
 }
 
@@ -181,6 +200,15 @@ impl<T> Properties<T> {
             .unwrap_or_else(|| theme.focus_color.unwrap_or(palette.info))
     }
 
+    fn get_elevation(&self, theme: &ButtonTheme) -> f32 {
+        self.elevation.unwrap_or(theme.elevation)
+    }
+
+    fn get_shadow_color(&self, theme: &ButtonTheme, palette: &Palette) -> Color {
+        self.shadow_color
+            .unwrap_or_else(|| theme.shadow_color.unwrap_or_else(|| palette.shadow()))
+    }
+
     fn get_transition(&self, theme: &ButtonTheme) -> Transition {
         self.transition.unwrap_or(theme.transition)
     }
@@ -213,6 +241,8 @@ where
         let active_color = self.properties.get_active_color(&theme, &palette);
         let border_color = self.properties.get_border_color(&theme, &palette);
         let focus_color = self.properties.get_focus_color(&theme, &palette);
+        let elevation = self.properties.get_elevation(&theme);
+        let shadow_color = self.properties.get_shadow_color(&theme, &palette);
         let transition = self.properties.get_transition(&theme);
 
         let (contents, state) = self.contents.build(cx, data);
@@ -226,6 +256,8 @@ where
         widgets::Button::set_active_color(&mut widget, active_color);
         widgets::Button::set_border_color(&mut widget, border_color);
         widgets::Button::set_focus_color(&mut widget, focus_color);
+        widgets::Button::set_elevation(&mut widget, elevation);
+        widgets::Button::set_shadow_color(&mut widget, shadow_color);
         widgets::Button::set_transition(&mut widget, transition);
 
         widgets::Button::set_on_click(&mut widget, move || {
@@ -249,52 +281,63 @@ where
 
         let palette = cx.get_or_default::<Palette>();
         let theme = cx.get_or_default::<ButtonTheme>();
+        let retheme = is_retheming(cx);
 
         let Ok(mut widget) = cx.get_widget_mut(*element) else {
             return;
         };
 
-        if self.properties.padding != properties.padding {
+        if retheme || self.properties.padding != properties.padding {
             let padding = self.properties.get_padding(&theme);
             widgets::Button::set_padding(&mut widget, padding);
         }
 
-        if self.properties.border_width != properties.border_width {
+        if retheme || self.properties.border_width != properties.border_width {
             let border_width = self.properties.get_border_width(&theme);
             widgets::Button::set_border_width(&mut widget, border_width);
         }
 
-        if self.properties.corner_radius != properties.corner_radius {
+        if retheme || self.properties.corner_radius != properties.corner_radius {
             let corner_radius = self.properties.get_corner_radius(&theme);
             widgets::Button::set_corner_radius(&mut widget, corner_radius);
         }
 
-        if self.properties.idle_color != properties.idle_color {
+        if retheme || self.properties.idle_color != properties.idle_color {
             let idle_color = self.properties.get_idle_color(&theme, &palette);
             widgets::Button::set_idle_color(&mut widget, idle_color);
         }
 
-        if self.properties.hovered_color != properties.hovered_color {
+        if retheme || self.properties.hovered_color != properties.hovered_color {
             let hovered_color = self.properties.get_hovered_color(&theme, &palette);
             widgets::Button::set_hovered_color(&mut widget, hovered_color);
         }
 
-        if self.properties.active_color != properties.active_color {
+        if retheme || self.properties.active_color != properties.active_color {
             let active_color = self.properties.get_active_color(&theme, &palette);
             widgets::Button::set_active_color(&mut widget, active_color);
         }
 
-        if self.properties.border_color != properties.border_color {
+        if retheme || self.properties.border_color != properties.border_color {
             let border_color = self.properties.get_border_color(&theme, &palette);
             widgets::Button::set_border_color(&mut widget, border_color);
         }
 
-        if self.properties.focus_color != properties.focus_color {
+        if retheme || self.properties.focus_color != properties.focus_color {
             let focus_color = self.properties.get_focus_color(&theme, &palette);
             widgets::Button::set_focus_color(&mut widget, focus_color);
         }
 
-        if self.properties.transition != properties.transition {
+        if retheme || self.properties.elevation != properties.elevation {
+            let elevation = self.properties.get_elevation(&theme);
+            widgets::Button::set_elevation(&mut widget, elevation);
+        }
+
+        if retheme || self.properties.shadow_color != properties.shadow_color {
+            let shadow_color = self.properties.get_shadow_color(&theme, &palette);
+            widgets::Button::set_shadow_color(&mut widget, shadow_color);
+        }
+
+        if retheme || self.properties.transition != properties.transition {
             let transition = self.properties.get_transition(&theme);
             widgets::Button::set_transition(&mut widget, transition);
         }
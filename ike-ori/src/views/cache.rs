@@ -0,0 +1,90 @@
+use ike_core::{Builder, WidgetId, widgets};
+use ori::{Action, Event, Mut, View, ViewMarker};
+
+use crate::Context;
+
+/// Rasterize `contents` once, and draw the image until they change.
+///
+/// This cuts the cost of drawing large contents that rarely change, e.g. long prose in a
+/// scroll view, at the cost of the memory the image takes.
+pub fn cache<V>(contents: V) -> Cache<V> {
+    Cache::new(contents)
+}
+
+pub struct Cache<V> {
+    contents: V,
+    enabled:  bool,
+}
+
+impl<V> Cache<V> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            enabled: true,
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<V> ViewMarker for Cache<V> {}
+impl<T, V> View<Context, T> for Cache<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Cache>;
+    type State = (bool, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = widgets::Cache::new(cx, contents);
+        widgets::Cache::set_enabled(&mut widget, self.enabled);
+
+        (
+            widget.id(),
+            (self.enabled, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (enabled, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.enabled != *enabled {
+            *enabled = self.enabled;
+            widgets::Cache::set_enabled(&mut widget, self.enabled);
+        }
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_enabled, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_enabled, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
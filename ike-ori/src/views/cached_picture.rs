@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+use ike_core::{
+    Builder, Color, Svg, WidgetId,
+    widgets::{self, Fit, Picturable},
+};
+use ori::{Action, Event, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, ImageCache};
+
+/// Create a [`CachedPicture`], showing the image at `path` once `cache` has loaded it.
+pub fn cached_picture(cache: &ImageCache, fit: Fit, path: impl Into<PathBuf>) -> CachedPicture {
+    CachedPicture::new(cache, fit, path)
+}
+
+/// A picture loaded in the background through an [`ImageCache`].
+///
+/// The placeholder is shown until the image has been loaded, or if loading it failed.
+pub struct CachedPicture {
+    cache:       ImageCache,
+    path:        PathBuf,
+    fit:         Fit,
+    color:       Option<Color>,
+    placeholder: Option<Picturable>,
+}
+
+impl CachedPicture {
+    pub fn new(cache: &ImageCache, fit: Fit, path: impl Into<PathBuf>) -> Self {
+        Self {
+            cache: cache.clone(),
+            path: path.into(),
+            fit,
+            color: None,
+            placeholder: None,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<Picturable>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    fn contents(&self, cx: &mut Context, id: ViewId) -> Picturable {
+        let proxy = cx.proxy();
+        let loaded = self.cache.load(self.path.clone(), move || {
+            proxy.event(Event::new(Loaded, id));
+        });
+
+        match loaded {
+            Some(svg) => Picturable::from(svg),
+            None => self.placeholder(),
+        }
+    }
+
+    fn placeholder(&self) -> Picturable {
+        match self.placeholder {
+            Some(ref placeholder) => placeholder.clone(),
+            None => Picturable::from(Svg::from_bytes(&[])),
+        }
+    }
+}
+
+struct Loaded;
+
+impl ViewMarker for CachedPicture {}
+impl<T> View<Context, T> for CachedPicture {
+    type Element = WidgetId<widgets::Picture>;
+    type State = (ViewId, Self);
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let id = ViewId::next();
+
+        let contents = self.contents(cx, id);
+        let mut widget = widgets::Picture::new(cx, contents);
+        widgets::Picture::set_fit(&mut widget, self.fit);
+        widgets::Picture::set_color(&mut widget, self.color);
+
+        (widget.id(), (id, self))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (id, picture): &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        let contents = match self.path != picture.path {
+            true => Some(self.contents(cx, *id)),
+            false => None,
+        };
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if let Some(contents) = contents {
+            widgets::Picture::set_contents(&mut widget, contents);
+        }
+
+        if self.fit != picture.fit {
+            widgets::Picture::set_fit(&mut widget, self.fit);
+        }
+
+        if self.color != picture.color {
+            widgets::Picture::set_color(&mut widget, self.color);
+        }
+
+        *picture = self;
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (id, picture): &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        if matches!(event.take_targeted(*id), Some(Loaded))
+            && let Some(svg) = picture.cache.get(&picture.path)
+            && let Ok(mut widget) = cx.get_widget_mut(*element)
+        {
+            widgets::Picture::set_contents(&mut widget, Picturable::from(svg));
+        }
+
+        Action::new()
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context) {
+        cx.remove_widget(element);
+    }
+}
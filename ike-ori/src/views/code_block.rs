@@ -0,0 +1,391 @@
+use std::ops::Range;
+
+use ike_core::{
+    BorderWidth, Builder, Color, CornerRadius, FontWeight, Padding, Paint, Paragraph, TextAlign,
+    TextStyle, TextWrap, WidgetId, widgets,
+};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Palette, is_retheming,
+    views::{TextTheme, scroll::ScrollTheme},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodeBlockTheme {
+    pub font_size:        Option<f32>,
+    pub font_family:      String,
+    pub line_height:      Option<f32>,
+    pub color:            Option<Color>,
+    pub selection_color:  Option<Color>,
+    pub background_color: Option<Color>,
+    pub border_color:     Option<Color>,
+    pub button_color:     Option<Color>,
+    pub padding:          Padding,
+    pub border_width:     BorderWidth,
+    pub corner_radius:    CornerRadius,
+    pub copy_label:       String,
+}
+
+impl Default for CodeBlockTheme {
+    fn default() -> Self {
+        Self {
+            font_size:        None,
+            font_family:      String::from("monospace"),
+            line_height:      None,
+            color:            None,
+            selection_color:  None,
+            background_color: None,
+            border_color:     None,
+            button_color:     None,
+            padding:          Padding::all(12.0),
+            border_width:     BorderWidth::all(1.0),
+            corner_radius:    CornerRadius::all(8.0),
+            copy_label:       String::from("Copy"),
+        }
+    }
+}
+
+/// A read-only block of `text` in a monospace font, scrolled horizontally when it doesn't fit,
+/// with a button copying it to the clipboard shown while it's hovered.
+///
+/// `language` is only used by the [`CodeBlock::highlight`] function.
+pub fn code_block(text: impl Into<String>, language: impl Into<String>) -> CodeBlock {
+    CodeBlock::new(text, language)
+}
+
+pub struct CodeBlock {
+    text:             String,
+    language:         String,
+    font_size:        Option<f32>,
+    color:            Option<Color>,
+    background_color: Option<Color>,
+
+    #[allow(clippy::type_complexity)]
+    highlight: Option<Box<dyn Fn(&str, &str) -> Vec<(Range<usize>, Color)>>>,
+}
+
+impl CodeBlock {
+    pub fn new(text: impl Into<String>, language: impl Into<String>) -> Self {
+        Self {
+            text:             text.into(),
+            language:         language.into(),
+            font_size:        None,
+            color:            None,
+            background_color: None,
+
+            highlight: None,
+        }
+    }
+
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Highlight the syntax of the code, `highlight` is called with the text and the language,
+    /// and returns the colors of ranges of the text. Text outside the ranges uses the regular
+    /// color.
+    ///
+    /// Only called when the text, the language or the theme changes.
+    pub fn highlight(
+        mut self,
+        highlight: impl Fn(&str, &str) -> Vec<(Range<usize>, Color)> + 'static,
+    ) -> Self {
+        self.highlight = Some(Box::new(highlight));
+        self
+    }
+}
+
+impl CodeBlock {
+    fn build_paragraph(
+        &self,
+        palette: &Palette,
+        text_theme: &TextTheme,
+        theme: &CodeBlockTheme,
+    ) -> Paragraph {
+        let style = TextStyle {
+            font_size:    self
+                .font_size
+                .unwrap_or_else(|| theme.font_size.unwrap_or(text_theme.font_size)),
+            font_family:  theme.font_family.clone(),
+            font_weight:  FontWeight::NORMAL,
+            font_stretch: text_theme.font_stretch,
+            font_style:   text_theme.font_style,
+            paint:        Paint::from(self.get_color(palette, text_theme, theme)),
+        };
+
+        let mut paragraph = Paragraph::new(
+            theme.line_height.unwrap_or(text_theme.line_height),
+            TextAlign::Start,
+            TextWrap::None,
+        );
+
+        let mut spans = match self.highlight {
+            Some(ref highlight) => highlight(&self.text, &self.language),
+            None => Vec::new(),
+        };
+
+        spans.sort_by_key(|(range, _)| range.start);
+
+        let mut end = 0;
+
+        for (range, color) in spans {
+            // skip spans overlapping the previous ones, or splitting characters
+            if range.start < end
+                || range.end > self.text.len()
+                || !self.text.is_char_boundary(range.start)
+                || !self.text.is_char_boundary(range.end)
+            {
+                continue;
+            }
+
+            if range.start > end {
+                paragraph.push(
+                    &self.text[end..range.start],
+                    style.clone(),
+                );
+            }
+
+            let mut highlighted = style.clone();
+            highlighted.paint = Paint::from(color);
+
+            paragraph.push(&self.text[range.clone()], highlighted);
+            end = range.end;
+        }
+
+        if end < self.text.len() || self.text.is_empty() {
+            paragraph.push(&self.text[end..], style);
+        }
+
+        paragraph
+    }
+
+    fn build_label(&self, palette: &Palette, text_theme: &TextTheme, label: &str) -> Paragraph {
+        let style = TextStyle {
+            font_size:    text_theme.font_size * 0.8,
+            font_family:  text_theme.font_family.clone().into_owned(),
+            font_weight:  FontWeight::MEDIUM,
+            font_stretch: text_theme.font_stretch,
+            font_style:   text_theme.font_style,
+            paint:        Paint::from(palette.contrast),
+        };
+
+        let mut paragraph = Paragraph::new(
+            text_theme.line_height,
+            TextAlign::Start,
+            TextWrap::None,
+        );
+
+        paragraph.push(label, style);
+        paragraph
+    }
+
+    fn get_color(
+        &self,
+        palette: &Palette,
+        text_theme: &TextTheme,
+        theme: &CodeBlockTheme,
+    ) -> Color {
+        self.color.unwrap_or_else(|| {
+            theme
+                .color
+                .unwrap_or_else(|| text_theme.color.unwrap_or(palette.contrast))
+        })
+    }
+
+    fn get_background_color(&self, palette: &Palette, theme: &CodeBlockTheme) -> Color {
+        self.background_color.unwrap_or_else(|| {
+            theme
+                .background_color
+                .unwrap_or_else(|| palette.surface(-1))
+        })
+    }
+
+    fn apply_theme(
+        &self,
+        cx: &mut Context,
+        state: &CodeBlockState,
+        palette: &Palette,
+        text_theme: &TextTheme,
+        theme: &CodeBlockTheme,
+    ) {
+        let scroll_theme = cx.get_or_default::<ScrollTheme>();
+
+        if let Ok(mut text_area) = cx.get_widget_mut(state.text_area) {
+            let selection_color = theme.selection_color.unwrap_or(palette.info);
+            widgets::TextArea::set_selection_color(&mut text_area, selection_color);
+        }
+
+        if let Ok(mut scroll) = cx.get_widget_mut(state.scroll) {
+            let knob_color = scroll_theme.knob_color.unwrap_or(palette.contrast);
+
+            widgets::Scroll::set_bar_thickness(&mut scroll, scroll_theme.bar_width);
+            widgets::Scroll::set_bar_padding(&mut scroll, scroll_theme.bar_padding);
+            widgets::Scroll::set_knob_corner_radius(
+                &mut scroll,
+                scroll_theme.knob_corner_radius,
+            );
+            widgets::Scroll::set_bar_paint(&mut scroll, Color::TRANSPARENT.into());
+            widgets::Scroll::set_knob_paint(&mut scroll, knob_color.into());
+            widgets::Scroll::set_transition(&mut scroll, scroll_theme.transition);
+        }
+
+        if let Ok(mut container) = cx.get_widget_mut(state.container) {
+            let background_color = self.get_background_color(palette, theme);
+            let border_color = theme.border_color.unwrap_or(palette.outline);
+
+            widgets::Container::set_padding(&mut container, theme.padding);
+            widgets::Container::set_border_width(&mut container, theme.border_width);
+            widgets::Container::set_corner_radius(&mut container, theme.corner_radius);
+            widgets::Container::set_background_color(&mut container, background_color);
+            widgets::Container::set_border_color(&mut container, border_color);
+        }
+
+        if let Ok(mut button) = cx.get_widget_mut(state.button) {
+            let color = theme.button_color.unwrap_or_else(|| palette.surface(1));
+
+            widgets::Button::set_padding(&mut button, Padding::all(4.0));
+            widgets::Button::set_border_width(&mut button, BorderWidth::all(0.0));
+            widgets::Button::set_corner_radius(&mut button, CornerRadius::all(4.0));
+            widgets::Button::set_idle_color(&mut button, color);
+            widgets::Button::set_hovered_color(&mut button, color.lighten(0.08));
+            widgets::Button::set_active_color(&mut button, color.lighten(0.04));
+        }
+
+        let label = self.build_label(palette, text_theme, &theme.copy_label);
+
+        if let Ok(mut widget) = cx.get_widget_mut(state.label) {
+            widgets::Label::set_text(&mut widget, label);
+        }
+    }
+}
+
+struct CopyCode;
+
+pub struct CodeBlockState {
+    id:        ViewId,
+    code:      CodeBlock,
+    text_area: WidgetId<widgets::TextArea<false>>,
+    scroll:    WidgetId<widgets::Scroll>,
+    container: WidgetId<widgets::Container>,
+    button:    WidgetId<widgets::Button>,
+    label:     WidgetId<widgets::Label>,
+}
+
+impl ViewMarker for CodeBlock {}
+impl<T> View<Context, T> for CodeBlock {
+    type Element = WidgetId<widgets::CodeBlock>;
+    type State = CodeBlockState;
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let text_theme = cx.get_or_default::<TextTheme>();
+        let theme = cx.get_or_default::<CodeBlockTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let paragraph = self.build_paragraph(&palette, &text_theme, &theme);
+        let text_area = widgets::TextArea::<false>::new(cx, paragraph).id();
+
+        // indentation is preserved by never wrapping, so the text scrolls instead
+        let mut scroll = widgets::Scroll::new(cx, text_area);
+        widgets::Scroll::set_vertical(&mut scroll, false);
+        widgets::Scroll::set_horizontal(&mut scroll, true);
+        widgets::Scroll::set_overlay(&mut scroll, true);
+        let scroll = scroll.id();
+
+        let container = widgets::Container::new(cx, scroll).id();
+
+        let label = self.build_label(&palette, &text_theme, &theme.copy_label);
+        let label = widgets::Label::new(cx, label).id();
+
+        let mut button = widgets::Button::new(cx, label);
+        widgets::Button::set_on_click(&mut button, move || {
+            proxy.event(Event::new(CopyCode, id));
+        });
+        let button = button.id();
+
+        let element = widgets::CodeBlock::new(cx, container, button).id();
+
+        let state = CodeBlockState {
+            id,
+            code: self,
+            text_area,
+            scroll,
+            container,
+            button,
+            label,
+        };
+
+        (state.code).apply_theme(
+            cx,
+            &state,
+            &palette,
+            &text_theme,
+            &theme,
+        );
+
+        (element, state)
+    }
+
+    fn rebuild(
+        self,
+        _element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        let palette = cx.get_or_default::<Palette>();
+        let text_theme = cx.get_or_default::<TextTheme>();
+        let theme = cx.get_or_default::<CodeBlockTheme>();
+        let retheme = is_retheming(cx);
+
+        if retheme
+            || self.text != state.code.text
+            || self.language != state.code.language
+            || self.font_size != state.code.font_size
+            || self.color != state.code.color
+        {
+            let paragraph = self.build_paragraph(&palette, &text_theme, &theme);
+
+            if let Ok(mut text_area) = cx.get_widget_mut(state.text_area) {
+                widgets::TextArea::set_text(&mut text_area, paragraph);
+            }
+        }
+
+        if retheme || self.background_color != state.code.background_color {
+            self.apply_theme(cx, state, &palette, &text_theme, &theme);
+        }
+
+        state.code = self;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        if event.take_targeted::<CopyCode>(state.id).is_some() {
+            cx.set_clipboard(state.code.text.clone());
+        }
+
+        Action::new()
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context) {
+        cx.remove_widget(element);
+    }
+}
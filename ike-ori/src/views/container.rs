@@ -3,19 +3,21 @@ use ike_core::{
 };
 use ori::{Action, Event, Provider, View, ViewMarker};
 
-use crate::{Context, Palette};
+use crate::{Context, Palette, is_retheming, theme_transition};
 
 pub fn container<V>(contents: V) -> Container<V> {
     Container::new(contents)
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ContainerTheme {
     pub padding:          Padding,
     pub border_width:     BorderWidth,
     pub corner_radius:    CornerRadius,
     pub background_color: Option<Color>,
     pub border_color:     Option<Color>,
+    pub elevation:        f32,
+    pub shadow_color:     Option<Color>,
 }
 
 impl Default for ContainerTheme {
@@ -26,6 +28,8 @@ impl Default for ContainerTheme {
             corner_radius:    CornerRadius::all(8.0),
             background_color: None,
             border_color:     None,
+            elevation:        0.0,
+            shadow_color:     None,
         }
     }
 }
@@ -46,6 +50,9 @@ impl<V> Container<V> {
                 corner_radius:    None,
                 background_color: None,
                 border_color:     None,
+                elevation:        None,
+                shadow_color:     None,
+                clip_contents:    false,
             },
         }
     }
@@ -74,6 +81,23 @@ impl<V> Container<V> {
         self.properties.border_color = Some(color);
         self
     }
+
+    /// Lift the container `elevation` above its parent, casting a shadow.
+    pub fn elevation(mut self, elevation: f32) -> Self {
+        self.properties.elevation = Some(elevation);
+        self
+    }
+
+    pub fn shadow_color(mut self, color: Color) -> Self {
+        self.properties.shadow_color = Some(color);
+        self
+    }
+
+    /// Clip the contents to the rounded corners of the container.
+    pub fn clip_contents(mut self, clip_contents: bool) -> Self {
+        self.properties.clip_contents = clip_contents;
+        self
+    }
 }
 
 pub struct Properties {
@@ -82,6 +106,9 @@ pub struct Properties {
     corner_radius:    Option<CornerRadius>,
     background_color: Option<Color>,
     border_color:     Option<Color>,
+    elevation:        Option<f32>,
+    shadow_color:     Option<Color>,
+    clip_contents:    bool,
 }
 
 impl Properties {
@@ -106,6 +133,15 @@ impl Properties {
         self.border_color
             .unwrap_or_else(|| theme.border_color.unwrap_or(palette.outline))
     }
+
+    fn get_elevation(&self, theme: &ContainerTheme) -> f32 {
+        self.elevation.unwrap_or(theme.elevation)
+    }
+
+    fn get_shadow_color(&self, theme: &ContainerTheme, palette: &Palette) -> Color {
+        self.shadow_color
+            .unwrap_or_else(|| theme.shadow_color.unwrap_or_else(|| palette.shadow()))
+    }
 }
 
 impl<V> ViewMarker for Container<V> {}
@@ -121,6 +157,7 @@ where
 
         let palette = cx.get_or_default::<Palette>();
         let theme = cx.get_or_default::<ContainerTheme>();
+        let transition = theme_transition(cx);
 
         let mut widget = widgets::Container::new(cx, contents.upcast());
 
@@ -129,12 +166,21 @@ where
         let corner_radius = self.properties.get_corner_radius(&theme);
         let background_color = self.properties.get_background_color(&theme, &palette);
         let border_color = self.properties.get_border_color(&theme, &palette);
+        let elevation = self.properties.get_elevation(&theme);
+        let shadow_color = self.properties.get_shadow_color(&theme, &palette);
 
         widgets::Container::set_padding(&mut widget, padding);
         widgets::Container::set_border_width(&mut widget, border_width);
         widgets::Container::set_corner_radius(&mut widget, corner_radius);
         widgets::Container::set_background_color(&mut widget, background_color);
         widgets::Container::set_border_color(&mut widget, border_color);
+        widgets::Container::set_elevation(&mut widget, elevation);
+        widgets::Container::set_shadow_color(&mut widget, shadow_color);
+        widgets::Container::set_clip_contents(
+            &mut widget,
+            self.properties.clip_contents,
+        );
+        widgets::Container::set_color_transition(&mut widget, transition);
 
         (
             widget.id(),
@@ -153,36 +199,59 @@ where
 
         let palette = cx.get_or_default::<Palette>();
         let theme = cx.get_or_default::<ContainerTheme>();
+        let retheme = is_retheming(cx);
+        let transition = theme_transition(cx);
 
         let Ok(mut widget) = cx.get_widget_mut(*element) else {
             return;
         };
 
-        if self.properties.padding != properties.padding {
+        if retheme {
+            widgets::Container::set_color_transition(&mut widget, transition);
+        }
+
+        if retheme || self.properties.padding != properties.padding {
             let padding = self.properties.get_padding(&theme);
             widgets::Container::set_padding(&mut widget, padding);
         }
 
-        if self.properties.border_width != properties.border_width {
+        if retheme || self.properties.border_width != properties.border_width {
             let border_width = self.properties.get_border_width(&theme);
             widgets::Container::set_border_width(&mut widget, border_width);
         }
 
-        if self.properties.corner_radius != properties.corner_radius {
+        if retheme || self.properties.corner_radius != properties.corner_radius {
             let corner_radius = self.properties.get_corner_radius(&theme);
             widgets::Container::set_corner_radius(&mut widget, corner_radius);
         }
 
-        if self.properties.background_color != properties.background_color {
+        if retheme || self.properties.background_color != properties.background_color {
             let background = self.properties.get_background_color(&theme, &palette);
             widgets::Container::set_background_color(&mut widget, background);
         }
 
-        if self.properties.border_color != properties.border_color {
+        if retheme || self.properties.border_color != properties.border_color {
             let border_color = self.properties.get_border_color(&theme, &palette);
             widgets::Container::set_border_color(&mut widget, border_color);
         }
 
+        if retheme || self.properties.elevation != properties.elevation {
+            let elevation = self.properties.get_elevation(&theme);
+            widgets::Container::set_elevation(&mut widget, elevation);
+        }
+
+        if retheme || self.properties.shadow_color != properties.shadow_color {
+            let shadow_color = self.properties.get_shadow_color(&theme, &palette);
+            widgets::Container::set_shadow_color(&mut widget, shadow_color);
+        }
+
+        if self.properties.clip_contents != properties.clip_contents {
+            widgets::Container::set_clip_contents(
+                &mut widget,
+                self.properties.clip_contents,
+            );
+        }
+
         *properties = self.properties;
     }
 
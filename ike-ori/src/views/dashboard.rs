@@ -0,0 +1,480 @@
+use std::mem;
+
+use ike_core::{
+    AnyWidgetId, Builder, Color, Transition, WidgetId,
+    widgets::{self, Tile},
+};
+use ori::{
+    Action, Element, Elements, Event, Provider, Proxied, Proxy, Super, View, ViewId, ViewMarker,
+    ViewSeq,
+};
+
+use crate::{Context, Palette, is_retheming};
+
+/// Create a [`Dashboard`] with `columns`, with `contents` placed with [`tile`].
+pub fn dashboard<T, V>(columns: usize, contents: V) -> Dashboard<T, V> {
+    Dashboard::new(columns, contents)
+}
+
+/// Place `contents` on `tile` of a [`Dashboard`].
+pub fn tile<V>(tile: Tile, contents: V) -> Tiled<V> {
+    Tiled::new(tile, contents)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DashboardTheme {
+    pub row_height:        f32,
+    pub gap:               f32,
+    pub transition:        Transition,
+    pub placeholder_color: Option<Color>,
+}
+
+impl Default for DashboardTheme {
+    fn default() -> Self {
+        Self {
+            row_height:        80.0,
+            gap:               8.0,
+            transition:        Transition::ease(0.2),
+            placeholder_color: None,
+        }
+    }
+}
+
+pub struct Dashboard<T, V> {
+    contents:   V,
+    properties: Properties<T>,
+}
+
+impl<T, V> Dashboard<T, V> {
+    pub fn new(columns: usize, contents: V) -> Self {
+        Self {
+            contents,
+            properties: Properties {
+                on_change: Box::new(|_, _| Action::new()),
+
+                columns,
+                resizable: true,
+                row_height: None,
+                gap: None,
+                transition: None,
+                placeholder_color: None,
+            },
+        }
+    }
+
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.properties.resizable = resizable;
+        self
+    }
+
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.properties.row_height = Some(row_height);
+        self
+    }
+
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.properties.gap = Some(gap);
+        self
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.properties.transition = Some(transition);
+        self
+    }
+
+    pub fn placeholder_color(mut self, color: Color) -> Self {
+        self.properties.placeholder_color = Some(color);
+        self
+    }
+
+    /// Called with every tile when the user has rearranged them.
+    ///
+    /// The tiles are expected to be stored and passed back with [`tile`], otherwise they are
+    /// reset on the next rebuild.
+    pub fn on_change<A>(
+        mut self,
+        mut on_change: impl FnMut(&mut T, Vec<Tile>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_change = Box::new(move |data, tiles| on_change(data, tiles).into());
+        self
+    }
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_change: Box<dyn FnMut(&mut T, Vec<Tile>) -> Action>,
+
+    columns:           usize,
+    resizable:         bool,
+    row_height:        Option<f32>,
+    gap:               Option<f32>,
+    transition:        Option<Transition>,
+    placeholder_color: Option<Color>,
+}
+
+impl<T> Properties<T> {
+    fn get_row_height(&self, theme: &DashboardTheme) -> f32 {
+        self.row_height.unwrap_or(theme.row_height)
+    }
+
+    fn get_gap(&self, theme: &DashboardTheme) -> f32 {
+        self.gap.unwrap_or(theme.gap)
+    }
+
+    fn get_transition(&self, theme: &DashboardTheme) -> Transition {
+        self.transition.unwrap_or(theme.transition)
+    }
+
+    fn get_placeholder_color(&self, theme: &DashboardTheme, palette: &Palette) -> Color {
+        self.placeholder_color.unwrap_or_else(|| {
+            theme
+                .placeholder_color
+                .unwrap_or_else(|| palette.primary.fade(0.2))
+        })
+    }
+}
+
+struct Rearranged(Vec<Tile>);
+
+impl<T, V> ViewMarker for Dashboard<T, V> {}
+impl<T, V> View<Context, T> for Dashboard<T, V>
+where
+    V: ViewSeq<Context, T, Tiled<WidgetId>>,
+{
+    type Element = WidgetId<widgets::Dashboard>;
+    type State = (
+        ViewId,
+        Properties<T>,
+        Vec<Tiled<WidgetId>>,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<DashboardTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let row_height = self.properties.get_row_height(&theme);
+        let gap = self.properties.get_gap(&theme);
+        let transition = self.properties.get_transition(&theme);
+        let placeholder_color = self.properties.get_placeholder_color(&theme, &palette);
+
+        let element = {
+            let mut widget = widgets::Dashboard::new(cx);
+
+            widgets::Dashboard::set_columns(&mut widget, self.properties.columns);
+            widgets::Dashboard::set_resizable(&mut widget, self.properties.resizable);
+            widgets::Dashboard::set_row_height(&mut widget, row_height);
+            widgets::Dashboard::set_gap(&mut widget, gap);
+            widgets::Dashboard::set_transition(&mut widget, transition);
+            widgets::Dashboard::set_placeholder_color(&mut widget, placeholder_color);
+
+            widgets::Dashboard::set_on_change(&mut widget, move |tiles| {
+                proxy.event(Event::new(
+                    Rearranged(tiles.to_vec()),
+                    id,
+                ));
+            });
+
+            widget.id()
+        };
+
+        let mut elements = Vec::new();
+        let states = self.contents.seq_build(
+            &mut TiledElements::new(element, &mut elements),
+            cx,
+            data,
+        );
+
+        (
+            element,
+            (id, self.properties, elements, states),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (_id, properties, elements, states): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.seq_rebuild(
+            &mut TiledElements::new(*element, elements),
+            states,
+            cx,
+            data,
+        );
+
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<DashboardTheme>();
+        let retheme = is_retheming(cx);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.properties.columns != properties.columns {
+            widgets::Dashboard::set_columns(&mut widget, self.properties.columns);
+        }
+
+        if self.properties.resizable != properties.resizable {
+            widgets::Dashboard::set_resizable(&mut widget, self.properties.resizable);
+        }
+
+        if retheme || self.properties.row_height != properties.row_height {
+            let row_height = self.properties.get_row_height(&theme);
+            widgets::Dashboard::set_row_height(&mut widget, row_height);
+        }
+
+        if retheme || self.properties.gap != properties.gap {
+            let gap = self.properties.get_gap(&theme);
+            widgets::Dashboard::set_gap(&mut widget, gap);
+        }
+
+        if retheme || self.properties.transition != properties.transition {
+            let transition = self.properties.get_transition(&theme);
+            widgets::Dashboard::set_transition(&mut widget, transition);
+        }
+
+        if retheme || self.properties.placeholder_color != properties.placeholder_color {
+            let placeholder_color = self.properties.get_placeholder_color(&theme, &palette);
+            widgets::Dashboard::set_placeholder_color(&mut widget, placeholder_color);
+        }
+
+        *properties = self.properties;
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (id, properties, elements, states): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::seq_event(
+            &mut TiledElements::new(*element, elements),
+            states,
+            cx,
+            data,
+            event,
+        );
+
+        match event.take_targeted(*id) {
+            Some(Rearranged(tiles)) => action | (properties.on_change)(data, tiles),
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _properties, mut elements, states): Self::State,
+        cx: &mut Context,
+    ) {
+        V::seq_teardown(
+            &mut TiledElements::new(element, &mut elements),
+            states,
+            cx,
+        );
+
+        cx.remove_widget(element);
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Tiled<V> {
+    contents: V,
+    tile:     Tile,
+}
+
+impl<V> Tiled<V> {
+    pub fn new(tile: Tile, contents: V) -> Self {
+        Self { contents, tile }
+    }
+}
+
+impl<V> ViewMarker for Tiled<V> {}
+impl<T, V> View<Context, T> for Tiled<V>
+where
+    V: crate::View<T>,
+{
+    type Element = Tiled<V::Element>;
+    type State = V::State;
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (element, state) = self.contents.build(cx, data);
+        let element = Tiled {
+            contents: element,
+            tile:     self.tile,
+        };
+
+        (element, state)
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(&mut element.contents, state, cx, data);
+        element.tile = self.tile;
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(
+            &mut element.contents,
+            state,
+            cx,
+            data,
+            event,
+        )
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context) {
+        V::teardown(element.contents, state, cx);
+    }
+}
+
+struct TiledElements<'a> {
+    widget:   WidgetId<widgets::Dashboard>,
+    elements: &'a mut Vec<Tiled<WidgetId>>,
+    index:    usize,
+}
+
+impl<'a> TiledElements<'a> {
+    fn new(widget: WidgetId<widgets::Dashboard>, elements: &'a mut Vec<Tiled<WidgetId>>) -> Self {
+        Self {
+            widget,
+            elements,
+            index: 0,
+        }
+    }
+
+    fn set_tile(&self, cx: &mut Context, tile: Tile) {
+        if let Ok(mut widget) = cx.get_widget_mut(self.widget)
+            && widget.widget.get_tile(self.index) != tile
+        {
+            widgets::Dashboard::set_tile(&mut widget, self.index, tile);
+        }
+    }
+}
+
+impl<'a> Elements<Context, Tiled<WidgetId>> for TiledElements<'a> {
+    fn next(&mut self, cx: &mut Context) -> Option<&mut Tiled<WidgetId>> {
+        let tile = self.elements.get(self.index)?.tile;
+        self.set_tile(cx, tile);
+
+        let element = self.elements.get_mut(self.index)?;
+        self.index += 1;
+        Some(element)
+    }
+
+    fn insert(&mut self, cx: &mut Context, element: Tiled<WidgetId>) {
+        cx.insert_child(
+            self.widget,
+            self.index,
+            element.contents,
+        );
+
+        self.set_tile(cx, element.tile);
+
+        self.elements.insert(self.index, element);
+        self.index += 1;
+    }
+
+    fn remove(&mut self, cx: &mut Context) -> Option<Tiled<WidgetId>> {
+        cx.remove_child(self.widget, self.index);
+        Some(self.elements.remove(self.index))
+    }
+
+    fn swap(&mut self, cx: &mut Context, offset: usize) {
+        cx.swap_children(
+            self.widget,
+            self.index,
+            self.index + offset,
+        );
+
+        self.elements.swap(self.index, self.index + offset);
+    }
+}
+
+impl<V> Element<Context> for Tiled<V> {
+    type Mut<'a>
+        = &'a mut Self
+    where
+        V: 'a;
+}
+
+impl<T> Super<Context, WidgetId<T>> for Tiled<WidgetId>
+where
+    T: ?Sized,
+{
+    fn replace(cx: &mut Context, this: &mut Self, other: WidgetId<T>) -> Self {
+        cx.replace_widget(this.contents, other);
+        this.contents = other.upcast();
+        this.tile = Tile::new(0, 0, 1, 1);
+        *this
+    }
+
+    fn upcast(_cx: &mut Context, sub: WidgetId<T>) -> Self {
+        Tiled {
+            contents: sub.upcast(),
+            tile:     Tile::new(0, 0, 1, 1),
+        }
+    }
+
+    fn downcast(self) -> WidgetId<T> {
+        self.contents.downcast()
+    }
+
+    fn downcast_with<U>(this: &mut Self, f: impl FnOnce(&mut WidgetId<T>) -> U) -> U {
+        let mut widget = this.downcast();
+        let output = f(&mut widget);
+        this.contents = widget.upcast();
+        output
+    }
+}
+
+impl<T> Super<Context, Tiled<WidgetId<T>>> for Tiled<WidgetId>
+where
+    T: ?Sized,
+{
+    fn replace(cx: &mut Context, this: &mut Tiled<WidgetId>, other: Tiled<WidgetId<T>>) -> Self {
+        cx.replace_widget(this.contents, other.contents);
+        mem::replace(this, Tiled::upcast(cx, other))
+    }
+
+    fn upcast(_cx: &mut Context, sub: Tiled<WidgetId<T>>) -> Self {
+        Self {
+            contents: sub.contents.upcast(),
+            tile:     sub.tile,
+        }
+    }
+
+    fn downcast(self) -> Tiled<WidgetId<T>> {
+        Tiled {
+            contents: self.contents.downcast(),
+            tile:     self.tile,
+        }
+    }
+
+    fn downcast_with<U>(this: &mut Self, f: impl FnOnce(&mut Tiled<WidgetId<T>>) -> U) -> U {
+        let mut widget = this.downcast();
+        let output = f(&mut widget);
+        this.contents = widget.contents.upcast();
+        this.tile = widget.tile;
+        output
+    }
+}
@@ -0,0 +1,576 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    mem,
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    task::{Context as TaskContext, Poll},
+};
+
+use ike_core::{
+    Axis, BorderWidth, Builder, Color, CornerRadius, FontWeight, Padding, Paint, Paragraph, Size,
+    TextStyle, WidgetId,
+    widgets::{self, Align, Justify},
+};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Palette, oneshot,
+    views::{ButtonTheme, EntryTheme, TextTheme},
+};
+
+/// Show the dialogs of `dialogs` over `contents`.
+pub fn dialogs<V>(dialogs: &Dialogs, contents: V) -> DialogHost<V> {
+    DialogHost::new(dialogs, contents)
+}
+
+enum DialogKind {
+    Alert,
+    Confirm,
+    Prompt(String),
+}
+
+enum Answer {
+    Accept(String),
+    Cancel,
+}
+
+struct DialogRequest {
+    kind:    DialogKind,
+    title:   String,
+    message: String,
+    respond: Box<dyn FnOnce(Answer) + Send>,
+}
+
+/// A handle for showing modal dialogs, usually kept in the app data.
+///
+/// Dialogs are queued when shown, and picked up by the [`DialogHost`] of the handle when it's
+/// next rebuilt. The returned [`DialogAnswer`] resolves once the user answers, and can be
+/// spawned with the [`Proxy`] to send the answer back as an event.
+#[derive(Clone, Default)]
+pub struct Dialogs {
+    requests: Arc<Mutex<Vec<DialogRequest>>>,
+}
+
+impl Dialogs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show a message with a single button to acknowledge it.
+    pub fn alert(&self, title: impl Into<String>, message: impl Into<String>) -> DialogAnswer<()> {
+        self.show(
+            DialogKind::Alert,
+            title,
+            message,
+            |_| (),
+        )
+    }
+
+    /// Ask the user to confirm something, resolving to whether they did.
+    pub fn confirm(
+        &self,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> DialogAnswer<bool> {
+        self.show(
+            DialogKind::Confirm,
+            title,
+            message,
+            |answer| matches!(answer, Answer::Accept(..)),
+        )
+    }
+
+    /// Ask the user for a line of text starting with `text`, resolving to `None` if cancelled.
+    pub fn prompt(
+        &self,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        text: impl Into<String>,
+    ) -> DialogAnswer<Option<String>> {
+        let kind = DialogKind::Prompt(text.into());
+
+        self.show(
+            kind,
+            title,
+            message,
+            |answer| match answer {
+                Answer::Accept(text) => Some(text),
+                Answer::Cancel => None,
+            },
+        )
+    }
+
+    fn show<U>(
+        &self,
+        kind: DialogKind,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        map: impl FnOnce(Answer) -> U + Send + 'static,
+    ) -> DialogAnswer<U>
+    where
+        U: Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+
+        let request = DialogRequest {
+            kind,
+            title: title.into(),
+            message: message.into(),
+            respond: Box::new(move |answer| sender.send(map(answer))),
+        };
+
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(request);
+        }
+
+        DialogAnswer { receiver }
+    }
+
+    fn take(&self) -> Vec<DialogRequest> {
+        match self.requests.lock() {
+            Ok(mut requests) => mem::take(&mut *requests),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.requests, &other.requests)
+    }
+}
+
+/// The answer to a dialog shown with [`Dialogs`].
+///
+/// Resolves to the answer of cancelling, if the dialog is removed before it's answered.
+pub struct DialogAnswer<U> {
+    receiver: oneshot::Receiver<U>,
+}
+
+impl<U: Default> Future for DialogAnswer<U> {
+    type Output = U;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(Option::unwrap_or_default)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DialogTheme {
+    pub max_width:        f32,
+    pub padding:          Padding,
+    pub corner_radius:    CornerRadius,
+    pub border_width:     BorderWidth,
+    pub spacing:          f32,
+    pub title_size:       f32,
+    pub font_size:        f32,
+    pub accept_label:     String,
+    pub cancel_label:     String,
+    pub scrim_color:      Option<Color>,
+    pub background_color: Option<Color>,
+    pub border_color:     Option<Color>,
+    pub color:            Option<Color>,
+}
+
+impl Default for DialogTheme {
+    fn default() -> Self {
+        Self {
+            max_width:        400.0,
+            padding:          Padding::all(20.0),
+            corner_radius:    CornerRadius::all(12.0),
+            border_width:     BorderWidth::all(1.0),
+            spacing:          12.0,
+            title_size:       18.0,
+            font_size:        14.0,
+            accept_label:     String::from("OK"),
+            cancel_label:     String::from("Cancel"),
+            scrim_color:      None,
+            background_color: None,
+            border_color:     None,
+            color:            None,
+        }
+    }
+}
+
+impl DialogTheme {
+    fn get_scrim_color(&self) -> Color {
+        self.scrim_color.unwrap_or(Color::BLACK.fade(0.5))
+    }
+
+    fn get_background_color(&self, palette: &Palette) -> Color {
+        self.background_color.unwrap_or_else(|| palette.surface(1))
+    }
+
+    fn get_border_color(&self, palette: &Palette) -> Color {
+        self.border_color.unwrap_or(palette.outline)
+    }
+
+    fn get_color(&self, palette: &Palette) -> Color {
+        self.color.unwrap_or(palette.contrast)
+    }
+}
+
+/// Shows the dialogs of a [`Dialogs`] handle one at a time, over its contents.
+///
+/// Pressing escape or going back cancels the dialog.
+pub struct DialogHost<V> {
+    contents: V,
+    dialogs:  Dialogs,
+}
+
+impl<V> DialogHost<V> {
+    pub fn new(dialogs: &Dialogs, contents: V) -> Self {
+        Self {
+            contents,
+            dialogs: dialogs.clone(),
+        }
+    }
+}
+
+enum DialogEvent {
+    Accept,
+    Cancel,
+}
+
+struct ShownDialog {
+    widget:  WidgetId,
+    request: DialogRequest,
+    text:    Rc<RefCell<String>>,
+}
+
+pub struct DialogHostState {
+    id:      ViewId,
+    dialogs: Dialogs,
+    shown:   Option<ShownDialog>,
+    queued:  VecDeque<DialogRequest>,
+}
+
+impl DialogHostState {
+    fn handle_requests(&mut self, cx: &mut Context, element: WidgetId<widgets::ZStack>) {
+        self.queued.extend(self.dialogs.take());
+        self.show_queued(cx, element);
+    }
+
+    fn answer(&mut self, cx: &mut Context, element: WidgetId<widgets::ZStack>, accept: bool) {
+        let Some(shown) = self.shown.take() else {
+            return;
+        };
+
+        cx.remove_widget(shown.widget);
+
+        let answer = match accept {
+            true => Answer::Accept(shown.text.take()),
+            false => Answer::Cancel,
+        };
+
+        (shown.request.respond)(answer);
+        self.show_queued(cx, element);
+    }
+
+    fn show_queued(&mut self, cx: &mut Context, element: WidgetId<widgets::ZStack>) {
+        if self.shown.is_some() {
+            return;
+        }
+
+        let Some(request) = self.queued.pop_front() else {
+            return;
+        };
+
+        let text = match request.kind {
+            DialogKind::Prompt(ref text) => text.clone(),
+            _ => String::new(),
+        };
+
+        let text = Rc::new(RefCell::new(text));
+        let widget = self.build_dialog(cx, &request, &text);
+        cx.add_child(element, widget);
+
+        self.shown = Some(ShownDialog {
+            widget,
+            request,
+            text,
+        });
+    }
+
+    fn build_dialog(
+        &self,
+        cx: &mut Context,
+        request: &DialogRequest,
+        text: &Rc<RefCell<String>>,
+    ) -> WidgetId {
+        let palette = cx.get_or_default::<Palette>();
+        let text_theme = cx.get_or_default::<TextTheme>();
+        let button_theme = cx.get_or_default::<ButtonTheme>();
+        let entry_theme = cx.get_or_default::<EntryTheme>();
+        let theme = cx.get_or_default::<DialogTheme>();
+        let proxy = cx.proxy();
+
+        let color = theme.get_color(&palette);
+
+        let paragraph = |text: &str, font_size, font_weight, color: Color| {
+            let style = TextStyle {
+                font_size,
+                font_weight,
+                font_stretch: text_theme.font_stretch,
+                font_style: text_theme.font_style,
+                font_family: text_theme.font_family.clone().into_owned(),
+                paint: Paint::from(color),
+            };
+
+            let mut paragraph = Paragraph::new(
+                text_theme.line_height,
+                text_theme.align,
+                text_theme.wrap,
+            );
+
+            paragraph.push(text, style);
+            paragraph
+        };
+
+        let send = |event: fn() -> DialogEvent| {
+            let proxy = proxy.cloned();
+            let id = self.id;
+
+            move || proxy.event(Event::new(event(), id))
+        };
+
+        let mut stack = widgets::Stack::new(cx);
+        widgets::Stack::set_axis(&mut stack, Axis::Vertical);
+        widgets::Stack::set_align(&mut stack, Align::Fill);
+        widgets::Stack::set_gap(&mut stack, theme.spacing);
+        let stack = stack.id();
+
+        let title = paragraph(
+            &request.title,
+            theme.title_size,
+            FontWeight::SEMI_BOLD,
+            color,
+        );
+
+        let title = widgets::Label::new(cx, title).id();
+        cx.add_child(stack, title);
+
+        if !request.message.is_empty() {
+            let message = paragraph(
+                &request.message,
+                theme.font_size,
+                text_theme.font_weight,
+                color,
+            );
+
+            let message = widgets::Label::new(cx, message).id();
+            cx.add_child(stack, message);
+        }
+
+        if let DialogKind::Prompt(ref initial) = request.kind {
+            let initial = paragraph(
+                initial,
+                theme.font_size,
+                text_theme.font_weight,
+                color,
+            );
+
+            let mut entry = widgets::Entry::new(cx, initial);
+            widgets::Entry::set_min_width(&mut entry, entry_theme.min_width);
+            widgets::Entry::set_padding(&mut entry, entry_theme.padding);
+            widgets::Entry::set_border_width(&mut entry, entry_theme.border_width);
+            widgets::Entry::set_corner_radius(&mut entry, entry_theme.corner_radius);
+            widgets::Entry::set_blink_rate(&mut entry, entry_theme.blink_rate);
+
+            widgets::Entry::set_background_color(
+                &mut entry,
+                entry_theme
+                    .background_color
+                    .unwrap_or_else(|| palette.surface(-1)),
+            );
+
+            let border_color = entry_theme.border_color.unwrap_or(palette.outline);
+            let focus_color = entry_theme.focus_color.unwrap_or(palette.info);
+            let cursor_color = entry_theme.cursor_color.unwrap_or(palette.contrast);
+            let selection_color = entry_theme.selection_color.unwrap_or(palette.info);
+
+            widgets::Entry::set_border_color(&mut entry, border_color);
+            widgets::Entry::set_focus_color(&mut entry, focus_color);
+            widgets::Entry::set_cursor_color(&mut entry, cursor_color);
+            widgets::Entry::set_selection_color(&mut entry, selection_color);
+            widgets::Entry::set_handle_color(&mut entry, palette.primary);
+            widgets::Entry::set_toolbar_color(&mut entry, palette.surface);
+
+            widgets::Entry::set_on_change(&mut entry, {
+                let text = text.clone();
+                move |new| *text.borrow_mut() = new.to_owned()
+            });
+
+            widgets::Entry::set_on_submit(&mut entry, {
+                let text = text.clone();
+                let accept = send(|| DialogEvent::Accept);
+
+                move |new| {
+                    *text.borrow_mut() = new.to_owned();
+                    accept();
+                }
+            });
+
+            let entry = entry.id();
+            cx.add_child(stack, entry);
+        }
+
+        let mut buttons = widgets::Stack::new(cx);
+        widgets::Stack::set_axis(&mut buttons, Axis::Horizontal);
+        widgets::Stack::set_justify(&mut buttons, Justify::End);
+        widgets::Stack::set_gap(&mut buttons, theme.spacing);
+        let buttons = buttons.id();
+
+        let mut button = |label: &str, color: Color, on_click| {
+            let label = paragraph(
+                label,
+                theme.font_size,
+                FontWeight::SEMI_BOLD,
+                color,
+            );
+
+            let label = widgets::Label::new(cx, label).id();
+            let idle = button_theme
+                .idle_color
+                .unwrap_or_else(|| palette.surface(0));
+
+            let mut button = widgets::Button::new(cx, label);
+            widgets::Button::set_padding(&mut button, button_theme.padding);
+            widgets::Button::set_border_width(&mut button, button_theme.border_width);
+            widgets::Button::set_corner_radius(&mut button, button_theme.corner_radius);
+            widgets::Button::set_border_color(&mut button, palette.outline);
+            widgets::Button::set_idle_color(&mut button, idle);
+            widgets::Button::set_hovered_color(&mut button, idle.lighten(0.04));
+            widgets::Button::set_active_color(&mut button, idle.darken(0.04));
+            widgets::Button::set_on_click(&mut button, on_click);
+
+            let button = button.id();
+            cx.add_child(buttons, button);
+        };
+
+        if !matches!(request.kind, DialogKind::Alert) {
+            button(
+                &theme.cancel_label,
+                color,
+                send(|| DialogEvent::Cancel),
+            );
+        }
+
+        button(
+            &theme.accept_label,
+            palette.primary,
+            send(|| DialogEvent::Accept),
+        );
+
+        cx.add_child(stack, buttons);
+
+        let mut card = widgets::Container::new(cx, stack);
+        widgets::Container::set_padding(&mut card, theme.padding);
+        widgets::Container::set_border_width(&mut card, theme.border_width);
+        widgets::Container::set_corner_radius(&mut card, theme.corner_radius);
+        widgets::Container::set_border_color(
+            &mut card,
+            theme.get_border_color(&palette),
+        );
+
+        widgets::Container::set_background_color(
+            &mut card,
+            theme.get_background_color(&palette),
+        );
+
+        let card = card.id();
+
+        let mut constrain = widgets::Constrain::new(cx, card);
+        widgets::Constrain::set_max_size(
+            &mut constrain,
+            Size::new(theme.max_width, f32::INFINITY),
+        );
+
+        let constrain = constrain.id();
+        let aligned = widgets::Aligned::new(cx, 0.5, 0.5, constrain).id();
+
+        let mut scrim = widgets::Container::new(cx, aligned);
+        widgets::Container::set_padding(&mut scrim, Padding::all(0.0));
+        widgets::Container::set_border_width(&mut scrim, BorderWidth::all(0.0));
+        widgets::Container::set_corner_radius(&mut scrim, CornerRadius::all(0.0));
+        widgets::Container::set_background_color(&mut scrim, theme.get_scrim_color());
+        let scrim = scrim.id();
+
+        let mut dismissable = widgets::Dismissable::new(cx, scrim);
+        widgets::Dismissable::set_on_dismiss(
+            &mut dismissable,
+            send(|| DialogEvent::Cancel),
+        );
+
+        dismissable.id().upcast()
+    }
+}
+
+impl<V> ViewMarker for DialogHost<V> {}
+impl<T, V> View<Context, T> for DialogHost<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::ZStack>;
+    type State = (DialogHostState, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let element = widgets::ZStack::new(cx).id();
+        cx.add_child(element, contents);
+
+        let mut host = DialogHostState {
+            id:      ViewId::next(),
+            dialogs: self.dialogs,
+            shown:   None,
+            queued:  VecDeque::new(),
+        };
+
+        host.handle_requests(cx, element);
+
+        (element, (host, contents, state))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (host, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(contents, state, cx, data);
+
+        if !self.dialogs.is_same(&host.dialogs) {
+            host.queued.clear();
+        }
+
+        host.dialogs = self.dialogs;
+        host.handle_requests(cx, *element);
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (host, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::event(contents, state, cx, data, event);
+
+        match event.take_targeted(host.id) {
+            Some(DialogEvent::Accept) => host.answer(cx, *element, true),
+            Some(DialogEvent::Cancel) => host.answer(cx, *element, false),
+            None => {}
+        }
+
+        action
+    }
+
+    fn teardown(element: Self::Element, (_host, contents, state): Self::State, cx: &mut Context) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
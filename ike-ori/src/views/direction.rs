@@ -0,0 +1,79 @@
+use ike_core::{Builder, LayoutDirection, WidgetId, widgets};
+use ori::{Action, Event, Mut, View, ViewMarker};
+
+use crate::Context;
+
+/// Lay out `contents` in `direction`, regardless of the direction of the language of the
+/// platform, e.g. for content in another language than the rest of the interface.
+pub fn direction<V>(direction: LayoutDirection, contents: V) -> Direction<V> {
+    Direction::new(direction, contents)
+}
+
+pub struct Direction<V> {
+    contents:  V,
+    direction: LayoutDirection,
+}
+
+impl<V> Direction<V> {
+    pub fn new(direction: LayoutDirection, contents: V) -> Self {
+        Self {
+            contents,
+            direction,
+        }
+    }
+}
+
+impl<V> ViewMarker for Direction<V> {}
+impl<T, V> View<Context, T> for Direction<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Direction>;
+    type State = (LayoutDirection, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+        let widget = widgets::Direction::new(cx, self.direction, contents);
+
+        (
+            widget.id(),
+            (self.direction, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (direction, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        if self.direction != *direction
+            && let Ok(mut widget) = cx.get_widget_mut(*element)
+        {
+            *direction = self.direction;
+            widgets::Direction::set_direction(&mut widget, self.direction);
+        }
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_direction, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_direction, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
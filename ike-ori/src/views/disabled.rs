@@ -0,0 +1,114 @@
+use ike_core::{Builder, Transition, WidgetId, widgets};
+use ori::{Action, Event, Mut, Provider, View, ViewMarker};
+
+use crate::{Context, is_retheming};
+
+/// Disable `contents` when `is_disabled` is true, see [`Disabled`].
+pub fn disabled<V>(contents: V, is_disabled: bool) -> Disabled<V> {
+    Disabled::new(contents, is_disabled)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisabledTheme {
+    /// The opacity disabled contents are dimmed to.
+    pub opacity:    f32,
+    pub transition: Transition,
+}
+
+impl Default for DisabledTheme {
+    fn default() -> Self {
+        Self {
+            opacity:    0.5,
+            transition: Transition::ease(0.1),
+        }
+    }
+}
+
+/// Disables its contents, which are dimmed and ignore pointer, touch, key and text input, and
+/// can't be focused.
+///
+/// Widgets inside nested [`disabled`] views stay disabled when the outer view is enabled.
+pub struct Disabled<V> {
+    contents:    V,
+    is_disabled: bool,
+}
+
+impl<V> Disabled<V> {
+    pub fn new(contents: V, is_disabled: bool) -> Self {
+        Self {
+            contents,
+            is_disabled,
+        }
+    }
+}
+
+impl<V> ViewMarker for Disabled<V> {}
+impl<T, V> View<Context, T> for Disabled<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Disabled>;
+    type State = (bool, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let theme = cx.get_or_default::<DisabledTheme>();
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = widgets::Disabled::new(cx, contents);
+
+        widgets::Disabled::set_disabled_opacity(&mut widget, theme.opacity);
+        widgets::Disabled::set_disabled(&mut widget, self.is_disabled);
+        widgets::Disabled::set_transition(&mut widget, theme.transition);
+
+        (
+            widget.id(),
+            (self.is_disabled, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (is_disabled, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        let theme = cx.get_or_default::<DisabledTheme>();
+        let retheme = is_retheming(cx);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if retheme {
+            widgets::Disabled::set_disabled_opacity(&mut widget, theme.opacity);
+            widgets::Disabled::set_transition(&mut widget, theme.transition);
+        }
+
+        if self.is_disabled != *is_disabled {
+            *is_disabled = self.is_disabled;
+            widgets::Disabled::set_disabled(&mut widget, self.is_disabled);
+        }
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_is_disabled, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_is_disabled, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -0,0 +1,103 @@
+use ike_core::{Builder, WidgetId, widgets};
+use ori::{Action, Event, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Wrap `contents` in a dismiss scope, calling `on_dismiss` when escape or back is pressed, or
+/// when the window is pressed outside of `contents`.
+///
+/// Nested scopes are dismissed innermost first.
+pub fn dismissable<T, V, A>(
+    contents: V,
+    on_dismiss: impl FnMut(&mut T) -> A + 'static,
+) -> Dismissable<T, V>
+where
+    A: Into<Action>,
+{
+    Dismissable::new(contents, on_dismiss)
+}
+
+pub struct Dismissable<T, V> {
+    contents:   V,
+    on_dismiss: Box<dyn FnMut(&mut T) -> Action>,
+}
+
+impl<T, V> Dismissable<T, V> {
+    pub fn new<A>(contents: V, mut on_dismiss: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            on_dismiss: Box::new(move |data| on_dismiss(data).into()),
+        }
+    }
+}
+
+struct Dismissed;
+
+impl<T, V> ViewMarker for Dismissable<T, V> {}
+impl<T, V> View<Context, T> for Dismissable<T, V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Dismissable>;
+    type State = (
+        ViewId,
+        Box<dyn FnMut(&mut T) -> Action>,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let (contents, state) = self.contents.build(cx, data);
+        let mut widget = widgets::Dismissable::new(cx, contents);
+
+        widgets::Dismissable::set_on_dismiss(&mut widget, move || {
+            proxy.event(Event::new(Dismissed, id));
+        });
+
+        (
+            widget.id(),
+            (id, self.on_dismiss, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        _element: &mut Self::Element,
+        (_id, on_dismiss, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(contents, state, cx, data);
+        *on_dismiss = self.on_dismiss;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (id, on_dismiss, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::event(contents, state, cx, data, event);
+
+        match event.take_targeted(*id) {
+            Some(Dismissed) => action | on_dismiss(data),
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _on_dismiss, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
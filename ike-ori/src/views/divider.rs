@@ -1,7 +1,7 @@
 use ike_core::{Axis, Builder, Color, CornerRadius, WidgetId, widgets};
 use ori::{Action, Event, Provider, View, ViewMarker};
 
-use crate::{Context, Palette};
+use crate::{Context, Palette, is_retheming, theme_transition};
 
 pub fn divider(axis: Axis) -> Divider {
     Divider::new(axis)
@@ -15,7 +15,7 @@ pub fn vdivider() -> Divider {
     divider(Axis::Vertical)
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct DividerTheme {
     pub thickness:     f32,
     pub inset:         f32,
@@ -120,6 +120,7 @@ impl<T> View<Context, T> for Divider {
     fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
         let palette = cx.get_or_default::<Palette>();
         let theme = cx.get_or_default::<DividerTheme>();
+        let transition = theme_transition(cx);
 
         let mut widget = widgets::Divider::new(cx);
 
@@ -135,6 +136,7 @@ impl<T> View<Context, T> for Divider {
         widgets::Divider::set_padding(&mut widget, padding);
         widgets::Divider::set_corner_radius(&mut widget, corner_radius);
         widgets::Divider::set_color(&mut widget, color);
+        widgets::Divider::set_color_transition(&mut widget, transition);
 
         (widget.id(), self.properties)
     }
@@ -148,36 +150,42 @@ impl<T> View<Context, T> for Divider {
     ) {
         let palette = cx.get_or_default::<Palette>();
         let theme = cx.get_or_default::<DividerTheme>();
+        let retheme = is_retheming(cx);
+        let transition = theme_transition(cx);
 
         let Ok(mut widget) = cx.get_widget_mut(*element) else {
             return;
         };
 
+        if retheme {
+            widgets::Divider::set_color_transition(&mut widget, transition);
+        }
+
         if self.properties.axis != properties.axis {
             widgets::Divider::set_axis(&mut widget, self.properties.axis);
         }
 
-        if self.properties.thickness != properties.thickness {
+        if retheme || self.properties.thickness != properties.thickness {
             let thickness = self.properties.get_thickness(&theme);
             widgets::Divider::set_thickness(&mut widget, thickness);
         }
 
-        if self.properties.inset != properties.inset {
+        if retheme || self.properties.inset != properties.inset {
             let inset = self.properties.get_inset(&theme);
             widgets::Divider::set_inset(&mut widget, inset);
         }
 
-        if self.properties.padding != properties.padding {
+        if retheme || self.properties.padding != properties.padding {
             let padding = self.properties.get_padding(&theme);
             widgets::Divider::set_padding(&mut widget, padding);
         }
 
-        if self.properties.corner_radius != properties.corner_radius {
+        if retheme || self.properties.corner_radius != properties.corner_radius {
             let corner_radius = self.properties.get_corner_radius(&theme);
             widgets::Divider::set_corner_radius(&mut widget, corner_radius);
         }
 
-        if self.properties.color != properties.color {
+        if retheme || self.properties.color != properties.color {
             let color = self.properties.get_color(&theme, &palette);
             widgets::Divider::set_color(&mut widget, color);
         }
@@ -1,13 +1,18 @@
+use std::time::Duration;
+
 use ike_core::{
-    BorderWidth, Builder, Color, CornerRadius, FontStretch, FontStyle, FontWeight, Padding, Paint,
-    Paragraph, TextAlign, TextStyle, TextWrap, WidgetId,
+    AnyWidgetId, BorderWidth, Builder, Color, CornerRadius, FontStretch, FontStyle, FontWeight,
+    Padding, Paint, Paragraph, TextAlign, TextStyle, TextWrap, WidgetId,
     widgets::{self, NewlineBehaviour, SubmitBehaviour},
 };
 use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
 
-use crate::{Context, Palette, views::TextTheme};
+use crate::{
+    Context, Palette, RateLimit, RateLimiter, is_retheming, theme_transition,
+    views::{GutterTheme, TextTheme, gutter::build_gutter},
+};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct EntryTheme {
     pub font_size:         Option<f32>,
     pub font_family:       Option<String>,
@@ -27,7 +32,11 @@ pub struct EntryTheme {
     pub background_color:  Option<Color>,
     pub border_color:      Option<Color>,
     pub focus_color:       Option<Color>,
+    pub error_color:       Option<Color>,
+    pub reveal_color:      Option<Color>,
+    pub adornment_color:   Option<Color>,
     pub cursor_color:      Option<Color>,
+    pub composing_color:   Option<Color>,
     pub selection_color:   Option<Color>,
     pub blink_rate:        f32,
 }
@@ -53,7 +62,11 @@ impl Default for EntryTheme {
             background_color:  None,
             border_color:      None,
             focus_color:       None,
+            error_color:       None,
+            reveal_color:      None,
+            adornment_color:   None,
             cursor_color:      None,
+            composing_color:   None,
             selection_color:   None,
             blink_rate:        5.0,
         }
@@ -64,6 +77,11 @@ pub fn entry<T>() -> Entry<T> {
     Entry::new()
 }
 
+/// Create an [`Entry`] for passwords, hiding the text with a toggle revealing it.
+pub fn password_entry<T>() -> Entry<T> {
+    Entry::new().secure(true).reveal_toggle(true)
+}
+
 pub struct Entry<T> {
     text:              Option<String>,
     placeholder:       String,
@@ -85,16 +103,35 @@ pub struct Entry<T> {
     background_color:  Option<Color>,
     border_color:      Option<Color>,
     focus_color:       Option<Color>,
+    error_color:       Option<Color>,
+    reveal_color:      Option<Color>,
+    adornment_color:   Option<Color>,
     cursor_color:      Option<Color>,
+    composing_color:   Option<Color>,
     selection_color:   Option<Color>,
     blink_rate:        Option<f32>,
     newline_behaviour: NewlineBehaviour,
     submit_behaviour:  SubmitBehaviour,
+    line_numbers:      bool,
+    secure:            bool,
+    reveal_toggle:     bool,
+    prefix:            Option<String>,
+    suffix:            Option<String>,
+    clear_button:      bool,
+    counter:           bool,
+    max_length:        Option<usize>,
+    change_limit:      RateLimit,
 
     #[allow(clippy::type_complexity)]
-    on_change: Box<dyn FnMut(&mut T, String) -> Action>,
+    on_change:   Box<dyn FnMut(&mut T, String) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_submit:   Box<dyn FnMut(&mut T, String) -> Action>,
+    #[allow(clippy::type_complexity)]
+    line_marker: Option<Box<dyn FnMut(usize) -> Option<Color>>>,
+    #[allow(clippy::type_complexity)]
+    filter:      Option<Box<dyn FnMut(&str) -> bool>>,
     #[allow(clippy::type_complexity)]
-    on_submit: Box<dyn FnMut(&mut T, String) -> Action>,
+    validator:   Option<Box<dyn FnMut(&str) -> bool>>,
 }
 
 impl<T> Default for Entry<T> {
@@ -126,14 +163,30 @@ impl<T> Entry<T> {
             background_color:  None,
             border_color:      None,
             focus_color:       None,
+            error_color:       None,
+            reveal_color:      None,
+            adornment_color:   None,
             cursor_color:      None,
+            composing_color:   None,
             selection_color:   None,
             blink_rate:        None,
             newline_behaviour: NewlineBehaviour::Never,
             submit_behaviour:  SubmitBehaviour::default(),
-
-            on_change: Box::new(|_, _| Action::new()),
-            on_submit: Box::new(|_, _| Action::new()),
+            line_numbers:      false,
+            secure:            false,
+            reveal_toggle:     false,
+            prefix:            None,
+            suffix:            None,
+            clear_button:      false,
+            counter:           false,
+            max_length:        None,
+            change_limit:      RateLimit::None,
+
+            on_change:   Box::new(|_, _| Action::new()),
+            on_submit:   Box::new(|_, _| Action::new()),
+            line_marker: None,
+            filter:      None,
+            validator:   None,
         }
     }
 
@@ -237,6 +290,23 @@ impl<T> Entry<T> {
         self
     }
 
+    /// The color of the border drawn while the text is invalid, see [`Self::validate`].
+    pub fn error_color(mut self, color: Color) -> Self {
+        self.error_color = Some(color);
+        self
+    }
+
+    pub fn reveal_color(mut self, color: Color) -> Self {
+        self.reveal_color = Some(color);
+        self
+    }
+
+    /// The color of the prefix, suffix, counter and clear button.
+    pub fn adornment_color(mut self, color: Color) -> Self {
+        self.adornment_color = Some(color);
+        self
+    }
+
     pub fn selection_color(mut self, color: Color) -> Self {
         self.selection_color = Some(color);
         self
@@ -247,6 +317,11 @@ impl<T> Entry<T> {
         self
     }
 
+    pub fn composing_color(mut self, color: Color) -> Self {
+        self.composing_color = Some(color);
+        self
+    }
+
     pub fn blink_rate(mut self, rate: f32) -> Self {
         self.blink_rate = Some(rate);
         self
@@ -262,11 +337,140 @@ impl<T> Entry<T> {
         self
     }
 
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Draw a marker behind the numbers of lines, e.g. breakpoints.
+    ///
+    /// Only drawn when [`Self::line_numbers`] are enabled.
+    pub fn line_marker(
+        mut self,
+        line_marker: impl FnMut(usize) -> Option<Color> + 'static,
+    ) -> Self {
+        self.line_marker = Some(Box::new(line_marker));
+        self
+    }
+
+    /// Draw every character as a bullet, and disable copying the text, e.g. for passwords.
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Show a button revealing the text while it's toggled on, when the entry is
+    /// [`Self::secure`].
+    pub fn reveal_toggle(mut self, reveal_toggle: bool) -> Self {
+        self.reveal_toggle = reveal_toggle;
+        self
+    }
+
+    /// Show `prefix` before the text, e.g. a currency symbol.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Show `suffix` after the text, e.g. a unit.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Show a button clearing the text while there is any.
+    pub fn clear_button(mut self, clear_button: bool) -> Self {
+        self.clear_button = clear_button;
+        self
+    }
+
+    /// Show the number of characters, and [`Self::max_length`] if set, after the text.
+    pub fn counter(mut self, counter: bool) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Reject typed or pasted text that would make the text longer than `max_length`
+    /// characters.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Reject typed or pasted text that would make `filter` return `false` for the edited text.
+    pub fn filter(mut self, filter: impl FnMut(&str) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Validate the text with `validator`, invalid text is drawn with an error border, and can't
+    /// be submitted.
+    pub fn validate(mut self, validator: impl FnMut(&str) -> bool + 'static) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Restrict the text to the pattern `mask`, where `#` is a digit, `a` is a letter, `*` is
+    /// either, and any other character must be typed as is, e.g. `"###-####"`.
+    ///
+    /// Text matching only the start of the mask can be typed, but not submitted.
+    pub fn mask(self, mask: impl Into<String>) -> Self {
+        let mask = mask.into();
+
+        self.filter({
+            let mask = mask.clone();
+            move |text| matches_mask(&mask, text, true)
+        })
+        .validate(move |text| matches_mask(&mask, text, false))
+    }
+
     pub fn on_change<A>(mut self, mut on_change: impl FnMut(&mut T, String) -> A + 'static) -> Self
     where
         A: Into<Action>,
     {
         self.on_change = Box::new(move |data, text| on_change(data, text).into());
+        self.change_limit = RateLimit::None;
+        self
+    }
+
+    /// Call `on_change` with the text once it hasn't changed for `delay`, e.g. to send a search
+    /// query once the user stops typing, rather than on every keystroke.
+    ///
+    /// A pending change is delivered right away when the entry is submitted.
+    pub fn on_change_debounced<A>(
+        self,
+        delay: Duration,
+        on_change: impl FnMut(&mut T, String) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_change_limited(RateLimit::Debounce(delay), on_change)
+    }
+
+    /// Call `on_change` at most once per `interval`, with the latest text.
+    pub fn on_change_throttled<A>(
+        self,
+        interval: Duration,
+        on_change: impl FnMut(&mut T, String) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_change_limited(RateLimit::Throttle(interval), on_change)
+    }
+
+    /// Call `on_change` as often as `limit` allows, see [`RateLimit`].
+    pub fn on_change_limited<A>(
+        mut self,
+        limit: RateLimit,
+        on_change: impl FnMut(&mut T, String) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self = self.on_change(on_change);
+        self.change_limit = limit;
         self
     }
 
@@ -287,7 +491,28 @@ impl<T> Entry<T> {
         text_theme: &TextTheme,
         entry_theme: &EntryTheme,
     ) -> Paragraph {
-        let style = TextStyle {
+        let style = self.build_style(color, text_theme, entry_theme);
+
+        let mut paragraph = Paragraph::new(
+            self.line_height
+                .unwrap_or_else(|| entry_theme.line_height.unwrap_or(text_theme.line_height)),
+            self.align
+                .unwrap_or_else(|| entry_theme.align.unwrap_or(text_theme.align)),
+            self.wrap
+                .unwrap_or_else(|| entry_theme.wrap.unwrap_or(text_theme.wrap)),
+        );
+
+        paragraph.push(text, style);
+        paragraph
+    }
+
+    fn build_style(
+        &self,
+        color: Color,
+        text_theme: &TextTheme,
+        entry_theme: &EntryTheme,
+    ) -> TextStyle {
+        TextStyle {
             font_size: self
                 .font_size
                 .unwrap_or_else(|| entry_theme.font_size.unwrap_or(text_theme.font_size)),
@@ -312,19 +537,20 @@ impl<T> Entry<T> {
             }),
 
             paint: Paint::from(color),
-        };
-
-        let mut paragraph = Paragraph::new(
-            self.line_height
-                .unwrap_or_else(|| entry_theme.line_height.unwrap_or(text_theme.line_height)),
-            self.align
-                .unwrap_or_else(|| entry_theme.align.unwrap_or(text_theme.align)),
-            self.wrap
-                .unwrap_or_else(|| entry_theme.wrap.unwrap_or(text_theme.wrap)),
-        );
+        }
+    }
 
-        paragraph.push(text, style);
-        paragraph
+    /// Build a label showing `text` as an adornment, placed before or after the text.
+    fn build_adornment(
+        &self,
+        cx: &mut Context,
+        text: Option<&str>,
+        color: Color,
+        text_theme: &TextTheme,
+        entry_theme: &EntryTheme,
+    ) -> Option<WidgetId> {
+        let paragraph = self.build_paragraph(text?, color, text_theme, entry_theme);
+        Some(widgets::Label::new(cx, paragraph).id().upcast())
     }
 
     fn get_color(&self, palette: &Palette, text_theme: &TextTheme, theme: &EntryTheme) -> Color {
@@ -361,17 +587,64 @@ impl<T> Entry<T> {
             .unwrap_or_else(|| theme.focus_color.unwrap_or(palette.info))
     }
 
+    fn get_error_color(&self, palette: &Palette, theme: &EntryTheme) -> Color {
+        self.error_color
+            .unwrap_or_else(|| theme.error_color.unwrap_or(palette.danger))
+    }
+
+    fn get_reveal_color(&self, palette: &Palette, theme: &EntryTheme) -> Color {
+        self.reveal_color.unwrap_or_else(|| {
+            theme
+                .reveal_color
+                .unwrap_or_else(|| palette.contrast_low(0))
+        })
+    }
+
+    fn get_adornment_color(&self, palette: &Palette, theme: &EntryTheme) -> Color {
+        self.adornment_color.unwrap_or_else(|| {
+            theme
+                .adornment_color
+                .unwrap_or_else(|| palette.contrast_low(0))
+        })
+    }
+
     fn get_cursor_color(&self, palette: &Palette, theme: &EntryTheme) -> Color {
         self.cursor_color
             .unwrap_or_else(|| theme.cursor_color.unwrap_or(palette.contrast))
     }
 
+    fn get_composing_color(&self, palette: &Palette, theme: &EntryTheme) -> Color {
+        self.composing_color
+            .unwrap_or_else(|| theme.composing_color.unwrap_or(palette.contrast))
+    }
+
     fn get_selection_color(&self, palette: &Palette, theme: &EntryTheme) -> Color {
         self.selection_color
             .unwrap_or_else(|| theme.selection_color.unwrap_or(palette.info))
     }
 }
 
+/// Whether `text` matches `mask`, or only the start of it when `partial`, see [`Entry::mask`].
+fn matches_mask(mask: &str, text: &str, partial: bool) -> bool {
+    let mut mask = mask.chars();
+
+    for c in text.chars() {
+        let matches = match mask.next() {
+            Some('#') => c.is_ascii_digit(),
+            Some('a') => c.is_alphabetic(),
+            Some('*') => c.is_alphanumeric(),
+            Some(literal) => c == literal,
+            None => false,
+        };
+
+        if !matches {
+            return false;
+        }
+    }
+
+    partial || mask.next().is_none()
+}
+
 enum EntryEvent {
     Change(String),
     Submit(String),
@@ -380,12 +653,14 @@ enum EntryEvent {
 impl<T> ViewMarker for Entry<T> {}
 impl<T> View<Context, T> for Entry<T> {
     type Element = WidgetId<widgets::Entry>;
-    type State = (ViewId, Self);
+    type State = (ViewId, Self, RateLimiter<String>);
 
-    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+    fn build(mut self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
         let palette = cx.get_or_default::<Palette>();
         let text_theme = cx.get_or_default::<TextTheme>();
         let theme = cx.get_or_default::<EntryTheme>();
+        let gutter_theme = cx.get_or_default::<GutterTheme>();
+        let transition = theme_transition(cx);
         let proxy = cx.proxy();
         let id = ViewId::next();
 
@@ -411,11 +686,16 @@ impl<T> View<Context, T> for Entry<T> {
         let background_color = self.get_background_color(&palette, &theme);
         let border_color = self.get_border_color(&palette, &theme);
         let focus_color = self.get_focus_color(&palette, &theme);
+        let error_color = self.get_error_color(&palette, &theme);
+        let reveal_color = self.get_reveal_color(&palette, &theme);
+        let adornment_color = self.get_adornment_color(&palette, &theme);
         let cursor_color = self.get_cursor_color(&palette, &theme);
+        let composing_color = self.get_composing_color(&palette, &theme);
         let selection_color = self.get_selection_color(&palette, &theme);
         let blink_rate = self.blink_rate.unwrap_or(theme.blink_rate);
 
         widgets::Entry::set_handle_color(&mut widget, palette.primary);
+        widgets::Entry::set_toolbar_color(&mut widget, palette.surface);
         widgets::Entry::set_placeholder(&mut widget, placeholder);
         widgets::Entry::set_min_width(&mut widget, min_width);
         widgets::Entry::set_max_width(&mut widget, max_width);
@@ -424,12 +704,43 @@ impl<T> View<Context, T> for Entry<T> {
         widgets::Entry::set_corner_radius(&mut widget, corner_radius);
         widgets::Entry::set_background_color(&mut widget, background_color);
         widgets::Entry::set_border_color(&mut widget, border_color);
+        widgets::Entry::set_color_transition(&mut widget, transition);
         widgets::Entry::set_focus_color(&mut widget, focus_color);
+        widgets::Entry::set_error_color(&mut widget, error_color);
+        widgets::Entry::set_reveal_color(&mut widget, reveal_color);
         widgets::Entry::set_cursor_color(&mut widget, cursor_color);
+        widgets::Entry::set_composing_color(&mut widget, composing_color);
         widgets::Entry::set_selection_color(&mut widget, selection_color);
         widgets::Entry::set_blink_rate(&mut widget, blink_rate);
         widgets::Entry::set_newline_behaviour(&mut widget, self.newline_behaviour);
         widgets::Entry::set_submit_behaviour(&mut widget, self.submit_behaviour);
+        widgets::Entry::set_secure(&mut widget, self.secure);
+        widgets::Entry::set_reveal_toggle(&mut widget, self.reveal_toggle);
+        widgets::Entry::set_clear_button(&mut widget, self.clear_button);
+        widgets::Entry::set_clear_color(&mut widget, adornment_color);
+        widgets::Entry::set_max_length(&mut widget, self.max_length);
+
+        if self.counter {
+            let style = self.build_style(adornment_color, &text_theme, &theme);
+            widgets::Entry::set_counter(&mut widget, Some(style));
+        }
+
+        if self.line_numbers {
+            let gutter = build_gutter(&palette, &text_theme, &gutter_theme);
+            widgets::Entry::set_gutter(&mut widget, Some(gutter));
+        }
+
+        if let Some(line_marker) = self.line_marker.take() {
+            widgets::Entry::set_line_marker(&mut widget, line_marker);
+        }
+
+        if let Some(filter) = self.filter.take() {
+            widgets::Entry::set_filter(&mut widget, filter);
+        }
+
+        if let Some(validator) = self.validator.take() {
+            widgets::Entry::set_validator(&mut widget, validator);
+        }
 
         widgets::Entry::set_on_change(&mut widget, {
             let proxy = proxy.cloned();
@@ -449,25 +760,58 @@ impl<T> View<Context, T> for Entry<T> {
             ))
         });
 
-        (widget.id(), (id, self))
+        let element = widget.id();
+        drop(widget);
+
+        let prefix = self.prefix.as_deref();
+        let prefix = self.build_adornment(
+            cx,
+            prefix,
+            adornment_color,
+            &text_theme,
+            &theme,
+        );
+        widgets::Entry::set_leading(cx, element, prefix);
+
+        let suffix = self.suffix.as_deref();
+        let suffix = self.build_adornment(
+            cx,
+            suffix,
+            adornment_color,
+            &text_theme,
+            &theme,
+        );
+        widgets::Entry::set_trailing(cx, element, suffix);
+
+        let limiter = RateLimiter::new(self.change_limit);
+        (element, (id, self, limiter))
     }
 
     fn rebuild(
-        self,
+        mut self,
         element: &mut Self::Element,
-        (_id, entry): &mut Self::State,
+        (_id, entry, limiter): &mut Self::State,
         cx: &mut Context,
         _data: &mut T,
     ) {
+        limiter.set_limit(self.change_limit);
+
         let palette = cx.get_or_default::<Palette>();
         let text_theme = cx.get_or_default::<TextTheme>();
         let theme = cx.get_or_default::<EntryTheme>();
+        let gutter_theme = cx.get_or_default::<GutterTheme>();
+        let retheme = is_retheming(cx);
+        let transition = theme_transition(cx);
 
         let Ok(mut widget) = cx.get_widget_mut(*element) else {
             return;
         };
 
-        if self.text != entry.text
+        if retheme {
+            widgets::Entry::set_color_transition(&mut widget, transition);
+        }
+
+        let style_changed = retheme
             || self.font_size != entry.font_size
             || self.font_family != entry.font_family
             || self.font_weight != entry.font_weight
@@ -475,9 +819,9 @@ impl<T> View<Context, T> for Entry<T> {
             || self.font_style != entry.font_style
             || self.line_height != entry.line_height
             || self.align != entry.align
-            || self.wrap != entry.wrap
-            || self.color != entry.color
-        {
+            || self.wrap != entry.wrap;
+
+        if style_changed || self.text != entry.text || self.color != entry.color {
             let text = match self.text {
                 Some(ref text) => text.clone(),
                 None => widgets::Entry::get_text_area_mut(&mut widget)
@@ -491,7 +835,8 @@ impl<T> View<Context, T> for Entry<T> {
             widgets::Entry::set_text(&mut widget, paragraph);
         }
 
-        if self.placeholder != entry.placeholder
+        if retheme
+            || self.placeholder != entry.placeholder
             || self.placeholder_color != entry.placeholder_color
         {
             let placeholder_color = self.get_placeholder_color(&palette, &theme);
@@ -504,57 +849,72 @@ impl<T> View<Context, T> for Entry<T> {
             widgets::Entry::set_placeholder(&mut widget, placeholder);
         }
 
-        if self.min_width != entry.min_width {
+        if retheme || self.min_width != entry.min_width {
             let min_width = self.min_width.unwrap_or(theme.min_width);
             widgets::Entry::set_min_width(&mut widget, min_width);
         }
 
-        if self.max_width != entry.max_width {
+        if retheme || self.max_width != entry.max_width {
             let max_width = self.max_width.unwrap_or(theme.max_width);
             widgets::Entry::set_max_width(&mut widget, max_width);
         }
 
-        if self.padding != entry.padding {
+        if retheme || self.padding != entry.padding {
             let padding = self.padding.unwrap_or(theme.padding);
             widgets::Entry::set_padding(&mut widget, padding);
         }
 
-        if self.border_width != entry.border_width {
+        if retheme || self.border_width != entry.border_width {
             let border_width = self.border_width.unwrap_or(theme.border_width);
             widgets::Entry::set_border_width(&mut widget, border_width);
         }
 
-        if self.corner_radius != entry.corner_radius {
+        if retheme || self.corner_radius != entry.corner_radius {
             let corner_radius = self.corner_radius.unwrap_or(theme.corner_radius);
             widgets::Entry::set_corner_radius(&mut widget, corner_radius);
         }
 
-        if self.background_color != entry.background_color {
+        if retheme || self.background_color != entry.background_color {
             let background_color = self.get_background_color(&palette, &theme);
             widgets::Entry::set_background_color(&mut widget, background_color);
         }
 
-        if self.border_color != entry.border_color {
+        if retheme || self.border_color != entry.border_color {
             let border_color = self.get_border_color(&palette, &theme);
             widgets::Entry::set_border_color(&mut widget, border_color);
         }
 
-        if self.focus_color != entry.focus_color {
+        if retheme || self.focus_color != entry.focus_color {
             let focus_color = self.get_focus_color(&palette, &theme);
             widgets::Entry::set_focus_color(&mut widget, focus_color);
         }
 
-        if self.cursor_color != entry.cursor_color {
+        if retheme || self.error_color != entry.error_color {
+            let error_color = self.get_error_color(&palette, &theme);
+            widgets::Entry::set_error_color(&mut widget, error_color);
+        }
+
+        if retheme || self.reveal_color != entry.reveal_color {
+            let reveal_color = self.get_reveal_color(&palette, &theme);
+            widgets::Entry::set_reveal_color(&mut widget, reveal_color);
+        }
+
+        if retheme || self.cursor_color != entry.cursor_color {
             let cursor_color = self.get_cursor_color(&palette, &theme);
             widgets::Entry::set_cursor_color(&mut widget, cursor_color);
         }
 
-        if self.selection_color != entry.selection_color {
+        if retheme || self.composing_color != entry.composing_color {
+            let composing_color = self.get_composing_color(&palette, &theme);
+            widgets::Entry::set_composing_color(&mut widget, composing_color);
+        }
+
+        if retheme || self.selection_color != entry.selection_color {
             let selection_color = self.get_selection_color(&palette, &theme);
             widgets::Entry::set_selection_color(&mut widget, selection_color);
         }
 
-        if self.blink_rate != entry.blink_rate {
+        if retheme || self.blink_rate != entry.blink_rate {
             let blink_rate = self.blink_rate.unwrap_or(theme.blink_rate);
             widgets::Entry::set_blink_rate(&mut widget, blink_rate);
         }
@@ -567,24 +927,119 @@ impl<T> View<Context, T> for Entry<T> {
             widgets::Entry::set_submit_behaviour(&mut widget, self.submit_behaviour);
         }
 
+        if self.secure != entry.secure {
+            widgets::Entry::set_secure(&mut widget, self.secure);
+        }
+
+        if self.reveal_toggle != entry.reveal_toggle {
+            widgets::Entry::set_reveal_toggle(&mut widget, self.reveal_toggle);
+        }
+
+        if self.clear_button != entry.clear_button {
+            widgets::Entry::set_clear_button(&mut widget, self.clear_button);
+        }
+
+        if self.max_length != entry.max_length {
+            widgets::Entry::set_max_length(&mut widget, self.max_length);
+        }
+
+        let adornment_color = self.get_adornment_color(&palette, &theme);
+        let adornments_changed = style_changed || self.adornment_color != entry.adornment_color;
+
+        if adornments_changed {
+            widgets::Entry::set_clear_color(&mut widget, adornment_color);
+        }
+
+        if adornments_changed || self.counter != entry.counter {
+            let counter = self
+                .counter
+                .then(|| self.build_style(adornment_color, &text_theme, &theme));
+
+            widgets::Entry::set_counter(&mut widget, counter);
+        }
+
+        if retheme || self.line_numbers != entry.line_numbers {
+            let gutter = self
+                .line_numbers
+                .then(|| build_gutter(&palette, &text_theme, &gutter_theme));
+
+            widgets::Entry::set_gutter(&mut widget, gutter);
+        }
+
+        // markers and validators can't be compared, so they're replaced on every rebuild
+        if let Some(line_marker) = self.line_marker.take() {
+            widgets::Entry::set_line_marker(&mut widget, line_marker);
+        }
+
+        if let Some(filter) = self.filter.take() {
+            widgets::Entry::set_filter(&mut widget, filter);
+        }
+
+        if let Some(validator) = self.validator.take() {
+            widgets::Entry::set_validator(&mut widget, validator);
+        }
+
+        drop(widget);
+
+        if adornments_changed || self.prefix != entry.prefix {
+            let prefix = self.prefix.as_deref();
+            let prefix = self.build_adornment(
+                cx,
+                prefix,
+                adornment_color,
+                &text_theme,
+                &theme,
+            );
+            widgets::Entry::set_leading(cx, *element, prefix);
+        }
+
+        if adornments_changed || self.suffix != entry.suffix {
+            let suffix = self.suffix.as_deref();
+            let suffix = self.build_adornment(
+                cx,
+                suffix,
+                adornment_color,
+                &text_theme,
+                &theme,
+            );
+            widgets::Entry::set_trailing(cx, *element, suffix);
+        }
+
         *entry = self;
     }
 
     fn event(
         _element: &mut Self::Element,
-        (id, entry): &mut Self::State,
-        _cx: &mut Context,
+        (id, entry, limiter): &mut Self::State,
+        cx: &mut Context,
         data: &mut T,
         event: &mut Event,
     ) -> Action {
+        if let Some(text) = limiter.event(event, *id) {
+            return (entry.on_change)(data, text);
+        }
+
         match event.take_targeted(*id) {
-            Some(EntryEvent::Change(text)) => (entry.on_change)(data, text),
-            Some(EntryEvent::Submit(text)) => (entry.on_submit)(data, text),
+            Some(EntryEvent::Change(text)) => match limiter.input(cx, *id, text) {
+                Some(text) => (entry.on_change)(data, text),
+                None => Action::new(),
+            },
+
+            Some(EntryEvent::Submit(text)) => {
+                // the change is delivered before the submission it led to
+                let action = match limiter.flush() {
+                    Some(pending) => (entry.on_change)(data, pending),
+                    None => Action::new(),
+                };
+
+                action | (entry.on_submit)(data, text)
+            }
+
             None => Action::new(),
         }
     }
 
-    fn teardown(element: Self::Element, (_id, _entry): Self::State, cx: &mut Context) {
+    fn teardown(element: Self::Element, (_id, _entry, _limiter): Self::State, cx: &mut Context) {
         cx.remove_widget(element);
     }
 }
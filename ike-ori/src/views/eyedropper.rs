@@ -0,0 +1,138 @@
+use ike_core::{Builder, Color, WidgetId, widgets};
+use ori::{Action, Event, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Create an [`Eyedropper`], picking colors from the window when `contents` is pressed and
+/// dragged.
+pub fn eyedropper<T, V, A>(
+    contents: V,
+    on_pick: impl FnMut(&mut T, Color) -> A + 'static,
+) -> Eyedropper<T, V>
+where
+    A: Into<Action>,
+{
+    Eyedropper::new(contents, on_pick)
+}
+
+pub struct Eyedropper<T, V> {
+    contents:   V,
+    properties: Properties<T>,
+}
+
+impl<T, V> Eyedropper<T, V> {
+    pub fn new<A>(contents: V, mut on_pick: impl FnMut(&mut T, Color) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            properties: Properties {
+                on_pick:   Box::new(move |data, color| on_pick(data, color).into()),
+                on_sample: Box::new(|_, _| Action::new()),
+            },
+        }
+    }
+
+    /// Called with the color under the pointer while picking, for previewing it.
+    pub fn on_sample<A>(mut self, mut on_sample: impl FnMut(&mut T, Color) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_sample = Box::new(move |data, color| on_sample(data, color).into());
+        self
+    }
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_pick:   Box<dyn FnMut(&mut T, Color) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_sample: Box<dyn FnMut(&mut T, Color) -> Action>,
+}
+
+enum EyedropperEvent {
+    Sampled(Color),
+    Picked(Color),
+}
+
+impl<T, V> ViewMarker for Eyedropper<T, V> {}
+impl<T, V> View<Context, T> for Eyedropper<T, V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Eyedropper>;
+    type State = (
+        ViewId,
+        Properties<T>,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let (contents, state) = self.contents.build(cx, data);
+        let mut widget = widgets::Eyedropper::new(cx, contents);
+
+        widgets::Eyedropper::set_on_sample(&mut widget, {
+            let proxy = proxy.cloned();
+
+            move |color| {
+                proxy.event(Event::new(
+                    EyedropperEvent::Sampled(color),
+                    id,
+                ));
+            }
+        });
+
+        widgets::Eyedropper::set_on_pick(&mut widget, move |color| {
+            proxy.event(Event::new(
+                EyedropperEvent::Picked(color),
+                id,
+            ));
+        });
+
+        (
+            widget.id(),
+            (id, self.properties, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        _element: &mut Self::Element,
+        (_id, properties, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(contents, state, cx, data);
+        *properties = self.properties;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (id, properties, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::event(contents, state, cx, data, event);
+
+        match event.take_targeted(*id) {
+            Some(EyedropperEvent::Sampled(color)) => action | (properties.on_sample)(data, color),
+            Some(EyedropperEvent::Picked(color)) => action | (properties.on_pick)(data, color),
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _properties, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
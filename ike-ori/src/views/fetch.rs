@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use ori::{Action, Event, NoElement, Proxied, Proxy, View, ViewId, ViewMarker};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    Context,
+    cancel::{Cancel, Cancellable},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    #[error("http error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Fetch `url` and deserialize the response as JSON, calling `on_result` with the result.
+///
+/// The request is sent when the view is built, and again whenever `url` changes. Requests that
+/// are still in flight when the url changes, or the view is removed, are cancelled.
+pub fn fetch_json<T, U, A>(
+    url: impl Into<String>,
+    on_result: impl FnMut(&mut T, Result<U, FetchError>) -> A + 'static,
+) -> FetchJson<T, U>
+where
+    A: Into<Action>,
+{
+    FetchJson::new(url, on_result)
+}
+
+pub struct FetchJson<T, U> {
+    url:        String,
+    properties: Properties<T, U>,
+}
+
+impl<T, U> FetchJson<T, U> {
+    pub fn new<A>(
+        url: impl Into<String>,
+        mut on_result: impl FnMut(&mut T, Result<U, FetchError>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            url:        url.into(),
+            properties: Properties {
+                on_result: Box::new(move |data, result| on_result(data, result).into()),
+                max_age:   None,
+            },
+        }
+    }
+
+    /// Reuse a cached response to the same url if it's younger than `max_age`.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.properties.max_age = Some(max_age);
+        self
+    }
+}
+
+pub struct Properties<T, U> {
+    #[allow(clippy::type_complexity)]
+    on_result: Box<dyn FnMut(&mut T, Result<U, FetchError>) -> Action>,
+    max_age:   Option<Duration>,
+}
+
+struct Fetched {
+    url:    String,
+    result: Result<Arc<[u8]>, reqwest::Error>,
+}
+
+type ResponseCache = Mutex<HashMap<String, (Instant, Arc<[u8]>)>>;
+
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new)
+}
+
+fn cache() -> &'static ResponseCache {
+    static CACHE: OnceLock<ResponseCache> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+fn cached(url: &str, max_age: Option<Duration>) -> Option<Arc<[u8]>> {
+    let max_age = max_age?;
+    let cache = cache().lock().unwrap_or_else(|err| err.into_inner());
+    let (time, body) = cache.get(url)?;
+
+    (time.elapsed() <= max_age).then(|| body.clone())
+}
+
+fn fetch(cx: &mut Context, id: ViewId, url: String, max_age: Option<Duration>) -> Arc<Cancel> {
+    let cancel = Arc::new(Cancel::default());
+    let proxy = cx.proxy();
+
+    if let Some(body) = cached(&url, max_age) {
+        let result = Ok(body);
+        proxy.event(Event::new(Fetched { url, result }, id));
+        return cancel;
+    }
+
+    let request = {
+        let url = url.clone();
+
+        async move {
+            let response = client().get(&url).send().await?.error_for_status()?;
+            let body = Arc::<[u8]>::from(response.bytes().await?.as_ref());
+
+            let mut cache = cache().lock().unwrap_or_else(|err| err.into_inner());
+            cache.insert(url, (Instant::now(), body.clone()));
+
+            Ok::<_, reqwest::Error>(body)
+        }
+    };
+
+    let future = Cancellable::new(request, cancel.clone());
+
+    proxy.spawn_boxed({
+        let proxy = proxy.cloned();
+
+        Box::pin(async move {
+            if let Some(result) = future.await {
+                proxy.event(Event::new(Fetched { url, result }, id));
+            }
+        })
+    });
+
+    cancel
+}
+
+impl<T, U> ViewMarker for FetchJson<T, U> {}
+impl<T, U> View<Context, T> for FetchJson<T, U>
+where
+    U: DeserializeOwned,
+{
+    type Element = NoElement;
+    type State = (
+        ViewId,
+        String,
+        Properties<T, U>,
+        Arc<Cancel>,
+    );
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let id = ViewId::next();
+        let cancel = fetch(
+            cx,
+            id,
+            self.url.clone(),
+            self.properties.max_age,
+        );
+
+        (
+            NoElement,
+            (id, self.url, self.properties, cancel),
+        )
+    }
+
+    fn rebuild(
+        self,
+        _element: (),
+        (id, url, properties, cancel): &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        if self.url != *url {
+            cancel.cancel();
+            *cancel = fetch(
+                cx,
+                *id,
+                self.url.clone(),
+                self.properties.max_age,
+            );
+        }
+
+        *url = self.url;
+        *properties = self.properties;
+    }
+
+    fn event(
+        _element: (),
+        (id, url, properties, _cancel): &mut Self::State,
+        _cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        match event.take_targeted(*id) {
+            // responses to a previous url may arrive before they were cancelled
+            Some(Fetched {
+                url: fetched,
+                result,
+            }) if fetched == *url => {
+                let result = match result {
+                    Ok(body) => serde_json::from_slice(&body).map_err(FetchError::from),
+                    Err(err) => Err(FetchError::from(err)),
+                };
+
+                (properties.on_result)(data, result)
+            }
+
+            _ => Action::new(),
+        }
+    }
+
+    fn teardown(_element: NoElement, (_, _, _, cancel): Self::State, _cx: &mut Context) {
+        cancel.cancel();
+    }
+}
@@ -0,0 +1,53 @@
+use ike_core::{Color, Paint, TextStyle, widgets};
+
+use crate::{Palette, views::TextTheme};
+
+/// The theme of the line number gutter of [`prose`](crate::views::prose) and
+/// [`entry`](crate::views::entry).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GutterTheme {
+    pub font_size:        Option<f32>,
+    pub font_family:      Option<String>,
+    pub color:            Option<Color>,
+    pub background_color: Option<Color>,
+    pub wrap_color:       Option<Color>,
+    pub padding:          f32,
+}
+
+impl Default for GutterTheme {
+    fn default() -> Self {
+        Self {
+            font_size:        None,
+            font_family:      None,
+            color:            None,
+            background_color: None,
+            wrap_color:       None,
+            padding:          8.0,
+        }
+    }
+}
+
+pub(crate) fn build_gutter(
+    palette: &Palette,
+    text_theme: &TextTheme,
+    theme: &GutterTheme,
+) -> widgets::Gutter {
+    let style = TextStyle {
+        font_size:    theme.font_size.unwrap_or(text_theme.font_size),
+        font_family:  theme
+            .font_family
+            .clone()
+            .unwrap_or_else(|| text_theme.font_family.clone().into_owned()),
+        font_weight:  text_theme.font_weight,
+        font_stretch: text_theme.font_stretch,
+        font_style:   text_theme.font_style,
+        paint:        Paint::from(theme.color.unwrap_or_else(|| palette.contrast_low(0))),
+    };
+
+    widgets::Gutter {
+        style,
+        padding: theme.padding,
+        background_color: theme.background_color.unwrap_or_else(|| palette.surface(0)),
+        wrap_color: theme.wrap_color.unwrap_or(palette.outline),
+    }
+}
@@ -0,0 +1,101 @@
+use ike_core::{Builder, Transition, WidgetId, widgets};
+use ori::{Action, Event, Mut, View, ViewMarker};
+
+use crate::Context;
+
+/// Share `contents` between screens, when a screen with a hero tagged `tag` replaces another
+/// with a hero of the same tag, the contents fly from where the old hero was.
+pub fn hero<V>(tag: impl Into<String>, contents: V) -> Hero<V> {
+    Hero::new(tag, contents)
+}
+
+pub struct Hero<V> {
+    contents:   V,
+    tag:        String,
+    transition: Transition,
+}
+
+impl<V> Hero<V> {
+    pub fn new(tag: impl Into<String>, contents: V) -> Self {
+        Self {
+            contents,
+            tag: tag.into(),
+            transition: Transition::ease(0.3),
+        }
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
+impl<V> ViewMarker for Hero<V> {}
+impl<T, V> View<Context, T> for Hero<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Hero>;
+    type State = (String, Transition, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = widgets::Hero::new(cx, self.tag.clone(), contents);
+        widgets::Hero::set_transition(&mut widget, self.transition);
+
+        (
+            widget.id(),
+            (
+                self.tag,
+                self.transition,
+                contents,
+                state,
+            ),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (tag, transition, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.tag != *tag {
+            widgets::Hero::set_tag(&mut widget, self.tag.clone());
+        }
+
+        if self.transition != *transition {
+            widgets::Hero::set_transition(&mut widget, self.transition);
+        }
+
+        *tag = self.tag;
+        *transition = self.transition;
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_tag, _transition, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_tag, _transition, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
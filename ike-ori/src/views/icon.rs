@@ -0,0 +1,158 @@
+use ike_core::{Builder, Color, WidgetId, widgets};
+use ori::{Action, Event, Provider, View, ViewMarker};
+
+use crate::{Context, Icons, Palette, is_retheming};
+
+/// Create an [`Icon`] showing the icon called `name` in the [`Icons`] of the theme.
+pub fn icon(name: impl Into<String>) -> Icon {
+    Icon::new(name)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IconTheme {
+    pub size:  f32,
+    pub color: Option<Color>,
+}
+
+impl Default for IconTheme {
+    fn default() -> Self {
+        Self {
+            size:  20.0,
+            color: None,
+        }
+    }
+}
+
+pub struct Icon {
+    name:     String,
+    size:     Option<f32>,
+    color:    Option<Color>,
+    mirrored: bool,
+}
+
+impl Icon {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name:     name.into(),
+            size:     None,
+            color:    None,
+            mirrored: false,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Mirror the icon horizontally in right-to-left layouts, e.g. for arrows pointing forward
+    /// or back.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    fn get_size(&self, theme: &IconTheme) -> f32 {
+        self.size.unwrap_or(theme.size)
+    }
+
+    fn get_color(&self, theme: &IconTheme, palette: &Palette) -> Color {
+        self.color
+            .unwrap_or_else(|| theme.color.unwrap_or(palette.contrast))
+    }
+
+    fn get_glyph(&self, icons: &Icons) -> Option<widgets::IconGlyph> {
+        let glyph = icons.get(&self.name);
+
+        if glyph.is_none() {
+            tracing::warn!(
+                "icon `{}` not found in any icon pack",
+                self.name
+            );
+        }
+
+        glyph
+    }
+}
+
+impl ViewMarker for Icon {}
+impl<T> View<Context, T> for Icon {
+    type Element = WidgetId<widgets::Icon>;
+    type State = Self;
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<IconTheme>();
+        let icons = cx.get_or_default::<Icons>();
+
+        let mut widget = widgets::Icon::new(
+            cx,
+            self.get_glyph(&icons),
+            self.get_size(&theme),
+            self.get_color(&theme, &palette),
+        );
+
+        widgets::Icon::set_mirrored(&mut widget, self.mirrored);
+
+        (widget.id(), self)
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        icon: &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<IconTheme>();
+        let icons = cx.get_or_default::<Icons>();
+        let retheme = is_retheming(cx);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if retheme || self.name != icon.name {
+            let glyph = self.get_glyph(&icons);
+
+            if widget.widget.glyph() != glyph.as_ref() {
+                widgets::Icon::set_glyph(&mut widget, glyph);
+            }
+        }
+
+        if retheme || self.size != icon.size {
+            widgets::Icon::set_size(&mut widget, self.get_size(&theme));
+        }
+
+        if retheme || self.color != icon.color {
+            let color = self.get_color(&theme, &palette);
+            widgets::Icon::set_color(&mut widget, color);
+        }
+
+        if self.mirrored != icon.mirrored {
+            widgets::Icon::set_mirrored(&mut widget, self.mirrored);
+        }
+
+        *icon = self;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        _state: &mut Self::State,
+        _cx: &mut Context,
+        _data: &mut T,
+        _event: &mut Event,
+    ) -> Action {
+        Action::new()
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context) {
+        cx.remove_widget(element);
+    }
+}
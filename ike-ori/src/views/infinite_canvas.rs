@@ -0,0 +1,385 @@
+use std::mem;
+
+use ike_core::{AnyWidgetId, Builder, Offset, Point, WidgetId, widgets};
+use ori::{
+    Action, Element, Elements, Event, Proxied, Proxy, Super, View, ViewId, ViewMarker, ViewSeq,
+};
+
+use crate::Context;
+
+/// Create an [`InfiniteCanvas`], with `contents` placed with [`placed`].
+pub fn infinite_canvas<T, V>(contents: V) -> InfiniteCanvas<T, V> {
+    InfiniteCanvas::new(contents)
+}
+
+/// Place `contents` at `position` on an [`InfiniteCanvas`].
+pub fn placed<V>(position: impl Into<Point>, contents: V) -> Placed<V> {
+    Placed::new(position.into(), contents)
+}
+
+pub struct InfiniteCanvas<T, V> {
+    contents:   V,
+    properties: Properties<T>,
+}
+
+impl<T, V> InfiniteCanvas<T, V> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            properties: Properties {
+                on_view_change: Box::new(|_, _, _| Action::new()),
+
+                view:       None,
+                zoom_range: (0.1, 10.0),
+            },
+        }
+    }
+
+    /// Set the `pan` and `zoom` of the view.
+    ///
+    /// The view is only moved when these change, so the user is free to move it in between.
+    pub fn view(mut self, pan: Offset, zoom: f32) -> Self {
+        self.properties.view = Some((pan, zoom));
+        self
+    }
+
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        self.properties.zoom_range = (min, max);
+        self
+    }
+
+    /// Called with the new pan and zoom when the user moves the view.
+    pub fn on_view_change<A>(
+        mut self,
+        mut on_view_change: impl FnMut(&mut T, Offset, f32) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_view_change =
+            Box::new(move |data, pan, zoom| on_view_change(data, pan, zoom).into());
+        self
+    }
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_view_change: Box<dyn FnMut(&mut T, Offset, f32) -> Action>,
+
+    view:       Option<(Offset, f32)>,
+    zoom_range: (f32, f32),
+}
+
+struct ViewChanged(Offset, f32);
+
+impl<T, V> ViewMarker for InfiniteCanvas<T, V> {}
+impl<T, V> View<Context, T> for InfiniteCanvas<T, V>
+where
+    V: ViewSeq<Context, T, Placed<WidgetId>>,
+{
+    type Element = WidgetId<widgets::InfiniteCanvas>;
+    type State = (
+        ViewId,
+        Properties<T>,
+        Vec<Placed<WidgetId>>,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let element = {
+            let mut widget = widgets::InfiniteCanvas::new(cx);
+
+            let (min, max) = self.properties.zoom_range;
+            widgets::InfiniteCanvas::set_zoom_range(&mut widget, min, max);
+
+            if let Some((pan, zoom)) = self.properties.view {
+                widgets::InfiniteCanvas::set_view(&mut widget, pan, zoom);
+            }
+
+            widgets::InfiniteCanvas::set_on_view_change(&mut widget, move |pan, zoom| {
+                proxy.event(Event::new(ViewChanged(pan, zoom), id));
+            });
+
+            widget.id()
+        };
+
+        let mut elements = Vec::new();
+        let states = self.contents.seq_build(
+            &mut PlacedElements::new(element, &mut elements),
+            cx,
+            data,
+        );
+
+        (
+            element,
+            (id, self.properties, elements, states),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (_id, properties, elements, states): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.seq_rebuild(
+            &mut PlacedElements::new(*element, elements),
+            states,
+            cx,
+            data,
+        );
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.properties.zoom_range != properties.zoom_range {
+            let (min, max) = self.properties.zoom_range;
+            widgets::InfiniteCanvas::set_zoom_range(&mut widget, min, max);
+        }
+
+        if self.properties.view != properties.view
+            && let Some((pan, zoom)) = self.properties.view
+        {
+            widgets::InfiniteCanvas::set_view(&mut widget, pan, zoom);
+        }
+
+        *properties = self.properties;
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (id, properties, elements, states): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::seq_event(
+            &mut PlacedElements::new(*element, elements),
+            states,
+            cx,
+            data,
+            event,
+        );
+
+        match event.take_targeted(*id) {
+            Some(ViewChanged(pan, zoom)) => action | (properties.on_view_change)(data, pan, zoom),
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _properties, mut elements, states): Self::State,
+        cx: &mut Context,
+    ) {
+        V::seq_teardown(
+            &mut PlacedElements::new(element, &mut elements),
+            states,
+            cx,
+        );
+
+        cx.remove_widget(element);
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Placed<V> {
+    contents: V,
+    position: Point,
+}
+
+impl<V> Placed<V> {
+    pub fn new(position: Point, contents: V) -> Self {
+        Self { contents, position }
+    }
+}
+
+impl<V> ViewMarker for Placed<V> {}
+impl<T, V> View<Context, T> for Placed<V>
+where
+    V: crate::View<T>,
+{
+    type Element = Placed<V::Element>;
+    type State = V::State;
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (element, state) = self.contents.build(cx, data);
+        let element = Placed {
+            contents: element,
+            position: self.position,
+        };
+
+        (element, state)
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(&mut element.contents, state, cx, data);
+        element.position = self.position;
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(
+            &mut element.contents,
+            state,
+            cx,
+            data,
+            event,
+        )
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context) {
+        V::teardown(element.contents, state, cx);
+    }
+}
+
+struct PlacedElements<'a> {
+    widget:   WidgetId<widgets::InfiniteCanvas>,
+    elements: &'a mut Vec<Placed<WidgetId>>,
+    index:    usize,
+}
+
+impl<'a> PlacedElements<'a> {
+    fn new(
+        widget: WidgetId<widgets::InfiniteCanvas>,
+        elements: &'a mut Vec<Placed<WidgetId>>,
+    ) -> Self {
+        Self {
+            widget,
+            elements,
+            index: 0,
+        }
+    }
+
+    fn set_position(&self, cx: &mut Context, position: Point) {
+        if let Ok(mut widget) = cx.get_widget_mut(self.widget)
+            && widget.widget.get_position(self.index) != position
+        {
+            widgets::InfiniteCanvas::set_position(&mut widget, self.index, position);
+        }
+    }
+}
+
+impl<'a> Elements<Context, Placed<WidgetId>> for PlacedElements<'a> {
+    fn next(&mut self, cx: &mut Context) -> Option<&mut Placed<WidgetId>> {
+        let position = self.elements.get(self.index)?.position;
+        self.set_position(cx, position);
+
+        let element = self.elements.get_mut(self.index)?;
+        self.index += 1;
+        Some(element)
+    }
+
+    fn insert(&mut self, cx: &mut Context, element: Placed<WidgetId>) {
+        cx.insert_child(
+            self.widget,
+            self.index,
+            element.contents,
+        );
+
+        self.set_position(cx, element.position);
+
+        self.elements.insert(self.index, element);
+        self.index += 1;
+    }
+
+    fn remove(&mut self, cx: &mut Context) -> Option<Placed<WidgetId>> {
+        cx.remove_child(self.widget, self.index);
+        Some(self.elements.remove(self.index))
+    }
+
+    fn swap(&mut self, cx: &mut Context, offset: usize) {
+        cx.swap_children(
+            self.widget,
+            self.index,
+            self.index + offset,
+        );
+
+        self.elements.swap(self.index, self.index + offset);
+    }
+}
+
+impl<V> Element<Context> for Placed<V> {
+    type Mut<'a>
+        = &'a mut Self
+    where
+        V: 'a;
+}
+
+impl<T> Super<Context, WidgetId<T>> for Placed<WidgetId>
+where
+    T: ?Sized,
+{
+    fn replace(cx: &mut Context, this: &mut Self, other: WidgetId<T>) -> Self {
+        cx.replace_widget(this.contents, other);
+        this.contents = other.upcast();
+        this.position = Point::ORIGIN;
+        *this
+    }
+
+    fn upcast(_cx: &mut Context, sub: WidgetId<T>) -> Self {
+        Placed {
+            contents: sub.upcast(),
+            position: Point::ORIGIN,
+        }
+    }
+
+    fn downcast(self) -> WidgetId<T> {
+        self.contents.downcast()
+    }
+
+    fn downcast_with<U>(this: &mut Self, f: impl FnOnce(&mut WidgetId<T>) -> U) -> U {
+        let mut widget = this.downcast();
+        let output = f(&mut widget);
+        this.contents = widget.upcast();
+        output
+    }
+}
+
+impl<T> Super<Context, Placed<WidgetId<T>>> for Placed<WidgetId>
+where
+    T: ?Sized,
+{
+    fn replace(cx: &mut Context, this: &mut Placed<WidgetId>, other: Placed<WidgetId<T>>) -> Self {
+        cx.replace_widget(this.contents, other.contents);
+        mem::replace(this, Placed::upcast(cx, other))
+    }
+
+    fn upcast(_cx: &mut Context, sub: Placed<WidgetId<T>>) -> Self {
+        Self {
+            contents: sub.contents.upcast(),
+            position: sub.position,
+        }
+    }
+
+    fn downcast(self) -> Placed<WidgetId<T>> {
+        Placed {
+            contents: self.contents.downcast(),
+            position: self.position,
+        }
+    }
+
+    fn downcast_with<U>(this: &mut Self, f: impl FnOnce(&mut Placed<WidgetId<T>>) -> U) -> U {
+        let mut widget = this.downcast();
+        let output = f(&mut widget);
+        this.contents = widget.contents.upcast();
+        this.position = widget.position;
+        output
+    }
+}
@@ -4,7 +4,7 @@ use ike_core::{
 };
 use ori::{Action, Provider, View, ViewMarker};
 
-use crate::{Context, Palette, views::TextTheme};
+use crate::{Context, Palette, is_retheming, l10n::Value, views::TextTheme};
 
 pub fn label(text: impl ToString) -> Label {
     Label::new(text)
@@ -12,6 +12,7 @@ pub fn label(text: impl ToString) -> Label {
 
 pub struct Label {
     text:         String,
+    message:      Option<(String, Vec<(&'static str, Value)>)>,
     font_size:    Option<f32>,
     font_family:  Option<String>,
     font_weight:  Option<FontWeight>,
@@ -27,6 +28,7 @@ impl Label {
     pub fn new(text: impl ToString) -> Self {
         Self {
             text:         text.to_string(),
+            message:      None,
             font_size:    None,
             font_family:  None,
             font_weight:  None,
@@ -39,6 +41,14 @@ impl Label {
         }
     }
 
+    /// A label showing the message `key` translated with `arguments`, see
+    /// [`tr!`](crate::tr).
+    pub fn translated(key: impl ToString, arguments: Vec<(&'static str, Value)>) -> Self {
+        let mut label = Self::new("");
+        label.message = Some((key.to_string(), arguments));
+        label
+    }
+
     pub fn font_size(mut self, font_size: f32) -> Self {
         self.font_size = Some(font_size);
         self
@@ -84,6 +94,13 @@ impl Label {
         self
     }
 
+    /// Translate the message of the label to the current locale, if it has one.
+    fn translate(&mut self, cx: &Context) {
+        if let Some((ref key, ref arguments)) = self.message {
+            self.text = cx.translate(key, arguments);
+        }
+    }
+
     fn build_paragraph(&self, palette: &Palette, theme: &TextTheme) -> Paragraph {
         let style = TextStyle {
             font_size:    self.font_size.unwrap_or(theme.font_size),
@@ -118,7 +135,9 @@ impl<T> View<Context, T> for Label {
     type Element = WidgetId<widgets::Label>;
     type State = Self;
 
-    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+    fn build(mut self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        self.translate(cx);
+
         let palette = cx.get_or_default::<Palette>();
         let theme = cx.get_or_default::<TextTheme>();
 
@@ -129,13 +148,18 @@ impl<T> View<Context, T> for Label {
     }
 
     fn rebuild(
-        self,
+        mut self,
         element: &mut Self::Element,
         label: &mut Self::State,
         cx: &mut Context,
         _data: &mut T,
     ) {
-        if self.text != label.text
+        self.translate(cx);
+
+        let retheme = is_retheming(cx);
+
+        if retheme
+            || self.text != label.text
             || self.font_size != label.font_size
             || self.font_family != label.font_family
             || self.font_weight != label.font_weight
@@ -0,0 +1,134 @@
+use ike_core::{Builder, WidgetId, widgets};
+use ori::{Action, Event, View, ViewMarker};
+
+use crate::Context;
+
+/// Defer building the view returned by `build` until it is [`active`](Lazy::active).
+///
+/// `build` is only called while the view is active, so expensive subtrees of collapsed
+/// sections or hidden pages cost nothing until they are first shown.
+pub fn lazy<F, V>(build: F) -> Lazy<F>
+where
+    F: FnOnce() -> V,
+{
+    Lazy::new(build)
+}
+
+pub struct Lazy<F> {
+    build:      F,
+    active:     bool,
+    keep_alive: bool,
+}
+
+impl<F> Lazy<F> {
+    pub fn new(build: F) -> Self {
+        Self {
+            build,
+            active: true,
+            keep_alive: false,
+        }
+    }
+
+    /// Set whether the contents are needed, e.g. whether they are visible, expanded or the page
+    /// of the selected tab.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Keep the contents, and their state, when the view becomes inactive, instead of tearing
+    /// them down and building them again when it becomes active.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+}
+
+impl<F> ViewMarker for Lazy<F> {}
+impl<T, F, V> View<Context, T> for Lazy<F>
+where
+    F: FnOnce() -> V,
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Lazy>;
+    type State = (bool, Option<(V::Element, V::State)>);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let element = widgets::Lazy::new(cx).id();
+
+        let contents = if self.active {
+            let (contents, state) = (self.build)().build(cx, data);
+            widgets::Lazy::set_contents(cx, element, contents);
+
+            Some((contents, state))
+        } else {
+            None
+        };
+
+        (element, (self.active, contents))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (active, contents): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        match contents.take() {
+            Some((mut child, mut state)) if self.active => {
+                (self.build)().rebuild(&mut child, &mut state, cx, data);
+
+                if !*active {
+                    cx.set_stashed(child, false);
+                }
+
+                *contents = Some((child, state));
+            }
+
+            None if self.active => {
+                let (new_contents, state) = (self.build)().build(cx, data);
+                widgets::Lazy::set_contents(cx, *element, new_contents);
+
+                *contents = Some((new_contents, state));
+            }
+
+            Some((child, state)) if self.keep_alive => {
+                if *active {
+                    cx.set_stashed(child, true);
+                }
+
+                *contents = Some((child, state));
+            }
+
+            Some((child, state)) => {
+                V::teardown(child, state, cx);
+            }
+
+            None => {}
+        }
+
+        *active = self.active;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (_active, contents): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        match contents {
+            Some((element, state)) => V::event(element, state, cx, data, event),
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, (_active, contents): Self::State, cx: &mut Context) {
+        if let Some((contents, state)) = contents {
+            V::teardown(contents, state, cx);
+        }
+
+        cx.remove_widget(element);
+    }
+}
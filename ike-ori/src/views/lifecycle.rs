@@ -0,0 +1,126 @@
+use ike_core::{Builder, Lifecycle};
+use ori::{Action, Event, NoElement, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Call `on_change` when the application moves through its [`Lifecycle`], e.g. when it's sent to
+/// the background on mobile.
+pub fn on_lifecycle<T, A>(on_change: impl FnMut(&mut T, Lifecycle) -> A + 'static) -> OnLifecycle<T>
+where
+    A: Into<Action>,
+{
+    OnLifecycle::new(on_change)
+}
+
+/// Have the platform save `state`, for when the application is killed in the background, see
+/// [`Builder::save_state`].
+///
+/// The state is saved when the view is built, and again whenever it changes.
+pub fn save_state(state: impl Into<Vec<u8>>) -> SaveState {
+    SaveState::new(state)
+}
+
+pub struct OnLifecycle<T> {
+    on_change: Box<dyn FnMut(&mut T, Lifecycle) -> Action>,
+}
+
+impl<T> OnLifecycle<T> {
+    pub fn new<A>(mut on_change: impl FnMut(&mut T, Lifecycle) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            on_change: Box::new(move |data, lifecycle| on_change(data, lifecycle).into()),
+        }
+    }
+}
+
+struct LifecycleChanged(Lifecycle);
+
+impl<T> ViewMarker for OnLifecycle<T> {}
+impl<T> View<Context, T> for OnLifecycle<T> {
+    type Element = NoElement;
+    type State = (ViewId, Lifecycle, OnLifecycle<T>);
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let id = ViewId::next();
+        let lifecycle = cx.world.lifecycle();
+
+        (NoElement, (id, lifecycle, self))
+    }
+
+    fn rebuild(
+        self,
+        _element: (),
+        (id, lifecycle, on_lifecycle): &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        *on_lifecycle = self;
+
+        // the shell rebuilds when the lifecycle changes, but the data can only be mutated in
+        // response to an event
+        if cx.world.lifecycle() != *lifecycle {
+            *lifecycle = cx.world.lifecycle();
+            let event = LifecycleChanged(*lifecycle);
+            cx.proxy().event(Event::new(event, *id));
+        }
+    }
+
+    fn event(
+        _element: (),
+        (id, _lifecycle, on_lifecycle): &mut Self::State,
+        _cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        match event.take_targeted(*id) {
+            Some(LifecycleChanged(lifecycle)) => (on_lifecycle.on_change)(data, lifecycle),
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(_element: NoElement, _state: Self::State, _cx: &mut Context) {}
+}
+
+pub struct SaveState {
+    state: Vec<u8>,
+}
+
+impl SaveState {
+    pub fn new(state: impl Into<Vec<u8>>) -> Self {
+        Self {
+            state: state.into(),
+        }
+    }
+}
+
+impl ViewMarker for SaveState {}
+impl<T> View<Context, T> for SaveState {
+    type Element = NoElement;
+    type State = Vec<u8>;
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        cx.save_state(self.state.clone());
+        (NoElement, self.state)
+    }
+
+    fn rebuild(self, _element: (), state: &mut Self::State, cx: &mut Context, _data: &mut T) {
+        if self.state != *state {
+            cx.save_state(self.state.clone());
+            *state = self.state;
+        }
+    }
+
+    fn event(
+        _element: (),
+        _state: &mut Self::State,
+        _cx: &mut Context,
+        _data: &mut T,
+        _event: &mut Event,
+    ) -> Action {
+        Action::new()
+    }
+
+    fn teardown(_element: NoElement, _state: Self::State, _cx: &mut Context) {}
+}
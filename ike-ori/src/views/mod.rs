@@ -1,45 +1,127 @@
 mod aligned;
+mod animated;
+mod animated_visibility;
+mod avatar;
+mod badge;
 mod button;
+mod cache;
+mod cached_picture;
+mod code_block;
 mod constrain;
 mod container;
+mod dashboard;
+mod dialogs;
+mod direction;
+mod disabled;
+mod dismissable;
 mod divider;
 mod entry;
+mod eyedropper;
+#[cfg(feature = "http")]
+mod fetch;
+mod gutter;
+mod hero;
+mod icon;
+mod ime_padding;
+mod infinite_canvas;
 mod label;
+mod lazy;
+mod lifecycle;
+mod navigator;
+mod node_graph;
+mod numeric_entry;
+mod opacity;
 mod pad;
+mod painter;
 mod palette;
 mod picture;
+mod plot_area;
 mod prose;
+mod redact;
+mod responsive;
 mod safe_area;
 mod scroll;
 mod spacer;
 mod stack;
+mod sticky;
+mod subscribe;
+mod table;
+mod tabs;
+mod task;
+mod test_id;
 mod text;
+mod toasts;
 mod transform;
+#[cfg(feature = "websocket")]
+mod websocket;
 mod window;
+mod window_area;
+mod zoom;
 mod zstack;
 
 pub use aligned::{
     Aligned, align, bottom, bottom_left, bottom_right, center, left, right, top, top_left,
     top_right,
 };
+pub use animated::{Animated, animated};
+pub use animated_visibility::{AnimatedVisibility, animated_visibility};
+pub use avatar::{Avatar, AvatarContents, AvatarSize, AvatarTheme, avatar};
+pub use badge::{Badge, BadgeIndicator, BadgeTheme, badge};
 pub use button::{Button, ButtonTheme, button};
+pub use cache::{Cache, cache};
+pub use cached_picture::{CachedPicture, cached_picture};
+pub use code_block::{CodeBlock, CodeBlockTheme, code_block};
 pub use constrain::{
     Constrain, constrain, fill, fill_height, fill_width, height, max_height, max_size, max_width,
     min_height, min_size, min_width, size, width,
 };
 pub use container::{Container, ContainerTheme, container};
+pub use dashboard::{Dashboard, DashboardTheme, Tiled, dashboard, tile};
+pub use dialogs::{DialogAnswer, DialogHost, DialogTheme, Dialogs, dialogs};
+pub use direction::{Direction, direction};
+pub use disabled::{Disabled, DisabledTheme, disabled};
+pub use dismissable::{Dismissable, dismissable};
 pub use divider::{Divider, DividerTheme, divider, hdivider, vdivider};
-pub use entry::{Entry, EntryTheme, entry};
+pub use entry::{Entry, EntryTheme, entry, password_entry};
+pub use eyedropper::{Eyedropper, eyedropper};
+#[cfg(feature = "http")]
+pub use fetch::{FetchError, FetchJson, fetch_json};
+pub use gutter::GutterTheme;
+pub use hero::{Hero, hero};
+pub use icon::{Icon, IconTheme, icon};
+pub use ime_padding::{ImePadding, ime_padding};
+pub use infinite_canvas::{InfiniteCanvas, Placed, infinite_canvas, placed};
 pub use label::{Label, label};
+pub use lazy::{Lazy, lazy};
+pub use lifecycle::{OnLifecycle, SaveState, on_lifecycle, save_state};
+pub use navigator::{Navigation, Navigator, NavigatorTheme, navigator};
+pub use node_graph::{NodeGraph, NodeGraphTheme, node_graph};
+pub use numeric_entry::{NumericEntry, numeric_entry};
+pub use opacity::{Opacity, opacity};
 pub use pad::{Pad, pad};
+pub use painter::{CanvasView, painter};
 pub use palette::palette;
 pub use picture::{Picture, picture};
+pub use plot_area::{PlotArea, PlotAreaTheme, plot_area};
 pub use prose::{Prose, ProseTheme, prose};
+pub use redact::{Redact, RedactTheme, redact};
+pub use responsive::{OnSizeChange, Responsive, SizeClass, SizeQuery, on_size_change, responsive};
 pub use safe_area::{SafeArea, safe_area};
-pub use scroll::{Scroll, hscroll, vscroll};
+pub use scroll::{Scroll, Scroller, hscroll, minimap, vscroll};
 pub use spacer::{Spacer, hspacer, spacer, vspacer};
 pub use stack::{Flex, Stack, expand, flex, hstack, stack, vstack};
+pub use sticky::{Sticky, sticky};
+pub use subscribe::{Backpressure, Subscribe, subscribe};
+pub use table::{Table, TableTheme, paged_table, table};
+pub use tabs::{Tabs, TabsTheme, tabs};
+pub use task::{Task, TaskState, task};
+pub use test_id::{TestId, WithTestId, test_id};
 pub use text::TextTheme;
+pub use toasts::{Toast, ToastId, ToastTheme, Toaster, Toasts, toasts};
 pub use transform::{Transform, transform};
-pub use window::{Window, window};
-pub use zstack::{ZStack, zstack};
+#[cfg(feature = "websocket")]
+pub use websocket::{WebSocket, WebSocketError, WebSocketEvent, websocket};
+pub use window::{Window, WithChildren, window};
+pub use window_area::{WindowArea, window_area, window_drag_area};
+pub use zoom::{Zoom, zoom};
+pub use zstack::{Layered, ZStack, layered, zstack};
@@ -0,0 +1,329 @@
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use ike_core::{AnyWidgetId, Builder, Transition, WidgetId, widgets};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Show the routes of `navigation` as a stack of pages, each built by `page`.
+///
+/// The `navigation` is provided in the context of the pages, so they can navigate without it
+/// being passed down.
+pub fn navigator<T, R, F, V>(navigation: &Navigation<R>, page: F) -> Navigator<T, R, F>
+where
+    F: Fn(&T, &R) -> V,
+{
+    Navigator::new(navigation, page)
+}
+
+/// The history of routes of a [`Navigator`], usually kept in the app data.
+///
+/// Navigating changes the routes right away, and the navigator picks them up when it's next
+/// rebuilt, the root route is never popped.
+#[derive(Clone, Debug)]
+pub struct Navigation<R> {
+    routes: Arc<Mutex<Vec<R>>>,
+}
+
+impl<R> Navigation<R> {
+    pub fn new(root: R) -> Self {
+        Self {
+            routes: Arc::new(Mutex::new(vec![root])),
+        }
+    }
+
+    /// Navigate to `route`, on top of the current one.
+    pub fn push(&self, route: R) {
+        self.lock().push(route);
+    }
+
+    /// Go back to the previous route, returning the current one.
+    pub fn pop(&self) -> Option<R> {
+        let mut routes = self.lock();
+
+        match routes.len() > 1 {
+            true => routes.pop(),
+            false => None,
+        }
+    }
+
+    /// Navigate to `route` in place of the current one.
+    pub fn replace(&self, route: R) -> R {
+        let mut routes = self.lock();
+        let index = routes.len() - 1;
+        std::mem::replace(&mut routes[index], route)
+    }
+
+    /// Go back to the root route.
+    pub fn pop_to_root(&self) {
+        self.lock().truncate(1);
+    }
+
+    /// Whether there's a route to go back to.
+    pub fn can_pop(&self) -> bool {
+        self.lock().len() > 1
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<R>> {
+        self.routes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.routes, &other.routes)
+    }
+}
+
+impl<R: Clone> Navigation<R> {
+    pub fn current(&self) -> R {
+        let routes = self.lock();
+        routes[routes.len() - 1].clone()
+    }
+
+    pub fn routes(&self) -> Vec<R> {
+        self.lock().clone()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavigatorTheme {
+    pub transition: Transition,
+
+    /// How far the page below the one sliding is moved, relative to the width.
+    pub parallax: f32,
+}
+
+impl Default for NavigatorTheme {
+    fn default() -> Self {
+        Self {
+            transition: Transition::ease(0.3),
+            parallax:   0.3,
+        }
+    }
+}
+
+pub struct Navigator<T, R, F> {
+    navigation: Navigation<R>,
+    page:       F,
+    transition: Option<Transition>,
+    marker:     PhantomData<fn(&T)>,
+}
+
+impl<T, R, F> Navigator<T, R, F> {
+    pub fn new(navigation: &Navigation<R>, page: F) -> Self {
+        Self {
+            navigation: navigation.clone(),
+            page,
+            transition: None,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+}
+
+enum NavigatorEvent {
+    Back,
+    Removed(WidgetId),
+}
+
+pub struct NavigatorState<R, E, S> {
+    id:         ViewId,
+    navigation: Navigation<R>,
+    pages:      Vec<(R, E, S)>,
+    leaving:    Vec<(E, S)>,
+}
+
+impl<R, E, S> NavigatorState<R, E, S>
+where
+    R: PartialEq,
+    E: AnyWidgetId + Copy,
+{
+    /// Pop the pages that are no longer in `routes`, returning how many are kept.
+    fn pop_pages(
+        &mut self,
+        cx: &mut Context,
+        element: WidgetId<widgets::Navigator>,
+        routes: &[R],
+    ) -> usize {
+        let kept = (self.pages.iter())
+            .zip(routes)
+            .take_while(|((page, _, _), route)| page == *route)
+            .count();
+
+        while self.pages.len() > kept
+            && let Some((_, child, state)) = self.pages.pop()
+        {
+            if let Ok(mut widget) = cx.get_widget_mut(element) {
+                widgets::Navigator::pop(&mut widget, child);
+            }
+
+            self.leaving.push((child, state));
+        }
+
+        kept
+    }
+}
+
+impl<T, R, F> ViewMarker for Navigator<T, R, F> {}
+impl<T, R, F, V> View<Context, T> for Navigator<T, R, F>
+where
+    R: Clone + PartialEq + 'static,
+    F: Fn(&T, &R) -> V,
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Navigator>;
+    type State = NavigatorState<R, V::Element, V::State>;
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let theme = cx.get_or_default::<NavigatorTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let element = {
+            let mut widget = widgets::Navigator::new(cx);
+
+            let transition = self.transition.unwrap_or(theme.transition);
+            widgets::Navigator::set_transition(&mut widget, transition);
+            widgets::Navigator::set_parallax(&mut widget, theme.parallax);
+
+            widgets::Navigator::set_on_remove(&mut widget, {
+                let proxy = proxy.clone();
+                move |page| {
+                    proxy.event(Event::new(
+                        NavigatorEvent::Removed(page),
+                        id,
+                    ))
+                }
+            });
+
+            widgets::Navigator::set_on_back(&mut widget, move || {
+                proxy.event(Event::new(NavigatorEvent::Back, id));
+            });
+
+            widget.id()
+        };
+
+        let mut pages = Vec::new();
+
+        cx.push(Box::new(self.navigation.clone()));
+
+        for route in self.navigation.routes() {
+            let (child, state) = (self.page)(data, &route).build(cx, data);
+            cx.add_child(element, child);
+            pages.push((route, child, state));
+        }
+
+        cx.pop::<Navigation<R>>();
+
+        let state = NavigatorState {
+            id,
+            navigation: self.navigation,
+            pages,
+            leaving: Vec::new(),
+        };
+
+        (element, state)
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        let theme = cx.get_or_default::<NavigatorTheme>();
+
+        if let Ok(mut widget) = cx.get_widget_mut(*element) {
+            let transition = self.transition.unwrap_or(theme.transition);
+            widgets::Navigator::set_transition(&mut widget, transition);
+            widgets::Navigator::set_parallax(&mut widget, theme.parallax);
+        }
+
+        if !self.navigation.is_same(&state.navigation) {
+            state.navigation = self.navigation;
+        }
+
+        let routes = state.navigation.routes();
+        let kept = state.pop_pages(cx, *element, &routes);
+
+        cx.push(Box::new(state.navigation.clone()));
+
+        for (route, child, page) in &mut state.pages {
+            (self.page)(data, route).rebuild(child, page, cx, data);
+        }
+
+        for route in &routes[kept..] {
+            let (child, page) = (self.page)(data, route).build(cx, data);
+            cx.add_child(*element, child);
+            state.pages.push((route.clone(), child, page));
+        }
+
+        cx.pop::<Navigation<R>>();
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let mut action = Action::new();
+
+        cx.push(Box::new(state.navigation.clone()));
+
+        for (_, child, page) in &mut state.pages {
+            action = action | V::event(child, page, cx, data, event);
+        }
+
+        for (child, page) in &mut state.leaving {
+            action = action | V::event(child, page, cx, data, event);
+        }
+
+        cx.pop::<Navigation<R>>();
+
+        match event.take_targeted(state.id) {
+            Some(NavigatorEvent::Back) => {
+                state.navigation.pop();
+
+                let routes = state.navigation.routes();
+                state.pop_pages(cx, *element, &routes);
+
+                // rebuild, so views showing the current route are updated
+                action | Action::from(())
+            }
+
+            Some(NavigatorEvent::Removed(widget)) => {
+                if let Some(index) =
+                    (state.leaving.iter()).position(|(child, _)| child.upcast() == widget)
+                {
+                    let (child, page) = state.leaving.remove(index);
+                    V::teardown(child, page, cx);
+                }
+
+                action
+            }
+
+            None => action,
+        }
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context) {
+        for (_, child, page) in state.pages {
+            V::teardown(child, page, cx);
+        }
+
+        for (child, page) in state.leaving {
+            V::teardown(child, page, cx);
+        }
+
+        cx.remove_widget(element);
+    }
+}
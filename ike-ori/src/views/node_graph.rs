@@ -0,0 +1,449 @@
+use ike_core::{
+    Builder, Color, Offset, Point, WidgetId,
+    widgets::{self, Port, Wire},
+};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker, ViewSeq};
+
+use crate::{
+    Context, Palette, is_retheming,
+    views::{InfiniteCanvas, Placed},
+};
+
+/// Create a [`NodeGraph`], with nodes placed with [`placed`](crate::views::placed).
+///
+/// The ports of the nodes are set with [`NodeGraph::ports`], in the same order as the nodes.
+pub fn node_graph<T, V>(contents: V) -> NodeGraph<T, V> {
+    NodeGraph::new(contents)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeGraphTheme {
+    pub port_radius:   f32,
+    pub wire_width:    f32,
+    pub wire_color:    Option<Color>,
+    pub select_color:  Option<Color>,
+    pub marquee_color: Option<Color>,
+}
+
+impl Default for NodeGraphTheme {
+    fn default() -> Self {
+        Self {
+            port_radius:   5.0,
+            wire_width:    2.0,
+            wire_color:    None,
+            select_color:  None,
+            marquee_color: None,
+        }
+    }
+}
+
+pub struct NodeGraph<T, V> {
+    canvas:     InfiniteCanvas<T, V>,
+    properties: Properties<T>,
+}
+
+impl<T, V> NodeGraph<T, V> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            canvas:     InfiniteCanvas::new(contents),
+            properties: Properties {
+                on_move:       Box::new(|_, _, _| Action::new()),
+                on_connect:    Box::new(|_, _| Action::new()),
+                on_disconnect: Box::new(|_, _| Action::new()),
+                on_delete:     Box::new(|_, _| Action::new()),
+                on_select:     Box::new(|_, _| Action::new()),
+
+                ports:         Vec::new(),
+                wires:         Vec::new(),
+                selected:      None,
+                port_radius:   None,
+                wire_width:    None,
+                wire_color:    None,
+                select_color:  None,
+                marquee_color: None,
+            },
+        }
+    }
+
+    /// Set the ports of every node, indexed by node.
+    pub fn ports(mut self, ports: impl Into<Vec<Vec<Port>>>) -> Self {
+        self.properties.ports = ports.into();
+        self
+    }
+
+    pub fn wires(mut self, wires: impl Into<Vec<Wire>>) -> Self {
+        self.properties.wires = wires.into();
+        self
+    }
+
+    /// Set the selected nodes, if not set the selection is left to the user.
+    pub fn selected(mut self, selected: impl Into<Vec<usize>>) -> Self {
+        self.properties.selected = Some(selected.into());
+        self
+    }
+
+    /// Set the `pan` and `zoom` of the view, see [`InfiniteCanvas::view`].
+    pub fn view(mut self, pan: Offset, zoom: f32) -> Self {
+        self.canvas = self.canvas.view(pan, zoom);
+        self
+    }
+
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        self.canvas = self.canvas.zoom_range(min, max);
+        self
+    }
+
+    /// Called with the new pan and zoom when the user moves the view.
+    pub fn on_view_change<A>(
+        mut self,
+        on_view_change: impl FnMut(&mut T, Offset, f32) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.canvas = self.canvas.on_view_change(on_view_change);
+        self
+    }
+
+    /// Called with the index of a node and its new position when it's dragged.
+    pub fn on_move<A>(
+        mut self,
+        mut on_move: impl FnMut(&mut T, usize, Point) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_move =
+            Box::new(move |data, node, position| on_move(data, node, position).into());
+        self
+    }
+
+    /// Called when the user connects two ports, the wire is expected to be added.
+    pub fn on_connect<A>(mut self, mut on_connect: impl FnMut(&mut T, Wire) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_connect = Box::new(move |data, wire| on_connect(data, wire).into());
+        self
+    }
+
+    /// Called with the index of a wire when the user deletes it.
+    pub fn on_disconnect<A>(
+        mut self,
+        mut on_disconnect: impl FnMut(&mut T, usize) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_disconnect =
+            Box::new(move |data, wire| on_disconnect(data, wire).into());
+        self
+    }
+
+    /// Called with the selected nodes when the user deletes them.
+    pub fn on_delete<A>(
+        mut self,
+        mut on_delete: impl FnMut(&mut T, Vec<usize>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_delete = Box::new(move |data, nodes| on_delete(data, nodes).into());
+        self
+    }
+
+    /// Called with the selected nodes when the selection changes.
+    pub fn on_select<A>(
+        mut self,
+        mut on_select: impl FnMut(&mut T, Vec<usize>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_select = Box::new(move |data, nodes| on_select(data, nodes).into());
+        self
+    }
+
+    pub fn port_radius(mut self, radius: f32) -> Self {
+        self.properties.port_radius = Some(radius);
+        self
+    }
+
+    pub fn wire_width(mut self, width: f32) -> Self {
+        self.properties.wire_width = Some(width);
+        self
+    }
+
+    pub fn wire_color(mut self, color: Color) -> Self {
+        self.properties.wire_color = Some(color);
+        self
+    }
+
+    pub fn select_color(mut self, color: Color) -> Self {
+        self.properties.select_color = Some(color);
+        self
+    }
+
+    pub fn marquee_color(mut self, color: Color) -> Self {
+        self.properties.marquee_color = Some(color);
+        self
+    }
+}
+
+enum NodeGraphEvent {
+    Move(usize, Point),
+    Connect(Wire),
+    Disconnect(usize),
+    Delete(Vec<usize>),
+    Select(Vec<usize>),
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_move:       Box<dyn FnMut(&mut T, usize, Point) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_connect:    Box<dyn FnMut(&mut T, Wire) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_disconnect: Box<dyn FnMut(&mut T, usize) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_delete:     Box<dyn FnMut(&mut T, Vec<usize>) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_select:     Box<dyn FnMut(&mut T, Vec<usize>) -> Action>,
+
+    ports:         Vec<Vec<Port>>,
+    wires:         Vec<Wire>,
+    selected:      Option<Vec<usize>>,
+    port_radius:   Option<f32>,
+    wire_width:    Option<f32>,
+    wire_color:    Option<Color>,
+    select_color:  Option<Color>,
+    marquee_color: Option<Color>,
+}
+
+impl<T> Properties<T> {
+    fn get_port_radius(&self, theme: &NodeGraphTheme) -> f32 {
+        self.port_radius.unwrap_or(theme.port_radius)
+    }
+
+    fn get_wire_width(&self, theme: &NodeGraphTheme) -> f32 {
+        self.wire_width.unwrap_or(theme.wire_width)
+    }
+
+    fn get_wire_color(&self, theme: &NodeGraphTheme, palette: &Palette) -> Color {
+        self.wire_color
+            .unwrap_or_else(|| theme.wire_color.unwrap_or(palette.contrast))
+    }
+
+    fn get_select_color(&self, theme: &NodeGraphTheme, palette: &Palette) -> Color {
+        self.select_color
+            .unwrap_or_else(|| theme.select_color.unwrap_or(palette.primary))
+    }
+
+    fn get_marquee_color(&self, theme: &NodeGraphTheme, palette: &Palette) -> Color {
+        self.marquee_color.unwrap_or_else(|| {
+            theme
+                .marquee_color
+                .unwrap_or_else(|| palette.primary.fade(0.2))
+        })
+    }
+}
+
+impl<T, V> ViewMarker for NodeGraph<T, V> {}
+impl<T, V> View<Context, T> for NodeGraph<T, V>
+where
+    V: ViewSeq<Context, T, Placed<WidgetId>>,
+{
+    type Element = WidgetId<widgets::NodeGraph>;
+    type State = (
+        ViewId,
+        Properties<T>,
+        WidgetId<widgets::InfiniteCanvas>,
+        <InfiniteCanvas<T, V> as View<Context, T>>::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<NodeGraphTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let port_radius = self.properties.get_port_radius(&theme);
+        let wire_width = self.properties.get_wire_width(&theme);
+        let wire_color = self.properties.get_wire_color(&theme, &palette);
+        let select_color = self.properties.get_select_color(&theme, &palette);
+        let marquee_color = self.properties.get_marquee_color(&theme, &palette);
+
+        let (canvas, state) = self.canvas.build(cx, data);
+        let mut widget = widgets::NodeGraph::new(cx, canvas);
+
+        widgets::NodeGraph::set_ports(
+            &mut widget,
+            self.properties.ports.clone(),
+        );
+        widgets::NodeGraph::set_wires(
+            &mut widget,
+            self.properties.wires.clone(),
+        );
+        widgets::NodeGraph::set_port_radius(&mut widget, port_radius);
+        widgets::NodeGraph::set_wire_width(&mut widget, wire_width);
+        widgets::NodeGraph::set_wire_color(&mut widget, wire_color);
+        widgets::NodeGraph::set_select_color(&mut widget, select_color);
+        widgets::NodeGraph::set_marquee_color(&mut widget, marquee_color);
+
+        if let Some(ref selected) = self.properties.selected {
+            widgets::NodeGraph::set_selected(&mut widget, selected.clone());
+        }
+
+        widgets::NodeGraph::set_on_move(&mut widget, {
+            let proxy = proxy.cloned();
+            move |node, position| {
+                proxy.event(Event::new(
+                    NodeGraphEvent::Move(node, position),
+                    id,
+                ))
+            }
+        });
+
+        widgets::NodeGraph::set_on_connect(&mut widget, {
+            let proxy = proxy.cloned();
+            move |wire| {
+                proxy.event(Event::new(
+                    NodeGraphEvent::Connect(wire),
+                    id,
+                ))
+            }
+        });
+
+        widgets::NodeGraph::set_on_disconnect(&mut widget, {
+            let proxy = proxy.cloned();
+            move |wire| {
+                proxy.event(Event::new(
+                    NodeGraphEvent::Disconnect(wire),
+                    id,
+                ))
+            }
+        });
+
+        widgets::NodeGraph::set_on_delete(&mut widget, {
+            let proxy = proxy.cloned();
+            move |nodes| {
+                proxy.event(Event::new(
+                    NodeGraphEvent::Delete(nodes.to_vec()),
+                    id,
+                ))
+            }
+        });
+
+        widgets::NodeGraph::set_on_select(&mut widget, move |nodes| {
+            proxy.event(Event::new(
+                NodeGraphEvent::Select(nodes.to_vec()),
+                id,
+            ));
+        });
+
+        (
+            widget.id(),
+            (id, self.properties, canvas, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (_id, properties, canvas, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.canvas.rebuild(canvas, state, cx, data);
+
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<NodeGraphTheme>();
+        let retheme = is_retheming(cx);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.properties.ports != properties.ports {
+            widgets::NodeGraph::set_ports(
+                &mut widget,
+                self.properties.ports.clone(),
+            );
+        }
+
+        if self.properties.wires != properties.wires {
+            widgets::NodeGraph::set_wires(
+                &mut widget,
+                self.properties.wires.clone(),
+            );
+        }
+
+        if self.properties.selected != properties.selected
+            && let Some(ref selected) = self.properties.selected
+        {
+            widgets::NodeGraph::set_selected(&mut widget, selected.clone());
+        }
+
+        if retheme || self.properties.port_radius != properties.port_radius {
+            let port_radius = self.properties.get_port_radius(&theme);
+            widgets::NodeGraph::set_port_radius(&mut widget, port_radius);
+        }
+
+        if retheme || self.properties.wire_width != properties.wire_width {
+            let wire_width = self.properties.get_wire_width(&theme);
+            widgets::NodeGraph::set_wire_width(&mut widget, wire_width);
+        }
+
+        if retheme || self.properties.wire_color != properties.wire_color {
+            let wire_color = self.properties.get_wire_color(&theme, &palette);
+            widgets::NodeGraph::set_wire_color(&mut widget, wire_color);
+        }
+
+        if retheme || self.properties.select_color != properties.select_color {
+            let select_color = self.properties.get_select_color(&theme, &palette);
+            widgets::NodeGraph::set_select_color(&mut widget, select_color);
+        }
+
+        if retheme || self.properties.marquee_color != properties.marquee_color {
+            let marquee_color = self.properties.get_marquee_color(&theme, &palette);
+            widgets::NodeGraph::set_marquee_color(&mut widget, marquee_color);
+        }
+
+        *properties = self.properties;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (id, properties, canvas, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = InfiniteCanvas::<T, V>::event(canvas, state, cx, data, event);
+
+        match event.take_targeted(*id) {
+            Some(NodeGraphEvent::Move(node, position)) => {
+                action | (properties.on_move)(data, node, position)
+            }
+
+            Some(NodeGraphEvent::Connect(wire)) => action | (properties.on_connect)(data, wire),
+
+            Some(NodeGraphEvent::Disconnect(wire)) => {
+                action | (properties.on_disconnect)(data, wire)
+            }
+
+            Some(NodeGraphEvent::Delete(nodes)) => action | (properties.on_delete)(data, nodes),
+            Some(NodeGraphEvent::Select(nodes)) => action | (properties.on_select)(data, nodes),
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _properties, canvas, state): Self::State,
+        cx: &mut Context,
+    ) {
+        InfiniteCanvas::<T, V>::teardown(canvas, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use ike_core::{WidgetId, widgets};
+use ori::{Action, Event, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context,
+    views::{Button, Entry, Flex, Label, Stack, button, entry, flex, hstack, label},
+};
+
+/// Create a [`NumericEntry`] showing `value`, with buttons stepping it up and down.
+///
+/// Only numbers between [`NumericEntry::min`] and [`NumericEntry::max`] can be submitted.
+pub fn numeric_entry<T>(value: f64) -> NumericEntry<T> {
+    NumericEntry::new(value)
+}
+
+pub struct NumericEntry<T> {
+    value:       f64,
+    min:         f64,
+    max:         f64,
+    step:        f64,
+    precision:   Option<usize>,
+    placeholder: Option<String>,
+    min_width:   Option<f32>,
+
+    #[allow(clippy::type_complexity)]
+    on_change: Box<dyn FnMut(&mut T, f64) -> Action>,
+}
+
+impl<T> NumericEntry<T> {
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            precision: None,
+            placeholder: None,
+            min_width: None,
+
+            on_change: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// The amount the value is changed by the spinner buttons.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Show the value with `precision` decimals, by default as many as needed are shown.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.placeholder = Some(text.into());
+        self
+    }
+
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = Some(min_width);
+        self
+    }
+
+    /// Called with the new value when a valid number is submitted, or a spinner button is
+    /// clicked.
+    pub fn on_change<A>(mut self, mut on_change: impl FnMut(&mut T, f64) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_change = Box::new(move |data, value| on_change(data, value).into());
+        self
+    }
+}
+
+type Contents<T> = Stack<(
+    Flex<Entry<T>>,
+    Button<T, Label>,
+    Button<T, Label>,
+)>;
+
+impl<T> NumericEntry<T> {
+    fn format(&self, value: f64) -> String {
+        match self.precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => format!("{value}"),
+        }
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    fn build_contents(&self, proxy: Arc<dyn Proxy>, id: ViewId) -> Contents<T> {
+        let (min, max) = (self.min, self.max);
+
+        let mut text = entry()
+            .text(self.format(self.value))
+            .filter(|text| {
+                text.chars()
+                    .all(|c| c.is_ascii_digit() || matches!(c, '-' | '.'))
+            })
+            .validate(move |text| parse(text, min, max).is_some())
+            .on_submit({
+                let proxy = proxy.cloned();
+                move |_, text| {
+                    proxy.event(Event::new(
+                        NumericEntryEvent::Submit(text),
+                        id,
+                    ))
+                }
+            });
+
+        if let Some(ref placeholder) = self.placeholder {
+            text = text.placeholder(placeholder.clone());
+        }
+
+        if let Some(min_width) = self.min_width {
+            text = text.min_width(min_width);
+        }
+
+        let step = |direction: f64| {
+            let proxy = proxy.cloned();
+            move |_: &mut T| {
+                proxy.event(Event::new(
+                    NumericEntryEvent::Step(direction),
+                    id,
+                ))
+            }
+        };
+
+        hstack((
+            flex(text),
+            button(label("\u{2212}"), step(-1.0)),
+            button(label("+"), step(1.0)),
+        ))
+        .gap(4.0)
+    }
+}
+
+/// Parse `text` as a finite number between `min` and `max`.
+fn parse(text: &str, min: f64, max: f64) -> Option<f64> {
+    let value = text.trim().parse::<f64>().ok()?;
+    (value.is_finite() && value >= min && value <= max).then_some(value)
+}
+
+enum NumericEntryEvent {
+    Submit(String),
+    Step(f64),
+}
+
+impl<T> ViewMarker for NumericEntry<T> {}
+impl<T> View<Context, T> for NumericEntry<T> {
+    type Element = WidgetId<widgets::Stack>;
+    type State = (
+        ViewId,
+        Self,
+        <Contents<T> as View<Context, T>>::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let (element, state) = self.build_contents(proxy, id).build(cx, data);
+        (element, (id, self, state))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (id, numeric, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        let proxy = cx.proxy();
+
+        let contents = self.build_contents(proxy, *id);
+        contents.rebuild(element, state, cx, data);
+
+        *numeric = self;
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (id, numeric, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = <Contents<T> as View<Context, T>>::event(element, state, cx, data, event);
+
+        match event.take_targeted(*id) {
+            Some(NumericEntryEvent::Submit(text)) => match parse(&text, numeric.min, numeric.max) {
+                Some(value) => action | (numeric.on_change)(data, value),
+                None => action,
+            },
+
+            Some(NumericEntryEvent::Step(direction)) => {
+                let value = numeric.clamp(numeric.value + numeric.step * direction);
+                action | (numeric.on_change)(data, value)
+            }
+
+            None => action,
+        }
+    }
+
+    fn teardown(element: Self::Element, (_id, _numeric, state): Self::State, cx: &mut Context) {
+        <Contents<T> as View<Context, T>>::teardown(element, state, cx);
+    }
+}
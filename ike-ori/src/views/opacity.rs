@@ -0,0 +1,92 @@
+use ike_core::{Builder, Transition, WidgetId, widgets};
+use ori::{Action, Event, Mut, View, ViewMarker};
+
+use crate::Context;
+
+pub fn opacity<V>(opacity: f32, contents: V) -> Opacity<V> {
+    Opacity::new(opacity, contents)
+}
+
+pub struct Opacity<V> {
+    contents:   V,
+    opacity:    f32,
+    transition: Transition,
+}
+
+impl<V> Opacity<V> {
+    pub fn new(opacity: f32, contents: V) -> Self {
+        Self {
+            contents,
+            opacity,
+            transition: Transition::INSTANT,
+        }
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
+impl<V> ViewMarker for Opacity<V> {}
+impl<T, V> View<Context, T> for Opacity<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Opacity>;
+    type State = (f32, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = widgets::Opacity::new(cx, contents);
+
+        widgets::Opacity::set_transition(&mut widget, self.transition);
+        widgets::Opacity::set_opacity(&mut widget, self.opacity);
+
+        (
+            widget.id(),
+            (self.opacity, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (opacity, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        widgets::Opacity::set_transition(&mut widget, self.transition);
+
+        if self.opacity != *opacity {
+            *opacity = self.opacity;
+            widgets::Opacity::set_opacity(&mut widget, self.opacity);
+        }
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_opacity, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_opacity, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
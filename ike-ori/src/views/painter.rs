@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use ike_core::{Builder, Canvas, PointerEvent, Size, TouchEvent, WidgetId, widgets};
+use ori::{Action, Event, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Draw custom contents with `draw`, see [`widgets::DrawArea`].
+///
+/// `draw` is called with the canvas, the size of the area and the time the area has been
+/// animated for.
+pub fn painter<T>(draw: impl FnMut(&mut dyn Canvas, Size, Duration) + 'static) -> CanvasView<T> {
+    CanvasView::new(draw)
+}
+
+pub struct CanvasView<T> {
+    #[allow(clippy::type_complexity)]
+    draw:       Box<dyn FnMut(&mut dyn Canvas, Size, Duration)>,
+    properties: Properties<T>,
+}
+
+impl<T> CanvasView<T> {
+    pub fn new(draw: impl FnMut(&mut dyn Canvas, Size, Duration) + 'static) -> Self {
+        Self {
+            draw:       Box::new(draw),
+            properties: Properties {
+                on_pointer: None,
+                on_touch:   None,
+
+                size:        Size::new(100.0, 100.0),
+                is_animated: false,
+            },
+        }
+    }
+
+    /// Set the preferred size of the area.
+    pub fn size(mut self, size: Size) -> Self {
+        self.properties.size = size;
+        self
+    }
+
+    /// Redraw the area every frame, advancing the time passed to `draw`.
+    pub fn animated(mut self, is_animated: bool) -> Self {
+        self.properties.is_animated = is_animated;
+        self
+    }
+
+    /// Called with pointer events over the area, in its local coordinates.
+    pub fn on_pointer<A>(
+        mut self,
+        mut on_pointer: impl FnMut(&mut T, &PointerEvent) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_pointer = Some(Box::new(move |data, event| {
+            on_pointer(data, event).into()
+        }));
+        self
+    }
+
+    /// Called with touch events over the area, in its local coordinates.
+    pub fn on_touch<A>(
+        mut self,
+        mut on_touch: impl FnMut(&mut T, &TouchEvent) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_touch = Some(Box::new(move |data, event| {
+            on_touch(data, event).into()
+        }));
+        self
+    }
+}
+
+enum CanvasViewEvent {
+    Pointer(PointerEvent),
+    Touch(TouchEvent),
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_pointer: Option<Box<dyn FnMut(&mut T, &PointerEvent) -> Action>>,
+    #[allow(clippy::type_complexity)]
+    on_touch:   Option<Box<dyn FnMut(&mut T, &TouchEvent) -> Action>>,
+
+    size:        Size,
+    is_animated: bool,
+}
+
+impl<T> ViewMarker for CanvasView<T> {}
+impl<T> View<Context, T> for CanvasView<T> {
+    type Element = WidgetId<widgets::DrawArea>;
+    type State = (ViewId, Properties<T>);
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let mut widget = widgets::DrawArea::new(cx, self.draw);
+        widgets::DrawArea::set_size(&mut widget, self.properties.size);
+        widgets::DrawArea::set_animated(&mut widget, self.properties.is_animated);
+
+        let handles_pointer = self.properties.on_pointer.is_some();
+        widgets::DrawArea::set_on_pointer(&mut widget, {
+            let proxy = proxy.cloned();
+            move |event| {
+                if handles_pointer {
+                    proxy.event(Event::new(
+                        CanvasViewEvent::Pointer(event.clone()),
+                        id,
+                    ));
+                }
+
+                handles_pointer
+            }
+        });
+
+        let handles_touch = self.properties.on_touch.is_some();
+        widgets::DrawArea::set_on_touch(&mut widget, move |event| {
+            if handles_touch {
+                proxy.event(Event::new(
+                    CanvasViewEvent::Touch(event.clone()),
+                    id,
+                ));
+            }
+
+            handles_touch
+        });
+
+        (widget.id(), (id, self.properties))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (_id, properties): &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        widgets::DrawArea::set_draw(&mut widget, self.draw);
+
+        if self.properties.size != properties.size {
+            widgets::DrawArea::set_size(&mut widget, self.properties.size);
+        }
+
+        if self.properties.is_animated != properties.is_animated {
+            widgets::DrawArea::set_animated(&mut widget, self.properties.is_animated);
+        }
+
+        *properties = self.properties;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (id, properties): &mut Self::State,
+        _cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        match event.take_targeted(*id) {
+            Some(CanvasViewEvent::Pointer(event)) => match properties.on_pointer {
+                Some(ref mut on_pointer) => on_pointer(data, &event),
+                None => Action::new(),
+            },
+            Some(CanvasViewEvent::Touch(event)) => match properties.on_touch {
+                Some(ref mut on_touch) => on_touch(data, &event),
+                None => Action::new(),
+            },
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context) {
+        cx.remove_widget(element);
+    }
+}
@@ -1,5 +1,5 @@
 use ike_core::{
-    Builder, Color, WidgetId,
+    Builder, Color, SvgStyle, WidgetId,
     widgets::{self, Fit, Picturable},
 };
 use ori::{Action, Event, View, ViewMarker};
@@ -14,6 +14,7 @@ pub struct Picture {
     contents: Picturable,
     fit:      Fit,
     color:    Option<Color>,
+    style:    SvgStyle,
 }
 
 impl Picture {
@@ -22,6 +23,7 @@ impl Picture {
             contents: content.into(),
             fit,
             color: None,
+            style: SvgStyle::default(),
         }
     }
 
@@ -29,6 +31,30 @@ impl Picture {
         self.color = Some(color);
         self
     }
+
+    /// The color of `currentColor` in svgs.
+    pub fn current_color(mut self, color: Color) -> Self {
+        self.style.color = Some(color);
+        self
+    }
+
+    /// Replace the fill of everything filled in svgs.
+    pub fn fill(mut self, color: Color) -> Self {
+        self.style.fill = Some(color);
+        self
+    }
+
+    /// Replace the stroke of everything stroked in svgs.
+    pub fn stroke(mut self, color: Color) -> Self {
+        self.style.stroke = Some(color);
+        self
+    }
+
+    /// Only draw the element of svgs with `id`, and its children.
+    pub fn node(mut self, id: impl Into<String>) -> Self {
+        self.style.node = Some(id.into());
+        self
+    }
 }
 
 impl ViewMarker for Picture {}
@@ -40,6 +66,7 @@ impl<T> View<Context, T> for Picture {
         let mut widget = widgets::Picture::new(cx, self.contents.clone());
         widgets::Picture::set_fit(&mut widget, self.fit);
         widgets::Picture::set_color(&mut widget, self.color);
+        widgets::Picture::set_svg_style(&mut widget, self.style.clone());
 
         (widget.id(), self)
     }
@@ -67,6 +94,10 @@ impl<T> View<Context, T> for Picture {
             widgets::Picture::set_color(&mut widget, self.color);
         }
 
+        if self.style != picture.style {
+            widgets::Picture::set_svg_style(&mut widget, self.style.clone());
+        }
+
         *picture = self;
     }
 
@@ -0,0 +1,375 @@
+use ike_core::{Builder, Color, Padding, Paint, Paragraph, Point, TextStyle, WidgetId, widgets};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, Palette, is_retheming, views::TextTheme};
+
+/// Add chart interactions to `contents`, see [`widgets::PlotArea`].
+pub fn plot_area<T, V>(contents: V) -> PlotArea<T, V> {
+    PlotArea::new(contents)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotAreaTheme {
+    pub snap_distance:   f32,
+    pub tooltip_padding: Padding,
+    pub crosshair_color: Option<Color>,
+    pub point_color:     Option<Color>,
+    pub brush_color:     Option<Color>,
+    pub tooltip_color:   Option<Color>,
+}
+
+impl Default for PlotAreaTheme {
+    fn default() -> Self {
+        Self {
+            snap_distance:   32.0,
+            tooltip_padding: Padding::all(4.0),
+            crosshair_color: None,
+            point_color:     None,
+            brush_color:     None,
+            tooltip_color:   None,
+        }
+    }
+}
+
+pub struct PlotArea<T, V> {
+    contents:   V,
+    properties: Properties<T>,
+}
+
+impl<T, V> PlotArea<T, V> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            properties: Properties {
+                on_hover: Box::new(|_, _| Action::new()),
+                on_brush: Box::new(|_, _, _| Action::new()),
+                on_zoom:  Box::new(|_, _, _| Action::new()),
+
+                points:          Vec::new(),
+                tooltip:         None,
+                snap_distance:   None,
+                tooltip_padding: None,
+                crosshair_color: None,
+                point_color:     None,
+                brush_color:     None,
+                tooltip_color:   None,
+            },
+        }
+    }
+
+    /// Set the positions of the data points, in the local coordinates of the plot area.
+    pub fn points(mut self, points: impl Into<Vec<Point>>) -> Self {
+        self.properties.points = points.into();
+        self
+    }
+
+    /// Set the text of the tooltip shown next to the hovered point.
+    pub fn tooltip(mut self, tooltip: Option<impl ToString>) -> Self {
+        self.properties.tooltip = tooltip.map(|tooltip| tooltip.to_string());
+        self
+    }
+
+    /// Called with the index of the hovered point when it changes.
+    pub fn on_hover<A>(
+        mut self,
+        mut on_hover: impl FnMut(&mut T, Option<usize>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_hover = Box::new(move |data, index| on_hover(data, index).into());
+        self
+    }
+
+    /// Called with the `start` and `end` of a range brushed by dragging.
+    pub fn on_brush<A>(mut self, mut on_brush: impl FnMut(&mut T, f32, f32) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_brush =
+            Box::new(move |data, start, end| on_brush(data, start, end).into());
+        self
+    }
+
+    /// Called with a zoom factor, and the point to zoom around, when scrolling.
+    pub fn on_zoom<A>(mut self, mut on_zoom: impl FnMut(&mut T, f32, Point) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_zoom =
+            Box::new(move |data, factor, anchor| on_zoom(data, factor, anchor).into());
+        self
+    }
+
+    pub fn snap_distance(mut self, distance: f32) -> Self {
+        self.properties.snap_distance = Some(distance);
+        self
+    }
+
+    pub fn tooltip_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.properties.tooltip_padding = Some(padding.into());
+        self
+    }
+
+    pub fn crosshair_color(mut self, color: Color) -> Self {
+        self.properties.crosshair_color = Some(color);
+        self
+    }
+
+    pub fn point_color(mut self, color: Color) -> Self {
+        self.properties.point_color = Some(color);
+        self
+    }
+
+    pub fn brush_color(mut self, color: Color) -> Self {
+        self.properties.brush_color = Some(color);
+        self
+    }
+
+    pub fn tooltip_color(mut self, color: Color) -> Self {
+        self.properties.tooltip_color = Some(color);
+        self
+    }
+}
+
+enum PlotAreaEvent {
+    Hover(Option<usize>),
+    Brush(f32, f32),
+    Zoom(f32, Point),
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_hover: Box<dyn FnMut(&mut T, Option<usize>) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_brush: Box<dyn FnMut(&mut T, f32, f32) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_zoom:  Box<dyn FnMut(&mut T, f32, Point) -> Action>,
+
+    points:          Vec<Point>,
+    tooltip:         Option<String>,
+    snap_distance:   Option<f32>,
+    tooltip_padding: Option<Padding>,
+    crosshair_color: Option<Color>,
+    point_color:     Option<Color>,
+    brush_color:     Option<Color>,
+    tooltip_color:   Option<Color>,
+}
+
+impl<T> Properties<T> {
+    fn get_snap_distance(&self, theme: &PlotAreaTheme) -> f32 {
+        self.snap_distance.unwrap_or(theme.snap_distance)
+    }
+
+    fn get_tooltip_padding(&self, theme: &PlotAreaTheme) -> Padding {
+        self.tooltip_padding.unwrap_or(theme.tooltip_padding)
+    }
+
+    fn get_crosshair_color(&self, theme: &PlotAreaTheme, palette: &Palette) -> Color {
+        self.crosshair_color
+            .unwrap_or_else(|| theme.crosshair_color.unwrap_or(palette.outline))
+    }
+
+    fn get_point_color(&self, theme: &PlotAreaTheme, palette: &Palette) -> Color {
+        self.point_color
+            .unwrap_or_else(|| theme.point_color.unwrap_or(palette.primary))
+    }
+
+    fn get_brush_color(&self, theme: &PlotAreaTheme, palette: &Palette) -> Color {
+        self.brush_color
+            .unwrap_or_else(|| theme.brush_color.unwrap_or(palette.primary.fade(0.2)))
+    }
+
+    fn get_tooltip_color(&self, theme: &PlotAreaTheme, palette: &Palette) -> Color {
+        self.tooltip_color
+            .unwrap_or_else(|| theme.tooltip_color.unwrap_or_else(|| palette.surface(1)))
+    }
+
+    fn build_tooltip(&self, text: &TextTheme, palette: &Palette) -> Option<Paragraph> {
+        let tooltip = self.tooltip.as_ref()?;
+
+        let style = TextStyle {
+            font_size:    text.font_size,
+            font_weight:  text.font_weight,
+            font_stretch: text.font_stretch,
+            font_style:   text.font_style,
+            font_family:  text.font_family.clone().into_owned(),
+            paint:        Paint::from(text.color.unwrap_or(palette.contrast)),
+        };
+
+        let mut paragraph = Paragraph::new(text.line_height, text.align, text.wrap);
+
+        paragraph.push(tooltip, style);
+        Some(paragraph)
+    }
+}
+
+impl<T, V> ViewMarker for PlotArea<T, V> {}
+impl<T, V> View<Context, T> for PlotArea<T, V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::PlotArea>;
+    type State = (
+        ViewId,
+        Properties<T>,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<PlotAreaTheme>();
+        let text = cx.get_or_default::<TextTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let tooltip = self.properties.build_tooltip(&text, &palette);
+        let snap_distance = self.properties.get_snap_distance(&theme);
+        let tooltip_padding = self.properties.get_tooltip_padding(&theme);
+        let crosshair_color = self.properties.get_crosshair_color(&theme, &palette);
+        let point_color = self.properties.get_point_color(&theme, &palette);
+        let brush_color = self.properties.get_brush_color(&theme, &palette);
+        let tooltip_color = self.properties.get_tooltip_color(&theme, &palette);
+
+        let (contents, state) = self.contents.build(cx, data);
+        let mut widget = widgets::PlotArea::new(cx, contents);
+
+        widgets::PlotArea::set_points(
+            &mut widget,
+            self.properties.points.clone(),
+        );
+        widgets::PlotArea::set_tooltip(&mut widget, tooltip);
+        widgets::PlotArea::set_snap_distance(&mut widget, snap_distance);
+        widgets::PlotArea::set_tooltip_padding(&mut widget, tooltip_padding);
+        widgets::PlotArea::set_crosshair_color(&mut widget, crosshair_color);
+        widgets::PlotArea::set_point_color(&mut widget, point_color);
+        widgets::PlotArea::set_brush_color(&mut widget, brush_color);
+        widgets::PlotArea::set_tooltip_color(&mut widget, tooltip_color);
+
+        widgets::PlotArea::set_on_hover(&mut widget, {
+            let proxy = proxy.cloned();
+            move |index| {
+                proxy.event(Event::new(
+                    PlotAreaEvent::Hover(index),
+                    id,
+                ))
+            }
+        });
+
+        widgets::PlotArea::set_on_brush(&mut widget, {
+            let proxy = proxy.cloned();
+            move |start, end| {
+                proxy.event(Event::new(
+                    PlotAreaEvent::Brush(start, end),
+                    id,
+                ))
+            }
+        });
+
+        widgets::PlotArea::set_on_zoom(&mut widget, move |factor, anchor| {
+            proxy.event(Event::new(
+                PlotAreaEvent::Zoom(factor, anchor),
+                id,
+            ));
+        });
+
+        (
+            widget.id(),
+            (id, self.properties, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (_id, properties, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(contents, state, cx, data);
+
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<PlotAreaTheme>();
+        let retheme = is_retheming(cx);
+        let text = cx.get_or_default::<TextTheme>();
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.properties.points != properties.points {
+            widgets::PlotArea::set_points(
+                &mut widget,
+                self.properties.points.clone(),
+            );
+        }
+
+        if retheme || self.properties.tooltip != properties.tooltip {
+            let tooltip = self.properties.build_tooltip(&text, &palette);
+            widgets::PlotArea::set_tooltip(&mut widget, tooltip);
+        }
+
+        if retheme || self.properties.snap_distance != properties.snap_distance {
+            let snap_distance = self.properties.get_snap_distance(&theme);
+            widgets::PlotArea::set_snap_distance(&mut widget, snap_distance);
+        }
+
+        if retheme || self.properties.tooltip_padding != properties.tooltip_padding {
+            let tooltip_padding = self.properties.get_tooltip_padding(&theme);
+            widgets::PlotArea::set_tooltip_padding(&mut widget, tooltip_padding);
+        }
+
+        if retheme || self.properties.crosshair_color != properties.crosshair_color {
+            let crosshair_color = self.properties.get_crosshair_color(&theme, &palette);
+            widgets::PlotArea::set_crosshair_color(&mut widget, crosshair_color);
+        }
+
+        if retheme || self.properties.point_color != properties.point_color {
+            let point_color = self.properties.get_point_color(&theme, &palette);
+            widgets::PlotArea::set_point_color(&mut widget, point_color);
+        }
+
+        if retheme || self.properties.brush_color != properties.brush_color {
+            let brush_color = self.properties.get_brush_color(&theme, &palette);
+            widgets::PlotArea::set_brush_color(&mut widget, brush_color);
+        }
+
+        if retheme || self.properties.tooltip_color != properties.tooltip_color {
+            let tooltip_color = self.properties.get_tooltip_color(&theme, &palette);
+            widgets::PlotArea::set_tooltip_color(&mut widget, tooltip_color);
+        }
+
+        *properties = self.properties;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (id, properties, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::event(contents, state, cx, data, event);
+
+        match event.take_targeted(*id) {
+            Some(PlotAreaEvent::Hover(index)) => action | (properties.on_hover)(data, index),
+            Some(PlotAreaEvent::Brush(start, end)) => {
+                action | (properties.on_brush)(data, start, end)
+            }
+            Some(PlotAreaEvent::Zoom(factor, anchor)) => {
+                action | (properties.on_zoom)(data, factor, anchor)
+            }
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _properties, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -1,12 +1,17 @@
+use std::ops::Range;
+
 use ike_core::{
-    Builder, Color, FontStretch, FontStyle, FontWeight, Paint, Paragraph, TextAlign, TextStyle,
-    TextWrap, WidgetId, widgets,
+    Builder, Color, FontStretch, FontStyle, FontWeight, Paint, Paragraph, TextAlign, TextLink,
+    TextStyle, TextWrap, WidgetId, widgets,
 };
-use ori::{Action, Provider, View, ViewMarker};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
 
-use crate::{Context, Palette, views::TextTheme};
+use crate::{
+    Context, Palette, is_retheming,
+    views::{GutterTheme, TextTheme, gutter::build_gutter},
+};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ProseTheme {
     pub font_size:       Option<f32>,
     pub font_family:     Option<String>,
@@ -17,6 +22,7 @@ pub struct ProseTheme {
     pub align:           Option<TextAlign>,
     pub wrap:            Option<TextWrap>,
     pub color:           Option<Color>,
+    pub link_color:      Option<Color>,
     pub cursor_color:    Option<Color>,
     pub selection_color: Option<Color>,
     pub blink_rate:      f32,
@@ -34,6 +40,7 @@ impl Default for ProseTheme {
             align:           None,
             wrap:            None,
             color:           None,
+            link_color:      None,
             cursor_color:    None,
             selection_color: None,
             blink_rate:      5.0,
@@ -41,12 +48,13 @@ impl Default for ProseTheme {
     }
 }
 
-pub fn prose(text: impl Into<String>) -> Prose {
+pub fn prose<T>(text: impl Into<String>) -> Prose<T> {
     Prose::new(text)
 }
 
-pub struct Prose {
+pub struct Prose<T> {
     text:            String,
+    links:           Vec<TextLink>,
     font_size:       Option<f32>,
     font_family:     Option<String>,
     font_weight:     Option<FontWeight>,
@@ -56,15 +64,23 @@ pub struct Prose {
     align:           Option<TextAlign>,
     wrap:            Option<TextWrap>,
     color:           Option<Color>,
+    link_color:      Option<Color>,
     cursor_color:    Option<Color>,
     selection_color: Option<Color>,
     blink_rate:      Option<f32>,
+    line_numbers:    bool,
+
+    #[allow(clippy::type_complexity)]
+    on_link:     Box<dyn FnMut(&mut T, String) -> Action>,
+    #[allow(clippy::type_complexity)]
+    line_marker: Option<Box<dyn FnMut(usize) -> Option<Color>>>,
 }
 
-impl Prose {
+impl<T> Prose<T> {
     pub fn new(text: impl Into<String>) -> Self {
         Self {
             text:            text.into(),
+            links:           Vec::new(),
             font_size:       None,
             font_family:     None,
             font_weight:     None,
@@ -74,12 +90,38 @@ impl Prose {
             align:           None,
             wrap:            None,
             color:           None,
+            link_color:      None,
             cursor_color:    None,
             selection_color: None,
             blink_rate:      None,
+            line_numbers:    false,
+
+            on_link:     Box::new(|_, _| Action::new()),
+            line_marker: None,
         }
     }
 
+    /// Make the bytes of the text in `range` a link to `target`, e.g. a url.
+    ///
+    /// Links are underlined when hovered, and passed to [`Self::on_link`] when clicked.
+    pub fn link(mut self, range: Range<usize>, target: impl Into<String>) -> Self {
+        let link = TextLink {
+            range,
+            target: target.into(),
+        };
+
+        self.links.push(link);
+        self
+    }
+
+    pub fn on_link<A>(mut self, mut on_link: impl FnMut(&mut T, String) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_link = Box::new(move |data, target| on_link(data, target).into());
+        self
+    }
+
     pub fn font_size(mut self, font_size: f32) -> Self {
         self.font_size = Some(font_size);
         self
@@ -125,6 +167,11 @@ impl Prose {
         self
     }
 
+    pub fn link_color(mut self, color: Color) -> Self {
+        self.link_color = Some(color);
+        self
+    }
+
     pub fn selection_color(mut self, color: Color) -> Self {
         self.selection_color = Some(color);
         self
@@ -139,9 +186,25 @@ impl Prose {
         self.blink_rate = Some(rate);
         self
     }
+
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Draw a marker behind the numbers of lines, e.g. breakpoints.
+    ///
+    /// Only drawn when [`Self::line_numbers`] are enabled.
+    pub fn line_marker(
+        mut self,
+        line_marker: impl FnMut(usize) -> Option<Color> + 'static,
+    ) -> Self {
+        self.line_marker = Some(Box::new(line_marker));
+        self
+    }
 }
 
-impl Prose {
+impl<T> Prose<T> {
     fn build_paragraph(
         &self,
         text: &str,
@@ -196,10 +259,44 @@ impl Prose {
                 .unwrap_or_else(|| text_area_theme.wrap.unwrap_or(text_theme.wrap)),
         );
 
-        paragraph.push(text, style);
+        let link_style = TextStyle {
+            paint: Paint::from(self.get_link_color(palette, text_area_theme)),
+            ..style.clone()
+        };
+
+        let mut links = self.links.clone();
+        links.sort_by_key(|link| link.range.start);
+
+        let mut start = 0;
+
+        for link in links {
+            // links overlapping others, or outside the text, are ignored
+            if link.range.start < start || text.get(link.range.clone()).is_none() {
+                continue;
+            }
+
+            paragraph.push(
+                &text[start..link.range.start],
+                style.clone(),
+            );
+            paragraph.push_link(
+                &text[link.range.clone()],
+                link_style.clone(),
+                link.target,
+            );
+
+            start = link.range.end;
+        }
+
+        paragraph.push(&text[start..], style);
         paragraph
     }
 
+    fn get_link_color(&self, palette: &Palette, theme: &ProseTheme) -> Color {
+        self.link_color
+            .unwrap_or_else(|| theme.link_color.unwrap_or(palette.primary))
+    }
+
     fn get_cursor_color(&self, palette: &Palette, theme: &ProseTheme) -> Color {
         self.cursor_color
             .unwrap_or_else(|| theme.cursor_color.unwrap_or(palette.contrast))
@@ -211,15 +308,22 @@ impl Prose {
     }
 }
 
-impl ViewMarker for Prose {}
-impl<T> View<Context, T> for Prose {
+enum ProseEvent {
+    Link(String),
+}
+
+impl<T> ViewMarker for Prose<T> {}
+impl<T> View<Context, T> for Prose<T> {
     type Element = WidgetId<widgets::TextArea<false>>;
-    type State = Self;
+    type State = (ViewId, Self);
 
-    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+    fn build(mut self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
         let palette = cx.get_or_default::<Palette>();
         let text_theme = cx.get_or_default::<TextTheme>();
         let theme = cx.get_or_default::<ProseTheme>();
+        let gutter_theme = cx.get_or_default::<GutterTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
 
         let paragraph = self.build_paragraph(
             &self.text,
@@ -238,25 +342,45 @@ impl<T> View<Context, T> for Prose {
         widgets::TextArea::set_selection_color(&mut widget, selection_color);
         widgets::TextArea::set_blink_rate(&mut widget, blink_rate);
 
-        (widget.id(), self)
+        if self.line_numbers {
+            let gutter = build_gutter(&palette, &text_theme, &gutter_theme);
+            widgets::TextArea::set_gutter(&mut widget, Some(gutter));
+        }
+
+        if let Some(line_marker) = self.line_marker.take() {
+            widgets::TextArea::set_line_marker(&mut widget, line_marker);
+        }
+
+        widgets::TextArea::set_on_link(&mut widget, move |target| {
+            proxy.event(Event::new(
+                ProseEvent::Link(target.into()),
+                id,
+            ))
+        });
+
+        (widget.id(), (id, self))
     }
 
     fn rebuild(
-        self,
+        mut self,
         element: &mut Self::Element,
-        prose: &mut Self::State,
+        (_id, prose): &mut Self::State,
         cx: &mut Context,
         _data: &mut T,
     ) {
         let palette = cx.get_or_default::<Palette>();
         let text_theme = cx.get_or_default::<TextTheme>();
         let theme = cx.get_or_default::<ProseTheme>();
+        let gutter_theme = cx.get_or_default::<GutterTheme>();
+        let retheme = is_retheming(cx);
 
         let Ok(mut widget) = cx.get_widget_mut(*element) else {
             return;
         };
 
-        if self.text != prose.text
+        if retheme
+            || self.text != prose.text
+            || self.links != prose.links
             || self.font_size != prose.font_size
             || self.font_family != prose.font_family
             || self.font_weight != prose.font_weight
@@ -266,6 +390,7 @@ impl<T> View<Context, T> for Prose {
             || self.align != prose.align
             || self.wrap != prose.wrap
             || self.color != prose.color
+            || self.link_color != prose.link_color
         {
             let paragraph = self.build_paragraph(
                 &self.text,
@@ -277,35 +402,51 @@ impl<T> View<Context, T> for Prose {
             widgets::TextArea::set_text(&mut widget, paragraph);
         }
 
-        if self.cursor_color != prose.cursor_color {
+        if retheme || self.cursor_color != prose.cursor_color {
             let cursor_color = self.cursor_color.unwrap_or(palette.contrast);
             widgets::TextArea::set_cursor_color(&mut widget, cursor_color);
         }
 
-        if self.selection_color != prose.selection_color {
+        if retheme || self.selection_color != prose.selection_color {
             let selection_color = self.selection_color.unwrap_or(palette.info);
             widgets::TextArea::set_selection_color(&mut widget, selection_color);
         }
 
-        if self.blink_rate != prose.blink_rate {
+        if retheme || self.blink_rate != prose.blink_rate {
             let blink_rate = self.blink_rate.unwrap_or(theme.blink_rate);
             widgets::TextArea::set_blink_rate(&mut widget, blink_rate);
         }
 
+        if retheme || self.line_numbers != prose.line_numbers {
+            let gutter = self
+                .line_numbers
+                .then(|| build_gutter(&palette, &text_theme, &gutter_theme));
+
+            widgets::TextArea::set_gutter(&mut widget, gutter);
+        }
+
+        // markers can't be compared, so they're replaced on every rebuild
+        if let Some(line_marker) = self.line_marker.take() {
+            widgets::TextArea::set_line_marker(&mut widget, line_marker);
+        }
+
         *prose = self;
     }
 
     fn event(
         _element: &mut Self::Element,
-        _prose: &mut Self::State,
+        (id, prose): &mut Self::State,
         _cx: &mut Context,
-        _data: &mut T,
-        _event: &mut ori::Event,
+        data: &mut T,
+        event: &mut Event,
     ) -> Action {
-        Action::new()
+        match event.take_targeted(*id) {
+            Some(ProseEvent::Link(target)) => (prose.on_link)(data, target),
+            None => Action::new(),
+        }
     }
 
-    fn teardown(element: Self::Element, _prose: Self::State, cx: &mut Context) {
+    fn teardown(element: Self::Element, (_id, _prose): Self::State, cx: &mut Context) {
         cx.remove_widget(element);
     }
 }
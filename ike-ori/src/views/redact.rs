@@ -0,0 +1,148 @@
+use ike_core::{Builder, Color, CornerRadius, WidgetId, widgets};
+use ori::{Action, Event, Mut, Provider, View, ViewMarker};
+
+use crate::{Context, Palette, is_retheming};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RedactTheme {
+    pub color:         Option<Color>,
+    pub corner_radius: CornerRadius,
+}
+
+impl Default for RedactTheme {
+    fn default() -> Self {
+        Self {
+            color:         None,
+            corner_radius: CornerRadius::all(4.0),
+        }
+    }
+}
+
+/// Mark `contents` as sensitive, replacing them by a placeholder block while redacted, e.g.
+/// while the screen is shared.
+///
+/// Redaction is toggled for every window with [`Builder::set_redacted`], or for a single window
+/// with [`Window::redacted`](crate::views::Window::redacted).
+pub fn redact<V>(contents: V) -> Redact<V> {
+    Redact::new(contents)
+}
+
+pub struct Redact<V> {
+    contents:      V,
+    color:         Option<Color>,
+    corner_radius: Option<CornerRadius>,
+}
+
+impl<V> Redact<V> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            color: None,
+            corner_radius: None,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
+        self.corner_radius = Some(corner_radius.into());
+        self
+    }
+
+    fn get_color(&self, palette: &Palette, theme: &RedactTheme) -> Color {
+        self.color
+            .unwrap_or_else(|| theme.color.unwrap_or(palette.outline))
+    }
+}
+
+impl<V> ViewMarker for Redact<V> {}
+impl<T, V> View<Context, T> for Redact<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Redact>;
+    type State = (
+        Option<Color>,
+        Option<CornerRadius>,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<RedactTheme>();
+
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = widgets::Redact::new(cx, contents);
+
+        let color = self.get_color(&palette, &theme);
+        let corner_radius = self.corner_radius.unwrap_or(theme.corner_radius);
+
+        widgets::Redact::set_color(&mut widget, color);
+        widgets::Redact::set_corner_radius(&mut widget, corner_radius);
+
+        (
+            widget.id(),
+            (
+                self.color,
+                self.corner_radius,
+                contents,
+                state,
+            ),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (color, corner_radius, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<RedactTheme>();
+        let retheme = is_retheming(cx);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if retheme || self.color != *color {
+            let color = self.get_color(&palette, &theme);
+            widgets::Redact::set_color(&mut widget, color);
+        }
+
+        if retheme || self.corner_radius != *corner_radius {
+            let corner_radius = self.corner_radius.unwrap_or(theme.corner_radius);
+            widgets::Redact::set_corner_radius(&mut widget, corner_radius);
+        }
+
+        *color = self.color;
+        *corner_radius = self.corner_radius;
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_color, _corner_radius, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_color, _corner_radius, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
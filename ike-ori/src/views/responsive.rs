@@ -0,0 +1,280 @@
+use ike_core::{Builder, Size, WidgetId, widgets};
+use ori::{Action, Event, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::Context;
+
+/// Build the contents with `build` from the space available to them, rebuilding them when it
+/// changes.
+///
+/// The contents are built once the view is first laid out, so they can be chosen by the
+/// [`SizeClass`] of the space, e.g. a navigation rail on tablets and desktops, and a bottom
+/// bar on phones.
+pub fn responsive<F, V>(build: F) -> Responsive<F>
+where
+    F: Fn(SizeQuery) -> V,
+{
+    Responsive::new(build)
+}
+
+/// Call `on_size_change` with the size of `contents` whenever it changes, including when it's
+/// first laid out.
+pub fn on_size_change<T, V, A>(
+    contents: V,
+    on_size_change: impl FnMut(&mut T, Size) -> A + 'static,
+) -> OnSizeChange<T, V>
+where
+    A: Into<Action>,
+{
+    OnSizeChange::new(contents, on_size_change)
+}
+
+/// A coarse classification of widths, for choosing between layouts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SizeClass {
+    /// Narrower than [`SizeClass::MEDIUM`], e.g. phones in portrait.
+    Compact,
+
+    /// E.g. tablets in portrait, and phones in landscape.
+    Medium,
+
+    /// At least [`SizeClass::EXPANDED`] wide, e.g. tablets in landscape and desktop windows.
+    Expanded,
+}
+
+impl SizeClass {
+    /// The narrowest width in the [`SizeClass::Medium`] class.
+    pub const MEDIUM: f32 = 600.0;
+
+    /// The narrowest width in the [`SizeClass::Expanded`] class.
+    pub const EXPANDED: f32 = 840.0;
+
+    pub fn from_width(width: f32) -> Self {
+        if width >= Self::EXPANDED {
+            SizeClass::Expanded
+        } else if width >= Self::MEDIUM {
+            SizeClass::Medium
+        } else {
+            SizeClass::Compact
+        }
+    }
+}
+
+/// The space available to the contents of a [`Responsive`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SizeQuery {
+    /// The largest size the contents can take, infinite in directions they can scroll in.
+    pub max_size: Size,
+
+    /// The size of the window the contents are in.
+    pub window: Size,
+}
+
+impl SizeQuery {
+    /// The class of the available width, or of the window width if the available width is
+    /// unbounded.
+    pub fn class(&self) -> SizeClass {
+        if self.max_size.width.is_finite() {
+            SizeClass::from_width(self.max_size.width)
+        } else {
+            self.window_class()
+        }
+    }
+
+    pub fn window_class(&self) -> SizeClass {
+        SizeClass::from_width(self.window.width)
+    }
+}
+
+pub struct Responsive<F> {
+    build: F,
+}
+
+impl<F> Responsive<F> {
+    pub fn new(build: F) -> Self {
+        Self { build }
+    }
+}
+
+struct SpaceChanged(SizeQuery);
+
+impl<F> ViewMarker for Responsive<F> {}
+impl<T, F, V> View<Context, T> for Responsive<F>
+where
+    F: Fn(SizeQuery) -> V + 'static,
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Measure>;
+    type State = (
+        ViewId,
+        Option<SizeQuery>,
+        F,
+        Option<(V::Element, V::State)>,
+    );
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let mut widget = widgets::Measure::new(cx);
+        widgets::Measure::set_on_space(&mut widget, move |max_size, window| {
+            let query = SizeQuery { max_size, window };
+            proxy.event(Event::new(SpaceChanged(query), id));
+        });
+
+        (
+            widget.id(),
+            (id, None, self.build, None),
+        )
+    }
+
+    fn rebuild(
+        self,
+        _element: Mut<Context, Self::Element>,
+        (_id, query, build, contents): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        *build = self.build;
+
+        if let (Some(query), Some((contents, state))) = (query, contents) {
+            build(*query).rebuild(contents, state, cx, data);
+        }
+    }
+
+    fn event(
+        element: Mut<Context, Self::Element>,
+        (id, query, build, contents): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let Some(SpaceChanged(new_query)) = event.take_targeted(*id) else {
+            return match contents {
+                Some((contents, state)) => V::event(contents, state, cx, data, event),
+                None => Action::new(),
+            };
+        };
+
+        if *query == Some(new_query) {
+            return Action::new();
+        }
+
+        *query = Some(new_query);
+
+        match contents {
+            Some((contents, state)) => {
+                build(new_query).rebuild(contents, state, cx, data);
+            }
+
+            None => {
+                let (new_contents, state) = build(new_query).build(cx, data);
+                widgets::Measure::set_contents(cx, *element, new_contents);
+
+                *contents = Some((new_contents, state));
+            }
+        }
+
+        Action::new()
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _query, _build, contents): Self::State,
+        cx: &mut Context,
+    ) {
+        if let Some((contents, state)) = contents {
+            V::teardown(contents, state, cx);
+        }
+
+        cx.remove_widget(element);
+    }
+}
+
+pub struct OnSizeChange<T, V> {
+    contents:       V,
+    on_size_change: Box<dyn FnMut(&mut T, Size) -> Action>,
+}
+
+impl<T, V> OnSizeChange<T, V> {
+    pub fn new<A>(contents: V, mut on_size_change: impl FnMut(&mut T, Size) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            on_size_change: Box::new(move |data, size| on_size_change(data, size).into()),
+        }
+    }
+}
+
+struct SizeChanged(Size);
+
+impl<T, V> ViewMarker for OnSizeChange<T, V> {}
+impl<T, V> View<Context, T> for OnSizeChange<T, V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Measure>;
+    type State = (
+        ViewId,
+        Box<dyn FnMut(&mut T, Size) -> Action>,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let (contents, state) = self.contents.build(cx, data);
+
+        let element = widgets::Measure::new(cx).id();
+        widgets::Measure::set_contents(cx, element, contents);
+
+        if let Ok(mut widget) = cx.get_widget_mut(element) {
+            widgets::Measure::set_on_size(&mut widget, move |size| {
+                proxy.event(Event::new(SizeChanged(size), id));
+            });
+        }
+
+        (
+            element,
+            (id, self.on_size_change, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        _element: Mut<Context, Self::Element>,
+        (_id, on_size_change, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(contents, state, cx, data);
+        *on_size_change = self.on_size_change;
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (id, on_size_change, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::event(contents, state, cx, data, event);
+
+        match event.take_targeted(*id) {
+            Some(SizeChanged(size)) => action | on_size_change(data, size),
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _on_size_change, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -10,7 +10,8 @@ pub fn safe_area<V>(contents: V) -> SafeArea<V> {
 pub struct SafeArea<V> {
     contents: V,
 
-    transition: Transition,
+    transition:     Transition,
+    avoid_keyboard: bool,
 }
 
 impl<V> SafeArea<V> {
@@ -18,6 +19,7 @@ impl<V> SafeArea<V> {
         Self {
             contents,
             transition: Transition::ease(0.1),
+            avoid_keyboard: true,
         }
     }
 
@@ -25,6 +27,14 @@ impl<V> SafeArea<V> {
         self.transition = transition;
         self
     }
+
+    /// Set whether the bottom inset grows to avoid the on-screen keyboard, on by default.
+    ///
+    /// Turn this off to only avoid the keyboard with [`ime_padding`](super::ime_padding).
+    pub fn avoid_keyboard(mut self, avoid_keyboard: bool) -> Self {
+        self.avoid_keyboard = avoid_keyboard;
+        self
+    }
 }
 
 impl<V> ViewMarker for SafeArea<V> {}
@@ -33,7 +43,7 @@ where
     V: crate::View<T>,
 {
     type Element = WidgetId<widgets::SafeArea>;
-    type State = (Transition, V::Element, V::State);
+    type State = (Transition, bool, V::Element, V::State);
 
     fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
         let (contents, state) = self.contents.build(cx, data);
@@ -41,17 +51,23 @@ where
         let mut widget = widgets::SafeArea::new(cx, contents);
 
         widgets::SafeArea::set_transition(&mut widget, self.transition);
+        widgets::SafeArea::set_avoid_keyboard(&mut widget, self.avoid_keyboard);
 
         (
             widget.id(),
-            (self.transition, contents, state),
+            (
+                self.transition,
+                self.avoid_keyboard,
+                contents,
+                state,
+            ),
         )
     }
 
     fn rebuild(
         self,
         element: &mut Self::Element,
-        (transition, contents, state): &mut Self::State,
+        (transition, avoid_keyboard, contents, state): &mut Self::State,
         cx: &mut Context,
         data: &mut T,
     ) {
@@ -65,11 +81,16 @@ where
             *transition = self.transition;
             widgets::SafeArea::set_transition(&mut widget, self.transition);
         }
+
+        if self.avoid_keyboard != *avoid_keyboard {
+            *avoid_keyboard = self.avoid_keyboard;
+            widgets::SafeArea::set_avoid_keyboard(&mut widget, self.avoid_keyboard);
+        }
     }
 
     fn event(
         _element: &mut Self::Element,
-        (_transition, contents, state): &mut Self::State,
+        (_transition, _avoid_keyboard, contents, state): &mut Self::State,
         cx: &mut Context,
         data: &mut T,
         event: &mut Event,
@@ -79,7 +100,7 @@ where
 
     fn teardown(
         element: Self::Element,
-        (_transition, contents, state): Self::State,
+        (_transition, _avoid_keyboard, contents, state): Self::State,
         cx: &mut Context,
     ) {
         V::teardown(contents, state, cx);
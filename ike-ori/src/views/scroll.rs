@@ -1,7 +1,15 @@
-use ike_core::{BorderWidth, Builder, Color, CornerRadius, Padding, Transition, WidgetId, widgets};
+use std::{
+    mem,
+    sync::{Arc, Mutex},
+};
+
+use ike_core::{
+    BorderWidth, Builder, Color, CornerRadius, Padding, Transition, WidgetId, WidgetMut,
+    widgets::{self, Overscroll, ScrollTarget},
+};
 use ori::{Action, Event, Provider, View, ViewMarker};
 
-use crate::{Context, Palette};
+use crate::{Context, Palette, is_retheming};
 
 pub fn vscroll<V>(contents: V) -> Scroll<V> {
     Scroll::new(contents).vertical(true)
@@ -11,7 +19,69 @@ pub fn hscroll<V>(contents: V) -> Scroll<V> {
     Scroll::new(contents).horizontal(true)
 }
 
-#[derive(Clone, Debug)]
+/// Scroll `contents` vertically, with a minimap in place of the scroll bar.
+pub fn minimap<V>(contents: V) -> Scroll<V> {
+    Scroll::new(contents).vertical(true).minimap(true)
+}
+
+/// A handle for scrolling a [`Scroll`], usually kept in the app data.
+///
+/// Requests are picked up by the scroll the scroller is attached to with [`Scroll::scroller`]
+/// when it's next rebuilt, and only the latest request is kept.
+#[derive(Clone, Default)]
+pub struct Scroller {
+    request: Arc<Mutex<Option<(ScrollTarget, bool)>>>,
+}
+
+impl Scroller {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scroll to `target` with the transition of the scroll.
+    pub fn scroll_to(&self, target: ScrollTarget) {
+        self.request(target, true);
+    }
+
+    /// Scroll to `target` immediately.
+    pub fn jump_to(&self, target: ScrollTarget) {
+        self.request(target, false);
+    }
+
+    pub fn scroll_to_start(&self) {
+        self.scroll_to(ScrollTarget::START);
+    }
+
+    pub fn scroll_to_end(&self) {
+        self.scroll_to(ScrollTarget::END);
+    }
+
+    /// Scroll the `n`th child of the contents into view, e.g. an item of a [`vstack`].
+    ///
+    /// [`vstack`]: crate::views::vstack
+    pub fn scroll_to_child(&self, n: usize) {
+        self.scroll_to(ScrollTarget::Child(n));
+    }
+
+    fn request(&self, target: ScrollTarget, animate: bool) {
+        if let Ok(mut request) = self.request.lock() {
+            *request = Some((target, animate));
+        }
+    }
+
+    fn apply(&self, widget: &mut WidgetMut<widgets::Scroll>) {
+        let request = match self.request.lock() {
+            Ok(mut request) => mem::take(&mut *request),
+            Err(_) => None,
+        };
+
+        if let Some((target, animate)) = request {
+            widgets::Scroll::scroll_to(widget, target, animate);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct ScrollTheme {
     pub bar_width:          f32,
     pub bar_padding:        Padding,
@@ -22,6 +92,13 @@ pub struct ScrollTheme {
     pub bar_border_color:   Option<Color>,
     pub bar_color:          Option<Color>,
     pub knob_color:         Option<Color>,
+    pub minimap_width:      f32,
+    pub minimap_color:      Option<Color>,
+    pub viewport_color:     Option<Color>,
+    pub kinetic:            bool,
+    pub friction:           f32,
+    pub overscroll:         Overscroll,
+    pub glow_color:         Option<Color>,
 }
 
 impl Default for ScrollTheme {
@@ -36,6 +113,13 @@ impl Default for ScrollTheme {
             bar_border_color:   None,
             bar_color:          None,
             knob_color:         None,
+            minimap_width:      96.0,
+            minimap_color:      None,
+            viewport_color:     None,
+            kinetic:            true,
+            friction:           0.1,
+            overscroll:         Overscroll::None,
+            glow_color:         None,
         }
     }
 }
@@ -62,6 +146,15 @@ impl<V> Scroll<V> {
                 bar_border_color:   None,
                 bar_color:          None,
                 knob_color:         None,
+                minimap:            false,
+                minimap_width:      None,
+                minimap_color:      None,
+                viewport_color:     None,
+                kinetic:            None,
+                friction:           None,
+                overscroll:         None,
+                glow_color:         None,
+                scroller:           None,
             },
         }
     }
@@ -125,6 +218,57 @@ impl<V> Scroll<V> {
         self.properties.transition = Some(transition);
         self
     }
+
+    /// Show a minimap in place of the vertical scroll bar.
+    pub fn minimap(mut self, minimap: bool) -> Self {
+        self.properties.minimap = minimap;
+        self
+    }
+
+    pub fn minimap_width(mut self, width: f32) -> Self {
+        self.properties.minimap_width = Some(width);
+        self
+    }
+
+    pub fn minimap_color(mut self, color: Color) -> Self {
+        self.properties.minimap_color = Some(color);
+        self
+    }
+
+    /// Set the color of the area of the minimap that is currently visible.
+    pub fn viewport_color(mut self, color: Color) -> Self {
+        self.properties.viewport_color = Some(color);
+        self
+    }
+
+    /// Keep scrolling with the velocity of a released pan.
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.properties.kinetic = Some(kinetic);
+        self
+    }
+
+    /// Set the fraction of the fling velocity kept after a second.
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.properties.friction = Some(friction);
+        self
+    }
+
+    pub fn overscroll(mut self, overscroll: Overscroll) -> Self {
+        self.properties.overscroll = Some(overscroll);
+        self
+    }
+
+    /// Set the color of the glow shown with [`Overscroll::Glow`].
+    pub fn glow_color(mut self, color: Color) -> Self {
+        self.properties.glow_color = Some(color);
+        self
+    }
+
+    /// Scroll with the requests made to `scroller`.
+    pub fn scroller(mut self, scroller: &Scroller) -> Self {
+        self.properties.scroller = Some(scroller.clone());
+        self
+    }
 }
 
 pub struct Properties {
@@ -140,6 +284,15 @@ pub struct Properties {
     bar_border_color:   Option<Color>,
     bar_color:          Option<Color>,
     knob_color:         Option<Color>,
+    minimap:            bool,
+    minimap_width:      Option<f32>,
+    minimap_color:      Option<Color>,
+    viewport_color:     Option<Color>,
+    kinetic:            Option<bool>,
+    friction:           Option<f32>,
+    overscroll:         Option<Overscroll>,
+    glow_color:         Option<Color>,
+    scroller:           Option<Scroller>,
 }
 
 impl Properties {
@@ -181,6 +334,37 @@ impl Properties {
     fn get_knob_corner_radius(&self, theme: &ScrollTheme) -> CornerRadius {
         self.knob_corner_radius.unwrap_or(theme.knob_corner_radius)
     }
+
+    fn get_minimap_width(&self, theme: &ScrollTheme) -> f32 {
+        self.minimap_width.unwrap_or(theme.minimap_width)
+    }
+
+    fn get_minimap_paint(&self, theme: &ScrollTheme, _palette: &Palette) -> Color {
+        self.minimap_color
+            .unwrap_or_else(|| theme.minimap_color.unwrap_or(Color::TRANSPARENT))
+    }
+
+    fn get_kinetic(&self, theme: &ScrollTheme) -> bool {
+        self.kinetic.unwrap_or(theme.kinetic)
+    }
+
+    fn get_friction(&self, theme: &ScrollTheme) -> f32 {
+        self.friction.unwrap_or(theme.friction)
+    }
+
+    fn get_overscroll(&self, theme: &ScrollTheme) -> Overscroll {
+        self.overscroll.unwrap_or(theme.overscroll)
+    }
+
+    fn get_glow_paint(&self, theme: &ScrollTheme, palette: &Palette) -> Color {
+        self.glow_color
+            .unwrap_or_else(|| theme.glow_color.unwrap_or(palette.primary.fade(0.3)))
+    }
+
+    fn get_viewport_paint(&self, theme: &ScrollTheme, palette: &Palette) -> Color {
+        self.viewport_color
+            .unwrap_or_else(|| theme.viewport_color.unwrap_or(palette.contrast.fade(0.1)))
+    }
 }
 
 impl<V> ViewMarker for Scroll<V> {}
@@ -208,6 +392,13 @@ where
         let bar_border_color = self.properties.get_bar_border_paint(&theme, &palette);
         let bar_color = self.properties.get_bar_paint(&theme, &palette);
         let knob_color = self.properties.get_knob_paint(&theme, &palette);
+        let minimap_width = self.properties.get_minimap_width(&theme);
+        let minimap_color = self.properties.get_minimap_paint(&theme, &palette);
+        let viewport_color = self.properties.get_viewport_paint(&theme, &palette);
+        let kinetic = self.properties.get_kinetic(&theme);
+        let friction = self.properties.get_friction(&theme);
+        let overscroll = self.properties.get_overscroll(&theme);
+        let glow_color = self.properties.get_glow_paint(&theme, &palette);
 
         widgets::Scroll::set_overlay(&mut widget, self.properties.overlay);
         widgets::Scroll::set_vertical(&mut widget, self.properties.vertical);
@@ -221,6 +412,18 @@ where
         widgets::Scroll::set_bar_border_paint(&mut widget, bar_border_color.into());
         widgets::Scroll::set_bar_paint(&mut widget, bar_color.into());
         widgets::Scroll::set_knob_paint(&mut widget, knob_color.into());
+        widgets::Scroll::set_minimap(&mut widget, self.properties.minimap);
+        widgets::Scroll::set_minimap_width(&mut widget, minimap_width);
+        widgets::Scroll::set_minimap_paint(&mut widget, minimap_color.into());
+        widgets::Scroll::set_minimap_viewport_paint(&mut widget, viewport_color.into());
+        widgets::Scroll::set_kinetic(&mut widget, kinetic);
+        widgets::Scroll::set_friction(&mut widget, friction);
+        widgets::Scroll::set_overscroll(&mut widget, overscroll);
+        widgets::Scroll::set_glow_color(&mut widget, glow_color);
+
+        if let Some(ref scroller) = self.properties.scroller {
+            scroller.apply(&mut widget);
+        }
 
         (
             widget.id(),
@@ -239,6 +442,7 @@ where
 
         let palette = cx.get_or_default::<Palette>();
         let theme = cx.get_or_default::<ScrollTheme>();
+        let retheme = is_retheming(cx);
 
         let Ok(mut widget) = cx.get_widget_mut(*element) else {
             return;
@@ -252,55 +456,98 @@ where
             widgets::Scroll::set_vertical(&mut widget, self.properties.vertical);
         }
 
+        if self.properties.minimap != properties.minimap {
+            widgets::Scroll::set_minimap(&mut widget, self.properties.minimap);
+        }
+
         if self.properties.horizontal != properties.horizontal {
             widgets::Scroll::set_horizontal(&mut widget, self.properties.horizontal);
         }
 
-        if self.properties.bar_width != properties.bar_width {
+        if retheme || self.properties.bar_width != properties.bar_width {
             let bar_width = self.properties.get_bar_width(&theme);
             widgets::Scroll::set_bar_thickness(&mut widget, bar_width);
         }
 
-        if self.properties.bar_padding != properties.bar_padding {
+        if retheme || self.properties.bar_padding != properties.bar_padding {
             let bar_padding = self.properties.get_bar_padding(&theme);
             widgets::Scroll::set_bar_padding(&mut widget, bar_padding);
         }
 
-        if self.properties.bar_border_width != properties.bar_border_width {
+        if retheme || self.properties.bar_border_width != properties.bar_border_width {
             let bar_border_width = self.properties.get_bar_border_width(&theme);
             widgets::Scroll::set_bar_border_width(&mut widget, bar_border_width);
         }
 
-        if self.properties.bar_corner_radius != properties.bar_corner_radius {
+        if retheme || self.properties.bar_corner_radius != properties.bar_corner_radius {
             let bar_corner_radius = self.properties.get_bar_corner_radius(&theme);
             widgets::Scroll::set_bar_corner_radius(&mut widget, bar_corner_radius);
         }
 
-        if self.properties.knob_corner_radius != properties.knob_corner_radius {
+        if retheme || self.properties.knob_corner_radius != properties.knob_corner_radius {
             let knob_corner_radius = self.properties.get_knob_corner_radius(&theme);
             widgets::Scroll::set_knob_corner_radius(&mut widget, knob_corner_radius);
         }
 
-        if self.properties.transition != properties.transition {
+        if retheme || self.properties.transition != properties.transition {
             let transition = self.properties.get_transition(&theme);
             widgets::Scroll::set_transition(&mut widget, transition);
         }
 
-        if self.properties.bar_border_color != properties.bar_border_color {
+        if retheme || self.properties.bar_border_color != properties.bar_border_color {
             let bar_border_color = self.properties.get_bar_border_paint(&theme, &palette);
             widgets::Scroll::set_bar_border_paint(&mut widget, bar_border_color.into());
         }
 
-        if self.properties.bar_color != properties.bar_color {
+        if retheme || self.properties.bar_color != properties.bar_color {
             let bar_color = self.properties.get_bar_paint(&theme, &palette);
             widgets::Scroll::set_bar_paint(&mut widget, bar_color.into());
         }
 
-        if self.properties.knob_color != properties.knob_color {
+        if retheme || self.properties.knob_color != properties.knob_color {
             let knob_color = self.properties.get_knob_paint(&theme, &palette);
             widgets::Scroll::set_knob_paint(&mut widget, knob_color.into());
         }
 
+        if retheme || self.properties.minimap_width != properties.minimap_width {
+            let minimap_width = self.properties.get_minimap_width(&theme);
+            widgets::Scroll::set_minimap_width(&mut widget, minimap_width);
+        }
+
+        if retheme || self.properties.minimap_color != properties.minimap_color {
+            let minimap_color = self.properties.get_minimap_paint(&theme, &palette);
+            widgets::Scroll::set_minimap_paint(&mut widget, minimap_color.into());
+        }
+
+        if retheme || self.properties.viewport_color != properties.viewport_color {
+            let viewport_color = self.properties.get_viewport_paint(&theme, &palette);
+            widgets::Scroll::set_minimap_viewport_paint(&mut widget, viewport_color.into());
+        }
+
+        if retheme || self.properties.kinetic != properties.kinetic {
+            let kinetic = self.properties.get_kinetic(&theme);
+            widgets::Scroll::set_kinetic(&mut widget, kinetic);
+        }
+
+        if retheme || self.properties.friction != properties.friction {
+            let friction = self.properties.get_friction(&theme);
+            widgets::Scroll::set_friction(&mut widget, friction);
+        }
+
+        if retheme || self.properties.overscroll != properties.overscroll {
+            let overscroll = self.properties.get_overscroll(&theme);
+            widgets::Scroll::set_overscroll(&mut widget, overscroll);
+        }
+
+        if retheme || self.properties.glow_color != properties.glow_color {
+            let glow_color = self.properties.get_glow_paint(&theme, &palette);
+            widgets::Scroll::set_glow_color(&mut widget, glow_color);
+        }
+
+        if let Some(ref scroller) = self.properties.scroller {
+            scroller.apply(&mut widget);
+        }
+
         *properties = self.properties;
     }
 
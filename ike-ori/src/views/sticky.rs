@@ -0,0 +1,117 @@
+use ike_core::{Axis, Builder, WidgetId, widgets};
+use ori::{Action, Event, View, ViewMarker};
+
+use crate::Context;
+
+/// A section of `contents` with a `header` that sticks to the start of an enclosing scroll
+/// while the section is visible.
+pub fn sticky<H, V>(header: H, contents: V) -> Sticky<H, V> {
+    Sticky::new(header, contents)
+}
+
+pub struct Sticky<H, V> {
+    header:   H,
+    contents: V,
+
+    axis: Axis,
+}
+
+impl<H, V> Sticky<H, V> {
+    pub fn new(header: H, contents: V) -> Self {
+        Self {
+            header,
+            contents,
+            axis: Axis::Vertical,
+        }
+    }
+
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+}
+
+impl<H, V> ViewMarker for Sticky<H, V> {}
+impl<T, H, V> View<Context, T> for Sticky<H, V>
+where
+    H: crate::View<T>,
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Sticky>;
+    type State = (
+        Axis,
+        H::Element,
+        H::State,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (header, header_state) = self.header.build(cx, data);
+        let (contents, contents_state) = self.contents.build(cx, data);
+
+        let mut widget = widgets::Sticky::new(cx, header, contents);
+
+        widgets::Sticky::set_axis(&mut widget, self.axis);
+
+        (
+            widget.id(),
+            (
+                self.axis,
+                header,
+                header_state,
+                contents,
+                contents_state,
+            ),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (axis, header, header_state, contents, contents_state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.header.rebuild(header, header_state, cx, data);
+        self.contents.rebuild(contents, contents_state, cx, data);
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.axis != *axis {
+            *axis = self.axis;
+            widgets::Sticky::set_axis(&mut widget, self.axis);
+        }
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (_axis, header, header_state, contents, contents_state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let header = H::event(header, header_state, cx, data, event);
+        let contents = V::event(
+            contents,
+            contents_state,
+            cx,
+            data,
+            event,
+        );
+
+        header | contents
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_axis, header, header_state, contents, contents_state): Self::State,
+        cx: &mut Context,
+    ) {
+        H::teardown(header, header_state, cx);
+        V::teardown(contents, contents_state, cx);
+        cx.remove_widget(element);
+    }
+}
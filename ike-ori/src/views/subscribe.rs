@@ -0,0 +1,235 @@
+use std::{
+    collections::VecDeque,
+    future,
+    pin::pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Poll, Waker},
+};
+
+use futures_core::Stream;
+use ori::{Action, Event, NoElement, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, TaskHandle};
+
+/// Subscribe to `stream`, calling `on_item` with each item it yields.
+///
+/// The stream is polled in the background from when the view is built, and dropped when the
+/// view is removed. Streams passed when the view is rebuilt are ignored, use
+/// [`keyed`](ori::keyed) to subscribe to another stream. How items are delivered when
+/// the stream yields them faster than they're handled is set with [`Subscribe::backpressure`].
+pub fn subscribe<T, S, A>(
+    stream: S,
+    on_item: impl FnMut(&mut T, S::Item) -> A + 'static,
+) -> Subscribe<T, S>
+where
+    S: Stream,
+    A: Into<Action>,
+{
+    Subscribe::new(stream, on_item)
+}
+
+/// How items of a [`Subscribe`] are delivered when the stream yields them faster than they're
+/// handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Deliver every item in order, queueing them while they wait to be handled.
+    #[default]
+    Queue,
+
+    /// Deliver every item in order, pausing the stream while `capacity` items wait to be
+    /// handled.
+    Bounded(usize),
+
+    /// Deliver only the most recent item, dropping items that weren't handled before the next
+    /// one was yielded, e.g. for streams of state where only the current state matters.
+    Latest,
+}
+
+pub struct Subscribe<T, S>
+where
+    S: Stream,
+{
+    stream:       S,
+    on_item:      Box<dyn FnMut(&mut T, S::Item) -> Action>,
+    backpressure: Backpressure,
+}
+
+impl<T, S> Subscribe<T, S>
+where
+    S: Stream,
+{
+    pub fn new<A>(stream: S, mut on_item: impl FnMut(&mut T, S::Item) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            stream,
+            on_item: Box::new(move |data, item| on_item(data, item).into()),
+            backpressure: Backpressure::default(),
+        }
+    }
+
+    /// Set how items are delivered when they're yielded faster than they're handled.
+    ///
+    /// Defaults to [`Backpressure::Queue`].
+    pub fn backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+}
+
+/// The items yielded by the stream that haven't been handled yet.
+struct Shared<I> {
+    items:       VecDeque<I>,
+    is_notified: bool,
+
+    /// The waker of the subscription, while it waits for the queue to drain.
+    waker: Option<Waker>,
+}
+
+fn lock<I>(shared: &Mutex<Shared<I>>) -> MutexGuard<'_, Shared<I>> {
+    shared.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Sent to the view when items were queued, and it wasn't already notified.
+struct Yielded;
+
+fn poll<S>(
+    cx: &mut Context,
+    id: ViewId,
+    stream: S,
+    backpressure: Backpressure,
+    shared: Arc<Mutex<Shared<S::Item>>>,
+) -> TaskHandle
+where
+    S: Stream + Send + 'static,
+    S::Item: Send,
+{
+    let proxy = cx.proxy();
+
+    cx.spawn_scoped(async move {
+        let mut stream = pin!(stream);
+
+        loop {
+            if let Backpressure::Bounded(capacity) = backpressure {
+                future::poll_fn(|task| {
+                    let mut shared = lock(&shared);
+
+                    if shared.items.len() < capacity.max(1) {
+                        Poll::Ready(())
+                    } else {
+                        shared.waker = Some(task.waker().clone());
+                        Poll::Pending
+                    }
+                })
+                .await;
+            }
+
+            let item = future::poll_fn(|task| stream.as_mut().poll_next(task)).await;
+
+            let Some(item) = item else {
+                break;
+            };
+
+            let mut shared = lock(&shared);
+
+            if let Backpressure::Latest = backpressure {
+                shared.items.clear();
+            }
+
+            shared.items.push_back(item);
+
+            if !shared.is_notified {
+                shared.is_notified = true;
+                proxy.event(Event::new(Yielded, id));
+            }
+        }
+    })
+}
+
+impl<T, S> ViewMarker for Subscribe<T, S> where S: Stream {}
+impl<T, S> View<Context, T> for Subscribe<T, S>
+where
+    S: Stream + Send + 'static,
+    S::Item: Send,
+{
+    type Element = NoElement;
+    type State = (
+        ViewId,
+        Box<dyn FnMut(&mut T, S::Item) -> Action>,
+        Arc<Mutex<Shared<S::Item>>>,
+        TaskHandle,
+    );
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let id = ViewId::next();
+
+        let shared = Arc::new(Mutex::new(Shared {
+            items:       VecDeque::new(),
+            is_notified: false,
+            waker:       None,
+        }));
+
+        let handle = poll(
+            cx,
+            id,
+            self.stream,
+            self.backpressure,
+            shared.clone(),
+        );
+
+        (
+            NoElement,
+            (id, self.on_item, shared, handle),
+        )
+    }
+
+    fn rebuild(
+        self,
+        _element: (),
+        (_id, on_item, _shared, _handle): &mut Self::State,
+        _cx: &mut Context,
+        _data: &mut T,
+    ) {
+        *on_item = self.on_item;
+    }
+
+    fn event(
+        _element: (),
+        (id, on_item, shared, _handle): &mut Self::State,
+        _cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        if event.take_targeted::<Yielded>(*id).is_none() {
+            return Action::new();
+        }
+
+        let items = {
+            let mut shared = lock(shared);
+            shared.is_notified = false;
+
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+
+            std::mem::take(&mut shared.items)
+        };
+
+        let mut action = Action::new();
+
+        for item in items {
+            action = action | on_item(data, item);
+        }
+
+        action
+    }
+
+    fn teardown(
+        _element: NoElement,
+        (_id, _on_item, _shared, handle): Self::State,
+        _cx: &mut Context,
+    ) {
+        handle.cancel();
+    }
+}
@@ -0,0 +1,794 @@
+use std::{fs, rc::Rc, sync::Arc};
+
+use ike_core::{
+    Builder, Color, FileDialog, Paint, Paragraph, TextStyle, TextWrap, WidgetId, WidgetMut,
+    widgets::{self, ColumnWidth, SortOrder, TableColumn},
+};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, PagedRows, Palette, export, is_retheming, views::TextTheme};
+
+/// Create a [`Table`] showing `rows` of text.
+///
+/// Columns are added with [`Table::column`], every row is expected to have a cell for each.
+pub fn table<T>(rows: impl Into<Vec<Vec<String>>>) -> Table<T> {
+    Table::new(rows)
+}
+
+/// Create a [`Table`] showing `rows` loaded a page at a time, see [`PagedRows`].
+pub fn paged_table<T>(rows: &PagedRows) -> Table<T> {
+    Table::paged(rows)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableTheme {
+    pub row_height:     f32,
+    pub header_height:  f32,
+    pub cell_padding:   f32,
+    pub font_size:      Option<f32>,
+    pub text_color:     Option<Color>,
+    pub header_color:   Option<Color>,
+    pub row_color:      Option<Color>,
+    pub alt_row_color:  Option<Color>,
+    pub hovered_color:  Option<Color>,
+    pub selected_color: Option<Color>,
+    pub divider_color:  Option<Color>,
+    pub focus_color:    Option<Color>,
+}
+
+impl Default for TableTheme {
+    fn default() -> Self {
+        Self {
+            row_height:     28.0,
+            header_height:  32.0,
+            cell_padding:   8.0,
+            font_size:      None,
+            text_color:     None,
+            header_color:   None,
+            row_color:      None,
+            alt_row_color:  None,
+            hovered_color:  None,
+            selected_color: None,
+            divider_color:  None,
+            focus_color:    None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Column {
+    title:     String,
+    width:     ColumnWidth,
+    resizable: bool,
+    sortable:  bool,
+}
+
+enum Rows {
+    Owned(Rc<Vec<Vec<String>>>),
+
+    /// Paged rows, and the generation they had when the view was created.
+    Paged(PagedRows, u64),
+}
+
+impl PartialEq for Rows {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Rows::Owned(a), Rows::Owned(b)) => a == b,
+            (Rows::Paged(a, a_generation), Rows::Paged(b, b_generation)) => {
+                a.ptr_eq(b) && a_generation == b_generation
+            }
+            _ => false,
+        }
+    }
+}
+
+pub struct Table<T> {
+    rows:       Rows,
+    properties: Properties<T>,
+}
+
+impl<T> Table<T> {
+    pub fn new(rows: impl Into<Vec<Vec<String>>>) -> Self {
+        Self::with_rows(Rows::Owned(Rc::new(rows.into())))
+    }
+
+    /// Create a table showing `rows` loaded a page at a time.
+    ///
+    /// Rows that are still loading are shown as placeholders.
+    pub fn paged(rows: &PagedRows) -> Self {
+        Self::with_rows(Rows::Paged(
+            rows.clone(),
+            rows.generation(),
+        ))
+    }
+
+    fn with_rows(rows: Rows) -> Self {
+        Self {
+            rows,
+            properties: Properties {
+                on_sort:   Box::new(|_, _| Action::new()),
+                on_select: Box::new(|_, _| Action::new()),
+                export:    Rc::new(|row: &[String], column: usize| {
+                    row.get(column).cloned().unwrap_or_default()
+                }),
+
+                columns:        Vec::new(),
+                save_csv:       None,
+                selected:       Vec::new(),
+                sort:           None,
+                row_height:     None,
+                header_height:  None,
+                cell_padding:   None,
+                font_size:      None,
+                text_color:     None,
+                header_color:   None,
+                row_color:      None,
+                alt_row_color:  None,
+                hovered_color:  None,
+                selected_color: None,
+                divider_color:  None,
+                focus_color:    None,
+            },
+        }
+    }
+
+    /// Add a resizable column with `title` and `width`.
+    pub fn column(mut self, title: impl ToString, width: ColumnWidth) -> Self {
+        self.properties.columns.push(Column {
+            title: title.to_string(),
+            width,
+            resizable: true,
+            sortable: false,
+        });
+        self
+    }
+
+    /// Set whether the last added column can be resized by dragging its header divider.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        if let Some(column) = self.properties.columns.last_mut() {
+            column.resizable = resizable;
+        }
+
+        self
+    }
+
+    /// Set whether the last added column can be sorted, see [`Table::on_sort`].
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        if let Some(column) = self.properties.columns.last_mut() {
+            column.sortable = sortable;
+        }
+
+        self
+    }
+
+    /// Set the selected rows.
+    pub fn selected(mut self, selected: impl Into<Vec<usize>>) -> Self {
+        self.properties.selected = selected.into();
+        self
+    }
+
+    /// Set the column the rows are sorted by, used to draw the sort indicator.
+    ///
+    /// Note that the rows are not sorted by the table, this is left to the user.
+    pub fn sort(mut self, sort: Option<(usize, SortOrder)>) -> Self {
+        self.properties.sort = sort;
+        self
+    }
+
+    /// Called with the index of a sortable column when its header is clicked.
+    pub fn on_sort<A>(mut self, mut on_sort: impl FnMut(&mut T, usize) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_sort = Box::new(move |data, column| on_sort(data, column).into());
+        self
+    }
+
+    /// Called with the selected rows when the selection changes.
+    pub fn on_select<A>(
+        mut self,
+        mut on_select: impl FnMut(&mut T, Vec<usize>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_select = Box::new(move |data, rows| on_select(data, rows).into());
+        self
+    }
+
+    /// Format the cell of a row at a column index when rows are copied or saved.
+    ///
+    /// By default cells are exported as they are shown.
+    pub fn export_cell(mut self, export: impl Fn(&[String], usize) -> String + 'static) -> Self {
+        self.properties.export = Rc::new(export);
+        self
+    }
+
+    /// Save the rows as CSV with the action modifier and `s`, suggesting `name` as the file
+    /// name in the save dialog.
+    ///
+    /// Of paged rows only the loaded pages are saved.
+    pub fn save_csv(mut self, name: impl Into<String>) -> Self {
+        self.properties.save_csv = Some(name.into());
+        self
+    }
+
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.properties.row_height = Some(row_height);
+        self
+    }
+
+    pub fn header_height(mut self, header_height: f32) -> Self {
+        self.properties.header_height = Some(header_height);
+        self
+    }
+
+    pub fn cell_padding(mut self, cell_padding: f32) -> Self {
+        self.properties.cell_padding = Some(cell_padding);
+        self
+    }
+
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.properties.font_size = Some(font_size);
+        self
+    }
+
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.properties.text_color = Some(color);
+        self
+    }
+
+    pub fn header_color(mut self, color: Color) -> Self {
+        self.properties.header_color = Some(color);
+        self
+    }
+
+    pub fn row_color(mut self, color: Color) -> Self {
+        self.properties.row_color = Some(color);
+        self
+    }
+
+    pub fn alt_row_color(mut self, color: Color) -> Self {
+        self.properties.alt_row_color = Some(color);
+        self
+    }
+
+    pub fn hovered_color(mut self, color: Color) -> Self {
+        self.properties.hovered_color = Some(color);
+        self
+    }
+
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.properties.selected_color = Some(color);
+        self
+    }
+
+    pub fn divider_color(mut self, color: Color) -> Self {
+        self.properties.divider_color = Some(color);
+        self
+    }
+
+    pub fn focus_color(mut self, color: Color) -> Self {
+        self.properties.focus_color = Some(color);
+        self
+    }
+}
+
+enum TableEvent {
+    Sort(usize),
+    Select(Vec<usize>),
+    Copy(Vec<usize>),
+    Save,
+    Loaded,
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_sort:   Box<dyn FnMut(&mut T, usize) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_select: Box<dyn FnMut(&mut T, Vec<usize>) -> Action>,
+    #[allow(clippy::type_complexity)]
+    export:    Rc<dyn Fn(&[String], usize) -> String>,
+
+    columns:        Vec<Column>,
+    save_csv:       Option<String>,
+    selected:       Vec<usize>,
+    sort:           Option<(usize, SortOrder)>,
+    row_height:     Option<f32>,
+    header_height:  Option<f32>,
+    cell_padding:   Option<f32>,
+    font_size:      Option<f32>,
+    text_color:     Option<Color>,
+    header_color:   Option<Color>,
+    row_color:      Option<Color>,
+    alt_row_color:  Option<Color>,
+    hovered_color:  Option<Color>,
+    selected_color: Option<Color>,
+    divider_color:  Option<Color>,
+    focus_color:    Option<Color>,
+}
+
+impl<T> Properties<T> {
+    fn get_row_height(&self, theme: &TableTheme) -> f32 {
+        self.row_height.unwrap_or(theme.row_height)
+    }
+
+    fn get_header_height(&self, theme: &TableTheme) -> f32 {
+        self.header_height.unwrap_or(theme.header_height)
+    }
+
+    fn get_cell_padding(&self, theme: &TableTheme) -> f32 {
+        self.cell_padding.unwrap_or(theme.cell_padding)
+    }
+
+    fn get_text_color(&self, theme: &TableTheme, text: &TextTheme, palette: &Palette) -> Color {
+        self.text_color.unwrap_or_else(|| {
+            theme
+                .text_color
+                .unwrap_or_else(|| text.color.unwrap_or(palette.contrast))
+        })
+    }
+
+    fn get_header_color(&self, theme: &TableTheme, palette: &Palette) -> Color {
+        self.header_color
+            .unwrap_or_else(|| theme.header_color.unwrap_or_else(|| palette.surface(1)))
+    }
+
+    fn get_row_color(&self, theme: &TableTheme, palette: &Palette) -> Color {
+        self.row_color
+            .unwrap_or_else(|| theme.row_color.unwrap_or_else(|| palette.surface(0)))
+    }
+
+    fn get_alt_row_color(&self, theme: &TableTheme, palette: &Palette) -> Color {
+        self.alt_row_color
+            .unwrap_or_else(|| theme.alt_row_color.unwrap_or_else(|| palette.surface(-1)))
+    }
+
+    fn get_hovered_color(&self, theme: &TableTheme, palette: &Palette) -> Color {
+        self.hovered_color
+            .unwrap_or_else(|| theme.hovered_color.unwrap_or_else(|| palette.surface(2)))
+    }
+
+    fn get_selected_color(&self, theme: &TableTheme, palette: &Palette) -> Color {
+        self.selected_color.unwrap_or_else(|| {
+            theme
+                .selected_color
+                .unwrap_or_else(|| palette.primary.fade(0.3))
+        })
+    }
+
+    fn get_divider_color(&self, theme: &TableTheme, palette: &Palette) -> Color {
+        self.divider_color
+            .unwrap_or_else(|| theme.divider_color.unwrap_or(palette.outline))
+    }
+
+    fn get_focus_color(&self, theme: &TableTheme, palette: &Palette) -> Color {
+        self.focus_color
+            .unwrap_or_else(|| theme.focus_color.unwrap_or(palette.info))
+    }
+
+    fn text_style(&self, theme: &TableTheme, text: &TextTheme, palette: &Palette) -> TextStyle {
+        TextStyle {
+            font_size:    self
+                .font_size
+                .unwrap_or_else(|| theme.font_size.unwrap_or(text.font_size)),
+            font_weight:  text.font_weight,
+            font_stretch: text.font_stretch,
+            font_style:   text.font_style,
+            font_family:  text.font_family.clone().into_owned(),
+
+            paint: Paint::from(self.get_text_color(theme, text, palette)),
+        }
+    }
+
+    fn build_columns(
+        &self,
+        theme: &TableTheme,
+        text: &TextTheme,
+        palette: &Palette,
+    ) -> Vec<TableColumn> {
+        let style = self.text_style(theme, text, palette);
+
+        self.columns
+            .iter()
+            .map(|column| {
+                let mut title = Paragraph::new(text.line_height, text.align, text.wrap);
+                title.push(&column.title, style.clone());
+
+                let mut table_column = TableColumn::new(title);
+                table_column.width = column.width;
+                table_column.resizable = column.resizable;
+                table_column.sortable = column.sortable;
+                table_column
+            })
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn set_cells(
+        &self,
+        widget: &mut WidgetMut<widgets::Table>,
+        rows: &Rows,
+        id: ViewId,
+        proxy: &Arc<dyn Proxy>,
+        theme: &TableTheme,
+        text: &TextTheme,
+        palette: &Palette,
+    ) {
+        let style = self.text_style(theme, text, palette);
+        let line_height = text.line_height;
+        let align = text.align;
+
+        match rows {
+            Rows::Owned(rows) => {
+                let rows = rows.clone();
+
+                widgets::Table::set_cells(
+                    widget,
+                    rows.len(),
+                    move |row, column| {
+                        let mut paragraph = Paragraph::new(line_height, align, TextWrap::None);
+
+                        if let Some(cell) = rows[row].get(column) {
+                            paragraph.push(cell, style.clone());
+                        }
+
+                        paragraph
+                    },
+                );
+
+                widgets::Table::set_on_visible(widget, |_| {});
+            }
+
+            Rows::Paged(rows, _) => {
+                let mut placeholder = style.clone();
+                placeholder.paint =
+                    Paint::from(self.get_text_color(theme, text, palette).fade(0.4));
+
+                widgets::Table::set_cells(widget, rows.count().unwrap_or(0), {
+                    let rows = rows.clone();
+
+                    move |row, column| {
+                        let mut paragraph = Paragraph::new(line_height, align, TextWrap::None);
+
+                        match rows.cell(row, column) {
+                            Some(cell) => paragraph.push(&cell, style.clone()),
+                            None => paragraph.push("…", placeholder.clone()),
+                        }
+
+                        paragraph
+                    }
+                });
+
+                widgets::Table::set_on_visible(widget, {
+                    let rows = rows.clone();
+                    let proxy = proxy.cloned();
+
+                    move |visible| {
+                        let on_load = {
+                            let proxy = proxy.cloned();
+                            move || proxy.event(Event::new(TableEvent::Loaded, id))
+                        };
+
+                        rows.show(&proxy, visible, on_load);
+                    }
+                });
+            }
+        }
+    }
+
+    fn set_on_save(
+        &self,
+        widget: &mut WidgetMut<widgets::Table>,
+        id: ViewId,
+        proxy: &Arc<dyn Proxy>,
+    ) {
+        let on_save = self.save_csv.as_ref().map(|_| {
+            let proxy = proxy.cloned();
+            Box::new(move || proxy.event(Event::new(TableEvent::Save, id))) as Box<dyn FnMut()>
+        });
+
+        widgets::Table::set_on_save(widget, on_save);
+    }
+
+    fn export_rows(
+        &self,
+        rows: &Rows,
+        selected: impl IntoIterator<Item = usize>,
+    ) -> Vec<Vec<String>> {
+        // rows of pages that aren't loaded are left out
+        match rows {
+            Rows::Owned(rows) => selected
+                .into_iter()
+                .filter_map(|row| rows.get(row).cloned())
+                .collect(),
+            Rows::Paged(rows, _) => selected
+                .into_iter()
+                .filter_map(|row| rows.row(row))
+                .collect(),
+        }
+    }
+
+    fn columns_changed(&self, other: &Self) -> bool {
+        self.columns != other.columns || self.text_changed(other)
+    }
+
+    fn text_changed(&self, other: &Self) -> bool {
+        self.font_size != other.font_size || self.text_color != other.text_color
+    }
+}
+
+impl<T> ViewMarker for Table<T> {}
+impl<T> View<Context, T> for Table<T> {
+    type Element = WidgetId<widgets::Table>;
+    type State = (ViewId, Rows, Properties<T>);
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<TableTheme>();
+        let text = cx.get_or_default::<TextTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let columns = self.properties.build_columns(&theme, &text, &palette);
+        let row_height = self.properties.get_row_height(&theme);
+        let header_height = self.properties.get_header_height(&theme);
+        let cell_padding = self.properties.get_cell_padding(&theme);
+        let text_color = self.properties.get_text_color(&theme, &text, &palette);
+        let header_color = self.properties.get_header_color(&theme, &palette);
+        let row_color = self.properties.get_row_color(&theme, &palette);
+        let alt_row_color = self.properties.get_alt_row_color(&theme, &palette);
+        let hovered_color = self.properties.get_hovered_color(&theme, &palette);
+        let selected_color = self.properties.get_selected_color(&theme, &palette);
+        let divider_color = self.properties.get_divider_color(&theme, &palette);
+        let focus_color = self.properties.get_focus_color(&theme, &palette);
+
+        let mut widget = widgets::Table::new(cx);
+
+        widgets::Table::set_columns(&mut widget, columns);
+        (self.properties).set_cells(
+            &mut widget,
+            &self.rows,
+            id,
+            &proxy,
+            &theme,
+            &text,
+            &palette,
+        );
+        widgets::Table::set_selected(
+            &mut widget,
+            self.properties.selected.clone(),
+        );
+        widgets::Table::set_sort(&mut widget, self.properties.sort);
+        widgets::Table::set_row_height(&mut widget, row_height);
+        widgets::Table::set_header_height(&mut widget, header_height);
+        widgets::Table::set_cell_padding(&mut widget, cell_padding);
+        widgets::Table::set_sort_color(&mut widget, text_color);
+        widgets::Table::set_header_color(&mut widget, header_color);
+        widgets::Table::set_row_color(&mut widget, row_color);
+        widgets::Table::set_alt_row_color(&mut widget, alt_row_color);
+        widgets::Table::set_hovered_color(&mut widget, hovered_color);
+        widgets::Table::set_selected_color(&mut widget, selected_color);
+        widgets::Table::set_divider_color(&mut widget, divider_color);
+        widgets::Table::set_focus_color(&mut widget, focus_color);
+
+        widgets::Table::set_on_sort(&mut widget, {
+            let proxy = proxy.cloned();
+            move |column| proxy.event(Event::new(TableEvent::Sort(column), id))
+        });
+
+        widgets::Table::set_on_select(&mut widget, {
+            let proxy = proxy.cloned();
+            move |rows| {
+                proxy.event(Event::new(
+                    TableEvent::Select(rows.to_vec()),
+                    id,
+                ))
+            }
+        });
+
+        widgets::Table::set_on_copy(&mut widget, {
+            let proxy = proxy.cloned();
+            move |rows| {
+                proxy.event(Event::new(
+                    TableEvent::Copy(rows.to_vec()),
+                    id,
+                ))
+            }
+        });
+
+        (self.properties).set_on_save(&mut widget, id, &proxy);
+
+        (
+            widget.id(),
+            (id, self.rows, self.properties),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (id, rows, properties): &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<TableTheme>();
+        let retheme = is_retheming(cx);
+        let text = cx.get_or_default::<TextTheme>();
+        let proxy = cx.proxy();
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        // resetting the columns would discard the widths the user has dragged them to
+        if self.properties.columns_changed(properties) {
+            let columns = self.properties.build_columns(&theme, &text, &palette);
+            widgets::Table::set_columns(&mut widget, columns);
+        }
+
+        if retheme || self.rows != *rows || self.properties.text_changed(properties) {
+            (self.properties).set_cells(
+                &mut widget,
+                &self.rows,
+                *id,
+                &proxy,
+                &theme,
+                &text,
+                &palette,
+            );
+        }
+
+        if self.properties.save_csv.is_some() != properties.save_csv.is_some() {
+            (self.properties).set_on_save(&mut widget, *id, &proxy);
+        }
+
+        if self.properties.selected != properties.selected {
+            widgets::Table::set_selected(
+                &mut widget,
+                self.properties.selected.clone(),
+            );
+        }
+
+        if self.properties.sort != properties.sort {
+            widgets::Table::set_sort(&mut widget, self.properties.sort);
+        }
+
+        if retheme || self.properties.row_height != properties.row_height {
+            let row_height = self.properties.get_row_height(&theme);
+            widgets::Table::set_row_height(&mut widget, row_height);
+        }
+
+        if retheme || self.properties.header_height != properties.header_height {
+            let header_height = self.properties.get_header_height(&theme);
+            widgets::Table::set_header_height(&mut widget, header_height);
+        }
+
+        if retheme || self.properties.cell_padding != properties.cell_padding {
+            let cell_padding = self.properties.get_cell_padding(&theme);
+            widgets::Table::set_cell_padding(&mut widget, cell_padding);
+        }
+
+        if retheme || self.properties.text_color != properties.text_color {
+            let text_color = self.properties.get_text_color(&theme, &text, &palette);
+            widgets::Table::set_sort_color(&mut widget, text_color);
+        }
+
+        if retheme || self.properties.header_color != properties.header_color {
+            let header_color = self.properties.get_header_color(&theme, &palette);
+            widgets::Table::set_header_color(&mut widget, header_color);
+        }
+
+        if retheme || self.properties.row_color != properties.row_color {
+            let row_color = self.properties.get_row_color(&theme, &palette);
+            widgets::Table::set_row_color(&mut widget, row_color);
+        }
+
+        if retheme || self.properties.alt_row_color != properties.alt_row_color {
+            let alt_row_color = self.properties.get_alt_row_color(&theme, &palette);
+            widgets::Table::set_alt_row_color(&mut widget, alt_row_color);
+        }
+
+        if retheme || self.properties.hovered_color != properties.hovered_color {
+            let hovered_color = self.properties.get_hovered_color(&theme, &palette);
+            widgets::Table::set_hovered_color(&mut widget, hovered_color);
+        }
+
+        if retheme || self.properties.selected_color != properties.selected_color {
+            let selected_color = self.properties.get_selected_color(&theme, &palette);
+            widgets::Table::set_selected_color(&mut widget, selected_color);
+        }
+
+        if retheme || self.properties.divider_color != properties.divider_color {
+            let divider_color = self.properties.get_divider_color(&theme, &palette);
+            widgets::Table::set_divider_color(&mut widget, divider_color);
+        }
+
+        if retheme || self.properties.focus_color != properties.focus_color {
+            let focus_color = self.properties.get_focus_color(&theme, &palette);
+            widgets::Table::set_focus_color(&mut widget, focus_color);
+        }
+
+        *rows = self.rows;
+        *properties = self.properties;
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (id, rows, properties): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        match event.take_targeted(*id) {
+            Some(TableEvent::Sort(column)) => (properties.on_sort)(data, column),
+            Some(TableEvent::Select(selected)) => (properties.on_select)(data, selected),
+
+            Some(TableEvent::Copy(selected)) => {
+                let selected = properties.export_rows(rows, selected);
+
+                let tsv = export::to_tsv(
+                    selected.iter(),
+                    properties.columns.len(),
+                    |row, column| (properties.export)(row, column),
+                );
+
+                cx.set_clipboard(tsv);
+                Action::new()
+            }
+
+            Some(TableEvent::Save) => {
+                let Some(ref name) = properties.save_csv else {
+                    return Action::new();
+                };
+
+                let count = match rows {
+                    Rows::Owned(rows) => rows.len(),
+                    Rows::Paged(rows, _) => rows.count().unwrap_or(0),
+                };
+
+                let headers: Vec<_> = (properties.columns.iter())
+                    .map(|column| column.title.as_str())
+                    .collect();
+
+                let csv = export::to_csv(
+                    Some(&headers[..]),
+                    properties.export_rows(rows, 0..count).iter(),
+                    properties.columns.len(),
+                    |row, column| (properties.export)(row, column),
+                );
+
+                let dialog = FileDialog::save(name.clone()).filter("CSV", ["csv"]);
+                cx.show_file_dialog(dialog, move |paths| {
+                    if let Some(path) = paths.first()
+                        && let Err(err) = fs::write(path, csv)
+                    {
+                        tracing::warn!(
+                            "failed to save table to `{}`: {err}",
+                            path.display()
+                        );
+                    }
+                });
+
+                Action::new()
+            }
+
+            Some(TableEvent::Loaded) => {
+                if let Rows::Paged(rows, _) = rows
+                    && let Ok(mut widget) = cx.get_widget_mut(*element)
+                {
+                    widgets::Table::set_rows(&mut widget, rows.count().unwrap_or(0));
+                }
+
+                Action::new()
+            }
+
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context) {
+        cx.remove_widget(element);
+    }
+}
@@ -0,0 +1,465 @@
+use std::mem;
+
+use ike_core::{
+    Builder, Color, Padding, Paint, Paragraph, TextStyle, Transition, WidgetId, widgets,
+};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, Palette, is_retheming, views::TextTheme};
+
+/// Create [`Tabs`] showing `contents` as the page of the `selected` tab.
+///
+/// Only the page of the selected tab is ever built, pages of other tabs are built when they are
+/// selected, and torn down when they are deselected.
+pub fn tabs<T, V>(selected: usize, contents: V) -> Tabs<T, V> {
+    Tabs::new(selected, contents)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TabsTheme {
+    pub padding:         Padding,
+    pub font_size:       Option<f32>,
+    pub close_size:      f32,
+    pub indicator_width: f32,
+    pub transition:      Transition,
+    pub text_color:      Option<Color>,
+    pub bar_color:       Option<Color>,
+    pub hovered_color:   Option<Color>,
+    pub indicator_color: Option<Color>,
+    pub close_color:     Option<Color>,
+    pub focus_color:     Option<Color>,
+}
+
+impl Default for TabsTheme {
+    fn default() -> Self {
+        Self {
+            padding:         Padding::all(8.0),
+            font_size:       None,
+            close_size:      8.0,
+            indicator_width: 2.0,
+            transition:      Transition::ease(0.15),
+            text_color:      None,
+            bar_color:       None,
+            hovered_color:   None,
+            indicator_color: None,
+            close_color:     None,
+            focus_color:     None,
+        }
+    }
+}
+
+pub struct Tabs<T, V> {
+    selected:   usize,
+    contents:   V,
+    properties: Properties<T>,
+}
+
+impl<T, V> Tabs<T, V> {
+    pub fn new(selected: usize, contents: V) -> Self {
+        Self {
+            selected,
+            contents,
+            properties: Properties {
+                on_select: Box::new(|_, _| Action::new()),
+                on_close:  Box::new(|_, _| Action::new()),
+
+                titles:          Vec::new(),
+                closable:        false,
+                padding:         None,
+                font_size:       None,
+                close_size:      None,
+                indicator_width: None,
+                transition:      None,
+                text_color:      None,
+                bar_color:       None,
+                hovered_color:   None,
+                indicator_color: None,
+                close_color:     None,
+                focus_color:     None,
+            },
+        }
+    }
+
+    /// Add a tab with `title`.
+    pub fn tab(mut self, title: impl ToString) -> Self {
+        self.properties.titles.push(title.to_string());
+        self
+    }
+
+    /// Add a tab for each of `titles`.
+    pub fn tabs(mut self, titles: impl IntoIterator<Item = impl ToString>) -> Self {
+        let titles = titles.into_iter().map(|title| title.to_string());
+        self.properties.titles.extend(titles);
+        self
+    }
+
+    /// Show a close button on every tab, see [`Tabs::on_close`].
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.properties.closable = closable;
+        self
+    }
+
+    pub fn on_select<A>(mut self, mut on_select: impl FnMut(&mut T, usize) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_select = Box::new(move |data, index| on_select(data, index).into());
+        self
+    }
+
+    pub fn on_close<A>(mut self, mut on_close: impl FnMut(&mut T, usize) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.properties.on_close = Box::new(move |data, index| on_close(data, index).into());
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.properties.padding = Some(padding.into());
+        self
+    }
+
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.properties.font_size = Some(font_size);
+        self
+    }
+
+    pub fn close_size(mut self, close_size: f32) -> Self {
+        self.properties.close_size = Some(close_size);
+        self
+    }
+
+    pub fn indicator_width(mut self, indicator_width: f32) -> Self {
+        self.properties.indicator_width = Some(indicator_width);
+        self
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.properties.transition = Some(transition);
+        self
+    }
+
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.properties.text_color = Some(color);
+        self
+    }
+
+    pub fn bar_color(mut self, color: Color) -> Self {
+        self.properties.bar_color = Some(color);
+        self
+    }
+
+    pub fn hovered_color(mut self, color: Color) -> Self {
+        self.properties.hovered_color = Some(color);
+        self
+    }
+
+    pub fn indicator_color(mut self, color: Color) -> Self {
+        self.properties.indicator_color = Some(color);
+        self
+    }
+
+    pub fn close_color(mut self, color: Color) -> Self {
+        self.properties.close_color = Some(color);
+        self
+    }
+
+    pub fn focus_color(mut self, color: Color) -> Self {
+        self.properties.focus_color = Some(color);
+        self
+    }
+}
+
+enum TabsEvent {
+    Select(usize),
+    Close(usize),
+}
+
+pub struct Properties<T> {
+    #[allow(clippy::type_complexity)]
+    on_select: Box<dyn FnMut(&mut T, usize) -> Action>,
+    #[allow(clippy::type_complexity)]
+    on_close:  Box<dyn FnMut(&mut T, usize) -> Action>,
+
+    titles:          Vec<String>,
+    closable:        bool,
+    padding:         Option<Padding>,
+    font_size:       Option<f32>,
+    close_size:      Option<f32>,
+    indicator_width: Option<f32>,
+    transition:      Option<Transition>,
+    text_color:      Option<Color>,
+    bar_color:       Option<Color>,
+    hovered_color:   Option<Color>,
+    indicator_color: Option<Color>,
+    close_color:     Option<Color>,
+    focus_color:     Option<Color>,
+}
+
+impl<T> Properties<T> {
+    fn get_padding(&self, theme: &TabsTheme) -> Padding {
+        self.padding.unwrap_or(theme.padding)
+    }
+
+    fn get_close_size(&self, theme: &TabsTheme) -> f32 {
+        self.close_size.unwrap_or(theme.close_size)
+    }
+
+    fn get_indicator_width(&self, theme: &TabsTheme) -> f32 {
+        self.indicator_width.unwrap_or(theme.indicator_width)
+    }
+
+    fn get_transition(&self, theme: &TabsTheme) -> Transition {
+        self.transition.unwrap_or(theme.transition)
+    }
+
+    fn get_bar_color(&self, theme: &TabsTheme, palette: &Palette) -> Color {
+        self.bar_color
+            .unwrap_or_else(|| theme.bar_color.unwrap_or_else(|| palette.surface(0)))
+    }
+
+    fn get_hovered_color(&self, theme: &TabsTheme, palette: &Palette) -> Color {
+        self.hovered_color
+            .unwrap_or_else(|| theme.hovered_color.unwrap_or_else(|| palette.surface(1)))
+    }
+
+    fn get_indicator_color(&self, theme: &TabsTheme, palette: &Palette) -> Color {
+        self.indicator_color
+            .unwrap_or_else(|| theme.indicator_color.unwrap_or(palette.primary))
+    }
+
+    fn get_close_color(&self, theme: &TabsTheme, palette: &Palette) -> Color {
+        self.close_color
+            .unwrap_or_else(|| theme.close_color.unwrap_or(palette.contrast))
+    }
+
+    fn get_focus_color(&self, theme: &TabsTheme, palette: &Palette) -> Color {
+        self.focus_color
+            .unwrap_or_else(|| theme.focus_color.unwrap_or(palette.info))
+    }
+
+    fn build_titles(
+        &self,
+        theme: &TabsTheme,
+        text: &TextTheme,
+        palette: &Palette,
+    ) -> Vec<Paragraph> {
+        let style = TextStyle {
+            font_size:    self
+                .font_size
+                .unwrap_or_else(|| theme.font_size.unwrap_or(text.font_size)),
+            font_weight:  text.font_weight,
+            font_stretch: text.font_stretch,
+            font_style:   text.font_style,
+            font_family:  text.font_family.clone().into_owned(),
+
+            paint: Paint::from(self.text_color.unwrap_or_else(|| {
+                theme
+                    .text_color
+                    .unwrap_or_else(|| text.color.unwrap_or(palette.contrast))
+            })),
+        };
+
+        self.titles
+            .iter()
+            .map(|title| {
+                let mut paragraph = Paragraph::new(text.line_height, text.align, text.wrap);
+
+                paragraph.push(title, style.clone());
+                paragraph
+            })
+            .collect()
+    }
+
+    fn titles_changed(&self, other: &Self) -> bool {
+        self.titles != other.titles
+            || self.font_size != other.font_size
+            || self.text_color != other.text_color
+    }
+}
+
+impl<T, V> ViewMarker for Tabs<T, V> {}
+impl<T, V> View<Context, T> for Tabs<T, V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Tabs>;
+    type State = (
+        ViewId,
+        usize,
+        Properties<T>,
+        V::Element,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<TabsTheme>();
+        let text = cx.get_or_default::<TextTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let titles = self.properties.build_titles(&theme, &text, &palette);
+        let padding = self.properties.get_padding(&theme);
+        let close_size = self.properties.get_close_size(&theme);
+        let indicator_width = self.properties.get_indicator_width(&theme);
+        let transition = self.properties.get_transition(&theme);
+        let bar_color = self.properties.get_bar_color(&theme, &palette);
+        let hovered_color = self.properties.get_hovered_color(&theme, &palette);
+        let indicator_color = self.properties.get_indicator_color(&theme, &palette);
+        let close_color = self.properties.get_close_color(&theme, &palette);
+        let focus_color = self.properties.get_focus_color(&theme, &palette);
+
+        let (contents, state) = self.contents.build(cx, data);
+        let mut widget = widgets::Tabs::new(cx, contents);
+
+        widgets::Tabs::set_tabs(&mut widget, titles);
+        widgets::Tabs::set_selected(&mut widget, self.selected);
+        widgets::Tabs::set_closable(&mut widget, self.properties.closable);
+        widgets::Tabs::set_padding(&mut widget, padding);
+        widgets::Tabs::set_close_size(&mut widget, close_size);
+        widgets::Tabs::set_indicator_width(&mut widget, indicator_width);
+        widgets::Tabs::set_transition(&mut widget, transition);
+        widgets::Tabs::set_bar_color(&mut widget, bar_color);
+        widgets::Tabs::set_hovered_color(&mut widget, hovered_color);
+        widgets::Tabs::set_indicator_color(&mut widget, indicator_color);
+        widgets::Tabs::set_close_color(&mut widget, close_color);
+        widgets::Tabs::set_focus_color(&mut widget, focus_color);
+
+        widgets::Tabs::set_on_select(&mut widget, {
+            let proxy = proxy.cloned();
+            move |index| proxy.event(Event::new(TabsEvent::Select(index), id))
+        });
+
+        widgets::Tabs::set_on_close(&mut widget, move |index| {
+            proxy.event(Event::new(TabsEvent::Close(index), id));
+        });
+
+        (
+            widget.id(),
+            (
+                id,
+                self.selected,
+                self.properties,
+                contents,
+                state,
+            ),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (_id, selected, properties, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        if self.selected == *selected {
+            (self.contents).rebuild(contents, state, cx, data);
+        } else {
+            // the page of the previous tab is torn down, and the new one built from scratch
+            let (new_contents, new_state) = self.contents.build(cx, data);
+            widgets::Tabs::set_contents(cx, *element, new_contents);
+
+            let old_contents = mem::replace(contents, new_contents);
+            let old_state = mem::replace(state, new_state);
+            V::teardown(old_contents, old_state, cx);
+        }
+
+        let palette = cx.get_or_default::<Palette>();
+        let theme = cx.get_or_default::<TabsTheme>();
+        let retheme = is_retheming(cx);
+        let text = cx.get_or_default::<TextTheme>();
+
+        let Ok(mut widget) = cx.get_widget_mut(*element) else {
+            return;
+        };
+
+        if self.properties.titles_changed(properties) {
+            let titles = self.properties.build_titles(&theme, &text, &palette);
+            widgets::Tabs::set_tabs(&mut widget, titles);
+        }
+
+        if self.selected != *selected {
+            widgets::Tabs::set_selected(&mut widget, self.selected);
+        }
+
+        if self.properties.closable != properties.closable {
+            widgets::Tabs::set_closable(&mut widget, self.properties.closable);
+        }
+
+        if retheme || self.properties.padding != properties.padding {
+            let padding = self.properties.get_padding(&theme);
+            widgets::Tabs::set_padding(&mut widget, padding);
+        }
+
+        if retheme || self.properties.close_size != properties.close_size {
+            let close_size = self.properties.get_close_size(&theme);
+            widgets::Tabs::set_close_size(&mut widget, close_size);
+        }
+
+        if retheme || self.properties.indicator_width != properties.indicator_width {
+            let indicator_width = self.properties.get_indicator_width(&theme);
+            widgets::Tabs::set_indicator_width(&mut widget, indicator_width);
+        }
+
+        if retheme || self.properties.transition != properties.transition {
+            let transition = self.properties.get_transition(&theme);
+            widgets::Tabs::set_transition(&mut widget, transition);
+        }
+
+        if retheme || self.properties.bar_color != properties.bar_color {
+            let bar_color = self.properties.get_bar_color(&theme, &palette);
+            widgets::Tabs::set_bar_color(&mut widget, bar_color);
+        }
+
+        if retheme || self.properties.hovered_color != properties.hovered_color {
+            let hovered_color = self.properties.get_hovered_color(&theme, &palette);
+            widgets::Tabs::set_hovered_color(&mut widget, hovered_color);
+        }
+
+        if retheme || self.properties.indicator_color != properties.indicator_color {
+            let indicator_color = self.properties.get_indicator_color(&theme, &palette);
+            widgets::Tabs::set_indicator_color(&mut widget, indicator_color);
+        }
+
+        if retheme || self.properties.close_color != properties.close_color {
+            let close_color = self.properties.get_close_color(&theme, &palette);
+            widgets::Tabs::set_close_color(&mut widget, close_color);
+        }
+
+        if retheme || self.properties.focus_color != properties.focus_color {
+            let focus_color = self.properties.get_focus_color(&theme, &palette);
+            widgets::Tabs::set_focus_color(&mut widget, focus_color);
+        }
+
+        *selected = self.selected;
+        *properties = self.properties;
+    }
+
+    fn event(
+        _element: &mut Self::Element,
+        (id, _selected, properties, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::event(contents, state, cx, data, event);
+
+        match event.take_targeted(*id) {
+            Some(TabsEvent::Select(index)) => action | (properties.on_select)(data, index),
+            Some(TabsEvent::Close(index)) => action | (properties.on_close)(data, index),
+            None => action,
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _selected, _properties, contents, state): Self::State,
+        cx: &mut Context,
+    ) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -0,0 +1,181 @@
+use ori::{Action, Event, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, TaskHandle};
+
+/// Run the future returned by `spawn`, showing the contents built by `render` from its
+/// [`TaskState`].
+///
+/// The future is spawned when the view is built, and spawned again whenever `key` changes.
+/// Futures that are still running when the key changes, or the view is removed, are cancelled.
+pub fn task<K, F, Fut, U, E, R, V>(key: K, spawn: F, render: R) -> Task<K, F, R>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<U, E>>,
+    R: Fn(&TaskState<U, E>) -> V,
+{
+    Task::new(key, spawn, render)
+}
+
+/// The state of the future of a [`Task`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TaskState<U, E> {
+    /// The future is still running.
+    Loading,
+
+    /// The future resolved to `Ok`.
+    Ready(U),
+
+    /// The future resolved to `Err`.
+    Error(E),
+}
+
+impl<U, E> TaskState<U, E> {
+    pub fn is_loading(&self) -> bool {
+        matches!(self, TaskState::Loading)
+    }
+
+    pub fn ready(&self) -> Option<&U> {
+        match self {
+            TaskState::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            TaskState::Error(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+pub struct Task<K, F, R> {
+    key:    K,
+    spawn:  F,
+    render: R,
+}
+
+impl<K, F, R> Task<K, F, R> {
+    pub fn new(key: K, spawn: F, render: R) -> Self {
+        Self { key, spawn, render }
+    }
+}
+
+struct Finished<U, E> {
+    generation: u64,
+    result:     Result<U, E>,
+}
+
+fn spawn<Fut, U, E>(cx: &mut Context, id: ViewId, generation: u64, future: Fut) -> TaskHandle
+where
+    Fut: Future<Output = Result<U, E>> + Send + 'static,
+    U: Send + 'static,
+    E: Send + 'static,
+{
+    let proxy = cx.proxy();
+
+    cx.spawn_scoped(async move {
+        let result = future.await;
+        proxy.event(Event::new(
+            Finished { generation, result },
+            id,
+        ));
+    })
+}
+
+impl<K, F, R> ViewMarker for Task<K, F, R> {}
+impl<T, K, F, Fut, U, E, R, V> View<Context, T> for Task<K, F, R>
+where
+    K: PartialEq + 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<U, E>> + Send + 'static,
+    U: Send + 'static,
+    E: Send + 'static,
+    R: Fn(&TaskState<U, E>) -> V + 'static,
+    V: crate::View<T>,
+{
+    type Element = V::Element;
+    type State = (
+        ViewId,
+        u64,
+        K,
+        R,
+        TaskState<U, E>,
+        TaskHandle,
+        V::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let id = ViewId::next();
+        let handle = spawn(cx, id, 0, (self.spawn)());
+
+        let task = TaskState::Loading;
+        let (element, state) = (self.render)(&task).build(cx, data);
+
+        (
+            element,
+            (
+                id,
+                0,
+                self.key,
+                self.render,
+                task,
+                handle,
+                state,
+            ),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (id, generation, key, render, task, handle, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        if self.key != *key {
+            *generation += 1;
+            *key = self.key;
+            *task = TaskState::Loading;
+
+            // replacing the handle cancels the previous future
+            *handle = spawn(cx, *id, *generation, (self.spawn)());
+        }
+
+        *render = self.render;
+        render(task).rebuild(element, state, cx, data);
+    }
+
+    fn event(
+        element: Mut<Context, Self::Element>,
+        (id, generation, _key, render, task, _handle, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        match event.take_targeted::<Finished<U, E>>(*id) {
+            // results of a previous key may arrive before they were cancelled
+            Some(finished) if finished.generation == *generation => {
+                *task = match finished.result {
+                    Ok(value) => TaskState::Ready(value),
+                    Err(error) => TaskState::Error(error),
+                };
+
+                render(task).rebuild(element, state, cx, data);
+                Action::new()
+            }
+
+            Some(_) => Action::new(),
+            None => V::event(element, state, cx, data, event),
+        }
+    }
+
+    fn teardown(
+        element: Self::Element,
+        (_id, _generation, _key, _render, _task, handle, state): Self::State,
+        cx: &mut Context,
+    ) {
+        handle.cancel();
+        V::teardown(element, state, cx);
+    }
+}
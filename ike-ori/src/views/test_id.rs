@@ -0,0 +1,85 @@
+use ike_core::{AnyWidgetId, Builder};
+use ori::{Action, Event, Mut, View, ViewMarker};
+
+use crate::Context;
+
+/// Identify the widget of `contents` to tests and tooling as `test_id`, see
+/// [`World::find_by_test_id`](ike_core::World::find_by_test_id).
+pub fn test_id<V>(test_id: impl Into<String>, contents: V) -> TestId<V> {
+    TestId::new(test_id, contents)
+}
+
+/// Set the test id of the widget of a view, see [`test_id`].
+pub trait WithTestId: ViewMarker + Sized {
+    /// Identify the widget of the view to tests and tooling as `test_id`, e.g.
+    /// `button(label("Log in"), log_in).id("login-button")`.
+    fn id(self, test_id: impl Into<String>) -> TestId<Self> {
+        TestId::new(test_id, self)
+    }
+}
+
+impl<V> WithTestId for V where V: ViewMarker {}
+
+pub struct TestId<V> {
+    contents: V,
+    test_id:  String,
+}
+
+impl<V> TestId<V> {
+    pub fn new(test_id: impl Into<String>, contents: V) -> Self {
+        Self {
+            contents,
+            test_id: test_id.into(),
+        }
+    }
+}
+
+impl<V> ViewMarker for TestId<V> {}
+impl<T, V> View<Context, T> for TestId<V>
+where
+    V: crate::View<T>,
+{
+    type Element = V::Element;
+    type State = V::State;
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (element, state) = self.contents.build(cx, data);
+
+        if let Ok(mut widget) = cx.get_widget_mut(element.upcast()) {
+            widget.set_test_id(Some(self.test_id));
+        }
+
+        (element, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(element, state, cx, data);
+
+        // the contents may have replaced their widget
+        if let Ok(mut widget) = cx.get_widget_mut(element.upcast())
+            && widget.cx.test_id() != Some(self.test_id.as_str())
+        {
+            widget.set_test_id(Some(self.test_id));
+        }
+    }
+
+    fn event(
+        element: Mut<Context, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(element, state, cx, data, event)
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context) {
+        V::teardown(element, state, cx);
+    }
+}
@@ -0,0 +1,455 @@
+use std::{
+    collections::VecDeque,
+    mem,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use ike_core::{
+    Axis, BorderWidth, Builder, Color, CornerRadius, FontWeight, Padding, Paint, Paragraph,
+    TextStyle, Transition, WidgetId, widgets,
+};
+use ori::{Action, Event, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{Context, Palette, views::TextTheme};
+
+/// Show the toasts of `toaster` over `contents`.
+pub fn toasts<T, V>(toaster: &Toaster, contents: V) -> Toasts<T, V> {
+    Toasts::new(toaster, contents)
+}
+
+/// A transient message shown by [`Toasts`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    message:  String,
+    action:   Option<String>,
+    duration: Option<Option<Duration>>,
+}
+
+impl Toast {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message:  message.into(),
+            action:   None,
+            duration: None,
+        }
+    }
+
+    /// Add an action button to the toast, like `Undo`, see [`Toasts::on_action`].
+    pub fn action(mut self, label: impl Into<String>) -> Self {
+        self.action = Some(label.into());
+        self
+    }
+
+    /// Set how long the toast is shown for, the default is [`ToastTheme::duration`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(Some(duration));
+        self
+    }
+
+    /// Show the toast until it's dismissed with [`Toaster::dismiss`], or its action is pressed.
+    pub fn persistent(mut self) -> Self {
+        self.duration = Some(None);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId {
+    data: u64,
+}
+
+impl ToastId {
+    fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            data: NEXT.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+enum ToastRequest {
+    Show(ToastId, Toast),
+    Dismiss(ToastId),
+}
+
+/// A handle for showing toasts, usually kept in the app data.
+///
+/// Toasts are queued when shown, and picked up by the [`Toasts`] view of the toaster when it's
+/// next rebuilt.
+#[derive(Clone, Default)]
+pub struct Toaster {
+    requests: Arc<Mutex<Vec<ToastRequest>>>,
+}
+
+impl Toaster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `toast` to be shown.
+    pub fn show(&self, toast: Toast) -> ToastId {
+        let id = ToastId::next();
+        self.push(ToastRequest::Show(id, toast));
+        id
+    }
+
+    /// Dismiss the toast `id`, whether it's shown or still queued.
+    pub fn dismiss(&self, id: ToastId) {
+        self.push(ToastRequest::Dismiss(id));
+    }
+
+    fn push(&self, request: ToastRequest) {
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(request);
+        }
+    }
+
+    fn take(&self) -> Vec<ToastRequest> {
+        match self.requests.lock() {
+            Ok(mut requests) => mem::take(&mut *requests),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.requests, &other.requests)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastTheme {
+    pub max_visible:      usize,
+    pub duration:         Duration,
+    pub padding:          Padding,
+    pub corner_radius:    CornerRadius,
+    pub spacing:          f32,
+    pub margin:           f32,
+    pub font_size:        f32,
+    pub transition:       Transition,
+    pub background_color: Option<Color>,
+    pub color:            Option<Color>,
+    pub action_color:     Option<Color>,
+}
+
+impl Default for ToastTheme {
+    fn default() -> Self {
+        Self {
+            max_visible:      3,
+            duration:         Duration::from_secs(4),
+            padding:          Padding::from([16.0, 10.0]),
+            corner_radius:    CornerRadius::all(8.0),
+            spacing:          8.0,
+            margin:           16.0,
+            font_size:        14.0,
+            transition:       Transition::ease(0.2),
+            background_color: None,
+            color:            None,
+            action_color:     None,
+        }
+    }
+}
+
+impl ToastTheme {
+    fn get_background_color(&self, palette: &Palette) -> Color {
+        self.background_color.unwrap_or(palette.contrast)
+    }
+
+    fn get_color(&self, palette: &Palette) -> Color {
+        self.color.unwrap_or_else(|| palette.surface(0))
+    }
+
+    fn get_action_color(&self, palette: &Palette) -> Color {
+        self.action_color.unwrap_or(palette.primary)
+    }
+}
+
+pub struct Toasts<T, V> {
+    contents:    V,
+    toaster:     Toaster,
+    max_visible: Option<usize>,
+    #[allow(clippy::type_complexity)]
+    on_action:   Box<dyn FnMut(&mut T, ToastId) -> Action>,
+}
+
+impl<T, V> Toasts<T, V> {
+    pub fn new(toaster: &Toaster, contents: V) -> Self {
+        Self {
+            contents,
+            toaster: toaster.clone(),
+            max_visible: None,
+            on_action: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    /// Set the maximum number of toasts shown at once, the rest are queued.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = Some(max_visible);
+        self
+    }
+
+    /// Called when the action of a toast is pressed, the toast is then dismissed.
+    pub fn on_action<A>(mut self, mut on_action: impl FnMut(&mut T, ToastId) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_action = Box::new(move |data, id| on_action(data, id).into());
+        self
+    }
+}
+
+enum ToastEvent {
+    Action(ToastId),
+    Expired(WidgetId),
+}
+
+pub struct ToastsState<T> {
+    id:          ViewId,
+    toaster:     Toaster,
+    max_visible: Option<usize>,
+    #[allow(clippy::type_complexity)]
+    on_action:   Box<dyn FnMut(&mut T, ToastId) -> Action>,
+    shown:       Vec<(ToastId, WidgetId)>,
+    queued:      VecDeque<(ToastId, Toast)>,
+}
+
+impl<T> ToastsState<T> {
+    fn handle_requests(&mut self, cx: &mut Context, element: WidgetId<widgets::Toasts>) {
+        for request in self.toaster.take() {
+            match request {
+                ToastRequest::Show(id, toast) => {
+                    self.queued.push_back((id, toast));
+                }
+
+                ToastRequest::Dismiss(id) => {
+                    self.dismiss(cx, element, id);
+                }
+            }
+        }
+
+        self.show_queued(cx, element);
+    }
+
+    fn dismiss(&mut self, cx: &mut Context, element: WidgetId<widgets::Toasts>, id: ToastId) {
+        self.queued.retain(|(queued, _)| *queued != id);
+
+        if let Some(index) = self.shown.iter().position(|(shown, _)| *shown == id)
+            && let Ok(mut widget) = cx.get_widget_mut(element)
+        {
+            widgets::Toasts::dismiss(&mut widget, index);
+        }
+    }
+
+    fn show_queued(&mut self, cx: &mut Context, element: WidgetId<widgets::Toasts>) {
+        let theme = cx.get_or_default::<ToastTheme>();
+        let max_visible = self.max_visible.unwrap_or(theme.max_visible);
+
+        while self.shown.len() < max_visible
+            && let Some((id, toast)) = self.queued.pop_front()
+        {
+            let widget = self.build_toast(cx, &theme, id, &toast);
+            let index = self.shown.len();
+
+            cx.insert_child(element, index + 1, widget);
+            self.shown.push((id, widget));
+
+            if let Ok(mut widget) = cx.get_widget_mut(element) {
+                let duration = toast.duration.unwrap_or(Some(theme.duration));
+                widgets::Toasts::set_timeout(&mut widget, index, duration);
+            }
+        }
+    }
+
+    fn build_toast(
+        &self,
+        cx: &mut Context,
+        theme: &ToastTheme,
+        toast_id: ToastId,
+        toast: &Toast,
+    ) -> WidgetId {
+        let palette = cx.get_or_default::<Palette>();
+        let text_theme = cx.get_or_default::<TextTheme>();
+
+        let paragraph = |text: &str, color: Color, weight| {
+            let style = TextStyle {
+                font_size:    theme.font_size,
+                font_weight:  weight,
+                font_stretch: text_theme.font_stretch,
+                font_style:   text_theme.font_style,
+                font_family:  text_theme.font_family.clone().into_owned(),
+                paint:        Paint::from(color),
+            };
+
+            let mut paragraph = Paragraph::new(
+                text_theme.line_height,
+                text_theme.align,
+                text_theme.wrap,
+            );
+
+            paragraph.push(text, style);
+            paragraph
+        };
+
+        let message = paragraph(
+            &toast.message,
+            theme.get_color(&palette),
+            text_theme.font_weight,
+        );
+
+        let message = widgets::Label::new(cx, message).id();
+
+        let mut stack = widgets::Stack::new(cx);
+        widgets::Stack::set_axis(&mut stack, Axis::Horizontal);
+        widgets::Stack::set_gap(&mut stack, theme.padding.left);
+        let stack = stack.id();
+
+        cx.add_child(stack, message);
+
+        if let Some(ref action) = toast.action {
+            let label = paragraph(
+                action,
+                theme.get_action_color(&palette),
+                FontWeight::SEMI_BOLD,
+            );
+
+            let label = widgets::Label::new(cx, label).id();
+            let background = theme.get_background_color(&palette);
+
+            let mut button = widgets::Button::new(cx, label);
+            widgets::Button::set_padding(&mut button, Padding::all(4.0));
+            widgets::Button::set_border_width(&mut button, BorderWidth::all(0.0));
+            widgets::Button::set_idle_color(&mut button, background);
+            widgets::Button::set_hovered_color(&mut button, background.lighten(0.08));
+            widgets::Button::set_active_color(&mut button, background.lighten(0.04));
+            widgets::Button::set_corner_radius(&mut button, CornerRadius::all(4.0));
+
+            let proxy = cx.proxy();
+            let id = self.id;
+
+            widgets::Button::set_on_click(&mut button, move || {
+                proxy.event(Event::new(
+                    ToastEvent::Action(toast_id),
+                    id,
+                ));
+            });
+
+            let button = button.id();
+            cx.add_child(stack, button);
+        }
+
+        let mut container = widgets::Container::new(cx, stack);
+        widgets::Container::set_padding(&mut container, theme.padding);
+        widgets::Container::set_border_width(&mut container, BorderWidth::all(0.0));
+        widgets::Container::set_corner_radius(&mut container, theme.corner_radius);
+
+        widgets::Container::set_background_color(
+            &mut container,
+            theme.get_background_color(&palette),
+        );
+
+        container.id().upcast()
+    }
+}
+
+impl<T, V> ViewMarker for Toasts<T, V> {}
+impl<T, V> View<Context, T> for Toasts<T, V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Toasts>;
+    type State = (ToastsState<T>, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let theme = cx.get_or_default::<ToastTheme>();
+        let proxy = cx.proxy();
+        let id = ViewId::next();
+
+        let (contents, state) = self.contents.build(cx, data);
+
+        let element = {
+            let mut widget = widgets::Toasts::new(cx, contents);
+
+            widgets::Toasts::set_spacing(&mut widget, theme.spacing);
+            widgets::Toasts::set_margin(&mut widget, theme.margin);
+            widgets::Toasts::set_transition(&mut widget, theme.transition);
+
+            widgets::Toasts::set_on_expire(&mut widget, move |toast| {
+                proxy.event(Event::new(
+                    ToastEvent::Expired(toast),
+                    id,
+                ));
+            });
+
+            widget.id()
+        };
+
+        let mut toasts = ToastsState {
+            id,
+            toaster: self.toaster,
+            max_visible: self.max_visible,
+            on_action: self.on_action,
+            shown: Vec::new(),
+            queued: VecDeque::new(),
+        };
+
+        toasts.handle_requests(cx, element);
+
+        (element, (toasts, contents, state))
+    }
+
+    fn rebuild(
+        self,
+        element: &mut Self::Element,
+        (toasts, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(contents, state, cx, data);
+
+        if !self.toaster.is_same(&toasts.toaster) {
+            toasts.queued.clear();
+        }
+
+        toasts.toaster = self.toaster;
+        toasts.max_visible = self.max_visible;
+        toasts.on_action = self.on_action;
+
+        toasts.handle_requests(cx, *element);
+    }
+
+    fn event(
+        element: &mut Self::Element,
+        (toasts, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = V::event(contents, state, cx, data, event);
+
+        match event.take_targeted(toasts.id) {
+            Some(ToastEvent::Action(id)) => {
+                toasts.dismiss(cx, *element, id);
+                action | (toasts.on_action)(data, id)
+            }
+
+            Some(ToastEvent::Expired(widget)) => {
+                toasts.shown.retain(|(_, shown)| *shown != widget);
+                cx.remove_widget(widget);
+
+                toasts.show_queued(cx, *element);
+                action
+            }
+
+            None => action,
+        }
+    }
+
+    fn teardown(element: Self::Element, (_toasts, contents, state): Self::State, cx: &mut Context) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
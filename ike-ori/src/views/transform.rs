@@ -1,4 +1,4 @@
-use ike_core::{Builder, Offset, Transition, WidgetId, widgets};
+use ike_core::{Affine, Builder, Offset, Transition, WidgetId, widgets};
 use ori::{Action, Event, Mut, View, ViewMarker};
 
 use crate::Context;
@@ -21,6 +21,7 @@ impl<V> Transform<V> {
                 rotation:    0.0,
                 scale_x:     1.0,
                 scale_y:     1.0,
+                affine:      Affine::IDENTITY,
 
                 translation_transition: Transition::INSTANT,
                 rotation_transition:    Transition::INSTANT,
@@ -45,6 +46,11 @@ impl<V> Transform<V> {
         self
     }
 
+    pub fn affine(mut self, affine: Affine) -> Self {
+        self.properties.affine = affine;
+        self
+    }
+
     pub fn translation_transition(mut self, transition: Transition) -> Self {
         self.properties.translation_transition = transition;
         self
@@ -72,6 +78,7 @@ pub struct Properties {
     rotation:    f32,
     scale_x:     f32,
     scale_y:     f32,
+    affine:      Affine,
 
     translation_transition: Transition,
     rotation_transition:    Transition,
@@ -98,6 +105,7 @@ where
             self.properties.scale_x,
             self.properties.scale_y,
         );
+        widgets::Transform::set_affine(&mut widget, self.properties.affine);
 
         widgets::Transform::set_translation_transition(
             &mut widget,
@@ -149,6 +157,10 @@ where
             );
         }
 
+        if self.properties.affine != properties.affine {
+            widgets::Transform::set_affine(&mut widget, self.properties.affine);
+        }
+
         widgets::Transform::set_translation_transition(
             &mut widget,
             self.properties.translation_transition,
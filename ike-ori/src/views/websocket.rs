@@ -0,0 +1,260 @@
+use std::{sync::Arc, time::Duration};
+
+use futures_util::StreamExt;
+use ori::{Action, Event, NoElement, Proxied, Proxy, View, ViewId, ViewMarker};
+use serde::de::DeserializeOwned;
+use tokio_tungstenite::tungstenite::{self, Message};
+
+use crate::{
+    Context,
+    cancel::{Cancel, Cancellable},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebSocketError {
+    #[error("websocket error: {0}")]
+    Connection(#[from] tungstenite::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Something that happened to the connection of a [`WebSocket`].
+#[derive(Debug)]
+pub enum WebSocketEvent<U> {
+    /// The connection was opened, either for the first time or after reconnecting.
+    Open,
+
+    /// A text or binary message was received and deserialized as JSON.
+    Message(U),
+
+    /// Connecting failed, the connection broke, or a message couldn't be deserialized.
+    Error(WebSocketError),
+
+    /// The connection was closed, a new connection is attempted after the backoff.
+    Closed,
+}
+
+/// Connect to the WebSocket at `url`, calling `on_event` for each message received.
+///
+/// The connection is opened when the view is built, and closed when the view is removed.
+/// Lost connections are reopened with exponential backoff, see [`WebSocket::backoff`].
+pub fn websocket<T, U, A>(
+    url: impl Into<String>,
+    on_event: impl FnMut(&mut T, WebSocketEvent<U>) -> A + 'static,
+) -> WebSocket<T, U>
+where
+    A: Into<Action>,
+{
+    WebSocket::new(url, on_event)
+}
+
+pub struct WebSocket<T, U> {
+    url:        String,
+    properties: Properties<T, U>,
+}
+
+impl<T, U> WebSocket<T, U> {
+    pub fn new<A>(
+        url: impl Into<String>,
+        mut on_event: impl FnMut(&mut T, WebSocketEvent<U>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            url:        url.into(),
+            properties: Properties {
+                on_event: Box::new(move |data, event| on_event(data, event).into()),
+                backoff:  Backoff {
+                    min: Duration::from_millis(500),
+                    max: Duration::from_secs(30),
+                },
+            },
+        }
+    }
+
+    /// Wait `min` before reconnecting, doubling the wait after every failed attempt up to `max`.
+    ///
+    /// Defaults to waiting between `500 ms` and `30 s`.
+    pub fn backoff(mut self, min: Duration, max: Duration) -> Self {
+        self.properties.backoff = Backoff { min, max };
+        self
+    }
+}
+
+pub struct Properties<T, U> {
+    #[allow(clippy::type_complexity)]
+    on_event: Box<dyn FnMut(&mut T, WebSocketEvent<U>) -> Action>,
+    backoff:  Backoff,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Backoff {
+    min: Duration,
+    max: Duration,
+}
+
+enum Incoming {
+    Open,
+    Message(Vec<u8>),
+    Error(tungstenite::Error),
+    Closed,
+}
+
+struct Received {
+    url:      String,
+    incoming: Incoming,
+}
+
+fn connect(cx: &mut Context, id: ViewId, url: String, backoff: Backoff) -> Arc<Cancel> {
+    let cancel = Arc::new(Cancel::default());
+    let proxy = cx.proxy();
+
+    let connection = {
+        let proxy = proxy.cloned();
+
+        async move {
+            let send = |incoming| {
+                let url = url.clone();
+                proxy.event(Event::new(
+                    Received { url, incoming },
+                    id,
+                ));
+            };
+
+            let mut delay = backoff.min;
+
+            loop {
+                match tokio_tungstenite::connect_async(url.as_str()).await {
+                    Ok((mut stream, _)) => {
+                        delay = backoff.min;
+                        send(Incoming::Open);
+
+                        while let Some(message) = stream.next().await {
+                            match message {
+                                Ok(Message::Text(text)) => {
+                                    send(Incoming::Message(
+                                        text.as_bytes().to_vec(),
+                                    ));
+                                }
+
+                                Ok(Message::Binary(bytes)) => {
+                                    send(Incoming::Message(bytes.to_vec()));
+                                }
+
+                                Ok(Message::Close(_)) => break,
+                                Ok(_) => {}
+
+                                Err(err) => {
+                                    send(Incoming::Error(err));
+                                    break;
+                                }
+                            }
+                        }
+
+                        send(Incoming::Closed);
+                    }
+
+                    Err(err) => send(Incoming::Error(err)),
+                }
+
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(backoff.max);
+            }
+        }
+    };
+
+    // dropping the connection when cancelled closes the socket
+    let future = Cancellable::new(connection, cancel.clone());
+
+    proxy.spawn_boxed(Box::pin(async move {
+        future.await;
+    }));
+
+    cancel
+}
+
+impl<T, U> ViewMarker for WebSocket<T, U> {}
+impl<T, U> View<Context, T> for WebSocket<T, U>
+where
+    U: DeserializeOwned,
+{
+    type Element = NoElement;
+    type State = (
+        ViewId,
+        String,
+        Properties<T, U>,
+        Arc<Cancel>,
+    );
+
+    fn build(self, cx: &mut Context, _data: &mut T) -> (Self::Element, Self::State) {
+        let id = ViewId::next();
+        let cancel = connect(
+            cx,
+            id,
+            self.url.clone(),
+            self.properties.backoff,
+        );
+
+        (
+            NoElement,
+            (id, self.url, self.properties, cancel),
+        )
+    }
+
+    fn rebuild(
+        self,
+        _element: (),
+        (id, url, properties, cancel): &mut Self::State,
+        cx: &mut Context,
+        _data: &mut T,
+    ) {
+        if self.url != *url || self.properties.backoff != properties.backoff {
+            cancel.cancel();
+            *cancel = connect(
+                cx,
+                *id,
+                self.url.clone(),
+                self.properties.backoff,
+            );
+        }
+
+        *url = self.url;
+        *properties = self.properties;
+    }
+
+    fn event(
+        _element: (),
+        (id, url, properties, _cancel): &mut Self::State,
+        _cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        match event.take_targeted(*id) {
+            // messages from a previous url may arrive before it was closed
+            Some(Received {
+                url: received,
+                incoming,
+            }) if received == *url => {
+                let event = match incoming {
+                    Incoming::Open => WebSocketEvent::Open,
+                    Incoming::Message(bytes) => match serde_json::from_slice(&bytes) {
+                        Ok(message) => WebSocketEvent::Message(message),
+                        Err(err) => WebSocketEvent::Error(WebSocketError::from(err)),
+                    },
+                    Incoming::Error(err) => WebSocketEvent::Error(WebSocketError::from(err)),
+                    Incoming::Closed => WebSocketEvent::Closed,
+                };
+
+                (properties.on_event)(data, event)
+            }
+
+            _ => Action::new(),
+        }
+    }
+
+    fn teardown(_element: NoElement, (_, _, _, cancel): Self::State, _cx: &mut Context) {
+        cancel.cancel();
+    }
+}
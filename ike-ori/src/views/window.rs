@@ -1,10 +1,11 @@
 use ike_core::{
     AnyWidgetId, Builder, Color, Key, KeyEvent, KeyPressEvent, Modifiers, PointerButton,
-    PointerButtonEvent, PointerEvent, Size, WindowId, WindowSizing,
+    PointerButtonEvent, PointerEvent, PopupAnchor, Presentation, Size, WindowId, WindowLevel,
+    WindowSizing,
 };
 use ori::{Action, Event, NoElement, Provider, Proxied, Proxy, View, ViewId, ViewMarker};
 
-use crate::{Context, Palette};
+use crate::{Context, Palette, is_retheming, theme_transition};
 
 pub fn window<V, T>(contents: V) -> Window<V, T> {
     Window::new(contents)
@@ -35,7 +36,17 @@ impl<V, T> Window<V, T> {
                 },
                 visible:        true,
                 decorated:      true,
+                redacted:       false,
+                opacity:        1.0,
+                level:          WindowLevel::Normal,
+                skip_taskbar:   false,
+                blur:           false,
+                modal:          false,
+                popup:          None,
                 color:          None,
+                scale:          None,
+                presentation:   Presentation::default(),
+                persist:        None,
                 key_filter:     None,
                 pointer_filter: None,
                 on_key:         None,
@@ -56,6 +67,13 @@ impl<V, T> Window<V, T> {
         self
     }
 
+    /// Draw the window with `scale` pixels per unit, instead of the scale factor of the
+    /// platform, e.g. to let users scale the interface independently of the display.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.properties.scale = Some(scale);
+        self
+    }
+
     pub fn sizing(mut self, sizing: WindowSizing) -> Self {
         self.properties.sizing = sizing;
         self
@@ -86,6 +104,91 @@ impl<V, T> Window<V, T> {
         self
     }
 
+    /// Replace content marked with [`redact`](crate::views::redact) by placeholders in this
+    /// window, e.g. while it's shared.
+    pub fn redacted(mut self, redacted: bool) -> Self {
+        self.properties.redacted = redacted;
+        self
+    }
+
+    /// Set the opacity of the window, applied to both its color and its contents.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.properties.opacity = opacity;
+        self
+    }
+
+    /// Set where the window is stacked relative to the windows of other applications.
+    pub fn level(mut self, level: WindowLevel) -> Self {
+        self.properties.level = level;
+        self
+    }
+
+    /// Keep the window above all other windows, e.g. for utility palettes and overlays.
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.properties.level = match always_on_top {
+            true => WindowLevel::AlwaysOnTop,
+            false => WindowLevel::Normal,
+        };
+
+        self
+    }
+
+    /// Hide the window from the taskbar, only supported on windows.
+    pub fn skip_taskbar(mut self, skip_taskbar: bool) -> Self {
+        self.properties.skip_taskbar = skip_taskbar;
+        self
+    }
+
+    /// Blur what is behind the window, where the platform supports it.
+    ///
+    /// Only visible where the window [`color`](Self::color) or [`opacity`](Self::opacity) is
+    /// translucent.
+    pub fn blur(mut self, blur: bool) -> Self {
+        self.properties.blur = blur;
+        self
+    }
+
+    /// Block input to the parent window while this window is visible, e.g. for dialogs and
+    /// preference windows, see [`Window::children`].
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.properties.modal = modal;
+        self
+    }
+
+    /// Make the window a popup placed next to `anchor` in its parent, e.g. a menu or tooltip
+    /// that isn't clipped by the parent, see [`Window::children`].
+    ///
+    /// Popups are flipped and slid to stay on the monitor, and are shown without decorations.
+    pub fn popup(mut self, anchor: PopupAnchor) -> Self {
+        self.properties.popup = Some(anchor);
+        self
+    }
+
+    /// Add `children` owned by this window, which are kept above it, centered on it when
+    /// created, and closed with it.
+    pub fn children<C>(self, children: C) -> WithChildren<V, T, C> {
+        WithChildren {
+            window: self,
+            children,
+        }
+    }
+
+    /// Set how frames are buffered, trading smoothness for latency, see [`Presentation`].
+    pub fn presentation(mut self, presentation: Presentation) -> Self {
+        self.properties.presentation = presentation;
+        self
+    }
+
+    /// Remember the size, position and maximized state of the window across runs as `name`, in
+    /// the provided [`Preferences`](crate::persist::Preferences).
+    ///
+    /// The remembered size replaces the default size of [`WindowSizing::Resizable`] windows.
+    #[cfg(feature = "persist")]
+    pub fn persist(mut self, name: impl Into<String>) -> Self {
+        self.properties.persist = Some(name.into());
+        self
+    }
+
     /// Register an [`Action`] callback for when a `key` is pressed with `modifiers` held.
     ///
     /// This is useful for registering keyboard shortcuts. Multiple keys can be set for one window.
@@ -167,7 +270,18 @@ pub struct Properties<T> {
     sizing:         WindowSizing,
     visible:        bool,
     decorated:      bool,
+    redacted:       bool,
+    opacity:        f32,
+    level:          WindowLevel,
+    skip_taskbar:   bool,
+    blur:           bool,
+    modal:          bool,
+    popup:          Option<PopupAnchor>,
     color:          Option<Color>,
+    scale:          Option<f32>,
+    presentation:   Presentation,
+    #[cfg_attr(not(feature = "persist"), allow(dead_code))]
+    persist:        Option<String>,
     key_filter:     Option<KeyFilter>,
     pointer_filter: Option<PointerFilter>,
     on_key:         Option<OnKeyEvent<T>>,
@@ -241,9 +355,75 @@ impl<T> Properties<T> {
     }
 }
 
+#[cfg(feature = "persist")]
+impl<T> Properties<T> {
+    /// Apply the geometry remembered for the window, and remember it whenever it changes.
+    fn restore_geometry(&mut self, cx: &mut Context, window_id: WindowId, view_id: ViewId) {
+        let Some(ref name) = self.persist else {
+            return;
+        };
+
+        let geometry = (cx.preferences()).and_then(|p| p.window_geometry(name));
+
+        if let Some(geometry) = geometry {
+            if let WindowSizing::Resizable {
+                ref mut default_size,
+                min_size,
+                max_size,
+            } = self.sizing
+            {
+                *default_size = geometry.size.max(min_size).min(max_size);
+            }
+
+            if let Some(position) = geometry.position {
+                cx.set_window_position(window_id, position);
+            }
+
+            if geometry.is_maximized {
+                cx.set_window_maximized(window_id, true);
+            }
+        }
+
+        let proxy = cx.proxy();
+        if let Some(window) = cx.world_mut().get_window_mut(window_id) {
+            window.set_on_geometry(Box::new(move |geometry| {
+                proxy.event(Event::new(
+                    WindowEvent::Geometry(*geometry),
+                    view_id,
+                ));
+            }));
+        }
+    }
+
+    fn remember_geometry(&self, cx: &mut Context, geometry: ike_core::WindowGeometry) {
+        let Some(ref name) = self.persist else {
+            return;
+        };
+
+        let Some(preferences) = cx.preferences() else {
+            return;
+        };
+
+        // maximized windows have the size of the monitor, keep the geometry to restore them to
+        let geometry = match preferences.window_geometry(name) {
+            Some(restored) if geometry.is_maximized => ike_core::WindowGeometry {
+                is_maximized: true,
+                ..restored
+            },
+
+            _ => geometry,
+        };
+
+        preferences.set_window_geometry(&*cx.proxy, name, geometry);
+    }
+}
+
 enum WindowEvent {
     Key(KeyEvent),
     Pointer(PointerEvent),
+
+    #[cfg(feature = "persist")]
+    Geometry(ike_core::WindowGeometry),
 }
 
 impl<V, T> ViewMarker for Window<V, T> {}
@@ -267,14 +447,31 @@ where
         let window_id = cx.world_mut().create_window(contents.upcast());
 
         let color = self.properties.color.unwrap_or(palette.background);
+        let transition = theme_transition(cx);
+        let view_id = ViewId::next();
+
+        #[cfg(feature = "persist")]
+        self.properties.restore_geometry(cx, window_id, view_id);
 
         cx.set_window_title(window_id, self.properties.title.clone());
         cx.set_window_sizing(window_id, self.properties.sizing);
         cx.set_window_visible(window_id, self.properties.visible);
         cx.set_window_decorated(window_id, self.properties.decorated);
+        cx.set_window_redacted(window_id, self.properties.redacted);
+        cx.set_window_opacity(window_id, self.properties.opacity);
+        cx.set_window_level(window_id, self.properties.level);
+        cx.set_window_skip_taskbar(window_id, self.properties.skip_taskbar);
+        cx.set_window_blurred(window_id, self.properties.blur);
+        cx.set_window_modal(window_id, self.properties.modal);
+
+        let parent = cx.get::<ParentWindow>().map(|parent| parent.0);
+        cx.set_window_parent(window_id, parent);
+        cx.set_window_popup(window_id, self.properties.popup);
         cx.set_window_color(window_id, color);
+        cx.set_window_color_transition(window_id, transition);
+        cx.set_window_presentation(window_id, self.properties.presentation);
+        cx.set_window_scale(window_id, self.properties.scale);
 
-        let view_id = ViewId::next();
         self.properties.register_on_key(cx, window_id, view_id);
 
         (
@@ -300,6 +497,7 @@ where
         self.properties.register_on_key(cx, *window_id, *view_id);
 
         let palette = cx.get_or_default::<Palette>();
+        let retheme = is_retheming(cx);
 
         if let Some(window) = cx.world().get_window(*window_id)
             && let Some(layer) = window.layers().first()
@@ -327,7 +525,48 @@ where
             cx.set_window_decorated(*window_id, self.properties.decorated);
         }
 
-        if self.properties.color != properties.color {
+        if self.properties.redacted != properties.redacted {
+            cx.set_window_redacted(*window_id, self.properties.redacted);
+        }
+
+        if self.properties.opacity != properties.opacity {
+            cx.set_window_opacity(*window_id, self.properties.opacity);
+        }
+
+        if self.properties.level != properties.level {
+            cx.set_window_level(*window_id, self.properties.level);
+        }
+
+        if self.properties.skip_taskbar != properties.skip_taskbar {
+            cx.set_window_skip_taskbar(*window_id, self.properties.skip_taskbar);
+        }
+
+        if self.properties.blur != properties.blur {
+            cx.set_window_blurred(*window_id, self.properties.blur);
+        }
+
+        if self.properties.modal != properties.modal {
+            cx.set_window_modal(*window_id, self.properties.modal);
+        }
+
+        if self.properties.popup != properties.popup {
+            cx.set_window_popup(*window_id, self.properties.popup);
+        }
+
+        if self.properties.presentation != properties.presentation {
+            cx.set_window_presentation(*window_id, self.properties.presentation);
+        }
+
+        if self.properties.scale != properties.scale {
+            cx.set_window_scale(*window_id, self.properties.scale);
+        }
+
+        if retheme {
+            let transition = theme_transition(cx);
+            cx.set_window_color_transition(*window_id, transition);
+        }
+
+        if retheme || self.properties.color != properties.color {
             let color = self.properties.color.unwrap_or(palette.background);
             cx.set_window_color(*window_id, color);
         }
@@ -374,6 +613,12 @@ where
                 };
             }
 
+            #[cfg(feature = "persist")]
+            Some(WindowEvent::Geometry(geometry)) => {
+                properties.remember_geometry(cx, geometry);
+                return Action::new();
+            }
+
             _ => {}
         }
 
@@ -398,3 +643,65 @@ where
         cx.world_mut().remove_window(window);
     }
 }
+
+/// The window the windows being built are children of.
+struct ParentWindow(WindowId);
+
+/// A [`Window`] with child windows, see [`Window::children`].
+pub struct WithChildren<V, T, C> {
+    window:   Window<V, T>,
+    children: C,
+}
+
+impl<V, T, C> ViewMarker for WithChildren<V, T, C> {}
+impl<T, V, C> View<Context, T> for WithChildren<V, T, C>
+where
+    V: crate::View<T>,
+    C: View<Context, T, Element = NoElement>,
+{
+    type Element = NoElement;
+    type State = (
+        <Window<V, T> as View<Context, T>>::State,
+        C::State,
+    );
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (_, window) = self.window.build(cx, data);
+
+        cx.push(Box::new(ParentWindow(window.1)));
+        let (_, children) = self.children.build(cx, data);
+        cx.pop::<ParentWindow>();
+
+        (NoElement, (window, children))
+    }
+
+    fn rebuild(
+        self,
+        _element: (),
+        (window, children): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.window.rebuild((), window, cx, data);
+
+        cx.push(Box::new(ParentWindow(window.1)));
+        self.children.rebuild((), children, cx, data);
+        cx.pop::<ParentWindow>();
+    }
+
+    fn event(
+        _element: (),
+        (window, children): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        let action = Window::<V, T>::event((), window, cx, data, event);
+        action | C::event((), children, cx, data, event)
+    }
+
+    fn teardown(_element: NoElement, (window, children): Self::State, cx: &mut Context) {
+        C::teardown(NoElement, children, cx);
+        Window::<V, T>::teardown(NoElement, window, cx);
+    }
+}
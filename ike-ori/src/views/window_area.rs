@@ -0,0 +1,83 @@
+use ike_core::{
+    Builder, WidgetId,
+    widgets::{self, WindowRegion},
+};
+use ori::{Action, Event, Mut, View, ViewMarker};
+
+use crate::Context;
+
+/// Mark `contents` as the titlebar of a window without decorations, pressing it moves the
+/// window and double clicking it toggles the window maximized.
+pub fn window_drag_area<V>(contents: V) -> WindowArea<V> {
+    WindowArea::new(WindowRegion::Caption, contents)
+}
+
+/// Mark `contents` as a `region` of a custom window frame, e.g. a resize edge or the close
+/// button of a titlebar.
+pub fn window_area<V>(region: WindowRegion, contents: V) -> WindowArea<V> {
+    WindowArea::new(region, contents)
+}
+
+pub struct WindowArea<V> {
+    contents: V,
+    region:   WindowRegion,
+}
+
+impl<V> WindowArea<V> {
+    pub fn new(region: WindowRegion, contents: V) -> Self {
+        Self { contents, region }
+    }
+}
+
+impl<V> ViewMarker for WindowArea<V> {}
+impl<T, V> View<Context, T> for WindowArea<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::WindowArea>;
+    type State = (WindowRegion, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let widget = widgets::WindowArea::new(cx, self.region, contents);
+
+        (
+            widget.id(),
+            (self.region, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (region, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        if self.region != *region {
+            *region = self.region;
+
+            if let Ok(mut widget) = cx.get_widget_mut(*element) {
+                widgets::WindowArea::set_region(&mut widget, self.region);
+            }
+        }
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_region, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(element: Self::Element, (_region, contents, state): Self::State, cx: &mut Context) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -0,0 +1,76 @@
+use ike_core::{Builder, WidgetId, widgets};
+use ori::{Action, Event, Mut, View, ViewMarker};
+
+use crate::Context;
+
+/// Magnify `contents` by `zoom`, laying them out in a correspondingly smaller space.
+///
+/// Text is laid out and drawn at the zoomed size, rather than the contents being scaled as a
+/// bitmap, e.g. for accessibility zoom or previewing a design at another size.
+pub fn zoom<V>(zoom: f32, contents: V) -> Zoom<V> {
+    Zoom::new(zoom, contents)
+}
+
+pub struct Zoom<V> {
+    contents: V,
+    zoom:     f32,
+}
+
+impl<V> Zoom<V> {
+    pub fn new(zoom: f32, contents: V) -> Self {
+        Self { contents, zoom }
+    }
+}
+
+impl<V> ViewMarker for Zoom<V> {}
+impl<T, V> View<Context, T> for Zoom<V>
+where
+    V: crate::View<T>,
+{
+    type Element = WidgetId<widgets::Zoom>;
+    type State = (f32, V::Element, V::State);
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = widgets::Zoom::new(cx, contents);
+        widgets::Zoom::set_zoom(&mut widget, self.zoom);
+
+        (
+            widget.id(),
+            (self.zoom, contents, state),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        (zoom, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        self.contents.rebuild(contents, state, cx, data);
+
+        if self.zoom != *zoom
+            && let Ok(mut widget) = cx.get_widget_mut(*element)
+        {
+            *zoom = self.zoom;
+            widgets::Zoom::set_zoom(&mut widget, self.zoom);
+        }
+    }
+
+    fn event(
+        _element: Mut<Context, Self::Element>,
+        (_zoom, contents, state): &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(contents, state, cx, data, event)
+    }
+
+    fn teardown(element: Self::Element, (_zoom, contents, state): Self::State, cx: &mut Context) {
+        V::teardown(contents, state, cx);
+        cx.remove_widget(element);
+    }
+}
@@ -1,12 +1,22 @@
+use std::mem;
+
 use ike_core::{Builder, WidgetId, widgets};
-use ori::{Action, Elements, Event, Mut, View, ViewMarker, ViewSeq};
+use ori::{Action, Element, Elements, Event, Mut, Super, View, ViewMarker, ViewSeq};
 
 use crate::Context;
 
+/// Stack `contents` on top of each other, the last on top.
+///
+/// Use [`layered`] to align a child within the stack, or to let pointer events pass through it.
 pub fn zstack<V>(contents: V) -> ZStack<V> {
     ZStack::new(contents)
 }
 
+/// Wrap `contents` in a [`Layered`], to be placed in a [`ZStack`].
+pub fn layered<V>(contents: V) -> Layered<V> {
+    Layered::new(contents)
+}
+
 pub struct ZStack<V> {
     contents: V,
 }
@@ -20,10 +30,10 @@ impl<V> ZStack<V> {
 impl<V> ViewMarker for ZStack<V> {}
 impl<T, V> View<Context, T> for ZStack<V>
 where
-    V: ViewSeq<Context, T, WidgetId>,
+    V: ViewSeq<Context, T, Layered<WidgetId>>,
 {
     type Element = WidgetId<widgets::ZStack>;
-    type State = (Vec<WidgetId>, V::State);
+    type State = (Vec<Layered<WidgetId>>, V::State);
 
     fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
         let element = widgets::ZStack::new(cx).id();
@@ -78,36 +88,145 @@ where
     }
 }
 
+/// A child of a [`ZStack`], with an optional alignment and hit-test pass-through.
+#[derive(Clone, Copy)]
+pub struct Layered<V> {
+    contents:     V,
+    alignment:    Option<(f32, f32)>,
+    pass_through: bool,
+}
+
+impl<V> Layered<V> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            alignment: None,
+            pass_through: false,
+        }
+    }
+
+    /// Align the child within the stack, where `(0.0, 0.0)` is the top left corner and
+    /// `(1.0, 1.0)` is the bottom right.
+    ///
+    /// Aligned children are laid out loosely, instead of filling the stack.
+    pub fn align(mut self, x: f32, y: f32) -> Self {
+        self.alignment = Some((x, y));
+        self
+    }
+
+    /// Let pointer events pass through the child, to the children below it.
+    pub fn pass_through(mut self, pass_through: bool) -> Self {
+        self.pass_through = pass_through;
+        self
+    }
+}
+
+impl<V> ViewMarker for Layered<V> {}
+impl<T, V> View<Context, T> for Layered<V>
+where
+    V: crate::View<T>,
+{
+    type Element = Layered<V::Element>;
+    type State = V::State;
+
+    fn build(self, cx: &mut Context, data: &mut T) -> (Self::Element, Self::State) {
+        let (element, state) = self.contents.build(cx, data);
+        let element = Layered {
+            contents:     element,
+            alignment:    self.alignment,
+            pass_through: self.pass_through,
+        };
+
+        (element, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<Context, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+    ) {
+        (self.contents).rebuild(&mut element.contents, state, cx, data);
+        element.alignment = self.alignment;
+        element.pass_through = self.pass_through;
+    }
+
+    fn event(
+        element: Mut<Context, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context,
+        data: &mut T,
+        event: &mut Event,
+    ) -> Action {
+        V::event(
+            &mut element.contents,
+            state,
+            cx,
+            data,
+            event,
+        )
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context) {
+        V::teardown(element.contents, state, cx);
+    }
+}
+
 struct ZStackElements<'a> {
     widget:   WidgetId<widgets::ZStack>,
-    elements: &'a mut Vec<WidgetId>,
+    elements: &'a mut Vec<Layered<WidgetId>>,
     index:    usize,
 }
 
 impl<'a> ZStackElements<'a> {
-    fn new(widget: WidgetId<widgets::ZStack>, elements: &'a mut Vec<WidgetId>) -> Self {
+    fn new(widget: WidgetId<widgets::ZStack>, elements: &'a mut Vec<Layered<WidgetId>>) -> Self {
         Self {
             widget,
             elements,
             index: 0,
         }
     }
+
+    fn set_layer(&self, cx: &mut Context, alignment: Option<(f32, f32)>, pass_through: bool) {
+        if let Ok(mut widget) = cx.get_widget_mut(self.widget) {
+            widgets::ZStack::set_alignment(&mut widget, self.index, alignment);
+            widgets::ZStack::set_pass_through(&mut widget, self.index, pass_through);
+        }
+    }
 }
 
-impl<'a> Elements<Context, WidgetId> for ZStackElements<'a> {
-    fn next(&mut self, _cx: &mut Context) -> Option<&mut WidgetId> {
+impl<'a> Elements<Context, Layered<WidgetId>> for ZStackElements<'a> {
+    fn next(&mut self, cx: &mut Context) -> Option<&mut Layered<WidgetId>> {
+        let element = self.elements.get(self.index)?;
+        self.set_layer(
+            cx,
+            element.alignment,
+            element.pass_through,
+        );
+
         let element = self.elements.get_mut(self.index)?;
         self.index += 1;
         Some(element)
     }
 
-    fn insert(&mut self, cx: &mut Context, element: WidgetId) {
-        cx.insert_child(self.widget, self.index, element);
+    fn insert(&mut self, cx: &mut Context, element: Layered<WidgetId>) {
+        cx.insert_child(
+            self.widget,
+            self.index,
+            element.contents,
+        );
+        self.set_layer(
+            cx,
+            element.alignment,
+            element.pass_through,
+        );
+
         self.elements.insert(self.index, element);
         self.index += 1;
     }
 
-    fn remove(&mut self, cx: &mut Context) -> Option<WidgetId> {
+    fn remove(&mut self, cx: &mut Context) -> Option<Layered<WidgetId>> {
         cx.remove_child(self.widget, self.index);
         Some(self.elements.remove(self.index))
     }
@@ -121,3 +240,77 @@ impl<'a> Elements<Context, WidgetId> for ZStackElements<'a> {
         );
     }
 }
+
+impl<V> Element<Context> for Layered<V> {
+    type Mut<'a>
+        = &'a mut Self
+    where
+        V: 'a;
+}
+
+impl<T> Super<Context, WidgetId<T>> for Layered<WidgetId>
+where
+    T: ?Sized,
+{
+    fn replace(cx: &mut Context, this: &mut Self, other: WidgetId<T>) -> Self {
+        cx.replace_widget(this.contents, other);
+        this.contents = other.upcast();
+        this.alignment = None;
+        this.pass_through = false;
+        *this
+    }
+
+    fn upcast(_cx: &mut Context, sub: WidgetId<T>) -> Self {
+        Layered::new(sub.upcast())
+    }
+
+    fn downcast(self) -> WidgetId<T> {
+        self.contents.downcast()
+    }
+
+    fn downcast_with<U>(this: &mut Self, f: impl FnOnce(&mut WidgetId<T>) -> U) -> U {
+        let mut widget = this.downcast();
+        let output = f(&mut widget);
+        this.contents = widget.upcast();
+        output
+    }
+}
+
+impl<T> Super<Context, Layered<WidgetId<T>>> for Layered<WidgetId>
+where
+    T: ?Sized,
+{
+    fn replace(
+        cx: &mut Context,
+        this: &mut Layered<WidgetId>,
+        other: Layered<WidgetId<T>>,
+    ) -> Self {
+        cx.replace_widget(this.contents, other.contents);
+        mem::replace(this, Layered::upcast(cx, other))
+    }
+
+    fn upcast(_cx: &mut Context, sub: Layered<WidgetId<T>>) -> Self {
+        Self {
+            contents:     sub.contents.upcast(),
+            alignment:    sub.alignment,
+            pass_through: sub.pass_through,
+        }
+    }
+
+    fn downcast(self) -> Layered<WidgetId<T>> {
+        Layered {
+            contents:     self.contents.downcast(),
+            alignment:    self.alignment,
+            pass_through: self.pass_through,
+        }
+    }
+
+    fn downcast_with<U>(this: &mut Self, f: impl FnOnce(&mut Layered<WidgetId<T>>) -> U) -> U {
+        let mut widget = this.downcast();
+        let output = f(&mut widget);
+        this.contents = widget.contents.upcast();
+        this.alignment = widget.alignment;
+        this.pass_through = widget.pass_through;
+        output
+    }
+}
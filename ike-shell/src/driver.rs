@@ -0,0 +1,115 @@
+use ike_core::{
+    FileDialog, FileDialogResponder, ImeSignal, PageSetup, Signal, WidgetId, WindowId, WindowUpdate,
+};
+
+use crate::{Shell, ShellEvent};
+
+/// The platform specific part of a backend, driving a [`Shell`].
+///
+/// Only [`Driver::shell`] and the window methods are required, the rest default to logging
+/// that the feature isn't supported.
+pub trait Driver<'a, T> {
+    type Error;
+
+    fn shell(&mut self) -> &mut Shell<'a, T>;
+
+    /// Request that `window` is redrawn, the backend should call [`Shell::animate`] and
+    /// [`World::draw`](ike_core::World::draw) once the platform is ready for a new frame.
+    fn request_redraw(&mut self, window: WindowId);
+
+    /// Create the platform window for `window`, the description of which can be read with
+    /// [`World::get_window`](ike_core::World::get_window).
+    fn create_window(&mut self, window: WindowId) -> Result<(), Self::Error>;
+
+    fn remove_window(&mut self, window: WindowId);
+
+    fn update_window(&mut self, window: WindowId, update: WindowUpdate);
+
+    /// Close `window`, as if its close button was pressed.
+    fn close_window(&mut self, window: WindowId) {
+        let _ = window;
+        tracing::warn!("closing windows is not supported by this backend");
+    }
+
+    fn set_clipboard(&mut self, text: String) {
+        let _ = text;
+        tracing::warn!("the clipboard is not supported by this backend");
+    }
+
+    /// Read the text contents of the clipboard, and paste them into `window` with
+    /// [`World::text_pasted`](ike_core::World::text_pasted).
+    fn paste_clipboard(&mut self, window: WindowId) {
+        let _ = window;
+        tracing::warn!("the clipboard is not supported by this backend");
+    }
+
+    fn ime_signal(&mut self, ime: ImeSignal) {
+        let _ = ime;
+    }
+
+    fn print(&mut self, widget: WidgetId, setup: PageSetup) -> Result<(), Self::Error> {
+        let _ = (widget, setup);
+        tracing::warn!("printing is not supported by this backend");
+        Ok(())
+    }
+
+    fn show_file_dialog(&mut self, dialog: FileDialog, respond: FileDialogResponder) {
+        let _ = dialog;
+        tracing::warn!("file dialogs are not supported by this backend");
+        respond(Vec::new());
+    }
+
+    /// Keep `state` to be saved by the platform, see
+    /// [`Builder::save_state`](ike_core::Builder::save_state).
+    fn save_state(&mut self, state: Vec<u8>) {
+        let _ = state;
+    }
+
+    /// Handle an event received through a [`ShellProxy`](crate::ShellProxy).
+    fn handle_event(&mut self, event: ShellEvent) -> Result<(), Self::Error> {
+        match event {
+            ShellEvent::Rebuild => self.shell().rebuild(),
+            ShellEvent::Event(event) => self.shell().event(event),
+            ShellEvent::Spawn(future) => self.shell().spawn(future),
+            ShellEvent::Signal(signal) => self.handle_signal(signal)?,
+        }
+
+        Ok(())
+    }
+
+    fn handle_signal(&mut self, signal: Signal) -> Result<(), Self::Error> {
+        match signal {
+            Signal::RequestRedraw { window } => {
+                self.shell().world().redraw_handled(window);
+                self.request_redraw(window);
+            }
+
+            Signal::RequestAnimate { window, start } => {
+                if self.shell().request_animate(window, start) {
+                    self.request_redraw(window);
+                }
+            }
+
+            Signal::Mutate(f) => f(self.shell().world()),
+            Signal::ClipboardSet(text) => self.set_clipboard(text),
+            Signal::ClipboardPaste(window) => self.paste_clipboard(window),
+            Signal::CreateWindow(window) => self.create_window(window)?,
+
+            Signal::RemoveWindow(window) => {
+                self.shell().window_removed(window);
+                self.remove_window(window);
+            }
+
+            Signal::UpdateWindow(window, update) => self.update_window(window, update),
+            Signal::CloseWindow(window) => self.close_window(window),
+            Signal::Ime(ime) => self.ime_signal(ime),
+            Signal::Print { widget, setup } => self.print(widget, setup)?,
+            Signal::FileDialog { dialog, respond } => self.show_file_dialog(dialog, respond),
+            Signal::SaveState(state) => self.save_state(state),
+            Signal::RenderSvg { widget, respond } => respond(self.shell().render_svg(widget)),
+            Signal::LoadFont { bytes, alias } => self.shell().load_font(&bytes, alias.as_deref()),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+//! Shared glue for writing ike backends.
+//!
+//! A backend owns the platform event loop, windows and surfaces, everything else is handled by
+//! a [`Shell`]. The contract between the two is:
+//!
+//! - The backend creates a [`ShellProxy`], that forwards [`ShellEvent`]s to the thread running
+//!   the event loop, and wakes it up if it is waiting.
+//! - The backend creates a [`Shell`] with the proxy, and calls [`Shell::build`] once the
+//!   platform is ready to create windows.
+//! - Whenever the event loop wakes up, every received [`ShellEvent`] is passed to
+//!   [`Driver::handle_event`]. [`Signal`](ike_core::Signal)s the shell can't handle by itself
+//!   are forwarded to the methods of the [`Driver`].
+//! - Platform input is fed to [`Shell::world`], e.g. with
+//!   [`World::pointer_moved`](ike_core::World::pointer_moved), and so are platform settings,
+//!   e.g. the layout direction of the language of the user with
+//!   [`World::layout_direction_changed`](ike_core::World::layout_direction_changed).
+//! - When a window is redrawn, the backend calls [`Shell::animate`] before
+//!   [`World::draw`](ike_core::World::draw), and drains the received events again, as
+//!   animating may have changed the view.
+
+#![warn(clippy::unwrap_used)]
+
+mod driver;
+mod locale;
+mod proxy;
+mod shell;
+
+pub use driver::Driver;
+pub use locale::{system_layout_direction, system_locale, system_locales};
+pub use proxy::{ShellEvent, ShellProxy};
+pub use shell::Shell;
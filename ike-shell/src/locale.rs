@@ -0,0 +1,39 @@
+use std::env;
+
+use ike_core::LayoutDirection;
+
+/// The locale of the user from the environment, e.g. `de_DE.UTF-8`, following the precedence of
+/// POSIX, `LC_ALL` over `LC_MESSAGES` over `LANG`.
+///
+/// Returns `None` if none is set, or the locale is the default `C` or `POSIX` locale, which is
+/// common on platforms that don't configure the locale through the environment.
+pub fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|locale| !locale.is_empty())
+        .filter(|locale| !matches!(locale.as_str(), "C" | "POSIX") && !locale.starts_with("C."))
+}
+
+/// The locales preferred by the user from the environment, most preferred first.
+///
+/// This is the colon-separated `LANGUAGE` list of GNU gettext, e.g. `de_AT:de:en`, followed by
+/// [`system_locale`].
+pub fn system_locales() -> Vec<String> {
+    let mut locales: Vec<String> = env::var("LANGUAGE")
+        .unwrap_or_default()
+        .split(':')
+        .filter(|locale| !locale.is_empty())
+        .map(String::from)
+        .collect();
+
+    locales.extend(system_locale());
+    locales
+}
+
+/// The layout direction of the language of [`system_locale`], left-to-right if it's unknown.
+pub fn system_layout_direction() -> LayoutDirection {
+    system_locale().map_or(LayoutDirection::Ltr, |locale| {
+        LayoutDirection::from_locale(&locale)
+    })
+}
@@ -0,0 +1,74 @@
+use std::{fmt, pin::Pin, sync::Arc};
+
+use ike_core::Signal;
+
+/// An event sent to the thread running a [`Shell`](crate::Shell).
+pub enum ShellEvent {
+    /// The view needs to be rebuilt.
+    Rebuild,
+
+    /// An event needs to be passed to the view.
+    Event(ori::Event),
+
+    /// A future needs to be spawned on the runtime.
+    Spawn(Pin<Box<dyn Future<Output = ()> + Send>>),
+
+    /// A signal was emitted by the [`World`](ike_core::World).
+    Signal(Signal),
+}
+
+impl fmt::Debug for ShellEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rebuild => write!(f, "Rebuild"),
+            Self::Event(event) => f.debug_tuple("Event").field(event).finish(),
+            Self::Spawn(..) => f.debug_tuple("Spawn").finish_non_exhaustive(),
+            Self::Signal(signal) => f.debug_tuple("Signal").field(signal).finish(),
+        }
+    }
+}
+
+/// A [`Proxy`](ori::Proxy) sending [`ShellEvent`]s to the event loop of a backend.
+#[derive(Clone)]
+pub struct ShellProxy {
+    send: Arc<dyn Fn(ShellEvent) + Send + Sync>,
+}
+
+impl ShellProxy {
+    /// Create a proxy calling `send` with every event.
+    ///
+    /// `send` may be called from any thread, it must queue the event and wake the event loop.
+    pub fn new(send: impl Fn(ShellEvent) + Send + Sync + 'static) -> Self {
+        Self {
+            send: Arc::new(send),
+        }
+    }
+
+    pub fn send(&self, event: ShellEvent) {
+        (self.send)(event);
+    }
+
+    /// Create a signaller for [`World::new`](ike_core::World::new).
+    pub fn signaller(&self) -> Box<dyn Fn(Signal)> {
+        let proxy = self.clone();
+        Box::new(move |signal| proxy.send(ShellEvent::Signal(signal)))
+    }
+}
+
+impl ori::Proxy for ShellProxy {
+    fn cloned(&self) -> Arc<dyn ori::Proxy> {
+        Arc::new(self.clone())
+    }
+
+    fn rebuild(&self) {
+        self.send(ShellEvent::Rebuild);
+    }
+
+    fn event(&self, event: ori::Event) {
+        self.send(ShellEvent::Event(event));
+    }
+
+    fn spawn_boxed(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        self.send(ShellEvent::Spawn(future));
+    }
+}
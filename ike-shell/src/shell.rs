@@ -0,0 +1,211 @@
+use std::{collections::HashMap, pin::Pin, sync::Arc, time::Instant};
+
+use ike_core::{Lifecycle, MemoryPressure, Settings, Signal, Throttle, WidgetId, WindowId, World};
+use ike_ori::l10n::Locale;
+use ike_skia::SkiaPainter;
+use ori::{AnyState, AnyView, Proxy as _, View};
+
+use crate::{ShellEvent, ShellProxy};
+
+/// The platform independent state of an application, see the [crate docs](crate).
+pub struct Shell<'a, T> {
+    pub context: ike_ori::Context,
+    pub painter: SkiaPainter,
+
+    data:    &'a mut T,
+    build:   ike_ori::UiBuilder<T>,
+    state:   Option<AnyState<ike_ori::Context, T, ori::NoElement>>,
+    runtime: tokio::runtime::Handle,
+    proxy:   ShellProxy,
+    animate: HashMap<WindowId, Instant>,
+
+    /// When each window was last animated, used to throttle windows in the background.
+    animated: HashMap<WindowId, Instant>,
+}
+
+impl<'a, T> Shell<'a, T> {
+    /// Create a shell, the view isn't built until [`Shell::build`] is called.
+    pub fn new(
+        data: &'a mut T,
+        build: ike_ori::UiBuilder<T>,
+        settings: Settings,
+        painter: SkiaPainter,
+        proxy: ShellProxy,
+        runtime: tokio::runtime::Handle,
+    ) -> Self {
+        let context = ike_ori::Context {
+            world:     World::new(proxy.signaller(), settings),
+            proxy:     Arc::new(proxy.clone()),
+            resources: ike_ori::Resources::new(),
+            locales:   ike_ori::l10n::Locales::default(),
+        };
+
+        Self {
+            context,
+            painter,
+
+            data,
+            build,
+            state: None,
+            runtime,
+            proxy,
+            animate: HashMap::new(),
+            animated: HashMap::new(),
+        }
+    }
+
+    pub fn world(&mut self) -> &mut World {
+        &mut self.context.world
+    }
+
+    pub fn runtime(&self) -> &tokio::runtime::Handle {
+        &self.runtime
+    }
+
+    pub fn is_built(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// Build the view, does nothing if it has already been built.
+    pub fn build(&mut self) {
+        if self.state.is_some() {
+            return;
+        }
+
+        let view = (self.build)(self.data);
+        let (_, state) = view.build(&mut self.context, self.data);
+        self.state = Some(state);
+    }
+
+    pub fn rebuild(&mut self) {
+        tracing::trace!("view rebuild");
+
+        if let Some(ref mut state) = self.state {
+            let view = (self.build)(self.data);
+            view.rebuild((), state, &mut self.context, self.data);
+        }
+    }
+
+    pub fn event(&mut self, mut event: ori::Event) {
+        tracing::trace!(?event, "view event");
+
+        if let Some(ref mut state) = self.state {
+            let action = Box::<dyn AnyView<_, _, _>>::event(
+                (),
+                state,
+                &mut self.context,
+                self.data,
+                &mut event,
+            );
+
+            self.context.proxy.action(action);
+        }
+    }
+
+    pub fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        self.runtime.spawn(future);
+    }
+
+    /// Record that `window` needs to be animated.
+    ///
+    /// Returns `true` if the window wasn't already waiting to be animated, in which case it
+    /// needs to be redrawn. Windows in the background are throttled according to
+    /// [`World::window_throttle`], a redraw is then requested later, or once the window is in
+    /// the foreground again.
+    pub fn request_animate(&mut self, window: WindowId, start: Instant) -> bool {
+        if self.animate.contains_key(&window) {
+            return false;
+        }
+
+        self.animate.insert(window, start);
+
+        match self.context.world.window_throttle(window) {
+            Throttle::None => true,
+            Throttle::Pause => false,
+
+            Throttle::Interval(interval) => {
+                let Some(next) = (self.animated.get(&window)).map(|last| *last + interval) else {
+                    return true;
+                };
+
+                if next <= Instant::now() {
+                    return true;
+                }
+
+                let proxy = self.proxy.clone();
+                self.runtime.spawn(async move {
+                    tokio::time::sleep_until(next.into()).await;
+
+                    let signal = Signal::RequestRedraw { window };
+                    proxy.send(ShellEvent::Signal(signal));
+                });
+
+                false
+            }
+        }
+    }
+
+    /// Animate `window` if it has requested it, returns whether it was animated.
+    pub fn animate(&mut self, window: WindowId) -> bool {
+        let Some(start) = self.animate.remove(&window) else {
+            return false;
+        };
+
+        self.animated.insert(window, Instant::now());
+        self.context.world.animate(window, start.elapsed());
+        true
+    }
+
+    /// Release memory held by the world and painter according to `pressure`.
+    ///
+    /// GPU resources are owned by the surfaces of the backend, which have to be trimmed
+    /// separately.
+    pub fn memory_pressure_changed(&mut self, pressure: MemoryPressure) {
+        if self.context.world.memory_pressure_changed(pressure) {
+            tracing::debug!(?pressure, "memory pressure changed");
+        }
+
+        self.painter.trim(pressure);
+    }
+
+    /// Render `widget` as an SVG document, at the size it was last laid out with.
+    pub fn render_svg(&mut self, widget: WidgetId) -> String {
+        ike_skia::render_svg(
+            &mut self.context.world,
+            widget,
+            &mut self.painter,
+        )
+    }
+
+    /// Load a font, as the family `alias` if set, and lay out text again with it.
+    pub fn load_font(&mut self, bytes: &[u8], alias: Option<&str>) {
+        self.painter.load_font(bytes, alias);
+        self.context.world.fonts_changed();
+    }
+
+    /// Set the state of the application in its lifecycle, rebuilding the view if it changed.
+    pub fn lifecycle_changed(&mut self, lifecycle: Lifecycle) {
+        if self.context.world.lifecycle_changed(lifecycle) {
+            tracing::debug!(?lifecycle, "lifecycle changed");
+            self.rebuild();
+        }
+    }
+
+    /// Set the locales preferred by the user, most preferred first, e.g. `de_AT.UTF-8` or
+    /// `de-AT`, rebuilding the view if they changed.
+    pub fn locales_changed(&mut self, locales: &[String]) {
+        let locales: Vec<_> = (locales.iter())
+            .filter_map(|locale| Locale::parse(locale))
+            .collect();
+
+        if self.context.system_locales_changed(locales) {
+            tracing::debug!(locales = ?self.context.locales.system, "locales changed");
+            self.rebuild();
+        }
+    }
+
+    pub(crate) fn window_removed(&mut self, window: WindowId) {
+        self.animate.remove(&window);
+        self.animated.remove(&window);
+    }
+}
@@ -1,12 +1,15 @@
 use ike_core::{
-    Affine, BorderWidth, Canvas, Clip, CornerRadius, Curve, Offset, Paint, Painter, Paragraph,
-    Recording, RecordingData, Rect, Svg,
+    Affine, BorderWidth, Canvas, Clip, Color, CornerRadius, Curve, LayerOptions, Offset, Paint,
+    Painter, Paragraph, Point, Recording, RecordingData, Rect, Shape, Svg, SvgStyle,
 };
 
 use crate::{painter::SkiaPainter, vulkan::Surface};
 
+/// The largest width and height, in pixels, svgs are rasterized at.
+const MAX_SVG_RASTER_SIZE: i32 = 2048;
+
 pub struct SkiaCanvas<'a> {
-    pub(crate) surface: &'a mut Surface,
+    pub(crate) surface: Option<&'a mut Surface>,
     pub(crate) painter: &'a mut SkiaPainter,
     pub(crate) canvas:  &'a skia_safe::Canvas,
 }
@@ -37,8 +40,43 @@ impl Canvas for SkiaCanvas<'_> {
         self.canvas.restore();
     }
 
-    fn layer(&mut self, f: &mut dyn FnMut(&mut dyn Canvas)) {
-        self.canvas.save_layer_alpha_f(None, 1.0);
+    fn layer(&mut self, options: &LayerOptions, f: &mut dyn FnMut(&mut dyn Canvas)) {
+        let mut paint = skia_safe::Paint::default();
+        paint.set_alpha_f(options.opacity);
+
+        if options.blur > 0.0 {
+            let sigma = blur_sigma(options.blur);
+            paint.set_image_filter(skia_safe::image_filters::blur(
+                (sigma, sigma),
+                None,
+                None,
+                None,
+            ));
+        }
+
+        if let Some(ref matrix) = options.color_matrix {
+            paint.set_color_filter(skia_safe::color_filters::matrix_row_major(matrix, None));
+        }
+
+        let backdrop = if options.backdrop_blur > 0.0 {
+            let sigma = blur_sigma(options.backdrop_blur);
+            skia_safe::image_filters::blur(
+                (sigma, sigma),
+                skia_safe::TileMode::Clamp,
+                None,
+                None,
+            )
+        } else {
+            None
+        };
+
+        let mut rec = skia_safe::canvas::SaveLayerRec::default().paint(&paint);
+
+        if let Some(ref backdrop) = backdrop {
+            rec = rec.backdrop(backdrop);
+        }
+
+        self.canvas.save_layer(&rec);
         f(self);
         self.canvas.restore();
     }
@@ -51,22 +89,21 @@ impl Canvas for SkiaCanvas<'_> {
     ) -> Option<Recording> {
         let memory = width as u64 * height as u64 * 4;
 
-        let mut surface = self
-            .surface
-            .create_render_target(width, height, false)
-            .ok()?;
+        // recordings can only be made when drawing to a gpu surface
+        let surface = self.surface.as_deref_mut()?;
+        let mut target = surface.create_render_target(width, height, false).ok()?;
 
         let mut canvas = SkiaCanvas {
-            surface: self.surface,
+            surface: Some(surface),
             painter: self.painter,
-            canvas:  surface.canvas(),
+            canvas:  target.canvas(),
         };
 
         canvas.canvas.clear(skia_safe::Color::TRANSPARENT);
 
         f(&mut canvas);
 
-        let image = surface.image_snapshot();
+        let image = target.image_snapshot();
 
         let recording = Recording::new(RecordingData {
             width,
@@ -113,6 +150,16 @@ impl Canvas for SkiaCanvas<'_> {
                     );
                 }
             }
+
+            Clip::Curve(curve) => {
+                let path = self.painter.create_path(curve).clone();
+
+                self.canvas.clip_path(
+                    &path,
+                    skia_safe::ClipOp::Intersect,
+                    true, // enable anti aliasing
+                );
+            }
         }
 
         f(self);
@@ -133,17 +180,7 @@ impl Canvas for SkiaCanvas<'_> {
     }
 
     fn draw_rect(&mut self, rect: Rect, radius: CornerRadius, paint: &Paint) {
-        let rect = skia_safe::RRect::new_rect_radii(
-            skia_safe::Rect::new(
-                rect.min.x, rect.min.y, rect.max.x, rect.max.y,
-            ),
-            &[
-                skia_safe::Point::new(radius.top_left, radius.top_left),
-                skia_safe::Point::new(radius.top_right, radius.top_right),
-                skia_safe::Point::new(radius.bottom_right, radius.bottom_right),
-                skia_safe::Point::new(radius.bottom_left, radius.bottom_left),
-            ],
-        );
+        let rect = create_rrect(rect, radius);
 
         let paint = self.painter.create_paint(paint);
         self.canvas.draw_rrect(rect, paint);
@@ -181,13 +218,77 @@ impl Canvas for SkiaCanvas<'_> {
         self.canvas.draw_drrect(outer, inner, paint);
     }
 
+    fn draw_shadow(&mut self, shape: &Shape, elevation: f32, color: Color) {
+        if elevation <= 0.0 || color.a <= 0.0 {
+            return;
+        }
+
+        // the shadow is cast by a light straight above, slightly towards the viewer, so it falls
+        // below the shape and spreads with elevation
+        let mut paint = skia_safe::Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color4f(
+            skia_safe::Color4f::new(color.r, color.g, color.b, color.a),
+            None,
+        );
+        paint.set_mask_filter(skia_safe::MaskFilter::blur(
+            skia_safe::BlurStyle::Normal,
+            elevation * 0.5,
+            false,
+        ));
+
+        self.canvas.save();
+        self.canvas.translate((0.0, elevation * 0.5));
+
+        match shape {
+            Shape::Rect(rect, radius) => {
+                self.canvas.draw_rrect(create_rrect(*rect, *radius), &paint);
+            }
+
+            Shape::Curve(curve) => {
+                let path = self.painter.create_path(curve).clone();
+                self.canvas.draw_path(&path, &paint);
+            }
+        }
+
+        self.canvas.restore();
+    }
+
     fn draw_text(&mut self, paragraph: &Paragraph, max_width: f32, offset: Offset) {
         let paragraph = self.painter.create_paragraph(paragraph, max_width + 1.0);
         paragraph.paint(self.canvas, (offset.x, offset.y));
     }
 
-    fn draw_svg(&mut self, svg: &Svg) {
-        if let Some(skia_dom) = self.painter.create_svg(svg) {
+    fn draw_svg(&mut self, svg: &Svg, style: &SvgStyle) {
+        let matrix = self.canvas.local_to_device_as_3x3();
+
+        // on screen svgs are rasterized at the size they're drawn at, so icons drawn every
+        // frame are only rendered once, anywhere else they're kept as vectors
+        if self.surface.is_some() && matrix.is_scale_translate() {
+            let size = self.painter.measure_svg(svg);
+            let width = (size.width * matrix.scale_x()).ceil() as i32;
+            let height = (size.height * matrix.scale_y()).ceil() as i32;
+
+            let fits = (1..=MAX_SVG_RASTER_SIZE).contains(&width)
+                && (1..=MAX_SVG_RASTER_SIZE).contains(&height);
+
+            if fits && let Some(image) = self.painter.rasterize_svg(svg, style, (width, height)) {
+                self.canvas.draw_image_rect_with_sampling_options(
+                    &image,
+                    None,
+                    skia_safe::Rect::from_wh(size.width, size.height),
+                    skia_safe::SamplingOptions::new(
+                        skia_safe::FilterMode::Linear,
+                        skia_safe::MipmapMode::None,
+                    ),
+                    &skia_safe::Paint::default(),
+                );
+
+                return;
+            }
+        }
+
+        if let Some(skia_dom) = self.painter.create_styled_svg(svg, style) {
             skia_dom.render(self.canvas);
         }
     }
@@ -215,4 +316,45 @@ impl Canvas for SkiaCanvas<'_> {
             tracing::error!("invalid recording drawn");
         }
     }
+
+    fn sample(&mut self, point: Point) -> Option<Color> {
+        let info = skia_safe::ImageInfo::new(
+            (1, 1),
+            skia_safe::ColorType::RGBA8888,
+            skia_safe::AlphaType::Unpremul,
+            skia_safe::ColorSpace::new_srgb(),
+        );
+
+        let mut pixel = [0u8; 4];
+        let point = (
+            point.x.floor() as i32,
+            point.y.floor() as i32,
+        );
+
+        if !self.canvas.read_pixels(&info, &mut pixel, 4, point) {
+            return None;
+        }
+
+        let [r, g, b, a] = pixel.map(|c| c as f32 / 255.0);
+        Some(Color::rgba(r, g, b, a))
+    }
+}
+
+fn create_rrect(rect: Rect, radius: CornerRadius) -> skia_safe::RRect {
+    skia_safe::RRect::new_rect_radii(
+        skia_safe::Rect::new(
+            rect.min.x, rect.min.y, rect.max.x, rect.max.y,
+        ),
+        &[
+            skia_safe::Point::new(radius.top_left, radius.top_left),
+            skia_safe::Point::new(radius.top_right, radius.top_right),
+            skia_safe::Point::new(radius.bottom_right, radius.bottom_right),
+            skia_safe::Point::new(radius.bottom_left, radius.bottom_left),
+        ],
+    )
+}
+
+// the conversion skia itself uses between blur radii and sigmas
+fn blur_sigma(radius: f32) -> f32 {
+    radius * 0.57735 + 0.5
 }
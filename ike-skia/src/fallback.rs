@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+/// A writing system, text in which falls back to the fonts registered for it in
+/// [`FontFallbacks`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    Arabic,
+    Cyrillic,
+    Devanagari,
+    Emoji,
+    Greek,
+
+    /// Chinese characters, also used in Japanese and Korean text.
+    Han,
+    Hangul,
+    Hebrew,
+
+    /// Hiragana and katakana.
+    Kana,
+    Thai,
+}
+
+impl Script {
+    /// The script `c` is written in, if it's one of those fallbacks can be registered for.
+    pub fn of(c: char) -> Option<Self> {
+        Some(match c as u32 {
+            0x0370..=0x03ff | 0x1f00..=0x1fff => Script::Greek,
+            0x0400..=0x052f | 0x2de0..=0x2dff | 0xa640..=0xa69f => Script::Cyrillic,
+            0x0590..=0x05ff | 0xfb1d..=0xfb4f => Script::Hebrew,
+            0x0600..=0x06ff | 0x0750..=0x077f | 0xfb50..=0xfdff | 0xfe70..=0xfeff => Script::Arabic,
+            0x0900..=0x097f | 0xa8e0..=0xa8ff => Script::Devanagari,
+            0x0e00..=0x0e7f => Script::Thai,
+            0x1100..=0x11ff | 0x3130..=0x318f | 0xac00..=0xd7af => Script::Hangul,
+            0x3040..=0x30ff | 0x31f0..=0x31ff | 0xff66..=0xff9f => Script::Kana,
+            0x2e80..=0x2fdf | 0x3000..=0x303f | 0x3400..=0x4dbf | 0x4e00..=0x9fff => Script::Han,
+            0xf900..=0xfaff | 0x20000..=0x3134f => Script::Han,
+            0x2600..=0x27bf | 0xfe0f | 0x1f000..=0x1faff => Script::Emoji,
+            _ => return None,
+        })
+    }
+}
+
+/// The fonts text falls back to when its font family doesn't have a glyph, by script.
+///
+/// Text is shaped with its own font family first, then the fallbacks of the scripts it contains
+/// in the order they appear, then the default fallbacks. Characters none of them have are
+/// matched against every font of the system, with fontconfig, DirectWrite, CoreText or the
+/// Android font configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontFallbacks {
+    scripts: HashMap<Script, Vec<String>>,
+    default: Vec<String>,
+}
+
+impl Default for FontFallbacks {
+    fn default() -> Self {
+        let mut fallbacks = Self::empty();
+
+        for &(script, family) in PLATFORM_FALLBACKS {
+            let families = fallbacks.scripts.entry(script).or_default();
+            families.push(String::from(family));
+        }
+
+        fallbacks
+    }
+}
+
+impl FontFallbacks {
+    /// Fallbacks without any fonts, only the fonts of the system are matched.
+    pub fn empty() -> Self {
+        Self {
+            scripts: HashMap::new(),
+            default: Vec::new(),
+        }
+    }
+
+    /// The fallbacks of `script`, highest priority first.
+    pub fn get(&self, script: Script) -> &[String] {
+        self.scripts.get(&script).map_or(&[], Vec::as_slice)
+    }
+
+    /// Set the fallbacks of `script`, highest priority first.
+    pub fn set(&mut self, script: Script, families: impl IntoIterator<Item = impl Into<String>>) {
+        let families = families.into_iter().map(Into::into).collect();
+        self.scripts.insert(script, families);
+    }
+
+    /// Add a fallback to `script`, with a higher priority than those already set.
+    pub fn prepend(&mut self, script: Script, family: impl Into<String>) {
+        let families = self.scripts.entry(script).or_default();
+        families.insert(0, family.into());
+    }
+
+    /// The fallbacks of text in any script, after those of its scripts.
+    pub fn default_fallbacks(&self) -> &[String] {
+        &self.default
+    }
+
+    pub fn set_default_fallbacks(&mut self, families: impl IntoIterator<Item = impl Into<String>>) {
+        self.default = families.into_iter().map(Into::into).collect();
+    }
+
+    /// The font families to shape `text` with, starting with `family`.
+    pub(crate) fn resolve(&self, family: &str, text: &str) -> Vec<String> {
+        let mut scripts = Vec::new();
+
+        for script in text.chars().filter_map(Script::of) {
+            if !scripts.contains(&script) {
+                scripts.push(script);
+            }
+        }
+
+        let mut families = vec![String::from(family)];
+
+        let fallbacks = scripts.iter().flat_map(|&script| self.get(script));
+
+        for fallback in fallbacks.chain(&self.default) {
+            if !families.contains(fallback) {
+                families.push(fallback.clone());
+            }
+        }
+
+        families
+    }
+}
+
+/// The fonts commonly installed on each platform, highest priority first.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+const PLATFORM_FALLBACKS: &[(Script, &str)] = &[
+    (Script::Emoji, "Apple Color Emoji"),
+    (Script::Han, "PingFang SC"),
+    (Script::Han, "Hiragino Sans GB"),
+    (Script::Kana, "Hiragino Sans"),
+    (Script::Hangul, "Apple SD Gothic Neo"),
+    (Script::Arabic, "Geeza Pro"),
+    (Script::Hebrew, "Arial Hebrew"),
+    (
+        Script::Devanagari,
+        "Kohinoor Devanagari",
+    ),
+    (Script::Thai, "Thonburi"),
+];
+
+/// The fonts commonly installed on each platform, highest priority first.
+#[cfg(target_os = "windows")]
+const PLATFORM_FALLBACKS: &[(Script, &str)] = &[
+    (Script::Emoji, "Segoe UI Emoji"),
+    (Script::Han, "Microsoft YaHei"),
+    (Script::Kana, "Yu Gothic UI"),
+    (Script::Kana, "Meiryo"),
+    (Script::Hangul, "Malgun Gothic"),
+    (Script::Arabic, "Segoe UI"),
+    (Script::Hebrew, "Segoe UI"),
+    (Script::Devanagari, "Nirmala UI"),
+    (Script::Thai, "Leelawadee UI"),
+];
+
+/// The fonts commonly installed on each platform, highest priority first.
+#[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "windows")))]
+const PLATFORM_FALLBACKS: &[(Script, &str)] = &[
+    (Script::Emoji, "Noto Color Emoji"),
+    (Script::Han, "Noto Sans CJK SC"),
+    (Script::Han, "WenQuanYi Micro Hei"),
+    (Script::Kana, "Noto Sans CJK JP"),
+    (Script::Hangul, "Noto Sans CJK KR"),
+    (Script::Arabic, "Noto Sans Arabic"),
+    (Script::Hebrew, "Noto Sans Hebrew"),
+    (
+        Script::Devanagari,
+        "Noto Sans Devanagari",
+    ),
+    (Script::Thai, "Noto Sans Thai"),
+];
@@ -1,10 +1,22 @@
 #![warn(clippy::unwrap_used)]
 
 mod canvas;
+mod fallback;
 mod painter;
+mod pdf;
+mod raster;
+mod shaping;
+mod snapshot;
+mod svg;
+mod text_cache;
 
 #[cfg(feature = "vulkan")]
 pub mod vulkan;
 
 pub use canvas::SkiaCanvas;
-pub use painter::SkiaPainter;
+pub use fallback::{FontFallbacks, Script};
+pub use painter::{FontError, PainterCacheUsage, SkiaPainter};
+pub use pdf::{PdfPrinter, render_pdf};
+pub use raster::{RasterPage, RasterPrinter, render_pages};
+pub use snapshot::{Snapshot, SnapshotDiff, SnapshotError, Snapshots, UPDATE_SNAPSHOTS};
+pub use svg::render_svg;
@@ -1,23 +1,76 @@
-use std::{collections::HashMap, hash::BuildHasherDefault, mem};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    hash::BuildHasherDefault,
+    io, mem,
+    path::{Path, PathBuf},
+};
 
 use ike_core::{
-    Curve, Fill, FontStretch, FontStyle, GlyphCluster, Paint, Painter, Paragraph, Point, Rect,
-    Shader, Size, Svg, TextDirection, TextLayoutLine, TextStyle, TextWrap, WeakCurve,
-    WeakParagraph, WeakRecording, WeakSvg,
+    Curve, Fill, Filter, FontStretch, FontStyle, GlyphCluster, Gradient, GradientKind, Image,
+    ImageShader, MemoryPressure, Paint, Painter, Paragraph, Point, Rect, Shader, Size, Spread, Svg,
+    SvgStyle, TextCacheStats, TextDirection, TextLayoutLine, TextStyle, WeakCurve, WeakImage,
+    WeakRecording, WeakSvg,
+};
+
+use crate::{
+    FontFallbacks,
+    shaping::{self, LoadedFonts, TextJob},
+    text_cache::TextCache,
 };
 
-type SeaHasher = BuildHasherDefault<seahash::SeaHasher>;
-type CachedParagraph = (f32, skia_safe::textlayout::Paragraph);
+pub(crate) type SeaHasher = BuildHasherDefault<seahash::SeaHasher>;
+
+/// The number of entries in each of the caches of a [`SkiaPainter`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PainterCacheUsage {
+    pub svgs: usize,
+
+    /// The svgs kept rasterized, one for each size and style they're drawn at.
+    pub svg_rasters: usize,
+
+    pub images:     usize,
+    pub paragraphs: usize,
+
+    /// The estimated memory used by shaped paragraphs.
+    pub paragraph_bytes: u64,
+
+    /// The sizes of measured text, memoized by their text, style and width.
+    pub text_sizes: usize,
+
+    pub recordings: usize,
+    pub paths:      usize,
+    pub paints:     usize,
+
+    /// The memory used by the pixels of recordings.
+    pub recording_bytes: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FontError {
+    #[error("font `{0}` could not be loaded")]
+    Invalid(PathBuf),
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
 
 pub struct SkiaPainter {
-    pub(crate) provider:   skia_safe::textlayout::TypefaceFontProvider,
-    pub(crate) manager:    skia_safe::FontMgr,
-    pub(crate) fonts:      skia_safe::textlayout::FontCollection,
-    pub(crate) svgs:       HashMap<WeakSvg, Option<skia_safe::svg::Dom>, SeaHasher>,
-    pub(crate) paragraphs: HashMap<WeakParagraph, CachedParagraph, SeaHasher>,
-    pub(crate) recordings: HashMap<WeakRecording, skia_safe::Image, SeaHasher>,
-    pub(crate) paths:      HashMap<WeakCurve, skia_safe::Path, SeaHasher>,
-    pub(crate) paints:     HashMap<Paint, skia_safe::Paint, SeaHasher>,
+    pub(crate) provider:    skia_safe::textlayout::TypefaceFontProvider,
+    pub(crate) manager:     skia_safe::FontMgr,
+    pub(crate) fonts:       skia_safe::textlayout::FontCollection,
+    pub(crate) loaded:      LoadedFonts,
+    pub(crate) fallbacks:   FontFallbacks,
+    pub(crate) svgs:        HashMap<WeakSvg, Option<skia_safe::svg::Dom>, SeaHasher>,
+    pub(crate) styled_svgs:
+        HashMap<WeakSvg, Vec<(SvgStyle, Option<skia_safe::svg::Dom>)>, SeaHasher>,
+    pub(crate) svg_rasters: HashMap<WeakSvg, Vec<SvgRaster>, SeaHasher>,
+    pub(crate) images:      HashMap<WeakImage, Option<skia_safe::Image>, SeaHasher>,
+    pub(crate) paragraphs:  TextCache,
+    pub(crate) text_sizes:  HashMap<u64, Size, SeaHasher>,
+    pub(crate) recordings:  HashMap<WeakRecording, skia_safe::Image, SeaHasher>,
+    pub(crate) paths:       HashMap<WeakCurve, skia_safe::Path, SeaHasher>,
+    pub(crate) paints:      HashMap<Paint, skia_safe::Paint, SeaHasher>,
 }
 
 impl Default for SkiaPainter {
@@ -27,6 +80,20 @@ impl Default for SkiaPainter {
 }
 
 impl SkiaPainter {
+    /// The memory shaped paragraphs may use by default, see
+    /// [`SkiaPainter::set_text_cache_budget`].
+    pub const DEFAULT_TEXT_CACHE_BUDGET: u64 = 16 * 1024 * 1024;
+
+    /// The most sizes, and styles, each svg is kept rasterized at.
+    const MAX_SVG_RASTERS: usize = 8;
+
+    /// The most text sizes kept memoized between calls to [`SkiaPainter::cleanup`].
+    const MAX_TEXT_SIZES: usize = 8192;
+
+    /// The fewest paragraphs [`Painter::prepare_text`] shapes on the thread pool, fewer are
+    /// shaped when measured, as sending them isn't worth it.
+    const MIN_PARALLEL_TEXT: usize = 8;
+
     pub fn new() -> Self {
         let provider = skia_safe::textlayout::TypefaceFontProvider::new();
         let manager = skia_safe::FontMgr::new();
@@ -38,8 +105,14 @@ impl SkiaPainter {
             provider,
             manager,
             fonts,
+            loaded: LoadedFonts::default(),
+            fallbacks: FontFallbacks::default(),
             svgs: HashMap::default(),
-            paragraphs: HashMap::default(),
+            styled_svgs: HashMap::default(),
+            svg_rasters: HashMap::default(),
+            images: HashMap::default(),
+            paragraphs: TextCache::new(Self::DEFAULT_TEXT_CACHE_BUDGET),
+            text_sizes: HashMap::default(),
             recordings: HashMap::default(),
             paths: HashMap::default(),
             paints: HashMap::default(),
@@ -48,51 +121,247 @@ impl SkiaPainter {
 
     pub fn cleanup(&mut self) {
         self.svgs.retain(|k, _| k.strong_count() > 0);
-        self.paragraphs.retain(|k, _| k.strong_count() > 0);
+        self.styled_svgs.retain(|k, _| k.strong_count() > 0);
+        self.svg_rasters.retain(|k, _| k.strong_count() > 0);
+        self.images.retain(|k, _| k.strong_count() > 0);
         self.recordings.retain(|k, _| k.strong_count() > 0);
         self.paths.retain(|k, _| k.strong_count() > 0);
         self.paints.clear();
+
+        if self.text_sizes.len() > Self::MAX_TEXT_SIZES {
+            self.text_sizes.clear();
+        }
     }
 
+    /// Release cached resources according to `pressure`.
+    ///
+    /// Recordings are owned by the [`Recorder`](ike_core::Recorder), so only those it has
+    /// already released are dropped, see [`World::memory_pressure_changed`].
+    ///
+    /// [`World::memory_pressure_changed`]: ike_core::World::memory_pressure_changed
+    pub fn trim(&mut self, pressure: MemoryPressure) {
+        self.cleanup();
+
+        match pressure {
+            MemoryPressure::None => {}
+
+            MemoryPressure::Moderate => {
+                self.svgs.clear();
+                self.styled_svgs.clear();
+                self.svg_rasters.clear();
+                self.images.clear();
+                self.paths.clear();
+                self.text_sizes.clear();
+                self.paragraphs.evict_to(self.paragraphs.budget() / 2);
+            }
+
+            // paragraphs are shaped again when next measured
+            MemoryPressure::Critical => {
+                self.svgs = HashMap::default();
+                self.styled_svgs = HashMap::default();
+                self.svg_rasters = HashMap::default();
+                self.images = HashMap::default();
+                self.paths = HashMap::default();
+                self.paints = HashMap::default();
+                self.paragraphs.clear();
+                self.text_sizes = HashMap::default();
+            }
+        }
+    }
+
+    pub fn cache_usage(&self) -> PainterCacheUsage {
+        let recording_bytes = self
+            .recordings
+            .values()
+            .map(|image| image.width() as u64 * image.height() as u64 * 4)
+            .sum();
+
+        PainterCacheUsage {
+            svgs: self.svgs.len(),
+            svg_rasters: self.svg_rasters.values().map(Vec::len).sum(),
+            images: self.images.len(),
+            paragraphs: self.paragraphs.len(),
+            paragraph_bytes: self.paragraphs.bytes(),
+            text_sizes: self.text_sizes.len(),
+            recordings: self.recordings.len(),
+            paths: self.paths.len(),
+            paints: self.paints.len(),
+            recording_bytes,
+        }
+    }
+
+    /// Load a font, as the family `alias` if set, otherwise as the family named in the font.
+    ///
+    /// Variable fonts are matched against the weight and stretch of text. Text already laid
+    /// out keeps its size until [`World::fonts_changed`](ike_core::World::fonts_changed).
     pub fn load_font(&mut self, bytes: &[u8], alias: Option<&str>) {
-        if let Some(typeface) = self.manager.new_from_data(bytes, None) {
-            self.provider.register_typeface(typeface, alias);
-        } else {
+        if !self.register_font(bytes, alias) {
             tracing::warn!("loading font failed");
         }
     }
 
+    /// Load the font in the file at `path`, see [`SkiaPainter::load_font`].
+    pub fn load_font_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        alias: Option<&str>,
+    ) -> Result<(), FontError> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)?;
+
+        match self.register_font(&bytes, alias) {
+            true => Ok(()),
+            false => Err(FontError::Invalid(path.to_path_buf())),
+        }
+    }
+
+    fn register_font(&mut self, bytes: &[u8], alias: Option<&str>) -> bool {
+        let Some(typeface) = self.manager.new_from_data(bytes, None) else {
+            return false;
+        };
+
+        self.provider.register_typeface(typeface, alias);
+        self.loaded.push(bytes, alias);
+        self.fonts_changed();
+
+        true
+    }
+
+    /// Unload the fonts loaded as `alias`, returning whether there were any.
+    pub fn unload_font(&mut self, alias: &str) -> bool {
+        if !self.loaded.remove(alias) {
+            return false;
+        }
+
+        // typefaces can't be unregistered, so the provider is built again without them
+        let provider = self.loaded.create_provider(&self.manager);
+        let mut fonts = skia_safe::textlayout::FontCollection::new();
+        fonts.set_dynamic_font_manager(skia_safe::FontMgr::clone(&provider));
+        fonts.set_default_font_manager(self.manager.clone(), None);
+
+        self.provider = provider;
+        self.fonts = fonts;
+
+        self.fonts_changed();
+        true
+    }
+
+    /// The families of the fonts installed on the system.
+    pub fn system_families(&self) -> Vec<String> {
+        self.manager.family_names().collect()
+    }
+
+    /// The families text can be shaped with, those loaded and installed on the system, sorted
+    /// by name.
+    pub fn families(&self) -> Vec<String> {
+        let loaded = skia_safe::FontMgr::clone(&self.provider).family_names();
+        let families: BTreeSet<_> = loaded.chain(self.manager.family_names()).collect();
+
+        families.into_iter().collect()
+    }
+
+    /// The fonts text falls back to when its font family doesn't have a glyph.
+    pub fn fallbacks(&self) -> &FontFallbacks {
+        &self.fallbacks
+    }
+
+    /// Set the fonts text falls back to, by default those commonly installed on the platform.
+    pub fn set_fallbacks(&mut self, fallbacks: FontFallbacks) {
+        if self.fallbacks != fallbacks {
+            self.fallbacks = fallbacks;
+            self.fonts_changed();
+        }
+    }
+
+    /// Set the memory shaped paragraphs may use before the least recently used are evicted,
+    /// [`SkiaPainter::DEFAULT_TEXT_CACHE_BUDGET`] by default.
+    pub fn set_text_cache_budget(&mut self, bytes: u64) {
+        self.paragraphs.set_budget(bytes);
+    }
+
+    fn fonts_changed(&mut self) {
+        // fallback fonts may have changed how text is shaped
+        self.paragraphs.clear();
+        self.text_sizes.clear();
+    }
+
     pub(crate) fn create_svg(&mut self, svg: &Svg) -> Option<skia_safe::svg::Dom> {
         let weak = Svg::downgrade(svg);
 
         self.svgs
             .entry(weak)
-            .or_insert_with(|| {
-                let dom = skia_safe::svg::Dom::from_bytes(
-                    svg.bytes(),
-                    self.manager.clone(), // reuse font manager
-                )
-                .ok()?;
-
-                let mut svg = dom.root();
-
-                if svg.intrinsic_size().is_zero() {
-                    svg.set_height(skia_safe::svg::Length::new(
-                        1.0,
-                        skia_safe::svg::LengthUnit::PX,
-                    ));
-                    svg.set_width(skia_safe::svg::Length::new(
-                        1.0,
-                        skia_safe::svg::LengthUnit::PX,
-                    ));
-                }
-
-                Some(dom)
-            })
+            .or_insert_with(|| load_svg(svg.bytes(), &self.manager))
             .clone()
     }
 
-    fn create_font_style(style: &TextStyle) -> skia_safe::FontStyle {
+    /// Create the dom of `svg` restyled with `style`, see [`SvgData::restyled`].
+    ///
+    /// [`SvgData::restyled`]: ike_core::SvgData::restyled
+    pub(crate) fn create_styled_svg(
+        &mut self,
+        svg: &Svg,
+        style: &SvgStyle,
+    ) -> Option<skia_safe::svg::Dom> {
+        if style.is_plain() {
+            return self.create_svg(svg);
+        }
+
+        let styled = self.styled_svgs.entry(Svg::downgrade(svg)).or_default();
+
+        if let Some((_, dom)) = styled.iter().find(|(other, _)| other == style) {
+            return dom.clone();
+        }
+
+        let dom = load_svg(&svg.restyled(style), &self.manager);
+        styled.push((style.clone(), dom.clone()));
+        dom
+    }
+
+    /// Rasterize `svg` restyled with `style` at `size` in pixels, the image is reused until the
+    /// svg is dropped, or it's drawn at too many other sizes.
+    pub(crate) fn rasterize_svg(
+        &mut self,
+        svg: &Svg,
+        style: &SvgStyle,
+        size: (i32, i32),
+    ) -> Option<skia_safe::Image> {
+        let weak = Svg::downgrade(svg);
+
+        if let Some(rasters) = self.svg_rasters.get(&weak)
+            && let Some(raster) = rasters
+                .iter()
+                .find(|raster| raster.size == size && raster.style == *style)
+        {
+            return Some(raster.image.clone());
+        }
+
+        let dom = self.create_styled_svg(svg, style)?;
+        let intrinsic = dom.root().intrinsic_size();
+
+        let mut surface = skia_safe::surfaces::raster_n32_premul(size)?;
+        surface.canvas().scale((
+            size.0 as f32 / intrinsic.width,
+            size.1 as f32 / intrinsic.height,
+        ));
+        dom.render(surface.canvas());
+
+        let image = surface.image_snapshot();
+        let rasters = self.svg_rasters.entry(weak).or_default();
+
+        if rasters.len() >= Self::MAX_SVG_RASTERS {
+            rasters.remove(0);
+        }
+
+        rasters.push(SvgRaster {
+            style: style.clone(),
+            size,
+            image: image.clone(),
+        });
+
+        Some(image)
+    }
+
+    pub(crate) fn create_font_style(style: &TextStyle) -> skia_safe::FontStyle {
         let weight = skia_safe::font_style::Weight::from(style.font_weight.0 as i32);
 
         let width = match style.font_stretch {
@@ -121,33 +390,27 @@ impl SkiaPainter {
         paragraph: &Paragraph,
         max_width: f32,
     ) -> &mut skia_safe::textlayout::Paragraph {
-        let weak = Paragraph::downgrade(paragraph);
-
-        if !self.paragraphs.contains_key(&weak) {
-            let mut style = skia_safe::textlayout::ParagraphStyle::new();
+        let key = shaping::paragraph_key(paragraph, max_width);
 
-            let align = match paragraph.align {
-                ike_core::TextAlign::Start => skia_safe::textlayout::TextAlign::Start,
-                ike_core::TextAlign::Center => skia_safe::textlayout::TextAlign::Center,
-                ike_core::TextAlign::End => skia_safe::textlayout::TextAlign::End,
-            };
-
-            style.set_height(paragraph.line_height);
-            style.set_text_align(align);
-
-            if let TextWrap::None = paragraph.wrap {
-                style.set_max_lines(1);
-            }
+        if !self.paragraphs.touch(key) {
+            let style = shaping::paragraph_style(
+                paragraph.line_height,
+                paragraph.align,
+                paragraph.wrap,
+            );
 
             let mut builder = skia_safe::textlayout::ParagraphBuilder::new(&style, &self.fonts);
 
             for (text, style) in paragraph.sections() {
+                let families = self.fallbacks.resolve(&style.font_family, text);
+                let font_style = Self::create_font_style(style);
                 let mut skia_style = skia_safe::textlayout::TextStyle::new();
 
                 skia_style.set_subpixel(true);
                 skia_style.set_font_size(style.font_size);
-                skia_style.set_font_families(&[&style.font_family]);
-                skia_style.set_font_style(Self::create_font_style(style));
+                skia_style.set_font_families(&families);
+                skia_style.set_font_style(font_style);
+                shaping::set_variations(&mut skia_style, font_style);
 
                 let paint = self.create_paint(&style.paint);
                 skia_style.set_foreground_paint(paint);
@@ -157,23 +420,16 @@ impl SkiaPainter {
                 builder.pop();
             }
 
-            let mut paragraph = builder.build();
-            paragraph.layout(max_width);
+            let mut skia = builder.build();
+            skia.layout(max_width);
 
-            self.paragraphs.insert(weak.clone(), (max_width, paragraph));
+            let bytes = shaping::paragraph_bytes(paragraph);
+            self.paragraphs.insert(key, skia, bytes);
         }
 
-        let (current_max_width, paragraph) = self
-            .paragraphs
-            .get_mut(&weak)
-            .expect("inserted if not contained");
-
-        if *current_max_width != max_width {
-            paragraph.layout(max_width);
-            *current_max_width = max_width;
-        }
-
-        paragraph
+        self.paragraphs
+            .get_mut(key)
+            .expect("inserted if not cached")
     }
 
     pub(crate) fn create_path(&mut self, curve: &Curve) -> &skia_safe::Path {
@@ -224,39 +480,245 @@ impl SkiaPainter {
         })
     }
 
+    pub(crate) fn create_image(&mut self, image: &Image) -> Option<skia_safe::Image> {
+        let weak = Image::downgrade(image);
+
+        self.images
+            .entry(weak)
+            .or_insert_with(|| {
+                let info = skia_safe::ImageInfo::new(
+                    (image.width as i32, image.height as i32),
+                    skia_safe::ColorType::RGBA8888,
+                    skia_safe::AlphaType::Unpremul,
+                    None,
+                );
+
+                skia_safe::images::raster_from_data(
+                    &info,
+                    skia_safe::Data::new_copy(&image.pixels),
+                    image.width as usize * 4,
+                )
+            })
+            .clone()
+    }
+
+    fn create_gradient(gradient: &Gradient) -> Option<skia_safe::Shader> {
+        let colors: Vec<_> = (gradient.stops.iter())
+            .map(|stop| {
+                let color = stop.color;
+                skia_safe::Color4f::new(color.r, color.g, color.b, color.a).to_color()
+            })
+            .collect();
+
+        let offsets: Vec<_> = gradient.stops.iter().map(|stop| stop.offset).collect();
+
+        let colors = skia_safe::gradient_shader::GradientShaderColors::from(colors.as_slice());
+        let offsets = Some(offsets.as_slice());
+        let mode = Self::create_tile_mode(gradient.spread);
+
+        match gradient.kind {
+            GradientKind::Linear { start, end } => skia_safe::gradient_shader::linear(
+                (
+                    skia_safe::Point::new(start.x, start.y),
+                    skia_safe::Point::new(end.x, end.y),
+                ),
+                colors,
+                offsets,
+                mode,
+                None,
+                None,
+            ),
+
+            GradientKind::Radial { center, radius } => skia_safe::gradient_shader::radial(
+                skia_safe::Point::new(center.x, center.y),
+                radius,
+                colors,
+                offsets,
+                mode,
+                None,
+                None,
+            ),
+
+            GradientKind::Conical {
+                start,
+                start_radius,
+                end,
+                end_radius,
+            } => skia_safe::gradient_shader::two_point_conical(
+                skia_safe::Point::new(start.x, start.y),
+                start_radius,
+                skia_safe::Point::new(end.x, end.y),
+                end_radius,
+                colors,
+                offsets,
+                mode,
+                None,
+                None,
+            ),
+
+            GradientKind::Sweep {
+                center,
+                start_angle,
+                end_angle,
+            } => skia_safe::gradient_shader::sweep(
+                skia_safe::Point::new(center.x, center.y),
+                colors,
+                offsets,
+                mode,
+                Some((
+                    start_angle.to_degrees(),
+                    end_angle.to_degrees(),
+                )),
+                None,
+                None,
+            ),
+        }
+    }
+
+    fn create_image_shader(&mut self, shader: &ImageShader) -> Option<skia_safe::Shader> {
+        let image = self.create_image(&shader.image)?;
+
+        let sampling = match shader.filter {
+            Filter::Nearest => skia_safe::SamplingOptions::new(
+                skia_safe::FilterMode::Nearest,
+                skia_safe::MipmapMode::None,
+            ),
+
+            Filter::Linear => skia_safe::SamplingOptions::new(
+                skia_safe::FilterMode::Linear,
+                skia_safe::MipmapMode::None,
+            ),
+
+            Filter::Mipmap => skia_safe::SamplingOptions::new(
+                skia_safe::FilterMode::Linear,
+                skia_safe::MipmapMode::Linear,
+            ),
+
+            Filter::Cubic => {
+                skia_safe::SamplingOptions::from(skia_safe::CubicResampler::mitchell())
+            }
+        };
+
+        let transform = shader.transform;
+        let matrix = skia_safe::Matrix::new_all(
+            transform.matrix.matrix[0],
+            transform.matrix.matrix[1],
+            transform.offset.x,
+            transform.matrix.matrix[2],
+            transform.matrix.matrix[3],
+            transform.offset.y,
+            0.0,
+            0.0,
+            1.0,
+        );
+
+        image.to_shader(
+            (
+                Self::create_tile_mode(shader.spread_x),
+                Self::create_tile_mode(shader.spread_y),
+            ),
+            sampling,
+            &matrix,
+        )
+    }
+
+    fn create_tile_mode(spread: Spread) -> skia_safe::TileMode {
+        match spread {
+            Spread::Pad => skia_safe::TileMode::Clamp,
+            Spread::Repeat => skia_safe::TileMode::Repeat,
+            Spread::Reflect => skia_safe::TileMode::Mirror,
+            Spread::Decal => skia_safe::TileMode::Decal,
+        }
+    }
+
     pub(crate) fn create_paint(&mut self, paint: &Paint) -> &skia_safe::Paint {
-        self.paints.entry(paint.clone()).or_insert_with(|| {
-            let mut skia_paint = skia_safe::Paint::default();
-            skia_paint.set_anti_alias(true);
-
-            match paint.shader {
-                Shader::Solid(color) => {
-                    skia_paint.set_color4f(
-                        skia_safe::Color4f::new(color.r, color.g, color.b, color.a),
-                        None,
-                    );
-                }
+        if !self.paints.contains_key(paint) {
+            let skia_paint = self.build_paint(paint);
+            self.paints.insert(paint.clone(), skia_paint);
+        }
+
+        &self.paints[paint]
+    }
+
+    fn build_paint(&mut self, paint: &Paint) -> skia_safe::Paint {
+        let mut skia_paint = skia_safe::Paint::default();
+        skia_paint.set_anti_alias(true);
+        skia_paint.set_dither(paint.dither);
+
+        match paint.shader {
+            Shader::Solid(color) => {
+                skia_paint.set_color4f(
+                    skia_safe::Color4f::new(color.r, color.g, color.b, color.a),
+                    None,
+                );
             }
 
-            let blend = match paint.blend {
-                ike_core::Blend::Clear => skia_safe::BlendMode::Clear,
-                ike_core::Blend::Src => skia_safe::BlendMode::Src,
-                ike_core::Blend::Dst => skia_safe::BlendMode::Dst,
-                ike_core::Blend::SrcOver => skia_safe::BlendMode::SrcOver,
-                ike_core::Blend::DstOver => skia_safe::BlendMode::DstOver,
-                ike_core::Blend::SrcIn => skia_safe::BlendMode::SrcIn,
-                ike_core::Blend::DstIn => skia_safe::BlendMode::DstIn,
-                ike_core::Blend::SrcATop => skia_safe::BlendMode::SrcATop,
-                ike_core::Blend::DstATop => skia_safe::BlendMode::DstATop,
-            };
+            // shaders that fail to build, eg. gradients without stops, draw nothing
+            Shader::Gradient(ref gradient) => match Self::create_gradient(gradient) {
+                Some(shader) => {
+                    skia_paint.set_shader(shader);
+                }
 
-            skia_paint.set_blend_mode(blend);
+                None => {
+                    skia_paint.set_color(skia_safe::Color::TRANSPARENT);
+                }
+            },
 
-            skia_paint
-        })
+            Shader::Image(ref image) => match self.create_image_shader(image) {
+                Some(shader) => {
+                    skia_paint.set_shader(shader);
+                }
+
+                None => {
+                    skia_paint.set_color(skia_safe::Color::TRANSPARENT);
+                }
+            },
+        }
+
+        let blend = match paint.blend {
+            ike_core::Blend::Clear => skia_safe::BlendMode::Clear,
+            ike_core::Blend::Src => skia_safe::BlendMode::Src,
+            ike_core::Blend::Dst => skia_safe::BlendMode::Dst,
+            ike_core::Blend::SrcOver => skia_safe::BlendMode::SrcOver,
+            ike_core::Blend::DstOver => skia_safe::BlendMode::DstOver,
+            ike_core::Blend::SrcIn => skia_safe::BlendMode::SrcIn,
+            ike_core::Blend::DstIn => skia_safe::BlendMode::DstIn,
+            ike_core::Blend::SrcATop => skia_safe::BlendMode::SrcATop,
+            ike_core::Blend::DstATop => skia_safe::BlendMode::DstATop,
+        };
+
+        skia_paint.set_blend_mode(blend);
+
+        skia_paint
     }
 }
 
+/// An svg rasterized at one size and style, see [`SkiaPainter::rasterize_svg`].
+pub(crate) struct SvgRaster {
+    style: SvgStyle,
+    size:  (i32, i32),
+    image: skia_safe::Image,
+}
+
+fn load_svg(bytes: &[u8], manager: &skia_safe::FontMgr) -> Option<skia_safe::svg::Dom> {
+    // reuse font manager
+    let dom = skia_safe::svg::Dom::from_bytes(bytes, manager.clone()).ok()?;
+    let mut svg = dom.root();
+
+    if svg.intrinsic_size().is_zero() {
+        svg.set_height(skia_safe::svg::Length::new(
+            1.0,
+            skia_safe::svg::LengthUnit::PX,
+        ));
+        svg.set_width(skia_safe::svg::Length::new(
+            1.0,
+            skia_safe::svg::LengthUnit::PX,
+        ));
+    }
+
+    Some(dom)
+}
+
 impl Painter for SkiaPainter {
     fn measure_svg(&mut self, svg: &Svg) -> Size {
         if let Some(skia_dom) = self.create_svg(svg) {
@@ -268,30 +730,65 @@ impl Painter for SkiaPainter {
     }
 
     fn measure_text(&mut self, paragraph: &Paragraph, max_width: f32) -> Size {
-        let mut min_height = 0.0;
+        let key = shaping::text_key(paragraph, max_width);
+
+        if let Some(&size) = self.text_sizes.get(&key) {
+            return size;
+        }
 
-        if let Some((_, style)) = paragraph.sections().next() {
-            let typefaces = self.fonts.find_typefaces(
-                &[&style.font_family],
+        let min_height = match paragraph.sections().next() {
+            Some((_, style)) => shaping::min_line_height(
+                &mut self.fonts,
+                &style.font_family,
                 Self::create_font_style(style),
-            );
+                style.font_size,
+            ),
+
+            None => 0.0,
+        };
+
+        let skia = self.create_paragraph(paragraph, max_width);
 
-            if let Some(typeface) = typefaces.first() {
-                let font = skia_safe::Font::new(typeface, style.font_size);
-                let (_, metrics) = font.metrics();
+        let size = Size {
+            width:  skia.max_intrinsic_width(),
+            height: skia.height().max(min_height),
+        };
+
+        self.text_sizes.insert(key, size);
+        size
+    }
+
+    fn prepare_text(&mut self, paragraphs: &[(&Paragraph, f32)]) {
+        let mut jobs = Vec::new();
+
+        // only text that hasn't been measured is copied into jobs
+        for &(paragraph, max_width) in paragraphs {
+            let key = shaping::text_key(paragraph, max_width);
 
-                min_height = metrics.descent - metrics.ascent + metrics.leading;
+            if !self.text_sizes.contains_key(&key) {
+                jobs.push(TextJob::new(
+                    paragraph,
+                    max_width,
+                    &self.fallbacks,
+                ));
             }
         }
 
-        let paragraph = self.create_paragraph(paragraph, max_width);
+        if jobs.len() < Self::MIN_PARALLEL_TEXT {
+            return;
+        }
+
+        let _span = tracing::trace_span!("prepare_text", count = jobs.len()).entered();
 
-        Size {
-            width:  paragraph.max_intrinsic_width(),
-            height: paragraph.height().max(min_height),
+        for (key, size) in shaping::measure_parallel(&self.loaded, jobs) {
+            self.text_sizes.insert(key, size);
         }
     }
 
+    fn text_cache_stats(&self) -> Option<TextCacheStats> {
+        Some(self.paragraphs.stats())
+    }
+
     fn layout_text(
         &mut self,
         paragraph: &Paragraph,
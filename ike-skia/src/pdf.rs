@@ -0,0 +1,75 @@
+use std::io;
+
+use ike_core::{AnyWidgetId, Canvas, PageSetup, Painter, Printer, Size, World};
+
+use crate::{SkiaCanvas, SkiaPainter};
+
+/// A [`Printer`] that writes pages to a PDF document.
+pub struct PdfPrinter<'a> {
+    painter:  &'a mut SkiaPainter,
+    document: Option<skia_safe::pdf::Document<'a>>,
+}
+
+impl<'a> PdfPrinter<'a> {
+    pub fn new(painter: &'a mut SkiaPainter, output: &'a mut impl io::Write) -> Self {
+        Self {
+            painter,
+            document: Some(skia_safe::pdf::new_document(
+                output, None,
+            )),
+        }
+    }
+
+    /// Finish the document, writing the remaining contents to the output.
+    pub fn finish(mut self) {
+        if let Some(document) = self.document.take() {
+            document.close();
+        }
+    }
+}
+
+impl Printer for PdfPrinter<'_> {
+    fn painter(&mut self) -> &mut dyn Painter {
+        self.painter
+    }
+
+    fn page(&mut self, size: Size, f: &mut dyn FnMut(&mut dyn Canvas)) {
+        let Some(document) = self.document.take() else {
+            return;
+        };
+
+        let mut page = document.begin_page((size.width, size.height), None);
+
+        let mut canvas = SkiaCanvas {
+            surface: None,
+            painter: self.painter,
+            canvas:  page.canvas(),
+        };
+
+        f(&mut canvas);
+
+        self.document = Some(page.end_page());
+    }
+}
+
+/// Render `widget` to a PDF document, see [`World::print`].
+pub fn render_pdf(
+    world: &mut World,
+    widget: impl AnyWidgetId,
+    setup: &PageSetup,
+    painter: &mut SkiaPainter,
+) -> Vec<u8> {
+    let mut output = Vec::new();
+
+    let mut printer = PdfPrinter::new(painter, &mut output);
+    let pages = world.print(widget, setup, &mut printer);
+    printer.finish();
+
+    tracing::debug!(
+        pages,
+        bytes = output.len(),
+        "rendered pdf"
+    );
+
+    output
+}
@@ -0,0 +1,117 @@
+use ike_core::{AnyWidgetId, Canvas, PageSetup, Painter, Printer, Size, World};
+use skia_safe::{AlphaType, ColorType, ImageInfo};
+
+use crate::{SkiaCanvas, SkiaPainter};
+
+/// A page rendered by a [`RasterPrinter`], as rows of RGBA pixels.
+#[derive(Clone, Debug)]
+pub struct RasterPage {
+    pub width:  u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl RasterPage {
+    fn from_image(image: &skia_safe::Image) -> Option<Self> {
+        let width = image.width() as u32;
+        let height = image.height() as u32;
+
+        let info = ImageInfo::new(
+            (width as i32, height as i32),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+
+        let mut pixels = vec![0; width as usize * height as usize * 4];
+
+        let is_read = image.read_pixels(
+            &info,
+            &mut pixels,
+            width as usize * 4,
+            (0, 0),
+            skia_safe::image::CachingHint::Disallow,
+        );
+
+        is_read.then_some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+}
+
+/// A [`Printer`] that renders pages with the CPU rasterizer, for printers that don't take PDF
+/// documents.
+pub struct RasterPrinter<'a> {
+    painter: &'a mut SkiaPainter,
+    scale:   f32,
+    pages:   Vec<RasterPage>,
+}
+
+impl<'a> RasterPrinter<'a> {
+    /// Create a printer rendering pages with `scale` pixels per point.
+    pub fn new(painter: &'a mut SkiaPainter, scale: f32) -> Self {
+        Self {
+            painter,
+            scale,
+            pages: Vec::new(),
+        }
+    }
+
+    /// Finish printing, returning the rendered pages.
+    pub fn finish(self) -> Vec<RasterPage> {
+        self.pages
+    }
+}
+
+impl Printer for RasterPrinter<'_> {
+    fn painter(&mut self) -> &mut dyn Painter {
+        self.painter
+    }
+
+    fn page(&mut self, size: Size, f: &mut dyn FnMut(&mut dyn Canvas)) {
+        let width = (size.width * self.scale).ceil().max(1.0) as i32;
+        let height = (size.height * self.scale).ceil().max(1.0) as i32;
+
+        let Some(mut surface) = skia_safe::surfaces::raster_n32_premul((width, height)) else {
+            tracing::warn!(
+                width,
+                height,
+                "failed to create page surface"
+            );
+            return;
+        };
+
+        {
+            let canvas = surface.canvas();
+            canvas.scale((self.scale, self.scale));
+            canvas.clear(skia_safe::Color::WHITE);
+
+            let mut canvas = SkiaCanvas {
+                surface: None,
+                painter: self.painter,
+                canvas,
+            };
+
+            f(&mut canvas);
+        }
+
+        if let Some(page) = RasterPage::from_image(&surface.image_snapshot()) {
+            self.pages.push(page);
+        }
+    }
+}
+
+/// Render `widget` to pages of pixels, with `scale` pixels per point, see [`World::print`].
+pub fn render_pages(
+    world: &mut World,
+    widget: impl AnyWidgetId,
+    setup: &PageSetup,
+    painter: &mut SkiaPainter,
+    scale: f32,
+) -> Vec<RasterPage> {
+    let mut printer = RasterPrinter::new(painter, scale);
+    world.print(widget, setup, &mut printer);
+    printer.finish()
+}
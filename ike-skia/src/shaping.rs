@@ -0,0 +1,332 @@
+use std::{
+    cell::RefCell,
+    hash::{Hash, Hasher},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use ike_core::{Paragraph, Size, TextAlign, TextWrap};
+use rayon::prelude::*;
+
+use crate::{FontFallbacks, SkiaPainter};
+
+/// The fonts loaded with [`SkiaPainter::load_font`], kept so worker threads can load them too.
+#[derive(Clone, Debug)]
+pub(crate) struct LoadedFonts {
+    /// Unique to this set of fonts, across every painter.
+    pub generation: u64,
+    pub fonts:      Arc<Vec<(Arc<[u8]>, Option<String>)>>,
+}
+
+impl Default for LoadedFonts {
+    fn default() -> Self {
+        Self {
+            generation: next_generation(),
+            fonts:      Arc::new(Vec::new()),
+        }
+    }
+}
+
+impl LoadedFonts {
+    pub fn push(&mut self, bytes: &[u8], alias: Option<&str>) {
+        let font = (
+            Arc::from(bytes),
+            alias.map(String::from),
+        );
+
+        Arc::make_mut(&mut self.fonts).push(font);
+        self.generation = next_generation();
+    }
+
+    /// Remove the fonts loaded as `alias`, returning whether there were any.
+    pub fn remove(&mut self, alias: &str) -> bool {
+        let len = self.fonts.len();
+
+        Arc::make_mut(&mut self.fonts).retain(|(_, loaded)| loaded.as_deref() != Some(alias));
+
+        if self.fonts.len() == len {
+            return false;
+        }
+
+        self.generation = next_generation();
+        true
+    }
+
+    pub fn create_provider(
+        &self,
+        manager: &skia_safe::FontMgr,
+    ) -> skia_safe::textlayout::TypefaceFontProvider {
+        let provider = skia_safe::textlayout::TypefaceFontProvider::new();
+
+        for (bytes, alias) in self.fonts.iter() {
+            if let Some(typeface) = manager.new_from_data(bytes, None) {
+                provider.register_typeface(typeface, alias.as_deref());
+            }
+        }
+
+        provider
+    }
+
+    fn create_collection(&self) -> skia_safe::textlayout::FontCollection {
+        let manager = skia_safe::FontMgr::new();
+        let provider = self.create_provider(&manager);
+
+        let mut fonts = skia_safe::textlayout::FontCollection::new();
+        fonts.set_dynamic_font_manager(skia_safe::FontMgr::clone(&provider));
+        fonts.set_default_font_manager(manager, None);
+        fonts
+    }
+}
+
+fn next_generation() -> u64 {
+    static GENERATION: AtomicU64 = AtomicU64::new(0);
+    GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+thread_local! {
+    // the font collection of each worker thread, rebuilt when other fonts are shaped with
+    static FONTS: RefCell<Option<(u64, skia_safe::textlayout::FontCollection)>> =
+        const { RefCell::new(None) };
+}
+
+/// The start, font size, font families and font style of a section of a [`TextJob`].
+type TextSection = (
+    usize,
+    f32,
+    Vec<String>,
+    skia_safe::FontStyle,
+);
+
+/// A paragraph stripped of its paints, which don't affect shaping and can't be sent to other
+/// threads.
+pub(crate) struct TextJob {
+    pub key:       u64,
+    pub max_width: f32,
+
+    line_height: f32,
+    align:       TextAlign,
+    wrap:        TextWrap,
+    text:        String,
+    sections:    Vec<TextSection>,
+}
+
+impl TextJob {
+    pub fn new(paragraph: &Paragraph, max_width: f32, fallbacks: &FontFallbacks) -> Self {
+        let sections = paragraph
+            .sections()
+            .zip(&paragraph.sections)
+            .map(|((text, style), (start, _))| {
+                (
+                    *start,
+                    style.font_size,
+                    fallbacks.resolve(&style.font_family, text),
+                    SkiaPainter::create_font_style(style),
+                )
+            })
+            .collect();
+
+        Self {
+            key: text_key(paragraph, max_width),
+            max_width,
+
+            line_height: paragraph.line_height,
+            align: paragraph.align,
+            wrap: paragraph.wrap,
+            text: paragraph.text.clone(),
+            sections,
+        }
+    }
+
+    fn measure(&self, fonts: &mut skia_safe::textlayout::FontCollection) -> Size {
+        let style = paragraph_style(self.line_height, self.align, self.wrap);
+        let mut builder = skia_safe::textlayout::ParagraphBuilder::new(&style, &*fonts);
+
+        for (i, (start, font_size, font_families, font_style)) in self.sections.iter().enumerate() {
+            let end = match self.sections.get(i + 1) {
+                Some((end, ..)) => *end,
+                None => self.text.len(),
+            };
+
+            let mut skia_style = skia_safe::textlayout::TextStyle::new();
+
+            skia_style.set_subpixel(true);
+            skia_style.set_font_size(*font_size);
+            skia_style.set_font_families(font_families);
+            skia_style.set_font_style(*font_style);
+            set_variations(&mut skia_style, *font_style);
+
+            builder.push_style(&skia_style);
+            builder.add_text(&self.text[*start..end]);
+            builder.pop();
+        }
+
+        let mut paragraph = builder.build();
+        paragraph.layout(self.max_width);
+
+        let min_height = match self.sections.first() {
+            Some((_, font_size, font_families, font_style)) => min_line_height(
+                fonts,
+                &font_families[0],
+                *font_style,
+                *font_size,
+            ),
+
+            None => 0.0,
+        };
+
+        Size {
+            width:  paragraph.max_intrinsic_width(),
+            height: paragraph.height().max(min_height),
+        }
+    }
+}
+
+/// Measure `jobs` on the rayon thread pool, returning their keys and sizes.
+pub(crate) fn measure_parallel(fonts: &LoadedFonts, jobs: Vec<TextJob>) -> Vec<(u64, Size)> {
+    jobs.into_par_iter()
+        .map(|job| {
+            let size = FONTS.with_borrow_mut(|cached| {
+                if cached
+                    .as_ref()
+                    .is_none_or(|(generation, _)| *generation != fonts.generation)
+                {
+                    *cached = Some((
+                        fonts.generation,
+                        fonts.create_collection(),
+                    ));
+                }
+
+                let (_, collection) = cached.as_mut().expect("set just above");
+                job.measure(collection)
+            });
+
+            (job.key, size)
+        })
+        .collect()
+}
+
+/// The key measured text is memoized with, made from the text, its styles except their
+/// paints, and `max_width`.
+pub(crate) fn text_key(paragraph: &Paragraph, max_width: f32) -> u64 {
+    let mut hasher = seahash::SeaHasher::new();
+
+    paragraph.text.hash(&mut hasher);
+    paragraph.line_height.to_bits().hash(&mut hasher);
+    paragraph.align.hash(&mut hasher);
+    paragraph.wrap.hash(&mut hasher);
+    max_width.to_bits().hash(&mut hasher);
+
+    for (start, style) in &paragraph.sections {
+        start.hash(&mut hasher);
+        style.font_size.to_bits().hash(&mut hasher);
+        style.font_family.hash(&mut hasher);
+        style.font_weight.hash(&mut hasher);
+        style.font_stretch.hash(&mut hasher);
+        style.font_style.hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+/// The key shaped paragraphs are cached with, like [`text_key`] but with the paints, as skia
+/// draws paragraphs with the paints they were shaped with.
+pub(crate) fn paragraph_key(paragraph: &Paragraph, max_width: f32) -> u64 {
+    let mut hasher = seahash::SeaHasher::new();
+
+    text_key(paragraph, max_width).hash(&mut hasher);
+
+    for (_, style) in &paragraph.sections {
+        style.paint.hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+/// An estimate of the memory used by `paragraph` once shaped, skia doesn't report it.
+///
+/// Mostly the glyphs, their positions and clusters, and the runs and lines they're in.
+pub(crate) fn paragraph_bytes(paragraph: &Paragraph) -> u64 {
+    const BASE: u64 = 1024;
+    const PER_SECTION: u64 = 256;
+    const PER_BYTE: u64 = 48;
+
+    BASE + paragraph.sections.len() as u64 * PER_SECTION + paragraph.text.len() as u64 * PER_BYTE
+}
+
+pub(crate) fn paragraph_style(
+    line_height: f32,
+    align: TextAlign,
+    wrap: TextWrap,
+) -> skia_safe::textlayout::ParagraphStyle {
+    let mut style = skia_safe::textlayout::ParagraphStyle::new();
+
+    let align = match align {
+        TextAlign::Start => skia_safe::textlayout::TextAlign::Start,
+        TextAlign::Center => skia_safe::textlayout::TextAlign::Center,
+        TextAlign::End => skia_safe::textlayout::TextAlign::End,
+    };
+
+    style.set_height(line_height);
+    style.set_text_align(align);
+
+    if let TextWrap::None = wrap {
+        style.set_max_lines(1);
+    }
+
+    style
+}
+
+/// Set the weight and width axes of variable fonts to match `font_style`, fonts without the
+/// axes ignore them.
+pub(crate) fn set_variations(
+    skia_style: &mut skia_safe::textlayout::TextStyle,
+    font_style: skia_safe::FontStyle,
+) {
+    use skia_safe::font_arguments::{VariationPosition, variation_position::Coordinate};
+
+    // the widths of css, in percent of the normal width
+    const WIDTHS: [f32; 9] = [50.0, 62.5, 75.0, 87.5, 100.0, 112.5, 125.0, 150.0, 200.0];
+
+    let width = (*font_style.width()).clamp(1, 9) as usize;
+
+    let coordinates = [
+        Coordinate {
+            axis:  skia_safe::FourByteTag::from_chars('w', 'g', 'h', 't'),
+            value: *font_style.weight() as f32,
+        },
+        Coordinate {
+            axis:  skia_safe::FourByteTag::from_chars('w', 'd', 't', 'h'),
+            value: WIDTHS[width - 1],
+        },
+    ];
+
+    let position = VariationPosition {
+        coordinates: &coordinates,
+    };
+
+    let arguments = skia_safe::FontArguments::new().set_variation_design_position(position);
+    skia_style.set_font_arguments(&arguments);
+}
+
+/// The height of a line of the font, empty paragraphs are measured this high.
+pub(crate) fn min_line_height(
+    fonts: &mut skia_safe::textlayout::FontCollection,
+    font_family: &str,
+    font_style: skia_safe::FontStyle,
+    font_size: f32,
+) -> f32 {
+    let typefaces = fonts.find_typefaces(&[font_family], font_style);
+
+    match typefaces.first() {
+        Some(typeface) => {
+            let font = skia_safe::Font::new(typeface, font_size);
+            let (_, metrics) = font.metrics();
+
+            metrics.descent - metrics.ascent + metrics.leading
+        }
+
+        None => 0.0,
+    }
+}
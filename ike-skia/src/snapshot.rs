@@ -0,0 +1,381 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use ike_core::{WindowId, World};
+use skia_safe::{AlphaType, ColorType, Data, EncodedImageFormat, ImageInfo, images};
+
+use crate::{SkiaCanvas, SkiaPainter};
+
+/// Rewrites golden images instead of comparing against them when set to `1`.
+pub const UPDATE_SNAPSHOTS: &str = "IKE_UPDATE_SNAPSHOTS";
+
+/// The pixels of a window rendered offscreen, see [`Snapshot::render`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub width:  u32,
+    pub height: u32,
+
+    /// The unpremultiplied RGBA pixels, row by row.
+    pub pixels: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Render `window` with the CPU rasterizer, with `scale` pixels per logical pixel.
+    ///
+    /// The window is laid out with `painter` if it hasn't been laid out yet, widgets already
+    /// laid out with another painter, e.g. the headless one of a test harness, keep their sizes.
+    pub fn render(
+        world: &mut World,
+        window: WindowId,
+        painter: &mut SkiaPainter,
+        scale: f32,
+    ) -> Option<Self> {
+        let win = world.get_window(window)?;
+        let size = win.size();
+        let color = win.color().fade(win.opacity());
+
+        let width = (size.width * scale).ceil().max(1.0) as i32;
+        let height = (size.height * scale).ceil().max(1.0) as i32;
+
+        let mut surface = skia_safe::surfaces::raster_n32_premul((width, height))?;
+
+        {
+            let canvas = surface.canvas();
+            canvas.scale((scale, scale));
+            canvas.clear(skia_safe::Color4f::new(
+                color.r, color.g, color.b, color.a,
+            ));
+
+            let mut canvas = SkiaCanvas {
+                surface: None,
+                painter,
+                canvas,
+            };
+
+            world.draw(window, &mut canvas);
+        }
+
+        Self::from_image(&surface.image_snapshot())
+    }
+
+    /// Decode a PNG image.
+    pub fn decode_png(bytes: &[u8]) -> Option<Self> {
+        let image = skia_safe::Image::from_encoded(Data::new_copy(bytes))?;
+        Self::from_image(&image)
+    }
+
+    /// Encode the snapshot as a PNG image.
+    pub fn encode_png(&self) -> Option<Vec<u8>> {
+        let info = Self::image_info(self.width, self.height);
+        let data = Data::new_copy(&self.pixels);
+
+        let image = images::raster_from_data(&info, data, self.width as usize * 4)?;
+        let png = image.encode(None, EncodedImageFormat::PNG, None)?;
+
+        Some(png.as_bytes().to_vec())
+    }
+
+    /// Compare against `golden`, pixels differ when their perceived difference is more than
+    /// `threshold`, from `0.0` to `1.0`.
+    ///
+    /// Returns `None` when the sizes differ.
+    pub fn compare(&self, golden: &Snapshot, threshold: f32) -> Option<SnapshotDiff> {
+        if self.width != golden.width || self.height != golden.height {
+            return None;
+        }
+
+        let mut different = 0;
+        let mut image = Vec::with_capacity(self.pixels.len());
+
+        let pixels = self.pixels.chunks_exact(4);
+        let golden_pixels = golden.pixels.chunks_exact(4);
+
+        for (pixel, golden) in pixels.zip(golden_pixels) {
+            if difference(pixel, golden) > threshold {
+                different += 1;
+                image.extend_from_slice(&[255, 0, 0, 255]);
+            } else {
+                // unchanged pixels are faded, so the differences stand out
+                let luma = (luma(golden) * 0.1 + 0.9) * 255.0;
+                image.extend_from_slice(&[luma as u8, luma as u8, luma as u8, 255]);
+            }
+        }
+
+        Some(SnapshotDiff {
+            different,
+            total: self.width as usize * self.height as usize,
+            image: Snapshot {
+                width:  self.width,
+                height: self.height,
+                pixels: image,
+            },
+        })
+    }
+
+    fn from_image(image: &skia_safe::Image) -> Option<Self> {
+        let width = image.width() as u32;
+        let height = image.height() as u32;
+
+        let info = Self::image_info(width, height);
+        let mut pixels = vec![0; width as usize * height as usize * 4];
+
+        let is_read = image.read_pixels(
+            &info,
+            &mut pixels,
+            width as usize * 4,
+            (0, 0),
+            skia_safe::image::CachingHint::Disallow,
+        );
+
+        is_read.then_some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    fn image_info(width: u32, height: u32) -> ImageInfo {
+        ImageInfo::new(
+            (width as i32, height as i32),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        )
+    }
+}
+
+/// The pixels that differ between two snapshots, see [`Snapshot::compare`].
+#[derive(Clone, Debug)]
+pub struct SnapshotDiff {
+    pub different: usize,
+    pub total:     usize,
+
+    /// The compared snapshot faded, with the differing pixels in red.
+    pub image: Snapshot,
+}
+
+impl SnapshotDiff {
+    /// The fraction of the pixels that differ.
+    pub fn ratio(&self) -> f32 {
+        self.different as f32 / self.total.max(1) as f32
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("snapshot `{0}` has no golden image, it was written to `{1}`")]
+    Missing(String, PathBuf),
+
+    #[error("snapshot `{0}` is {1}x{2} but the golden image is {3}x{4}")]
+    Size(String, u32, u32, u32, u32),
+
+    #[error("snapshot `{name}` differs in {different} pixels, the difference is in `{diff}`")]
+    Different {
+        name:      String,
+        different: usize,
+        diff:      PathBuf,
+    },
+
+    #[error("snapshot `{0}` could not be rendered")]
+    Render(String),
+
+    #[error("golden image `{0}` could not be decoded")]
+    Decode(PathBuf),
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Compares windows against golden PNG images stored in a directory.
+///
+/// For a snapshot `name` the golden image is `<name>.png`. When it doesn't match, the
+/// rendered image is written next to it as `<name>.new.png` and the differences as
+/// `<name>.diff.png`. Setting [`UPDATE_SNAPSHOTS`] to `1` overwrites the golden images
+/// instead, e.g. with `cargo ike test --update-snapshots`.
+pub struct Snapshots {
+    pub dir: PathBuf,
+
+    /// The pixels per logical pixel snapshots are rendered with.
+    pub scale: f32,
+
+    /// How much pixels may differ before they are counted as different, see
+    /// [`Snapshot::compare`].
+    pub threshold: f32,
+
+    /// The fraction of the pixels that may differ before a snapshot fails.
+    pub tolerance: f32,
+
+    /// Whether the golden images are overwritten.
+    pub update: bool,
+
+    painter: SkiaPainter,
+}
+
+impl Snapshots {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir:       dir.into(),
+            scale:     1.0,
+            threshold: 0.1,
+            tolerance: 0.0,
+            update:    env::var(UPDATE_SNAPSHOTS).is_ok_and(|update| update == "1"),
+            painter:   SkiaPainter::new(),
+        }
+    }
+
+    /// Snapshots in `tests/snapshots` of the package being tested.
+    pub fn package() -> Self {
+        let root = env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+        Self::new(Path::new(&root).join("tests").join("snapshots"))
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// The painter snapshots are rendered with, load fonts with it so text renders the same
+    /// on every machine.
+    pub fn painter(&mut self) -> &mut SkiaPainter {
+        &mut self.painter
+    }
+
+    /// Render `window` and compare it against the golden image `name`.
+    pub fn check(
+        &mut self,
+        name: &str,
+        world: &mut World,
+        window: WindowId,
+    ) -> Result<(), SnapshotError> {
+        let snapshot = Snapshot::render(
+            world,
+            window,
+            &mut self.painter,
+            self.scale,
+        )
+        .ok_or_else(|| SnapshotError::Render(name.into()))?;
+
+        let golden_path = self.dir.join(format!("{name}.png"));
+        let new_path = self.dir.join(format!("{name}.new.png"));
+        let diff_path = self.dir.join(format!("{name}.diff.png"));
+
+        // stale results of earlier runs are removed, so only failures are left behind
+        let _ = fs::remove_file(&new_path);
+        let _ = fs::remove_file(&diff_path);
+
+        if self.update {
+            return write_png(&golden_path, &snapshot, name);
+        }
+
+        let golden = match fs::read(&golden_path) {
+            Ok(bytes) => Snapshot::decode_png(&bytes)
+                .ok_or_else(|| SnapshotError::Decode(golden_path.clone()))?,
+
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                write_png(&new_path, &snapshot, name)?;
+                return Err(SnapshotError::Missing(
+                    name.into(),
+                    new_path,
+                ));
+            }
+
+            Err(err) => return Err(err.into()),
+        };
+
+        let Some(diff) = snapshot.compare(&golden, self.threshold) else {
+            write_png(&new_path, &snapshot, name)?;
+
+            return Err(SnapshotError::Size(
+                name.into(),
+                snapshot.width,
+                snapshot.height,
+                golden.width,
+                golden.height,
+            ));
+        };
+
+        if diff.different > 0 && diff.ratio() > self.tolerance {
+            write_png(&new_path, &snapshot, name)?;
+            write_png(&diff_path, &diff.image, name)?;
+
+            return Err(SnapshotError::Different {
+                name:      name.into(),
+                different: diff.different,
+                diff:      diff_path,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Render `window` and compare it against the golden image `name`.
+    ///
+    /// # Panics
+    ///
+    /// When the snapshot doesn't match, see [`Snapshots::check`].
+    #[track_caller]
+    pub fn assert(&mut self, name: &str, world: &mut World, window: WindowId) {
+        if let Err(err) = self.check(name, world, window) {
+            panic!("{err}");
+        }
+    }
+}
+
+fn write_png(path: &Path, snapshot: &Snapshot, name: &str) -> Result<(), SnapshotError> {
+    let png = snapshot
+        .encode_png()
+        .ok_or_else(|| SnapshotError::Render(name.into()))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, png)?;
+    Ok(())
+}
+
+/// The perceived difference between two pixels from `0.0` to `1.0`, compared in the YIQ color
+/// space after blending them onto white.
+fn difference(a: &[u8], b: &[u8]) -> f32 {
+    let (ay, ai, aq) = yiq(a);
+    let (by, bi, bq) = yiq(b);
+
+    let (y, i, q) = (ay - by, ai - bi, aq - bq);
+    let delta = 0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q;
+
+    // the largest possible delta, between black and white
+    (delta / 0.5053).sqrt()
+}
+
+fn yiq(pixel: &[u8]) -> (f32, f32, f32) {
+    let alpha = pixel[3] as f32 / 255.0;
+    let blend = |c: u8| 1.0 + (c as f32 / 255.0 - 1.0) * alpha;
+
+    let (r, g, b) = (
+        blend(pixel[0]),
+        blend(pixel[1]),
+        blend(pixel[2]),
+    );
+
+    (
+        r * 0.29889531 + g * 0.58662247 + b * 0.11448223,
+        r * 0.59597799 - g * 0.27417610 - b * 0.32180189,
+        r * 0.21147017 - g * 0.52261711 + b * 0.31114694,
+    )
+}
+
+fn luma(pixel: &[u8]) -> f32 {
+    yiq(pixel).0
+}
@@ -0,0 +1,31 @@
+use ike_core::{AnyWidgetId, Builder, World};
+
+use crate::{SkiaCanvas, SkiaPainter};
+
+/// Render `widget` to an SVG document, see [`World::export`].
+pub fn render_svg(
+    world: &mut World,
+    widget: impl AnyWidgetId,
+    painter: &mut SkiaPainter,
+) -> String {
+    let Ok(size) = world
+        .get_widget(widget.upcast())
+        .map(|widget| widget.cx.size())
+    else {
+        return String::new();
+    };
+
+    let bounds = skia_safe::Rect::from_wh(size.width, size.height);
+    let svg = skia_safe::svg::Canvas::new(bounds, None);
+
+    let mut canvas = SkiaCanvas {
+        surface: None,
+        painter,
+        canvas: &svg,
+    };
+
+    world.export(widget, &mut canvas);
+
+    let data = svg.end();
+    String::from_utf8_lossy(data.as_bytes()).into_owned()
+}
@@ -0,0 +1,132 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ike_core::TextCacheStats;
+
+use crate::painter::SeaHasher;
+
+/// Shaped paragraphs keyed by their content, style and width, evicting the least recently used
+/// when more than the budget is used.
+pub(crate) struct TextCache {
+    entries: HashMap<u64, Entry, SeaHasher>,
+
+    /// The keys of the entries by when they were last used, oldest first.
+    used:   BTreeMap<u64, u64>,
+    tick:   u64,
+    bytes:  u64,
+    budget: u64,
+    hits:   u64,
+    misses: u64,
+}
+
+struct Entry {
+    paragraph: skia_safe::textlayout::Paragraph,
+    bytes:     u64,
+    used:      u64,
+}
+
+impl TextCache {
+    pub fn new(budget: u64) -> Self {
+        Self {
+            entries: HashMap::default(),
+            used: BTreeMap::new(),
+            tick: 0,
+            bytes: 0,
+            budget,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    pub fn budget(&self) -> u64 {
+        self.budget
+    }
+
+    pub fn set_budget(&mut self, budget: u64) {
+        self.budget = budget;
+        self.evict_to(budget);
+    }
+
+    pub fn stats(&self) -> TextCacheStats {
+        TextCacheStats {
+            entries: self.entries.len(),
+            bytes:   self.bytes,
+            budget:  self.budget,
+            hits:    self.hits,
+            misses:  self.misses,
+        }
+    }
+
+    /// Mark the paragraph of `key` as used, returning whether it's cached.
+    pub fn touch(&mut self, key: u64) -> bool {
+        let Some(entry) = self.entries.get_mut(&key) else {
+            self.misses += 1;
+            return false;
+        };
+
+        self.hits += 1;
+        self.tick += 1;
+
+        self.used.remove(&entry.used);
+        self.used.insert(self.tick, key);
+        entry.used = self.tick;
+
+        true
+    }
+
+    pub fn get_mut(&mut self, key: u64) -> Option<&mut skia_safe::textlayout::Paragraph> {
+        self.entries.get_mut(&key).map(|entry| &mut entry.paragraph)
+    }
+
+    /// Insert a paragraph estimated to use `bytes`, evicting others until it fits the budget.
+    ///
+    /// Paragraphs larger than the budget are still inserted, and evicted with the next.
+    pub fn insert(&mut self, key: u64, paragraph: skia_safe::textlayout::Paragraph, bytes: u64) {
+        self.remove(key);
+        self.evict_to(self.budget.saturating_sub(bytes));
+
+        self.tick += 1;
+        self.bytes += bytes;
+        self.used.insert(self.tick, key);
+
+        let entry = Entry {
+            paragraph,
+            bytes,
+            used: self.tick,
+        };
+
+        self.entries.insert(key, entry);
+    }
+
+    /// Evict the least recently used paragraphs until at most `bytes` are used.
+    pub fn evict_to(&mut self, bytes: u64) {
+        while self.bytes > bytes
+            && let Some((_, key)) = self.used.pop_first()
+        {
+            if let Some(entry) = self.entries.remove(&key) {
+                self.bytes -= entry.bytes;
+            }
+        }
+    }
+
+    /// Remove every paragraph, keeping the statistics.
+    pub fn clear(&mut self) {
+        self.entries = HashMap::default();
+        self.used.clear();
+        self.bytes = 0;
+    }
+
+    fn remove(&mut self, key: u64) {
+        if let Some(entry) = self.entries.remove(&key) {
+            self.used.remove(&entry.used);
+            self.bytes -= entry.bytes;
+        }
+    }
+}
@@ -1,10 +1,10 @@
-use std::{ptr, slice};
+use std::{ffi::c_char, ptr, slice, time::Duration};
 
 use ash::{
     LoadingError,
     vk::{self, Handle},
 };
-use ike_core::Color;
+use ike_core::{Color, MemoryPressure, Presentation};
 use raw_window_handle::{DisplayHandle, WindowHandle};
 
 use crate::{SkiaCanvas, SkiaPainter};
@@ -40,8 +40,24 @@ impl Drop for Context {
 impl Context {
     pub fn new(display: DisplayHandle) -> Result<Self> {
         let entry = unsafe { ash::Entry::load()? };
-        let raw_display = display.as_raw();
 
+        let extensions = ash_window::enumerate_required_extensions(display.as_raw())
+            .map_err(|_| Error::Runtime("failed querying required extensions"))?;
+
+        Self::with_extensions(entry, extensions)
+    }
+
+    /// Create a context for presenting directly to a display with `VK_KHR_display`, without a
+    /// display server, see [`Surface::new_display`].
+    pub fn new_direct() -> Result<Self> {
+        let entry = unsafe { ash::Entry::load()? };
+
+        let extensions = [vk::KHR_SURFACE_NAME.as_ptr(), vk::KHR_DISPLAY_NAME.as_ptr()];
+
+        Self::with_extensions(entry, &extensions)
+    }
+
+    fn with_extensions(entry: ash::Entry, extensions: &[*const c_char]) -> Result<Self> {
         let api_version = unsafe {
             entry
                 .try_enumerate_instance_version()
@@ -61,9 +77,6 @@ impl Context {
             .engine_name(c"skia")
             .api_version(api_version);
 
-        let extensions = ash_window::enumerate_required_extensions(raw_display)
-            .map_err(|_| Error::Runtime("failed querying required extensions"))?;
-
         let validation_layers = [c"VK_LAYER_KHRONOS_validation"];
         let validation_layer_names = validation_layers
             .into_iter()
@@ -146,6 +159,22 @@ impl Context {
     }
 }
 
+/// The GPU memory used by the resource cache of a [`Surface`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GpuMemoryUsage {
+    /// The number of cached resources.
+    pub resources: usize,
+
+    /// The bytes used by cached resources.
+    pub bytes: usize,
+
+    /// The bytes used by resources that could be released right now.
+    pub purgeable_bytes: usize,
+
+    /// The bytes the cache is allowed to use before releasing resources.
+    pub limit: usize,
+}
+
 pub struct Surface {
     entry:            ash::Entry,
     instance:         ash::Instance,
@@ -170,6 +199,9 @@ pub struct Surface {
     width:            u32,
     height:           u32,
     msaa:             bool,
+    presentation:     Presentation,
+    cache_limit:      usize,
+    memory_pressure:  MemoryPressure,
 }
 
 impl Drop for Surface {
@@ -229,6 +261,119 @@ impl Surface {
         width: u32,
         height: u32,
         hdr: bool,
+    ) -> Result<Self> {
+        let surface = unsafe {
+            ash_window::create_surface(
+                &context.entry,
+                &context.instance,
+                display.as_raw(),
+                window.as_raw(),
+                None,
+            )
+            .map_err(|_| Error::Runtime("failed creating window surface"))?
+        };
+
+        unsafe { Self::from_surface(context, surface, width, height, hdr) }
+    }
+
+    /// Create a surface covering the first connected display, using its preferred mode.
+    ///
+    /// `context` must have been created with [`Context::new_direct`], and no other process,
+    /// e.g. a display server, may be presenting to the display.
+    pub fn new_display(context: &mut Context, hdr: bool) -> Result<Self> {
+        let display = ash::khr::display::Instance::new(&context.entry, &context.instance);
+
+        let displays = unsafe {
+            display
+                .get_physical_device_display_properties(context.physical)
+                .map_err(|_| Error::Runtime("failed querying displays"))?
+        };
+
+        let properties = displays
+            .first()
+            .ok_or(Error::Runtime("no connected display"))?;
+
+        let modes = unsafe {
+            display
+                .get_display_mode_properties(context.physical, properties.display)
+                .map_err(|_| Error::Runtime("failed querying display modes"))?
+        };
+
+        // prefer the native resolution, and then the highest refresh rate
+        let mode = modes
+            .iter()
+            .max_by_key(|mode| {
+                let region = mode.parameters.visible_region;
+
+                (
+                    region.width == properties.physical_resolution.width
+                        && region.height == properties.physical_resolution.height,
+                    region.width * region.height,
+                    mode.parameters.refresh_rate,
+                )
+            })
+            .ok_or(Error::Runtime("display has no modes"))?;
+
+        let planes = unsafe {
+            display
+                .get_physical_device_display_plane_properties(context.physical)
+                .map_err(|_| Error::Runtime("failed querying display planes"))?
+        };
+
+        let plane_index = (0..planes.len() as u32)
+            .find(|&index| unsafe {
+                display
+                    .get_display_plane_supported_displays(context.physical, index)
+                    .is_ok_and(|displays| displays.contains(&properties.display))
+            })
+            .ok_or(Error::Runtime(
+                "no plane supports the display",
+            ))?;
+
+        let extent = mode.parameters.visible_region;
+
+        tracing::debug!(
+            width = extent.width,
+            height = extent.height,
+            refresh_rate = mode.parameters.refresh_rate,
+            plane = plane_index,
+            "creating vulkan display surface",
+        );
+
+        let surface_info = vk::DisplaySurfaceCreateInfoKHR::default()
+            .display_mode(mode.display_mode)
+            .plane_index(plane_index)
+            .plane_stack_index(planes[plane_index as usize].current_stack_index)
+            .transform(vk::SurfaceTransformFlagsKHR::IDENTITY)
+            .global_alpha(1.0)
+            .alpha_mode(vk::DisplayPlaneAlphaFlagsKHR::OPAQUE)
+            .image_extent(extent);
+
+        let surface = unsafe {
+            display
+                .create_display_plane_surface(&surface_info, None)
+                .map_err(|_| Error::Runtime("failed creating display surface"))?
+        };
+
+        unsafe {
+            Self::from_surface(
+                context,
+                surface,
+                extent.width,
+                extent.height,
+                hdr,
+            )
+        }
+    }
+
+    /// # Safety
+    /// - `surface` must have been created with the instance of `context`.
+    unsafe fn from_surface(
+        context: &mut Context,
+        surface: vk::SurfaceKHR,
+        width: u32,
+        height: u32,
+        hdr: bool,
     ) -> Result<Self> {
         let skia_context = unsafe {
             let get_proc = |gpo| match gpo {
@@ -265,17 +410,6 @@ impl Surface {
             ))?
         };
 
-        let surface = unsafe {
-            ash_window::create_surface(
-                &context.entry,
-                &context.instance,
-                display.as_raw(),
-                window.as_raw(),
-                None,
-            )
-            .map_err(|_| Error::Runtime("failed creating window surface"))?
-        };
-
         let instance = ash::khr::surface::Instance::new(&context.entry, &context.instance);
 
         let capabilities = unsafe {
@@ -344,6 +478,9 @@ impl Surface {
                 .map_err(|_| Error::Runtime("failed creating command pool"))?
         };
 
+        // the limit skia picked, restored when memory pressure is lifted
+        let cache_limit = skia_context.resource_cache_limit();
+
         let mut this = Self {
             entry: context.entry.clone(),
             instance: context.instance.clone(),
@@ -371,6 +508,9 @@ impl Surface {
                 target_os = "android",
                 target_os = "ios",
             )),
+            presentation: Presentation::default(),
+            cache_limit,
+            memory_pressure: MemoryPressure::None,
         };
 
         this.resize(width, height)?;
@@ -440,6 +580,73 @@ impl Surface {
         ))
     }
 
+    /// The size of the surface in physical pixels.
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Set the number of swapchain images and frames in flight, recreating the swapchain.
+    pub fn set_presentation(&mut self, presentation: Presentation) -> Result<()> {
+        if self.presentation == presentation {
+            return Ok(());
+        }
+
+        self.presentation = presentation;
+        self.resize(self.width, self.height)
+    }
+
+    pub fn gpu_memory_usage(&self) -> GpuMemoryUsage {
+        let usage = self.skia_context.resource_cache_usage();
+
+        GpuMemoryUsage {
+            resources:       usage.resource_count,
+            bytes:           usage.resource_bytes,
+            purgeable_bytes: self.skia_context.resource_cache_purgeable_bytes(),
+            limit:           self.skia_context.resource_cache_limit(),
+        }
+    }
+
+    /// Shrink the resource cache according to `pressure`, releasing what no longer fits.
+    pub fn set_memory_pressure(&mut self, pressure: MemoryPressure) {
+        let limit = match pressure {
+            MemoryPressure::None => self.cache_limit,
+            MemoryPressure::Moderate => self.cache_limit / 4,
+            MemoryPressure::Critical => self.cache_limit / 16,
+        };
+
+        self.memory_pressure = pressure;
+        self.skia_context.set_resource_cache_limit(limit);
+
+        match pressure {
+            MemoryPressure::None => {}
+
+            MemoryPressure::Moderate => {
+                (self.skia_context).purge_unlocked_resources(
+                    skia_safe::gpu::PurgeResourceOptions::ScratchResourcesOnly,
+                );
+            }
+
+            MemoryPressure::Critical => {
+                self.skia_context.free_gpu_resources();
+            }
+        }
+    }
+
+    /// How long a cached resource may go unused before it's released.
+    fn resource_idle_time(&self) -> Duration {
+        match self.memory_pressure {
+            MemoryPressure::None => Duration::from_secs(10),
+            MemoryPressure::Moderate => Duration::from_secs(3),
+            MemoryPressure::Critical => Duration::from_secs(1),
+        }
+    }
+
+    /// The number of frames that can be in flight, never more than there are images.
+    fn frames_in_flight(&self) -> u32 {
+        let images = self.swapchain_images.len() as u32;
+        (self.presentation.max_frames_in_flight).clamp(1, images.max(1))
+    }
+
     pub fn resize(&mut self, width: u32, height: u32) -> Result<()> {
         unsafe {
             self.device
@@ -453,9 +660,16 @@ impl Surface {
 
         unsafe { device.destroy_swapchain(self.swapchain, None) };
 
+        // a max image count of zero means there is no limit
+        let mut image_count = (self.presentation.buffers).max(self.capabilities.min_image_count);
+
+        if self.capabilities.max_image_count > 0 {
+            image_count = image_count.min(self.capabilities.max_image_count);
+        }
+
         let swapchain_info = vk::SwapchainCreateInfoKHR::default()
             .surface(self.surface)
-            .min_image_count(self.capabilities.min_image_count.max(2))
+            .min_image_count(image_count)
             .image_array_layers(1)
             .image_format(self.surface_format.format)
             .image_color_space(self.surface_format.color_space)
@@ -484,6 +698,9 @@ impl Surface {
         self.width = width;
         self.height = height;
 
+        // the image count or latency might have changed
+        self.current_frame %= self.frames_in_flight();
+
         if self.command_buffers.len() < self.swapchain_images.len() {
             let buffer_info = vk::CommandBufferAllocateInfo::default()
                 .command_pool(self.command_pool)
@@ -616,7 +833,7 @@ impl Surface {
 
             let output = {
                 let mut canvas = SkiaCanvas {
-                    surface: self,
+                    surface: Some(self),
                     painter,
                     canvas,
                 };
@@ -629,6 +846,7 @@ impl Surface {
             }
 
             self.skia_context.flush_and_submit();
+            (self.skia_context).perform_deferred_cleanup(self.resource_idle_time(), None);
 
             // record command buffer
             let begin_info = vk::CommandBufferBeginInfo::default()
@@ -746,7 +964,7 @@ impl Surface {
                 .queue_present(self.queue, &present_info)
                 .map_err(|_| Error::Runtime("failed presenting swapchain"))?;
 
-            self.current_frame = (self.current_frame + 1) % self.swapchain_images.len() as u32;
+            self.current_frame = (self.current_frame + 1) % self.frames_in_flight();
 
             Ok(Some(output))
         }
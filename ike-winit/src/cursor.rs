@@ -0,0 +1,107 @@
+use ike_core::{CursorGrab, Image, Point, WeakImage};
+use winit::{
+    event_loop::ActiveEventLoop,
+    window::{CursorGrabMode, CustomCursor, Window},
+};
+
+/// Custom cursors created from images, kept while their images are alive.
+#[derive(Default)]
+pub(crate) struct Cursors {
+    custom: Vec<(WeakImage, Point, CustomCursor)>,
+}
+
+impl Cursors {
+    pub(crate) fn set(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window: &Window,
+        cursor: ike_core::Cursor,
+    ) {
+        match cursor {
+            ike_core::Cursor::Icon(icon) => {
+                window.set_cursor(icon);
+                window.set_cursor_visible(true);
+            }
+
+            ike_core::Cursor::Image { image, hotspot } => {
+                if let Some(cursor) = self.custom(event_loop, &image, hotspot) {
+                    window.set_cursor(cursor);
+                }
+
+                window.set_cursor_visible(true);
+            }
+
+            ike_core::Cursor::Hidden => {
+                window.set_cursor_visible(false);
+            }
+        }
+    }
+
+    fn custom(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        image: &Image,
+        hotspot: Point,
+    ) -> Option<CustomCursor> {
+        self.custom.retain(|(image, ..)| image.strong_count() > 0);
+
+        let weak = Image::downgrade(image);
+
+        if let Some((.., cursor)) = (self.custom.iter())
+            .find(|(other, other_hotspot, _)| *other == weak && *other_hotspot == hotspot)
+        {
+            return Some(cursor.clone());
+        }
+
+        let source = CustomCursor::from_rgba(
+            image.pixels.clone(),
+            image.width as u16,
+            image.height as u16,
+            hotspot.x as u16,
+            hotspot.y as u16,
+        );
+
+        let source = match source {
+            Ok(source) => source,
+            Err(err) => {
+                tracing::warn!("invalid cursor image: {err}");
+                return None;
+            }
+        };
+
+        let cursor = event_loop.create_custom_cursor(source);
+        self.custom.push((weak, hotspot, cursor.clone()));
+
+        Some(cursor)
+    }
+}
+
+/// Grab the cursor, falling back to the other kind of grab where a platform only supports
+/// one, e.g. x11 can only confine and macos can only lock. Returns the grab that was set.
+pub(crate) fn set_grab(window: &Window, grab: CursorGrab) -> CursorGrab {
+    let fallback = match grab {
+        CursorGrab::None => CursorGrab::None,
+        CursorGrab::Confined => CursorGrab::Locked,
+        CursorGrab::Locked => CursorGrab::Confined,
+    };
+
+    if window.set_cursor_grab(grab_mode(grab)).is_ok() {
+        return grab;
+    }
+
+    match window.set_cursor_grab(grab_mode(fallback)) {
+        Ok(()) => fallback,
+        Err(err) => {
+            tracing::warn!("failed grabbing cursor: {err}");
+            CursorGrab::None
+        }
+    }
+}
+
+fn grab_mode(grab: CursorGrab) -> CursorGrabMode {
+    match grab {
+        CursorGrab::None => CursorGrabMode::None,
+        CursorGrab::Confined => CursorGrabMode::Confined,
+        CursorGrab::Locked => CursorGrabMode::Locked,
+    }
+}
@@ -0,0 +1,49 @@
+use std::{future::Future, path::PathBuf};
+
+use ike_core::{FileDialog, FileDialogKind};
+use rfd::{AsyncFileDialog, FileHandle};
+use winit::window::Window;
+
+/// Show `dialog` with the platform file dialog, modal to `parent`.
+pub(crate) fn show(
+    dialog: FileDialog,
+    parent: Option<&Window>,
+) -> impl Future<Output = Vec<PathBuf>> + Send + 'static {
+    let mut builder = AsyncFileDialog::new();
+
+    if let Some(title) = dialog.title {
+        builder = builder.set_title(title);
+    }
+
+    for filter in &dialog.filters {
+        builder = builder.add_filter(
+            &filter.name,
+            filter.extensions.as_slice(),
+        );
+    }
+
+    if let Some(directory) = dialog.directory {
+        builder = builder.set_directory(directory);
+    }
+
+    if let Some(parent) = parent {
+        builder = builder.set_parent(parent);
+    }
+
+    async move {
+        let files = match dialog.kind {
+            FileDialogKind::Open => builder.pick_file().await.into_iter().collect(),
+            FileDialogKind::OpenMany => builder.pick_files().await.unwrap_or_default(),
+            FileDialogKind::Folder => builder.pick_folder().await.into_iter().collect(),
+            FileDialogKind::Save { name } => {
+                if let Some(name) = name {
+                    builder = builder.set_file_name(name);
+                }
+
+                builder.save_file().await.into_iter().collect()
+            }
+        };
+
+        files.iter().map(FileHandle::path).map(Into::into).collect()
+    }
+}
@@ -1,4 +1,4 @@
-use ike_core::{Key, NamedKey};
+use ike_core::{Code, Key, Location, NamedKey};
 
 pub(crate) fn convert_winit_key(key: winit::keyboard::Key) -> Key {
     match key {
@@ -324,3 +324,221 @@ pub(crate) fn convert_winit_key(key: winit::keyboard::Key) -> Key {
         }
     }
 }
+
+/// Convert the physical key of a winit key event to a [`Code`].
+pub(crate) fn convert_winit_code(key: winit::keyboard::PhysicalKey) -> Code {
+    match key {
+        winit::keyboard::PhysicalKey::Unidentified(..) => Code::Unidentified,
+
+        winit::keyboard::PhysicalKey::Code(code) => {
+            use winit::keyboard::KeyCode::*;
+
+            match code {
+                Backquote => Code::Backquote,
+                Backslash => Code::Backslash,
+                BracketLeft => Code::BracketLeft,
+                BracketRight => Code::BracketRight,
+                Comma => Code::Comma,
+                Digit0 => Code::Digit0,
+                Digit1 => Code::Digit1,
+                Digit2 => Code::Digit2,
+                Digit3 => Code::Digit3,
+                Digit4 => Code::Digit4,
+                Digit5 => Code::Digit5,
+                Digit6 => Code::Digit6,
+                Digit7 => Code::Digit7,
+                Digit8 => Code::Digit8,
+                Digit9 => Code::Digit9,
+                Equal => Code::Equal,
+                IntlBackslash => Code::IntlBackslash,
+                IntlRo => Code::IntlRo,
+                IntlYen => Code::IntlYen,
+                KeyA => Code::KeyA,
+                KeyB => Code::KeyB,
+                KeyC => Code::KeyC,
+                KeyD => Code::KeyD,
+                KeyE => Code::KeyE,
+                KeyF => Code::KeyF,
+                KeyG => Code::KeyG,
+                KeyH => Code::KeyH,
+                KeyI => Code::KeyI,
+                KeyJ => Code::KeyJ,
+                KeyK => Code::KeyK,
+                KeyL => Code::KeyL,
+                KeyM => Code::KeyM,
+                KeyN => Code::KeyN,
+                KeyO => Code::KeyO,
+                KeyP => Code::KeyP,
+                KeyQ => Code::KeyQ,
+                KeyR => Code::KeyR,
+                KeyS => Code::KeyS,
+                KeyT => Code::KeyT,
+                KeyU => Code::KeyU,
+                KeyV => Code::KeyV,
+                KeyW => Code::KeyW,
+                KeyX => Code::KeyX,
+                KeyY => Code::KeyY,
+                KeyZ => Code::KeyZ,
+                Minus => Code::Minus,
+                Period => Code::Period,
+                Quote => Code::Quote,
+                Semicolon => Code::Semicolon,
+                Slash => Code::Slash,
+                AltLeft => Code::AltLeft,
+                AltRight => Code::AltRight,
+                Backspace => Code::Backspace,
+                CapsLock => Code::CapsLock,
+                ContextMenu => Code::ContextMenu,
+                ControlLeft => Code::ControlLeft,
+                ControlRight => Code::ControlRight,
+                Enter => Code::Enter,
+                SuperLeft => Code::MetaLeft,
+                SuperRight => Code::MetaRight,
+                ShiftLeft => Code::ShiftLeft,
+                ShiftRight => Code::ShiftRight,
+                Space => Code::Space,
+                Tab => Code::Tab,
+                Convert => Code::Convert,
+                KanaMode => Code::KanaMode,
+                Lang1 => Code::Lang1,
+                Lang2 => Code::Lang2,
+                Lang3 => Code::Lang3,
+                Lang4 => Code::Lang4,
+                Lang5 => Code::Lang5,
+                NonConvert => Code::NonConvert,
+                Delete => Code::Delete,
+                End => Code::End,
+                Help => Code::Help,
+                Home => Code::Home,
+                Insert => Code::Insert,
+                PageDown => Code::PageDown,
+                PageUp => Code::PageUp,
+                ArrowDown => Code::ArrowDown,
+                ArrowLeft => Code::ArrowLeft,
+                ArrowRight => Code::ArrowRight,
+                ArrowUp => Code::ArrowUp,
+                NumLock => Code::NumLock,
+                Numpad0 => Code::Numpad0,
+                Numpad1 => Code::Numpad1,
+                Numpad2 => Code::Numpad2,
+                Numpad3 => Code::Numpad3,
+                Numpad4 => Code::Numpad4,
+                Numpad5 => Code::Numpad5,
+                Numpad6 => Code::Numpad6,
+                Numpad7 => Code::Numpad7,
+                Numpad8 => Code::Numpad8,
+                Numpad9 => Code::Numpad9,
+                NumpadAdd => Code::NumpadAdd,
+                NumpadBackspace => Code::NumpadBackspace,
+                NumpadClear => Code::NumpadClear,
+                NumpadClearEntry => Code::NumpadClearEntry,
+                NumpadComma => Code::NumpadComma,
+                NumpadDecimal => Code::NumpadDecimal,
+                NumpadDivide => Code::NumpadDivide,
+                NumpadEnter => Code::NumpadEnter,
+                NumpadEqual => Code::NumpadEqual,
+                NumpadHash => Code::NumpadHash,
+                NumpadMemoryAdd => Code::NumpadMemoryAdd,
+                NumpadMemoryClear => Code::NumpadMemoryClear,
+                NumpadMemoryRecall => Code::NumpadMemoryRecall,
+                NumpadMemoryStore => Code::NumpadMemoryStore,
+                NumpadMemorySubtract => Code::NumpadMemorySubtract,
+                NumpadMultiply => Code::NumpadMultiply,
+                NumpadParenLeft => Code::NumpadParenLeft,
+                NumpadParenRight => Code::NumpadParenRight,
+                NumpadStar => Code::NumpadStar,
+                NumpadSubtract => Code::NumpadSubtract,
+                Escape => Code::Escape,
+                Fn => Code::Fn,
+                FnLock => Code::FnLock,
+                PrintScreen => Code::PrintScreen,
+                ScrollLock => Code::ScrollLock,
+                Pause => Code::Pause,
+                BrowserBack => Code::BrowserBack,
+                BrowserFavorites => Code::BrowserFavorites,
+                BrowserForward => Code::BrowserForward,
+                BrowserHome => Code::BrowserHome,
+                BrowserRefresh => Code::BrowserRefresh,
+                BrowserSearch => Code::BrowserSearch,
+                BrowserStop => Code::BrowserStop,
+                Eject => Code::Eject,
+                LaunchApp1 => Code::LaunchApp1,
+                LaunchApp2 => Code::LaunchApp2,
+                LaunchMail => Code::LaunchMail,
+                MediaPlayPause => Code::MediaPlayPause,
+                MediaSelect => Code::MediaSelect,
+                MediaStop => Code::MediaStop,
+                MediaTrackNext => Code::MediaTrackNext,
+                MediaTrackPrevious => Code::MediaTrackPrevious,
+                Power => Code::Power,
+                Sleep => Code::Sleep,
+                AudioVolumeDown => Code::AudioVolumeDown,
+                AudioVolumeMute => Code::AudioVolumeMute,
+                AudioVolumeUp => Code::AudioVolumeUp,
+                WakeUp => Code::WakeUp,
+                Hyper => Code::Hyper,
+                Turbo => Code::Turbo,
+                Abort => Code::Abort,
+                Resume => Code::Resume,
+                Suspend => Code::Suspend,
+                Again => Code::Again,
+                Copy => Code::Copy,
+                Cut => Code::Cut,
+                Find => Code::Find,
+                Open => Code::Open,
+                Paste => Code::Paste,
+                Props => Code::Props,
+                Select => Code::Select,
+                Undo => Code::Undo,
+                Hiragana => Code::Hiragana,
+                Katakana => Code::Katakana,
+                F1 => Code::F1,
+                F2 => Code::F2,
+                F3 => Code::F3,
+                F4 => Code::F4,
+                F5 => Code::F5,
+                F6 => Code::F6,
+                F7 => Code::F7,
+                F8 => Code::F8,
+                F9 => Code::F9,
+                F10 => Code::F10,
+                F11 => Code::F11,
+                F12 => Code::F12,
+                F13 => Code::F13,
+                F14 => Code::F14,
+                F15 => Code::F15,
+                F16 => Code::F16,
+                F17 => Code::F17,
+                F18 => Code::F18,
+                F19 => Code::F19,
+                F20 => Code::F20,
+                F21 => Code::F21,
+                F22 => Code::F22,
+                F23 => Code::F23,
+                F24 => Code::F24,
+                F25 => Code::F25,
+                F26 => Code::F26,
+                F27 => Code::F27,
+                F28 => Code::F28,
+                F29 => Code::F29,
+                F30 => Code::F30,
+                F31 => Code::F31,
+                F32 => Code::F32,
+                F33 => Code::F33,
+                F34 => Code::F34,
+                F35 => Code::F35,
+                Meta => Code::Super,
+                _ => Code::Unidentified,
+            }
+        }
+    }
+}
+
+pub(crate) fn convert_winit_location(location: winit::keyboard::KeyLocation) -> Location {
+    match location {
+        winit::keyboard::KeyLocation::Standard => Location::Standard,
+        winit::keyboard::KeyLocation::Left => Location::Left,
+        winit::keyboard::KeyLocation::Right => Location::Right,
+        winit::keyboard::KeyLocation::Numpad => Location::Numpad,
+    }
+}
@@ -1,33 +1,37 @@
 #![warn(clippy::unwrap_used)]
 
-use std::{
-    io,
-    pin::Pin,
-    sync::{Arc, mpsc::Receiver},
-    time::Instant,
-};
+use std::{io, sync::mpsc::Receiver};
 
 use clipboard_rs::{Clipboard, ClipboardContext};
 use ike_core::{
-    Modifiers, Offset, Point, PointerButton, PointerId, ScrollDelta, Signal, Size, WindowSizing,
-    WindowUpdate, World,
+    Builder, ColorScheme, CursorGrab, FileDialog, FileDialogResponder, ImeSignal, KeyInput,
+    MemoryPressure, Modifiers, Offset, PageSetup, Point, PointerButton, PointerId, ResizeEdge,
+    ScrollDelta, SeatId, Size, Tool, ToolKind, TouchId, WidgetId, WindowLevel, WindowSizing,
+    WindowUpdate,
 };
+use ike_shell::{Driver, Shell, ShellEvent, ShellProxy};
 use ike_skia::{SkiaPainter, vulkan::Surface};
-use ori::{AnyState, AnyView, Proxy as _, View};
+use ori::Proxy as _;
 use winit::{
     application::ApplicationHandler,
-    dpi::LogicalSize,
+    dpi::{LogicalPosition, LogicalSize},
     error::{EventLoopError, OsError},
-    event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent},
+    event::{
+        DeviceEvent, DeviceId, ElementState, Force, Ime, MouseButton, MouseScrollDelta, TouchPhase,
+        WindowEvent,
+    },
     event_loop::{ActiveEventLoop, EventLoop},
     raw_window_handle::{HandleError, HasDisplayHandle, HasWindowHandle},
-    window::{Window, WindowId},
+    window::{ResizeDirection, Window, WindowId},
 };
 
-use crate::proxy::Proxy;
-
+mod cursor;
+mod dialog;
 mod key;
-mod proxy;
+mod motion;
+mod owner;
+mod print;
+mod touchpad;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -63,6 +67,11 @@ pub fn run<T>(
         None
     };
 
+    let animation = settings
+        .animation
+        .unwrap_or_else(motion::animation_settings);
+    ike_core::AnimationSettings::set(animation);
+
     let runtime = tokio::runtime::Handle::current();
     let event_loop = EventLoop::with_user_event().build()?;
     let display_handle = event_loop.display_handle()?;
@@ -75,90 +84,75 @@ pub fn run<T>(
     );
 
     let (sender, receiver) = std::sync::mpsc::channel();
-    let proxy = Proxy::new(
-        sender.clone(),
-        event_loop.create_proxy(),
-    );
+    let proxy = ShellProxy::new({
+        let proxy = event_loop.create_proxy();
 
-    let signaller = Box::new(move |signal| {
-        let _ = sender.send(Event::Signal(signal));
+        move |event| {
+            let _ = sender.send(event);
+            let _ = proxy.send_event(());
+        }
     });
 
-    let context = ike_ori::Context {
-        world:     World::new(signaller, settings),
-        proxy:     Arc::new(proxy),
-        resources: ike_ori::Resources::new(),
-    };
-
     let mut state = AppState {
-        data,
-
-        build,
-        state: None,
-
-        runtime,
+        shell: Shell::new(
+            data, build, settings, painter, proxy, runtime,
+        ),
         receiver,
 
         clipboard: ClipboardContext::new().map_err(Error::Clipboard)?,
-        painter,
+        cursors: cursor::Cursors::default(),
         windows: Vec::new(),
 
-        context,
-
         vulkan,
         result: Ok(()),
     };
 
+    let direction = ike_shell::system_layout_direction();
+    state.shell.world().layout_direction_changed(direction);
+    state.shell.locales_changed(&ike_shell::system_locales());
+
     event_loop.run_app(&mut state)?;
 
     state.result
 }
 
-enum Event {
-    Rebuild,
-    Event(ori::Event),
-    Spawn(Pin<Box<dyn Future<Output = ()> + Send>>),
-    Signal(Signal),
-}
-
 struct AppState<'a, T> {
-    data: &'a mut T,
+    shell:    Shell<'a, T>,
+    receiver: Receiver<ShellEvent>,
 
-    build: ike_ori::UiBuilder<T>,
-    state: Option<AnyState<ike_ori::Context, T, ori::NoElement>>,
-
-    runtime:  tokio::runtime::Handle,
-    receiver: Receiver<Event>,
-
-    context:   ike_ori::Context,
     clipboard: ClipboardContext,
+    cursors:   cursor::Cursors,
 
     windows: Vec<WindowState>,
     result:  Result<(), Error>,
 
-    painter: SkiaPainter,
-    vulkan:  ike_skia::vulkan::Context,
+    vulkan: ike_skia::vulkan::Context,
 }
 
-struct WindowState {
-    animate: Option<Instant>,
+/// The [`Driver`] for the duration of a single event loop callback.
+struct WinitDriver<'s, 'a, T> {
+    app:        &'s mut AppState<'a, T>,
+    event_loop: &'s ActiveEventLoop,
+}
 
+struct WindowState {
     surface: Surface,
 
-    id:     ike_core::WindowId,
-    window: Window,
+    id:       ike_core::WindowId,
+    window:   Window,
+    touchpad: touchpad::Touchpad,
+
+    /// The grab of the cursor, as supported by the platform.
+    cursor_grab: CursorGrab,
 }
 
 impl<T> ApplicationHandler for AppState<'_, T> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        if self.state.is_some() {
+        if self.shell.is_built() {
             return;
         }
 
-        let view = (self.build)(self.data);
-        let (_, state) = view.build(&mut self.context, self.data);
-
-        self.state = Some(state);
+        self.shell.build();
         self.handle_events(event_loop);
     }
 
@@ -180,9 +174,7 @@ impl<T> ApplicationHandler for AppState<'_, T> {
 
         match event {
             WindowEvent::RedrawRequested => {
-                if let Some(animate) = window.animate.take() {
-                    let delta_time = animate.elapsed();
-                    self.context.world.animate(window.id, delta_time);
+                if self.shell.animate(window.id) {
                     self.handle_events(event_loop);
                 }
 
@@ -191,17 +183,17 @@ impl<T> ApplicationHandler for AppState<'_, T> {
                     return;
                 };
 
-                let Some(desc) = self.context.world.get_window(window.id) else {
+                let Some(desc) = self.shell.context.world.get_window(window.id) else {
                     tracing::error!("window redraw request before it has been created");
                     return;
                 };
 
                 let Ok(new_window_size) = window.surface.draw(
-                    &mut self.painter,
-                    desc.color(),
+                    &mut self.shell.painter,
+                    desc.color().fade(desc.opacity()),
                     window.window.scale_factor() as f32,
                     || window.window.pre_present_notify(),
-                    |canvas| self.context.world.draw(window.id, canvas),
+                    |canvas| self.shell.context.world.draw(window.id, canvas),
                 ) else {
                     tracing::error!("drawing failed");
                     return;
@@ -227,48 +219,74 @@ impl<T> ApplicationHandler for AppState<'_, T> {
                 let scale = scale as f32;
                 let size = Size::new(size.width, size.height);
 
+                let is_maximized = window.window.is_maximized();
+                (self.shell.context.world).window_maximized(window.id, is_maximized);
+
                 match event {
                     WindowEvent::Resized(..) => {
-                        self.context.world.window_resized(window.id, size);
+                        self.shell.context.world.window_resized(window.id, size);
                     }
 
                     WindowEvent::ScaleFactorChanged { .. } => {
-                        self.context.world.window_scaled(window.id, size, scale);
+                        (self.shell.context.world).window_scaled(window.id, size, scale);
                     }
 
                     _ => unreachable!(),
                 }
+
+                // popups fitting their content are only sized after they're first drawn
+                let id = window.id;
+                self.place_popup(id);
+            }
+
+            WindowEvent::Moved(position) => {
+                let scale = window.window.scale_factor();
+                let position = position.to_logical::<f32>(scale);
+                let position = Point::new(position.x, position.y);
+
+                (self.shell.context.world).window_moved(window.id, position);
             }
 
             WindowEvent::Focused(is_focused) => {
-                self.context.world.window_focused(window.id, is_focused);
+                (self.shell.context.world).window_focused(window.id, is_focused);
+            }
+
+            WindowEvent::Occluded(is_occluded) => {
+                (self.shell.context.world).window_occluded(window.id, is_occluded);
             }
 
             WindowEvent::CursorEntered { device_id } => {
                 let pointer_id = PointerId::from_hash(device_id);
-                self.context.world.pointer_entered(window.id, pointer_id);
+                (self.shell.context.world).pointer_entered(window.id, pointer_id);
             }
 
             WindowEvent::CursorLeft { device_id } => {
                 let pointer_id = PointerId::from_hash(device_id);
-                self.context.world.pointer_left(window.id, pointer_id);
+                self.shell.context.world.pointer_left(window.id, pointer_id);
             }
 
+            // a locked cursor is moved by the motion of the device instead, see `device_event`
             WindowEvent::CursorMoved {
                 device_id,
                 position,
-            } => {
+            } if window.cursor_grab != CursorGrab::Locked => {
                 let pointer_id = PointerId::from_hash(device_id);
                 let position = position.to_logical(window.window.scale_factor());
                 let position = Point::new(position.x, position.y);
 
-                self.context
-                    .world
-                    .pointer_moved(window.id, pointer_id, position);
+                window.touchpad.cursor_moved(position);
+                (self.shell.context.world).pointer_moved(
+                    window.id,
+                    pointer_id,
+                    position,
+                    Tool::MOUSE,
+                );
             }
 
             WindowEvent::MouseWheel {
-                device_id, delta, ..
+                device_id,
+                delta,
+                phase,
             } => {
                 let pointer_id = PointerId::from_hash(device_id);
                 let delta = match delta {
@@ -279,9 +297,56 @@ impl<T> ApplicationHandler for AppState<'_, T> {
                     ),
                 };
 
-                self.context
-                    .world
-                    .pointer_scrolled(window.id, pointer_id, delta);
+                let world = &mut self.shell.context.world;
+
+                // pixel deltas come from touchpads, which fling when released
+                if let ScrollDelta::Pixel(offset) = delta {
+                    window.touchpad.scrolled(
+                        world, window.id, device_id, offset, phase,
+                    );
+                }
+
+                world.pointer_scrolled(window.id, pointer_id, delta);
+            }
+
+            WindowEvent::PinchGesture {
+                device_id,
+                delta,
+                phase,
+            } => {
+                let world = &mut self.shell.context.world;
+                window.touchpad.pinch(
+                    world, window.id, device_id, delta, phase,
+                );
+            }
+
+            WindowEvent::RotationGesture {
+                device_id,
+                delta,
+                phase,
+            } => {
+                let world = &mut self.shell.context.world;
+                window.touchpad.rotate(
+                    world, window.id, device_id, delta, phase,
+                );
+            }
+
+            WindowEvent::PanGesture {
+                device_id,
+                delta,
+                phase,
+            } => {
+                let delta = Offset::new(delta.x, delta.y) / window.window.scale_factor() as f32;
+
+                let world = &mut self.shell.context.world;
+                window.touchpad.pan(
+                    world, window.id, device_id, delta, phase,
+                );
+            }
+
+            WindowEvent::DoubleTapGesture { device_id } => {
+                let world = &mut self.shell.context.world;
+                window.touchpad.double_tap(world, window.id, device_id);
             }
 
             WindowEvent::MouseInput {
@@ -300,11 +365,36 @@ impl<T> ApplicationHandler for AppState<'_, T> {
                     MouseButton::Other(i) => PointerButton::Other(i),
                 };
 
-                (self.context.world).pointer_pressed(window.id, pointer_id, button, pressed);
+                (self.shell.context.world).pointer_pressed(window.id, pointer_id, button, pressed);
+            }
+
+            WindowEvent::Touch(touch) => {
+                let touch_id = TouchId::from_hash((touch.device_id, touch.id));
+                let position = touch.location.to_logical(window.window.scale_factor());
+                let position = Point::new(position.x, position.y);
+                let tool = touch_tool(touch.force);
+
+                let world = &mut self.shell.context.world;
+
+                match touch.phase {
+                    TouchPhase::Started => {
+                        world.touch_down(window.id, touch_id, position, tool);
+                    }
+
+                    TouchPhase::Moved => {
+                        world.touch_move(window.id, touch_id, position, tool);
+                    }
+
+                    // a cancelled touch is released where it was last seen, so
+                    // gestures in progress are always ended
+                    TouchPhase::Ended | TouchPhase::Cancelled => {
+                        world.touch_up(window.id, touch_id, position, tool);
+                    }
+                }
             }
 
             WindowEvent::KeyboardInput { event, .. } => {
-                let action_mod = match self.context.world.get_window(window.id) {
+                let action_mod = match self.shell.context.world.get_window(window.id) {
                     Some(window) if cfg!(target_os = "macos") => window.modifiers().meta(),
                     Some(window) => window.modifiers().ctrl(),
                     None => false,
@@ -322,19 +412,39 @@ impl<T> ApplicationHandler for AppState<'_, T> {
                     ))
                 {
                     if let Ok(text) = self.clipboard.get_text() {
-                        self.context.world.text_pasted(window.id, text);
+                        self.shell.context.world.text_pasted(window.id, text);
                     }
                 } else {
-                    self.context.world.key_pressed(
+                    let input = KeyInput {
+                        key:      key::convert_winit_key(event.logical_key),
+                        code:     key::convert_winit_code(event.physical_key),
+                        location: key::convert_winit_location(event.location),
+                        text:     event.text.map(String::from),
+                        repeat:   event.repeat,
+                    };
+
+                    (self.shell.context.world).seat_key_pressed(
                         window.id,
-                        key::convert_winit_key(event.logical_key),
-                        event.repeat,
-                        event.text.as_deref(),
+                        SeatId::PRIMARY,
+                        input,
                         event.state.is_pressed(),
                     );
                 }
             }
 
+            WindowEvent::Ime(ime) => match ime {
+                Ime::Enabled | Ime::Disabled => {}
+
+                Ime::Preedit(text, cursor) => {
+                    let cursor = cursor.map(|(start, end)| start..end);
+                    (self.shell.context.world).ime_preedit(window.id, text, cursor);
+                }
+
+                Ime::Commit(text) => {
+                    self.shell.context.world.ime_commit_text(window.id, text);
+                }
+            },
+
             WindowEvent::ModifiersChanged(mods) => {
                 let mut modifiers = Modifiers::empty();
 
@@ -354,7 +464,15 @@ impl<T> ApplicationHandler for AppState<'_, T> {
                     modifiers |= Modifiers::META;
                 }
 
-                self.context.world.modifiers_changed(window.id, modifiers);
+                (self.shell.context.world).modifiers_changed(window.id, modifiers);
+            }
+
+            WindowEvent::ThemeChanged(theme) => {
+                let color_scheme = Some(color_scheme(theme));
+
+                if self.shell.context.world.color_scheme_changed(color_scheme) {
+                    self.shell.context.proxy.rebuild();
+                }
             }
 
             WindowEvent::CloseRequested => {
@@ -367,143 +485,401 @@ impl<T> ApplicationHandler for AppState<'_, T> {
         self.handle_events(event_loop);
     }
 
+    fn device_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        device_id: DeviceId,
+        event: DeviceEvent,
+    ) {
+        // a locked cursor stays in place, so the pointer is moved by the motion of the device
+        if let DeviceEvent::MouseMotion { delta } = event
+            && let Some(window) = (self.windows.iter_mut())
+                .find(|w| w.cursor_grab == CursorGrab::Locked && w.window.has_focus())
+        {
+            let delta = Offset::new(delta.0 as f32, delta.1 as f32);
+            let position = window.touchpad.cursor() + delta / window.window.scale_factor() as f32;
+
+            window.touchpad.cursor_moved(position);
+            (self.shell.context.world).pointer_moved(
+                window.id,
+                PointerId::from_hash(device_id),
+                position,
+                Tool::MOUSE,
+            );
+
+            self.handle_events(event_loop);
+        }
+    }
+
     fn user_event(&mut self, event_loop: &ActiveEventLoop, _event: ()) {
         self.handle_events(event_loop);
     }
 
     fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
-        self.painter.cleanup();
+        self.shell.painter.cleanup();
+    }
+
+    fn memory_warning(&mut self, _event_loop: &ActiveEventLoop) {
+        // the warning is a one-off without any follow-up when memory is available again, so
+        // release what we can and let the caches grow back afterwards
+        for pressure in [MemoryPressure::Critical, MemoryPressure::None] {
+            self.shell.memory_pressure_changed(pressure);
+
+            for window in &mut self.windows {
+                window.surface.set_memory_pressure(pressure);
+            }
+        }
     }
 }
 
 impl<T> AppState<'_, T> {
     fn handle_events(&mut self, event_loop: &ActiveEventLoop) {
         while let Ok(event) = self.receiver.try_recv() {
-            if let Err(error) = self.handle_event(event_loop, event) {
+            let mut driver = WinitDriver {
+                app: self,
+                event_loop,
+            };
+
+            if let Err(error) = driver.handle_event(event) {
                 tracing::error!("{error}");
             }
         }
     }
 
-    fn handle_event(&mut self, event_loop: &ActiveEventLoop, event: Event) -> Result<(), Error> {
-        match event {
-            Event::Rebuild => {
-                tracing::trace!("view rebuild");
+    fn window_mut(&mut self, id: ike_core::WindowId) -> Option<&mut WindowState> {
+        self.windows.iter_mut().find(|w| w.id == id)
+    }
 
-                if let Some(ref mut state) = self.state {
-                    let view = (self.build)(self.data);
-                    view.rebuild((), state, &mut self.context, self.data);
-                }
-            }
+    /// Place the popup `id` next to its anchor, see [`ike_core::Window::popup`].
+    fn place_popup(&self, id: ike_core::WindowId) {
+        let Some(desc) = self.shell.context.world.get_window(id) else {
+            return;
+        };
 
-            Event::Event(mut event) => {
-                tracing::trace!(?event, "view event");
+        let (Some(parent), Some(anchor)) = (desc.parent(), desc.popup()) else {
+            return;
+        };
 
-                if let Some(ref mut state) = self.state {
-                    let action = Box::<dyn AnyView<_, _, _>>::event(
-                        (),
-                        state,
-                        &mut self.context,
-                        self.data,
-                        &mut event,
-                    );
+        let parent = self.windows.iter().find(|w| w.id == parent);
+        let popup = self.windows.iter().find(|w| w.id == id);
 
-                    self.context.proxy.action(action);
-                }
-            }
+        let (Some(parent), Some(popup)) = (parent, popup) else {
+            return;
+        };
+
+        let size = (popup.window.outer_size()).to_logical(popup.window.scale_factor());
 
-            Event::Spawn(future) => {
-                self.runtime.spawn(future);
+        if let Some(position) = owner::popup_position(&parent.window, anchor, size) {
+            popup.window.set_outer_position(position);
+        }
+    }
+}
+
+impl<'a, T> Driver<'a, T> for WinitDriver<'_, 'a, T> {
+    type Error = Error;
+
+    fn shell(&mut self) -> &mut Shell<'a, T> {
+        &mut self.app.shell
+    }
+
+    fn request_redraw(&mut self, window: ike_core::WindowId) {
+        if let Some(window) = self.app.window_mut(window) {
+            window.window.request_redraw();
+        }
+    }
+
+    fn create_window(&mut self, id: ike_core::WindowId) -> Result<(), Error> {
+        let app = &mut *self.app;
+
+        if let Some(window) = app.shell.context.world.get_window(id) {
+            let parent = (window.parent())
+                .and_then(|parent| app.windows.iter().find(|w| w.id == parent))
+                .map(|parent| &parent.window);
+
+            let window = WindowState::new(
+                &mut app.vulkan,
+                self.event_loop,
+                window,
+                parent,
+            )?;
+            let theme = window.window.theme();
+
+            if let Ok(position) = window.window.outer_position() {
+                let position = position.to_logical::<f32>(window.window.scale_factor());
+                let position = Point::new(position.x, position.y);
+
+                (app.shell.context.world).window_moved(id, position);
             }
 
-            Event::Signal(signal) => {
-                self.handle_signal(event_loop, signal)?;
+            app.windows.push(window);
+
+            if let Some(theme) = theme
+                && (app.shell.context.world).color_scheme_changed(Some(color_scheme(theme)))
+            {
+                app.shell.context.proxy.rebuild();
             }
         }
 
         Ok(())
     }
 
-    fn handle_signal(&mut self, event_loop: &ActiveEventLoop, signal: Signal) -> Result<(), Error> {
-        match signal {
-            Signal::RequestRedraw { window } => {
-                if let Some(window) = self.windows.iter().find(|w| w.id == window) {
-                    window.window.request_redraw();
+    fn remove_window(&mut self, id: ike_core::WindowId) {
+        self.app.windows.retain(|w| w.id != id);
+    }
+
+    fn update_window(&mut self, id: ike_core::WindowId, update: WindowUpdate) {
+        let Some(win) = self.app.windows.iter_mut().find(|w| w.id == id) else {
+            return;
+        };
+
+        match update {
+            WindowUpdate::Title(title) => win.window.set_title(&title),
+
+            WindowUpdate::Sizing(sizing) => {
+                if let WindowSizing::Resizable {
+                    min_size, max_size, ..
+                } = sizing
+                {
+                    win.window.set_min_inner_size(Some(LogicalSize::new(
+                        min_size.width,
+                        min_size.height,
+                    )));
+
+                    win.window.set_max_inner_size(Some(LogicalSize::new(
+                        max_size.width,
+                        max_size.height,
+                    )));
                 }
             }
 
-            Signal::RequestAnimate { window, start } => {
-                if let Some(window) = self.windows.iter_mut().find(|w| w.id == window)
-                    && window.animate.is_none()
-                {
-                    window.animate = Some(start);
-                    window.window.request_redraw();
+            WindowUpdate::Visible(visible) => {
+                win.window.set_visible(visible);
+            }
+
+            WindowUpdate::Decorated(decorated) => {
+                win.window.set_decorations(decorated);
+            }
+
+            WindowUpdate::Level(level) => {
+                win.window.set_window_level(window_level(level));
+            }
+
+            WindowUpdate::SkipTaskbar(skip_taskbar) => {
+                set_skip_taskbar(&win.window, skip_taskbar);
+            }
+
+            WindowUpdate::Blur(blur) => {
+                win.window.set_blur(blur);
+            }
+
+            WindowUpdate::Cursor(cursor) => {
+                (self.app.cursors).set(self.event_loop, &win.window, cursor);
+            }
+
+            WindowUpdate::CursorGrab(grab) => {
+                win.cursor_grab = cursor::set_grab(&win.window, grab);
+            }
+
+            WindowUpdate::CursorPosition(position) => {
+                let position = LogicalPosition::new(position.x, position.y);
+
+                if let Err(err) = win.window.set_cursor_position(position) {
+                    tracing::warn!("failed moving cursor: {err}");
                 }
             }
 
-            Signal::Mutate(f) => {
-                f(&mut self.context.world);
+            WindowUpdate::Presentation(presentation) => {
+                if let Err(err) = win.surface.set_presentation(presentation) {
+                    tracing::error!("failed setting presentation: {err}");
+                }
             }
 
-            Signal::ClipboardSet(text) => {
-                let _ = self.clipboard.set_text(text);
+            WindowUpdate::Drag => {
+                if let Err(err) = win.window.drag_window() {
+                    tracing::warn!("failed dragging window: {err}");
+                }
             }
 
-            Signal::CreateWindow(id) => {
-                if let Some(window) = self.context.world.get_window(id) {
-                    let window = WindowState::new(&mut self.vulkan, event_loop, window)?;
-                    self.windows.push(window);
+            WindowUpdate::DragResize(edge) => {
+                if let Err(err) = win.window.drag_resize_window(resize_direction(edge)) {
+                    tracing::warn!("failed resizing window: {err}");
                 }
             }
 
-            Signal::RemoveWindow(id) => {
-                self.windows.retain(|w| w.id != id);
+            WindowUpdate::Maximized(maximized) => {
+                win.window.set_maximized(maximized);
             }
 
-            Signal::UpdateWindow(id, update) => {
-                let Some(win) = self.windows.iter_mut().find(|w| w.id == id) else {
-                    return Ok(());
-                };
+            WindowUpdate::Minimized(minimized) => {
+                win.window.set_minimized(minimized);
+            }
 
-                match update {
-                    WindowUpdate::Title(title) => win.window.set_title(&title),
-
-                    WindowUpdate::Sizing(sizing) => {
-                        if let WindowSizing::Resizable {
-                            min_size, max_size, ..
-                        } = sizing
-                        {
-                            win.window.set_min_inner_size(Some(LogicalSize::new(
-                                min_size.width,
-                                min_size.height,
-                            )));
-
-                            win.window.set_max_inner_size(Some(LogicalSize::new(
-                                max_size.width,
-                                max_size.height,
-                            )));
-                        }
-                    }
+            WindowUpdate::Position(position) => {
+                let position = LogicalPosition::new(position.x, position.y);
+                win.window.set_outer_position(position);
+            }
 
-                    WindowUpdate::Visible(visible) => {
-                        win.window.set_visible(visible);
-                    }
+            WindowUpdate::Focus => {
+                win.window.focus_window();
+            }
 
-                    WindowUpdate::Decorated(decorated) => {
-                        win.window.set_decorations(decorated);
-                    }
+            WindowUpdate::Popup(..) => {
+                self.app.place_popup(id);
+            }
+        }
+    }
 
-                    WindowUpdate::Cursor(cursor) => {
-                        win.window.set_cursor(cursor);
+    fn close_window(&mut self, _window: ike_core::WindowId) {
+        // closing any window exits, like when its close button is pressed
+        self.event_loop.exit();
+    }
+
+    fn set_clipboard(&mut self, text: String) {
+        let _ = self.app.clipboard.set_text(text);
+    }
+
+    fn paste_clipboard(&mut self, window: ike_core::WindowId) {
+        if let Ok(text) = self.app.clipboard.get_text() {
+            self.app.shell.context.world.text_pasted(window, text);
+        }
+    }
+
+    fn ime_signal(&mut self, ime: ImeSignal) {
+        match ime {
+            ImeSignal::Start | ImeSignal::End => {
+                let allowed = matches!(ime, ImeSignal::Start);
+
+                for window in &self.app.windows {
+                    window.window.set_ime_allowed(allowed);
+                }
+            }
+
+            ImeSignal::Area(area) => {
+                let position = LogicalPosition::new(area.min.x, area.min.y);
+                let size = LogicalSize::new(area.width(), area.height());
+
+                for window in &self.app.windows {
+                    if window.window.has_focus() {
+                        window.window.set_ime_cursor_area(position, size);
                     }
                 }
             }
 
-            Signal::Ime(..) => {}
+            ImeSignal::Purpose(purpose) => {
+                let purpose = match purpose {
+                    ike_core::ImePurpose::Normal => winit::window::ImePurpose::Normal,
+                    ike_core::ImePurpose::Password => winit::window::ImePurpose::Password,
+                };
+
+                for window in &self.app.windows {
+                    window.window.set_ime_purpose(purpose);
+                }
+            }
+
+            // winit keeps track of the text itself
+            ImeSignal::Text(..) | ImeSignal::Selection { .. } => {}
         }
+    }
+
+    fn print(&mut self, widget: WidgetId, setup: PageSetup) -> Result<(), Error> {
+        let runtime = self.app.shell.runtime().clone();
+        let context = &mut self.app.shell.context;
+
+        // the print dialog is modal to the window showing the widget
+        let parent = (context.world.get_widget(widget).ok())
+            .and_then(|widget| widget.cx.window())
+            .and_then(|window| self.app.windows.iter().find(|w| w.id == window))
+            .map(|w| &w.window);
+
+        print::print(
+            &mut context.world,
+            &mut self.app.shell.painter,
+            widget,
+            &setup,
+            parent,
+            &runtime,
+        )?;
 
         Ok(())
     }
+
+    fn show_file_dialog(&mut self, dialog: FileDialog, respond: FileDialogResponder) {
+        let parent = dialog.parent.and_then(|parent| {
+            let window = self.app.windows.iter().find(|w| w.id == parent);
+            window.map(|w| &w.window)
+        });
+
+        let paths = dialog::show(dialog, parent);
+        (self.app.shell.runtime()).spawn(async move { respond(paths.await) });
+    }
+}
+
+/// Winit doesn't tell fingers and styluses apart, only styluses report their altitude.
+fn touch_tool(force: Option<Force>) -> Tool {
+    let Some(force) = force else {
+        return Tool::FINGER;
+    };
+
+    let pressure = force.normalized() as f32;
+
+    match force {
+        Force::Calibrated {
+            altitude_angle: Some(altitude),
+            ..
+        } => Tool {
+            kind: ToolKind::Stylus,
+            pressure,
+            tilt: std::f32::consts::FRAC_PI_2 - altitude as f32,
+            azimuth: 0.0,
+        },
+
+        _ => Tool {
+            pressure,
+            ..Tool::FINGER
+        },
+    }
+}
+
+fn color_scheme(theme: winit::window::Theme) -> ColorScheme {
+    match theme {
+        winit::window::Theme::Light => ColorScheme::Light,
+        winit::window::Theme::Dark => ColorScheme::Dark,
+    }
+}
+
+fn window_level(level: WindowLevel) -> winit::window::WindowLevel {
+    match level {
+        WindowLevel::AlwaysOnBottom => winit::window::WindowLevel::AlwaysOnBottom,
+        WindowLevel::Normal => winit::window::WindowLevel::Normal,
+        WindowLevel::AlwaysOnTop => winit::window::WindowLevel::AlwaysOnTop,
+    }
+}
+
+fn resize_direction(edge: ResizeEdge) -> ResizeDirection {
+    match edge {
+        ResizeEdge::North => ResizeDirection::North,
+        ResizeEdge::NorthEast => ResizeDirection::NorthEast,
+        ResizeEdge::East => ResizeDirection::East,
+        ResizeEdge::SouthEast => ResizeDirection::SouthEast,
+        ResizeEdge::South => ResizeDirection::South,
+        ResizeEdge::SouthWest => ResizeDirection::SouthWest,
+        ResizeEdge::West => ResizeDirection::West,
+        ResizeEdge::NorthWest => ResizeDirection::NorthWest,
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn set_skip_taskbar(window: &Window, skip_taskbar: bool) {
+    use winit::platform::windows::WindowExtWindows;
+
+    window.set_skip_taskbar(skip_taskbar);
+}
+
+#[cfg(not(target_os = "windows"))]
+fn set_skip_taskbar(_window: &Window, skip_taskbar: bool) {
+    if skip_taskbar {
+        tracing::warn!("skipping the taskbar is not supported on this platform");
+    }
 }
 
 impl WindowState {
@@ -511,6 +887,7 @@ impl WindowState {
         vulkan: &mut ike_skia::vulkan::Context,
         event_loop: &ActiveEventLoop,
         desc: &ike_core::Window,
+        parent: Option<&Window>,
     ) -> Result<Self, Error> {
         use winit::dpi::LogicalSize;
 
@@ -542,18 +919,42 @@ impl WindowState {
             .with_visible(desc.is_visible())
             .with_decorations(desc.is_decorated())
             .with_transparent(true)
+            .with_blur(desc.is_blurred())
+            .with_window_level(window_level(desc.level()))
             .with_min_inner_size(min_size)
             .with_max_inner_size(max_size)
             .with_inner_size(size)
-            .with_cursor(desc.cursor())
+            .with_maximized(desc.is_maximized())
+            .with_cursor(match desc.cursor() {
+                ike_core::Cursor::Icon(icon) => *icon,
+                _ => ike_core::CursorIcon::Default,
+            })
             .with_resizable(matches!(
                 desc.sizing(),
                 WindowSizing::Resizable { .. }
             ));
 
+        // parents place their children themselves
+        let attributes = match desc.position() {
+            Some(position) if parent.is_none() => attributes.with_position(LogicalPosition::new(
+                position.x, position.y,
+            )),
+
+            _ => attributes,
+        };
+
+        let attributes = match parent {
+            Some(parent) => owner::with_parent(attributes, parent, desc.popup(), size),
+            None => attributes,
+        };
+
         let window = event_loop.create_window(attributes)?;
 
-        let surface = unsafe {
+        if desc.skips_taskbar() {
+            set_skip_taskbar(&window, true);
+        }
+
+        let mut surface = unsafe {
             let physical = window.inner_size();
             Surface::new(
                 vulkan,
@@ -565,11 +966,14 @@ impl WindowState {
             )?
         };
 
+        surface.set_presentation(desc.presentation())?;
+
         Ok(Self {
             id: desc.id(),
-            animate: None,
             surface,
             window,
+            touchpad: touchpad::Touchpad::default(),
+            cursor_grab: CursorGrab::None,
         })
     }
 
@@ -0,0 +1,63 @@
+use std::process::Command;
+
+use ike_core::AnimationSettings;
+
+/// Read the animation preferences from the accessibility settings of the OS.
+pub(crate) fn animation_settings() -> AnimationSettings {
+    AnimationSettings {
+        reduce_motion: reduce_motion().unwrap_or(false),
+        ..AnimationSettings::default()
+    }
+}
+
+fn output(command: &mut Command) -> Option<String> {
+    let output = command.output().ok()?;
+
+    match output.status.success() {
+        true => Some(String::from_utf8_lossy(&output.stdout).trim().to_owned()),
+        false => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn reduce_motion() -> Option<bool> {
+    let value = output(
+        Command::new("defaults")
+            .arg("read")
+            .arg("com.apple.universalaccess")
+            .arg("reduceMotion"),
+    )?;
+
+    Some(value == "1")
+}
+
+#[cfg(target_os = "windows")]
+fn reduce_motion() -> Option<bool> {
+    let value = output(
+        Command::new("reg")
+            .arg("query")
+            .arg(r"HKCU\Control Panel\Desktop\WindowMetrics")
+            .arg("/v")
+            .arg("MinAnimate"),
+    )?;
+
+    // the value is printed as `MinAnimate    REG_SZ    0`
+    Some(value.split_whitespace().last()? == "0")
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn reduce_motion() -> Option<bool> {
+    let value = output(
+        Command::new("gsettings")
+            .arg("get")
+            .arg("org.gnome.desktop.interface")
+            .arg("enable-animations"),
+    )?;
+
+    Some(value == "false")
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn reduce_motion() -> Option<bool> {
+    None
+}
@@ -0,0 +1,149 @@
+use ike_core::{Point, PopupAnchor, Rect, Size};
+use winit::{
+    dpi::{LogicalPosition, LogicalSize, PhysicalPosition},
+    window::{Window, WindowAttributes},
+};
+
+/// Make the window created with `attributes` owned by `parent`, so it's kept above it.
+///
+/// Only windows and macos support owned windows, elsewhere the window is just centered. Popups
+/// are placed next to their anchor instead.
+pub(crate) fn with_parent(
+    attributes: WindowAttributes,
+    parent: &Window,
+    popup: Option<PopupAnchor>,
+    size: LogicalSize<f32>,
+) -> WindowAttributes {
+    let position = match popup {
+        Some(anchor) => popup_position(parent, anchor, size),
+        None => centered(parent, size),
+    };
+
+    let attributes = match position {
+        Some(position) => attributes.with_position(position),
+        None => attributes,
+    };
+
+    let attributes = match popup {
+        Some(_) => with_popup(attributes.with_decorations(false)),
+        None => attributes,
+    };
+
+    with_owner(attributes, parent)
+}
+
+/// The position placing a popup of `size` next to `anchor` in `parent`, flipped and slid to
+/// stay on the monitor of `parent`.
+///
+/// Wayland doesn't let windows be positioned, popups are placed by the compositor there.
+pub(crate) fn popup_position(
+    parent: &Window,
+    anchor: PopupAnchor,
+    size: LogicalSize<f32>,
+) -> Option<PhysicalPosition<i32>> {
+    let scale = parent.scale_factor();
+    let origin = parent.inner_position().ok()?.to_logical::<f32>(scale);
+    let monitor = parent.current_monitor()?;
+
+    let monitor_position = monitor.position().to_logical::<f32>(scale);
+    let monitor_size = monitor.size().to_logical::<f32>(scale);
+
+    let bounds = Rect::min_size(
+        Point::new(monitor_position.x, monitor_position.y),
+        Size::new(monitor_size.width, monitor_size.height),
+    );
+
+    // the anchor is in the coordinates of the parent, the bounds in those of the desktop
+    let anchor = PopupAnchor {
+        rect: Rect {
+            min: Point::new(
+                origin.x + anchor.rect.min.x,
+                origin.y + anchor.rect.min.y,
+            ),
+            max: Point::new(
+                origin.x + anchor.rect.max.x,
+                origin.y + anchor.rect.max.y,
+            ),
+        },
+        ..anchor
+    };
+
+    let position = anchor.place(
+        Size::new(size.width, size.height),
+        bounds,
+    );
+    Some(LogicalPosition::new(position.x, position.y).to_physical(scale))
+}
+
+/// The position centering a window of `size` on `parent`.
+fn centered(parent: &Window, size: LogicalSize<f32>) -> Option<PhysicalPosition<i32>> {
+    let position = parent.outer_position().ok()?;
+    let parent_size = parent.outer_size();
+    let size = size.to_physical::<i32>(parent.scale_factor());
+
+    Some(PhysicalPosition::new(
+        position.x + (parent_size.width as i32 - size.width) / 2,
+        position.y + (parent_size.height as i32 - size.height) / 2,
+    ))
+}
+
+/// Create the window as a popup, that isn't managed like a regular window by the platform.
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+fn with_popup(attributes: WindowAttributes) -> WindowAttributes {
+    use winit::platform::x11::{WindowAttributesExtX11, WindowType};
+
+    // only applies to x11, wayland has no popups in winit yet
+    attributes
+        .with_override_redirect(true)
+        .with_x11_window_type(vec![WindowType::PopupMenu])
+}
+
+#[cfg(target_os = "windows")]
+fn with_popup(attributes: WindowAttributes) -> WindowAttributes {
+    use winit::platform::windows::WindowAttributesExtWindows;
+
+    attributes.with_skip_taskbar(true)
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+    )
+)))]
+fn with_popup(attributes: WindowAttributes) -> WindowAttributes {
+    attributes.with_window_level(winit::window::WindowLevel::AlwaysOnTop)
+}
+
+#[cfg(target_os = "windows")]
+fn with_owner(attributes: WindowAttributes, parent: &Window) -> WindowAttributes {
+    use winit::{
+        platform::windows::WindowAttributesExtWindows,
+        raw_window_handle::{HasWindowHandle, RawWindowHandle},
+    };
+
+    match parent.window_handle().map(|handle| handle.as_raw()) {
+        Ok(RawWindowHandle::Win32(handle)) => attributes.with_owner_window(handle.hwnd.get()),
+        _ => attributes,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn with_owner(attributes: WindowAttributes, parent: &Window) -> WindowAttributes {
+    use winit::raw_window_handle::HasWindowHandle;
+
+    match parent.window_handle() {
+        // SAFETY: the parent outlives its children, they're removed together
+        Ok(handle) => unsafe { attributes.with_parent_window(Some(handle.as_raw())) },
+        Err(_) => attributes,
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn with_owner(attributes: WindowAttributes, _parent: &Window) -> WindowAttributes {
+    attributes
+}
@@ -0,0 +1,333 @@
+use std::io;
+
+use ike_core::{PageSetup, WidgetId, World};
+use ike_skia::SkiaPainter;
+use tokio::runtime::Handle;
+use winit::window::Window;
+
+/// The title of print jobs, shown in the print dialog and the queue of the printer.
+const TITLE: &str = "ike";
+
+/// Show the platform print dialog modal to `parent`, and print `widget` with the picked
+/// printer and settings.
+///
+/// The portal prints in the background, the dialogs of windows and macos block until closed.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub(crate) fn print(
+    world: &mut World,
+    painter: &mut SkiaPainter,
+    widget: WidgetId,
+    setup: &PageSetup,
+    _parent: Option<&Window>,
+    runtime: &Handle,
+) -> io::Result<()> {
+    use ashpd::{Error, desktop::ResponseError};
+
+    let pdf = ike_skia::render_pdf(world, widget, setup, painter);
+    let file = portal::unlinked_file(&pdf)?;
+
+    runtime.spawn(async move {
+        match portal::print(file).await {
+            Ok(()) | Err(Error::Response(ResponseError::Cancelled)) => {}
+            Err(err) => tracing::warn!("failed to print: {err}"),
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+mod portal {
+    use std::{
+        env,
+        fs::{self, File},
+        io::{self, Seek, Write},
+        process,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use ashpd::desktop::print::{PageSetup, PrintProxy, Settings};
+
+    use super::TITLE;
+
+    /// Write `bytes` to a temporary file that's removed as soon as it's created, so it only
+    /// lives on as the returned handle.
+    pub(super) fn unlinked_file(bytes: &[u8]) -> io::Result<File> {
+        static NEXT_JOB: AtomicU64 = AtomicU64::new(0);
+
+        let job = NEXT_JOB.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!(
+            "ike-print-{}-{job}.pdf",
+            process::id(),
+        ));
+
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+
+        fs::remove_file(&path)?;
+
+        file.write_all(bytes)?;
+        file.rewind()?;
+
+        Ok(file)
+    }
+
+    /// Show the print dialog of the desktop portal, and hand it the document in `file`.
+    pub(super) async fn print(file: File) -> ashpd::Result<()> {
+        let proxy = PrintProxy::new().await?;
+
+        let prepared = proxy
+            .prepare_print(
+                None,
+                TITLE,
+                Settings::default(),
+                PageSetup::default(),
+                None,
+                true,
+            )
+            .await?
+            .response()?;
+
+        proxy
+            .print(
+                None,
+                TITLE,
+                &file,
+                Some(prepared.token),
+                true,
+            )
+            .await?
+            .response()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn print(
+    world: &mut World,
+    painter: &mut SkiaPainter,
+    widget: WidgetId,
+    setup: &PageSetup,
+    _parent: Option<&Window>,
+    _runtime: &Handle,
+) -> io::Result<()> {
+    use objc2::AllocAnyThread;
+    use objc2_app_kit::NSPrintInfo;
+    use objc2_foundation::NSData;
+    use objc2_pdf_kit::{PDFDocument, PDFPrintScalingMode};
+
+    let pdf = ike_skia::render_pdf(world, widget, setup, painter);
+    let data = NSData::with_bytes(&pdf);
+
+    // SAFETY: print signals are handled by the event loop, on the main thread AppKit requires
+    unsafe {
+        let document = PDFDocument::initWithData(PDFDocument::alloc(), &data)
+            .ok_or_else(|| io::Error::other("invalid pdf document"))?;
+
+        let info = NSPrintInfo::sharedPrintInfo();
+        let operation = document
+            .printOperationForPrintInfo_scalingMode_autoRotate(
+                Some(&info),
+                PDFPrintScalingMode::PageScaleNone,
+                true,
+            )
+            .ok_or_else(|| io::Error::other("pdf document can't be printed"))?;
+
+        // runs the print panel modally, and prints once it's confirmed
+        operation.setShowsPrintPanel(true);
+        operation.runOperation();
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn print(
+    world: &mut World,
+    painter: &mut SkiaPainter,
+    widget: WidgetId,
+    setup: &PageSetup,
+    parent: Option<&Window>,
+    _runtime: &Handle,
+) -> io::Result<()> {
+    use std::mem;
+
+    use windows_sys::Win32::{
+        Foundation::{GlobalFree, HWND, S_OK},
+        Graphics::Gdi::DeleteDC,
+        UI::Controls::Dialogs::{
+            PD_NOCURRENTPAGE, PD_NOPAGENUMS, PD_NOSELECTION, PD_RESULT_PRINT, PD_RETURNDC,
+            PD_USEDEVMODECOPIESANDCOLLATE, PRINTDLGEXW, PrintDlgExW, START_PAGE_GENERAL,
+        },
+    };
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    // the dialog can't be shown without a window to own it
+    let owner = parent.and_then(|parent| match parent.window_handle() {
+        Ok(handle) => match handle.as_raw() {
+            RawWindowHandle::Win32(handle) => Some(handle.hwnd.get() as HWND),
+            _ => None,
+        },
+        Err(_) => None,
+    });
+
+    let Some(owner) = owner else {
+        return Err(io::Error::other(
+            "no window to show the print dialog for",
+        ));
+    };
+
+    // SAFETY: the fields not set are valid zeroed, and the handles returned by the dialog are
+    // freed once the document is printed
+    unsafe {
+        let mut dialog = PRINTDLGEXW {
+            lStructSize: mem::size_of::<PRINTDLGEXW>() as u32,
+            hwndOwner: owner,
+            Flags: PD_RETURNDC
+                | PD_NOPAGENUMS
+                | PD_NOSELECTION
+                | PD_NOCURRENTPAGE
+                | PD_USEDEVMODECOPIESANDCOLLATE,
+            nStartPage: START_PAGE_GENERAL,
+            ..mem::zeroed()
+        };
+
+        let result = PrintDlgExW(&mut dialog);
+
+        if !dialog.hDevMode.is_null() {
+            GlobalFree(dialog.hDevMode);
+        }
+
+        if !dialog.hDevNames.is_null() {
+            GlobalFree(dialog.hDevNames);
+        }
+
+        if result != S_OK {
+            return Err(io::Error::other(format!(
+                "print dialog failed: {result:#x}"
+            )));
+        }
+
+        if dialog.hDC.is_null() {
+            return Ok(());
+        }
+
+        let printed = match dialog.dwResultAction == PD_RESULT_PRINT {
+            true => gdi::print(
+                dialog.hDC, world, painter, widget, setup,
+            ),
+            false => Ok(()),
+        };
+
+        DeleteDC(dialog.hDC);
+        printed
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod gdi {
+    use std::{io, mem, ptr};
+
+    use ike_core::{PageSetup, WidgetId, World};
+    use ike_skia::SkiaPainter;
+    use windows_sys::Win32::{
+        Graphics::Gdi::{
+            BI_RGB, BITMAPINFO, BITMAPINFOHEADER, DIB_RGB_COLORS, GetDeviceCaps, HDC, LOGPIXELSX,
+            LOGPIXELSY, PHYSICALOFFSETX, PHYSICALOFFSETY, SRCCOPY, StretchDIBits,
+        },
+        Storage::Xps::{DOCINFOW, EndDoc, EndPage, StartDocW, StartPage},
+    };
+
+    use super::TITLE;
+
+    /// Pages are rendered at most at this resolution, and stretched to that of the printer.
+    const MAX_DPI: f32 = 300.0;
+
+    /// Print `widget` to the printer `hdc`, rasterizing its pages.
+    ///
+    /// # Safety
+    /// `hdc` must be a valid printer device context.
+    pub(super) unsafe fn print(
+        hdc: HDC,
+        world: &mut World,
+        painter: &mut SkiaPainter,
+        widget: WidgetId,
+        setup: &PageSetup,
+    ) -> io::Result<()> {
+        // SAFETY: `hdc` is valid by the contract of the function
+        unsafe {
+            let dpi_x = GetDeviceCaps(hdc, LOGPIXELSX) as f32;
+            let dpi_y = GetDeviceCaps(hdc, LOGPIXELSY) as f32;
+
+            // the printable area of the page starts at the physical offset
+            let offset_x = GetDeviceCaps(hdc, PHYSICALOFFSETX);
+            let offset_y = GetDeviceCaps(hdc, PHYSICALOFFSETY);
+
+            let width = (setup.size.width * dpi_x / 72.0).round() as i32;
+            let height = (setup.size.height * dpi_y / 72.0).round() as i32;
+
+            let scale = f32::min(dpi_x, MAX_DPI) / 72.0;
+            let pages = ike_skia::render_pages(world, widget, setup, painter, scale);
+
+            let title: Vec<u16> = TITLE.encode_utf16().chain([0]).collect();
+            let document = DOCINFOW {
+                cbSize:       mem::size_of::<DOCINFOW>() as i32,
+                lpszDocName:  title.as_ptr(),
+                lpszOutput:   ptr::null(),
+                lpszDatatype: ptr::null(),
+                fwType:       0,
+            };
+
+            if StartDocW(hdc, &document) <= 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            for page in pages {
+                // the device independent bitmap is top down BGRA
+                let mut pixels = page.pixels;
+                for pixel in pixels.chunks_exact_mut(4) {
+                    pixel.swap(0, 2);
+                }
+
+                let info = BITMAPINFO {
+                    bmiHeader: BITMAPINFOHEADER {
+                        biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+                        biWidth: page.width as i32,
+                        biHeight: -(page.height as i32),
+                        biPlanes: 1,
+                        biBitCount: 32,
+                        biCompression: BI_RGB,
+                        ..mem::zeroed()
+                    },
+                    ..mem::zeroed()
+                };
+
+                StartPage(hdc);
+                StretchDIBits(
+                    hdc,
+                    -offset_x,
+                    -offset_y,
+                    width,
+                    height,
+                    0,
+                    0,
+                    page.width as i32,
+                    page.height as i32,
+                    pixels.as_ptr().cast(),
+                    &info,
+                    DIB_RGB_COLORS,
+                    SRCCOPY,
+                );
+                EndPage(hdc);
+            }
+
+            EndDoc(hdc);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use std::time::{Duration, Instant};
+
+use ike_core::{
+    FlingGesture, Gesture, Offset, PanGesture, PinchGesture, Point, RotateGesture, TapGesture,
+    TouchId, WindowId, World,
+};
+use winit::event::{DeviceId, TouchPhase};
+
+/// How long a touchpad can be held still before being released and still fling.
+const FLING_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// The weight of the latest delta when computing the velocity of a touchpad.
+const VELOCITY_SMOOTHING: f32 = 0.6;
+
+/// The gestures of a touchpad over a window, which winit only reports as deltas.
+pub(crate) struct Touchpad {
+    cursor: Point,
+
+    scale: f32,
+    angle: f32,
+
+    pan_start: Point,
+    pan:       Offset,
+
+    velocity:  Offset,
+    last_move: Instant,
+}
+
+impl Default for Touchpad {
+    fn default() -> Self {
+        Self {
+            cursor:    Point::ORIGIN,
+            scale:     1.0,
+            angle:     0.0,
+            pan_start: Point::ORIGIN,
+            pan:       Offset::ZERO,
+            velocity:  Offset::ZERO,
+            last_move: Instant::now(),
+        }
+    }
+}
+
+impl Touchpad {
+    /// Gestures are sent to the widget under the cursor.
+    pub(crate) fn cursor_moved(&mut self, position: Point) {
+        self.cursor = position;
+    }
+
+    pub(crate) fn cursor(&self) -> Point {
+        self.cursor
+    }
+
+    pub(crate) fn pinch(
+        &mut self,
+        world: &mut World,
+        window: WindowId,
+        device: DeviceId,
+        delta: f64,
+        phase: TouchPhase,
+    ) {
+        if let TouchPhase::Started = phase {
+            self.scale = 1.0;
+        }
+
+        // winit reports how much the scale should grow, relative to the current scale
+        let delta = 1.0 + delta as f32;
+        self.scale *= delta;
+
+        let touch = touch_id(device);
+        let gesture = Gesture::Pinch(PinchGesture {
+            touches: [touch, touch],
+            focal: self.cursor,
+            scale: self.scale,
+            delta,
+        });
+
+        world.gesture(window, self.cursor, gesture);
+    }
+
+    pub(crate) fn rotate(
+        &mut self,
+        world: &mut World,
+        window: WindowId,
+        device: DeviceId,
+        delta: f32,
+        phase: TouchPhase,
+    ) {
+        if let TouchPhase::Started = phase {
+            self.angle = 0.0;
+        }
+
+        // winit reports counter-clockwise degrees
+        let delta = -delta.to_radians();
+        self.angle += delta;
+
+        let touch = touch_id(device);
+        let gesture = Gesture::Rotate(RotateGesture {
+            touches: [touch, touch],
+            focal: self.cursor,
+            angle: self.angle,
+            delta,
+        });
+
+        world.gesture(window, self.cursor, gesture);
+    }
+
+    pub(crate) fn double_tap(&mut self, world: &mut World, window: WindowId, device: DeviceId) {
+        let gesture = Gesture::DoubleTap(TapGesture {
+            touch:    touch_id(device),
+            position: self.cursor,
+        });
+
+        world.gesture(window, self.cursor, gesture);
+    }
+
+    pub(crate) fn pan(
+        &mut self,
+        world: &mut World,
+        window: WindowId,
+        device: DeviceId,
+        delta: Offset,
+        phase: TouchPhase,
+    ) {
+        if let TouchPhase::Started = phase {
+            self.pan_start = self.cursor;
+            self.pan = Offset::ZERO;
+            self.velocity = Offset::ZERO;
+            self.last_move = Instant::now();
+        }
+
+        if let TouchPhase::Ended | TouchPhase::Cancelled = phase {
+            self.fling(world, window, device);
+            return;
+        }
+
+        self.pan += delta;
+        self.track_velocity(delta);
+
+        let gesture = Gesture::Pan(PanGesture {
+            touch: touch_id(device),
+            start: self.pan_start,
+            position: self.pan_start + self.pan,
+            delta,
+            velocity: self.velocity,
+        });
+
+        world.gesture(window, self.cursor, gesture);
+    }
+
+    /// Track the velocity of scrolling with a touchpad, so it can fling when released.
+    pub(crate) fn scrolled(
+        &mut self,
+        world: &mut World,
+        window: WindowId,
+        device: DeviceId,
+        delta: Offset,
+        phase: TouchPhase,
+    ) {
+        match phase {
+            TouchPhase::Started => {
+                self.velocity = Offset::ZERO;
+                self.last_move = Instant::now();
+            }
+
+            TouchPhase::Moved => {
+                self.track_velocity(delta);
+            }
+
+            // macos continues scrolling with momentum of its own
+            TouchPhase::Ended | TouchPhase::Cancelled if !cfg!(target_os = "macos") => {
+                self.fling(world, window, device);
+            }
+
+            TouchPhase::Ended | TouchPhase::Cancelled => {}
+        }
+    }
+
+    fn track_velocity(&mut self, delta: Offset) {
+        let now = Instant::now();
+        let dt = now.duration_since(self.last_move).as_secs_f32();
+        self.last_move = now;
+
+        if dt > 0.0 {
+            let velocity = delta / dt;
+            self.velocity =
+                self.velocity * (1.0 - VELOCITY_SMOOTHING) + velocity * VELOCITY_SMOOTHING;
+        }
+    }
+
+    fn fling(&mut self, world: &mut World, window: WindowId, device: DeviceId) {
+        let velocity = std::mem::replace(&mut self.velocity, Offset::ZERO);
+
+        if self.last_move.elapsed() > FLING_TIMEOUT
+            || velocity.length() < world.settings().touch.fling_velocity
+        {
+            return;
+        }
+
+        let gesture = Gesture::Fling(FlingGesture {
+            touch: touch_id(device),
+            position: self.cursor,
+            velocity,
+        });
+
+        world.gesture(window, self.cursor, gesture);
+    }
+}
+
+fn touch_id(device: DeviceId) -> TouchId {
+    TouchId::from_hash(device)
+}
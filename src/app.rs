@@ -1,4 +1,4 @@
-use ike_core::Settings;
+use ike_core::{AnimationSettings, ColorBlindness, Settings};
 use ike_ori::Effect;
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt};
 
@@ -11,6 +11,10 @@ pub enum Error {
     #[cfg(backend = "android")]
     #[error(transparent)]
     Android(ike_android::Error),
+
+    #[cfg(backend = "kms")]
+    #[error(transparent)]
+    Kms(ike_kms::Error),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -42,11 +46,52 @@ impl App {
         self
     }
 
+    /// Show the allocations made in each phase of a frame, see [`ike_core::CountingAllocator`].
+    pub fn allocation_overlay(mut self, enabled: bool) -> Self {
+        self.settings.debug.allocation_overlay = enabled;
+        self
+    }
+
+    /// Draw windows as they're seen with `color_blindness`.
+    pub fn color_blindness(mut self, color_blindness: Option<ColorBlindness>) -> Self {
+        self.settings.debug.color_blindness = color_blindness;
+        self
+    }
+
+    /// Flag text with too little contrast to its background.
+    pub fn contrast_overlay(mut self, enabled: bool) -> Self {
+        self.settings.debug.contrast_overlay = enabled;
+        self
+    }
+
     pub fn pixel_align(mut self, enabled: bool) -> Self {
         self.settings.render.pixel_align = enabled;
         self
     }
 
+    /// Override the animation settings read from the OS accessibility settings.
+    pub fn animation(mut self, animation: AnimationSettings) -> Self {
+        self.settings.animation = Some(animation);
+        self
+    }
+
+    /// Make every transition complete instantly, regardless of the OS settings.
+    pub fn reduce_motion(mut self, enabled: bool) -> Self {
+        let animation = self.settings.animation.get_or_insert_default();
+        animation.reduce_motion = enabled;
+        self
+    }
+
+    /// Get the state saved with [`Builder::save_state`](crate::Builder::save_state) before the
+    /// application was last killed in the background, only supported on android.
+    pub fn saved_state() -> Option<Vec<u8>> {
+        #[cfg(backend = "android")]
+        return ike_android::saved_state();
+
+        #[cfg(not(backend = "android"))]
+        None
+    }
+
     pub fn install_log() {
         let mut filter = EnvFilter::default();
 
@@ -91,6 +136,9 @@ impl App {
         #[cfg(backend = "android")]
         ike_android::run(data, build, self.settings).map_err(Error::Android)?;
 
+        #[cfg(backend = "kms")]
+        ike_kms::run(data, build, self.settings).map_err(Error::Kms)?;
+
         Ok(())
     }
 }
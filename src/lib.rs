@@ -17,13 +17,23 @@ pub mod prelude {
     pub use crate::App;
 
     pub use ike_core::{
-        Axis, BorderWidth, Color, CornerRadius, FontStretch, FontStyle, FontWeight, Key, KeyEvent,
-        Modifiers, NamedKey, Offset, Padding, Point, PointerButton, PointerEvent, Size, Svg,
-        SvgData, TextAlign, TextWrap, Transition, WindowSizing, include_svg,
-        widgets::{Align, Fit, Justify, NewlineBehaviour, Picturable, SubmitBehaviour},
+        Axis, BorderWidth, Color, ColorScheme, CornerRadius, FontStretch, FontStyle, FontWeight,
+        Key, KeyEvent, Keyframe, LayoutDirection, Modifiers, NamedKey, Offset, Padding, PageSetup,
+        Point, PointerButton, PointerEvent, Presentation, Size, Spring, Svg, SvgData, TextAlign,
+        TextWrap, Transition, TransitionCurve, WindowSizing, include_svg,
+        widgets::{
+            Align, ColumnWidth, Fit, Justify, NewlineBehaviour, Overscroll, Picturable, Port,
+            PortDirection, ScrollTarget, SortOrder, SubmitBehaviour, Tile, VisibilityTransition,
+            Wire,
+        },
     };
 
-    pub use ike_ori::{Effect, Palette, View, views::*};
+    pub use ike_ori::{
+        Effect, Palette, Theme, View,
+        l10n::{Locale, localized},
+        themed, tr,
+        views::*,
+    };
     pub use ori::{Action, Event, Keyed, Proxy, ViewId, keyed};
 
     pub use tracing::{